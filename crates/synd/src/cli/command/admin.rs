@@ -0,0 +1,194 @@
+use std::{io, process::ExitCode};
+
+use clap::{Args, Subcommand};
+use synd_client::{
+    Client,
+    payload::{PageInfo, Subscriber, SubscriberDetails, SubscriberSubscription},
+};
+
+use crate::{
+    cli::{command::CommandFailure, port::PortContext},
+    config::ConfigResolver,
+};
+
+const PAGE_SIZE: i64 = 100;
+
+/// Manage the subscribers of a multi-user daemon
+#[derive(Args, Debug)]
+pub struct AdminCommand {
+    #[command(subcommand)]
+    command: AdminSubcommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum AdminSubcommand {
+    Subscribers(SubscribersCommand),
+    Inspect(InspectCommand),
+    Delete(DeleteCommand),
+}
+
+impl AdminCommand {
+    pub async fn run(self, config: ConfigResolver) -> ExitCode {
+        let result = match self.command {
+            AdminSubcommand::Subscribers(subscribers) => subscribers.run(config).await,
+            AdminSubcommand::Inspect(inspect) => inspect.run(config).await,
+            AdminSubcommand::Delete(delete) => delete.run(config).await,
+        };
+        match result {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => CommandFailure::report(err),
+        }
+    }
+}
+
+/// List subscribers holding at least one subscription
+#[derive(Args, Debug)]
+struct SubscribersCommand {}
+
+impl SubscribersCommand {
+    async fn run(self, config: ConfigResolver) -> anyhow::Result<()> {
+        let cx = PortContext::new(&config).await?;
+        let result = async {
+            let subscribers = Self::fetch_all(&cx.client).await?;
+            if subscribers.is_empty() {
+                println!("No subscribers.");
+            } else {
+                Self::print(io::stdout().lock(), &subscribers)?;
+            }
+            Ok(())
+        }
+        .await;
+
+        cx.finish(result).await
+    }
+
+    async fn fetch_all(client: &Client) -> anyhow::Result<Vec<Subscriber>> {
+        let mut subscribers = Vec::new();
+        let mut after = None;
+        loop {
+            let page = client.fetch_subscribers(after, PAGE_SIZE).await?;
+            subscribers.extend(page.nodes);
+            match page.page_info {
+                PageInfo::More { next_cursor } => after = Some(next_cursor),
+                PageInfo::Complete { .. } => return Ok(subscribers),
+            }
+        }
+    }
+
+    fn print(mut writer: impl io::Write, subscribers: &[Subscriber]) -> io::Result<()> {
+        writeln!(writer, "{:<20} {:>13} ID", "SINCE", "SUBSCRIPTIONS")?;
+        for subscriber in subscribers {
+            writeln!(
+                writer,
+                "{:<20} {:>13} {}",
+                subscriber.first_subscribed_at.format("%Y-%m-%dT%H:%M:%SZ"),
+                subscriber.subscription_count,
+                subscriber.id,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Show a subscriber and the feeds it subscribes to
+#[derive(Args, Debug)]
+struct InspectCommand {
+    /// Subscriber ID, as listed by `admin subscribers`
+    id: String,
+}
+
+impl InspectCommand {
+    async fn run(self, config: ConfigResolver) -> anyhow::Result<()> {
+        let cx = PortContext::new(&config).await?;
+        let result = async {
+            let Some((subscriber, subscriptions)) = Self::fetch_all(&cx.client, &self.id).await?
+            else {
+                anyhow::bail!("Subscriber {} has no subscriptions", self.id);
+            };
+            Self::print(io::stdout().lock(), &subscriber, &subscriptions)?;
+            Ok(())
+        }
+        .await;
+
+        cx.finish(result).await
+    }
+
+    async fn fetch_all(
+        client: &Client,
+        id: &str,
+    ) -> anyhow::Result<Option<(Subscriber, Vec<SubscriberSubscription>)>> {
+        let mut subscriptions = Vec::new();
+        let mut after = None;
+        loop {
+            let Some(SubscriberDetails {
+                subscriber,
+                subscriptions: page,
+            }) = client
+                .fetch_subscriber(id.to_owned(), after, PAGE_SIZE)
+                .await?
+            else {
+                return Ok(None);
+            };
+            subscriptions.extend(page.nodes);
+            match page.page_info {
+                PageInfo::More { next_cursor } => after = Some(next_cursor),
+                PageInfo::Complete { .. } => return Ok(Some((subscriber, subscriptions))),
+            }
+        }
+    }
+
+    fn print(
+        mut writer: impl io::Write,
+        subscriber: &Subscriber,
+        subscriptions: &[SubscriberSubscription],
+    ) -> io::Result<()> {
+        writeln!(writer, "Subscriber: {}", subscriber.id)?;
+        writeln!(
+            writer,
+            "     Since: {}",
+            subscriber.first_subscribed_at.format("%Y-%m-%dT%H:%M:%SZ")
+        )?;
+        writeln!(writer, "     Feeds: {}", subscriber.subscription_count)?;
+        writeln!(writer)?;
+        writeln!(writer, "{:<11} {:<16} URL", "REQUIREMENT", "CATEGORY")?;
+        for subscription in subscriptions {
+            writeln!(
+                writer,
+                "{:<11} {:<16} {}",
+                subscription
+                    .requirement
+                    .map_or_else(|| "-".to_owned(), |requirement| requirement.to_string()),
+                subscription
+                    .category
+                    .as_ref()
+                    .map_or("-", |category| category.as_str()),
+                subscription.url,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Remove every subscription and saved entry of a subscriber
+#[derive(Args, Debug)]
+struct DeleteCommand {
+    /// Subscriber ID, as listed by `admin subscribers`
+    id: String,
+}
+
+impl DeleteCommand {
+    async fn run(self, config: ConfigResolver) -> anyhow::Result<()> {
+        let cx = PortContext::new(&config).await?;
+        let result = async {
+            let deleted = cx.client.delete_subscriber(self.id).await?;
+            println!(
+                "Deleted subscriber {}: unsubscribed {} feeds, unsaved {} entries.",
+                deleted.id, deleted.unsubscribed, deleted.unsaved
+            );
+            Ok(())
+        }
+        .await;
+
+        cx.finish(result).await
+    }
+}
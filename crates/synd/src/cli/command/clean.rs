@@ -2,7 +2,12 @@ use std::{io::ErrorKind, path::Path, process::ExitCode};
 
 use anyhow::Context;
 use clap::Args;
-use synd_support::fs::FileSystem;
+use synd_persistence::sqlite::{SqliteDatabase, SqliteFeedRegistryDb};
+use synd_registry::{BlobCollectionConfig, crawl::collector::collect_unreferenced_blobs};
+use synd_support::{
+    fs::FileSystem,
+    time::{Clock as _, SystemClock},
+};
 use synd_term::application::Cache;
 use tracing::{error, info};
 
@@ -17,17 +22,25 @@ pub struct CleanCommand {
     /// Remove log file
     #[arg(long, action = clap::ArgAction::SetTrue)]
     logs: bool,
+    /// Delete feed bodies in the local database that are no longer referenced
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    blobs: bool,
 }
 
 impl CleanCommand {
-    #[allow(clippy::unused_self)]
-    pub fn run<FS>(self, config: &ConfigResolver, fs: &FS) -> ExitCode
+    pub async fn run<FS>(self, config: &ConfigResolver, fs: &FS) -> ExitCode
     where
         FS: FileSystem + Clone,
     {
         let cache_dir = config.cache_dir();
         let log_file = config.log_file();
-        ExitCode::from(self.clean(fs, &cache_dir, &log_file))
+        let blobs = self.targets().blobs;
+
+        let mut code = self.clean(fs, &cache_dir, &log_file);
+        if code == 0 && blobs {
+            code = clean_blobs(&config.sqlite_db()).await;
+        }
+        ExitCode::from(code)
     }
 
     fn clean<FS>(self, fs: &FS, cache_dir: &Path, log: &Path) -> u8
@@ -73,11 +86,14 @@ impl CleanCommand {
         Ok(())
     }
 
+    /// Without flags, cache and logs are cleaned. Blobs live in the database
+    /// and are only touched when asked for explicitly.
     fn targets(&self) -> CleanTargets {
-        let default_all = !self.cache && !self.logs;
+        let default_all = !self.cache && !self.logs && !self.blobs;
         CleanTargets {
             cache: default_all || self.cache,
             logs: default_all || self.logs,
+            blobs: self.blobs,
         }
     }
 }
@@ -86,6 +102,37 @@ impl CleanCommand {
 struct CleanTargets {
     cache: bool,
     logs: bool,
+    blobs: bool,
+}
+
+async fn clean_blobs(sqlite_db: &Path) -> u8 {
+    if let Err(err) = try_clean_blobs(sqlite_db).await {
+        error!("{err:#}");
+        1
+    } else {
+        0
+    }
+}
+
+async fn try_clean_blobs(sqlite_db: &Path) -> anyhow::Result<()> {
+    if !sqlite_db.exists() {
+        info!("Database {} does not exist", sqlite_db.display());
+        return Ok(());
+    }
+
+    let db = SqliteDatabase::open(sqlite_db)
+        .await
+        .with_context(|| format!("path: {}", sqlite_db.display()))?;
+    let removed = collect_unreferenced_blobs(
+        &SqliteFeedRegistryDb::new(db),
+        &BlobCollectionConfig::default(),
+        SystemClock.now(),
+    )
+    .await
+    .with_context(|| format!("path: {}", sqlite_db.display()))?;
+    info!("Remove {removed} unreferenced blobs");
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -184,11 +231,39 @@ mod tests {
         assert!(log_file.path().exists());
     }
 
+    #[test]
+    fn blobs_only_preserves_log_file() {
+        let clean = CleanCommand::blobs();
+        let cache_dir = TempDir::new().unwrap();
+        let log_file = NamedTempFile::new().unwrap();
+        let exit_code = clean.clean(
+            &fsimpl::FileSystem::new(),
+            cache_dir.path(),
+            log_file.path(),
+        );
+        assert_eq!(exit_code, 0);
+        assert!(log_file.path().exists());
+    }
+
+    #[test]
+    fn default_targets_exclude_blobs() {
+        assert!(!CleanCommand::all().targets().blobs);
+    }
+
+    #[tokio::test]
+    async fn clean_blobs_ignores_missing_database() {
+        let dir = TempDir::new().unwrap();
+        let sqlite_db = dir.path().join("synd.db");
+        assert_eq!(clean_blobs(&sqlite_db).await, 0);
+        assert!(!sqlite_db.exists());
+    }
+
     impl CleanCommand {
         fn all() -> Self {
             Self {
                 cache: false,
                 logs: false,
+                blobs: false,
             }
         }
 
@@ -196,6 +271,7 @@ mod tests {
             Self {
                 cache: true,
                 logs: false,
+                blobs: false,
             }
         }
 
@@ -203,6 +279,15 @@ mod tests {
             Self {
                 cache: false,
                 logs: true,
+                blobs: false,
+            }
+        }
+
+        fn blobs() -> Self {
+            Self {
+                cache: false,
+                logs: false,
+                blobs: true,
             }
         }
     }
@@ -6,6 +6,7 @@ use std::{
 
 use clap::Args;
 use serde::Serialize;
+use synd_runtime::NotifySink;
 use synd_support::time::humantime::HumanDuration;
 
 use crate::{
@@ -54,7 +55,11 @@ struct ConfigViewOutput {
     backend: BackendOutput,
     api: ApiOutput,
     daemon: DaemonOutput,
+    remote: RemoteOutput,
+    crawl: CrawlOutput,
+    retention: RetentionOutput,
     feed: FeedOutput,
+    notify: NotifyOutput,
     #[serde(rename = "github")]
     gh: GhOutput,
     theme: ThemeOutput,
@@ -92,6 +97,27 @@ struct DaemonOutput {
     session_idle_shutdown_grace: String,
 }
 
+#[derive(Debug, Serialize)]
+struct RemoteOutput {
+    endpoint: Option<String>,
+    token_file: Option<PathBuf>,
+    ca_cert: Option<PathBuf>,
+}
+
+#[derive(Debug, Serialize)]
+struct CrawlOutput {
+    host_max_running_jobs: usize,
+    host_request_spacing: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct RetentionOutput {
+    max_age: Option<String>,
+    max_entries_per_feed: Option<usize>,
+    keep_unread: bool,
+    tombstone_seq_window: u64,
+}
+
 #[derive(Debug, Serialize)]
 struct FeedOutput {
     entries_limit: usize,
@@ -104,6 +130,12 @@ struct BrowserOutput {
     args: Vec<String>,
 }
 
+#[derive(Debug, Serialize)]
+struct NotifyOutput {
+    sinks: Vec<&'static str>,
+    rules: usize,
+}
+
 #[derive(Debug, Serialize)]
 struct GhOutput {
     enabled: bool,
@@ -112,7 +144,7 @@ struct GhOutput {
 
 #[derive(Debug, Serialize)]
 struct ThemeOutput {
-    name: &'static str,
+    name: String,
 }
 
 impl ConfigViewOutput {
@@ -124,6 +156,9 @@ impl ConfigViewOutput {
             Some(browser_command)
         };
 
+        let notify = config.notify();
+        let retention = config.timeline_retention();
+
         Self {
             config: ConfigFileOutput {
                 path: config.config_file(),
@@ -149,6 +184,25 @@ impl ConfigViewOutput {
                     config.daemon_session_idle_shutdown_grace(),
                 )),
             },
+            remote: RemoteOutput {
+                endpoint: config.remote_endpoint().map(String::from),
+                token_file: config.remote_token_file(),
+                ca_cert: config.remote_ca_cert(),
+            },
+            crawl: CrawlOutput {
+                host_max_running_jobs: config.crawl_host_max_running_jobs(),
+                host_request_spacing: config
+                    .crawl_host_request_spacing()
+                    .map(|spacing| String::from(HumanDuration::from(spacing))),
+            },
+            retention: RetentionOutput {
+                max_age: retention
+                    .max_age
+                    .map(|max_age| String::from(HumanDuration::from(max_age))),
+                max_entries_per_feed: retention.max_entries_per_feed,
+                keep_unread: retention.keep_unread,
+                tombstone_seq_window: retention.tombstone_seq_window,
+            },
             feed: FeedOutput {
                 entries_limit: config.feed_entries_limit(),
                 browser: BrowserOutput {
@@ -156,12 +210,16 @@ impl ConfigViewOutput {
                     args: config.feed_browser_args(),
                 },
             },
+            notify: NotifyOutput {
+                sinks: notify.sinks().iter().map(NotifySink::kind).collect(),
+                rules: notify.rules().len(),
+            },
             gh: GhOutput {
                 enabled: config.is_gh_enabled(),
                 pat_configured: !config.gh_pat().is_empty(),
             },
             theme: ThemeOutput {
-                name: config.palette().name(),
+                name: config.palette().name().to_owned(),
             },
         }
     }
@@ -187,6 +245,21 @@ impl ConfigViewOutput {
             "Daemon Grace: {}",
             self.daemon.session_idle_shutdown_grace
         )?;
+        writeln!(
+            writer,
+            "     Remote: {}",
+            self.remote.endpoint.as_deref().unwrap_or("not set")
+        )?;
+        writeln!(
+            writer,
+            " Crawl Host: {} jobs, spacing {}",
+            self.crawl.host_max_running_jobs,
+            self.crawl
+                .host_request_spacing
+                .as_deref()
+                .unwrap_or("not set")
+        )?;
+        writeln!(writer, "  Retention: {}", self.retention.summary())?;
         writeln!(writer, " Feed Limit: {}", self.feed.entries_limit)?;
         writeln!(
             writer,
@@ -196,6 +269,19 @@ impl ConfigViewOutput {
         if !self.feed.browser.args.is_empty() {
             writeln!(writer, "Browser Arg: {}", self.feed.browser.args.join(" "))?;
         }
+        writeln!(
+            writer,
+            "     Notify: {}",
+            if self.notify.sinks.is_empty() {
+                "disabled".to_owned()
+            } else {
+                format!(
+                    "{}, {} rules",
+                    self.notify.sinks.join(", "),
+                    self.notify.rules
+                )
+            }
+        )?;
         writeln!(writer, "      Theme: {}", self.theme.name)?;
         writeln!(
             writer,
@@ -219,6 +305,25 @@ impl ConfigViewOutput {
     }
 }
 
+impl RetentionOutput {
+    fn summary(&self) -> String {
+        let mut limits = Vec::new();
+        if let Some(max_age) = &self.max_age {
+            limits.push(format!("max age {max_age}"));
+        }
+        if let Some(max_entries) = self.max_entries_per_feed {
+            limits.push(format!("{max_entries} entries per feed"));
+        }
+        if limits.is_empty() {
+            return "keep all".to_owned();
+        }
+        if self.keep_unread {
+            limits.push("unread kept".to_owned());
+        }
+        limits.join(", ")
+    }
+}
+
 fn path_or_not_set(path: Option<&Path>) -> String {
     path.map_or_else(|| "not set".to_owned(), |path| path.display().to_string())
 }
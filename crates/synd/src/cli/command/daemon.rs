@@ -1,10 +1,19 @@
-use std::{io, path::PathBuf, process::ExitCode, time::Duration};
+use std::{
+    io,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
 
+use anyhow::Context as _;
 use clap::{Args, Subcommand};
 use serde::Serialize;
+use synd_registry::CrawlWorkerHostConfig;
 use synd_runtime::{
-    Daemon, DaemonConfig, DaemonIdleShutdownStatus, DaemonSessionStatus, DaemonState, DaemonStatus,
-    RuntimeDatabase,
+    Daemon, DaemonConfig, DaemonCrawlStatus, DaemonHostBackoff, DaemonIdleShutdownStatus,
+    DaemonListener, DaemonSessionStatus, DaemonState, DaemonStatus, RemoteToken, RuntimeDatabase,
+    SystemdUnits,
 };
 use synd_support::time::humantime::HumanDuration;
 
@@ -26,6 +35,7 @@ enum DaemonSubcommand {
     Serve(DaemonServeCommand),
     Status(DaemonStatusCommand),
     Shutdown(DaemonShutdownCommand),
+    InstallUnit(DaemonInstallUnitCommand),
 }
 
 impl DaemonCommand {
@@ -34,6 +44,7 @@ impl DaemonCommand {
             DaemonSubcommand::Serve(serve) => serve.run(config).await,
             DaemonSubcommand::Status(status) => status.run(&config).await,
             DaemonSubcommand::Shutdown(shutdown) => shutdown.run(&config).await,
+            DaemonSubcommand::InstallUnit(install_unit) => install_unit.run(&config),
         }
     }
 }
@@ -50,6 +61,34 @@ struct DaemonServeCommand {
     /// Grace period before this daemon shuts down after all sessions are gone
     #[arg(long, value_parser = config::parse::flag::parse_duration_opt, env = config::env::DAEMON_SESSION_IDLE_SHUTDOWN_GRACE)]
     session_idle_shutdown_grace: Option<Duration>,
+    /// Keep serving after all sessions are gone
+    #[arg(long)]
+    persistent: bool,
+    /// Serve remote clients on this TCP address instead of the runtime socket
+    #[arg(long, value_name = "ADDR", requires_all = ["tls_cert", "tls_key", "token_file"])]
+    listen: Option<SocketAddr>,
+    /// TLS certificate PEM file for `--listen`
+    #[arg(long, requires = "listen")]
+    tls_cert: Option<PathBuf>,
+    /// TLS private key PEM file for `--listen`
+    #[arg(long, requires = "listen")]
+    tls_key: Option<PathBuf>,
+    /// File containing the bearer token remote clients must present
+    #[arg(long, requires = "listen")]
+    token_file: Option<PathBuf>,
+    /// Also serve GitHub and Google users on `--listen`, each as its own
+    /// subscriber. The token then identifies the operator
+    #[arg(long, requires = "listen")]
+    multi_user: bool,
+    /// Maximum feeds each subscriber may subscribe to
+    #[arg(long, value_name = "COUNT")]
+    max_subscriptions: Option<usize>,
+    /// Remove entries ordered longer ago than this from timelines
+    #[arg(long, value_parser = config::parse::flag::parse_duration_opt)]
+    retention_max_age: Option<Duration>,
+    /// Keep only this many of the newest entries of each feed on timelines
+    #[arg(long, value_name = "COUNT")]
+    retention_max_entries_per_feed: Option<usize>,
 }
 
 impl DaemonServeCommand {
@@ -57,7 +96,20 @@ impl DaemonServeCommand {
         let sqlite_db = self.sqlite_db.unwrap_or_else(|| config.sqlite_db());
         let mut daemon_config = DaemonConfig::new(RuntimeDatabase::sqlite(sqlite_db))
             .with_session_lease_duration(config.daemon_session_lease_duration())
-            .with_session_idle_shutdown_grace(config.daemon_session_idle_shutdown_grace());
+            .with_session_idle_shutdown_grace(config.daemon_session_idle_shutdown_grace())
+            .with_crawl_host(CrawlWorkerHostConfig {
+                max_running_jobs: config.crawl_host_max_running_jobs(),
+                min_request_spacing: config.crawl_host_request_spacing(),
+            })
+            .with_notify(config.notify());
+        let mut retention = config.timeline_retention();
+        if let Some(max_age) = self.retention_max_age {
+            retention.max_age = Some(max_age);
+        }
+        if let Some(max_entries) = self.retention_max_entries_per_feed {
+            retention.max_entries_per_feed = Some(max_entries);
+        }
+        daemon_config = daemon_config.with_timeline_retention(retention);
         if let Some(root) = config.daemon_runtime_root() {
             daemon_config = daemon_config.with_runtime_root(root);
         }
@@ -67,9 +119,25 @@ impl DaemonServeCommand {
         if let Some(grace) = self.session_idle_shutdown_grace {
             daemon_config = daemon_config.with_session_idle_shutdown_grace(grace);
         }
+        daemon_config = daemon_config.with_persistent(self.persistent);
+        if let Some(max_subscriptions) = self.max_subscriptions {
+            daemon_config = daemon_config.with_max_subscriptions(max_subscriptions);
+        }
+        if let (Some(addr), Some(tls_cert), Some(tls_key), Some(token_file)) =
+            (self.listen, self.tls_cert, self.tls_key, self.token_file)
+        {
+            let token = match RemoteToken::from_file(&token_file) {
+                Ok(token) => token,
+                Err(err) => return CommandFailure::report(err),
+            };
+            daemon_config = daemon_config.with_listener(
+                DaemonListener::new(addr, tls_cert, tls_key, token)
+                    .with_multi_user(self.multi_user),
+            );
+        }
         let daemon = Daemon::new(daemon_config);
 
-        match daemon.serve().await {
+        match Box::pin(daemon.serve()).await {
             Ok(()) => ExitCode::SUCCESS,
             Err(err) => CommandFailure::report(err),
         }
@@ -125,6 +193,73 @@ impl DaemonShutdownCommand {
     }
 }
 
+/// Write systemd user units that keep a persistent daemon socket-activated
+#[derive(Args, Debug)]
+struct DaemonInstallUnitCommand {
+    /// Directory to write the units to, defaults to the systemd user unit directory
+    #[arg(long)]
+    dir: Option<PathBuf>,
+    /// Overwrite existing unit files
+    #[arg(long)]
+    force: bool,
+}
+
+impl DaemonInstallUnitCommand {
+    fn run(self, config: &ConfigResolver) -> ExitCode {
+        match self.install(config) {
+            Ok(units) => {
+                println!(
+                    "Enable with: systemctl --user daemon-reload && systemctl --user enable --now {} {}",
+                    units.socket_name(),
+                    units.service_name(),
+                );
+                ExitCode::SUCCESS
+            }
+            Err(err) => CommandFailure::report(err),
+        }
+    }
+
+    fn install(self, config: &ConfigResolver) -> anyhow::Result<SystemdUnits> {
+        let units = FeedRuntime::new(config)?.systemd_units()?;
+        let dir = match self.dir {
+            Some(dir) => dir,
+            None => systemd_user_unit_dir()
+                .context("Failed to resolve systemd user unit directory, pass --dir")?,
+        };
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+
+        for (name, content) in [
+            (units.socket_name(), units.socket()),
+            (units.service_name(), units.service()),
+        ] {
+            let path = dir.join(name);
+            write_unit(&path, content, self.force)?;
+            println!("Wrote {}", path.display());
+        }
+
+        Ok(units)
+    }
+}
+
+fn write_unit(path: &Path, content: &str, force: bool) -> anyhow::Result<()> {
+    if !force && path.exists() {
+        anyhow::bail!(
+            "{} already exists, pass --force to overwrite",
+            path.display()
+        );
+    }
+    std::fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn systemd_user_unit_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|config| config.join("systemd").join("user"))
+}
+
 async fn inspect_daemon(config: &ConfigResolver) -> anyhow::Result<DaemonStatus> {
     FeedRuntime::new(config)?.inspect_daemon().await
 }
@@ -155,6 +290,7 @@ struct DaemonStatusOutput {
     state: &'static str,
     placement: DaemonPlacementOutput,
     sessions: Option<DaemonSessionsOutput>,
+    crawl: Option<DaemonCrawlOutput>,
 }
 
 impl DaemonStatusOutput {
@@ -199,6 +335,9 @@ impl DaemonStatusOutput {
         if let Some(sessions) = &self.sessions {
             sessions.write_human(writer)?;
         }
+        if let Some(crawl) = &self.crawl {
+            crawl.write_human(writer)?;
+        }
 
         Ok(())
     }
@@ -220,6 +359,7 @@ impl From<&DaemonStatus> for DaemonStatusOutput {
                 daemon_claim_lock: placement.daemon_claim_lock().to_path_buf(),
             },
             sessions: status.sessions().map(DaemonSessionsOutput::from),
+            crawl: status.crawl().map(DaemonCrawlOutput::from),
         }
     }
 }
@@ -303,6 +443,56 @@ impl From<&DaemonIdleShutdownStatus> for DaemonIdleShutdownOutput {
     }
 }
 
+#[derive(Debug, Serialize)]
+struct DaemonCrawlOutput {
+    host_backoffs: Vec<DaemonHostBackoffOutput>,
+}
+
+impl DaemonCrawlOutput {
+    fn write_human(&self, writer: &mut impl io::Write) -> io::Result<()> {
+        writeln!(writer, "crawl host backoffs: {}", self.host_backoffs.len())?;
+        for backoff in &self.host_backoffs {
+            writeln!(
+                writer,
+                "  {}: {} left (rate limited on {})",
+                backoff.host,
+                HumanDuration::from(backoff.remaining),
+                backoff.feed_url
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&DaemonCrawlStatus> for DaemonCrawlOutput {
+    fn from(status: &DaemonCrawlStatus) -> Self {
+        Self {
+            host_backoffs: status
+                .host_backoffs()
+                .iter()
+                .map(DaemonHostBackoffOutput::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct DaemonHostBackoffOutput {
+    host: String,
+    feed_url: String,
+    remaining: Duration,
+}
+
+impl From<&DaemonHostBackoff> for DaemonHostBackoffOutput {
+    fn from(backoff: &DaemonHostBackoff) -> Self {
+        Self {
+            host: backoff.host().to_owned(),
+            feed_url: backoff.feed_url().to_owned(),
+            remaining: backoff.remaining(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -323,6 +513,7 @@ mod tests {
                 daemon_claim_lock: PathBuf::from("/runtime/daemon.lock"),
             },
             sessions: None,
+            crawl: None,
         };
         let mut buffer = Vec::new();
 
@@ -342,4 +533,26 @@ daemon claim lock: /runtime/daemon.lock
 "
         );
     }
+
+    #[test]
+    fn writes_crawl_host_backoffs_in_human_status() {
+        let output = DaemonCrawlOutput {
+            host_backoffs: vec![DaemonHostBackoffOutput {
+                host: "example.com".to_owned(),
+                feed_url: "https://example.com/feed.xml".to_owned(),
+                remaining: Duration::from_secs(90),
+            }],
+        };
+        let mut buffer = Vec::new();
+
+        output.write_human(&mut buffer).unwrap();
+
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            format!(
+                "crawl host backoffs: 1\n  example.com: {} left (rate limited on https://example.com/feed.xml)\n",
+                HumanDuration::from(Duration::from_secs(90))
+            )
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use std::process::ExitCode;
+use std::{io, process::ExitCode};
 
 use clap::Args;
 use schemars::JsonSchema;
@@ -7,7 +7,11 @@ use synd_client::payload::PageInfo;
 use synd_term::types::ExportedFeed;
 
 use crate::{
-    cli::{command::CommandFailure, port::PortContext},
+    cli::{
+        FeedListFormat,
+        command::{CommandFailure, opml},
+        port::PortContext,
+    },
     config::ConfigResolver,
 };
 
@@ -27,6 +31,9 @@ pub struct ExportCommand {
         visible_alias = "print-json-schema",
     )]
     print_schema: bool,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = FeedListFormat::Json)]
+    format: FeedListFormat,
 }
 
 impl ExportCommand {
@@ -65,11 +72,15 @@ impl ExportCommand {
                 }
             }
 
-            let output = Export {
-                feeds: exported_feeds,
-            };
-
-            serde_json::to_writer_pretty(std::io::stdout(), &output)?;
+            match self.format {
+                FeedListFormat::Json => {
+                    let output = Export {
+                        feeds: exported_feeds,
+                    };
+                    serde_json::to_writer_pretty(io::stdout(), &output)?;
+                }
+                FeedListFormat::Opml => opml::write_feeds(io::stdout(), &exported_feeds)?,
+            }
 
             Ok(())
         }
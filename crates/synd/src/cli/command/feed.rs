@@ -1,8 +1,16 @@
-use std::process::ExitCode;
+use std::{io, process::ExitCode};
 
 use clap::{Args, Subcommand};
-use synd_client::payload::{SubscribeDisposition, SubscribeFeedInput, UnsubscribeDisposition};
+use synd_client::{
+    Client,
+    payload::{
+        CrawlHistoryEntry, CrawlPolicyInput, FeedCandidate, FeedCandidateSource, PageInfo,
+        PollingPolicyInput, RequestCrawlDisposition, SubscribeDisposition, SubscribeFeedInput,
+        UnsubscribeDisposition,
+    },
+};
 use synd_feed::types::{Category, FeedUrl, Requirement};
+use synd_term::types::parse_polling_policy;
 
 use crate::{
     cli::{command::CommandFailure, port::PortContext},
@@ -24,6 +32,8 @@ enum FeedSubcommand {
     Export(ExportCommand),
     Subscribe(SubscribeCommand),
     Unsubscribe(UnsubscribeCommand),
+    Refresh(RefreshCommand),
+    History(HistoryCommand),
 }
 
 impl FeedCommand {
@@ -33,6 +43,8 @@ impl FeedCommand {
             FeedSubcommand::Export(export) => export.run(config).await,
             FeedSubcommand::Subscribe(subscribe) => subscribe.run(config).await,
             FeedSubcommand::Unsubscribe(unsubscribe) => unsubscribe.run(config).await,
+            FeedSubcommand::Refresh(refresh) => refresh.run(config).await,
+            FeedSubcommand::History(history) => history.run(config).await,
         }
     }
 }
@@ -40,7 +52,7 @@ impl FeedCommand {
 /// Subscribe to a feed
 #[derive(Args, Debug)]
 struct SubscribeCommand {
-    /// Feed URL
+    /// Feed URL, or the URL of a website advertising its feeds
     #[arg(long)]
     url: String,
     /// Feed category
@@ -49,6 +61,16 @@ struct SubscribeCommand {
     /// Feed requirement: must, should, or may
     #[arg(long)]
     requirement: Option<String>,
+    /// Crawl policy: manual, interval:<duration> or adaptive:<min>-<max>
+    #[arg(long, value_parser = parse_polling_policy)]
+    polling: Option<PollingPolicyInput>,
+    /// Subscribe to the URL as is instead of looking for the feeds it leads to
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    no_discover: bool,
+    /// Fetch the full article of new entries from their web page, for feeds
+    /// that only publish summaries
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    full_content: bool,
 }
 
 impl SubscribeCommand {
@@ -60,10 +82,14 @@ impl SubscribeCommand {
     }
 
     async fn subscribe(self, config: ConfigResolver) -> anyhow::Result<()> {
-        let input = self.input()?;
-        let url = input.url.clone();
+        let discover = !self.no_discover;
+        let mut input = self.input()?;
         let cx = PortContext::new(&config).await?;
         let result = async {
+            if discover {
+                input.url = Self::discover(&cx.client, input.url).await?;
+            }
+            let url = input.url.clone();
             let response = cx.client.subscribe_feed(input).await?;
             match response.disposition {
                 SubscribeDisposition::Subscribed => {
@@ -83,11 +109,50 @@ impl SubscribeCommand {
         cx.finish(result).await
     }
 
+    /// Resolves the URL to the best feed it leads to. Discovery failing to
+    /// reach the URL does not block the subscription, since the crawler
+    /// retries unreachable feeds anyway.
+    async fn discover(client: &Client, url: FeedUrl) -> anyhow::Result<FeedUrl> {
+        let candidates = match client.discover_feeds(url.clone()).await {
+            Ok(candidates) => candidates,
+            Err(err) => {
+                eprintln!("Could not discover feeds from {url}, subscribing as is: {err}");
+                return Ok(url);
+            }
+        };
+        let Some((best, others)) = candidates.split_first() else {
+            anyhow::bail!("No feed found at {url}. Pass --no-discover to subscribe anyway.");
+        };
+        if best.source == FeedCandidateSource::Requested {
+            return Ok(best.url.clone());
+        }
+
+        println!("Discovered {} from {url}.", Self::describe(best));
+        for other in others {
+            println!("  also found {}", Self::describe(other));
+        }
+        Ok(best.url.clone())
+    }
+
+    fn describe(candidate: &FeedCandidate) -> String {
+        match &candidate.title {
+            Some(title) => format!(
+                "{} ({}, \"{title}\")",
+                candidate.url,
+                candidate.kind.as_str()
+            ),
+            None => format!("{} ({})", candidate.url, candidate.kind.as_str()),
+        }
+    }
+
     fn input(self) -> anyhow::Result<SubscribeFeedInput> {
         let Self {
             url,
             category,
             requirement,
+            polling,
+            no_discover: _,
+            full_content,
         } = self;
         let url = FeedUrl::parse(&url)?;
         let category: Option<Category<'static>> = category.map(Category::new).transpose()?;
@@ -100,7 +165,8 @@ impl SubscribeCommand {
             url,
             requirement,
             category,
-            crawl_policy: None,
+            crawl_policy: polling.map(|polling| CrawlPolicyInput { polling }),
+            fetch_full_content: full_content,
         })
     }
 }
@@ -142,3 +208,141 @@ impl UnsubscribeCommand {
         cx.finish(result).await
     }
 }
+
+/// Request an immediate crawl of subscribed feeds
+#[derive(Args, Debug)]
+#[command(group(clap::ArgGroup::new("target").required(true)))]
+struct RefreshCommand {
+    /// Feed URL
+    #[arg(long, group = "target")]
+    url: Option<String>,
+    /// Refresh every subscribed feed
+    #[arg(long, group = "target", action = clap::ArgAction::SetTrue)]
+    all: bool,
+}
+
+impl RefreshCommand {
+    async fn run(self, config: ConfigResolver) -> ExitCode {
+        match self.refresh(config).await {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => CommandFailure::report(err),
+        }
+    }
+
+    async fn refresh(self, config: ConfigResolver) -> anyhow::Result<()> {
+        let url = self.url.as_deref().map(FeedUrl::parse).transpose()?;
+        let cx = PortContext::new(&config).await?;
+        let result = async {
+            let urls = match url {
+                Some(url) => vec![url],
+                None => Self::subscribed_urls(&cx.client).await?,
+            };
+            for url in urls {
+                let response = cx.client.request_crawl(url).await?;
+                let url = response.url;
+                match response.disposition {
+                    RequestCrawlDisposition::Requested => {
+                        println!("{url} crawl requested.");
+                    }
+                    RequestCrawlDisposition::AlreadyPending => {
+                        println!("{url} crawl already requested.");
+                    }
+                    RequestCrawlDisposition::NotActiveTarget => {
+                        println!("{url} is not subscribed; crawl skipped.");
+                    }
+                    RequestCrawlDisposition::Other(disposition) => {
+                        println!("{url} crawl requested ({disposition}).");
+                    }
+                }
+            }
+            Ok(())
+        }
+        .await;
+
+        cx.finish(result).await
+    }
+
+    async fn subscribed_urls(client: &Client) -> anyhow::Result<Vec<FeedUrl>> {
+        let mut after = None;
+        let mut urls = Vec::new();
+
+        loop {
+            let response = client.fetch_subscription(after.take(), Some(50)).await?;
+            urls.extend(response.feeds.nodes.into_iter().map(|feed| feed.url));
+
+            match response.feeds.page_info {
+                PageInfo::Complete { .. } => break,
+                PageInfo::More { next_cursor } => after = Some(next_cursor),
+            }
+        }
+
+        Ok(urls)
+    }
+}
+
+/// Show recent crawls of a feed
+#[derive(Args, Debug)]
+struct HistoryCommand {
+    /// Feed URL
+    #[arg(long)]
+    url: String,
+    /// Number of most recent crawls to show
+    #[arg(long, default_value_t = 20)]
+    limit: i64,
+}
+
+impl HistoryCommand {
+    async fn run(self, config: ConfigResolver) -> ExitCode {
+        match self.history(config).await {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => CommandFailure::report(err),
+        }
+    }
+
+    async fn history(self, config: ConfigResolver) -> anyhow::Result<()> {
+        let url = FeedUrl::parse(&self.url)?;
+        let cx = PortContext::new(&config).await?;
+        let result = async {
+            let history = cx
+                .client
+                .crawl_history(url.clone(), Some(self.limit))
+                .await?;
+            if history.is_empty() {
+                println!("{url} has no recorded crawls.");
+            } else {
+                Self::print(io::stdout().lock(), &history)?;
+            }
+            Ok(())
+        }
+        .await;
+
+        cx.finish(result).await
+    }
+
+    fn print(mut writer: impl io::Write, history: &[CrawlHistoryEntry]) -> io::Result<()> {
+        writeln!(
+            writer,
+            "{:<20} {:<14} {:<17} {:>6} {:>8} {:>9} {:<7} ERROR",
+            "STARTED", "TRIGGER", "OUTCOME", "STATUS", "DURATION", "BYTES", "CHANGED"
+        )?;
+        for entry in history {
+            writeln!(
+                writer,
+                "{:<20} {:<14} {:<17} {:>6} {:>6}ms {:>9} {:<7} {}",
+                entry.started_at.format("%Y-%m-%dT%H:%M:%SZ"),
+                entry.trigger.as_str(),
+                entry.outcome.as_str(),
+                entry
+                    .http_status
+                    .map_or_else(|| "-".to_owned(), |status| status.to_string()),
+                entry.duration_ms,
+                entry
+                    .body_bytes
+                    .map_or_else(|| "-".to_owned(), |bytes| bytes.to_string()),
+                if entry.body_changed { "yes" } else { "no" },
+                entry.error_kind.as_deref().unwrap_or("-"),
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use std::{
-    io,
+    io::{self, Read as _},
     path::{Path, PathBuf},
     process::ExitCode,
     time::Duration,
@@ -16,7 +16,11 @@ use synd_client::{
 use synd_term::{types::ExportedFeed, ui};
 
 use crate::{
-    cli::{command::CommandFailure, port::PortContext},
+    cli::{
+        FeedListFormat,
+        command::{CommandFailure, opml},
+        port::PortContext,
+    },
     config::ConfigResolver,
 };
 
@@ -25,6 +29,20 @@ struct Input {
     feeds: Vec<ExportedFeed>,
 }
 
+impl Input {
+    fn parse(src: &[u8], format: Option<FeedListFormat>) -> anyhow::Result<Self> {
+        let format = format.unwrap_or(if opml::is_xml(src) {
+            FeedListFormat::Opml
+        } else {
+            FeedListFormat::Json
+        });
+        match format {
+            FeedListFormat::Json => serde_json::from_slice(src).map_err(anyhow::Error::from),
+            FeedListFormat::Opml => opml::read_feeds(src).map(|feeds| Input { feeds }),
+        }
+    }
+}
+
 /// Import subscribed feeds
 #[derive(Args, Debug)]
 pub struct ImportCommand {
@@ -36,6 +54,9 @@ pub struct ImportCommand {
         visible_alias = "print-json-schema",
     )]
     print_schema: bool,
+    /// Input format. Detected from the input when omitted
+    #[arg(long, value_enum)]
+    format: Option<FeedListFormat>,
     /// Path to input file, '-' means stdin.
     #[arg()]
     input: Option<PathBuf>,
@@ -63,11 +84,12 @@ impl ImportCommand {
     async fn import(self, config: ConfigResolver) -> anyhow::Result<()> {
         let Self {
             print_schema: _,
+            format,
             input,
         } = self;
 
         let input = match input {
-            Some(input) => Self::read_input(input.as_path())?,
+            Some(input) => Self::read_input(input.as_path(), format)?,
             None => {
                 anyhow::bail!("input file path required")
             }
@@ -85,14 +107,16 @@ impl ImportCommand {
         cx.finish(result).await
     }
 
-    fn read_input(path: &Path) -> anyhow::Result<Input> {
-        let src = if path == Path::new("-") {
+    fn read_input(path: &Path, format: Option<FeedListFormat>) -> anyhow::Result<Input> {
+        let mut src = if path == Path::new("-") {
             Either::Left(std::io::stdin())
         } else {
             Either::Right(std::fs::File::open(path)?)
         };
+        let mut buf = Vec::new();
+        src.read_to_end(&mut buf)?;
 
-        serde_json::from_reader(src).map_err(anyhow::Error::from)
+        Input::parse(&buf, format)
     }
 }
 
@@ -218,6 +242,7 @@ mod tests {
                     requirement: Some(Requirement::Must),
                     category: Some(cat_rust.clone()),
                     crawl_policy: None,
+                    fetch_full_content: false,
                 },
                 ExportedFeed {
                     title: Some(String::from("err unuvailable")),
@@ -225,6 +250,7 @@ mod tests {
                     requirement: Some(Requirement::Must),
                     category: Some(cat_rust.clone()),
                     crawl_policy: None,
+                    fetch_full_content: false,
                 },
                 ExportedFeed {
                     title: Some(String::from("ok2")),
@@ -232,6 +258,7 @@ mod tests {
                     requirement: Some(Requirement::Should),
                     category: Some(cat_long.clone()),
                     crawl_policy: None,
+                    fetch_full_content: false,
                 },
             ],
         };
@@ -296,4 +323,24 @@ mod tests {
             insta::assert_snapshot!("import_feeds_reports_success_and_failure",buf);
         });
     }
+
+    #[test]
+    fn input_format_is_detected() {
+        let json = br#"{"feeds":[{"url":"https://ok1.ymgyt.io/feed.xml","requirement":"Must"}]}"#;
+        let opml = br#"<opml version="2.0"><body>
+            <outline text="rust"><outline xmlUrl="https://ok1.ymgyt.io/feed.xml" synd:requirement="MUST"/></outline>
+        </body></opml>"#;
+
+        let from_json = Input::parse(json, None).unwrap();
+        let from_opml = Input::parse(opml, None).unwrap();
+
+        assert_eq!(from_json.feeds[0].url, from_opml.feeds[0].url);
+        assert_eq!(from_json.feeds[0].requirement, Some(Requirement::Must));
+        assert_eq!(from_opml.feeds[0].requirement, Some(Requirement::Must));
+        assert_eq!(
+            from_opml.feeds[0].category,
+            Some(Category::new("rust").unwrap())
+        );
+        assert!(Input::parse(opml, Some(FeedListFormat::Json)).is_err());
+    }
 }
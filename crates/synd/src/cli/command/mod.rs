@@ -1,3 +1,4 @@
+pub mod admin;
 pub mod clean;
 pub mod config;
 pub mod daemon;
@@ -7,6 +8,8 @@ pub mod feed;
 pub mod import;
 pub mod term;
 
+mod opml;
+
 use std::{io, process::ExitCode};
 
 /// User-facing failure report for CLI command boundaries.
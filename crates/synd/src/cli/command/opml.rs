@@ -0,0 +1,518 @@
+//! OPML codec for `feed import` and `feed export`.
+//!
+//! Feeds are `<outline>` elements carrying `xmlUrl`. Outlines without
+//! `xmlUrl` are folders and map to the category of the feeds they contain;
+//! when folders nest, the innermost one wins. Attributes OPML has no slot for
+//! are written under the `synd` namespace so that an exported document
+//! imports back without loss. Documents from other readers simply lack them.
+
+use std::{collections::BTreeMap, io};
+
+use anyhow::{Context as _, bail};
+use quick_xml::{
+    Reader, Writer, XmlVersion,
+    events::{BytesDecl, BytesStart, BytesText, Event},
+};
+use synd_client::payload::PollingPolicyInput;
+use synd_feed::types::{Category, FeedUrl, Requirement};
+use synd_term::types::{
+    ExportedCrawlPolicy, ExportedFeed, ExportedPollingPolicy, ExportedPollingPolicyKind,
+    parse_polling_policy,
+};
+use tracing::warn;
+
+const SYND_NAMESPACE: &str = "https://docs.syndicationd.ymgyt.io/opml";
+const REQUIREMENT_ATTR: &str = "synd:requirement";
+const POLLING_ATTR: &str = "synd:polling";
+const FULL_CONTENT_ATTR: &str = "synd:fullContent";
+const DOCUMENT_TITLE: &str = "syndicationd subscriptions";
+
+/// Returns whether `src` looks like an XML document rather than JSON.
+pub(crate) fn is_xml(src: &[u8]) -> bool {
+    let src = src.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(src);
+    src.iter()
+        .find(|b| !b.is_ascii_whitespace())
+        .is_some_and(|b| *b == b'<')
+}
+
+/// Reads every feed outline of an OPML 1.0 or 2.0 document.
+pub(crate) fn read_feeds(src: &[u8]) -> anyhow::Result<Vec<ExportedFeed>> {
+    let mut reader = Reader::from_reader(src);
+    let mut buf = Vec::new();
+    let mut root_seen = false;
+    // One frame per open non-empty outline: the category its children inherit.
+    let mut folders: Vec<Option<Category<'static>>> = Vec::new();
+    let mut feeds = Vec::new();
+
+    loop {
+        let event = reader
+            .read_event_into(&mut buf)
+            .with_context(|| format!("malformed OPML at byte {}", reader.error_position()))?;
+        match event {
+            Event::Start(element) | Event::Empty(element) if !root_seen => {
+                if element.name().as_ref() != b"opml" {
+                    bail!("not an OPML document: root element must be <opml>");
+                }
+                root_seen = true;
+            }
+            Event::Start(element) if element.name().as_ref() == b"outline" => {
+                let outline = Outline::parse(&element)?;
+                let inherited = folders.last().cloned().flatten();
+                let category = if outline.xml_url.is_some() {
+                    let category = inherited.clone();
+                    feeds.push(outline.into_feed(category)?);
+                    inherited
+                } else {
+                    outline.folder_category().or(inherited)
+                };
+                folders.push(category);
+            }
+            Event::Empty(element) if element.name().as_ref() == b"outline" => {
+                let outline = Outline::parse(&element)?;
+                if outline.xml_url.is_some() {
+                    let category = folders.last().cloned().flatten();
+                    feeds.push(outline.into_feed(category)?);
+                }
+            }
+            Event::End(element) if element.name().as_ref() == b"outline" => {
+                folders.pop();
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    if !root_seen {
+        bail!("not an OPML document: no root element");
+    }
+    Ok(feeds)
+}
+
+/// Writes `feeds` as an OPML 2.0 document, one folder per category.
+pub(crate) fn write_feeds(out: impl io::Write, feeds: &[ExportedFeed]) -> io::Result<()> {
+    let mut uncategorized = Vec::new();
+    let mut folders: BTreeMap<&str, Vec<&ExportedFeed>> = BTreeMap::new();
+    for feed in feeds {
+        match &feed.category {
+            Some(category) => folders.entry(category.as_str()).or_default().push(feed),
+            None => uncategorized.push(feed),
+        }
+    }
+
+    let mut writer = Writer::new_with_indent(out, b' ', 2);
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer
+        .create_element("opml")
+        .with_attribute(("version", "2.0"))
+        .with_attribute(("xmlns:synd", SYND_NAMESPACE))
+        .write_inner_content(|w| {
+            w.create_element("head").write_inner_content(|w| {
+                w.create_element("title")
+                    .write_text_content(BytesText::new(DOCUMENT_TITLE))?;
+                Ok(())
+            })?;
+            w.create_element("body").write_inner_content(|w| {
+                for feed in uncategorized {
+                    write_feed_outline(w, feed)?;
+                }
+                for (category, feeds) in folders {
+                    w.create_element("outline")
+                        .with_attribute(("text", category))
+                        .with_attribute(("title", category))
+                        .write_inner_content(|w| {
+                            for feed in feeds {
+                                write_feed_outline(w, feed)?;
+                            }
+                            Ok(())
+                        })?;
+                }
+                Ok(())
+            })?;
+            Ok(())
+        })?;
+    writeln!(writer.get_mut())
+}
+
+fn write_feed_outline<W: io::Write>(w: &mut Writer<W>, feed: &ExportedFeed) -> io::Result<()> {
+    let text = feed.title.as_deref().unwrap_or(feed.url.as_str());
+    let requirement = feed.requirement.map(|r| r.to_string());
+    let polling = feed.crawl_policy.as_ref().and_then(|policy| {
+        let polling = encode_polling(&policy.polling);
+        if polling.is_none() {
+            warn!(
+                url = feed.url.as_str(),
+                policy = ?policy.polling,
+                "Polling policy has no OPML representation and is not exported"
+            );
+        }
+        polling
+    });
+
+    let mut element = w
+        .create_element("outline")
+        .with_attribute(("type", "rss"))
+        .with_attribute(("text", text))
+        .with_attribute(("title", text))
+        .with_attribute(("xmlUrl", feed.url.as_str()));
+    if let Some(requirement) = requirement.as_deref() {
+        element = element.with_attribute((REQUIREMENT_ATTR, requirement));
+    }
+    if let Some(polling) = polling.as_deref() {
+        element = element.with_attribute((POLLING_ATTR, polling));
+    }
+    if feed.fetch_full_content {
+        element = element.with_attribute((FULL_CONTENT_ATTR, "true"));
+    }
+    element.write_empty()?;
+    Ok(())
+}
+
+/// Attributes of one `<outline>` element this codec understands.
+#[derive(Default)]
+struct Outline {
+    text: Option<String>,
+    title: Option<String>,
+    xml_url: Option<String>,
+    requirement: Option<String>,
+    polling: Option<String>,
+    full_content: Option<String>,
+}
+
+impl Outline {
+    fn parse(element: &BytesStart<'_>) -> anyhow::Result<Self> {
+        let mut outline = Self::default();
+        for attr in element.attributes() {
+            let attr = attr?;
+            let value = attr.normalized_value(XmlVersion::Implicit1_0)?.into_owned();
+            match attr.key.as_ref() {
+                b"text" => outline.text = Some(value),
+                b"title" => outline.title = Some(value),
+                b"xmlUrl" => outline.xml_url = Some(value),
+                key if key == REQUIREMENT_ATTR.as_bytes() => outline.requirement = Some(value),
+                key if key == POLLING_ATTR.as_bytes() => outline.polling = Some(value),
+                key if key == FULL_CONTENT_ATTR.as_bytes() => outline.full_content = Some(value),
+                _ => {}
+            }
+        }
+        Ok(outline)
+    }
+
+    /// The category named by a folder outline, if it names one at all.
+    ///
+    /// Other readers allow folder names longer than a category, so those are
+    /// truncated rather than failing the import of the folder's feeds.
+    fn folder_category(&self) -> Option<Category<'static>> {
+        let name = self.text.as_deref().or(self.title.as_deref())?.trim();
+        if name.is_empty() {
+            return None;
+        }
+        if let Ok(category) = Category::new(name.to_owned()) {
+            return Some(category);
+        }
+        let mut end = Category::MAX_LEN.min(name.len());
+        while !name.is_char_boundary(end) {
+            end -= 1;
+        }
+        match Category::new(name[..end].to_owned()) {
+            Ok(category) => {
+                warn!(
+                    folder = name,
+                    category = category.as_str(),
+                    "Truncated folder name to fit a category"
+                );
+                Some(category)
+            }
+            Err(error) => {
+                warn!(folder = name, %error, "Ignored folder that names no valid category");
+                None
+            }
+        }
+    }
+
+    fn into_feed(self, category: Option<Category<'static>>) -> anyhow::Result<ExportedFeed> {
+        let Self {
+            text,
+            title,
+            xml_url,
+            requirement,
+            polling,
+            full_content,
+        } = self;
+        let xml_url = xml_url.unwrap_or_default();
+        let url =
+            FeedUrl::parse(&xml_url).with_context(|| format!("invalid xmlUrl '{xml_url}'"))?;
+        let requirement = requirement
+            .as_deref()
+            .map(|value| {
+                value
+                    .parse::<Requirement>()
+                    .map_err(anyhow::Error::msg)
+                    .with_context(|| format!("{url}: invalid requirement '{value}'"))
+            })
+            .transpose()?;
+        let crawl_policy = polling
+            .as_deref()
+            .map(|value| {
+                decode_polling(value)
+                    .map(|polling| ExportedCrawlPolicy { polling })
+                    .with_context(|| format!("{url}: invalid polling policy '{value}'"))
+            })
+            .transpose()?;
+        let fetch_full_content = full_content
+            .as_deref()
+            .map(|value| {
+                value
+                    .parse::<bool>()
+                    .with_context(|| format!("{url}: invalid full content flag '{value}'"))
+            })
+            .transpose()?
+            .unwrap_or_default();
+        // The feed URL is the conventional text for untitled outlines and is
+        // not worth keeping as a title.
+        let title = title
+            .or(text)
+            .filter(|title| !title.is_empty() && title != url.as_str());
+
+        Ok(ExportedFeed {
+            title,
+            url,
+            requirement,
+            category,
+            crawl_policy,
+            fetch_full_content,
+        })
+    }
+}
+
+/// Encodes a polling policy as `manual`, `interval:<seconds>s` or
+/// `adaptive:<seconds>s-<seconds>s`.
+fn encode_polling(polling: &ExportedPollingPolicy) -> Option<String> {
+    match polling.kind {
+        ExportedPollingPolicyKind::Manual => Some(String::from("manual")),
+        ExportedPollingPolicyKind::Interval => polling
+            .interval_seconds
+            .map(|seconds| format!("interval:{seconds}s")),
+        ExportedPollingPolicyKind::Adaptive => {
+            match (polling.min_interval_seconds, polling.max_interval_seconds) {
+                (Some(min), Some(max)) => Some(format!("adaptive:{min}s-{max}s")),
+                _ => None,
+            }
+        }
+    }
+}
+
+fn decode_polling(value: &str) -> anyhow::Result<ExportedPollingPolicy> {
+    let policy = ExportedPollingPolicy {
+        kind: ExportedPollingPolicyKind::Manual,
+        interval_seconds: None,
+        min_interval_seconds: None,
+        max_interval_seconds: None,
+    };
+    Ok(match parse_polling_policy(value)? {
+        PollingPolicyInput::Manual => policy,
+        PollingPolicyInput::Interval { seconds } => ExportedPollingPolicy {
+            kind: ExportedPollingPolicyKind::Interval,
+            interval_seconds: Some(seconds.get()),
+            ..policy
+        },
+        PollingPolicyInput::Adaptive { min, max } => ExportedPollingPolicy {
+            kind: ExportedPollingPolicyKind::Adaptive,
+            min_interval_seconds: Some(min.get()),
+            max_interval_seconds: Some(max.get()),
+            ..policy
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed(url: &str) -> ExportedFeed {
+        ExportedFeed {
+            title: None,
+            url: FeedUrl::parse(url).unwrap(),
+            requirement: None,
+            category: None,
+            crawl_policy: None,
+            fetch_full_content: false,
+        }
+    }
+
+    #[test]
+    fn exported_document_imports_back_unchanged() {
+        let feeds = vec![
+            ExportedFeed {
+                title: Some(String::from("This Week in Rust & friends")),
+                requirement: Some(Requirement::Must),
+                category: Some(Category::new("rust").unwrap()),
+                crawl_policy: Some(ExportedCrawlPolicy {
+                    polling: ExportedPollingPolicy {
+                        kind: ExportedPollingPolicyKind::Interval,
+                        interval_seconds: Some(1800),
+                        min_interval_seconds: None,
+                        max_interval_seconds: None,
+                    },
+                }),
+                fetch_full_content: true,
+                ..feed("https://this-week-in-rust.org/atom.xml")
+            },
+            ExportedFeed {
+                requirement: Some(Requirement::May),
+                crawl_policy: Some(ExportedCrawlPolicy {
+                    polling: ExportedPollingPolicy {
+                        kind: ExportedPollingPolicyKind::Manual,
+                        interval_seconds: None,
+                        min_interval_seconds: None,
+                        max_interval_seconds: None,
+                    },
+                }),
+                ..feed("https://blog.ymgyt.io/atom.xml")
+            },
+            ExportedFeed {
+                category: Some(Category::new("rust").unwrap()),
+                crawl_policy: Some(ExportedCrawlPolicy {
+                    polling: ExportedPollingPolicy {
+                        kind: ExportedPollingPolicyKind::Adaptive,
+                        interval_seconds: None,
+                        min_interval_seconds: Some(900),
+                        max_interval_seconds: Some(43_200),
+                    },
+                }),
+                ..feed("https://blog.rust-lang.org/feed.xml")
+            },
+        ];
+
+        let mut out = Vec::new();
+        write_feeds(&mut out, &feeds).unwrap();
+        let imported = read_feeds(&out).unwrap();
+
+        // Uncategorized feeds are written ahead of the category folders.
+        assert_eq!(
+            serde_json::to_value(&imported).unwrap(),
+            serde_json::to_value([&feeds[1], &feeds[0], &feeds[2]]).unwrap(),
+        );
+    }
+
+    #[test]
+    fn non_default_polling_policies_import_back_unchanged() {
+        let polling = |kind, interval_seconds, min_interval_seconds, max_interval_seconds| {
+            Some(ExportedCrawlPolicy {
+                polling: ExportedPollingPolicy {
+                    kind,
+                    interval_seconds,
+                    min_interval_seconds,
+                    max_interval_seconds,
+                },
+            })
+        };
+        let feeds = vec![
+            ExportedFeed {
+                crawl_policy: polling(ExportedPollingPolicyKind::Interval, Some(90), None, None),
+                ..feed("https://example.com/a.xml")
+            },
+            ExportedFeed {
+                crawl_policy: polling(
+                    ExportedPollingPolicyKind::Adaptive,
+                    None,
+                    Some(61),
+                    Some(90_061),
+                ),
+                ..feed("https://example.com/b.xml")
+            },
+        ];
+
+        let mut out = Vec::new();
+        write_feeds(&mut out, &feeds).unwrap();
+        let imported = read_feeds(&out).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&imported).unwrap(),
+            serde_json::to_value(&feeds).unwrap(),
+        );
+    }
+
+    #[test]
+    fn long_folder_names_are_truncated_to_a_category() {
+        let src = br#"<?xml version="1.0"?>
+<opml version="2.0">
+  <body>
+    <outline text="Engineering blogs of companies I follow">
+      <outline text="a" type="rss" xmlUrl="https://example.com/a.xml"/>
+    </outline>
+  </body>
+</opml>"#;
+
+        let feeds = read_feeds(src).unwrap();
+
+        assert_eq!(feeds.len(), 1);
+        assert_eq!(
+            feeds[0].category.as_ref().map(Category::as_str),
+            Some("engineering blogs of companies")
+        );
+    }
+
+    #[test]
+    fn nested_outlines_map_to_innermost_category() {
+        let src = br#"<?xml version="1.0"?>
+<opml version="1.0">
+  <head><title>other reader</title></head>
+  <body>
+    <outline text="Tech">
+      <outline text="Rust">
+        <outline text="TWIR" type="rss" xmlUrl="https://this-week-in-rust.org/atom.xml"/>
+      </outline>
+      <outline text="HN" type="rss" xmlUrl="https://news.ycombinator.com/rss"/>
+    </outline>
+    <outline type="rss" text="https://blog.ymgyt.io/atom.xml" xmlUrl="https://blog.ymgyt.io/atom.xml"/>
+  </body>
+</opml>"#;
+
+        let feeds = read_feeds(src).unwrap();
+
+        let got = feeds
+            .iter()
+            .map(|f| {
+                (
+                    f.url.as_str(),
+                    f.title.as_deref(),
+                    f.category.as_ref().map(Category::as_str),
+                    f.requirement,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            got,
+            vec![
+                (
+                    "https://this-week-in-rust.org/atom.xml",
+                    Some("TWIR"),
+                    Some("rust"),
+                    None
+                ),
+                (
+                    "https://news.ycombinator.com/rss",
+                    Some("HN"),
+                    Some("tech"),
+                    None
+                ),
+                ("https://blog.ymgyt.io/atom.xml", None, None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_non_opml_documents() {
+        let err = read_feeds(br#"<rss version="2.0"></rss>"#).unwrap_err();
+        assert!(err.to_string().contains("not an OPML document"), "{err}");
+    }
+
+    #[test]
+    fn detects_xml_input() {
+        assert!(is_xml(b"\xEF\xBB\xBF  <?xml version=\"1.0\"?><opml/>"));
+        assert!(is_xml(b"\n<opml version=\"2.0\"/>"));
+        assert!(!is_xml(br#"{"feeds":[]}"#));
+        assert!(!is_xml(b""));
+    }
+}
@@ -7,7 +7,6 @@ use synd_term::{
     client::gh::GhClient,
     interact::{ProcessInteractor, TextBrowserInteractor},
     terminal::{self, Terminal},
-    ui::theme::Theme,
 };
 use tracing::{error, info, warn};
 
@@ -89,7 +88,8 @@ async fn build_app(config: ConfigResolver) -> anyhow::Result<(Application, Sessi
             ..Default::default()
         };
         let cache = Cache::new(config.cache_dir());
-        let theme = Theme::with_palette(config.palette());
+        let themes = config.themes();
+        let theme = themes.theme(config.palette());
         let interactor = {
             let text_browser = TextBrowserInteractor::new(
                 config.feed_browser_command(),
@@ -106,6 +106,7 @@ async fn build_app(config: ConfigResolver) -> anyhow::Result<(Application, Sessi
             .config(app_config)
             .cache(cache)
             .theme(theme)
+            .themes(themes)
             .interactor(interactor);
         let builder = match gh_client {
             Some(gh_client) => builder.gh_client(gh_client),
@@ -1,9 +1,6 @@
 use std::{path::PathBuf, time::Duration};
 
 use clap::{Parser, Subcommand};
-use serde::{Deserialize, Serialize};
-
-use synd_term::ui::theme;
 
 use crate::config::{self, ConfigResolver, ConfigResolverBuilder};
 
@@ -12,28 +9,6 @@ mod port;
 
 use command::term::TermCommand;
 
-#[derive(Copy, Clone, PartialEq, Eq, Debug, clap::ValueEnum, Serialize, Deserialize)]
-#[serde(rename_all(deserialize = "kebab-case"))]
-pub enum Palette {
-    Dracula,
-    Eldritch,
-    Ferra,
-    SolarizedDark,
-    Helix,
-}
-
-impl From<Palette> for theme::Palette {
-    fn from(p: Palette) -> Self {
-        match p {
-            Palette::Dracula => theme::Palette::dracula(),
-            Palette::Eldritch => theme::Palette::eldritch(),
-            Palette::Ferra => theme::Palette::ferra(),
-            Palette::SolarizedDark => theme::Palette::solarized_dark(),
-            Palette::Helix => theme::Palette::helix(),
-        }
-    }
-}
-
 #[derive(Parser, Debug)]
 #[command(version, propagate_version = true, name = "synd")]
 struct Args {
@@ -64,9 +39,9 @@ struct Args {
 #[derive(clap::Args, Debug)]
 #[command(next_help_heading = "Term options")]
 pub struct TermOptions {
-    /// Color theme
-    #[arg(value_enum, long = "theme", env = config::env::THEME, value_name = "THEME")]
-    pub palette: Option<Palette>,
+    /// Color theme, either built-in or defined by `[theme.palettes.<name>]`
+    #[arg(long = "theme", env = config::env::THEME, value_name = "THEME")]
+    pub palette: Option<String>,
     #[command(flatten)]
     pub feed: FeedOptions,
     #[command(flatten)]
@@ -149,6 +124,7 @@ pub enum Command {
     Daemon(command::daemon::DaemonCommand),
     Doctor(command::doctor::DoctorCommand),
     Feed(command::feed::FeedCommand),
+    Admin(command::admin::AdminCommand),
     Config(command::config::ConfigCommand),
 }
 
@@ -158,6 +134,14 @@ pub enum OutputFormat {
     Json,
 }
 
+/// Document format of the subscription list exchanged by `feed import` and
+/// `feed export`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, clap::ValueEnum)]
+pub enum FeedListFormat {
+    Json,
+    Opml,
+}
+
 /// Parse CLI arguments, then decide the command to run and prime the config
 /// resolver with the parsed flags.
 /// `synd` without a subcommand runs the terminal UI.
@@ -2,10 +2,12 @@ use std::{collections::HashMap, io, path::PathBuf, time::Duration};
 
 use serde::Deserialize;
 use thiserror::Error;
+use url::Url;
 
-use synd_term::{config::CategoryConfig, keymap::KeymapConfig};
-
-use crate::cli::Palette;
+use synd_term::{
+    config::{CategoryConfig, PaletteConfig, ThemeOverrides},
+    keymap::KeymapConfig,
+};
 
 #[derive(Debug, Deserialize)]
 pub struct CacheEntry {
@@ -19,7 +21,9 @@ pub struct LogEntry {
 
 #[derive(Debug, Deserialize)]
 pub struct ThemeEntry {
-    pub(super) name: Option<Palette>,
+    pub(super) name: Option<String>,
+    pub(super) palettes: Option<HashMap<String, PaletteConfig>>,
+    pub(super) overrides: Option<ThemeOverrides>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -58,11 +62,62 @@ pub struct DaemonEntry {
     pub(super) session_idle_shutdown_grace: Option<Duration>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct RemoteEntry {
+    pub(super) endpoint: Option<Url>,
+    pub(super) token_file: Option<PathBuf>,
+    pub(super) ca_cert: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CrawlEntry {
+    pub(super) host_max_running_jobs: Option<usize>,
+    #[serde(
+        default,
+        deserialize_with = "synd_support::time::humantime::de::parse_duration_opt"
+    )]
+    pub(super) host_request_spacing: Option<Duration>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RetentionEntry {
+    #[serde(
+        default,
+        deserialize_with = "synd_support::time::humantime::de::parse_duration_opt"
+    )]
+    pub(super) max_age: Option<Duration>,
+    pub(super) max_entries_per_feed: Option<usize>,
+    pub(super) keep_unread: Option<bool>,
+    pub(super) tombstone_seq_window: Option<u64>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct BackendEntry {
     pub(super) sqlite_db: Option<PathBuf>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct NotifyEntry {
+    pub(super) desktop: Option<bool>,
+    pub(super) webhook: Option<Url>,
+    pub(super) hook: Option<NotifyHookEntry>,
+    pub(super) rules: Option<Vec<NotifyRuleEntry>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NotifyHookEntry {
+    pub(super) command: PathBuf,
+    pub(super) args: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NotifyRuleEntry {
+    pub(super) subscriber: Option<String>,
+    pub(super) requirement: Option<String>,
+    pub(super) category: Option<String>,
+    pub(super) title: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct GhEntry {
     pub(super) enable: Option<bool>,
@@ -85,7 +140,11 @@ pub struct ConfigFile {
     pub(super) backend: Option<BackendEntry>,
     pub(super) api: Option<ApiEntry>,
     pub(super) daemon: Option<DaemonEntry>,
+    pub(super) remote: Option<RemoteEntry>,
+    pub(super) crawl: Option<CrawlEntry>,
+    pub(super) retention: Option<RetentionEntry>,
     pub(super) feed: Option<FeedEntry>,
+    pub(super) notify: Option<NotifyEntry>,
     #[serde(rename = "github")]
     pub(super) gh: Option<GhEntry>,
     pub(super) categories: Option<HashMap<String, CategoryConfig>>,
@@ -100,7 +159,7 @@ impl ConfigFile {
     }
 }
 
-pub static INIT_CONFIG: &str = r#"
+pub static INIT_CONFIG: &str = r##"
 [cache]
 # Cache directory
 # directory = "path/to/dir"
@@ -111,9 +170,22 @@ pub static INIT_CONFIG: &str = r#"
 
 [theme]
 # Theme name 
-# The available themes can be found by `synd --help`
+# Built-in themes are ferra, solarized_dark, helix, dracula and eldritch
 # name = "ferra"
 
+# User defined palette, selectable by name and included in theme rotation
+# Colors are hex codes("#rrggbb") or ANSI colors("light-red", "208")
+# [theme.palettes.mytheme]
+# bg = "#282a36"
+# fg = "#f8f8f2"
+# fg_inactive = "#6272a4"
+# fg_focus = "#ff79c6"
+# error = "#ff5555"
+
+# Per-element styles applied on top of every palette
+# [theme.overrides.entries]
+# selected_entry = { fg = "#50fa7b", bold = true }
+
 [api]
 # Client timeout duration 
 # timeout = "30s"
@@ -128,6 +200,38 @@ pub static INIT_CONFIG: &str = r#"
 # Grace period before the local daemon shuts down after all sessions are gone
 # session_idle_shutdown_grace = "30s"
 
+[remote]
+# Remote daemon started with `synd daemon serve --listen`
+# When set, synd connects to it instead of the local daemon
+# endpoint = "https://homeserver:5960"
+
+# File containing the token passed to `--token-file` of the remote daemon
+# token_file = "path/to/token"
+
+# CA certificate to trust the remote daemon's certificate
+# ca_cert = "path/to/ca.pem"
+
+[crawl]
+# Concurrent crawls against one host
+# host_max_running_jobs = 2
+
+# Minimum delay between the starts of two crawls against one host
+# host_request_spacing = "1s"
+
+[retention]
+# Entries ordered longer ago than this leave the timeline
+# max_age = "90days"
+
+# Entries beyond the newest this many of each feed leave the timeline
+# max_entries_per_feed = 500
+
+# Keep unread entries regardless of age and count
+# keep_unread = true
+
+# Most recent timeline changes whose removals clients can still sync
+# Clients further behind reload their timeline
+# tombstone_seq_window = 10000
+
 [backend]
 # Local SQLite database path
 # sqlite_db = "path/to/synd.db"
@@ -139,6 +243,25 @@ pub static INIT_CONFIG: &str = r#"
 # Command to browse feed
 # browser = { command = "", args = [] }
 
+[notify]
+# Show a desktop notification through the freedesktop notification service
+# desktop = true
+
+# POST each notification as a JSON body
+# webhook = "https://example.com/hook"
+
+# Run a command with each notification as JSON on stdin
+# hook = { command = "path/to/hook", args = [] }
+
+# Notify fresh entries of subscriptions matching any rule
+# A rule matches when all of its conditions hold
+# Rules match the local subscriber unless `subscriber` names another one
+# [[notify.rules]]
+# subscriber = "local"
+# requirement = "must"
+# category = "rust"
+# title = "(?i)release"
+
 [github]
 # Enable GitHub notification feature
 # enable = true
@@ -152,7 +275,7 @@ pub static INIT_CONFIG: &str = r#"
 #   { on = ["g", "g"], command = "entries.first", desc = "Go to first entry" },
 #   { on = "up", command = "no_op" },
 # ]
-"#;
+"##;
 
 #[cfg(test)]
 mod tests {
@@ -160,7 +283,7 @@ mod tests {
 
     #[test]
     fn deserialize() {
-        let src = r#"
+        let src = r##"
 [cache]
 directory = "/tmp/synd/cache"
 
@@ -168,7 +291,20 @@ directory = "/tmp/synd/cache"
 path = "/tmp/synd/synd.log"
 
 [theme]
-name = "ferra"
+name = "mytheme"
+
+[theme.palettes.mytheme]
+bg = "#282a36"
+fg = "#f8f8f2"
+fg_inactive = "#6272a4"
+fg_focus = "magenta"
+error = "9"
+
+[theme.overrides.entries]
+selected_entry = { fg = "#50fa7b", underlined = true }
+
+[theme.overrides.requirement]
+must = "red"
 
 [api]
 timeout = "30s"
@@ -178,6 +314,21 @@ runtime_root = "/tmp/synd/runtime"
 session_lease_duration = "60s"
 session_idle_shutdown_grace = "120s"
 
+[remote]
+endpoint = "https://homeserver:5960"
+token_file = "/tmp/synd/token"
+ca_cert = "/tmp/synd/ca.pem"
+
+[crawl]
+host_max_running_jobs = 1
+host_request_spacing = "2s"
+
+[retention]
+max_age = "30days"
+max_entries_per_feed = 300
+keep_unread = false
+tombstone_seq_window = 5000
+
 [backend]
 sqlite_db = "/tmp/synd/synd.db"
 
@@ -185,6 +336,19 @@ sqlite_db = "/tmp/synd/synd.db"
 entries_limit = 100
 browser = { command = "w3m", args = ["--foo", "--bar"] }
 
+[notify]
+desktop = true
+webhook = "https://example.com/hook"
+hook = { command = "/usr/local/bin/synd-hook", args = ["--quiet"] }
+
+[[notify.rules]]
+requirement = "must"
+
+[[notify.rules]]
+subscriber = "cc80eb522a51b6b3"
+category = "rust"
+title = "(?i)release"
+
 [github]
 enable = true
 pat = "ghp_xxxx"
@@ -199,7 +363,7 @@ keymap = [
   { on = ["g", "g"], command = "entries.first", desc = "Go to first entry" },
   { on = "up", command = "no_op" },
 ]
-"#;
+"##;
 
         let config = ConfigFile::new(src.as_bytes()).unwrap();
 
@@ -54,6 +54,32 @@ pub mod daemon {
     }
 }
 
+pub mod crawl {
+    use std::time::Duration;
+
+    use synd_registry::CrawlWorkerHostConfig;
+
+    pub fn default_host_max_running_jobs() -> usize {
+        CrawlWorkerHostConfig::default().max_running_jobs
+    }
+
+    pub fn default_host_request_spacing() -> Option<Duration> {
+        CrawlWorkerHostConfig::default().min_request_spacing
+    }
+}
+
+pub mod retention {
+    use synd_registry::TimelineRetentionConfig;
+
+    pub fn default_keep_unread() -> bool {
+        TimelineRetentionConfig::default().keep_unread
+    }
+
+    pub fn default_tombstone_seq_window() -> u64 {
+        TimelineRetentionConfig::default().tombstone_seq_window
+    }
+}
+
 pub mod feed {
     use std::path::PathBuf;
 
@@ -81,9 +107,7 @@ pub mod local {
 }
 
 pub(crate) mod theme {
-    use crate::cli::Palette;
-
-    pub(crate) const DEFAULT_PALETTE: Palette = Palette::Ferra;
+    pub(crate) const DEFAULT_PALETTE: &str = "ferra";
 }
 
 pub fn log_path() -> PathBuf {
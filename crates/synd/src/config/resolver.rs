@@ -4,22 +4,29 @@ use std::{
     time::Duration,
 };
 
+use synd_feed::types::{Category, Requirement};
+use synd_registry::{SubscriberId, TimelineRetentionConfig};
+use synd_runtime::{NotifyConfig, NotifyRule, NotifySink};
 use synd_support::{
     conf::Entry,
     fs::{FileSystem, fsimpl},
 };
 use thiserror::Error;
 use tracing::debug;
+use url::Url;
 
 use crate::{
-    cli::{self, ApiOptions, BackendOptions, DaemonOptions, FeedOptions, GhOptions},
+    cli::{ApiOptions, BackendOptions, DaemonOptions, FeedOptions, GhOptions},
     config::{
         self,
-        file::{ConfigFile, ConfigFileError},
+        file::{ConfigFile, ConfigFileError, NotifyRuleEntry},
     },
 };
 use synd_term::keymap::{CompiledKeymaps, KeymapError};
-use synd_term::{config::Categories, ui::theme::Palette};
+use synd_term::{
+    config::Categories,
+    ui::theme::{Palette, Themes},
+};
 
 /// `ConfigResolver` is responsible for resolving the application's configuration
 /// while taking priority into account.
@@ -39,12 +46,20 @@ pub struct ConfigResolver {
     daemon_runtime_root: Entry<Option<PathBuf>>,
     daemon_session_lease_duration: Entry<Duration>,
     daemon_session_idle_shutdown_grace: Entry<Duration>,
+    remote_endpoint: Entry<Option<Url>>,
+    remote_token_file: Entry<Option<PathBuf>>,
+    remote_ca_cert: Entry<Option<PathBuf>>,
+    crawl_host_max_running_jobs: Entry<usize>,
+    crawl_host_request_spacing: Entry<Option<Duration>>,
+    retention: RetentionConfigEntries,
     feed_entries_limit: Entry<usize>,
     feed_browser_command: Entry<PathBuf>,
     feed_browser_args: Entry<Vec<String>>,
     gh_enabled: Entry<bool>,
     gh_pat: Entry<String>,
-    palette: Entry<Palette>,
+    notify: NotifyConfig,
+    palette: Entry<String>,
+    themes: Themes,
     categories: Categories,
     keymaps: CompiledKeymaps,
 }
@@ -86,6 +101,36 @@ impl ConfigResolver {
         self.daemon_session_idle_shutdown_grace.resolve()
     }
 
+    pub fn remote_endpoint(&self) -> Option<Url> {
+        self.remote_endpoint.resolve_ref().clone()
+    }
+
+    pub fn remote_token_file(&self) -> Option<PathBuf> {
+        self.remote_token_file.resolve_ref().clone()
+    }
+
+    pub fn remote_ca_cert(&self) -> Option<PathBuf> {
+        self.remote_ca_cert.resolve_ref().clone()
+    }
+
+    pub fn crawl_host_max_running_jobs(&self) -> usize {
+        self.crawl_host_max_running_jobs.resolve()
+    }
+
+    pub fn crawl_host_request_spacing(&self) -> Option<Duration> {
+        self.crawl_host_request_spacing.resolve()
+    }
+
+    pub fn timeline_retention(&self) -> TimelineRetentionConfig {
+        TimelineRetentionConfig {
+            max_age: self.retention.max_age.resolve(),
+            max_entries_per_feed: self.retention.max_entries_per_feed.resolve(),
+            keep_unread: self.retention.keep_unread.resolve(),
+            tombstone_seq_window: self.retention.tombstone_seq_window.resolve(),
+            ..TimelineRetentionConfig::default()
+        }
+    }
+
     pub fn feed_entries_limit(&self) -> usize {
         self.feed_entries_limit.resolve()
     }
@@ -106,8 +151,19 @@ impl ConfigResolver {
         self.gh_pat.resolve_ref().clone()
     }
 
+    pub fn notify(&self) -> NotifyConfig {
+        self.notify.clone()
+    }
+
     pub fn palette(&self) -> Palette {
-        self.palette.resolve_ref().clone()
+        self.themes
+            .palette(self.palette.resolve_ref())
+            .cloned()
+            .expect("palette name should be validated")
+    }
+
+    pub fn themes(&self) -> Themes {
+        self.themes.clone()
     }
 
     pub fn categories(&self) -> Categories {
@@ -127,6 +183,20 @@ impl ConfigResolver {
                 "GitHub PAT is required for GitHub feature".into(),
             ));
         }
+        if self.remote_endpoint.resolve_ref().is_some()
+            && self.remote_token_file.resolve_ref().is_none()
+        {
+            return Err(ConfigResolverBuildError::ValidateConfigFile(
+                "remote.token_file is required for remote endpoint".into(),
+            ));
+        }
+        let palette = self.palette.resolve_ref();
+        if self.themes.palette(palette).is_none() {
+            let available = self.themes.names().collect::<Vec<_>>().join(", ");
+            return Err(ConfigResolverBuildError::ValidateConfigFile(format!(
+                "unknown theme `{palette}`, available themes are {available}"
+            )));
+        }
         Ok(self)
     }
 }
@@ -153,7 +223,7 @@ pub struct ConfigResolverBuilder<FS = fsimpl::FileSystem> {
     backend_flags: Option<BackendOptions>,
     feed_flags: Option<FeedOptions>,
     gh_flags: Option<GhOptions>,
-    palette_flag: Option<cli::Palette>,
+    palette_flag: Option<String>,
     fs: FS,
 }
 
@@ -223,7 +293,7 @@ impl ConfigResolverBuilder {
     }
 
     #[must_use]
-    pub fn palette(self, palette: Option<cli::Palette>) -> Self {
+    pub fn palette(self, palette: Option<String>) -> Self {
         Self {
             palette_flag: palette,
             ..self
@@ -270,6 +340,8 @@ impl ConfigResolverBuilder {
         };
 
         let daemon_entries = DaemonConfigEntries::from_sources(&mut config_file, daemon_flags);
+        let remote_entries = RemoteConfigEntries::from_file(&mut config_file);
+        let crawl_entries = CrawlConfigEntries::from_file(&mut config_file);
         let resolver = ConfigResolver {
             config_file: config_path,
             log_file: Entry::with_default(config::log_path())
@@ -307,6 +379,12 @@ impl ConfigResolverBuilder {
             daemon_runtime_root: daemon_entries.runtime_root,
             daemon_session_lease_duration: daemon_entries.session_lease_duration,
             daemon_session_idle_shutdown_grace: daemon_entries.session_idle_shutdown_grace,
+            remote_endpoint: remote_entries.endpoint,
+            remote_token_file: remote_entries.token_file,
+            remote_ca_cert: remote_entries.ca_cert,
+            crawl_host_max_running_jobs: crawl_entries.host_max_running_jobs,
+            crawl_host_request_spacing: crawl_entries.host_request_spacing,
+            retention: RetentionConfigEntries::from_file(&mut config_file),
 
             feed_entries_limit: Entry::with_default(config::feed::DEFAULT_ENTRIES_LIMIT)
                 .with_file(
@@ -352,15 +430,16 @@ impl ConfigResolverBuilder {
                         .and_then(|gh| gh.pat.take()),
                 )
                 .with_flag(gh_pat),
-            palette: Entry::with_default(config::theme::DEFAULT_PALETTE.into())
+            notify: notify_from_file(&mut config_file)?,
+            palette: Entry::with_default(config::theme::DEFAULT_PALETTE.to_owned())
                 .with_file(
                     config_file
                         .as_mut()
                         .and_then(|c| c.theme.as_mut())
-                        .and_then(|theme| theme.name.take())
-                        .map(Into::into),
+                        .and_then(|theme| theme.name.take()),
                 )
-                .with_flag(palette_flag.map(Into::into)),
+                .with_flag(palette_flag),
+            themes: themes_from_file(&mut config_file),
             categories,
             keymaps,
         };
@@ -400,6 +479,81 @@ impl ConfigResolverBuilder {
     }
 }
 
+/// Custom palettes and overrides from the `[theme]` table.
+fn themes_from_file(config_file: &mut Option<ConfigFile>) -> Themes {
+    let Some(theme) = config_file.as_mut().and_then(|c| c.theme.as_mut()) else {
+        return Themes::default();
+    };
+    let palettes = theme
+        .palettes
+        .take()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, palette)| Palette::from_config(name, &palette));
+    Themes::new(palettes, theme.overrides.take().unwrap_or_default())
+}
+
+/// Notification sinks and rules from the `[notify]` table.
+fn notify_from_file(
+    config_file: &mut Option<ConfigFile>,
+) -> Result<NotifyConfig, ConfigResolverBuildError> {
+    let Some(notify) = config_file.as_mut().and_then(|c| c.notify.take()) else {
+        return Ok(NotifyConfig::default());
+    };
+
+    let mut sinks = Vec::new();
+    if notify.desktop.unwrap_or(false) {
+        sinks.push(NotifySink::Desktop);
+    }
+    if let Some(url) = notify.webhook {
+        sinks.push(NotifySink::Webhook(url));
+    }
+    if let Some(hook) = notify.hook {
+        sinks.push(NotifySink::Command {
+            program: hook.command,
+            args: hook.args.unwrap_or_default(),
+        });
+    }
+    let rules = notify
+        .rules
+        .unwrap_or_default()
+        .into_iter()
+        .map(notify_rule)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(NotifyConfig::new(rules, sinks))
+}
+
+fn notify_rule(entry: NotifyRuleEntry) -> Result<NotifyRule, ConfigResolverBuildError> {
+    let invalid = |message: String| ConfigResolverBuildError::ValidateConfigFile(message);
+    let mut rule = NotifyRule::new();
+    if let Some(subscriber) = entry.subscriber {
+        if subscriber.is_empty() {
+            return Err(invalid(
+                "notify.rules subscriber must not be empty".to_owned(),
+            ));
+        }
+        rule = rule.with_subscriber(SubscriberId::new(subscriber));
+    }
+    if let Some(requirement) = entry.requirement {
+        let requirement = requirement
+            .parse::<Requirement>()
+            .map_err(|err| invalid(format!("notify.rules requirement `{requirement}`: {err}")))?;
+        rule = rule.with_requirement(requirement);
+    }
+    if let Some(category) = entry.category {
+        let category = Category::new(category.clone())
+            .map_err(|err| invalid(format!("notify.rules category `{category}`: {err}")))?;
+        rule = rule.with_category(category);
+    }
+    if let Some(title) = entry.title {
+        rule = rule
+            .with_title(&title)
+            .map_err(|err| invalid(format!("notify.rules title: {err}")))?;
+    }
+    Ok(rule)
+}
+
 #[derive(Debug)]
 struct DaemonConfigEntries {
     runtime_root: Entry<Option<PathBuf>>,
@@ -442,3 +596,94 @@ impl DaemonConfigEntries {
         }
     }
 }
+
+#[derive(Debug)]
+struct RemoteConfigEntries {
+    endpoint: Entry<Option<Url>>,
+    token_file: Entry<Option<PathBuf>>,
+    ca_cert: Entry<Option<PathBuf>>,
+}
+
+impl RemoteConfigEntries {
+    fn from_file(config_file: &mut Option<ConfigFile>) -> Self {
+        let (endpoint, token_file, ca_cert) =
+            match config_file.as_mut().and_then(|c| c.remote.as_mut()) {
+                Some(remote) => (
+                    remote.endpoint.take(),
+                    remote.token_file.take(),
+                    remote.ca_cert.take(),
+                ),
+                None => (None, None, None),
+            };
+
+        Self {
+            endpoint: Entry::with_default(None).with_file(endpoint.map(Some)),
+            token_file: Entry::with_default(None).with_file(token_file.map(Some)),
+            ca_cert: Entry::with_default(None).with_file(ca_cert.map(Some)),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CrawlConfigEntries {
+    host_max_running_jobs: Entry<usize>,
+    host_request_spacing: Entry<Option<Duration>>,
+}
+
+impl CrawlConfigEntries {
+    fn from_file(config_file: &mut Option<ConfigFile>) -> Self {
+        let (host_max_running_jobs, host_request_spacing) =
+            match config_file.as_mut().and_then(|c| c.crawl.as_mut()) {
+                Some(crawl) => (
+                    crawl.host_max_running_jobs.take(),
+                    crawl.host_request_spacing.take(),
+                ),
+                None => (None, None),
+            };
+
+        Self {
+            host_max_running_jobs: Entry::with_default(
+                config::crawl::default_host_max_running_jobs(),
+            )
+            .with_file(host_max_running_jobs),
+            host_request_spacing:
+                Entry::with_default(config::crawl::default_host_request_spacing())
+                    .with_file(host_request_spacing.map(Some)),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RetentionConfigEntries {
+    max_age: Entry<Option<Duration>>,
+    max_entries_per_feed: Entry<Option<usize>>,
+    keep_unread: Entry<bool>,
+    tombstone_seq_window: Entry<u64>,
+}
+
+impl RetentionConfigEntries {
+    fn from_file(config_file: &mut Option<ConfigFile>) -> Self {
+        let (max_age, max_entries_per_feed, keep_unread, tombstone_seq_window) =
+            match config_file.as_mut().and_then(|c| c.retention.as_mut()) {
+                Some(retention) => (
+                    retention.max_age.take(),
+                    retention.max_entries_per_feed.take(),
+                    retention.keep_unread.take(),
+                    retention.tombstone_seq_window.take(),
+                ),
+                None => (None, None, None, None),
+            };
+
+        Self {
+            max_age: Entry::with_default(None).with_file(max_age.map(Some)),
+            max_entries_per_feed: Entry::with_default(None)
+                .with_file(max_entries_per_feed.map(Some)),
+            keep_unread: Entry::with_default(config::retention::default_keep_unread())
+                .with_file(keep_unread),
+            tombstone_seq_window: Entry::with_default(
+                config::retention::default_tombstone_seq_window(),
+            )
+            .with_file(tombstone_seq_window),
+        }
+    }
+}
@@ -20,6 +20,7 @@ fn command_name(command: &cli::Command) -> &'static str {
         cli::Command::Daemon(_) => "daemon",
         cli::Command::Doctor(_) => "doctor",
         cli::Command::Feed(_) => "feed",
+        cli::Command::Admin(_) => "admin",
         cli::Command::Config(_) => "config",
     }
 }
@@ -77,6 +78,7 @@ fn init_tracing(
         cli::Command::Clean(_)
         | cli::Command::Doctor(_)
         | cli::Command::Feed(_)
+        | cli::Command::Admin(_)
         | cli::Command::Config(_) => "warn",
     };
 
@@ -176,10 +178,11 @@ async fn main() -> ExitCode {
 
     let exit_code = match command {
         cli::Command::Term(term) => term.run(config).await,
-        cli::Command::Clean(clean) => clean.run(&config, &FileSystem::new()),
+        cli::Command::Clean(clean) => clean.run(&config, &FileSystem::new()).await,
         cli::Command::Daemon(daemon) => daemon.run(config).await,
         cli::Command::Doctor(doctor) => doctor.run(config).await,
         cli::Command::Feed(feed) => feed.run(config).await,
+        cli::Command::Admin(admin) => admin.run(config).await,
         cli::Command::Config(command) => command.run(&config),
     };
 
@@ -2,7 +2,8 @@ use std::time::Duration;
 
 use anyhow::Context as _;
 use synd_runtime::{
-    DaemonStatus, Runtime, RuntimeConfig, RuntimeDatabase, Session, ShutdownResult,
+    DaemonStatus, RemoteEndpoint, RemoteToken, Runtime, RuntimeConfig, RuntimeDatabase, Session,
+    ShutdownResult, SystemdUnits,
 };
 
 use crate::config::ConfigResolver;
@@ -26,9 +27,13 @@ impl FeedRuntime {
                     config.daemon_session_idle_shutdown_grace(),
                 );
 
-            match config.daemon_runtime_root() {
+            let runtime_config = match config.daemon_runtime_root() {
                 Some(root) => runtime_config.with_runtime_root(root),
                 None => runtime_config,
+            };
+            match remote_endpoint(config)? {
+                Some(remote) => runtime_config.with_remote(remote),
+                None => runtime_config,
             }
         };
         let runtime =
@@ -61,4 +66,26 @@ impl FeedRuntime {
 
         result.context("Failed to shutdown runtime daemon")
     }
+
+    pub(crate) fn systemd_units(&self) -> anyhow::Result<SystemdUnits> {
+        self.runtime
+            .daemon()
+            .systemd_units()
+            .context("Failed to render systemd units")
+    }
+}
+
+fn remote_endpoint(config: &ConfigResolver) -> anyhow::Result<Option<RemoteEndpoint>> {
+    let (Some(endpoint), Some(token_file)) = (config.remote_endpoint(), config.remote_token_file())
+    else {
+        return Ok(None);
+    };
+    let token = RemoteToken::from_file(&token_file)
+        .with_context(|| format!("Failed to read remote token file {}", token_file.display()))?;
+    let remote = RemoteEndpoint::new(endpoint, token);
+
+    Ok(Some(match config.remote_ca_cert() {
+        Some(ca_cert) => remote.with_root_certificate(ca_cert),
+        None => remote,
+    }))
 }
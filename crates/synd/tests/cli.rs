@@ -74,3 +74,41 @@ fn clean_command() {
         .assert()
         .success();
 }
+
+#[test]
+fn feed_history_requires_url() {
+    let dir = temp_dir().keep();
+    let sqlite_db = dir.join("synd.db").display().to_string();
+
+    assert_cmd::Command::cargo_bin("synd")
+        .unwrap()
+        .args(["--sqlite-db", &sqlite_db, "feed", "history"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn feed_refresh_requires_target() {
+    let dir = temp_dir().keep();
+    let sqlite_db = dir.join("synd.db").display().to_string();
+
+    assert_cmd::Command::cargo_bin("synd")
+        .unwrap()
+        .args(["--sqlite-db", &sqlite_db, "feed", "refresh"])
+        .assert()
+        .failure();
+
+    assert_cmd::Command::cargo_bin("synd")
+        .unwrap()
+        .args([
+            "--sqlite-db",
+            &sqlite_db,
+            "feed",
+            "refresh",
+            "--url",
+            "https://example.com/feed.xml",
+            "--all",
+        ])
+        .assert()
+        .failure();
+}
@@ -276,6 +276,7 @@ async fn seed_subscription(
         requirement: fixture.requirement,
         category: fixture.category,
         crawl_policy: fixture.crawl_policy,
+        fetch_full_content: false,
     };
     tx.upsert_subscription(&subscription, attrs, fixture.subscribed_at)
         .await?;
@@ -9,6 +9,7 @@ mod subscription;
 pub(crate) use subscription::RegistrySubscription;
 
 use crate::{dependency::LiveFeedRegistry, principal::Principal};
+use synd_feed::types::FeedUrl;
 use synd_registry::SubscriberId;
 
 pub(crate) mod object;
@@ -102,6 +103,34 @@ pub(crate) fn principal(cx: &async_graphql::Context<'_>) -> Principal {
     cx.data_unchecked::<Principal>().clone()
 }
 
+/// Rejects callers that do not operate the daemon.
+pub(crate) fn require_admin(cx: &async_graphql::Context<'_>) -> async_graphql::Result<()> {
+    if principal(cx).is_admin() {
+        Ok(())
+    } else {
+        Err(async_graphql::Error::new(
+            "admin operations are limited to the daemon operator",
+        ))
+    }
+}
+
+/// Rejects callers that neither subscribe to the feed nor operate the daemon,
+/// so a subscriber cannot tell which feeds only others follow.
+pub(crate) async fn require_subscribed(
+    cx: &async_graphql::Context<'_>,
+    feed_url: &FeedUrl,
+) -> async_graphql::Result<()> {
+    if principal(cx).is_admin()
+        || registry(cx)
+            .has_subscription(&subscriber_id(cx), feed_url)
+            .await?
+    {
+        Ok(())
+    } else {
+        Err(async_graphql::Error::new("feed is not subscribed"))
+    }
+}
+
 pub(crate) fn registry<'a>(cx: &'a async_graphql::Context<'_>) -> &'a LiveFeedRegistry {
     cx.data_unchecked::<LiveFeedRegistry>()
 }
@@ -110,3 +139,167 @@ pub(crate) fn subscriber_id(cx: &async_graphql::Context<'_>) -> SubscriberId {
     let principal = principal(cx);
     SubscriberId::new(principal.principal_id())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use async_graphql::{Request, Response};
+    use futures_util::{Stream, StreamExt as _};
+    use synd_persistence::sqlite::{SqliteDatabase, SqliteFeedRegistryDb};
+    use synd_registry::{
+        FeedRegistry, FeedRegistryConfig, FeedRegistryWorkerConfig, SaveEntryCommand,
+        event::WorkerSet,
+        query::{TimelineEntriesFilter, TimelineEntriesQuery},
+    };
+    use tokio_util::sync::CancellationToken;
+
+    use super::*;
+    use crate::principal::User;
+
+    const FEED: &str = "https://example.com/feed.xml";
+
+    async fn execute(schema: &SyndSchema, principal: &Principal, query: &str) -> Response {
+        schema
+            .execute(Request::new(query).data(principal.clone()))
+            .await
+    }
+
+    async fn start_registry(
+        dir: &tempfile::TempDir,
+        config: &FeedRegistryConfig,
+        ct: &CancellationToken,
+    ) -> (LiveFeedRegistry, WorkerSet) {
+        let db = SqliteDatabase::create_or_open(dir.path().join("synd.db"))
+            .await
+            .unwrap();
+        db.migrate().await.unwrap();
+        FeedRegistry::start(SqliteFeedRegistryDb::new(db), *config, ct.clone())
+    }
+
+    #[tokio::test]
+    async fn crawl_fields_are_limited_to_subscribers_of_the_feed() {
+        let dir = tempfile::tempdir().unwrap();
+        let ct = CancellationToken::new();
+        let (registry, _workers) = start_registry(&dir, &FeedRegistryConfig::default(), &ct).await;
+        let schema = schema_builder().data(registry).finish();
+        let subscriber = Principal::User(User::from_email("subscriber@example.com"));
+        let other = Principal::User(User::from_email("other@example.com"));
+        let operator = Principal::User(User::local());
+
+        let subscribe =
+            format!(r#"mutation {{ subscribeFeed(input: {{ url: "{FEED}" }}) {{ __typename }} }}"#);
+        assert!(execute(&schema, &subscriber, &subscribe).await.is_ok());
+
+        let history =
+            format!(r#"{{ feedRegistry {{ crawlHistory(url: "{FEED}") {{ outcome }} }} }}"#);
+        let request =
+            format!(r#"mutation {{ requestCrawl(input: {{ url: "{FEED}" }}) {{ disposition }} }}"#);
+        for query in [&history, &request] {
+            assert!(
+                execute(&schema, &subscriber, query).await.is_ok(),
+                "{query}"
+            );
+            assert!(execute(&schema, &operator, query).await.is_ok(), "{query}");
+            let response = execute(&schema, &other, query).await;
+            assert_eq!(
+                response.errors[0].message, "feed is not subscribed",
+                "{query}"
+            );
+        }
+
+        ct.cancel();
+    }
+
+    /// Mirrors the `FeedEvent` decoding of clients that predate entry-level
+    /// events: any other `__typename` fails to decode.
+    #[derive(serde::Deserialize)]
+    #[serde(tag = "__typename")]
+    enum LegacyFeedEvent {
+        TimelineChanged {
+            #[serde(rename = "changedAt")]
+            _changed_at: String,
+        },
+    }
+
+    #[derive(serde::Deserialize)]
+    struct LegacyFeedEvents {
+        #[serde(rename = "feedEvents")]
+        _feed_events: LegacyFeedEvent,
+    }
+
+    async fn next_legacy_event(events: &mut (impl Stream<Item = Response> + Unpin)) {
+        let response = tokio::time::timeout(Duration::from_secs(30), events.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        async_graphql::from_value::<LegacyFeedEvents>(response.data).unwrap();
+    }
+
+    #[tokio::test]
+    async fn feed_events_without_entry_events_announce_every_timeline_change() {
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0))
+            .await
+            .unwrap();
+        let feed_url = FeedUrl::parse(&format!(
+            "http://{}/feed/twir_atom",
+            listener.local_addr().unwrap()
+        ))
+        .unwrap();
+        synd_test::mock::spawn(listener);
+        let dir = tempfile::tempdir().unwrap();
+        let ct = CancellationToken::new();
+        let config = FeedRegistryConfig {
+            workers: FeedRegistryWorkerConfig::with_poll_interval(Duration::from_millis(50)),
+            ..FeedRegistryConfig::default()
+        };
+        let (registry, _workers) = start_registry(&dir, &config, &ct).await;
+        let schema = schema_builder().data(registry.clone()).finish();
+        let principal = Principal::User(User::local());
+        let subscriber_id = SubscriberId::new(principal.principal_id());
+
+        let mut events = Box::pin(schema.execute_stream(
+            Request::new(
+                "subscription { feedEvents { __typename ... on TimelineChanged { changedAt } } }",
+            )
+            .data(principal.clone()),
+        ));
+        // Polling the stream registers the listener before anything changes.
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), events.next())
+                .await
+                .is_err()
+        );
+
+        let subscribe = format!(
+            r#"mutation {{ subscribeFeed(input: {{ url: "{feed_url}" }}) {{ __typename }} }}"#
+        );
+        assert!(execute(&schema, &principal, &subscribe).await.is_ok());
+        next_legacy_event(&mut events).await;
+
+        // Later changes are resolved into entry-level events, which these
+        // clients must still learn about.
+        let page = registry
+            .list_timeline_entries(TimelineEntriesQuery {
+                subscriber_id: subscriber_id.clone(),
+                filter: TimelineEntriesFilter::default(),
+                after: None,
+                first: 2,
+            })
+            .await
+            .unwrap();
+        for entry in &page.nodes {
+            registry
+                .save_entry(SaveEntryCommand {
+                    subscriber_id: subscriber_id.clone(),
+                    entry_id: entry.entry.id().clone(),
+                })
+                .await
+                .unwrap();
+            next_legacy_event(&mut events).await;
+        }
+
+        ct.cancel();
+    }
+}
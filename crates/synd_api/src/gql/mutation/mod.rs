@@ -1,11 +1,17 @@
-use async_graphql::{Context, Enum, Error, InputObject, Object, SimpleObject};
-use synd_feed::types::{Category, FeedUrl, Requirement};
+use async_graphql::{Context, Enum, Error, ID, InputObject, Object, SimpleObject};
+use synd_feed::{
+    entry::EntryId,
+    types::{Category, FeedUrl, Requirement},
+};
 use synd_registry::{
-    SubscribeFeedCommand, SubscribeOutcome, UnsubscribeFeedCommand, UnsubscribeOutcome,
+    CrawlRequestReject, DeleteSubscriberCommand, FeedRegistryError, MarkEntriesReadCommand,
+    MarkEntriesUnreadCommand, RequestCrawlCommand, RequestCrawlOutcome, SaveEntryCommand,
+    SubscribeFeedCommand, SubscribeOutcome, SubscriberId, UnsaveEntryCommand,
+    UnsubscribeFeedCommand, UnsubscribeOutcome,
     crawl::policy::{CrawlPolicy, PollingInterval, PollingPolicy},
 };
 
-use crate::gql::{registry, subscriber_id};
+use crate::gql::{registry, require_admin, require_subscribed, subscriber_id};
 
 #[derive(Enum, Clone, Copy, PartialEq, Eq, Debug)]
 pub(crate) enum ResponseCode {
@@ -35,6 +41,9 @@ struct SubscribeFeedInput {
     requirement: Option<Requirement>,
     category: Option<Category<'static>>,
     crawl_policy: Option<CrawlPolicyInput>,
+    /// Fetch the full article of new entries from their web page
+    #[graphql(default)]
+    fetch_full_content: bool,
 }
 
 #[derive(InputObject)]
@@ -54,42 +63,81 @@ impl CrawlPolicyInput {
 enum PollingPolicyKindInput {
     Manual,
     Interval,
+    Adaptive,
 }
 
 #[derive(InputObject)]
 struct PollingPolicyInput {
     kind: PollingPolicyKindInput,
     interval_seconds: Option<i64>,
+    min_interval_seconds: Option<i64>,
+    max_interval_seconds: Option<i64>,
 }
 
 impl PollingPolicyInput {
     fn into_policy(self) -> async_graphql::Result<PollingPolicy> {
         match self.kind {
             PollingPolicyKindInput::Manual => {
-                if self.interval_seconds.is_some() {
+                if self.interval_seconds.is_some()
+                    || self.min_interval_seconds.is_some()
+                    || self.max_interval_seconds.is_some()
+                {
                     return Err(Error::new(
-                        "intervalSeconds must be omitted when polling policy kind is MANUAL",
+                        "interval fields must be omitted when polling policy kind is MANUAL",
                     ));
                 }
                 Ok(PollingPolicy::manual())
             }
             PollingPolicyKindInput::Interval => {
+                if self.min_interval_seconds.is_some() || self.max_interval_seconds.is_some() {
+                    return Err(Error::new(
+                        "minIntervalSeconds and maxIntervalSeconds must be omitted when polling policy kind is INTERVAL",
+                    ));
+                }
                 let seconds = self.interval_seconds.ok_or_else(|| {
                     Error::new("intervalSeconds is required when polling policy kind is INTERVAL")
                 })?;
-                let seconds = u64::try_from(seconds)
-                    .map_err(|_| Error::new("intervalSeconds must be a positive integer"))?;
-                if seconds == 0 {
-                    return Err(Error::new("intervalSeconds must be greater than zero"));
+                Ok(PollingPolicy::interval(polling_interval(
+                    "intervalSeconds",
+                    seconds,
+                )?))
+            }
+            PollingPolicyKindInput::Adaptive => {
+                if self.interval_seconds.is_some() {
+                    return Err(Error::new(
+                        "intervalSeconds must be omitted when polling policy kind is ADAPTIVE",
+                    ));
                 }
-                let interval = PollingInterval::try_from(std::time::Duration::from_secs(seconds))
-                    .map_err(|err| Error::new(err.to_string()))?;
-                Ok(PollingPolicy::interval(interval))
+                let (Some(min), Some(max)) = (self.min_interval_seconds, self.max_interval_seconds)
+                else {
+                    return Err(Error::new(
+                        "minIntervalSeconds and maxIntervalSeconds are required when polling policy kind is ADAPTIVE",
+                    ));
+                };
+                if min > max {
+                    return Err(Error::new(
+                        "minIntervalSeconds must not exceed maxIntervalSeconds",
+                    ));
+                }
+                Ok(PollingPolicy::adaptive(
+                    polling_interval("minIntervalSeconds", min)?,
+                    polling_interval("maxIntervalSeconds", max)?,
+                ))
             }
         }
     }
 }
 
+fn polling_interval(field: &str, seconds: i64) -> async_graphql::Result<PollingInterval> {
+    let seconds = u64::try_from(seconds)
+        .map_err(|_| Error::new(format!("{field} must be a positive integer")))?;
+    if seconds == 0 {
+        return Err(Error::new(format!("{field} must be greater than zero")));
+    }
+    PollingInterval::try_from(std::time::Duration::from_secs(seconds))
+        .map_err(|err| Error::new(err.to_string()))
+}
+
 #[derive(SimpleObject)]
 struct SubscribeFeedPayload {
     status: ResponseStatus,
@@ -103,6 +151,21 @@ enum SubscribeDisposition {
     Changed,
 }
 
+#[derive(InputObject)]
+struct DeleteSubscriberInput {
+    id: String,
+}
+
+#[derive(SimpleObject)]
+struct DeleteSubscriberPayload {
+    status: ResponseStatus,
+    id: String,
+    /// Feeds the subscriber was unsubscribed from
+    unsubscribed: i64,
+    /// Entries removed from the subscriber's saved entries
+    unsaved: i64,
+}
+
 #[derive(InputObject)]
 struct UnsubscribeFeedInput {
     url: FeedUrl,
@@ -120,6 +183,109 @@ enum UnsubscribeDisposition {
     Unsubscribed,
 }
 
+#[derive(InputObject)]
+struct RequestCrawlInput {
+    url: FeedUrl,
+}
+
+#[derive(SimpleObject)]
+struct RequestCrawlPayload {
+    status: ResponseStatus,
+    url: FeedUrl,
+    disposition: RequestCrawlDisposition,
+}
+
+/// How a manual crawl request was handled. A rejected request leaves the
+/// crawl target untouched.
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+enum RequestCrawlDisposition {
+    /// The crawl is dispatched on the next scheduler tick
+    Requested,
+    /// An earlier request for the feed has not been served yet
+    AlreadyPending,
+    /// The feed is not subscribed by anyone
+    NotActiveTarget,
+}
+
+impl From<RequestCrawlOutcome> for RequestCrawlDisposition {
+    fn from(outcome: RequestCrawlOutcome) -> Self {
+        match outcome {
+            RequestCrawlOutcome::Requested => Self::Requested,
+            RequestCrawlOutcome::AlreadyPending => Self::AlreadyPending,
+        }
+    }
+}
+
+impl From<&CrawlRequestReject> for RequestCrawlDisposition {
+    fn from(reject: &CrawlRequestReject) -> Self {
+        match reject {
+            CrawlRequestReject::NotActiveTarget(_) => Self::NotActiveTarget,
+        }
+    }
+}
+
+#[derive(InputObject)]
+struct MarkEntriesInput {
+    entry_ids: Vec<ID>,
+}
+
+impl MarkEntriesInput {
+    fn parse_entry_ids(self) -> async_graphql::Result<Vec<EntryId>> {
+        self.entry_ids
+            .into_iter()
+            .map(|id| EntryId::parse(id.0).map_err(|err| Error::new(err.to_string())))
+            .collect()
+    }
+}
+
+#[derive(SimpleObject)]
+struct MarkEntriesPayload {
+    status: ResponseStatus,
+    /// Entries whose read state changed. Entries already in the requested
+    /// state or not on the timeline are left out
+    entry_ids: Vec<ID>,
+}
+
+impl MarkEntriesPayload {
+    fn ok(changed: &[EntryId]) -> Self {
+        Self {
+            status: ResponseStatus::ok(),
+            entry_ids: changed
+                .iter()
+                .map(|entry_id| entry_id.as_str().into())
+                .collect(),
+        }
+    }
+}
+
+#[derive(InputObject)]
+struct SaveEntryInput {
+    entry_id: ID,
+}
+
+impl SaveEntryInput {
+    fn parse_entry_id(self) -> async_graphql::Result<EntryId> {
+        EntryId::parse(self.entry_id.0).map_err(|err| Error::new(err.to_string()))
+    }
+}
+
+#[derive(SimpleObject)]
+struct SaveEntryPayload {
+    status: ResponseStatus,
+    /// Whether the saved state changed. False when the entry was already in
+    /// the requested state or, when saving, is not on the timeline
+    changed: bool,
+}
+
+impl SaveEntryPayload {
+    fn ok(changed: bool) -> Self {
+        Self {
+            status: ResponseStatus::ok(),
+            changed,
+        }
+    }
+}
+
 pub(crate) struct Mutation;
 
 #[Object]
@@ -141,6 +307,7 @@ impl Mutation {
                 requirement: input.requirement,
                 category: input.category,
                 crawl_policy,
+                fetch_full_content: input.fetch_full_content,
             })
             .await?;
 
@@ -183,4 +350,112 @@ impl Mutation {
             disposition,
         })
     }
+
+    /// Removes every subscription and saved entry of a subscriber. Fails
+    /// unless the caller operates the daemon
+    async fn delete_subscriber(
+        &self,
+        cx: &Context<'_>,
+        input: DeleteSubscriberInput,
+    ) -> async_graphql::Result<DeleteSubscriberPayload> {
+        require_admin(cx)?;
+        let out = registry(cx)
+            .delete_subscriber(DeleteSubscriberCommand {
+                subscriber_id: SubscriberId::new(input.id.clone()),
+            })
+            .await?;
+
+        Ok(DeleteSubscriberPayload {
+            status: ResponseStatus::ok(),
+            id: input.id,
+            unsubscribed: i64::try_from(out.unsubscribed).unwrap_or(i64::MAX),
+            unsaved: i64::try_from(out.unsaved).unwrap_or(i64::MAX),
+        })
+    }
+
+    async fn request_crawl(
+        &self,
+        cx: &Context<'_>,
+        input: RequestCrawlInput,
+    ) -> async_graphql::Result<RequestCrawlPayload> {
+        require_subscribed(cx, &input.url).await?;
+        let result = registry(cx)
+            .request_crawl(RequestCrawlCommand {
+                feed_url: input.url.clone(),
+            })
+            .await;
+        let disposition = match result {
+            Ok(out) => out.outcome.into(),
+            Err(FeedRegistryError::CrawlRequestRejected(reject)) => (&reject).into(),
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(RequestCrawlPayload {
+            status: ResponseStatus::ok(),
+            url: input.url,
+            disposition,
+        })
+    }
+
+    async fn mark_entries_read(
+        &self,
+        cx: &Context<'_>,
+        input: MarkEntriesInput,
+    ) -> async_graphql::Result<MarkEntriesPayload> {
+        let out = registry(cx)
+            .mark_entries_read(MarkEntriesReadCommand {
+                subscriber_id: subscriber_id(cx),
+                entry_ids: input.parse_entry_ids()?,
+            })
+            .await?;
+
+        Ok(MarkEntriesPayload::ok(&out.changed))
+    }
+
+    async fn mark_entries_unread(
+        &self,
+        cx: &Context<'_>,
+        input: MarkEntriesInput,
+    ) -> async_graphql::Result<MarkEntriesPayload> {
+        let out = registry(cx)
+            .mark_entries_unread(MarkEntriesUnreadCommand {
+                subscriber_id: subscriber_id(cx),
+                entry_ids: input.parse_entry_ids()?,
+            })
+            .await?;
+
+        Ok(MarkEntriesPayload::ok(&out.changed))
+    }
+
+    /// Saves a timeline entry so it stays listed in `timeline.saved` after
+    /// it left the timeline
+    async fn save_entry(
+        &self,
+        cx: &Context<'_>,
+        input: SaveEntryInput,
+    ) -> async_graphql::Result<SaveEntryPayload> {
+        let out = registry(cx)
+            .save_entry(SaveEntryCommand {
+                subscriber_id: subscriber_id(cx),
+                entry_id: input.parse_entry_id()?,
+            })
+            .await?;
+
+        Ok(SaveEntryPayload::ok(out.changed))
+    }
+
+    async fn unsave_entry(
+        &self,
+        cx: &Context<'_>,
+        input: SaveEntryInput,
+    ) -> async_graphql::Result<SaveEntryPayload> {
+        let out = registry(cx)
+            .unsave_entry(UnsaveEntryCommand {
+                subscriber_id: subscriber_id(cx),
+                entry_id: input.parse_entry_id()?,
+            })
+            .await?;
+
+        Ok(SaveEntryPayload::ok(out.changed))
+    }
 }
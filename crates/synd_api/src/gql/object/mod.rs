@@ -5,7 +5,7 @@ use async_graphql::{
     connection::{Connection, ConnectionNameType, Edge, EdgeNameType, EmptyFields},
 };
 use synd_feed::{
-    entry::{Content, Entry as SyndFeedEntry},
+    entry::{self, Content, Entry as SyndFeedEntry},
     types::{self, Annotated, Category, FeedType, FeedUrl, Requirement},
 };
 use synd_registry::query::TimelineEntry;
@@ -37,9 +37,54 @@ impl From<&types::Link> for Link {
     }
 }
 
+/// Media file attached to an entry.
+#[derive(SimpleObject)]
+pub(crate) struct Enclosure {
+    pub url: String,
+    pub mime_type: Option<String>,
+    /// Size in bytes
+    pub length: Option<i64>,
+    /// Play duration in seconds
+    pub duration_seconds: Option<i64>,
+}
+
+impl From<&entry::Enclosure> for Enclosure {
+    fn from(value: &entry::Enclosure) -> Self {
+        Self {
+            url: value.url().to_owned(),
+            mime_type: value.mime_type().map(ToOwned::to_owned),
+            length: value.length().and_then(|length| i64::try_from(length).ok()),
+            duration_seconds: value
+                .duration()
+                .and_then(|duration| i64::try_from(duration.as_secs()).ok()),
+        }
+    }
+}
+
+/// Representative image of an entry.
+#[derive(SimpleObject)]
+pub(crate) struct Thumbnail {
+    pub url: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+impl From<&entry::Thumbnail> for Thumbnail {
+    fn from(value: &entry::Thumbnail) -> Self {
+        Self {
+            url: value.url().to_owned(),
+            width: value.width(),
+            height: value.height(),
+        }
+    }
+}
+
+#[expect(clippy::struct_field_names)]
 pub(crate) struct Entry {
     meta: Annotated<types::FeedMeta>,
     entry: SyndFeedEntry,
+    /// Article fetched from the entry's web page
+    article: Option<String>,
 }
 
 #[Object]
@@ -76,15 +121,51 @@ impl Entry {
             .or_else(|| self.entry.content().and_then(Content::body))
     }
 
+    /// Entry content body, usually HTML. For subscriptions fetching full
+    /// content, `timeline.entry` serves the article extracted from the web
+    /// page instead of what the feed published
+    async fn content(&self) -> Option<&str> {
+        self.article
+            .as_deref()
+            .or_else(|| self.entry.content().and_then(Content::body))
+    }
+
     /// Link to websiteurl at which this entry is published
     async fn website_url(&self) -> Option<&str> {
         self.entry.website_url(self.meta.feed.r#type())
     }
+
+    /// Categories the feed filed this entry under
+    async fn tags(&self) -> &[String] {
+        self.entry.tags()
+    }
+
+    /// Media files attached to this entry, such as podcast episodes
+    async fn enclosures(&self) -> Vec<Enclosure> {
+        self.entry
+            .enclosures()
+            .iter()
+            .map(Enclosure::from)
+            .collect()
+    }
+
+    /// Representative images of this entry
+    async fn thumbnails(&self) -> Vec<Thumbnail> {
+        self.entry
+            .thumbnails()
+            .iter()
+            .map(Thumbnail::from)
+            .collect()
+    }
 }
 
 impl Entry {
     pub fn new(meta: Annotated<types::FeedMeta>, entry: SyndFeedEntry) -> Self {
-        Self { meta, entry }
+        Self {
+            meta,
+            entry,
+            article: None,
+        }
     }
 
     fn from_feed(feed: &Annotated<Arc<types::Feed>>, entry: &SyndFeedEntry) -> Self {
@@ -94,7 +175,10 @@ impl Entry {
 
 impl From<TimelineEntry> for Entry {
     fn from(node: TimelineEntry) -> Self {
-        Self::new(node.feed_meta, node.entry)
+        Self {
+            article: node.article,
+            ..Self::new(node.feed_meta, node.entry)
+        }
     }
 }
 
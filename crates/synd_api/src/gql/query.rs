@@ -1,35 +1,56 @@
 use std::{collections::HashMap, sync::Arc};
 
 use async_graphql::{
-    Context, Enum, Object, Result, SimpleObject, Union,
-    connection::{Connection, Edge, EmptyFields},
+    Context, Enum, Error, ID, InputObject, Object, Result, SimpleObject, Union,
+    connection::{Connection, ConnectionNameType, Edge, EdgeNameType, EmptyFields},
 };
-use synd_feed::types::{Annotated, Category, Feed, FeedUrl, Requirement};
+use synd_feed::{
+    entry::EntryId,
+    feed::discovery::{
+        FeedCandidate as RegistryFeedCandidate, FeedCandidateKind as RegistryFeedCandidateKind,
+        FeedCandidateSource as RegistryFeedCandidateSource,
+    },
+    types::{Annotated, Category, Feed, FeedUrl, Requirement},
+};
+use synd_protocol::capability;
 use synd_registry::{
-    Subscription as RegistrySubscription,
-    crawl::policy::{CrawlPolicy as RegistryCrawlPolicy, PollingPolicy as RegistryPollingPolicy},
+    SubscriberId, Subscription as RegistrySubscription,
+    crawl::{
+        history::CrawlOutcome as RegistryCrawlOutcome,
+        job::CrawlJobTrigger,
+        policy::{
+            CrawlPolicy as RegistryCrawlPolicy, PollingInterval,
+            PollingPolicy as RegistryPollingPolicy,
+        },
+    },
     query::{
-        Subscriptions, SubscriptionsQuery, TimelineChange as RegistryTimelineChange,
-        TimelineChangesQuery, TimelineEntriesPage, TimelineEntriesQuery,
-        TimelineEntry as RegistryTimelineEntry, TimelineEntryCursor,
+        CrawlHistoryEntry as RegistryCrawlHistoryEntry, CrawlHistoryQuery, FeedEntriesPage,
+        FeedEntriesQuery, SavedEntriesPage, SavedEntriesQuery, SavedEntryCursor, SubscriberSummary,
+        SubscribersQuery, Subscriptions, SubscriptionsQuery,
+        TimelineChange as RegistryTimelineChange, TimelineChangesQuery, TimelineEntriesFilter,
+        TimelineEntriesPage, TimelineEntriesQuery, TimelineEntry as RegistryTimelineEntry,
+        TimelineEntryCursor, TimelineSearchPage, TimelineSearchQuery,
     },
 };
 
 use crate::gql::{
     object::{self, Entry},
-    registry, subscriber_id,
+    registry, require_admin, require_subscribed, subscriber_id,
 };
 
 #[derive(Enum, Clone, Copy, PartialEq, Eq)]
 enum PollingPolicyKind {
     Manual,
     Interval,
+    Adaptive,
 }
 
 #[derive(SimpleObject)]
 struct PollingPolicy {
     kind: PollingPolicyKind,
     interval_seconds: Option<i64>,
+    min_interval_seconds: Option<i64>,
+    max_interval_seconds: Option<i64>,
 }
 
 impl From<RegistryPollingPolicy> for PollingPolicy {
@@ -38,15 +59,29 @@ impl From<RegistryPollingPolicy> for PollingPolicy {
             RegistryPollingPolicy::Manual => Self {
                 kind: PollingPolicyKind::Manual,
                 interval_seconds: None,
+                min_interval_seconds: None,
+                max_interval_seconds: None,
             },
             RegistryPollingPolicy::Interval { interval } => Self {
                 kind: PollingPolicyKind::Interval,
-                interval_seconds: Some(i64::try_from(interval.as_secs()).unwrap_or(i64::MAX)),
+                interval_seconds: Some(seconds(interval)),
+                min_interval_seconds: None,
+                max_interval_seconds: None,
+            },
+            RegistryPollingPolicy::Adaptive { min, max } => Self {
+                kind: PollingPolicyKind::Adaptive,
+                interval_seconds: None,
+                min_interval_seconds: Some(seconds(min)),
+                max_interval_seconds: Some(seconds(max)),
             },
         }
     }
 }
 
+fn seconds(interval: PollingInterval) -> i64 {
+    i64::try_from(interval.as_secs()).unwrap_or(i64::MAX)
+}
+
 #[derive(SimpleObject)]
 struct CrawlPolicy {
     polling: PollingPolicy,
@@ -83,6 +118,11 @@ impl SubscribedFeed {
         self.subscription.crawl_policy.into()
     }
 
+    /// Whether the full article of new entries is fetched from their web page
+    async fn fetch_full_content(&self) -> bool {
+        self.subscription.fetch_full_content
+    }
+
     async fn feed(&self) -> Option<&object::Feed> {
         self.feed.as_ref()
     }
@@ -122,10 +162,15 @@ struct SubscribedFeedsPage {
 }
 
 impl SubscribedFeedsPage {
-    async fn load(cx: &Context<'_>, after: Option<String>, first: Option<i32>) -> Result<Self> {
+    async fn load(
+        cx: &Context<'_>,
+        subscriber_id: SubscriberId,
+        after: Option<String>,
+        first: Option<i32>,
+    ) -> Result<Self> {
         let page = registry(cx)
             .list_subscriptions(SubscriptionsQuery {
-                subscriber_id: subscriber_id(cx),
+                subscriber_id,
                 after,
                 first: usize::try_from(first.unwrap_or(20).clamp(0, 100)).unwrap_or(0),
             })
@@ -180,28 +225,335 @@ impl FeedRegistry {
         after: Option<String>,
         #[graphql(default = 20)] first: Option<i32>,
     ) -> Result<Connection<String, SubscribedFeed>> {
-        Ok(SubscribedFeedsPage::load(cx, after, first).await?.into())
+        Ok(
+            SubscribedFeedsPage::load(cx, subscriber_id(cx), after, first)
+                .await?
+                .into(),
+        )
     }
 
     async fn timeline(&self) -> Timeline {
         Timeline
     }
+
+    /// Entry history of one subscribed feed
+    async fn feed(&self, url: FeedUrl) -> FeedHistory {
+        FeedHistory { url }
+    }
+
+    /// Feeds the URL leads to, best candidate first. A website URL yields
+    /// the feeds it advertises, a feed URL yields itself
+    async fn discover_feeds(&self, cx: &Context<'_>, url: FeedUrl) -> Result<Vec<FeedCandidate>> {
+        let candidates = registry(cx).discover_feeds(url).await?;
+        Ok(candidates.into_iter().map(Into::into).collect())
+    }
+
+    /// Most recent crawls of the feed, newest first
+    async fn crawl_history(
+        &self,
+        cx: &Context<'_>,
+        url: FeedUrl,
+        #[graphql(default = 20)] first: Option<i32>,
+    ) -> Result<Vec<CrawlHistoryEntry>> {
+        require_subscribed(cx, &url).await?;
+        let history = registry(cx)
+            .list_crawl_history(CrawlHistoryQuery {
+                feed_url: url,
+                limit: usize::try_from(first.unwrap_or(20).clamp(0, 100)).unwrap_or(0),
+            })
+            .await?;
+        Ok(history.into_iter().map(Into::into).collect())
+    }
+}
+
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+enum FeedCandidateKind {
+    Atom,
+    Rss,
+    Json,
+}
+
+impl From<RegistryFeedCandidateKind> for FeedCandidateKind {
+    fn from(value: RegistryFeedCandidateKind) -> Self {
+        match value {
+            RegistryFeedCandidateKind::Atom => Self::Atom,
+            RegistryFeedCandidateKind::Rss => Self::Rss,
+            RegistryFeedCandidateKind::Json => Self::Json,
+        }
+    }
+}
+
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+enum FeedCandidateSource {
+    Requested,
+    LinkAlternate,
+    WellKnownPath,
+}
+
+impl From<RegistryFeedCandidateSource> for FeedCandidateSource {
+    fn from(value: RegistryFeedCandidateSource) -> Self {
+        match value {
+            RegistryFeedCandidateSource::Requested => Self::Requested,
+            RegistryFeedCandidateSource::LinkAlternate => Self::LinkAlternate,
+            RegistryFeedCandidateSource::WellKnownPath => Self::WellKnownPath,
+        }
+    }
+}
+
+/// Feed found while discovering feeds from a URL.
+#[derive(SimpleObject)]
+struct FeedCandidate {
+    url: FeedUrl,
+    kind: FeedCandidateKind,
+    title: Option<String>,
+    source: FeedCandidateSource,
+}
+
+impl From<RegistryFeedCandidate> for FeedCandidate {
+    fn from(candidate: RegistryFeedCandidate) -> Self {
+        Self {
+            url: candidate.url,
+            kind: candidate.kind.into(),
+            title: candidate.title,
+            source: candidate.source.into(),
+        }
+    }
+}
+
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+enum CrawlTrigger {
+    PeriodicDue,
+    ManualRequest,
+    RetryDue,
+}
+
+impl From<CrawlJobTrigger> for CrawlTrigger {
+    fn from(value: CrawlJobTrigger) -> Self {
+        match value {
+            CrawlJobTrigger::PeriodicDue => Self::PeriodicDue,
+            CrawlJobTrigger::ManualRequest => Self::ManualRequest,
+            CrawlJobTrigger::RetryDue => Self::RetryDue,
+        }
+    }
+}
+
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+enum CrawlOutcome {
+    Fetched,
+    NotModified,
+    UnexpectedStatus,
+    BodyReadFailed,
+    FetchFailed,
+    ParseFailed,
+}
+
+impl From<RegistryCrawlOutcome> for CrawlOutcome {
+    fn from(value: RegistryCrawlOutcome) -> Self {
+        match value {
+            RegistryCrawlOutcome::Fetched => Self::Fetched,
+            RegistryCrawlOutcome::NotModified => Self::NotModified,
+            RegistryCrawlOutcome::UnexpectedStatus => Self::UnexpectedStatus,
+            RegistryCrawlOutcome::BodyReadFailed => Self::BodyReadFailed,
+            RegistryCrawlOutcome::FetchFailed => Self::FetchFailed,
+            RegistryCrawlOutcome::ParseFailed => Self::ParseFailed,
+        }
+    }
+}
+
+/// One finished crawl of a feed.
+#[derive(SimpleObject)]
+pub(crate) struct CrawlHistoryEntry {
+    job_id: String,
+    trigger: CrawlTrigger,
+    started_at: crate::gql::scalar::Rfc3339Time,
+    finished_at: crate::gql::scalar::Rfc3339Time,
+    duration_ms: i64,
+    outcome: CrawlOutcome,
+    http_status: Option<i32>,
+    /// Classified failure such as `http_unavailable` or `fetch_timeout`
+    error_kind: Option<String>,
+    /// Length of the response body when one was read
+    body_bytes: Option<i64>,
+    /// Whether the accepted body differs from the previously accepted one
+    body_changed: bool,
+}
+
+impl From<RegistryCrawlHistoryEntry> for CrawlHistoryEntry {
+    fn from(entry: RegistryCrawlHistoryEntry) -> Self {
+        Self {
+            job_id: entry.job_id.as_str().to_owned(),
+            trigger: entry.trigger.into(),
+            started_at: entry.started_at.into(),
+            finished_at: entry.finished_at.into(),
+            duration_ms: entry.duration_ms(),
+            outcome: entry.outcome.into(),
+            http_status: entry.http_status.map(|status| i32::from(status.as_u16())),
+            error_kind: entry.error_kind.map(|kind| kind.to_string()),
+            body_bytes: entry
+                .body_bytes
+                .map(|len| i64::try_from(len).unwrap_or(i64::MAX)),
+            body_changed: entry.body_changed,
+        }
+    }
+}
+
+struct FeedHistory {
+    url: FeedUrl,
+}
+
+#[Object]
+impl FeedHistory {
+    async fn url(&self) -> &FeedUrl {
+        &self.url
+    }
+
+    /// Every entry the feed published while known to the registry, newest
+    /// first, including entries no longer on the timeline. Empty unless the
+    /// feed is subscribed
+    async fn entries(
+        &self,
+        cx: &Context<'_>,
+        after: Option<String>,
+        #[graphql(default = 20)] first: Option<i32>,
+    ) -> Result<FeedHistoryConnection> {
+        let first = usize::try_from(first.unwrap_or(20).clamp(0, 100)).unwrap_or(0);
+        let after = after
+            .as_deref()
+            .map(TimelineEntryCursor::decode)
+            .transpose()
+            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+        let page = registry(cx)
+            .list_feed_entries(FeedEntriesQuery {
+                subscriber_id: subscriber_id(cx),
+                feed_url: self.url.clone(),
+                after,
+                first,
+            })
+            .await?;
+
+        Ok(FeedHistoryGraphqlPage(page).into())
+    }
+}
+
+type FeedHistoryConnection = Connection<
+    String,
+    TimelineEntry,
+    EmptyFields,
+    EmptyFields,
+    FeedHistoryConnectionName,
+    FeedHistoryEdgeName,
+>;
+
+/// Registry feed entries page at the GraphQL connection boundary.
+struct FeedHistoryGraphqlPage(FeedEntriesPage);
+
+impl From<FeedHistoryGraphqlPage> for FeedHistoryConnection {
+    fn from(page: FeedHistoryGraphqlPage) -> Self {
+        let page = page.0;
+        let mut connection = Self::new(false, page.has_next_page);
+        connection.edges.extend(page.nodes.into_iter().map(|node| {
+            let cursor = node.cursor.encode();
+            Edge::new(cursor, TimelineEntry::from(node))
+        }));
+        connection
+    }
+}
+
+struct FeedHistoryConnectionName;
+
+impl ConnectionNameType for FeedHistoryConnectionName {
+    fn type_name<T: async_graphql::OutputType>() -> String {
+        "FeedHistoryConnection".into()
+    }
+}
+
+struct FeedHistoryEdgeName;
+
+impl EdgeNameType for FeedHistoryEdgeName {
+    fn type_name<T: async_graphql::OutputType>() -> String {
+        "FeedHistoryEdge".into()
+    }
 }
 
 struct Timeline;
 
 #[Object]
 impl Timeline {
+    /// Entries in timeline order, narrowed to the subscriptions matching
+    /// `filter` when given
     async fn entries(
         &self,
         cx: &Context<'_>,
+        filter: Option<TimelineEntriesFilterInput>,
         after: Option<String>,
         #[graphql(default = 20)] first: Option<i32>,
     ) -> Result<Connection<String, TimelineEntry, TimelineEntriesFields>> {
-        let request = TimelineEntriesRequest::from_graphql(cx, after.as_deref(), first)?;
+        let request = TimelineEntriesRequest::from_graphql(cx, filter, after.as_deref(), first)?;
         Ok(request.load(cx).await?.into())
     }
 
+    /// Entries whose title, summary or content contain every word of
+    /// `query`, in timeline order. Paged with the same cursors as `entries`.
+    async fn search(
+        &self,
+        cx: &Context<'_>,
+        query: String,
+        after: Option<String>,
+        #[graphql(default = 20)] first: Option<i32>,
+    ) -> Result<TimelineSearchConnection> {
+        let first = usize::try_from(first.unwrap_or(20).clamp(0, 100)).unwrap_or(0);
+        let after = after
+            .as_deref()
+            .map(TimelineEntryCursor::decode)
+            .transpose()
+            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+        let page = registry(cx)
+            .search_timeline(TimelineSearchQuery {
+                subscriber_id: subscriber_id(cx),
+                text: query,
+                after,
+                first,
+            })
+            .await?;
+
+        Ok(TimelineSearchGraphqlPage(page).into())
+    }
+
+    /// Entries the subscriber saved, latest save first. Saved entries stay
+    /// listed after they left the timeline.
+    async fn saved(
+        &self,
+        cx: &Context<'_>,
+        after: Option<String>,
+        #[graphql(default = 20)] first: Option<i32>,
+    ) -> Result<SavedEntryConnection> {
+        let first = usize::try_from(first.unwrap_or(20).clamp(0, 100)).unwrap_or(0);
+        let after = after
+            .as_deref()
+            .map(SavedEntryCursor::decode)
+            .transpose()
+            .map_err(|err| async_graphql::Error::new(err.to_string()))?;
+        let page = registry(cx)
+            .list_saved_entries(SavedEntriesQuery {
+                subscriber_id: subscriber_id(cx),
+                after,
+                first,
+            })
+            .await?;
+
+        Ok(SavedEntriesGraphqlPage(page).into())
+    }
+
+    /// One entry of the timeline, or null when it is not on the timeline.
+    async fn entry(&self, cx: &Context<'_>, id: ID) -> Result<Option<TimelineEntry>> {
+        let entry_id = EntryId::parse(id.0).map_err(|err| Error::new(err.to_string()))?;
+        let entry = registry(cx)
+            .load_timeline_entry(&subscriber_id(cx), &entry_id)
+            .await?;
+
+        Ok(entry.map(Into::into))
+    }
+
     async fn changes(
         &self,
         cx: &Context<'_>,
@@ -221,15 +573,20 @@ impl Timeline {
             changes: page.changes.into_iter().map(Into::into).collect(),
             seq: page.seq,
             has_more: page.has_more,
+            expired: page.expired,
         })
     }
 }
 
 /// Entry as it appears on one timeline: display position and content.
 #[derive(SimpleObject)]
-struct TimelineEntry {
+pub(crate) struct TimelineEntry {
     /// Display position on the timeline
     order_time: crate::gql::scalar::Rfc3339Time,
+    /// Whether the subscriber marked the entry as read
+    read: bool,
+    /// Whether the subscriber saved the entry
+    saved: bool,
     entry: Entry,
 }
 
@@ -238,6 +595,8 @@ impl From<RegistryTimelineEntry> for TimelineEntry {
         let order_time = node.cursor.order_time().into();
         Self {
             order_time,
+            read: node.read,
+            saved: node.saved,
             entry: node.into(),
         }
     }
@@ -271,11 +630,130 @@ impl From<TimelineEntriesGraphqlPage> for TimelineEntriesConnection {
     }
 }
 
+type TimelineSearchConnection = Connection<
+    String,
+    TimelineEntry,
+    EmptyFields,
+    EmptyFields,
+    TimelineSearchConnectionName,
+    TimelineSearchEdgeName,
+>;
+
+/// Registry search page at the GraphQL connection boundary.
+struct TimelineSearchGraphqlPage(TimelineSearchPage);
+
+impl From<TimelineSearchGraphqlPage> for TimelineSearchConnection {
+    fn from(page: TimelineSearchGraphqlPage) -> Self {
+        let page = page.0;
+        let mut connection = Self::new(false, page.has_next_page);
+        connection.edges.extend(page.nodes.into_iter().map(|node| {
+            let cursor = node.cursor.encode();
+            Edge::new(cursor, TimelineEntry::from(node))
+        }));
+        connection
+    }
+}
+
+struct TimelineSearchConnectionName;
+
+impl ConnectionNameType for TimelineSearchConnectionName {
+    fn type_name<T: async_graphql::OutputType>() -> String {
+        "TimelineSearchConnection".into()
+    }
+}
+
+struct TimelineSearchEdgeName;
+
+impl EdgeNameType for TimelineSearchEdgeName {
+    fn type_name<T: async_graphql::OutputType>() -> String {
+        "TimelineSearchEdge".into()
+    }
+}
+
+/// Edge-level fields of one saved entry.
+#[derive(SimpleObject)]
+struct SavedEntryEdgeFields {
+    saved_at: crate::gql::scalar::Rfc3339Time,
+}
+
+type SavedEntryConnection = Connection<
+    String,
+    TimelineEntry,
+    EmptyFields,
+    SavedEntryEdgeFields,
+    SavedEntryConnectionName,
+    SavedEntryEdgeName,
+>;
+
+/// Registry saved entries page at the GraphQL connection boundary.
+struct SavedEntriesGraphqlPage(SavedEntriesPage);
+
+impl From<SavedEntriesGraphqlPage> for SavedEntryConnection {
+    fn from(page: SavedEntriesGraphqlPage) -> Self {
+        let page = page.0;
+        let mut connection = Self::new(false, page.has_next_page);
+        connection.edges.extend(page.nodes.into_iter().map(|saved| {
+            let cursor = saved.cursor().encode();
+            Edge::with_additional_fields(
+                cursor,
+                TimelineEntry::from(saved.node),
+                SavedEntryEdgeFields {
+                    saved_at: saved.saved_at.into(),
+                },
+            )
+        }));
+        connection
+    }
+}
+
+struct SavedEntryConnectionName;
+
+impl ConnectionNameType for SavedEntryConnectionName {
+    fn type_name<T: async_graphql::OutputType>() -> String {
+        "SavedEntryConnection".into()
+    }
+}
+
+struct SavedEntryEdgeName;
+
+impl EdgeNameType for SavedEntryEdgeName {
+    fn type_name<T: async_graphql::OutputType>() -> String {
+        "SavedEntryEdge".into()
+    }
+}
+
+/// Conditions on the subscription each timeline entry came from. Omitted
+/// conditions match every entry
+#[derive(InputObject, Default)]
+struct TimelineEntriesFilterInput {
+    /// Requirements to include. `null` includes subscriptions without one
+    requirements: Option<Vec<Option<Requirement>>>,
+    /// Categories to include. `null` includes subscriptions without one
+    categories: Option<Vec<Option<Category<'static>>>>,
+    /// Feeds to include
+    feed_urls: Option<Vec<FeedUrl>>,
+}
+
+impl From<TimelineEntriesFilterInput> for TimelineEntriesFilter {
+    fn from(input: TimelineEntriesFilterInput) -> Self {
+        Self {
+            requirements: input.requirements,
+            categories: input.categories,
+            feed_urls: input.feed_urls,
+        }
+    }
+}
+
 /// Validated GraphQL arguments for one timeline entries query.
 struct TimelineEntriesRequest(TimelineEntriesQuery);
 
 impl TimelineEntriesRequest {
-    fn from_graphql(cx: &Context<'_>, after: Option<&str>, first: Option<i32>) -> Result<Self> {
+    fn from_graphql(
+        cx: &Context<'_>,
+        filter: Option<TimelineEntriesFilterInput>,
+        after: Option<&str>,
+        first: Option<i32>,
+    ) -> Result<Self> {
         let first = usize::try_from(first.unwrap_or(20).clamp(0, 100)).unwrap_or(0);
         let after = after
             .map(TimelineEntryCursor::decode)
@@ -283,6 +761,7 @@ impl TimelineEntriesRequest {
             .map_err(|err| async_graphql::Error::new(err.to_string()))?;
         Ok(Self(TimelineEntriesQuery {
             subscriber_id: subscriber_id(cx),
+            filter: filter.unwrap_or_default().into(),
             after,
             first,
         }))
@@ -302,6 +781,9 @@ struct TimelineChanges {
     /// Seq the client remembers after applying this page
     seq: i64,
     has_more: bool,
+    /// `since` is older than the removals the server still keeps.
+    /// Clients reload the timeline instead of applying changes
+    expired: bool,
 }
 
 #[derive(Union)]
@@ -333,6 +815,71 @@ impl From<RegistryTimelineChange> for TimelineChange {
     }
 }
 
+/// Subscriber management, limited to the daemon operator.
+struct Admin;
+
+#[Object]
+impl Admin {
+    /// Subscribers holding at least one subscription, ordered by id
+    async fn subscribers(
+        &self,
+        cx: &Context<'_>,
+        after: Option<String>,
+        #[graphql(default = 20)] first: Option<i32>,
+    ) -> Result<Connection<String, Subscriber>> {
+        let page = registry(cx)
+            .list_subscribers(SubscribersQuery {
+                after: after.map(SubscriberId::new),
+                first: usize::try_from(first.unwrap_or(20).clamp(0, 100)).unwrap_or(0),
+            })
+            .await?;
+        let mut connection = Connection::new(false, page.has_next_page);
+        connection.edges.extend(
+            page.subscribers
+                .into_iter()
+                .map(|summary| Edge::new(summary.subscriber_id.to_string(), Subscriber(summary))),
+        );
+        Ok(connection)
+    }
+
+    async fn subscriber(&self, cx: &Context<'_>, id: String) -> Result<Option<Subscriber>> {
+        Ok(registry(cx)
+            .load_subscriber(&SubscriberId::new(id))
+            .await?
+            .map(Subscriber))
+    }
+}
+
+struct Subscriber(SubscriberSummary);
+
+#[Object]
+impl Subscriber {
+    async fn id(&self) -> &str {
+        self.0.subscriber_id.as_str()
+    }
+
+    async fn subscription_count(&self) -> i64 {
+        i64::try_from(self.0.subscriptions).unwrap_or(i64::MAX)
+    }
+
+    async fn first_subscribed_at(&self) -> crate::gql::scalar::Rfc3339Time {
+        self.0.first_subscribed_at.into()
+    }
+
+    async fn subscriptions(
+        &self,
+        cx: &Context<'_>,
+        after: Option<String>,
+        #[graphql(default = 20)] first: Option<i32>,
+    ) -> Result<Connection<String, SubscribedFeed>> {
+        Ok(
+            SubscribedFeedsPage::load(cx, self.0.subscriber_id.clone(), after, first)
+                .await?
+                .into(),
+        )
+    }
+}
+
 pub(crate) struct Query;
 
 #[Object]
@@ -340,4 +887,16 @@ impl Query {
     async fn feed_registry(&self) -> FeedRegistry {
         FeedRegistry
     }
+
+    /// Subscriber management. Fails unless the caller operates the daemon
+    #[allow(clippy::unused_async)]
+    async fn admin(&self, cx: &Context<'_>) -> Result<Admin> {
+        require_admin(cx)?;
+        Ok(Admin)
+    }
+
+    /// Capabilities this daemon serves, so remote clients need not assume them
+    async fn capabilities(&self) -> Vec<String> {
+        capability::local_api_capabilities().names().to_vec()
+    }
 }
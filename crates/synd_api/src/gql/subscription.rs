@@ -1,17 +1,28 @@
-use async_graphql::{Context, Result, SimpleObject, Subscription, Union};
+use async_graphql::{Context, Enum, Result, SimpleObject, Subscription, Union};
 use futures_util::{Stream, stream};
 use synd_feed::types::FeedUrl;
-use synd_registry::api::{ApiEvent, ApiEventRecvError, ApiTimelineChanged};
+use synd_registry::api::{
+    ApiCrawlFailed, ApiEntryAdded, ApiEntryRemoved, ApiEvent, ApiEventRecvError,
+    ApiSubscriptionChange, ApiSubscriptionChanged, ApiTimelineChanged,
+};
 
-use crate::gql::{registry, scalar, subscriber_id};
+use crate::gql::{
+    query::{CrawlHistoryEntry, TimelineEntry},
+    registry, scalar, subscriber_id,
+};
 
 pub(crate) struct RegistrySubscription;
 
 #[derive(Union)]
 enum FeedEvent {
     TimelineChanged(TimelineChanged),
+    EntryAdded(EntryAdded),
+    EntryRemoved(EntryRemoved),
+    SubscriptionChanged(SubscriptionChanged),
+    CrawlFailed(CrawlFailed),
 }
 
+/// The timeline changed in a way only `timeline.changes` can tell
 #[derive(SimpleObject)]
 struct TimelineChanged {
     changed_at: scalar::Rfc3339Time,
@@ -28,30 +39,150 @@ impl From<ApiTimelineChanged> for TimelineChanged {
     }
 }
 
+/// An entry was added to the timeline or its state changed. Clients synced
+/// up to any seq from `since` to `seq` upsert it and are then synced up to
+/// `seq`. Clients behind `since` catch up through `timeline.changes` instead
+#[derive(SimpleObject)]
+struct EntryAdded {
+    timeline_entry: Box<TimelineEntry>,
+    /// Position of the entry on the timeline
+    cursor: String,
+    since: i64,
+    seq: i64,
+}
+
+impl From<ApiEntryAdded> for EntryAdded {
+    fn from(value: ApiEntryAdded) -> Self {
+        let cursor = value.entry.cursor.encode();
+        Self {
+            timeline_entry: Box::new(TimelineEntry::from(*value.entry)),
+            cursor,
+            since: value.seqs.since,
+            seq: value.seqs.seq,
+        }
+    }
+}
+
+/// An entry left the timeline. Applied like `EntryAdded`
+#[derive(SimpleObject)]
+struct EntryRemoved {
+    entry_id: String,
+    since: i64,
+    seq: i64,
+}
+
+impl From<ApiEntryRemoved> for EntryRemoved {
+    fn from(value: ApiEntryRemoved) -> Self {
+        Self {
+            entry_id: value.entry_id.as_str().to_owned(),
+            since: value.seqs.since,
+            seq: value.seqs.seq,
+        }
+    }
+}
+
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+enum SubscriptionChange {
+    Subscribed,
+    Changed,
+    Unsubscribed,
+}
+
+impl From<ApiSubscriptionChange> for SubscriptionChange {
+    fn from(value: ApiSubscriptionChange) -> Self {
+        match value {
+            ApiSubscriptionChange::Subscribed => Self::Subscribed,
+            ApiSubscriptionChange::Changed => Self::Changed,
+            ApiSubscriptionChange::Unsubscribed => Self::Unsubscribed,
+        }
+    }
+}
+
+/// A subscription was created, updated or ended
+#[derive(SimpleObject)]
+struct SubscriptionChanged {
+    feed_url: FeedUrl,
+    change: SubscriptionChange,
+    changed_at: scalar::Rfc3339Time,
+}
+
+impl From<ApiSubscriptionChanged> for SubscriptionChanged {
+    fn from(value: ApiSubscriptionChanged) -> Self {
+        Self {
+            feed_url: value.feed_url,
+            change: value.change.into(),
+            changed_at: value.changed_at.into(),
+        }
+    }
+}
+
+/// A crawl of a subscribed feed failed
+#[derive(SimpleObject)]
+struct CrawlFailed {
+    feed_url: FeedUrl,
+    crawl: CrawlHistoryEntry,
+}
+
+impl From<ApiCrawlFailed> for CrawlFailed {
+    fn from(value: ApiCrawlFailed) -> Self {
+        Self {
+            feed_url: value.feed_url,
+            crawl: value.crawl.into(),
+        }
+    }
+}
+
 #[Subscription]
 impl RegistrySubscription {
     // async-graphql requires subscription stream resolvers to be async.
     #[allow(clippy::unused_async)]
-    async fn feed_events(&self, cx: &Context<'_>) -> Result<impl Stream<Item = Result<FeedEvent>>> {
+    /// Without `entryEvents`, only `TimelineChanged` is sent, once for every
+    /// change, as clients that predate the other events expect. With it,
+    /// `TimelineChanged` is only sent for changes the entry-level events do
+    /// not cover
+    async fn feed_events(
+        &self,
+        cx: &Context<'_>,
+        #[graphql(default = false)] entry_events: bool,
+    ) -> Result<impl Stream<Item = Result<FeedEvent>>> {
         let subscriber = registry(cx).subscribe_events(subscriber_id(cx));
 
-        Ok(stream::unfold(subscriber, |mut subscriber| async move {
-            match subscriber.recv().await {
-                Ok(event) => Some((Ok(feed_event_from_api_event(event)), subscriber)),
-                Err(ApiEventRecvError::Lagged(skipped)) => Some((
-                    Err(async_graphql::Error::new(format!(
-                        "feed event stream lagged by {skipped} messages"
-                    ))),
-                    subscriber,
-                )),
-                Err(ApiEventRecvError::Closed) => None,
-            }
-        }))
+        Ok(stream::unfold(
+            subscriber,
+            move |mut subscriber| async move {
+                loop {
+                    match subscriber.recv().await {
+                        Ok(event) => {
+                            if let Some(event) = feed_event_from_api_event(event, entry_events) {
+                                return Some((Ok(event), subscriber));
+                            }
+                        }
+                        Err(ApiEventRecvError::Lagged(skipped)) => {
+                            return Some((
+                                Err(async_graphql::Error::new(format!(
+                                    "feed event stream lagged by {skipped} messages"
+                                ))),
+                                subscriber,
+                            ));
+                        }
+                        Err(ApiEventRecvError::Closed) => return None,
+                    }
+                }
+            },
+        ))
     }
 }
 
-fn feed_event_from_api_event(event: ApiEvent) -> FeedEvent {
+fn feed_event_from_api_event(event: ApiEvent, entry_events: bool) -> Option<FeedEvent> {
     match event {
-        ApiEvent::TimelineChanged(event) => FeedEvent::TimelineChanged(event.into()),
+        ApiEvent::TimelineChanged(event) if !(entry_events && event.resolved) => {
+            Some(FeedEvent::TimelineChanged(event.into()))
+        }
+        _ if !entry_events => None,
+        ApiEvent::TimelineChanged(_) => None,
+        ApiEvent::EntryAdded(event) => Some(FeedEvent::EntryAdded(event.into())),
+        ApiEvent::EntryRemoved(event) => Some(FeedEvent::EntryRemoved(event.into())),
+        ApiEvent::SubscriptionChanged(event) => Some(FeedEvent::SubscriptionChanged(event.into())),
+        ApiEvent::CrawlFailed(event) => Some(FeedEvent::CrawlFailed(event.into())),
     }
 }
@@ -14,3 +14,4 @@ pub mod session;
 pub mod shutdown;
 
 pub use error::{Error, Result};
+pub use principal::local_subscriber_id;
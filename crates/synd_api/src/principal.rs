@@ -1,7 +1,7 @@
-use std::{
-    collections::hash_map::DefaultHasher,
-    hash::{Hash, Hasher},
-};
+use std::fmt::Write as _;
+
+use sha2::{Digest, Sha256};
+use synd_registry::SubscriberId;
 
 #[derive(Clone, Debug)]
 pub enum Principal {
@@ -18,6 +18,22 @@ impl Principal {
             Principal::User(User { id, .. }) => id.as_str(),
         }
     }
+
+    /// Whether the principal operates the daemon and may manage other
+    /// subscribers. Only the local user does; identity provider users never do.
+    pub fn is_admin(&self) -> bool {
+        match self {
+            Principal::User(user) => user.is_local(),
+        }
+    }
+}
+
+const LOCAL_USER_ID: &str = "local";
+
+/// Registry subscriber of the local user, which the operator token also
+/// authenticates as.
+pub fn local_subscriber_id() -> SubscriberId {
+    SubscriberId::new(LOCAL_USER_ID)
 }
 
 #[derive(Clone, Debug)]
@@ -30,18 +46,21 @@ pub struct User {
 impl User {
     pub fn local() -> Self {
         User {
-            id: String::from("local"),
-            email: String::from("local"),
+            id: String::from(LOCAL_USER_ID),
+            email: String::from(LOCAL_USER_ID),
         }
     }
 
+    /// The id keys the user's subscriptions, timeline and read state, so it
+    /// is derived from a digest that stays stable across toolchain upgrades.
     pub fn from_email(email: impl Into<String>) -> Self {
-        let mut s = DefaultHasher::new();
         let email = email.into();
-
-        email.hash(&mut s);
-        let id = s.finish();
-        let id = format!("{id:016x}");
+        let normalized = email.trim().to_ascii_lowercase();
+        let digest = Sha256::digest(normalized.as_bytes());
+        let mut id = String::with_capacity(16);
+        for byte in &digest[..8] {
+            let _ = write!(id, "{byte:02x}");
+        }
 
         User { id, email }
     }
@@ -49,22 +68,39 @@ impl User {
     pub fn id(&self) -> &str {
         self.id.as_str()
     }
+
+    pub fn is_local(&self) -> bool {
+        self.id == LOCAL_USER_ID
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::User;
+    use super::{Principal, User};
 
     #[test]
     fn user_from_email() {
         let u = User::from_email("foo@ymgyt.io");
         assert_eq!(u.id().len(), 16);
-        assert_eq!(u.id(), "585779d8c9b2e06d");
+        assert_eq!(u.id(), "cc80eb522a51b6b3");
+    }
+
+    #[test]
+    fn user_id_ignores_email_case_and_surrounding_whitespace() {
+        let u = User::from_email(" Foo@YMGYT.io ");
+        assert_eq!(u.id(), "cc80eb522a51b6b3");
     }
 
     #[test]
     fn local_user() {
         let u = User::local();
         assert_eq!(u.id(), "local");
+        assert!(u.is_local());
+    }
+
+    #[test]
+    fn only_local_user_is_admin() {
+        assert!(Principal::User(User::local()).is_admin());
+        assert!(!Principal::User(User::from_email("foo@ymgyt.io")).is_admin());
     }
 }
@@ -2,6 +2,7 @@ use std::time::Duration;
 
 use futures_util::future::BoxFuture;
 use moka::future::Cache;
+use sha2::{Digest, Sha256};
 use synd_auth::jwt::google::JwtService as GoogleJwtService;
 use tracing::warn;
 use tracing::{debug, instrument};
@@ -24,6 +25,7 @@ enum AuthenticatorKind {
         github: GithubClient,
         google: Box<GoogleJwtService>,
         cache: Cache<String, Principal>,
+        operator_token: Option<String>,
     },
     Local {
         token: String,
@@ -43,6 +45,7 @@ impl Authenticator {
                 github: GithubClient::new()?,
                 google: Box::default(),
                 cache,
+                operator_token: None,
             },
         })
     }
@@ -67,11 +70,17 @@ impl Authenticator {
     #[must_use]
     pub fn with_github_client(self, github: GithubClient) -> Self {
         match self.kind {
-            AuthenticatorKind::Remote { google, cache, .. } => Self {
+            AuthenticatorKind::Remote {
+                google,
+                cache,
+                operator_token,
+                ..
+            } => Self {
                 kind: AuthenticatorKind::Remote {
                     github,
                     google,
                     cache,
+                    operator_token,
                 },
             },
             AuthenticatorKind::Local { token } => Self {
@@ -86,11 +95,17 @@ impl Authenticator {
     #[must_use]
     pub fn with_google_jwt(self, google: GoogleJwtService) -> Self {
         match self.kind {
-            AuthenticatorKind::Remote { github, cache, .. } => Self {
+            AuthenticatorKind::Remote {
+                github,
+                cache,
+                operator_token,
+                ..
+            } => Self {
                 kind: AuthenticatorKind::Remote {
                     github,
                     google: Box::new(google),
                     cache,
+                    operator_token,
                 },
             },
             AuthenticatorKind::Local { token } => Self {
@@ -102,6 +117,32 @@ impl Authenticator {
         }
     }
 
+    /// Also accepts `token` as a bearer token for the local user, so the
+    /// operator of a multi-user daemon can manage it remotely.
+    pub fn with_operator_token(self, token: impl Into<String>) -> ApiResult<Self> {
+        let token = token.into();
+        if token.is_empty() {
+            return Err(Error::EmptyLocalToken);
+        }
+
+        match self.kind {
+            AuthenticatorKind::Remote {
+                github,
+                google,
+                cache,
+                ..
+            } => Ok(Self {
+                kind: AuthenticatorKind::Remote {
+                    github,
+                    google,
+                    cache,
+                    operator_token: Some(token),
+                },
+            }),
+            kind => Ok(Self { kind }),
+        }
+    }
+
     /// Authenticate from given token
     #[instrument(skip_all)]
     pub async fn authenticate<S>(&self, token: S) -> Result<Principal, ()>
@@ -114,7 +155,15 @@ impl Authenticator {
                 github,
                 google,
                 cache,
-            } => Self::authenticate_remote(github, google, cache, token).await,
+                operator_token,
+            } => {
+                if let Some(operator_token) = operator_token
+                    && Self::authenticate_local(operator_token, token).is_ok()
+                {
+                    return Ok(Principal::User(User::local()));
+                }
+                Self::authenticate_remote(github, google, cache, token).await
+            }
             AuthenticatorKind::Local {
                 token: expected_token,
             } => Self::authenticate_local(expected_token, token),
@@ -196,13 +245,26 @@ impl Authenticator {
         let mut split = token.splitn(2, ' ');
         match (split.next(), split.next()) {
             (Some(scheme), Some(actual_token))
-                if scheme.eq_ignore_ascii_case("Bearer") && actual_token == expected_token =>
+                if scheme.eq_ignore_ascii_case("Bearer")
+                    && Self::tokens_match(expected_token, actual_token) =>
             {
                 Ok(Principal::User(User::local()))
             }
             _ => Err(()),
         }
     }
+
+    /// Compares digests of both tokens so the time taken reveals neither
+    /// the length nor a matching prefix of the expected token.
+    fn tokens_match(expected_token: &str, actual_token: &str) -> bool {
+        let expected = Sha256::digest(expected_token.as_bytes());
+        let actual = Sha256::digest(actual_token.as_bytes());
+        expected
+            .iter()
+            .zip(actual.iter())
+            .fold(0_u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+    }
 }
 
 impl Authenticate for Authenticator {
@@ -231,10 +293,24 @@ mod tests {
         let authenticator = Authenticator::local("secret").unwrap();
 
         assert!(authenticator.authenticate("Bearer wrong").await.is_err());
+        assert!(authenticator.authenticate("Bearer secre").await.is_err());
+        assert!(authenticator.authenticate("Bearer secrets").await.is_err());
         assert!(authenticator.authenticate("github secret").await.is_err());
         assert!(authenticator.authenticate("").await.is_err());
     }
 
+    #[tokio::test]
+    async fn remote_auth_accepts_operator_token_as_local() {
+        let authenticator = Authenticator::new()
+            .unwrap()
+            .with_operator_token("secret")
+            .unwrap();
+        let principal = authenticator.authenticate("Bearer secret").await.unwrap();
+
+        assert!(principal.is_admin());
+        assert!(authenticator.authenticate("Bearer wrong").await.is_err());
+    }
+
     #[test]
     fn local_auth_requires_non_empty_token() {
         assert!(Authenticator::local("").is_err());
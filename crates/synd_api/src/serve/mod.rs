@@ -104,11 +104,16 @@ pub async fn serve_unix(listener: UnixListener, dep: Dependency, shutdown: Shutd
     let daemon_sessions = dep.serve_options.daemon_sessions;
     let sessions = dep
         .sessions
-        .with_lease_policy(daemon_sessions.lease_policy())
-        .with_idle_shutdown(SessionIdleShutdown::new(
+        .with_lease_policy(daemon_sessions.lease_policy());
+    let sessions = if daemon_sessions.is_persistent() {
+        sessions
+    } else {
+        sessions.with_idle_shutdown(SessionIdleShutdown::new(
             daemon_sessions.idle_shutdown_grace(),
             shutdown.clone(),
-        ));
+        ))
+    };
+    let registry = dep.registry.clone();
     let ApiService { router, .. } = build_service(dep, &shutdown);
     let shutdown_requested = shutdown.cancellation_token();
     let _session_sweeper =
@@ -120,6 +125,7 @@ pub async fn serve_unix(listener: UnixListener, dep: Dependency, shutdown: Shutd
         .route(synd_protocol::daemon::STATUS_PATH, get(control::status))
         .route("/daemon/shutdown", post(control::shutdown))
         .layer(Extension(sessions))
+        .layer(Extension(registry))
         .layer(Extension(shutdown));
 
     debug!("Serving on Unix socket");
@@ -193,15 +199,37 @@ fn build_service(dep: Dependency, shutdown: &Shutdown) -> ApiService {
 
 mod control {
     use axum::{Extension, Json, http::StatusCode};
-    use synd_protocol::daemon::DaemonStatusResponse;
+    use chrono::{DateTime, Utc};
+    use synd_protocol::daemon::{DaemonCrawlStatus, DaemonHostBackoff, DaemonStatusResponse};
+    use synd_registry::crawl::host::HostBackoff;
 
+    use crate::dependency::LiveFeedRegistry;
     use crate::session::DaemonSessions;
     use crate::shutdown::{Shutdown, ShutdownReason};
 
     pub(super) async fn status(
         Extension(sessions): Extension<DaemonSessions>,
+        Extension(registry): Extension<LiveFeedRegistry>,
     ) -> Json<DaemonStatusResponse> {
-        Json(DaemonStatusResponse::new(sessions.status()))
+        let crawl = crawl_status(registry.crawl_host_backoffs(), Utc::now());
+        Json(DaemonStatusResponse::new(sessions.status(), crawl))
+    }
+
+    /// Backoffs that expired since the dispatcher's latest pass are dropped.
+    fn crawl_status(host_backoffs: Vec<HostBackoff>, now: DateTime<Utc>) -> DaemonCrawlStatus {
+        DaemonCrawlStatus::new(
+            host_backoffs
+                .into_iter()
+                .filter_map(|backoff| {
+                    let remaining = (backoff.until - now).to_std().ok()?;
+                    Some(DaemonHostBackoff::new(
+                        backoff.host,
+                        backoff.feed_url.to_string(),
+                        remaining,
+                    ))
+                })
+                .collect(),
+        )
     }
 
     pub(super) async fn shutdown(Extension(shutdown): Extension<Shutdown>) -> StatusCode {
@@ -39,6 +39,7 @@ pub const DEFAULT_DAEMON_SESSION_SWEEP_INTERVAL: Duration = Duration::from_secs(
 pub struct DaemonSessionConfig {
     lease_policy: DaemonSessionLeasePolicy,
     idle_shutdown_grace: Duration,
+    persistent: bool,
 }
 
 impl DaemonSessionConfig {
@@ -47,6 +48,7 @@ impl DaemonSessionConfig {
         Self {
             lease_policy,
             idle_shutdown_grace,
+            persistent: false,
         }
     }
 
@@ -69,6 +71,12 @@ impl DaemonSessionConfig {
         }
     }
 
+    /// A persistent daemon keeps running after its last session is gone.
+    #[must_use]
+    pub fn with_persistent(self, persistent: bool) -> Self {
+        Self { persistent, ..self }
+    }
+
     #[must_use]
     pub fn lease_policy(self) -> DaemonSessionLeasePolicy {
         self.lease_policy
@@ -78,6 +86,11 @@ impl DaemonSessionConfig {
     pub fn idle_shutdown_grace(self) -> Duration {
         self.idle_shutdown_grace
     }
+
+    #[must_use]
+    pub fn is_persistent(self) -> bool {
+        self.persistent
+    }
 }
 
 impl Default for DaemonSessionConfig {
@@ -0,0 +1,135 @@
+use tracing::instrument;
+
+use super::GraphqlRequest;
+use crate::{
+    Client, SyndApiError,
+    payload::{DeleteSubscriberPayload, SubscriberConnection, SubscriberDetails},
+};
+
+const SUBSCRIBERS_QUERY: &str = include_str!("query/subscribers.gql");
+const SUBSCRIBER_QUERY: &str = include_str!("query/subscriber.gql");
+const DELETE_SUBSCRIBER_MUTATION: &str = include_str!("query/delete_subscriber.gql");
+
+#[derive(Debug, serde::Serialize)]
+struct SubscribersVariables {
+    after: Option<String>,
+    first: i64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SubscribersData {
+    output: SubscribersOutput,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SubscribersOutput {
+    subscribers: SubscriberConnection,
+}
+
+impl From<SubscribersData> for SubscriberConnection {
+    fn from(data: SubscribersData) -> Self {
+        data.output.subscribers
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SubscriberVariables {
+    id: String,
+    after: Option<String>,
+    first: i64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SubscriberData {
+    output: SubscriberOutput,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SubscriberOutput {
+    subscriber: Option<SubscriberDetails>,
+}
+
+impl From<SubscriberData> for Option<SubscriberDetails> {
+    fn from(data: SubscriberData) -> Self {
+        data.output.subscriber
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DeleteSubscriberVariables {
+    input: DeleteSubscriberInput,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DeleteSubscriberInput {
+    id: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DeleteSubscriberData {
+    delete_subscriber: DeleteSubscriberPayload,
+}
+
+impl From<DeleteSubscriberData> for DeleteSubscriberPayload {
+    fn from(data: DeleteSubscriberData) -> Self {
+        data.delete_subscriber
+    }
+}
+
+impl Client {
+    /// Lists the subscribers of the daemon. Requires the operator credential.
+    #[instrument(skip(self))]
+    pub async fn fetch_subscribers(
+        &self,
+        after: Option<String>,
+        first: i64,
+    ) -> Result<SubscriberConnection, SyndApiError> {
+        let data: SubscribersData = self
+            .execute_graphql(&GraphqlRequest::new(
+                SUBSCRIBERS_QUERY,
+                SubscribersVariables { after, first },
+            ))
+            .await?
+            .require_complete()?;
+        Ok(data.into())
+    }
+
+    /// Fetches one subscriber and a page of its subscriptions. Requires the
+    /// operator credential.
+    #[instrument(skip(self))]
+    pub async fn fetch_subscriber(
+        &self,
+        id: String,
+        after: Option<String>,
+        first: i64,
+    ) -> Result<Option<SubscriberDetails>, SyndApiError> {
+        let data: SubscriberData = self
+            .execute_graphql(&GraphqlRequest::new(
+                SUBSCRIBER_QUERY,
+                SubscriberVariables { id, after, first },
+            ))
+            .await?
+            .require_complete()?;
+        Ok(data.into())
+    }
+
+    /// Removes every subscription and saved entry of a subscriber. Requires
+    /// the operator credential.
+    #[instrument(skip(self))]
+    pub async fn delete_subscriber(
+        &self,
+        id: String,
+    ) -> Result<DeleteSubscriberPayload, SyndApiError> {
+        let data: DeleteSubscriberData = self
+            .execute_graphql(&GraphqlRequest::new(
+                DELETE_SUBSCRIBER_MUTATION,
+                DeleteSubscriberVariables {
+                    input: DeleteSubscriberInput { id },
+                },
+            ))
+            .await?
+            .require_complete()?;
+        Ok(data.into())
+    }
+}
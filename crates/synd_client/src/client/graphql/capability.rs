@@ -0,0 +1,36 @@
+use synd_protocol::CapabilitySet;
+use tracing::instrument;
+
+use super::GraphqlRequest;
+use crate::{Client, SyndApiError};
+
+const CAPABILITIES_QUERY: &str = include_str!("query/capabilities.gql");
+
+#[derive(Debug, serde::Serialize)]
+struct CapabilitiesVariables {}
+
+#[derive(Debug, serde::Deserialize)]
+struct CapabilitiesData {
+    capabilities: Vec<String>,
+}
+
+impl From<CapabilitiesData> for CapabilitySet {
+    fn from(data: CapabilitiesData) -> Self {
+        CapabilitySet::new(data.capabilities)
+    }
+}
+
+impl Client {
+    /// Capabilities the daemon behind this client serves.
+    #[instrument(skip(self))]
+    pub async fn fetch_capabilities(&self) -> Result<CapabilitySet, SyndApiError> {
+        let data: CapabilitiesData = self
+            .execute_graphql(&GraphqlRequest::new(
+                CAPABILITIES_QUERY,
+                CapabilitiesVariables {},
+            ))
+            .await?
+            .require_complete()?;
+        Ok(data.into())
+    }
+}
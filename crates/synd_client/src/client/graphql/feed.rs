@@ -5,13 +5,18 @@ use super::GraphqlRequest;
 use crate::{
     Client, SyndApiError,
     payload::{
-        SubscribeFeedInput, SubscribeFeedPayload, SubscriptionPayload, UnsubscribeFeedPayload,
+        CrawlHistoryEntry, CrawlHistoryPayload, DiscoverFeedsPayload, FeedCandidate,
+        RequestCrawlPayload, SubscribeFeedInput, SubscribeFeedPayload, SubscriptionPayload,
+        UnsubscribeFeedPayload,
     },
 };
 
 const FETCH_SUBSCRIPTION_QUERY: &str = include_str!("query/fetch_subscription.gql");
 const SUBSCRIBE_FEED_MUTATION: &str = include_str!("query/subscribe_feed.gql");
 const UNSUBSCRIBE_FEED_MUTATION: &str = include_str!("query/unsubscribe_feed.gql");
+const REQUEST_CRAWL_MUTATION: &str = include_str!("query/request_crawl.gql");
+const CRAWL_HISTORY_QUERY: &str = include_str!("query/crawl_history.gql");
+const DISCOVER_FEEDS_QUERY: &str = include_str!("query/discover_feeds.gql");
 
 #[derive(Debug, serde::Serialize)]
 struct FetchSubscriptionVariables {
@@ -69,6 +74,67 @@ impl From<UnsubscribeFeedData> for UnsubscribeFeedPayload {
     }
 }
 
+#[derive(Debug, serde::Serialize)]
+struct RequestCrawlVariables {
+    input: RequestCrawlInput,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct RequestCrawlInput {
+    url: FeedUrl,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestCrawlData {
+    request_crawl: RequestCrawlPayload,
+}
+
+impl From<RequestCrawlData> for RequestCrawlPayload {
+    fn from(data: RequestCrawlData) -> Self {
+        data.request_crawl
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DiscoverFeedsVariables {
+    url: FeedUrl,
+}
+
+/// `feedRegistry` is nullable, so a failed `discover_feeds` leaves `output` null
+/// next to the errors.
+#[derive(Debug, serde::Deserialize)]
+struct DiscoverFeedsData {
+    output: Option<DiscoverFeedsPayload>,
+}
+
+impl From<DiscoverFeedsData> for Vec<FeedCandidate> {
+    fn from(data: DiscoverFeedsData) -> Self {
+        data.output
+            .map(|output| output.discover_feeds)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct CrawlHistoryVariables {
+    url: FeedUrl,
+    first: Option<i64>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CrawlHistoryData {
+    output: Option<CrawlHistoryPayload>,
+}
+
+impl From<CrawlHistoryData> for Vec<CrawlHistoryEntry> {
+    fn from(data: CrawlHistoryData) -> Self {
+        data.output
+            .map(|output| output.crawl_history)
+            .unwrap_or_default()
+    }
+}
+
 impl Client {
     #[instrument(skip(self))]
     pub async fn fetch_subscription(
@@ -117,4 +183,46 @@ impl Client {
             .require_complete()?;
         Ok(data.into())
     }
+
+    #[instrument(skip(self))]
+    pub async fn request_crawl(&self, url: FeedUrl) -> Result<RequestCrawlPayload, SyndApiError> {
+        let data: RequestCrawlData = self
+            .execute_graphql(&GraphqlRequest::new(
+                REQUEST_CRAWL_MUTATION,
+                RequestCrawlVariables {
+                    input: RequestCrawlInput { url },
+                },
+            ))
+            .await?
+            .require_complete()?;
+        Ok(data.into())
+    }
+
+    #[instrument(skip(self))]
+    pub async fn crawl_history(
+        &self,
+        url: FeedUrl,
+        first: Option<i64>,
+    ) -> Result<Vec<CrawlHistoryEntry>, SyndApiError> {
+        let data: CrawlHistoryData = self
+            .execute_graphql(&GraphqlRequest::new(
+                CRAWL_HISTORY_QUERY,
+                CrawlHistoryVariables { url, first },
+            ))
+            .await?
+            .require_complete()?;
+        Ok(data.into())
+    }
+
+    #[instrument(skip(self))]
+    pub async fn discover_feeds(&self, url: FeedUrl) -> Result<Vec<FeedCandidate>, SyndApiError> {
+        let data: DiscoverFeedsData = self
+            .execute_graphql(&GraphqlRequest::new(
+                DISCOVER_FEEDS_QUERY,
+                DiscoverFeedsVariables { url },
+            ))
+            .await?
+            .require_complete()?;
+        Ok(data.into())
+    }
 }
@@ -8,6 +8,8 @@ use tracing::{Span, debug, warn};
 use super::Client;
 use crate::SyndApiError;
 
+mod admin;
+mod capability;
 mod feed;
 mod timeline;
 
@@ -1,13 +1,26 @@
+use synd_feed::{entry::EntryId, types::FeedUrl};
 use tracing::instrument;
 
 use super::GraphqlRequest;
 use crate::{
     Client, SyndApiError,
-    payload::{TimelineChangesPayload, TimelineEntryConnection},
+    payload::{
+        FeedEntryConnection, MarkEntriesPayload, SaveEntryPayload, SavedEntryConnection,
+        TimelineChangesPayload, TimelineEntriesFilter, TimelineEntry, TimelineEntryConnection,
+        TimelineSearchConnection,
+    },
 };
 
 const TIMELINE_CHANGES_QUERY: &str = include_str!("query/timeline_changes.gql");
 const TIMELINE_ENTRIES_QUERY: &str = include_str!("query/timeline_entries.gql");
+const TIMELINE_ENTRY_QUERY: &str = include_str!("query/timeline_entry.gql");
+const SEARCH_TIMELINE_QUERY: &str = include_str!("query/search_timeline.gql");
+const MARK_ENTRIES_READ_MUTATION: &str = include_str!("query/mark_entries_read.gql");
+const MARK_ENTRIES_UNREAD_MUTATION: &str = include_str!("query/mark_entries_unread.gql");
+const SAVED_ENTRIES_QUERY: &str = include_str!("query/saved_entries.gql");
+const FEED_ENTRIES_QUERY: &str = include_str!("query/feed_entries.gql");
+const SAVE_ENTRY_MUTATION: &str = include_str!("query/save_entry.gql");
+const UNSAVE_ENTRY_MUTATION: &str = include_str!("query/unsave_entry.gql");
 
 #[derive(Debug, serde::Serialize)]
 struct TimelineEntriesVariables {
@@ -15,6 +28,13 @@ struct TimelineEntriesVariables {
     first: i64,
 }
 
+#[derive(Debug, serde::Serialize)]
+struct FilteredTimelineEntriesVariables {
+    filter: TimelineEntriesFilter,
+    after: Option<String>,
+    first: i64,
+}
+
 #[derive(Debug, serde::Deserialize)]
 struct TimelineEntriesData {
     output: TimelineEntriesOutput,
@@ -36,6 +56,60 @@ impl From<TimelineEntriesData> for TimelineEntryConnection {
     }
 }
 
+#[derive(Debug, serde::Serialize)]
+struct TimelineEntryVariables {
+    id: EntryId,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TimelineEntryData {
+    output: TimelineEntryOutput,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TimelineEntryOutput {
+    timeline: TimelineEntryField,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TimelineEntryField {
+    entry: Option<TimelineEntry>,
+}
+
+impl From<TimelineEntryData> for Option<TimelineEntry> {
+    fn from(data: TimelineEntryData) -> Self {
+        data.output.timeline.entry
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SearchTimelineVariables {
+    query: String,
+    after: Option<String>,
+    first: i64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SearchTimelineData {
+    output: SearchTimelineOutput,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SearchTimelineOutput {
+    timeline: SearchTimeline,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SearchTimeline {
+    search: TimelineSearchConnection,
+}
+
+impl From<SearchTimelineData> for TimelineSearchConnection {
+    fn from(data: SearchTimelineData) -> Self {
+        data.output.timeline.search
+    }
+}
+
 #[derive(Debug, serde::Serialize)]
 struct TimelineChangesVariables {
     since: i64,
@@ -63,17 +137,180 @@ impl From<TimelineChangesData> for TimelineChangesPayload {
     }
 }
 
+#[derive(Debug, serde::Serialize)]
+struct MarkEntriesVariables {
+    input: MarkEntriesInput,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MarkEntriesInput {
+    entry_ids: Vec<EntryId>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MarkEntriesReadData {
+    mark_entries_read: MarkEntriesPayload,
+}
+
+impl From<MarkEntriesReadData> for MarkEntriesPayload {
+    fn from(data: MarkEntriesReadData) -> Self {
+        data.mark_entries_read
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MarkEntriesUnreadData {
+    mark_entries_unread: MarkEntriesPayload,
+}
+
+impl From<MarkEntriesUnreadData> for MarkEntriesPayload {
+    fn from(data: MarkEntriesUnreadData) -> Self {
+        data.mark_entries_unread
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SavedEntriesData {
+    output: SavedEntriesOutput,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SavedEntriesOutput {
+    timeline: SavedEntries,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SavedEntries {
+    saved: SavedEntryConnection,
+}
+
+impl From<SavedEntriesData> for SavedEntryConnection {
+    fn from(data: SavedEntriesData) -> Self {
+        data.output.timeline.saved
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct FeedEntriesVariables {
+    url: FeedUrl,
+    after: Option<String>,
+    first: i64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct FeedEntriesData {
+    output: FeedEntriesOutput,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct FeedEntriesOutput {
+    feed: FeedEntries,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct FeedEntries {
+    entries: FeedEntryConnection,
+}
+
+impl From<FeedEntriesData> for FeedEntryConnection {
+    fn from(data: FeedEntriesData) -> Self {
+        data.output.feed.entries
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct SaveEntryVariables {
+    input: SaveEntryInput,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SaveEntryInput {
+    entry_id: EntryId,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SaveEntryData {
+    save_entry: SaveEntryPayload,
+}
+
+impl From<SaveEntryData> for SaveEntryPayload {
+    fn from(data: SaveEntryData) -> Self {
+        data.save_entry
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UnsaveEntryData {
+    unsave_entry: SaveEntryPayload,
+}
+
+impl From<UnsaveEntryData> for SaveEntryPayload {
+    fn from(data: UnsaveEntryData) -> Self {
+        data.unsave_entry
+    }
+}
+
 impl Client {
     #[instrument(skip(self))]
     pub async fn fetch_timeline_entries(
         &self,
+        filter: TimelineEntriesFilter,
         after: Option<String>,
         first: i64,
     ) -> Result<TimelineEntryConnection, SyndApiError> {
         let outcome = self
             .execute_graphql::<_, TimelineEntriesData>(&GraphqlRequest::new(
                 TIMELINE_ENTRIES_QUERY,
-                TimelineEntriesVariables { after, first },
+                FilteredTimelineEntriesVariables {
+                    filter,
+                    after,
+                    first,
+                },
+            ))
+            .await?
+            .accept_partial()?;
+        outcome.warn_partial_errors();
+        Ok(outcome.into_data().into())
+    }
+
+    /// Fetches one timeline entry including its content body.
+    #[instrument(skip(self))]
+    pub async fn fetch_timeline_entry(
+        &self,
+        id: EntryId,
+    ) -> Result<Option<TimelineEntry>, SyndApiError> {
+        let outcome = self
+            .execute_graphql::<_, TimelineEntryData>(&GraphqlRequest::new(
+                TIMELINE_ENTRY_QUERY,
+                TimelineEntryVariables { id },
+            ))
+            .await?
+            .accept_partial()?;
+        outcome.warn_partial_errors();
+        Ok(outcome.into_data().into())
+    }
+
+    #[instrument(skip(self))]
+    pub async fn search_timeline(
+        &self,
+        query: String,
+        after: Option<String>,
+        first: i64,
+    ) -> Result<TimelineSearchConnection, SyndApiError> {
+        let outcome = self
+            .execute_graphql::<_, SearchTimelineData>(&GraphqlRequest::new(
+                SEARCH_TIMELINE_QUERY,
+                SearchTimelineVariables {
+                    query,
+                    after,
+                    first,
+                },
             ))
             .await?
             .accept_partial()?;
@@ -97,4 +334,102 @@ impl Client {
         outcome.warn_partial_errors();
         Ok(outcome.into_data().into())
     }
+
+    #[instrument(skip(self))]
+    pub async fn mark_entries_read(
+        &self,
+        entry_ids: Vec<EntryId>,
+    ) -> Result<MarkEntriesPayload, SyndApiError> {
+        let data: MarkEntriesReadData = self
+            .execute_graphql(&GraphqlRequest::new(
+                MARK_ENTRIES_READ_MUTATION,
+                MarkEntriesVariables {
+                    input: MarkEntriesInput { entry_ids },
+                },
+            ))
+            .await?
+            .require_complete()?;
+        Ok(data.into())
+    }
+
+    #[instrument(skip(self))]
+    pub async fn mark_entries_unread(
+        &self,
+        entry_ids: Vec<EntryId>,
+    ) -> Result<MarkEntriesPayload, SyndApiError> {
+        let data: MarkEntriesUnreadData = self
+            .execute_graphql(&GraphqlRequest::new(
+                MARK_ENTRIES_UNREAD_MUTATION,
+                MarkEntriesVariables {
+                    input: MarkEntriesInput { entry_ids },
+                },
+            ))
+            .await?
+            .require_complete()?;
+        Ok(data.into())
+    }
+
+    #[instrument(skip(self))]
+    pub async fn fetch_saved_entries(
+        &self,
+        after: Option<String>,
+        first: i64,
+    ) -> Result<SavedEntryConnection, SyndApiError> {
+        let outcome = self
+            .execute_graphql::<_, SavedEntriesData>(&GraphqlRequest::new(
+                SAVED_ENTRIES_QUERY,
+                TimelineEntriesVariables { after, first },
+            ))
+            .await?
+            .accept_partial()?;
+        outcome.warn_partial_errors();
+        Ok(outcome.into_data().into())
+    }
+
+    /// Fetches the entry history of a subscribed feed.
+    #[instrument(skip(self))]
+    pub async fn fetch_feed_entries(
+        &self,
+        url: FeedUrl,
+        after: Option<String>,
+        first: i64,
+    ) -> Result<FeedEntryConnection, SyndApiError> {
+        let outcome = self
+            .execute_graphql::<_, FeedEntriesData>(&GraphqlRequest::new(
+                FEED_ENTRIES_QUERY,
+                FeedEntriesVariables { url, after, first },
+            ))
+            .await?
+            .accept_partial()?;
+        outcome.warn_partial_errors();
+        Ok(outcome.into_data().into())
+    }
+
+    #[instrument(skip(self))]
+    pub async fn save_entry(&self, entry_id: EntryId) -> Result<SaveEntryPayload, SyndApiError> {
+        let data: SaveEntryData = self
+            .execute_graphql(&GraphqlRequest::new(
+                SAVE_ENTRY_MUTATION,
+                SaveEntryVariables {
+                    input: SaveEntryInput { entry_id },
+                },
+            ))
+            .await?
+            .require_complete()?;
+        Ok(data.into())
+    }
+
+    #[instrument(skip(self))]
+    pub async fn unsave_entry(&self, entry_id: EntryId) -> Result<SaveEntryPayload, SyndApiError> {
+        let data: UnsaveEntryData = self
+            .execute_graphql(&GraphqlRequest::new(
+                UNSAVE_ENTRY_MUTATION,
+                SaveEntryVariables {
+                    input: SaveEntryInput { entry_id },
+                },
+            ))
+            .await?
+            .require_complete()?;
+        Ok(data.into())
+    }
 }
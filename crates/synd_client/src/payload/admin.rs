@@ -0,0 +1,51 @@
+use serde::Deserialize;
+use synd_feed::types::{Category, FeedUrl, Requirement, Time};
+
+use super::{PageInfo, ResponseStatus};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriberConnection {
+    pub nodes: Vec<Subscriber>,
+    pub page_info: PageInfo,
+}
+
+/// One subscriber of a multi-user daemon.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Subscriber {
+    pub id: String,
+    pub subscription_count: i64,
+    pub first_subscribed_at: Time,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriberDetails {
+    #[serde(flatten)]
+    pub subscriber: Subscriber,
+    pub subscriptions: SubscriberSubscriptionConnection,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriberSubscriptionConnection {
+    pub nodes: Vec<SubscriberSubscription>,
+    pub page_info: PageInfo,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubscriberSubscription {
+    pub url: FeedUrl,
+    #[serde(default, with = "super::requirement")]
+    pub requirement: Option<Requirement>,
+    pub category: Option<Category<'static>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeleteSubscriberPayload {
+    pub status: ResponseStatus,
+    pub id: String,
+    pub unsubscribed: i64,
+    pub unsaved: i64,
+}
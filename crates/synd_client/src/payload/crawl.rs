@@ -0,0 +1,100 @@
+use serde::{Deserialize, Deserializer};
+use synd_feed::types::Time;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrawlHistoryPayload {
+    pub crawl_history: Vec<CrawlHistoryEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrawlHistoryEntry {
+    pub job_id: String,
+    pub trigger: CrawlTrigger,
+    pub started_at: Time,
+    pub finished_at: Time,
+    pub duration_ms: i64,
+    pub outcome: CrawlOutcome,
+    pub http_status: Option<u16>,
+    pub error_kind: Option<String>,
+    pub body_bytes: Option<u64>,
+    pub body_changed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CrawlTrigger {
+    PeriodicDue,
+    ManualRequest,
+    RetryDue,
+    Other(String),
+}
+
+impl CrawlTrigger {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::PeriodicDue => "periodic_due",
+            Self::ManualRequest => "manual_request",
+            Self::RetryDue => "retry_due",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CrawlTrigger {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "PERIODIC_DUE" => Self::PeriodicDue,
+            "MANUAL_REQUEST" => Self::ManualRequest,
+            "RETRY_DUE" => Self::RetryDue,
+            _ => Self::Other(value),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CrawlOutcome {
+    Fetched,
+    NotModified,
+    UnexpectedStatus,
+    BodyReadFailed,
+    FetchFailed,
+    ParseFailed,
+    Other(String),
+}
+
+impl CrawlOutcome {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Fetched => "fetched",
+            Self::NotModified => "not_modified",
+            Self::UnexpectedStatus => "unexpected_status",
+            Self::BodyReadFailed => "body_read_failed",
+            Self::FetchFailed => "fetch_failed",
+            Self::ParseFailed => "parse_failed",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CrawlOutcome {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "FETCHED" => Self::Fetched,
+            "NOT_MODIFIED" => Self::NotModified,
+            "UNEXPECTED_STATUS" => Self::UnexpectedStatus,
+            "BODY_READ_FAILED" => Self::BodyReadFailed,
+            "FETCH_FAILED" => Self::FetchFailed,
+            "PARSE_FAILED" => Self::ParseFailed,
+            _ => Self::Other(value),
+        })
+    }
+}
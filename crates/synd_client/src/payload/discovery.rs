@@ -0,0 +1,86 @@
+use serde::{Deserialize, Deserializer};
+use synd_feed::types::FeedUrl;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscoverFeedsPayload {
+    pub discover_feeds: Vec<FeedCandidate>,
+}
+
+/// Feed the daemon found while discovering feeds from a URL.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedCandidate {
+    pub url: FeedUrl,
+    pub kind: FeedCandidateKind,
+    pub title: Option<String>,
+    pub source: FeedCandidateSource,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeedCandidateKind {
+    Atom,
+    Rss,
+    Json,
+    Other(String),
+}
+
+impl FeedCandidateKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Atom => "atom",
+            Self::Rss => "rss",
+            Self::Json => "json",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FeedCandidateKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "ATOM" => Self::Atom,
+            "RSS" => Self::Rss,
+            "JSON" => Self::Json,
+            _ => Self::Other(value),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeedCandidateSource {
+    Requested,
+    LinkAlternate,
+    WellKnownPath,
+    Other(String),
+}
+
+impl FeedCandidateSource {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Requested => "requested",
+            Self::LinkAlternate => "link_alternate",
+            Self::WellKnownPath => "well_known_path",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FeedCandidateSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "REQUESTED" => Self::Requested,
+            "LINK_ALTERNATE" => Self::LinkAlternate,
+            "WELL_KNOWN_PATH" => Self::WellKnownPath,
+            _ => Self::Other(value),
+        })
+    }
+}
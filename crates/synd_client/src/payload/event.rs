@@ -1,12 +1,22 @@
-use serde::Deserialize;
-use synd_feed::types::{FeedUrl, Time};
+use serde::{Deserialize, Deserializer};
+use synd_feed::{
+    entry::EntryId,
+    types::{FeedUrl, Time},
+};
+
+use super::{CrawlHistoryEntry, TimelineEntry};
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(tag = "__typename")]
 pub enum FeedEvent {
     TimelineChanged(TimelineChangeEvent),
+    EntryAdded(EntryAddedEvent),
+    EntryRemoved(EntryRemovedEvent),
+    SubscriptionChanged(SubscriptionChangeEvent),
+    CrawlFailed(CrawlFailedEvent),
 }
 
+/// The timeline changed in a way only the timeline changes query can tell.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TimelineChangeEvent {
@@ -14,11 +24,73 @@ pub struct TimelineChangeEvent {
     pub affected_feeds: Option<Vec<FeedUrl>>,
 }
 
+/// An entry was added to the timeline or its state changed.
+///
+/// Clients synced up to any seq from `since` to `seq` upsert the entry and
+/// are then synced up to `seq`. Clients behind `since` missed changes.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryAddedEvent {
+    pub timeline_entry: Box<TimelineEntry>,
+    /// Position of the entry on the timeline
+    pub cursor: String,
+    pub since: i64,
+    pub seq: i64,
+}
+
+/// An entry left the timeline. Applied like [`EntryAddedEvent`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryRemovedEvent {
+    pub entry_id: EntryId,
+    pub since: i64,
+    pub seq: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionChangeEvent {
+    pub feed_url: FeedUrl,
+    pub change: SubscriptionChange,
+    pub changed_at: Time,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionChange {
+    Subscribed,
+    Changed,
+    Unsubscribed,
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for SubscriptionChange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "SUBSCRIBED" => Self::Subscribed,
+            "CHANGED" => Self::Changed,
+            "UNSUBSCRIBED" => Self::Unsubscribed,
+            _ => Self::Other(value),
+        })
+    }
+}
+
+/// A crawl of a subscribed feed failed.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrawlFailedEvent {
+    pub feed_url: FeedUrl,
+    pub crawl: Box<CrawlHistoryEntry>,
+}
+
 #[cfg(test)]
 mod tests {
     use core::assert_matches;
 
-    use super::FeedEvent;
+    use super::{FeedEvent, SubscriptionChange};
 
     #[test]
     fn decodes_timeline_changed_feed_event() {
@@ -29,8 +101,67 @@ mod tests {
         }))
         .unwrap();
 
-        let FeedEvent::TimelineChanged(event) = event;
+        let FeedEvent::TimelineChanged(event) = event else {
+            panic!("unexpected feed event: {event:?}");
+        };
         assert_eq!(event.changed_at.to_rfc3339(), "2026-06-13T00:00:00+00:00");
         assert_matches!(event.affected_feeds, Some(feeds) if feeds.len() == 1);
     }
+
+    #[test]
+    fn decodes_entry_added_feed_event() {
+        let entry_id = format!("synd:entry:v1:{}", "a".repeat(64));
+        let event: FeedEvent = serde_json::from_value(serde_json::json!({
+            "__typename": "EntryAdded",
+            "timelineEntry": {
+                "orderTime": "2026-06-13T00:00:00Z",
+                "read": false,
+                "saved": true,
+                "entry": {
+                    "id": entry_id,
+                    "title": "Rust 1.90",
+                    "published": "2026-06-13T00:00:00Z",
+                    "updated": null,
+                    "summary": null,
+                    "websiteUrl": "https://example.com/a",
+                    "tags": [],
+                    "enclosures": [],
+                    "thumbnails": [],
+                    "feed": {
+                        "title": "Example",
+                        "url": "https://example.com/feed.xml",
+                        "requirement": "MUST",
+                        "category": "rust"
+                    }
+                }
+            },
+            "cursor": "opaque",
+            "since": 3,
+            "seq": 5
+        }))
+        .unwrap();
+
+        let FeedEvent::EntryAdded(event) = event else {
+            panic!("unexpected feed event: {event:?}");
+        };
+        assert_eq!(event.timeline_entry.entry.id.as_str(), entry_id);
+        assert!(event.timeline_entry.saved);
+        assert_eq!((event.since, event.seq), (3, 5));
+    }
+
+    #[test]
+    fn decodes_subscription_changed_feed_event() {
+        let event: FeedEvent = serde_json::from_value(serde_json::json!({
+            "__typename": "SubscriptionChanged",
+            "feedUrl": "https://example.com/feed.xml",
+            "change": "UNSUBSCRIBED",
+            "changedAt": "2026-06-13T00:00:00Z"
+        }))
+        .unwrap();
+
+        let FeedEvent::SubscriptionChanged(event) = event else {
+            panic!("unexpected feed event: {event:?}");
+        };
+        assert_eq!(event.change, SubscriptionChange::Unsubscribed);
+    }
 }
@@ -1,18 +1,33 @@
+mod admin;
+mod crawl;
+mod discovery;
 mod event;
 mod page;
 mod requirement;
 mod subscription;
 mod timeline;
 
-pub use event::{FeedEvent, TimelineChangeEvent};
+pub use admin::{
+    DeleteSubscriberPayload, Subscriber, SubscriberConnection, SubscriberDetails,
+    SubscriberSubscription, SubscriberSubscriptionConnection,
+};
+pub use crawl::{CrawlHistoryEntry, CrawlHistoryPayload, CrawlOutcome, CrawlTrigger};
+pub use discovery::{DiscoverFeedsPayload, FeedCandidate, FeedCandidateKind, FeedCandidateSource};
+pub use event::{
+    CrawlFailedEvent, EntryAddedEvent, EntryRemovedEvent, FeedEvent, SubscriptionChange,
+    SubscriptionChangeEvent, TimelineChangeEvent,
+};
 pub use page::PageInfo;
 pub use subscription::{
     AuthorsConnection, CrawlPolicy, CrawlPolicyInput, EntryMeta, EntryMetaConnection,
     FeedConnection, FeedDetails, GraphqlFeedType, InvalidPollingInterval, Link, LinkConnection,
-    PollingIntervalSeconds, PollingPolicy, PollingPolicyInput, ResponseCode, ResponseStatus,
-    SubscribeDisposition, SubscribeFeedInput, SubscribeFeedPayload, SubscribedFeed,
-    SubscriptionPayload, UnsubscribeDisposition, UnsubscribeFeedPayload, UnsupportedFeedType,
+    PollingIntervalSeconds, PollingPolicy, PollingPolicyInput, RequestCrawlDisposition,
+    RequestCrawlPayload, ResponseCode, ResponseStatus, SubscribeDisposition, SubscribeFeedInput,
+    SubscribeFeedPayload, SubscribedFeed, SubscriptionPayload, UnsubscribeDisposition,
+    UnsubscribeFeedPayload, UnsupportedFeedType,
 };
 pub use timeline::{
-    Entry, FeedMeta, TimelineChange, TimelineChangesPayload, TimelineEntry, TimelineEntryConnection,
+    Enclosure, Entry, FeedEntryConnection, FeedMeta, MarkEntriesPayload, SaveEntryPayload,
+    SavedEntryConnection, Thumbnail, TimelineChange, TimelineChangesPayload, TimelineEntriesFilter,
+    TimelineEntry, TimelineEntryConnection, TimelineSearchConnection,
 };
@@ -1,4 +1,4 @@
-use serde::{Deserialize, Deserializer, Serializer, de};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 use synd_feed::types::Requirement;
 
 const VARIANTS: &[&str] = &["MUST", "SHOULD", "MAY"];
@@ -31,3 +31,21 @@ where
         value => Err(de::Error::unknown_variant(value, VARIANTS)),
     }
 }
+
+/// Serializes a list of optional requirements, as taken by timeline filters.
+#[allow(clippy::ref_option)]
+pub(super) fn serialize_list<S>(
+    values: &Option<Vec<Option<Requirement>>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    #[derive(Serialize)]
+    struct Item(#[serde(serialize_with = "serialize")] Option<Requirement>);
+
+    values
+        .as_ref()
+        .map(|values| values.iter().copied().map(Item).collect::<Vec<_>>())
+        .serialize(serializer)
+}
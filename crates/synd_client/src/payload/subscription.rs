@@ -23,6 +23,8 @@ pub struct SubscribedFeed {
     pub requirement: Option<Requirement>,
     pub category: Option<Category<'static>>,
     pub crawl_policy: CrawlPolicy,
+    #[serde(default)]
+    pub fetch_full_content: bool,
     pub feed: Option<FeedDetails>,
 }
 
@@ -40,6 +42,10 @@ pub enum PollingPolicy {
     Interval {
         seconds: PollingIntervalSeconds,
     },
+    Adaptive {
+        min: PollingIntervalSeconds,
+        max: PollingIntervalSeconds,
+    },
     Other {
         kind: String,
         interval_seconds: Option<i64>,
@@ -52,17 +58,29 @@ impl<'de> Deserialize<'de> for PollingPolicy {
         D: Deserializer<'de>,
     {
         let wire = PollingPolicyWire::deserialize(deserializer)?;
+        let seconds = |seconds: i64| {
+            PollingIntervalSeconds::try_from(seconds).map_err(serde::de::Error::custom)
+        };
         match (wire.kind.as_str(), wire.interval_seconds) {
             ("MANUAL", None) => Ok(Self::Manual),
             ("MANUAL", Some(_)) => Err(serde::de::Error::custom(
                 "MANUAL polling policy must omit intervalSeconds",
             )),
-            ("INTERVAL", Some(seconds)) => Ok(Self::Interval {
-                seconds: seconds.try_into().map_err(serde::de::Error::custom)?,
+            ("INTERVAL", Some(interval)) => Ok(Self::Interval {
+                seconds: seconds(interval)?,
             }),
             ("INTERVAL", None) => Err(serde::de::Error::custom(
                 "INTERVAL polling policy requires intervalSeconds",
             )),
+            ("ADAPTIVE", _) => match (wire.min_interval_seconds, wire.max_interval_seconds) {
+                (Some(min), Some(max)) => Ok(Self::Adaptive {
+                    min: seconds(min)?,
+                    max: seconds(max)?,
+                }),
+                _ => Err(serde::de::Error::custom(
+                    "ADAPTIVE polling policy requires minIntervalSeconds and maxIntervalSeconds",
+                )),
+            },
             (_, interval_seconds) => Ok(Self::Other {
                 kind: wire.kind,
                 interval_seconds,
@@ -76,6 +94,10 @@ impl<'de> Deserialize<'de> for PollingPolicy {
 struct PollingPolicyWire {
     kind: String,
     interval_seconds: Option<i64>,
+    #[serde(default)]
+    min_interval_seconds: Option<i64>,
+    #[serde(default)]
+    max_interval_seconds: Option<i64>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -183,6 +205,7 @@ pub struct SubscribeFeedInput {
     pub requirement: Option<Requirement>,
     pub category: Option<Category<'static>>,
     pub crawl_policy: Option<CrawlPolicyInput>,
+    pub fetch_full_content: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
@@ -194,7 +217,14 @@ pub struct CrawlPolicyInput {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PollingPolicyInput {
     Manual,
-    Interval { seconds: PollingIntervalSeconds },
+    Interval {
+        seconds: PollingIntervalSeconds,
+    },
+    /// Polls between `min` and `max` depending on how often the feed publishes
+    Adaptive {
+        min: PollingIntervalSeconds,
+        max: PollingIntervalSeconds,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -232,10 +262,20 @@ impl Serialize for PollingPolicyInput {
             Self::Manual => PollingPolicyInputWire {
                 kind: PollingPolicyInputKind::Manual,
                 interval_seconds: None,
+                min_interval_seconds: None,
+                max_interval_seconds: None,
             },
             Self::Interval { seconds } => PollingPolicyInputWire {
                 kind: PollingPolicyInputKind::Interval,
                 interval_seconds: Some(seconds.get()),
+                min_interval_seconds: None,
+                max_interval_seconds: None,
+            },
+            Self::Adaptive { min, max } => PollingPolicyInputWire {
+                kind: PollingPolicyInputKind::Adaptive,
+                interval_seconds: None,
+                min_interval_seconds: Some(min.get()),
+                max_interval_seconds: Some(max.get()),
             },
         };
         wire.serialize(serializer)
@@ -247,6 +287,8 @@ impl Serialize for PollingPolicyInput {
 struct PollingPolicyInputWire {
     kind: PollingPolicyInputKind,
     interval_seconds: Option<i64>,
+    min_interval_seconds: Option<i64>,
+    max_interval_seconds: Option<i64>,
 }
 
 #[derive(Serialize)]
@@ -254,6 +296,7 @@ struct PollingPolicyInputWire {
 enum PollingPolicyInputKind {
     Manual,
     Interval,
+    Adaptive,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -312,6 +355,37 @@ impl<'de> Deserialize<'de> for UnsubscribeDisposition {
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestCrawlPayload {
+    pub status: ResponseStatus,
+    pub url: FeedUrl,
+    pub disposition: RequestCrawlDisposition,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequestCrawlDisposition {
+    Requested,
+    AlreadyPending,
+    NotActiveTarget,
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for RequestCrawlDisposition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "REQUESTED" => Self::Requested,
+            "ALREADY_PENDING" => Self::AlreadyPending,
+            "NOT_ACTIVE_TARGET" => Self::NotActiveTarget,
+            _ => Self::Other(value),
+        })
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ResponseStatus {
     pub code: ResponseCode,
@@ -343,3 +417,26 @@ impl<'de> Deserialize<'de> for ResponseCode {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::assert_matches;
+
+    use super::PollingPolicy;
+
+    #[test]
+    fn decodes_adaptive_polling_policy() {
+        let policy: PollingPolicy = serde_json::from_value(serde_json::json!({
+            "kind": "ADAPTIVE",
+            "intervalSeconds": null,
+            "minIntervalSeconds": 900,
+            "maxIntervalSeconds": 43200
+        }))
+        .unwrap();
+
+        assert_matches!(
+            policy,
+            PollingPolicy::Adaptive { min, max } if (min.get(), max.get()) == (900, 43_200)
+        );
+    }
+}
@@ -1,19 +1,38 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use synd_feed::{
     entry::EntryId,
     types::{Category, FeedUrl, Requirement, Time},
 };
 
-use super::PageInfo;
+use super::{PageInfo, ResponseStatus};
 
 /// Entry as it appears on one timeline: display position + content.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TimelineEntry {
     pub order_time: Time,
+    /// Whether the subscriber marked the entry as read
+    #[serde(default)]
+    pub read: bool,
+    /// Whether the subscriber saved the entry
+    #[serde(default)]
+    pub saved: bool,
     pub entry: Entry,
 }
 
+/// Narrows timeline entries by the subscription each entry came from.
+/// Unset conditions match every entry
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineEntriesFilter {
+    /// Requirements to include. `None` includes subscriptions without one
+    #[serde(serialize_with = "super::requirement::serialize_list")]
+    pub requirements: Option<Vec<Option<Requirement>>>,
+    /// Categories to include. `None` includes subscriptions without one
+    pub categories: Option<Vec<Option<Category<'static>>>>,
+    pub feed_urls: Option<Vec<FeedUrl>>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TimelineEntryConnection {
@@ -23,6 +42,49 @@ pub struct TimelineEntryConnection {
     pub seq: i64,
 }
 
+/// Page of timeline entries matching a search, in timeline order.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineSearchConnection {
+    pub nodes: Vec<TimelineEntry>,
+    pub page_info: PageInfo,
+}
+
+/// Page of saved entries, latest save first.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedEntryConnection {
+    pub nodes: Vec<TimelineEntry>,
+    pub page_info: PageInfo,
+}
+
+/// Page of one feed's entries, newest first, including those no longer on
+/// the timeline.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedEntryConnection {
+    pub nodes: Vec<TimelineEntry>,
+    pub page_info: PageInfo,
+}
+
+/// Result of marking timeline entries read or unread.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkEntriesPayload {
+    pub status: ResponseStatus,
+    /// Entries whose read state changed
+    pub entry_ids: Vec<EntryId>,
+}
+
+/// Result of saving or unsaving one entry.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveEntryPayload {
+    pub status: ResponseStatus,
+    /// Whether the saved state changed
+    pub changed: bool,
+}
+
 /// Page of timeline changes for incremental sync, ordered by seq.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -31,6 +93,9 @@ pub struct TimelineChangesPayload {
     /// Seq the client remembers after applying this page
     pub seq: i64,
     pub has_more: bool,
+    /// `since` is older than the removals the server still keeps, so the
+    /// timeline has to be reloaded
+    pub expired: bool,
 }
 
 /// One timeline change, applied in seq order.
@@ -54,9 +119,38 @@ pub struct Entry {
     pub updated: Option<Time>,
     pub website_url: Option<String>,
     pub summary: Option<String>,
+    /// Content body, only requested when reading a single entry
+    #[serde(default)]
+    pub content: Option<String>,
+    /// Categories the feed filed the entry under
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub enclosures: Vec<Enclosure>,
+    #[serde(default)]
+    pub thumbnails: Vec<Thumbnail>,
     pub feed: FeedMeta,
 }
 
+/// Media file attached to an entry, such as a podcast episode.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Enclosure {
+    pub url: String,
+    pub mime_type: Option<String>,
+    /// Size in bytes
+    pub length: Option<i64>,
+    pub duration_seconds: Option<i64>,
+}
+
+/// Representative image of an entry.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Thumbnail {
+    pub url: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct FeedMeta {
     pub title: Option<String>,
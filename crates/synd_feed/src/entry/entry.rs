@@ -6,7 +6,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::types::{FeedType, FeedUrl, Link, Person, Text, Time, link};
 
-use super::{Content, EntryId, EntryIdError};
+use super::{
+    Content, Enclosure, EntryId, EntryIdError, Thumbnail,
+    media::{collect_enclosures, collect_thumbnails},
+};
 
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
 #[serde(rename_all = "snake_case")]
@@ -21,6 +24,15 @@ pub struct Entry {
     links: Vec<Link>,
     summary: Option<Text>,
     published: Option<Time>,
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    enclosures: Vec<Enclosure>,
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    thumbnails: Vec<Thumbnail>,
 }
 
 impl Entry {
@@ -56,6 +68,36 @@ impl Entry {
         self.summary.as_ref()
     }
 
+    /// Returns the categories the feed filed this entry under, by label.
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Returns media files attached to this entry.
+    pub fn enclosures(&self) -> &[Enclosure] {
+        &self.enclosures
+    }
+
+    /// Returns representative images of this entry.
+    pub fn thumbnails(&self) -> &[Thumbnail] {
+        &self.thumbnails
+    }
+
+    /// Whether `observed` differs from this entry only by tags, enclosures,
+    /// or thumbnails that this entry, stored before they were captured, lacks.
+    pub(super) fn is_backfilled_by(&self, observed: &Entry) -> bool {
+        if !(self.tags.is_empty() && self.enclosures.is_empty() && self.thumbnails.is_empty()) {
+            return false;
+        }
+        let backfilled = Entry {
+            tags: observed.tags.clone(),
+            enclosures: observed.enclosures.clone(),
+            thumbnails: observed.thumbnails.clone(),
+            ..self.clone()
+        };
+        &backfilled == observed
+    }
+
     pub fn website_url(&self, feed_type: FeedType) -> Option<&str> {
         link::find_website_url(feed_type, &self.links)
     }
@@ -73,9 +115,13 @@ impl Entry {
             content,
             links,
             summary,
+            categories,
             published,
+            media,
             ..
         } = entry;
+        let enclosures = collect_enclosures(feed_type, &media, &links);
+        let thumbnails = collect_thumbnails(&media);
         Ok(Self {
             id,
             title: title.map(Into::into),
@@ -85,10 +131,30 @@ impl Entry {
             links: links.into_iter().map(Into::into).collect(),
             summary: summary.map(Into::into),
             published,
+            tags: collect_tags(categories),
+            enclosures,
+            thumbnails,
         })
     }
 }
 
+/// Prefers the human-readable label over the raw term, dropping blanks and
+/// repeats.
+fn collect_tags(categories: Vec<feedrs::Category>) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for category in categories {
+        let tag = category
+            .label
+            .filter(|label| !label.trim().is_empty())
+            .unwrap_or(category.term);
+        let tag = tag.trim();
+        if !tag.is_empty() && !tags.iter().any(|known| known == tag) {
+            tags.push(tag.to_owned());
+        }
+    }
+    tags
+}
+
 impl fmt::Debug for Entry {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Entry")
@@ -159,4 +225,69 @@ mod tests {
         assert_eq!(entry.summary().map(Text::content), Some("Entry summary"));
         assert_eq!(entry.published(), Some(published));
     }
+
+    #[test]
+    fn captures_tags_enclosures_and_thumbnails() {
+        let document = br#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd"
+     xmlns:media="http://search.yahoo.com/mrss/">
+  <channel>
+    <title>Podcast</title>
+    <link>https://example.com/</link>
+    <item>
+      <guid>episode-1</guid>
+      <title>Episode 1</title>
+      <category>rust</category>
+      <category>async</category>
+      <category>rust</category>
+      <enclosure url="https://example.com/episode-1.mp3" length="1024" type="audio/mpeg"/>
+      <itunes:duration>00:30:00</itunes:duration>
+      <media:thumbnail url="https://example.com/episode-1.jpg" width="640" height="360"/>
+    </item>
+  </channel>
+</rss>"#;
+        let mut feed = feed_rs::parser::parse(&document[..]).unwrap();
+
+        let entry = Entry::from_feed_rs(
+            &FeedUrl::parse("https://example.com/feed.xml").unwrap(),
+            FeedType::RSS2,
+            feed.entries.remove(0),
+        )
+        .unwrap();
+
+        assert_eq!(entry.tags(), ["rust", "async"]);
+        let [enclosure] = entry.enclosures() else {
+            panic!("expected one enclosure: {:?}", entry.enclosures());
+        };
+        assert_eq!(enclosure.url(), "https://example.com/episode-1.mp3");
+        assert_eq!(enclosure.mime_type(), Some("audio/mpeg"));
+        assert_eq!(enclosure.length(), Some(1024));
+        assert_eq!(
+            enclosure.duration(),
+            Some(std::time::Duration::from_mins(30))
+        );
+        let [thumbnail] = entry.thumbnails() else {
+            panic!("expected one thumbnail: {:?}", entry.thumbnails());
+        };
+        assert_eq!(thumbnail.url(), "https://example.com/episode-1.jpg");
+        assert_eq!(
+            (thumbnail.width(), thumbnail.height()),
+            (Some(640), Some(360))
+        );
+    }
+
+    #[test]
+    fn reads_entries_stored_before_media_was_captured() {
+        let entry_id = format!("synd:entry:v1:{}", "a".repeat(64));
+        let stored = format!(
+            r#"{{"id":"{entry_id}","title":null,"updated":null,"authors":[],"content":null,"links":[],"summary":null,"published":null}}"#
+        );
+
+        let entry: Entry = serde_json::from_str(&stored).unwrap();
+
+        assert!(entry.tags().is_empty());
+        assert!(entry.enclosures().is_empty());
+        assert!(entry.thumbnails().is_empty());
+        assert_eq!(serde_json::to_string(&entry).unwrap(), stored);
+    }
 }
@@ -0,0 +1,200 @@
+use std::time::Duration;
+
+use bon::Builder;
+use feed_rs::model as feedrs;
+use serde::{Deserialize, Serialize};
+
+use crate::types::FeedType;
+
+/// Media file attached to an entry, such as a podcast episode or a video.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "snake_case")]
+pub struct Enclosure {
+    url: String,
+    mime_type: Option<String>,
+    length: Option<u64>,
+    duration_secs: Option<u64>,
+}
+
+impl Enclosure {
+    /// Returns the URL the media file is served from.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the declared media type, such as `audio/mpeg`.
+    pub fn mime_type(&self) -> Option<&str> {
+        self.mime_type.as_deref()
+    }
+
+    /// Returns the declared file size in bytes.
+    pub fn length(&self) -> Option<u64> {
+        self.length
+    }
+
+    /// Returns how long the media plays.
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration_secs.map(Duration::from_secs)
+    }
+}
+
+/// Representative image of an entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "snake_case")]
+pub struct Thumbnail {
+    url: String,
+    width: Option<u32>,
+    height: Option<u32>,
+}
+
+impl Thumbnail {
+    /// Returns the image URL.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the declared image width in pixels.
+    pub fn width(&self) -> Option<u32> {
+        self.width
+    }
+
+    /// Returns the declared image height in pixels.
+    pub fn height(&self) -> Option<u32> {
+        self.height
+    }
+}
+
+/// Collects enclosures from `MediaRSS` content, which feed-rs also fills from
+/// RSS `<enclosure>`, and from Atom `enclosure` links and JSON Feed
+/// attachments, which feed-rs keeps as links.
+pub(super) fn collect_enclosures(
+    feed_type: FeedType,
+    media: &[feedrs::MediaObject],
+    links: &[feedrs::Link],
+) -> Vec<Enclosure> {
+    let from_media = media.iter().flat_map(|object| {
+        object.content.iter().filter_map(|content| {
+            Some(Enclosure {
+                url: content.url.as_ref()?.to_string(),
+                mime_type: content.content_type.as_ref().map(ToString::to_string),
+                length: content.size,
+                duration_secs: content.duration.or(object.duration).map(|d| d.as_secs()),
+            })
+        })
+    });
+    let from_links = links
+        .iter()
+        .filter(|link| match feed_type {
+            // Attachments are the only JSON Feed item links with a media type.
+            FeedType::JSON => link.media_type.is_some(),
+            _ => link.rel.as_deref() == Some("enclosure"),
+        })
+        .map(|link| Enclosure {
+            url: link.href.clone(),
+            mime_type: link.media_type.clone(),
+            length: link.length,
+            duration_secs: None,
+        });
+
+    let mut enclosures: Vec<Enclosure> = Vec::new();
+    for enclosure in from_media.chain(from_links) {
+        if !enclosures.iter().any(|known| known.url == enclosure.url) {
+            enclosures.push(enclosure);
+        }
+    }
+    enclosures
+}
+
+pub(super) fn collect_thumbnails(media: &[feedrs::MediaObject]) -> Vec<Thumbnail> {
+    let mut thumbnails: Vec<Thumbnail> = Vec::new();
+    for thumbnail in media.iter().flat_map(|object| &object.thumbnails) {
+        let image = &thumbnail.image;
+        if image.uri.is_empty() || thumbnails.iter().any(|known| known.url == image.uri) {
+            continue;
+        }
+        thumbnails.push(Thumbnail {
+            url: image.uri.clone(),
+            width: image.width,
+            height: image.height,
+        });
+    }
+    thumbnails
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_rss_enclosure_with_itunes_duration() {
+        let media = vec![feedrs::MediaObject {
+            content: vec![feedrs::MediaContent {
+                url: Some("https://example.com/episode-1.mp3".parse().unwrap()),
+                content_type: Some("audio/mpeg".parse().unwrap()),
+                height: None,
+                width: None,
+                duration: None,
+                size: Some(1_024),
+                rating: None,
+            }],
+            duration: Some(Duration::from_mins(30)),
+            ..Default::default()
+        }];
+
+        let enclosures = collect_enclosures(FeedType::RSS2, &media, &[]);
+
+        assert_eq!(
+            enclosures,
+            vec![Enclosure {
+                url: "https://example.com/episode-1.mp3".into(),
+                mime_type: Some("audio/mpeg".into()),
+                length: Some(1_024),
+                duration_secs: Some(1_800),
+            }]
+        );
+    }
+
+    #[test]
+    fn collects_enclosure_links_once() {
+        let link = |href: &str, rel: Option<&str>, media_type: Option<&str>| feedrs::Link {
+            href: href.into(),
+            rel: rel.map(Into::into),
+            media_type: media_type.map(Into::into),
+            href_lang: None,
+            title: None,
+            length: None,
+        };
+        let links = vec![
+            link(
+                "https://example.com/entry",
+                Some("alternate"),
+                Some("text/html"),
+            ),
+            link(
+                "https://example.com/talk.mp4",
+                Some("enclosure"),
+                Some("video/mp4"),
+            ),
+            link(
+                "https://example.com/talk.mp4",
+                Some("enclosure"),
+                Some("video/mp4"),
+            ),
+        ];
+
+        let enclosures = collect_enclosures(FeedType::Atom, &[], &links);
+
+        assert_eq!(enclosures.len(), 1);
+        assert_eq!(enclosures[0].url(), "https://example.com/talk.mp4");
+        assert_eq!(enclosures[0].mime_type(), Some("video/mp4"));
+
+        let json_links = vec![
+            link("https://example.com/entry", None, None),
+            link("https://example.com/talk.mp4", None, Some("video/mp4")),
+        ];
+        let enclosures = collect_enclosures(FeedType::JSON, &[], &json_links);
+
+        assert_eq!(enclosures.len(), 1);
+        assert_eq!(enclosures[0].url(), "https://example.com/talk.mp4");
+    }
+}
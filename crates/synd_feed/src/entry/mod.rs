@@ -8,6 +8,9 @@ pub use content::Content;
 mod entry;
 pub use entry::Entry;
 
+mod media;
+pub use media::{Enclosure, Thumbnail};
+
 mod id;
 pub(crate) use id::feed_rs_missing_id_marker;
 pub use id::{EntryId, EntryIdError};
@@ -60,6 +60,8 @@ impl SyndEntry {
 
         Ok(if &self.entry == observed {
             SyndEntryDiff::Unchanged
+        } else if self.entry.is_backfilled_by(observed) {
+            SyndEntryDiff::Backfilled
         } else {
             SyndEntryDiff::EntryChanged
         })
@@ -70,6 +72,9 @@ impl SyndEntry {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SyndEntryDiff {
     Unchanged,
+    /// Only fields stored entries predate went from empty to set. The entry
+    /// takes them without being reported as changed.
+    Backfilled,
     EntryChanged,
 }
 
@@ -154,6 +159,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn diff_backfills_fields_missing_from_a_stored_entry() {
+        let current = Entry::builder().id(entry_id('a')).build();
+        let synd_entry = SyndEntry::builder()
+            .entry(current)
+            .order_key(EntryOrderKey::from_datetime(time("2026-07-20T12:00:00Z")))
+            .build();
+        let tagged = Entry::builder()
+            .id(entry_id('a'))
+            .tags(vec!["rust".to_owned()])
+            .build();
+        let tagged_and_updated = Entry::builder()
+            .id(entry_id('a'))
+            .updated(time("2026-07-21T12:00:00Z"))
+            .tags(vec!["rust".to_owned()])
+            .build();
+
+        assert_eq!(
+            synd_entry.compute_diff(&tagged),
+            Ok(SyndEntryDiff::Backfilled)
+        );
+        assert_eq!(
+            synd_entry.compute_diff(&tagged_and_updated),
+            Ok(SyndEntryDiff::EntryChanged)
+        );
+    }
+
+    #[test]
+    fn diff_detects_changed_tags() {
+        let current = Entry::builder()
+            .id(entry_id('a'))
+            .tags(vec!["rust".to_owned()])
+            .build();
+        let synd_entry = SyndEntry::builder()
+            .entry(current)
+            .order_key(EntryOrderKey::from_datetime(time("2026-07-20T12:00:00Z")))
+            .build();
+        let observed = Entry::builder()
+            .id(entry_id('a'))
+            .tags(vec!["async".to_owned()])
+            .build();
+
+        assert_eq!(
+            synd_entry.compute_diff(&observed),
+            Ok(SyndEntryDiff::EntryChanged)
+        );
+    }
+
     #[test]
     fn synd_entry_diff_rejects_different_entry_ids() {
         let current_id = entry_id('a');
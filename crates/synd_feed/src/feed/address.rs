@@ -0,0 +1,159 @@
+use std::{
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+};
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use url::{Host, Url};
+
+/// Fetch refused because its target is not a public internet address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ForbiddenAddress {
+    host: String,
+}
+
+impl ForbiddenAddress {
+    fn new(host: impl Into<String>) -> Self {
+        Self { host: host.into() }
+    }
+
+    /// Whether the error or any of its sources is a refused address.
+    pub(crate) fn is_cause_of(err: &(dyn std::error::Error + 'static)) -> bool {
+        let mut source = Some(err);
+        while let Some(err) = source {
+            if err.is::<Self>() {
+                return true;
+            }
+            source = err.source();
+        }
+        false
+    }
+}
+
+impl fmt::Display for ForbiddenAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not a public address", self.host)
+    }
+}
+
+impl std::error::Error for ForbiddenAddress {}
+
+/// Rejects URLs whose host is an IP literal outside the public internet.
+///
+/// Host names are not resolved here; [`PublicResolver`] filters their
+/// addresses when the connection is made.
+pub(crate) fn check_url(url: &Url) -> Result<(), ForbiddenAddress> {
+    let ip = match url.host() {
+        Some(Host::Ipv4(ip)) => IpAddr::V4(ip),
+        Some(Host::Ipv6(ip)) => IpAddr::V6(ip),
+        Some(Host::Domain(_)) => return Ok(()),
+        None => return Err(ForbiddenAddress::new(url.as_str())),
+    };
+    if is_public(ip) {
+        Ok(())
+    } else {
+        Err(ForbiddenAddress::new(ip.to_string()))
+    }
+}
+
+/// Resolver that drops loopback, private, link-local, and other non-public
+/// addresses, so a host name cannot lead a fetch into the daemon's network.
+pub(crate) struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| is_public(addr.ip()))
+                .collect::<Vec<SocketAddr>>();
+            if addrs.is_empty() {
+                return Err(ForbiddenAddress::new(name.as_str()).into());
+            }
+            let addrs: Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_v4(ip),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // "this network", shared address space, benchmarking, and reserved
+        || a == 0
+        || (a == 100 && (64..128).contains(&b))
+        || (a == 198 && (18..20).contains(&b))
+        || a >= 240)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let [a, b, ..] = ip.segments();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        || ip.is_unique_local()
+        || ip.is_unicast_link_local()
+        // documentation
+        || (a == 0x2001 && b == 0x0db8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_public_ip_literals_are_rejected() {
+        for url in [
+            "http://127.0.0.1/feed",
+            "http://10.0.0.1/feed",
+            "http://172.16.0.1/feed",
+            "http://192.168.1.1/feed",
+            "http://169.254.169.254/latest/meta-data",
+            "http://100.64.0.1/feed",
+            "http://0.0.0.0/feed",
+            "http://[::1]/feed",
+            "http://[fd00::1]/feed",
+            "http://[fe80::1]/feed",
+            "http://[::ffff:127.0.0.1]/feed",
+        ] {
+            assert!(check_url(&Url::parse(url).unwrap()).is_err(), "{url}");
+        }
+    }
+
+    #[test]
+    fn public_addresses_and_host_names_are_allowed() {
+        for url in [
+            "https://93.184.215.14/feed",
+            "https://[2606:4700::1111]/feed",
+            "https://example.com/feed",
+        ] {
+            assert!(check_url(&Url::parse(url).unwrap()).is_ok(), "{url}");
+        }
+    }
+
+    #[tokio::test]
+    async fn resolver_drops_loopback_addresses() {
+        let err = PublicResolver
+            .resolve("localhost".parse().unwrap())
+            .await
+            .err()
+            .unwrap();
+        assert!(ForbiddenAddress::is_cause_of(err.as_ref()));
+    }
+}
@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+
+use dom_query::{Document, NodeId, NodeRef};
+use url::Url;
+
+/// Elements that never carry article text.
+const NOISE_SELECTOR: &str = "script, style, noscript, template, iframe, object, embed, form, \
+    button, input, select, textarea, nav, aside, footer, svg, canvas, dialog";
+
+/// Elements whose text is scored as prose.
+const PARAGRAPH_SELECTOR: &str = "p, pre, td, blockquote";
+
+/// `class`/`id` fragments of page chrome around the article.
+const UNLIKELY_NAMES: &[&str] = &[
+    "ad-",
+    "banner",
+    "breadcrumb",
+    "comment",
+    "cookie",
+    "disqus",
+    "footer",
+    "menu",
+    "modal",
+    "nav",
+    "newsletter",
+    "popup",
+    "related",
+    "share",
+    "sidebar",
+    "social",
+    "sponsor",
+    "subscribe",
+];
+
+/// `class`/`id` fragments of the article itself.
+const LIKELY_NAMES: &[&str] = &[
+    "article", "body", "content", "entry", "hentry", "main", "page", "post", "story", "text",
+];
+
+/// Attributes kept on the extracted elements.
+const KEPT_ATTRIBUTES: &[&str] = &["href", "src", "alt", "title", "colspan", "rowspan"];
+
+/// Paragraphs shorter than this are captions, bylines and the like.
+const MIN_PARAGRAPH_CHARS: usize = 25;
+
+/// Best candidates with less text are not worth replacing the feed content.
+const MIN_ARTICLE_CHARS: usize = 250;
+
+/// Extracts the main article of an HTML page as an HTML fragment.
+///
+/// Follows the Readability approach: page chrome is dropped, every
+/// paragraph scores its parent and grandparent by length and commas, and
+/// the container with the best score, discounted by how much of its text
+/// is links, is the article. Links and images are resolved against the
+/// page so the fragment stands on its own. Pages without enough prose in
+/// one place, such as index pages, yield `None`.
+pub(crate) fn extract(document: &[u8], page_url: &Url) -> Option<String> {
+    let document = Document::from(String::from_utf8_lossy(document).as_ref());
+    let base = document
+        .base_uri()
+        .and_then(|base| page_url.join(&base).ok())
+        .unwrap_or_else(|| page_url.clone());
+
+    document.select(NOISE_SELECTOR).remove();
+    for node in document.select("[class], [id]").nodes() {
+        if is_unlikely(node) {
+            node.remove_from_parent();
+        }
+    }
+
+    let article = top_candidate(&document)?;
+    if article.normalized_char_count() < MIN_ARTICLE_CHARS {
+        return None;
+    }
+    clean(&article, &base);
+    Some(article.html().to_string())
+}
+
+fn is_unlikely(node: &NodeRef<'_>) -> bool {
+    if matches!(
+        node.node_name().as_deref(),
+        Some("html" | "body" | "article" | "main")
+    ) {
+        return false;
+    }
+    let names = names_of(node);
+    UNLIKELY_NAMES.iter().any(|name| names.contains(name))
+        && !LIKELY_NAMES.iter().any(|name| names.contains(name))
+}
+
+/// Lowercased `class` and `id` of the element.
+fn names_of(node: &NodeRef<'_>) -> String {
+    let mut names = node
+        .class()
+        .map(|class| class.to_string())
+        .unwrap_or_default();
+    if let Some(id) = node.id_attr() {
+        names.push(' ');
+        names.push_str(&id);
+    }
+    names.to_ascii_lowercase()
+}
+
+/// Container whose paragraphs score best once link text is discounted.
+fn top_candidate(document: &Document) -> Option<NodeRef<'_>> {
+    let mut candidates: HashMap<NodeId, (NodeRef<'_>, i64)> = HashMap::new();
+    for paragraph in document.select(PARAGRAPH_SELECTOR).nodes() {
+        let text = paragraph.text();
+        let chars = text.trim().chars().count();
+        if chars < MIN_PARAGRAPH_CHARS {
+            continue;
+        }
+        let commas = text.matches([',', '、', '，']).count();
+        let score = to_i64(1 + commas + (chars / 100).min(3));
+
+        let parent = paragraph.parent().filter(NodeRef::is_element);
+        let grandparent = parent
+            .and_then(|parent| parent.parent())
+            .filter(NodeRef::is_element);
+        for (ancestor, score) in [(parent, score), (grandparent, score / 2)] {
+            let Some(ancestor) = ancestor else {
+                continue;
+            };
+            candidates
+                .entry(ancestor.id)
+                .or_insert_with(|| (ancestor, initial_score(&ancestor)))
+                .1 += score;
+        }
+    }
+
+    candidates
+        .into_values()
+        .map(|(node, score)| (node, discount_links(&node, score)))
+        .max_by_key(|(_, score)| *score)
+        .map(|(node, _)| node)
+}
+
+/// Bias of a candidate by its tag and naming, before paragraphs are counted.
+fn initial_score(node: &NodeRef<'_>) -> i64 {
+    let tag = match node.node_name().as_deref() {
+        Some("article") => 10,
+        Some("div") => 5,
+        Some("pre" | "td" | "blockquote") => 3,
+        Some("address" | "ol" | "ul" | "dl" | "dd" | "dt" | "li" | "form") => -3,
+        Some("h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "th") => -5,
+        _ => 0,
+    };
+    let names = names_of(node);
+    let naming = if LIKELY_NAMES.iter().any(|name| names.contains(name)) {
+        25
+    } else if UNLIKELY_NAMES.iter().any(|name| names.contains(name)) {
+        -25
+    } else {
+        0
+    };
+    tag + naming
+}
+
+/// Scales the score by the share of the text that is not link text, so
+/// link lists lose to prose.
+fn discount_links(node: &NodeRef<'_>, score: i64) -> i64 {
+    let chars = node.normalized_char_count();
+    if chars == 0 {
+        return score;
+    }
+    let link_chars = node
+        .descendants_it()
+        .filter(|descendant| descendant.has_name("a"))
+        .map(|link| link.normalized_char_count())
+        .sum::<usize>()
+        .min(chars);
+    score * to_i64(chars - link_chars) / to_i64(chars)
+}
+
+/// Strips presentation attributes and resolves links and image sources.
+fn clean(article: &NodeRef<'_>, base: &Url) {
+    // The tree is borrowed while iterating, so elements are edited afterwards.
+    let elements = article
+        .descendants_it()
+        .filter(NodeRef::is_element)
+        .collect::<Vec<_>>();
+    for node in elements {
+        for attr in ["href", "src"] {
+            if let Some(url) = node
+                .attr(attr)
+                .and_then(|value| base.join(value.trim()).ok())
+            {
+                node.set_attr(attr, url.as_str());
+            }
+        }
+        node.retain_attrs(KEPT_ATTRIBUTES);
+    }
+    article.retain_attrs(&[]);
+}
+
+fn to_i64(value: usize) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page_url() -> Url {
+        Url::parse("https://blog.example.com/posts/hello/").unwrap()
+    }
+
+    fn prose(sentences: usize) -> String {
+        "Readers want the whole story, not only the teaser, so the article is fetched. "
+            .repeat(sentences)
+    }
+
+    #[test]
+    fn extract_picks_the_article_over_page_chrome() {
+        let document = format!(
+            r#"<html>
+<head><script>track();</script></head>
+<body>
+  <nav><a href="/">Home</a><a href="/about">About</a></nav>
+  <div class="sidebar"><p>{sidebar}</p></div>
+  <div id="main" class="post-content" style="color: red">
+    <h1>Hello</h1>
+    <p>{first}</p>
+    <p>See <a href="../other/" onclick="x()">the other post</a>, and <img src="img/a.png" alt="A">.</p>
+    <p>{second}</p>
+  </div>
+  <div class="related-links">
+    <p><a href="/a">Some related post with a long enough title</a></p>
+  </div>
+  <footer><p>{footer}</p></footer>
+</body>
+</html>"#,
+            sidebar = prose(2),
+            first = prose(3),
+            second = prose(2),
+            footer = prose(4),
+        );
+
+        let article = extract(document.as_bytes(), &page_url()).unwrap();
+
+        assert!(article.starts_with("<div>"), "{article}");
+        assert!(article.contains("<h1>Hello</h1>"));
+        assert!(article.contains(r#"<a href="https://blog.example.com/posts/other/">"#));
+        assert!(article.contains(r#"src="https://blog.example.com/posts/hello/img/a.png""#));
+        assert!(!article.contains("onclick"));
+        assert!(!article.contains("track()"));
+        assert!(!article.contains("About"));
+        assert!(!article.contains("related post"));
+        assert_eq!(article.matches(&prose(1)).count(), 5);
+    }
+
+    #[test]
+    fn extract_resolves_against_base() {
+        let document = format!(
+            r#"<head><base href="https://static.example.com/site/"></head>
+<body><article><p>{body}</p><p><a href="next.html">Next</a></p></article></body>"#,
+            body = prose(4),
+        );
+
+        let article = extract(document.as_bytes(), &page_url()).unwrap();
+
+        assert!(article.contains(r#"href="https://static.example.com/site/next.html""#));
+    }
+
+    #[test]
+    fn extract_gives_up_without_enough_prose() {
+        let index = r#"<body><ul>
+  <li><a href="/1">First post</a></li>
+  <li><a href="/2">Second post</a></li>
+</ul><p>Short intro to the blog.</p></body>"#;
+
+        assert_eq!(extract(index.as_bytes(), &page_url()), None);
+        assert_eq!(extract(b"", &page_url()), None);
+    }
+}
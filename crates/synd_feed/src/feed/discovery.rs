@@ -0,0 +1,409 @@
+use std::fmt;
+
+use url::Url;
+
+use crate::types::{FeedType, FeedUrl};
+
+/// Paths probed on the site root when a page advertises no feed.
+pub(crate) const WELL_KNOWN_PATHS: &[&str] = &[
+    "/feed",
+    "/feed.xml",
+    "/atom.xml",
+    "/rss.xml",
+    "/index.xml",
+    "/feed.json",
+];
+
+/// Format a discovered feed is expected to be served in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FeedCandidateKind {
+    Atom,
+    Rss,
+    Json,
+}
+
+impl FeedCandidateKind {
+    /// Maps the `type` of a `<link rel="alternate">` to a feed format.
+    fn from_media_type(media_type: &str) -> Option<Self> {
+        let essence = media_type.split(';').next().unwrap_or_default().trim();
+        match essence.to_ascii_lowercase().as_str() {
+            "application/atom+xml" => Some(Self::Atom),
+            "application/rss+xml" | "application/rdf+xml" => Some(Self::Rss),
+            "application/feed+json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Atom => "atom",
+            Self::Rss => "rss",
+            Self::Json => "json",
+        }
+    }
+}
+
+impl From<FeedType> for FeedCandidateKind {
+    fn from(feed_type: FeedType) -> Self {
+        match feed_type {
+            FeedType::Atom => Self::Atom,
+            FeedType::JSON => Self::Json,
+            FeedType::RSS0 | FeedType::RSS1 | FeedType::RSS2 => Self::Rss,
+        }
+    }
+}
+
+impl fmt::Display for FeedCandidateKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Where a candidate was found. Declared in ranking order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FeedCandidateSource {
+    /// The requested URL is a feed itself.
+    Requested,
+    /// Advertised by the page with `<link rel="alternate">`.
+    LinkAlternate,
+    /// Found by probing a well-known path such as `/feed`.
+    WellKnownPath,
+}
+
+impl FeedCandidateSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Requested => "requested",
+            Self::LinkAlternate => "link_alternate",
+            Self::WellKnownPath => "well_known_path",
+        }
+    }
+}
+
+impl fmt::Display for FeedCandidateSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Feed found while discovering feeds from a URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedCandidate {
+    pub url: FeedUrl,
+    pub kind: FeedCandidateKind,
+    pub title: Option<String>,
+    pub source: FeedCandidateSource,
+}
+
+impl FeedCandidate {
+    /// Comment feeds are rarely what a subscriber is after.
+    fn is_comments(&self) -> bool {
+        let mentions_comments = |value: &str| value.to_ascii_lowercase().contains("comments");
+        self.title.as_deref().is_some_and(mentions_comments) || mentions_comments(self.url.as_str())
+    }
+}
+
+/// Orders candidates best first and drops duplicated URLs.
+///
+/// Where a candidate was found matters most, then comment feeds go after
+/// the others, then Atom is preferred over RSS over JSON Feed. Otherwise
+/// the order in which the page listed them is kept.
+pub(crate) fn rank(mut candidates: Vec<FeedCandidate>) -> Vec<FeedCandidate> {
+    candidates.sort_by_key(|candidate| (candidate.source, candidate.is_comments(), candidate.kind));
+    let mut ranked: Vec<FeedCandidate> = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        if !ranked.iter().any(|ranked| ranked.url == candidate.url) {
+            ranked.push(candidate);
+        }
+    }
+    ranked
+}
+
+/// Collects the feeds an HTML page advertises with `<link rel="alternate">`.
+///
+/// HTML is not XML, so this is a lenient tag scanner rather than a parser:
+/// only `<link>` and `<base>` tags are read, comments are skipped, and
+/// relative `href`s are resolved against `<base href>` or the page URL.
+pub(crate) fn scan_html(document: &[u8], page_url: &Url) -> Vec<FeedCandidate> {
+    let document = String::from_utf8_lossy(document);
+    let mut base = page_url.clone();
+    let mut candidates = Vec::new();
+    let mut rest = document.as_ref();
+
+    while let Some(open) = rest.find('<') {
+        rest = &rest[open + 1..];
+        if let Some(comment) = rest.strip_prefix("!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let name_len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(rest.len());
+        let name = &rest[..name_len];
+        let Some(tag) = Tag::parse(&rest[name_len..]) else {
+            continue;
+        };
+        rest = tag.rest;
+
+        if name.eq_ignore_ascii_case("script") || name.eq_ignore_ascii_case("style") {
+            rest = skip_raw_text(rest, name);
+        } else if name.eq_ignore_ascii_case("base") {
+            if let Some(href) = tag.attr("href").and_then(|href| page_url.join(&href).ok()) {
+                base = href;
+            }
+        } else if name.eq_ignore_ascii_case("link") {
+            if let Some(candidate) = tag.link_candidate(&base) {
+                candidates.push(candidate);
+            }
+        } else if name.eq_ignore_ascii_case("body") {
+            // Feeds are advertised in the head.
+            break;
+        }
+    }
+
+    candidates
+}
+
+/// Skips the raw text of a `<script>` or `<style>` element, whose `<` do
+/// not open tags.
+fn skip_raw_text<'a>(s: &'a str, name: &str) -> &'a str {
+    let close = format!("</{}", name.to_ascii_lowercase());
+    s.to_ascii_lowercase()
+        .find(&close)
+        .map_or("", |end| &s[end..])
+}
+
+/// Attributes of one start tag.
+struct Tag<'a> {
+    attrs: Vec<(&'a str, Option<&'a str>)>,
+    rest: &'a str,
+}
+
+impl<'a> Tag<'a> {
+    /// Reads attributes up to the closing `>`. Returns `None` for an
+    /// unterminated tag or quote.
+    fn parse(mut s: &'a str) -> Option<Self> {
+        let mut attrs = Vec::new();
+        loop {
+            s = s.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+            if let Some(rest) = s.strip_prefix('>') {
+                return Some(Self { attrs, rest });
+            }
+            if s.is_empty() {
+                return None;
+            }
+            let name_len = s
+                .find(|c: char| c.is_ascii_whitespace() || matches!(c, '=' | '>' | '/'))
+                .unwrap_or(s.len());
+            let name = &s[..name_len];
+            s = s[name_len..].trim_start();
+            let Some(value) = s.strip_prefix('=') else {
+                attrs.push((name, None));
+                continue;
+            };
+            let value = value.trim_start();
+            let (value, rest) = if let Some(quote @ ('"' | '\'')) = value.chars().next() {
+                let value = &value[1..];
+                let end = value.find(quote)?;
+                (&value[..end], &value[end + 1..])
+            } else {
+                let end = value
+                    .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                    .unwrap_or(value.len());
+                (&value[..end], &value[end..])
+            };
+            attrs.push((name, Some(value)));
+            s = rest;
+        }
+    }
+
+    fn attr(&self, name: &str) -> Option<String> {
+        self.attrs
+            .iter()
+            .find(|(attr, _)| attr.eq_ignore_ascii_case(name))
+            .and_then(|(_, value)| *value)
+            .map(decode_entities)
+    }
+
+    fn link_candidate(&self, base: &Url) -> Option<FeedCandidate> {
+        let rel = self.attr("rel")?;
+        let mut rel = rel.split_ascii_whitespace();
+        if !rel.any(|rel| rel.eq_ignore_ascii_case("alternate")) {
+            return None;
+        }
+        let kind = FeedCandidateKind::from_media_type(&self.attr("type")?)?;
+        let url = base.join(self.attr("href")?.trim()).ok()?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return None;
+        }
+
+        Some(FeedCandidate {
+            url: FeedUrl::from(url),
+            kind,
+            title: self
+                .attr("title")
+                .map(|title| title.trim().to_owned())
+                .filter(|title| !title.is_empty()),
+            source: FeedCandidateSource::LinkAlternate,
+        })
+    }
+}
+
+/// Decodes the character references that show up in `href` and `title`.
+fn decode_entities(value: &str) -> String {
+    if !value.contains('&') {
+        return value.to_owned();
+    }
+    value
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page_url() -> Url {
+        Url::parse("https://blog.example.com/posts/hello/").unwrap()
+    }
+
+    fn candidate(
+        url: &str,
+        kind: FeedCandidateKind,
+        title: Option<&str>,
+        source: FeedCandidateSource,
+    ) -> FeedCandidate {
+        FeedCandidate {
+            url: FeedUrl::parse(url).unwrap(),
+            kind,
+            title: title.map(ToOwned::to_owned),
+            source,
+        }
+    }
+
+    #[test]
+    fn scan_html_collects_alternate_feed_links() {
+        let document = br#"<!DOCTYPE html>
+<html>
+<head>
+  <!-- <link rel="alternate" type="application/rss+xml" href="/commented.xml"> -->
+  <link rel="stylesheet" type="text/css" href="/style.css">
+  <LINK REL="Alternate" TYPE="application/atom+xml" TITLE="Example &amp; Co" HREF="/atom.xml" />
+  <link rel=alternate type=application/rss+xml href=../../rss.xml>
+  <link rel="alternate" type="application/feed+json" href='https://cdn.example.com/feed.json'>
+  <link rel="alternate" type="text/html" hreflang="ja" href="/ja/">
+  <link rel="alternate" type="application/rss+xml" href="javascript:void(0)">
+</head>
+<body>
+  <link rel="alternate" type="application/rss+xml" href="/body.xml">
+</body>
+</html>"#;
+
+        assert_eq!(
+            scan_html(document, &page_url()),
+            [
+                candidate(
+                    "https://blog.example.com/atom.xml",
+                    FeedCandidateKind::Atom,
+                    Some("Example & Co"),
+                    FeedCandidateSource::LinkAlternate,
+                ),
+                candidate(
+                    "https://blog.example.com/rss.xml",
+                    FeedCandidateKind::Rss,
+                    None,
+                    FeedCandidateSource::LinkAlternate,
+                ),
+                candidate(
+                    "https://cdn.example.com/feed.json",
+                    FeedCandidateKind::Json,
+                    None,
+                    FeedCandidateSource::LinkAlternate,
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn scan_html_resolves_links_against_base() {
+        let document = br#"<head>
+  <base href="https://static.example.com/site/">
+  <link rel="alternate" type="application/rss+xml; charset=utf-8" href="index.xml">
+</head>"#;
+
+        assert_eq!(
+            scan_html(document, &page_url()),
+            [candidate(
+                "https://static.example.com/site/index.xml",
+                FeedCandidateKind::Rss,
+                None,
+                FeedCandidateSource::LinkAlternate,
+            )]
+        );
+    }
+
+    #[test]
+    fn scan_html_tolerates_unterminated_tags() {
+        let document = br#"<head>
+  <script>if (a < b && "<link") { run(); }</script>
+  <link rel="alternate" type="application/atom+xml" href="/a">
+  <link rel="#;
+
+        assert_eq!(scan_html(document, &page_url()).len(), 1);
+        assert!(scan_html(b"not html at all", &page_url()).is_empty());
+    }
+
+    #[test]
+    fn rank_prefers_source_then_non_comment_atom() {
+        let ranked = rank(vec![
+            candidate(
+                "https://example.com/feed",
+                FeedCandidateKind::Rss,
+                None,
+                FeedCandidateSource::WellKnownPath,
+            ),
+            candidate(
+                "https://example.com/comments/feed",
+                FeedCandidateKind::Atom,
+                Some("Comments Feed"),
+                FeedCandidateSource::LinkAlternate,
+            ),
+            candidate(
+                "https://example.com/rss.xml",
+                FeedCandidateKind::Rss,
+                Some("Posts"),
+                FeedCandidateSource::LinkAlternate,
+            ),
+            candidate(
+                "https://example.com/atom.xml",
+                FeedCandidateKind::Atom,
+                Some("Posts"),
+                FeedCandidateSource::LinkAlternate,
+            ),
+            candidate(
+                "https://example.com/atom.xml",
+                FeedCandidateKind::Atom,
+                None,
+                FeedCandidateSource::WellKnownPath,
+            ),
+        ]);
+
+        assert_eq!(
+            ranked
+                .iter()
+                .map(|candidate| candidate.url.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "https://example.com/atom.xml",
+                "https://example.com/rss.xml",
+                "https://example.com/comments/feed",
+                "https://example.com/feed",
+            ]
+        );
+    }
+}
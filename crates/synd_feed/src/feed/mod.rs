@@ -1 +1,4 @@
+mod address;
+pub mod article;
+pub mod discovery;
 pub mod service;
@@ -1,10 +1,17 @@
-use std::{fmt, sync::Arc, time::Duration};
+use std::{borrow::Borrow, fmt, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use feed_rs::parser::{ParseFeedError, Parser};
 
-use crate::types::{Feed, FeedUrl};
+use crate::{
+    feed::{
+        address::{self, ForbiddenAddress, PublicResolver},
+        article,
+        discovery::{self, FeedCandidate, FeedCandidateKind, FeedCandidateSource},
+    },
+    types::{Feed, FeedUrl},
+};
 
 pub type FetchFeedResult<T> = std::result::Result<T, FetchFeedError>;
 pub type FeedParseResult<T> = std::result::Result<T, FeedParseError>;
@@ -26,11 +33,54 @@ pub enum FetchFeedError {
     Parse(#[from] FeedParseError),
 }
 
+/// Failure to read the page feeds were to be discovered from.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum FeedDiscoveryError {
+    #[error("fetch failed: {0}")]
+    Fetch(FeedFetchFailure),
+    #[error("body read failed: {0}")]
+    BodyRead(FeedFetchFailure),
+    #[error("unexpected http status: {0}")]
+    UnexpectedStatus(FeedHttpStatus),
+}
+
+/// Failure to read the main article of an entry's web page.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ArticleFetchError {
+    #[error("fetch failed: {0}")]
+    Fetch(FeedFetchFailure),
+    #[error("body read failed: {0}")]
+    BodyRead(FeedFetchFailure),
+    #[error("unexpected http status: {0}")]
+    UnexpectedStatus(FeedHttpStatus),
+    #[error("not an html page: {0}")]
+    NotHtml(String),
+    #[error("no article found in the page")]
+    NoArticle,
+}
+
+impl ArticleFetchError {
+    /// Whether fetching the same page again later may succeed.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Fetch(failure) => failure.kind != FeedFetchFailureKind::Forbidden,
+            Self::BodyRead(failure) => failure.kind != FeedFetchFailureKind::TooLarge,
+            Self::UnexpectedStatus(status) => matches!(status.as_u16(), 408 | 429 | 500..=599),
+            Self::NotHtml(_) | Self::NoArticle => false,
+        }
+    }
+}
+
 #[async_trait]
 pub trait FetchFeed: Send + Sync {
     async fn fetch_feed(&self, request: FeedFetchRequest) -> FeedFetchOutcome;
 }
 
+#[async_trait]
+pub trait FetchArticle: Send + Sync {
+    async fn fetch_article(&self, url: FeedUrl) -> Result<String, ArticleFetchError>;
+}
+
 /// Request for fetching one feed URL.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FeedFetchRequest {
@@ -215,7 +265,9 @@ pub struct FeedFetchFailure {
 
 impl FeedFetchFailure {
     fn from_reqwest(err: &reqwest::Error) -> Self {
-        let kind = if err.is_timeout() {
+        let kind = if ForbiddenAddress::is_cause_of(err) {
+            FeedFetchFailureKind::Forbidden
+        } else if err.is_timeout() {
             FeedFetchFailureKind::Timeout
         } else if err.is_connect() {
             FeedFetchFailureKind::Connect
@@ -233,6 +285,13 @@ impl FeedFetchFailure {
         }
     }
 
+    fn forbidden(err: &ForbiddenAddress) -> Self {
+        Self {
+            kind: FeedFetchFailureKind::Forbidden,
+            message: err.to_string(),
+        }
+    }
+
     fn too_large(limit: usize) -> Self {
         Self {
             kind: FeedFetchFailureKind::TooLarge,
@@ -258,6 +317,8 @@ pub enum FeedFetchFailureKind {
     Body,
     TooLarge,
     Unsupported,
+    /// The target is not a public internet address.
+    Forbidden,
     Other,
 }
 
@@ -270,6 +331,7 @@ impl FeedFetchFailureKind {
             Self::Body => "body",
             Self::TooLarge => "too_large",
             Self::Unsupported => "unsupported",
+            Self::Forbidden => "forbidden",
             Self::Other => "other",
         }
     }
@@ -388,11 +450,22 @@ where
     }
 }
 
+#[async_trait]
+impl<T> FetchArticle for Arc<T>
+where
+    T: FetchArticle,
+{
+    async fn fetch_article(&self, url: FeedUrl) -> Result<String, ArticleFetchError> {
+        self.as_ref().fetch_article(url).await
+    }
+}
+
 /// Feed Process entry point.
 #[derive(Clone)]
 pub struct FeedService {
     http: reqwest::Client,
     buff_limit: usize,
+    public_only: bool,
 }
 
 #[async_trait]
@@ -402,16 +475,57 @@ impl FetchFeed for FeedService {
     }
 }
 
+#[async_trait]
+impl FetchArticle for FeedService {
+    async fn fetch_article(&self, url: FeedUrl) -> Result<String, ArticleFetchError> {
+        FeedService::fetch_article(self, url).await
+    }
+}
+
 impl FeedService {
     pub fn new(user_agent: &str, buff_limit: usize) -> Self {
-        let http = reqwest::ClientBuilder::new()
-            .user_agent(user_agent)
-            .timeout(Duration::from_secs(10))
-            .connect_timeout(Duration::from_secs(10))
+        let http = Self::client_builder(user_agent).build().unwrap();
+
+        Self {
+            http,
+            buff_limit,
+            public_only: false,
+        }
+    }
+
+    /// Like [`FeedService::new`], but refuses to fetch from loopback, private,
+    /// link-local, and other non-public addresses, whether the URL names one
+    /// directly, its host resolves to one, or a redirect leads to one.
+    pub fn public_only(user_agent: &str, buff_limit: usize) -> Self {
+        const MAX_REDIRECTS: usize = 10;
+
+        let redirect = reqwest::redirect::Policy::custom(|attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                attempt.error("too many redirects")
+            } else if let Err(err) = address::check_url(attempt.url()) {
+                attempt.error(err)
+            } else {
+                attempt.follow()
+            }
+        });
+        let http = Self::client_builder(user_agent)
+            .dns_resolver(Arc::new(PublicResolver))
+            .redirect(redirect)
             .build()
             .unwrap();
 
-        Self { http, buff_limit }
+        Self {
+            http,
+            buff_limit,
+            public_only: true,
+        }
+    }
+
+    fn client_builder(user_agent: &str) -> reqwest::ClientBuilder {
+        reqwest::ClientBuilder::new()
+            .user_agent(user_agent)
+            .timeout(Duration::from_secs(10))
+            .connect_timeout(Duration::from_secs(10))
     }
 
     pub async fn fetch_feed(&self, request: FeedFetchRequest) -> FeedFetchOutcome {
@@ -436,6 +550,12 @@ impl FeedService {
     pub async fn fetch_body(&self, request: FeedFetchRequest) -> FeedBodyFetchOutcome {
         use futures_util::StreamExt;
 
+        if self.public_only
+            && let Err(err) = address::check_url(request.url.borrow())
+        {
+            return FeedBodyFetchOutcome::FetchFailed(FeedFetchFailure::forbidden(&err));
+        }
+
         let mut request_builder = self.http.get(request.url.clone().into_inner());
         if let Some(etag) = &request.conditional.etag {
             request_builder = request_builder.header(reqwest::header::IF_NONE_MATCH, etag);
@@ -515,6 +635,113 @@ impl FeedService {
             .map(|fetched| fetched.feed)
     }
 
+    /// Finds the feeds a URL leads to, best candidate first.
+    ///
+    /// A URL that already serves a feed is its own only candidate. Otherwise
+    /// the page is scanned for `<link rel="alternate">` feeds, and when it
+    /// advertises none, well-known paths like `/feed` on the site root are
+    /// probed. Advertised feeds are not fetched; probed ones are kept only
+    /// when they parse.
+    pub async fn discover_feeds(
+        &self,
+        url: FeedUrl,
+    ) -> Result<Vec<FeedCandidate>, FeedDiscoveryError> {
+        let body = match self.fetch_body(FeedFetchRequest::new(url)).await {
+            FeedBodyFetchOutcome::Fetched(body) if body.response.is_success() => body,
+            FeedBodyFetchOutcome::Fetched(body) => {
+                return Err(FeedDiscoveryError::UnexpectedStatus(body.response.status));
+            }
+            FeedBodyFetchOutcome::NotModified(response) => {
+                return Err(FeedDiscoveryError::UnexpectedStatus(response.status));
+            }
+            FeedBodyFetchOutcome::BodyReadFailed(failure) => {
+                return Err(FeedDiscoveryError::BodyRead(failure.failure));
+            }
+            FeedBodyFetchOutcome::FetchFailed(failure) => {
+                return Err(FeedDiscoveryError::Fetch(failure));
+            }
+        };
+
+        let requested_url = body.response.requested_url.clone();
+        if let Ok(feed) = self.parse(requested_url.clone(), body.bytes.as_slice()) {
+            return Ok(vec![Self::parsed_candidate(
+                requested_url,
+                &feed,
+                FeedCandidateSource::Requested,
+            )]);
+        }
+
+        let page_url = body.response.response_url.into_inner();
+        let advertised = discovery::scan_html(&body.bytes, &page_url);
+        if !advertised.is_empty() {
+            return Ok(discovery::rank(advertised));
+        }
+
+        let probes = discovery::WELL_KNOWN_PATHS
+            .iter()
+            .filter_map(|path| page_url.join(path).ok())
+            .map(|url| async move {
+                let url = FeedUrl::from(url);
+                match self.fetch_feed(FeedFetchRequest::new(url.clone())).await {
+                    FeedFetchOutcome::Fetched(fetched) => Some(Self::parsed_candidate(
+                        url,
+                        &fetched.feed,
+                        FeedCandidateSource::WellKnownPath,
+                    )),
+                    _ => None,
+                }
+            });
+        let found = futures_util::future::join_all(probes)
+            .await
+            .into_iter()
+            .flatten()
+            .collect();
+
+        Ok(discovery::rank(found))
+    }
+
+    /// Fetches an entry's web page and extracts its main article as an HTML
+    /// fragment.
+    pub async fn fetch_article(&self, url: FeedUrl) -> Result<String, ArticleFetchError> {
+        let body = match self.fetch_body(FeedFetchRequest::new(url)).await {
+            FeedBodyFetchOutcome::Fetched(body) if body.response.is_success() => body,
+            FeedBodyFetchOutcome::Fetched(body) => {
+                return Err(ArticleFetchError::UnexpectedStatus(body.response.status));
+            }
+            FeedBodyFetchOutcome::NotModified(response) => {
+                return Err(ArticleFetchError::UnexpectedStatus(response.status));
+            }
+            FeedBodyFetchOutcome::BodyReadFailed(failure) => {
+                return Err(ArticleFetchError::BodyRead(failure.failure));
+            }
+            FeedBodyFetchOutcome::FetchFailed(failure) => {
+                return Err(ArticleFetchError::Fetch(failure));
+            }
+        };
+
+        if let Some(content_type) = body
+            .response
+            .headers
+            .content_type
+            .as_deref()
+            .filter(|content_type| !content_type.to_ascii_lowercase().contains("html"))
+        {
+            return Err(ArticleFetchError::NotHtml(content_type.to_owned()));
+        }
+
+        let page_url = body.response.response_url.into_inner();
+        article::extract(&body.bytes, &page_url).ok_or(ArticleFetchError::NoArticle)
+    }
+
+    fn parsed_candidate(url: FeedUrl, feed: &Feed, source: FeedCandidateSource) -> FeedCandidate {
+        FeedCandidate {
+            url,
+            kind: FeedCandidateKind::from(feed.meta().r#type()),
+            title: feed.meta().title().map(|title| title.content().to_owned()),
+            source,
+        }
+    }
+
     pub fn parse<S>(&self, url: FeedUrl, source: S) -> FeedParseResult<Feed>
     where
         S: std::io::Read,
@@ -523,15 +750,22 @@ impl FeedService {
     }
 
     /// Parses a feed document without performing an HTTP fetch.
-    pub fn parse_feed<S>(url: FeedUrl, source: S) -> FeedParseResult<Feed>
+    pub fn parse_feed<S>(url: FeedUrl, mut source: S) -> FeedParseResult<Feed>
     where
         S: std::io::Read,
     {
+        let mut document = Vec::new();
+        source
+            .read_to_end(&mut document)
+            .map_err(|err| FeedParseError {
+                kind: FeedParseErrorKind::Io,
+                message: err.to_string(),
+            })?;
         let parser = Self::build_parser(&url);
 
         parser
-            .parse(source)
-            .map(|feed| Feed::from_feed_rs(url, feed))
+            .parse(document.as_slice())
+            .map(|feed| Feed::from_feed_rs(url, feed, &document))
             .map_err(FeedParseError::from)
     }
 
@@ -578,6 +812,20 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn public_only_refuses_non_public_targets() {
+        let service = FeedService::public_only("synd-test", 1024);
+        for url in ["http://127.0.0.1:1/feed", "http://localhost:1/feed"] {
+            let outcome = service
+                .fetch_body(FeedFetchRequest::new(FeedUrl::parse(url).unwrap()))
+                .await;
+            let FeedBodyFetchOutcome::FetchFailed(failure) = outcome else {
+                panic!("{url} should not be fetched: {outcome:?}");
+            };
+            assert_eq!(failure.kind, FeedFetchFailureKind::Forbidden, "{url}");
+        }
+    }
+
     #[test]
     fn rss2_entry_updated_uses_item_pub_date() {
         let service = FeedService::new("synd-test", 1024);
@@ -0,0 +1,309 @@
+use std::time::Duration;
+
+use bon::Builder;
+use chrono::{Datelike, DurationRound, Timelike, Weekday};
+use quick_xml::{
+    NsReader,
+    events::Event,
+    name::{Namespace, ResolveResult},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::types::Time;
+
+const SYNDICATION_NS: &[u8] = b"http://purl.org/rss/1.0/modules/syndication/";
+
+/// Publisher hints on how often a feed is worth polling.
+///
+/// Collected from RSS `<ttl>`, `<skipHours>` and `<skipDays>`, and from the
+/// RSS 1.0 Syndication module's `sy:updatePeriod` and `sy:updateFrequency`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "snake_case")]
+pub struct UpdateHints {
+    ttl_minutes: Option<u32>,
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    skip_hours: Vec<u8>,
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    skip_days: Vec<Weekday>,
+    update_period: Option<UpdatePeriod>,
+    update_frequency: Option<u32>,
+}
+
+/// Period unit of the Syndication module's `sy:updatePeriod`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdatePeriod {
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl UpdatePeriod {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "hourly" => Some(Self::Hourly),
+            "daily" => Some(Self::Daily),
+            "weekly" => Some(Self::Weekly),
+            "monthly" => Some(Self::Monthly),
+            "yearly" => Some(Self::Yearly),
+            _ => None,
+        }
+    }
+
+    fn duration(self) -> Duration {
+        match self {
+            Self::Hourly => Duration::from_hours(1),
+            Self::Daily => Duration::from_hours(24),
+            Self::Weekly => Duration::from_hours(24 * 7),
+            Self::Monthly => Duration::from_hours(24 * 30),
+            Self::Yearly => Duration::from_hours(24 * 365),
+        }
+    }
+}
+
+impl UpdateHints {
+    /// Returns the RSS `<ttl>`: how long the feed may be cached.
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl_minutes
+            .map(|minutes| Duration::from_mins(u64::from(minutes)))
+    }
+
+    /// Returns the UTC hours (0-23) during which the feed is not updated.
+    pub fn skip_hours(&self) -> &[u8] {
+        &self.skip_hours
+    }
+
+    /// Returns the UTC weekdays on which the feed is not updated.
+    pub fn skip_days(&self) -> &[Weekday] {
+        &self.skip_days
+    }
+
+    /// Returns the declared `sy:updatePeriod`.
+    pub fn update_period(&self) -> Option<UpdatePeriod> {
+        self.update_period
+    }
+
+    /// Returns the declared `sy:updateFrequency`.
+    pub fn update_frequency(&self) -> Option<u32> {
+        self.update_frequency
+    }
+
+    /// Returns whether the feed declares no hint at all.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Returns the interval the publisher asks pollers not to undercut: the
+    /// longer of `<ttl>` and the Syndication module's update interval.
+    ///
+    /// The Syndication module defaults a missing period to daily and a
+    /// missing frequency to once per period.
+    pub fn min_interval(&self) -> Option<Duration> {
+        let syndication =
+            (self.update_period.is_some() || self.update_frequency.is_some()).then(|| {
+                let period = self.update_period.unwrap_or(UpdatePeriod::Daily);
+                period.duration() / self.update_frequency.unwrap_or(1).max(1)
+            });
+        self.ttl().max(syndication)
+    }
+
+    /// Returns the first instant at or after `at` that falls outside the
+    /// skipped hours and days. Hints that skip every hour are ignored.
+    pub fn next_allowed(&self, at: Time) -> Time {
+        if self.skip_hours.is_empty() && self.skip_days.is_empty() {
+            return at;
+        }
+        let mut candidate = at;
+        // One week of hours covers every combination of skipped hours and days.
+        for _ in 0..=24 * 7 {
+            if !self.is_skipped(candidate) {
+                return candidate;
+            }
+            candidate = candidate
+                .duration_trunc(chrono::Duration::hours(1))
+                .map_or(candidate, |hour| hour + chrono::Duration::hours(1));
+        }
+        at
+    }
+
+    fn is_skipped(&self, at: Time) -> bool {
+        u8::try_from(at.hour()).is_ok_and(|hour| self.skip_hours.contains(&hour))
+            || self.skip_days.contains(&at.weekday())
+    }
+
+    /// Scans a feed document for the hints feed-rs does not expose.
+    ///
+    /// Only `<skipHours>`, `<skipDays>` and the Syndication module elements
+    /// are collected here; malformed documents yield whatever was read before
+    /// the error, since the feed parser reports the error itself.
+    pub(crate) fn scan(document: &[u8], ttl_minutes: Option<u32>) -> Self {
+        let mut hints = Self {
+            ttl_minutes,
+            ..Self::default()
+        };
+        let mut reader = NsReader::from_reader(document);
+        let mut buf = Vec::new();
+        let mut path: Vec<(bool, Vec<u8>)> = Vec::new();
+
+        loop {
+            match reader.read_resolved_event_into(&mut buf) {
+                Ok((ns, Event::Start(element))) => {
+                    let syndication =
+                        matches!(ns, ResolveResult::Bound(Namespace(ns)) if ns == SYNDICATION_NS);
+                    path.push((syndication, element.local_name().as_ref().to_vec()));
+                }
+                Ok((_, Event::End(_))) => {
+                    path.pop();
+                }
+                Ok((_, Event::Text(text))) => {
+                    if let Ok(text) = text.decode() {
+                        hints.apply(&path, text.trim());
+                    }
+                }
+                Ok((_, Event::Eof)) | Err(_) => break,
+                Ok(_) => {}
+            }
+            buf.clear();
+        }
+
+        hints.skip_hours.sort_unstable();
+        hints.skip_hours.dedup();
+        hints
+            .skip_days
+            .sort_unstable_by_key(Weekday::num_days_from_monday);
+        hints.skip_days.dedup();
+        hints
+    }
+
+    fn apply(&mut self, path: &[(bool, Vec<u8>)], text: &str) {
+        let Some(((syndication, element), parents)) = path.split_last() else {
+            return;
+        };
+        let parent = parents.last().map(|(_, parent)| parent.as_slice());
+        match (*syndication, element.as_slice(), parent) {
+            (true, b"updatePeriod", _) => {
+                self.update_period = UpdatePeriod::parse(text).or(self.update_period);
+            }
+            (true, b"updateFrequency", _) => {
+                self.update_frequency = text
+                    .parse()
+                    .ok()
+                    .filter(|frequency| *frequency > 0)
+                    .or(self.update_frequency);
+            }
+            (false, b"hour", Some(b"skipHours")) => {
+                // Some publishers write midnight as 24.
+                if let Ok(hour @ 0..=24) = text.parse::<u8>() {
+                    self.skip_hours.push(hour % 24);
+                }
+            }
+            (false, b"day", Some(b"skipDays")) => {
+                if let Ok(day) = text.parse::<Weekday>() {
+                    self.skip_days.push(day);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn rss(channel: &str) -> Vec<u8> {
+        format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<rss version="2.0" xmlns:sy="http://purl.org/rss/1.0/modules/syndication/">
+  <channel>
+    <title>example</title>
+    {channel}
+    <item><title>entry</title><guid>entry-1</guid></item>
+  </channel>
+</rss>"#
+        )
+        .into_bytes()
+    }
+
+    fn at(day: u32, hour: u32) -> Time {
+        // 2026-06-01 is a Monday.
+        chrono::Utc
+            .with_ymd_and_hms(2026, 6, day, hour, 30, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn scan_collects_skip_hours_and_days() {
+        let hints = UpdateHints::scan(
+            &rss(
+                "<skipHours><hour>3</hour><hour>1</hour><hour>24</hour></skipHours>
+                 <skipDays><day>Sunday</day><day>Saturday</day></skipDays>",
+            ),
+            None,
+        );
+
+        assert_eq!(hints.skip_hours(), [0, 1, 3]);
+        assert_eq!(hints.skip_days(), [Weekday::Sat, Weekday::Sun]);
+    }
+
+    #[test]
+    fn scan_collects_syndication_module_by_namespace() {
+        let hints = UpdateHints::scan(
+            &rss(
+                "<sy:updatePeriod>hourly</sy:updatePeriod><sy:updateFrequency>2</sy:updateFrequency>",
+            ),
+            None,
+        );
+
+        assert_eq!(hints.update_period(), Some(UpdatePeriod::Hourly));
+        assert_eq!(hints.update_frequency(), Some(2));
+        assert_eq!(hints.min_interval(), Some(Duration::from_mins(30)));
+    }
+
+    #[test]
+    fn scan_ignores_unprefixed_update_period() {
+        let hints = UpdateHints::scan(&rss("<updatePeriod>weekly</updatePeriod>"), None);
+
+        assert!(hints.is_empty());
+    }
+
+    #[test]
+    fn min_interval_prefers_the_longer_hint() {
+        let hints = UpdateHints::builder()
+            .ttl_minutes(180)
+            .update_period(UpdatePeriod::Hourly)
+            .build();
+
+        assert_eq!(hints.min_interval(), Some(Duration::from_hours(3)));
+        assert_eq!(UpdateHints::default().min_interval(), None);
+    }
+
+    #[test]
+    fn next_allowed_skips_hours_and_days() {
+        let hints = UpdateHints::builder()
+            .skip_hours(vec![22, 23])
+            .skip_days(vec![Weekday::Tue])
+            .build();
+
+        // Monday 10:30 is allowed as is.
+        assert_eq!(hints.next_allowed(at(1, 10)), at(1, 10));
+        // Monday 22:30 -> Tuesday is skipped -> Wednesday 00:00.
+        assert_eq!(
+            hints.next_allowed(at(1, 22)),
+            chrono::Utc.with_ymd_and_hms(2026, 6, 3, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn next_allowed_ignores_hints_skipping_everything() {
+        let hints = UpdateHints::builder().skip_hours((0..24).collect()).build();
+
+        assert_eq!(hints.next_allowed(at(1, 10)), at(1, 10));
+    }
+}
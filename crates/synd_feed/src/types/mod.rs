@@ -22,6 +22,9 @@ pub use url::FeedUrl;
 mod feed_type;
 pub use feed_type::FeedType;
 
+mod hints;
+pub use hints::{UpdateHints, UpdatePeriod};
+
 /// Text content with its media type and optional source URI.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Builder)]
 #[serde(rename_all = "snake_case")]
@@ -202,6 +205,9 @@ pub struct FeedMeta {
     links: Vec<Link>,
     generator: Option<Generator>,
     published: Option<Time>,
+    #[builder(default)]
+    #[serde(default)]
+    update_hints: UpdateHints,
 }
 
 #[derive(Debug, Clone)]
@@ -276,6 +282,11 @@ impl FeedMeta {
     pub fn generator(&self) -> Option<&Generator> {
         self.generator.as_ref()
     }
+
+    /// Returns the publisher's hints on how often to poll the feed.
+    pub fn update_hints(&self) -> &UpdateHints {
+        &self.update_hints
+    }
 }
 
 impl<'a> From<&'a FeedMeta> for Cow<'a, FeedMeta> {
@@ -302,7 +313,9 @@ impl Feed {
         Self { meta, entries }
     }
 
-    pub(crate) fn from_feed_rs(url: FeedUrl, feed: feedrs::Feed) -> Self {
+    /// Converts a parsed feed; `document` is the source it was parsed from,
+    /// scanned for the update hints feed-rs does not expose.
+    pub(crate) fn from_feed_rs(url: FeedUrl, feed: feedrs::Feed, document: &[u8]) -> Self {
         let feed_rs::model::Feed {
             feed_type,
             title,
@@ -312,6 +325,7 @@ impl Feed {
             links,
             generator,
             published,
+            ttl,
             entries,
             ..
         } = feed;
@@ -340,6 +354,7 @@ impl Feed {
             links: links.into_iter().map(Into::into).collect(),
             generator: generator.map(Into::into),
             published,
+            update_hints: UpdateHints::scan(document, ttl),
         };
         Self::new(meta, entries)
     }
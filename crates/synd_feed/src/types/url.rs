@@ -64,6 +64,10 @@ impl FeedUrl {
         self.0.as_str()
     }
 
+    pub fn host(&self) -> Option<&str> {
+        self.0.host_str()
+    }
+
     pub fn parse(input: &str) -> Result<Self, url::ParseError> {
         Url::parse(input).map(FeedUrl)
     }
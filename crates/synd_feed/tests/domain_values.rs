@@ -1,6 +1,6 @@
 use synd_feed::{
-    entry::{Content, Entry, EntryId},
-    types::{Feed, FeedMeta, FeedType, FeedUrl, Generator, Link, Person, Text},
+    entry::{Content, Enclosure, Entry, EntryId, Thumbnail},
+    types::{Feed, FeedMeta, FeedType, FeedUrl, Generator, Link, Person, Text, UpdateHints},
 };
 
 #[test]
@@ -28,6 +28,17 @@ fn domain_values_are_publicly_constructible() {
         .length(10)
         .src(link.clone())
         .build();
+    let enclosure = Enclosure::builder()
+        .url("https://example.com/entry.mp3".to_owned())
+        .mime_type("audio/mpeg".to_owned())
+        .length(1024)
+        .duration_secs(90)
+        .build();
+    let thumbnail = Thumbnail::builder()
+        .url("https://example.com/entry.jpg".to_owned())
+        .width(640)
+        .height(360)
+        .build();
     let entry = Entry::builder()
         .id(EntryId::parse(format!("synd:entry:v1:{}", "a".repeat(64))).unwrap())
         .title(title.clone())
@@ -35,6 +46,9 @@ fn domain_values_are_publicly_constructible() {
         .content(content.clone())
         .links(vec![link.clone()])
         .summary(title.clone())
+        .tags(vec!["rust".to_owned()])
+        .enclosures(vec![enclosure])
+        .thumbnails(vec![thumbnail])
         .build();
     let generator = Generator::builder()
         .content("synd test generator".to_owned())
@@ -48,6 +62,7 @@ fn domain_values_are_publicly_constructible() {
         .authors(vec![author.clone()])
         .links(vec![link.clone()])
         .generator(generator)
+        .update_hints(UpdateHints::builder().ttl_minutes(60).build())
         .build();
     let feed = Feed::new(meta, vec![entry.clone()]);
 
@@ -56,8 +71,16 @@ fn domain_values_are_publicly_constructible() {
     assert_eq!(link.href(), "https://example.com/entry");
 
     assert_eq!(entry.website_url(FeedType::Atom), Some(link.href()));
+    assert_eq!(
+        entry.enclosures()[0].duration(),
+        Some(std::time::Duration::from_secs(90))
+    );
     assert_eq!(feed.meta().title().map(Text::content), Some("Entry title"));
     assert_eq!(feed.entries().next(), Some(&entry));
+    assert_eq!(
+        feed.meta().update_hints().ttl(),
+        Some(std::time::Duration::from_hours(1))
+    );
 
     let json = serde_json::to_string(&entry).unwrap();
     let decoded = serde_json::from_str::<Entry>(&json).unwrap();
@@ -0,0 +1,12 @@
+-- Declaration: entries a subscriber has read. Row existence means read;
+-- marking an entry unread deletes the row. Kept apart from timeline_entry so
+-- projecting membership never touches what the subscriber declared.
+CREATE TABLE entry_read (
+    subscriber_id TEXT NOT NULL,
+    entry_id      TEXT NOT NULL,
+    read_at       DATETIME NOT NULL,
+
+    PRIMARY KEY (subscriber_id, entry_id),
+    FOREIGN KEY (subscriber_id, entry_id)
+        REFERENCES timeline_entry(subscriber_id, entry_id)
+);
@@ -0,0 +1,26 @@
+-- Observation log: one row per finished crawl job, appended by the crawl
+-- worker alongside crawl_state. Bounded per feed; the oldest rows are pruned
+-- on append.
+CREATE TABLE crawl_history (
+    pk           INTEGER PRIMARY KEY,
+    feed_pk      INTEGER NOT NULL,
+    job_id       TEXT NOT NULL,
+    job_trigger  TEXT NOT NULL, -- 'periodic_due' | 'manual_request' | 'retry_due'
+    started_at   DATETIME NOT NULL,
+    finished_at  DATETIME NOT NULL,
+    outcome      TEXT NOT NULL,
+    http_status  INTEGER,
+    error_kind   TEXT,
+    body_bytes   INTEGER,
+    -- Identity of the accepted body, compared against the next crawl to
+    -- derive body_changed. Deliberately not a foreign key: history does not
+    -- keep bodies alive against blob collection.
+    body_blob_pk INTEGER,
+    body_changed INTEGER NOT NULL,
+
+    FOREIGN KEY (feed_pk) REFERENCES feed(pk)
+);
+
+-- Newest-first reads and per-feed pruning.
+CREATE INDEX crawl_history_feed_pk_pk_idx
+    ON crawl_history (feed_pk, pk);
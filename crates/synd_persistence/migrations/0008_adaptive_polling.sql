@@ -0,0 +1,4 @@
+-- Consecutive crawls answered with 304 Not Modified. Adaptive polling
+-- stretches the interval of feeds that keep reporting no change.
+ALTER TABLE crawl_state
+    ADD COLUMN not_modified_streak INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,35 @@
+-- Stable integer key of an entry in the search index. entry has no INTEGER
+-- PRIMARY KEY, so its rowid may change on VACUUM and cannot address FTS rows.
+CREATE TABLE entry_search_doc (
+    pk       INTEGER PRIMARY KEY,
+    entry_id TEXT NOT NULL UNIQUE,
+
+    FOREIGN KEY (entry_id) REFERENCES entry(entry_id)
+);
+
+-- Derived: searchable text of each entry, rowid = entry_search_doc.pk.
+-- Rewritten by the entry projection together with entry_json, with markup
+-- stripped.
+CREATE VIRTUAL TABLE entry_search USING fts5(
+    title,
+    summary,
+    content,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+-- Entries stored before the index existed are indexed from their documents
+-- as is; the projection strips their markup the next time it writes them.
+INSERT INTO entry_search_doc (entry_id)
+SELECT entry_id
+FROM entry
+ORDER BY entry_id;
+
+INSERT INTO entry_search (rowid, title, summary, content)
+SELECT
+    d.pk,
+    COALESCE(json_extract(e.entry_json, '$.title.content'), ''),
+    COALESCE(json_extract(e.entry_json, '$.summary.content'), ''),
+    COALESCE(json_extract(e.entry_json, '$.content.body'), '')
+FROM entry_search_doc AS d
+INNER JOIN entry AS e
+    ON e.entry_id = d.entry_id;
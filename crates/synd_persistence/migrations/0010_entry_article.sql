@@ -0,0 +1,24 @@
+-- Whether the subscriber asked for the full article of entries whose feed
+-- only publishes a summary.
+ALTER TABLE feed_subscription
+    ADD COLUMN fetch_full_content INTEGER NOT NULL DEFAULT 0;
+
+-- Observation: the article extracted from the web page an entry links to.
+-- Queued when the entry is discovered for a feed someone wants full content
+-- of; next_attempt_at is cleared once the fetch succeeded or was given up.
+CREATE TABLE entry_article (
+    entry_id        TEXT PRIMARY KEY,
+    url             TEXT NOT NULL,
+    -- Failed attempts so far.
+    attempts        INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at DATETIME,
+    article_blob_pk INTEGER,
+    fetched_at      DATETIME,
+
+    FOREIGN KEY (entry_id) REFERENCES entry(entry_id),
+    FOREIGN KEY (article_blob_pk) REFERENCES blob(pk)
+);
+
+CREATE INDEX entry_article_next_attempt_at
+    ON entry_article(next_attempt_at)
+    WHERE next_attempt_at IS NOT NULL;
@@ -0,0 +1,17 @@
+-- Declaration: entries a subscriber saved. Row existence means saved;
+-- unsaving deletes the row. References entry rather than timeline_entry:
+-- a saved entry stays listed after the feed rotated it out of its body or
+-- the subscriber unsubscribed from the feed, and nothing that prunes
+-- entries may remove it while a row here points at it.
+CREATE TABLE entry_saved (
+    subscriber_id TEXT NOT NULL,
+    entry_id      TEXT NOT NULL,
+    saved_at      DATETIME NOT NULL,
+
+    PRIMARY KEY (subscriber_id, entry_id),
+    FOREIGN KEY (entry_id) REFERENCES entry(entry_id)
+);
+
+-- Saved view paging: keyset scan from the latest save.
+CREATE INDEX entry_saved_order_idx
+    ON entry_saved(subscriber_id, saved_at DESC, entry_id DESC);
@@ -0,0 +1,8 @@
+-- Filtered timeline listing: the subscriptions of one subscriber matching a
+-- requirement or category condition, resolved once per query before the
+-- keyset scan over timeline_entry_order_idx.
+CREATE INDEX feed_subscription_requirement_idx
+    ON feed_subscription(subscriber_id, requirement);
+
+CREATE INDEX feed_subscription_category_idx
+    ON feed_subscription(subscriber_id, category);
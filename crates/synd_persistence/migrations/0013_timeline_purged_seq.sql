@@ -0,0 +1,4 @@
+-- Highest seq of a tombstone purged from the timeline. Clients syncing
+-- changes from an older seq may have missed a removal and reload instead.
+ALTER TABLE timeline
+    ADD COLUMN purged_seq INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,19 @@
+-- Retention high-water mark of one feed in one timeline. Entries ordered at
+-- or before retired_through that are not live were pruned; catch-up and
+-- entry changes leave them out so they stay gone, even after their
+-- tombstones are purged.
+CREATE TABLE timeline_retired (
+    subscriber_id   TEXT NOT NULL,
+    feed_pk         INTEGER NOT NULL,
+    retired_through DATETIME NOT NULL,
+
+    PRIMARY KEY (subscriber_id, feed_pk),
+    FOREIGN KEY (subscriber_id) REFERENCES timeline(subscriber_id),
+    FOREIGN KEY (feed_pk)       REFERENCES feed(pk)
+);
+
+-- Age retention: range scan over live rows older than the cutoff across
+-- every timeline.
+CREATE INDEX timeline_entry_retire_idx
+    ON timeline_entry(order_time)
+    WHERE deleted = 0;
@@ -0,0 +1,10 @@
+-- Body blob a CrawlJobFinished event points at. Blob collection looks up
+-- journal references per blob, so the lookup has to be an index probe
+-- rather than a json_extract over the whole journal.
+ALTER TABLE event_journal
+    ADD COLUMN body_blob INTEGER
+    GENERATED ALWAYS AS (json_extract(payload_json, '$.body_blob')) VIRTUAL;
+
+CREATE INDEX event_journal_body_blob_idx
+    ON event_journal (body_blob)
+    WHERE body_blob IS NOT NULL;
@@ -0,0 +1,235 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Sqlite, Transaction};
+use synd_feed::{entry::EntryId, types::FeedUrl};
+use synd_registry::{
+    RegistryDbResult,
+    article::{ArticleFetch, ArticleFetchOutcome},
+    crawl::blob::BlobRef,
+    db::ArticleDb,
+    subscription::SubscriberId,
+};
+
+use super::error::{DecodeResultExt, IntoDbResult, SqliteError, SqliteResult};
+
+fn encode_fetches(fetches: &[ArticleFetch]) -> SqliteResult<String> {
+    let fetches = fetches
+        .iter()
+        .map(|fetch| (fetch.entry_id.as_str(), fetch.url.as_str()))
+        .collect::<Vec<_>>();
+    Ok(serde_json::to_string(&fetches)?)
+}
+
+async fn enqueue(
+    tx: &mut Transaction<'_, Sqlite>,
+    feed_url: &FeedUrl,
+    fetches: &[ArticleFetch],
+    queued_at: DateTime<Utc>,
+) -> SqliteResult<()> {
+    if fetches.is_empty() {
+        return Ok(());
+    }
+
+    // Entries already queued keep their progress; a fetched or abandoned
+    // article is not fetched again when the entry is rediscovered.
+    sqlx::query(
+        r#"
+            INSERT OR IGNORE INTO entry_article (entry_id, url, next_attempt_at)
+            SELECT
+                json_extract(value, '$[0]'),
+                json_extract(value, '$[1]'),
+                ?
+            FROM json_each(?)
+            WHERE EXISTS (
+                SELECT 1
+                FROM feed_subscription AS s
+                INNER JOIN feed AS f
+                    ON f.pk = s.feed_pk
+                WHERE f.url = ?
+                  AND s.fetch_full_content = 1
+            )
+            "#,
+    )
+    .bind(queued_at)
+    .bind(encode_fetches(fetches)?)
+    .bind(feed_url.as_str())
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+async fn load_due(
+    tx: &mut Transaction<'_, Sqlite>,
+    now: DateTime<Utc>,
+    limit: usize,
+) -> SqliteResult<Vec<ArticleFetch>> {
+    let rows = sqlx::query_as::<_, ArticleFetchRow>(
+        r#"
+            SELECT
+                entry_id,
+                url,
+                attempts
+            FROM entry_article
+            WHERE next_attempt_at IS NOT NULL
+              AND next_attempt_at <= ?
+            ORDER BY next_attempt_at, entry_id
+            LIMIT ?
+            "#,
+    )
+    .bind(now)
+    .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+    .fetch_all(&mut **tx)
+    .await?;
+
+    rows.into_iter().map(ArticleFetchRow::into_fetch).collect()
+}
+
+async fn next_attempt_at(tx: &mut Transaction<'_, Sqlite>) -> SqliteResult<Option<DateTime<Utc>>> {
+    let next = sqlx::query_scalar::<_, Option<DateTime<Utc>>>(
+        r#"
+            SELECT MIN(next_attempt_at)
+            FROM entry_article
+            WHERE next_attempt_at IS NOT NULL
+            "#,
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(next)
+}
+
+async fn record(
+    tx: &mut Transaction<'_, Sqlite>,
+    entry_id: &EntryId,
+    outcome: ArticleFetchOutcome,
+) -> SqliteResult<()> {
+    let query = match outcome {
+        ArticleFetchOutcome::Fetched {
+            article,
+            fetched_at,
+        } => sqlx::query(
+            r#"
+                UPDATE entry_article
+                SET next_attempt_at = NULL,
+                    article_blob_pk = ?,
+                    fetched_at = ?
+                WHERE entry_id = ?
+                "#,
+        )
+        .bind(article.pk())
+        .bind(fetched_at),
+        ArticleFetchOutcome::Retry {
+            attempts,
+            next_attempt_at,
+        } => sqlx::query(
+            r#"
+                UPDATE entry_article
+                SET attempts = ?,
+                    next_attempt_at = ?
+                WHERE entry_id = ?
+                "#,
+        )
+        .bind(i64::from(attempts))
+        .bind(next_attempt_at),
+        ArticleFetchOutcome::Abandoned { attempts } => sqlx::query(
+            r#"
+                UPDATE entry_article
+                SET attempts = ?,
+                    next_attempt_at = NULL
+                WHERE entry_id = ?
+                "#,
+        )
+        .bind(i64::from(attempts)),
+    };
+    query.bind(entry_id.as_str()).execute(&mut **tx).await?;
+    Ok(())
+}
+
+async fn load(
+    tx: &mut Transaction<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+    entry_id: &EntryId,
+) -> SqliteResult<Option<BlobRef>> {
+    let pk = sqlx::query_scalar::<_, Option<i64>>(
+        r#"
+            SELECT a.article_blob_pk
+            FROM entry_article AS a
+            WHERE a.entry_id = ?
+              AND EXISTS (
+                  SELECT 1
+                  FROM entry AS e
+                  INNER JOIN feed_subscription AS s
+                      ON s.feed_pk = e.feed_pk
+                  WHERE e.entry_id = a.entry_id
+                    AND s.subscriber_id = ?
+                    AND s.fetch_full_content = 1
+              )
+            "#,
+    )
+    .bind(entry_id.as_str())
+    .bind(subscriber_id.as_str())
+    .fetch_optional(&mut **tx)
+    .await?;
+    Ok(pk.flatten().map(BlobRef::new))
+}
+
+#[derive(sqlx::FromRow)]
+struct ArticleFetchRow {
+    entry_id: String,
+    url: String,
+    attempts: i64,
+}
+
+impl ArticleFetchRow {
+    fn into_fetch(self) -> SqliteResult<ArticleFetch> {
+        Ok(ArticleFetch {
+            entry_id: EntryId::parse(self.entry_id).decode()?,
+            url: FeedUrl::parse(&self.url).decode()?,
+            attempts: u32::try_from(self.attempts).map_err(|_| {
+                SqliteError::decode_message("article fetch attempts must be non-negative")
+            })?,
+        })
+    }
+}
+
+impl ArticleDb for super::SqliteRegistryTx<'_> {
+    async fn enqueue_article_fetches(
+        &mut self,
+        feed_url: &FeedUrl,
+        fetches: &[ArticleFetch],
+        queued_at: DateTime<Utc>,
+    ) -> RegistryDbResult<()> {
+        enqueue(&mut self.tx, feed_url, fetches, queued_at)
+            .await
+            .db()
+    }
+
+    async fn load_due_article_fetches(
+        &mut self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> RegistryDbResult<Vec<ArticleFetch>> {
+        load_due(&mut self.tx, now, limit).await.db()
+    }
+
+    async fn next_article_fetch_at(&mut self) -> RegistryDbResult<Option<DateTime<Utc>>> {
+        next_attempt_at(&mut self.tx).await.db()
+    }
+
+    async fn record_article_fetch(
+        &mut self,
+        entry_id: &EntryId,
+        outcome: ArticleFetchOutcome,
+    ) -> RegistryDbResult<()> {
+        record(&mut self.tx, entry_id, outcome).await.db()
+    }
+
+    async fn load_article(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+    ) -> RegistryDbResult<Option<BlobRef>> {
+        load(&mut self.tx, subscriber_id, entry_id).await.db()
+    }
+}
+
+#[cfg(test)]
+mod tests;
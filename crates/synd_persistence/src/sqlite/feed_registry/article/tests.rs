@@ -0,0 +1,172 @@
+use crate::sqlite::feed_registry::test_support::*;
+
+fn far_future() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap()
+}
+
+async fn subscribe_and_crawl(
+    db: &SqliteFeedRegistryDb,
+    path: &str,
+    fetch_full_content: bool,
+) -> anyhow::Result<()> {
+    store_subscription_in_db(
+        db,
+        Subscription {
+            fetch_full_content,
+            ..subscription(path)
+        },
+    )
+    .await?;
+    let event = record_fetched_crawl(
+        db,
+        &feed_url(path),
+        rss_body_with_entry(path, "Entry", &format!("{path}-1")),
+        0,
+    )
+    .await?;
+    project_feed(db, event).await?;
+    Ok(())
+}
+
+async fn load_due(
+    db: &SqliteFeedRegistryDb,
+    now: DateTime<Utc>,
+) -> anyhow::Result<Vec<ArticleFetch>> {
+    let mut tx = db.begin().await?;
+    let due = tx.load_due_article_fetches(now, 10).await?;
+    tx.commit().await?;
+    Ok(due)
+}
+
+#[tokio::test]
+async fn discovered_entries_are_queued_only_for_opted_in_feeds() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    subscribe_and_crawl(&db, "full", true).await?;
+    subscribe_and_crawl(&db, "teaser", false).await?;
+
+    let due = load_due(&db, far_future()).await?;
+
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].url.as_str(), "https://example.com/entry/full-1");
+    assert_eq!(due[0].attempts, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn recorded_outcomes_reschedule_and_store_the_article() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    subscribe_and_crawl(&db, "full", true).await?;
+    let fetch = load_due(&db, far_future()).await?.remove(0);
+    let retry_at = test_occurred_at() + chrono::Duration::minutes(10);
+
+    let mut tx = db.begin().await?;
+    tx.record_article_fetch(
+        &fetch.entry_id,
+        ArticleFetchOutcome::Retry {
+            attempts: 1,
+            next_attempt_at: retry_at,
+        },
+    )
+    .await?;
+    assert_eq!(tx.next_article_fetch_at().await?, Some(retry_at));
+    tx.commit().await?;
+
+    assert!(load_due(&db, test_occurred_at()).await?.is_empty());
+    let due = load_due(&db, retry_at).await?;
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].attempts, 1);
+
+    let mut tx = db.begin().await?;
+    let article = tx
+        .put_blob(PutBlobCommand::new(b"<p>article</p>".to_vec(), retry_at))
+        .await?;
+    tx.record_article_fetch(
+        &fetch.entry_id,
+        ArticleFetchOutcome::Fetched {
+            article,
+            fetched_at: retry_at,
+        },
+    )
+    .await?;
+
+    assert_eq!(
+        tx.load_article(&subscriber_id(), &fetch.entry_id).await?,
+        Some(article)
+    );
+    assert_eq!(tx.next_article_fetch_at().await?, None);
+    let collection = tx.delete_unreferenced_blobs(far_future(), 10).await?;
+    assert_eq!(collection.removed, 0);
+    assert_eq!(tx.load_blob(article).await?, b"<p>article</p>");
+    tx.commit().await?;
+
+    // Rediscovering the entry does not queue it again
+    let mut tx = db.begin().await?;
+    tx.enqueue_article_fetches(&feed_url("full"), &[fetch], test_occurred_at())
+        .await?;
+    tx.commit().await?;
+    assert!(load_due(&db, far_future()).await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn abandoned_fetches_are_not_due_anymore() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    subscribe_and_crawl(&db, "full", true).await?;
+    let fetch = load_due(&db, far_future()).await?.remove(0);
+
+    let mut tx = db.begin().await?;
+    tx.record_article_fetch(
+        &fetch.entry_id,
+        ArticleFetchOutcome::Abandoned { attempts: 3 },
+    )
+    .await?;
+    assert_eq!(
+        tx.load_article(&subscriber_id(), &fetch.entry_id).await?,
+        None
+    );
+    tx.commit().await?;
+
+    assert!(load_due(&db, far_future()).await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn articles_are_loaded_only_for_opted_in_subscribers() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    subscribe_and_crawl(&db, "full", true).await?;
+    let teaser = SubscriberId::new("teaser");
+    store_subscription_in_db(
+        &db,
+        subscription_with(
+            teaser.clone(),
+            "full",
+            CrawlPolicy::interval(interval(3600)),
+        ),
+    )
+    .await?;
+    let fetch = load_due(&db, far_future()).await?.remove(0);
+
+    let mut tx = db.begin().await?;
+    let article = tx
+        .put_blob(PutBlobCommand::new(
+            b"<p>article</p>".to_vec(),
+            test_occurred_at(),
+        ))
+        .await?;
+    tx.record_article_fetch(
+        &fetch.entry_id,
+        ArticleFetchOutcome::Fetched {
+            article,
+            fetched_at: test_occurred_at(),
+        },
+    )
+    .await?;
+
+    assert_eq!(
+        tx.load_article(&subscriber_id(), &fetch.entry_id).await?,
+        Some(article)
+    );
+    assert_eq!(tx.load_article(&teaser, &fetch.entry_id).await?, None);
+    tx.commit().await?;
+    Ok(())
+}
@@ -1,9 +1,11 @@
+use chrono::{DateTime, Utc};
 use sha2::{Digest, Sha256};
 use sqlx::{Sqlite, Transaction};
 use synd_registry::{
     RegistryDbResult,
-    crawl::blob::{BlobRef, PutBlobCommand},
+    crawl::blob::{BlobCollection, BlobRef, PutBlobCommand},
     db::BlobDb,
+    event::EventType,
 };
 
 use crate::compression::{
@@ -89,6 +91,56 @@ where
     )?)
 }
 
+const COLLECTABLE_SELECT: &str = r"
+SELECT blob.pk
+FROM blob
+WHERE blob.created_at < ?
+  AND NOT EXISTS (
+      SELECT 1 FROM feed_snapshot WHERE feed_snapshot.body_blob_pk = blob.pk
+  )
+  AND NOT EXISTS (
+      SELECT 1 FROM entry_article WHERE entry_article.article_blob_pk = blob.pk
+  )
+  AND NOT EXISTS (
+      SELECT 1
+      FROM event_journal
+      WHERE event_journal.body_blob = blob.pk
+        AND event_journal.event_type = ?
+  )
+";
+
+/// Deletes old blobs with no `feed_snapshot` or `entry_article` row and no
+/// journaled `CrawlJobFinished` event pointing at them. Row references are
+/// also enforced by foreign keys; the journal ones are only visible through
+/// the event payload, indexed as `event_journal.body_blob`.
+async fn delete_unreferenced(
+    tx: &mut Transaction<'_, Sqlite>,
+    created_before: DateTime<Utc>,
+    limit: usize,
+) -> SqliteResult<BlobCollection> {
+    let crawl_job_finished: &'static str = EventType::CrawlJobFinished.into();
+    let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+
+    let sql =
+        format!("DELETE FROM blob WHERE pk IN ({COLLECTABLE_SELECT} ORDER BY blob.pk LIMIT ?)");
+    let removed = sqlx::query(&sql)
+        .bind(created_before)
+        .bind(crawl_job_finished)
+        .bind(limit)
+        .execute(&mut **tx)
+        .await?
+        .rows_affected();
+
+    let sql = format!("SELECT EXISTS ({COLLECTABLE_SELECT})");
+    let has_more = sqlx::query_scalar::<_, bool>(&sql)
+        .bind(created_before)
+        .bind(crawl_job_finished)
+        .fetch_one(&mut **tx)
+        .await?;
+
+    Ok(BlobCollection { removed, has_more })
+}
+
 #[derive(sqlx::FromRow)]
 struct PkRow {
     pk: i64,
@@ -121,6 +173,16 @@ impl BlobDb for super::SqliteRegistryTx<'_> {
     async fn load_blob(&mut self, blob: BlobRef) -> RegistryDbResult<Vec<u8>> {
         load(&mut self.tx, DefaultCompressionCodec, blob).await.db()
     }
+
+    async fn delete_unreferenced_blobs(
+        &mut self,
+        created_before: DateTime<Utc>,
+        limit: usize,
+    ) -> RegistryDbResult<BlobCollection> {
+        delete_unreferenced(&mut self.tx, created_before, limit)
+            .await
+            .db()
+    }
 }
 
 #[cfg(test)]
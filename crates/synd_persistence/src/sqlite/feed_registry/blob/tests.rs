@@ -33,3 +33,90 @@ async fn blob_store_deduplicates_by_uncompressed_digest() -> anyhow::Result<()>
     tx.commit().await?;
     Ok(())
 }
+
+#[tokio::test]
+async fn collection_keeps_blobs_referenced_by_snapshot_or_journal() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let snapshot_event = record_fetched_crawl(
+        &db,
+        &feed_url("snapshot"),
+        rss_body_with_entry("Snapshot", "Entry", "snapshot-1"),
+        0,
+    )
+    .await?;
+    project_feed(&db, snapshot_event.clone()).await?;
+    let journal_event = record_fetched_crawl(
+        &db,
+        &feed_url("journal"),
+        rss_body_with_entry("Journal", "Entry", "journal-1"),
+        1,
+    )
+    .await?;
+    let created_at = Utc.with_ymd_and_hms(2026, 6, 7, 12, 0, 0).unwrap();
+    let mut tx = db.begin().await?;
+    let mut recorded = RecordedEvents::empty();
+    EventRecorder::new(&mut tx, &mut recorded, &TestClock(test_occurred_at()))
+        .record(journal_event.clone())
+        .await?;
+    let orphan = tx
+        .put_blob(PutBlobCommand::new(b"orphan".to_vec(), created_at))
+        .await?;
+    tx.commit().await?;
+
+    let mut tx = db.begin().await?;
+    let collection = tx
+        .delete_unreferenced_blobs(Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap(), 10)
+        .await?;
+
+    assert_eq!(
+        collection,
+        BlobCollection {
+            removed: 1,
+            has_more: false,
+        }
+    );
+    assert!(tx.load_blob(orphan).await.is_err());
+    assert!(
+        tx.load_blob(snapshot_event.body_blob.unwrap())
+            .await
+            .is_ok()
+    );
+    assert!(tx.load_blob(journal_event.body_blob.unwrap()).await.is_ok());
+    tx.commit().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn collection_respects_margin_and_limit() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let old = Utc.with_ymd_and_hms(2026, 6, 7, 12, 0, 0).unwrap();
+    let recent = old + chrono::Duration::days(1);
+    let mut tx = db.begin().await?;
+    for body in [b"first".as_slice(), b"second".as_slice()] {
+        tx.put_blob(PutBlobCommand::new(body.to_vec(), old)).await?;
+    }
+    let fresh = tx
+        .put_blob(PutBlobCommand::new(b"fresh".to_vec(), recent))
+        .await?;
+
+    let first = tx.delete_unreferenced_blobs(recent, 1).await?;
+    let second = tx.delete_unreferenced_blobs(recent, 1).await?;
+
+    assert_eq!(
+        first,
+        BlobCollection {
+            removed: 1,
+            has_more: true,
+        }
+    );
+    assert_eq!(
+        second,
+        BlobCollection {
+            removed: 1,
+            has_more: false,
+        }
+    );
+    assert_eq!(tx.load_blob(fresh).await?, b"fresh");
+    tx.commit().await?;
+    Ok(())
+}
@@ -1,9 +1,11 @@
 use synd_feed::feed::service::{FeedFetchFailureKind, FeedParseErrorKind};
 use synd_feed::{
     entry::{Entry, EntryId},
-    types::{FeedMeta, FeedUrl},
+    types::{FeedMeta, FeedUrl, UpdateHints},
 };
 use synd_registry::crawl::{
+    history::CrawlOutcome,
+    job::CrawlJobTrigger,
     policy::CrawlPolicy,
     state::{CrawlHttpErrorKind, CrawlStateErrorKind},
 };
@@ -54,6 +56,11 @@ pub(super) fn decode_stored_feed_meta(feed_url: &str, meta_json: &str) -> Sqlite
     Ok(meta)
 }
 
+/// Decodes the `update_hints` object extracted from a stored feed meta.
+pub(super) fn decode_update_hints_json(hints_json: &str) -> SqliteResult<UpdateHints> {
+    Ok(serde_json::from_str(hints_json)?)
+}
+
 pub(super) fn encode_crawl_state_error_kind(kind: CrawlStateErrorKind) -> String {
     match kind {
         CrawlStateErrorKind::Fetch(kind) => format!("fetch_{}", kind.as_str()),
@@ -83,6 +90,7 @@ pub(super) fn decode_fetch_failure_kind(value: &str) -> SqliteResult<FeedFetchFa
         "body" => Ok(FeedFetchFailureKind::Body),
         "too_large" => Ok(FeedFetchFailureKind::TooLarge),
         "unsupported" => Ok(FeedFetchFailureKind::Unsupported),
+        "forbidden" => Ok(FeedFetchFailureKind::Forbidden),
         "other" => Ok(FeedFetchFailureKind::Other),
         value => Err(unknown_value("feed fetch failure kind", value)),
     }
@@ -112,6 +120,27 @@ fn decode_http_error_kind(value: &str) -> SqliteResult<CrawlHttpErrorKind> {
     }
 }
 
+pub(super) fn decode_crawl_job_trigger(value: &str) -> SqliteResult<CrawlJobTrigger> {
+    match value {
+        "periodic_due" => Ok(CrawlJobTrigger::PeriodicDue),
+        "manual_request" => Ok(CrawlJobTrigger::ManualRequest),
+        "retry_due" => Ok(CrawlJobTrigger::RetryDue),
+        value => Err(unknown_value("crawl job trigger", value)),
+    }
+}
+
+pub(super) fn decode_crawl_outcome(value: &str) -> SqliteResult<CrawlOutcome> {
+    match value {
+        "fetched" => Ok(CrawlOutcome::Fetched),
+        "not_modified" => Ok(CrawlOutcome::NotModified),
+        "unexpected_status" => Ok(CrawlOutcome::UnexpectedStatus),
+        "body_read_failed" => Ok(CrawlOutcome::BodyReadFailed),
+        "fetch_failed" => Ok(CrawlOutcome::FetchFailed),
+        "parse_failed" => Ok(CrawlOutcome::ParseFailed),
+        value => Err(unknown_value("crawl outcome", value)),
+    }
+}
+
 fn unknown_value(field: &'static str, value: &str) -> SqliteError {
     SqliteError::decode_message(format!("unknown {field}: {value}"))
 }
@@ -119,9 +148,16 @@ fn unknown_value(field: &'static str, value: &str) -> SqliteError {
 #[cfg(test)]
 mod tests {
     use synd_feed::feed::service::{FeedFetchFailureKind, FeedParseErrorKind};
-    use synd_registry::crawl::state::{CrawlHttpErrorKind, CrawlStateErrorKind};
+    use synd_registry::crawl::{
+        history::CrawlOutcome,
+        job::CrawlJobTrigger,
+        state::{CrawlHttpErrorKind, CrawlStateErrorKind},
+    };
 
-    use super::{decode_crawl_state_error_kind, encode_crawl_state_error_kind};
+    use super::{
+        decode_crawl_job_trigger, decode_crawl_outcome, decode_crawl_state_error_kind,
+        encode_crawl_state_error_kind,
+    };
 
     #[test]
     fn crawl_state_error_kind_round_trips() {
@@ -132,6 +168,7 @@ mod tests {
             CrawlStateErrorKind::Fetch(FeedFetchFailureKind::Body),
             CrawlStateErrorKind::Fetch(FeedFetchFailureKind::TooLarge),
             CrawlStateErrorKind::Fetch(FeedFetchFailureKind::Unsupported),
+            CrawlStateErrorKind::Fetch(FeedFetchFailureKind::Forbidden),
             CrawlStateErrorKind::Fetch(FeedFetchFailureKind::Other),
             CrawlStateErrorKind::Http(CrawlHttpErrorKind::RateLimited),
             CrawlStateErrorKind::Http(CrawlHttpErrorKind::Unavailable),
@@ -152,4 +189,25 @@ mod tests {
             assert_eq!(decode_crawl_state_error_kind(&encoded).unwrap(), case);
         }
     }
+
+    #[test]
+    fn crawl_history_labels_round_trip() {
+        for trigger in [
+            CrawlJobTrigger::PeriodicDue,
+            CrawlJobTrigger::ManualRequest,
+            CrawlJobTrigger::RetryDue,
+        ] {
+            assert_eq!(decode_crawl_job_trigger(trigger.as_str()).unwrap(), trigger);
+        }
+        for outcome in [
+            CrawlOutcome::Fetched,
+            CrawlOutcome::NotModified,
+            CrawlOutcome::UnexpectedStatus,
+            CrawlOutcome::BodyReadFailed,
+            CrawlOutcome::FetchFailed,
+            CrawlOutcome::ParseFailed,
+        ] {
+            assert_eq!(decode_crawl_outcome(outcome.as_str()).unwrap(), outcome);
+        }
+    }
 }
@@ -0,0 +1,223 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Sqlite, Transaction};
+use synd_feed::{feed::service::FeedHttpStatus, types::FeedUrl};
+use synd_registry::{
+    RegistryDbResult,
+    crawl::{
+        blob::BlobRef,
+        history::{AppendCrawlHistoryCommand, CrawlHistoryEntry, CrawlHistoryQuery},
+        job::CrawlJobId,
+    },
+    db::CrawlHistoryDb,
+};
+
+use super::super::{
+    SqliteRegistryTx, codec,
+    error::{IntoDbResult, SqliteError, SqliteResult},
+    feed,
+};
+
+async fn load_last_body(
+    tx: &mut Transaction<'_, Sqlite>,
+    feed_url: &FeedUrl,
+) -> SqliteResult<Option<BlobRef>> {
+    let body_blob_pk = sqlx::query_scalar::<_, i64>(
+        r#"
+            SELECT ch.body_blob_pk
+            FROM crawl_history AS ch
+            INNER JOIN feed AS f
+                ON f.pk = ch.feed_pk
+            WHERE f.url = ?
+              AND ch.body_blob_pk IS NOT NULL
+            ORDER BY ch.pk DESC
+            LIMIT 1
+            "#,
+    )
+    .bind(feed_url.as_str())
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    Ok(body_blob_pk.map(BlobRef::new))
+}
+
+async fn append(
+    tx: &mut Transaction<'_, Sqlite>,
+    command: AppendCrawlHistoryCommand,
+) -> SqliteResult<()> {
+    let feed_pk = feed::resolve_pk(tx, &command.feed_url).await?;
+    let entry = command.entry;
+    let error_kind = entry.error_kind.map(codec::encode_crawl_state_error_kind);
+    let body_bytes = entry
+        .body_bytes
+        .map(|len| {
+            i64::try_from(len).map_err(|_| {
+                SqliteError::decode_message("crawl body length exceeds SQLite INTEGER range")
+            })
+        })
+        .transpose()?;
+
+    sqlx::query(
+        r#"
+            INSERT INTO crawl_history (
+                feed_pk,
+                job_id,
+                job_trigger,
+                started_at,
+                finished_at,
+                outcome,
+                http_status,
+                error_kind,
+                body_bytes,
+                body_blob_pk,
+                body_changed
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+    )
+    .bind(feed_pk)
+    .bind(entry.job_id.as_str())
+    .bind(entry.trigger.as_str())
+    .bind(entry.started_at)
+    .bind(entry.finished_at)
+    .bind(entry.outcome.as_str())
+    .bind(entry.http_status.map(|status| i64::from(status.as_u16())))
+    .bind(error_kind)
+    .bind(body_bytes)
+    .bind(command.body_blob.map(BlobRef::pk))
+    .bind(entry.body_changed)
+    .execute(&mut **tx)
+    .await?;
+
+    sqlx::query(
+        r#"
+            DELETE FROM crawl_history
+            WHERE feed_pk = ?
+              AND pk NOT IN (
+                  SELECT pk
+                  FROM crawl_history
+                  WHERE feed_pk = ?
+                  ORDER BY pk DESC
+                  LIMIT ?
+              )
+            "#,
+    )
+    .bind(feed_pk)
+    .bind(feed_pk)
+    .bind(i64::try_from(command.retain).unwrap_or(i64::MAX))
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+async fn list(
+    tx: &mut Transaction<'_, Sqlite>,
+    query: CrawlHistoryQuery,
+) -> SqliteResult<Vec<CrawlHistoryEntry>> {
+    let rows = sqlx::query_as::<_, CrawlHistoryRow>(
+        r#"
+            SELECT
+                ch.job_id,
+                ch.job_trigger,
+                ch.started_at,
+                ch.finished_at,
+                ch.outcome,
+                ch.http_status,
+                ch.error_kind,
+                ch.body_bytes,
+                ch.body_changed
+            FROM crawl_history AS ch
+            INNER JOIN feed AS f
+                ON f.pk = ch.feed_pk
+            WHERE f.url = ?
+            ORDER BY ch.pk DESC
+            LIMIT ?
+            "#,
+    )
+    .bind(query.feed_url.as_str())
+    .bind(i64::try_from(query.limit).unwrap_or(i64::MAX))
+    .fetch_all(&mut **tx)
+    .await?;
+
+    rows.into_iter().map(CrawlHistoryRow::into_entry).collect()
+}
+
+#[derive(sqlx::FromRow)]
+struct CrawlHistoryRow {
+    job_id: String,
+    job_trigger: String,
+    started_at: DateTime<Utc>,
+    finished_at: DateTime<Utc>,
+    outcome: String,
+    http_status: Option<i64>,
+    error_kind: Option<String>,
+    body_bytes: Option<i64>,
+    body_changed: bool,
+}
+
+impl CrawlHistoryRow {
+    fn into_entry(self) -> SqliteResult<CrawlHistoryEntry> {
+        let http_status = self
+            .http_status
+            .map(|status| {
+                u16::try_from(status).map(FeedHttpStatus::new).map_err(|_| {
+                    SqliteError::decode_message(format!(
+                        "crawl history http status out of range: {status}"
+                    ))
+                })
+            })
+            .transpose()?;
+        let body_bytes = self
+            .body_bytes
+            .map(|len| {
+                u64::try_from(len).map_err(|_| {
+                    SqliteError::decode_message(format!(
+                        "crawl history body length must be non-negative: {len}"
+                    ))
+                })
+            })
+            .transpose()?;
+
+        Ok(CrawlHistoryEntry {
+            job_id: CrawlJobId::new(self.job_id),
+            trigger: codec::decode_crawl_job_trigger(&self.job_trigger)?,
+            started_at: self.started_at,
+            finished_at: self.finished_at,
+            outcome: codec::decode_crawl_outcome(&self.outcome)?,
+            http_status,
+            error_kind: self
+                .error_kind
+                .as_deref()
+                .map(codec::decode_crawl_state_error_kind)
+                .transpose()?,
+            body_bytes,
+            body_changed: self.body_changed,
+        })
+    }
+}
+
+impl CrawlHistoryDb for SqliteRegistryTx<'_> {
+    async fn load_last_crawl_body(
+        &mut self,
+        feed_url: &FeedUrl,
+    ) -> RegistryDbResult<Option<BlobRef>> {
+        load_last_body(&mut self.tx, feed_url).await.db()
+    }
+
+    async fn append_crawl_history(
+        &mut self,
+        command: AppendCrawlHistoryCommand,
+    ) -> RegistryDbResult<()> {
+        append(&mut self.tx, command).await.db()
+    }
+
+    async fn list_crawl_history(
+        &mut self,
+        query: CrawlHistoryQuery,
+    ) -> RegistryDbResult<Vec<CrawlHistoryEntry>> {
+        list(&mut self.tx, query).await.db()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,124 @@
+use synd_registry::crawl::{
+    blob::BlobRef,
+    history::{AppendCrawlHistoryCommand, CrawlHistoryEntry, CrawlHistoryQuery, CrawlOutcome},
+    job::CrawlJobTrigger,
+    state::CrawlHttpErrorKind,
+};
+
+use crate::sqlite::feed_registry::test_support::*;
+
+fn history_entry(seq: i64, outcome: CrawlOutcome) -> CrawlHistoryEntry {
+    let started_at = test_occurred_at() + chrono::Duration::minutes(seq);
+    CrawlHistoryEntry {
+        job_id: CrawlJobId::new(format!("job-{seq}")),
+        trigger: CrawlJobTrigger::PeriodicDue,
+        started_at,
+        finished_at: started_at + chrono::Duration::milliseconds(250),
+        outcome,
+        http_status: None,
+        error_kind: None,
+        body_bytes: None,
+        body_changed: false,
+    }
+}
+
+#[tokio::test]
+async fn crawl_history_round_trips_newest_first() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let feed_url = feed_url("history");
+    let mut tx = db.begin().await?;
+    store_feed(&mut tx, &feed_url).await?;
+    let body_blob = tx
+        .put_blob(PutBlobCommand::new(b"body".to_vec(), test_occurred_at()))
+        .await?;
+
+    let fetched = CrawlHistoryEntry {
+        trigger: CrawlJobTrigger::ManualRequest,
+        http_status: Some(FeedHttpStatus::new(200)),
+        body_bytes: Some(4),
+        body_changed: true,
+        ..history_entry(0, CrawlOutcome::Fetched)
+    };
+    let failed = CrawlHistoryEntry {
+        http_status: Some(FeedHttpStatus::new(503)),
+        error_kind: Some(CrawlStateErrorKind::Http(CrawlHttpErrorKind::Unavailable)),
+        body_bytes: Some(0),
+        ..history_entry(1, CrawlOutcome::UnexpectedStatus)
+    };
+    tx.append_crawl_history(AppendCrawlHistoryCommand::new(
+        feed_url.clone(),
+        fetched.clone(),
+        Some(body_blob),
+        10,
+    ))
+    .await?;
+    tx.append_crawl_history(AppendCrawlHistoryCommand::new(
+        feed_url.clone(),
+        failed.clone(),
+        None,
+        10,
+    ))
+    .await?;
+
+    let history = tx
+        .list_crawl_history(CrawlHistoryQuery {
+            feed_url: feed_url.clone(),
+            limit: 10,
+        })
+        .await?;
+    assert_eq!(history, vec![failed, fetched]);
+    assert_eq!(history[0].duration_ms(), 250);
+    // The failed crawl kept no body, so the fetched one is still the last.
+    assert_eq!(tx.load_last_crawl_body(&feed_url).await?, Some(body_blob));
+    tx.commit().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn crawl_history_prunes_to_retention_per_feed() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let pruned = feed_url("pruned");
+    let other = feed_url("other");
+    let mut tx = db.begin().await?;
+    store_feed(&mut tx, &pruned).await?;
+    store_feed(&mut tx, &other).await?;
+
+    tx.append_crawl_history(AppendCrawlHistoryCommand::new(
+        other.clone(),
+        history_entry(0, CrawlOutcome::NotModified),
+        Some(BlobRef::new(1)),
+        2,
+    ))
+    .await?;
+    for seq in 1..=3 {
+        tx.append_crawl_history(AppendCrawlHistoryCommand::new(
+            pruned.clone(),
+            history_entry(seq, CrawlOutcome::NotModified),
+            None,
+            2,
+        ))
+        .await?;
+    }
+
+    let job_ids = |history: Vec<CrawlHistoryEntry>| {
+        history
+            .into_iter()
+            .map(|entry| entry.job_id.as_str().to_owned())
+            .collect::<Vec<_>>()
+    };
+    let query = |feed_url: &FeedUrl| CrawlHistoryQuery {
+        feed_url: feed_url.clone(),
+        limit: 10,
+    };
+    assert_eq!(
+        job_ids(tx.list_crawl_history(query(&pruned)).await?),
+        ["job-3", "job-2"]
+    );
+    assert_eq!(
+        job_ids(tx.list_crawl_history(query(&other)).await?),
+        ["job-0"]
+    );
+    assert_eq!(tx.load_last_crawl_body(&pruned).await?, None);
+    tx.commit().await?;
+    Ok(())
+}
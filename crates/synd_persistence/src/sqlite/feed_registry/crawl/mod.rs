@@ -1,2 +1,3 @@
+pub(super) mod history;
 pub(super) mod state;
 pub(super) mod target;
@@ -8,7 +8,7 @@ use synd_registry::{
     RegistryDbResult,
     crawl::state::{
         CrawlHealth, CrawlState, CrawlStateError, FailureStreak, LastCrawlResult,
-        UpsertCrawlStateCommand,
+        NotModifiedStreak, UpsertCrawlStateCommand,
     },
     db::CrawlStateDb,
 };
@@ -31,6 +31,7 @@ async fn load(
                 cs.last_http_status,
                 cs.last_error_kind,
                 cs.failure_streak,
+                cs.not_modified_streak,
                 cs.retry_after,
                 cs.etag,
                 cs.last_modified
@@ -61,6 +62,10 @@ async fn upsert(
         .error
         .map(|error| codec::encode_crawl_state_error_kind(error.kind));
     let failure_streak = encode_u64(command.health.failure_streak.value(), "failure streak")?;
+    let not_modified_streak = encode_u64(
+        command.health.not_modified_streak.value(),
+        "not modified streak",
+    )?;
 
     sqlx::query(
         r#"
@@ -71,17 +76,19 @@ async fn upsert(
                 last_http_status,
                 last_error_kind,
                 failure_streak,
+                not_modified_streak,
                 retry_after,
                 etag,
                 last_modified
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             ON CONFLICT(feed_pk) DO UPDATE SET
                 last_started_at = excluded.last_started_at,
                 last_finished_at = excluded.last_finished_at,
                 last_http_status = excluded.last_http_status,
                 last_error_kind = excluded.last_error_kind,
                 failure_streak = excluded.failure_streak,
+                not_modified_streak = excluded.not_modified_streak,
                 retry_after = excluded.retry_after,
                 etag = excluded.etag,
                 last_modified = excluded.last_modified
@@ -93,6 +100,7 @@ async fn upsert(
     .bind(last_http_status)
     .bind(last_error_kind)
     .bind(failure_streak)
+    .bind(not_modified_streak)
     .bind(command.last.retry_after)
     .bind(command.conditional.etag.as_deref())
     .bind(command.conditional.last_modified.as_deref())
@@ -111,6 +119,7 @@ pub(in crate::sqlite::feed_registry) struct CrawlStateRow {
     pub(in crate::sqlite::feed_registry) last_http_status: Option<i64>,
     pub(in crate::sqlite::feed_registry) last_error_kind: Option<String>,
     pub(in crate::sqlite::feed_registry) failure_streak: i64,
+    pub(in crate::sqlite::feed_registry) not_modified_streak: i64,
     pub(in crate::sqlite::feed_registry) retry_after: Option<DateTime<Utc>>,
     pub(in crate::sqlite::feed_registry) etag: Option<String>,
     pub(in crate::sqlite::feed_registry) last_modified: Option<String>,
@@ -143,6 +152,12 @@ impl CrawlStateRow {
                 self.failure_streak
             ))
         })?;
+        let not_modified_streak = u64::try_from(self.not_modified_streak).map_err(|_| {
+            SqliteError::decode_message(format!(
+                "crawl state not modified streak must be non-negative: {}",
+                self.not_modified_streak
+            ))
+        })?;
 
         let last = LastCrawlResult {
             started_at: self.last_started_at,
@@ -157,6 +172,7 @@ impl CrawlStateRow {
             last,
             health: CrawlHealth {
                 failure_streak: FailureStreak::new(failure_streak),
+                not_modified_streak: NotModifiedStreak::new(not_modified_streak),
             },
             conditional: FeedConditionalFetch {
                 etag: self.etag,
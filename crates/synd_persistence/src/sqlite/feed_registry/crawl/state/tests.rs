@@ -1,6 +1,7 @@
 use synd_feed::feed::service::{FeedConditionalFetch, FeedParseErrorKind};
 use synd_registry::crawl::state::{
-    CrawlHealth, CrawlStateError, FailureStreak, LastCrawlResult, UpsertCrawlStateCommand,
+    CrawlHealth, CrawlStateError, FailureStreak, LastCrawlResult, NotModifiedStreak,
+    UpsertCrawlStateCommand,
 };
 
 use crate::sqlite::feed_registry::test_support::*;
@@ -32,6 +33,7 @@ async fn crawl_state_round_trips_and_joins_into_due_input() -> anyhow::Result<()
         ),
         CrawlHealth {
             failure_streak: FailureStreak::new(2),
+            not_modified_streak: NotModifiedStreak::zero(),
         },
         FeedConditionalFetch {
             etag: Some("etag-value".to_owned()),
@@ -64,3 +66,38 @@ async fn crawl_state_round_trips_and_joins_into_due_input() -> anyhow::Result<()
     tx.commit().await?;
     Ok(())
 }
+
+#[tokio::test]
+async fn crawl_state_round_trips_not_modified_streak() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let feed_url = feed_url("crawl-state-not-modified");
+    let started_at = test_occurred_at();
+    let finished_at = started_at + chrono::Duration::seconds(1);
+
+    let mut tx = db.begin().await?;
+    store_feed(&mut tx, &feed_url).await?;
+    tx.upsert_crawl_state(UpsertCrawlStateCommand::new(
+        feed_url.clone(),
+        LastCrawlResult::normal(
+            started_at,
+            finished_at,
+            Some(FeedHttpStatus::new(304)),
+            None,
+        ),
+        CrawlHealth {
+            failure_streak: FailureStreak::zero(),
+            not_modified_streak: NotModifiedStreak::new(3),
+        },
+        FeedConditionalFetch::default(),
+    ))
+    .await?;
+    let state = tx
+        .load_crawl_state(&feed_url)
+        .await?
+        .expect("crawl state should be stored");
+    tx.commit().await?;
+
+    assert!(state.last.is_not_modified());
+    assert_eq!(state.health.not_modified_streak.value(), 3);
+    Ok(())
+}
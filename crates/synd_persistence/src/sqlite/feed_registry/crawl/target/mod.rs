@@ -6,7 +6,7 @@ use synd_feed::types::FeedUrl;
 use synd_registry::{
     RegistryDbResult,
     crawl::{
-        due::CrawlDueInput,
+        due::{CrawlDueInput, PUBLISH_ACTIVITY_SAMPLE_SIZE, PublishActivity},
         target_list::{CrawlTarget, CrawlTargetState},
     },
     db::CrawlTargetDb,
@@ -72,6 +72,9 @@ async fn load(
     row.map(CrawlTargetRow::into_target).transpose()
 }
 
+/// Publish activity summarizes each feed's newest entries; both sample
+/// subqueries take `PUBLISH_ACTIVITY_SAMPLE_SIZE` as their limit and walk
+/// `entry_feed_order_idx`.
 const DUE_INPUT_SELECT: &str = r#"
 SELECT
     f.url AS feed_url,
@@ -82,14 +85,43 @@ SELECT
     cs.last_http_status,
     cs.last_error_kind,
     cs.failure_streak,
+    cs.not_modified_streak,
     cs.retry_after,
     cs.etag,
-    cs.last_modified
+    cs.last_modified,
+    (
+        SELECT COUNT(*)
+        FROM (
+            SELECT 1
+            FROM entry AS e
+            WHERE e.feed_pk = ct.feed_pk
+            ORDER BY e.order_time DESC, e.entry_id DESC
+            LIMIT ?
+        )
+    ) AS activity_sampled,
+    (
+        SELECT MIN(sample.order_time)
+        FROM (
+            SELECT e.order_time
+            FROM entry AS e
+            WHERE e.feed_pk = ct.feed_pk
+            ORDER BY e.order_time DESC, e.entry_id DESC
+            LIMIT ?
+        ) AS sample
+    ) AS activity_oldest,
+    (
+        SELECT MAX(e.order_time)
+        FROM entry AS e
+        WHERE e.feed_pk = ct.feed_pk
+    ) AS activity_newest,
+    json_extract(fs.meta_json, '$.update_hints') AS update_hints_json
 FROM crawl_target AS ct
 INNER JOIN feed AS f
     ON f.pk = ct.feed_pk
 LEFT JOIN crawl_state AS cs
     ON cs.feed_pk = ct.feed_pk
+LEFT JOIN feed_snapshot AS fs
+    ON fs.feed_pk = ct.feed_pk
 WHERE ct.state = 'active'
 "#;
 
@@ -99,6 +131,8 @@ async fn load_due_input(
 ) -> SqliteResult<Option<CrawlDueInput>> {
     let sql = format!("{DUE_INPUT_SELECT} AND f.url = ?");
     let row = sqlx::query_as::<_, CrawlDueInputRow>(&sql)
+        .bind(PUBLISH_ACTIVITY_SAMPLE_SIZE)
+        .bind(PUBLISH_ACTIVITY_SAMPLE_SIZE)
         .bind(feed_url.as_str())
         .fetch_optional(&mut **tx)
         .await?;
@@ -109,6 +143,8 @@ async fn load_due_input(
 async fn list_due_inputs(tx: &mut Transaction<'_, Sqlite>) -> SqliteResult<Vec<CrawlDueInput>> {
     let sql = format!("{DUE_INPUT_SELECT} ORDER BY f.url");
     let rows = sqlx::query_as::<_, CrawlDueInputRow>(&sql)
+        .bind(PUBLISH_ACTIVITY_SAMPLE_SIZE)
+        .bind(PUBLISH_ACTIVITY_SAMPLE_SIZE)
         .fetch_all(&mut **tx)
         .await?;
 
@@ -261,9 +297,14 @@ struct CrawlDueInputRow {
     last_http_status: Option<i64>,
     last_error_kind: Option<String>,
     failure_streak: Option<i64>,
+    not_modified_streak: Option<i64>,
     retry_after: Option<DateTime<Utc>>,
     etag: Option<String>,
     last_modified: Option<String>,
+    activity_sampled: i64,
+    activity_oldest: Option<DateTime<Utc>>,
+    activity_newest: Option<DateTime<Utc>>,
+    update_hints_json: Option<String>,
 }
 
 impl CrawlDueInputRow {
@@ -281,14 +322,21 @@ impl CrawlDueInputRow {
             self.last_started_at,
             self.last_finished_at,
             self.failure_streak,
+            self.not_modified_streak,
         ) {
-            (Some(last_started_at), Some(last_finished_at), Some(failure_streak)) => Some(
+            (
+                Some(last_started_at),
+                Some(last_finished_at),
+                Some(failure_streak),
+                Some(not_modified_streak),
+            ) => Some(
                 CrawlStateRow {
                     last_started_at,
                     last_finished_at,
                     last_http_status: self.last_http_status,
                     last_error_kind: self.last_error_kind,
                     failure_streak,
+                    not_modified_streak,
                     retry_after: self.retry_after,
                     etag: self.etag,
                     last_modified: self.last_modified,
@@ -297,12 +345,35 @@ impl CrawlDueInputRow {
             ),
             _ => None,
         };
+        // MIN/MAX are NULL for a feed without entries.
+        let activity = match (self.activity_oldest, self.activity_newest) {
+            (Some(oldest), Some(newest)) => Some(PublishActivity {
+                sampled: u32::try_from(self.activity_sampled).map_err(|_| {
+                    SqliteError::decode_message(format!(
+                        "publish activity sample size out of range: {}",
+                        self.activity_sampled
+                    ))
+                })?,
+                oldest,
+                newest,
+            }),
+            _ => None,
+        };
+        // Snapshots stored before hints were parsed carry none.
+        let hints = self
+            .update_hints_json
+            .as_deref()
+            .map(codec::decode_update_hints_json)
+            .transpose()?
+            .unwrap_or_default();
 
         Ok(CrawlDueInput {
             feed_url,
             polling: policy.polling,
             manual_requested_at: self.manual_requested_at,
             state,
+            activity,
+            hints,
         })
     }
 }
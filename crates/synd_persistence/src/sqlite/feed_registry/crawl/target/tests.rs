@@ -362,5 +362,92 @@ async fn due_inputs_list_only_active_targets_with_their_state() -> anyhow::Resul
     assert_eq!(inputs[0].manual_requested_at, None);
     // Never crawled: no state joined.
     assert!(inputs[0].state.is_none());
+    assert!(inputs[0].activity.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn due_input_summarizes_newest_entries_as_publish_activity() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let subscription = subscription("crawl-due-activity");
+
+    let mut tx = db.begin().await?;
+    store_subscription(&mut tx, subscription.clone()).await?;
+    tx.commit().await?;
+    project_crawl_targets(
+        &db,
+        vec![SubEvent::Subscribed(feed_subscribed_event(&subscription))],
+    )
+    .await?;
+
+    // Twelve hourly entries; only the newest ten are sampled.
+    let first_published_at = test_occurred_at();
+    let mut tx = db.begin().await?;
+    for hour in 0..12 {
+        sqlx::query(
+            r#"
+            INSERT INTO entry (entry_id, feed_pk, entry_json, order_time)
+            VALUES (?, (SELECT pk FROM feed WHERE url = ?), '{}', ?)
+            "#,
+        )
+        .bind(format!("synd:entry:v1:{hour:064x}"))
+        .bind(subscription.feed_url.as_str())
+        .bind(first_published_at + chrono::Duration::hours(hour))
+        .execute(&mut *tx.tx)
+        .await?;
+    }
+    let input = tx
+        .load_crawl_due_input(&subscription.feed_url)
+        .await?
+        .expect("active target should have a due input");
+    tx.commit().await?;
+
+    assert_eq!(
+        input.activity,
+        Some(PublishActivity {
+            sampled: PUBLISH_ACTIVITY_SAMPLE_SIZE,
+            oldest: first_published_at + chrono::Duration::hours(2),
+            newest: first_published_at + chrono::Duration::hours(11),
+        })
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn due_input_carries_update_hints_of_the_current_snapshot() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let subscription = subscription("crawl-due-hints");
+
+    let mut tx = db.begin().await?;
+    store_subscription(&mut tx, subscription.clone()).await?;
+    tx.commit().await?;
+    project_crawl_targets(
+        &db,
+        vec![SubEvent::Subscribed(feed_subscribed_event(&subscription))],
+    )
+    .await?;
+
+    let body = br#"<?xml version="1.0" encoding="utf-8"?>
+<rss version="2.0">
+  <channel>
+    <title>hinted</title>
+    <link>https://example.com/</link>
+    <description>example feed</description>
+    <ttl>240</ttl>
+    <skipDays><day>Sunday</day></skipDays>
+  </channel>
+</rss>"#;
+    let crawl = record_fetched_crawl(&db, &subscription.feed_url, body.to_vec(), 0).await?;
+    project_feed(&db, crawl).await?;
+
+    let mut tx = db.begin().await?;
+    let input = tx
+        .load_crawl_due_input(&subscription.feed_url)
+        .await?
+        .expect("active target should have a due input");
+    tx.commit().await?;
+
+    assert_eq!(input.hints.ttl(), Some(Duration::from_hours(4)));
+    assert_eq!(input.hints.skip_days(), [chrono::Weekday::Sun]);
     Ok(())
 }
@@ -1,11 +1,16 @@
 use chrono::{DateTime, Utc};
 use sqlx::{Sqlite, Transaction};
 use synd_feed::entry::{EntryId, EntryOrderKey, SyndEntry};
-use synd_registry::entry::{Changes, Entries};
+use synd_registry::{
+    entry::{Changes, Entries},
+    timeline::retention::RetentionPurge,
+};
 
 use super::{
     codec::{decode_stored_entry, encode_entry_json},
     error::{SqliteError, SqliteResult},
+    pagination::PageLimit,
+    search,
 };
 
 pub(super) async fn load(
@@ -124,7 +129,65 @@ async fn upsert(
             rows_affected: result.rows_affected(),
         });
     }
-    Ok(())
+    search::index_entry(tx, entry.entry()).await
+}
+
+/// Deletes catalog entries no feed declares that are neither on a timeline,
+/// tombstones included, nor saved, along with their search index rows and
+/// fetched articles. Article bodies are left to blob collection.
+pub(super) async fn delete_orphaned(
+    tx: &mut Transaction<'_, Sqlite>,
+    limit: usize,
+) -> SqliteResult<RetentionPurge> {
+    let page = PageLimit::new(limit);
+    let mut entry_ids = sqlx::query_scalar::<_, String>(
+        r#"
+            SELECT e.entry_id
+            FROM entry AS e
+            WHERE NOT EXISTS (
+                  SELECT 1 FROM feed_entry AS fe WHERE fe.entry_id = e.entry_id
+              )
+              AND NOT EXISTS (
+                  SELECT 1 FROM timeline_entry AS te WHERE te.entry_id = e.entry_id
+              )
+              AND NOT EXISTS (
+                  SELECT 1 FROM entry_saved AS es WHERE es.entry_id = e.entry_id
+              )
+            ORDER BY e.entry_id
+            LIMIT ?
+            "#,
+    )
+    .bind(page.sql_limit())
+    .fetch_all(&mut **tx)
+    .await?;
+    let has_more = page.truncate_overfetch(&mut entry_ids);
+    if entry_ids.is_empty() {
+        return Ok(RetentionPurge::default());
+    }
+
+    let entry_ids_json = serde_json::to_string(&entry_ids)?;
+    search::unindex_entries(tx, &entry_ids_json).await?;
+    sqlx::query(
+        r#"
+            DELETE FROM entry_article
+            WHERE entry_id IN (SELECT CAST(value AS TEXT) FROM json_each(?))
+            "#,
+    )
+    .bind(&entry_ids_json)
+    .execute(&mut **tx)
+    .await?;
+    let removed = sqlx::query(
+        r#"
+            DELETE FROM entry
+            WHERE entry_id IN (SELECT CAST(value AS TEXT) FROM json_each(?))
+            "#,
+    )
+    .bind(&entry_ids_json)
+    .execute(&mut **tx)
+    .await?
+    .rows_affected();
+
+    Ok(RetentionPurge { removed, has_more })
 }
 
 #[derive(sqlx::FromRow)]
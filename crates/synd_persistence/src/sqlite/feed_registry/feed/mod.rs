@@ -1,18 +1,55 @@
 use std::{collections::HashMap, hash::BuildHasher, iter::FromIterator};
 
-use sqlx::{Sqlite, Transaction};
+use sqlx::{QueryBuilder, Sqlite, Transaction};
 use synd_feed::{
     entry::{Entry, EntryId},
     types::{Feed, FeedMeta, FeedUrl},
 };
-use synd_registry::{RegistryDbResult, db::FeedDb, entry::Entries, feed::FeedUpdate};
+use synd_registry::{
+    RegistryDbResult,
+    db::FeedDb,
+    entry::Entries,
+    feed::FeedUpdate,
+    query::{FeedEntriesPage, FeedEntriesQuery, TimelineEntry},
+    timeline::retention::RetentionPurge,
+};
 
 use super::{
     codec::{decode_stored_entry, decode_stored_feed_meta, encode_feed_meta_json},
     entry,
     error::{DecodeResultExt, IntoDbResult, SqliteError, SqliteResult},
+    pagination::PageLimit,
+    timeline::TimelineEntryRow,
 };
 
+// Walks the catalog of one subscribed feed through entry_feed_order_idx, so
+// entries that left the feed body or the timeline are listed too
+const FEED_ENTRY_SELECT: &str = r#"
+SELECT
+    e.order_time,
+    e.entry_id,
+    e.entry_json,
+    f.url AS feed_url,
+    fs.meta_json,
+    s.requirement,
+    s.category,
+    er.entry_id IS NOT NULL AS read,
+    es.entry_id IS NOT NULL AS saved
+FROM feed_subscription AS s
+INNER JOIN feed AS f
+    ON f.pk = s.feed_pk
+INNER JOIN feed_snapshot AS fs
+    ON fs.feed_pk = f.pk
+INNER JOIN entry AS e
+    ON e.feed_pk = f.pk
+LEFT JOIN entry_read AS er
+    ON er.subscriber_id = s.subscriber_id
+   AND er.entry_id = e.entry_id
+LEFT JOIN entry_saved AS es
+    ON es.subscriber_id = s.subscriber_id
+   AND es.entry_id = e.entry_id
+"#;
+
 /// Registers the URL in the feed ledger and returns its pk.
 pub(super) async fn upsert_pk(
     tx: &mut Transaction<'_, Sqlite>,
@@ -96,6 +133,42 @@ async fn load_feeds(
     Ok(complete.into())
 }
 
+async fn list_entries(
+    tx: &mut Transaction<'_, Sqlite>,
+    query: FeedEntriesQuery,
+) -> SqliteResult<FeedEntriesPage> {
+    let limit = PageLimit::new(query.first);
+    let mut sql = QueryBuilder::<Sqlite>::new(FEED_ENTRY_SELECT);
+    sql.push(" WHERE s.subscriber_id = ");
+    sql.push_bind(query.subscriber_id.as_str());
+    sql.push(" AND f.url = ");
+    sql.push_bind(query.feed_url.as_str());
+    if let Some(after) = &query.after {
+        sql.push(" AND (e.order_time, e.entry_id) < (");
+        sql.push_bind(after.order_time());
+        sql.push(", ");
+        sql.push_bind(after.entry_id().as_str().to_owned());
+        sql.push(")");
+    }
+    sql.push(" ORDER BY e.order_time DESC, e.entry_id DESC LIMIT ");
+    sql.push_bind(limit.sql_limit());
+
+    let mut nodes = sql
+        .build_query_as::<TimelineEntryRow>()
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(TimelineEntry::try_from)
+        .collect::<SqliteResult<Vec<_>>>()?;
+    let has_next_page = limit.truncate_overfetch(&mut nodes);
+    let end_cursor = nodes.last().map(|node| node.cursor.clone());
+    Ok(FeedEntriesPage {
+        nodes,
+        has_next_page,
+        end_cursor,
+    })
+}
+
 /// Stable JSON representation of one batch lookup request.
 struct RequestedFeeds(String);
 
@@ -291,6 +364,17 @@ impl FeedDb for super::SqliteRegistryTx<'_> {
     ) -> RegistryDbResult<HashMap<FeedUrl, Feed>> {
         load_feeds(&mut self.tx, feed_urls).await.db()
     }
+
+    async fn list_feed_entries(
+        &mut self,
+        query: FeedEntriesQuery,
+    ) -> RegistryDbResult<FeedEntriesPage> {
+        list_entries(&mut self.tx, query).await.db()
+    }
+
+    async fn delete_orphaned_entries(&mut self, limit: usize) -> RegistryDbResult<RetentionPurge> {
+        entry::delete_orphaned(&mut self.tx, limit).await.db()
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use synd_feed::types::Text;
+use synd_feed::types::{Requirement, Text};
 
 use crate::sqlite::feed_registry::test_support::*;
 
@@ -70,3 +70,58 @@ async fn feed_projection_replaces_membership_without_deleting_catalog_history()
     assert_eq!(catalog_count, 2);
     Ok(())
 }
+
+#[tokio::test]
+async fn feed_entries_list_catalog_history_of_subscribed_feed() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let subscription = Subscription {
+        requirement: Some(Requirement::Must),
+        ..subscription("feed-history")
+    };
+    let feed_url = subscription.feed_url.clone();
+    for (index, guid) in ["entry-1", "entry-2"].into_iter().enumerate() {
+        let crawl = record_fetched_crawl(
+            &db,
+            &feed_url,
+            rss_body_with_entry("feed", guid, guid),
+            i64::try_from(index)?,
+        )
+        .await?;
+        project_feed(&db, crawl).await?;
+    }
+    store_subscription_in_db(&db, subscription).await?;
+    let query = |subscriber_id, after| FeedEntriesQuery {
+        subscriber_id,
+        feed_url: feed_url.clone(),
+        after,
+        first: 1,
+    };
+
+    let mut tx = db.begin().await?;
+    let first = tx.list_feed_entries(query(subscriber_id(), None)).await?;
+    let second = tx
+        .list_feed_entries(query(subscriber_id(), first.end_cursor.clone()))
+        .await?;
+    let unsubscribed = tx
+        .list_feed_entries(query(SubscriberId::new("other"), None))
+        .await?;
+    tx.commit().await?;
+
+    let mut titles = first
+        .nodes
+        .iter()
+        .chain(&second.nodes)
+        .filter_map(|node| node.entry.title().map(Text::content))
+        .collect::<Vec<_>>();
+    titles.sort_unstable();
+    // entry-1 left the feed body but stays in its history
+    assert_eq!(titles, ["entry-1", "entry-2"]);
+    assert!(first.has_next_page);
+    assert!(!second.has_next_page);
+    assert_eq!(
+        first.nodes[0].feed_meta.requirement,
+        Some(Requirement::Must)
+    );
+    assert!(unsubscribed.nodes.is_empty());
+    Ok(())
+}
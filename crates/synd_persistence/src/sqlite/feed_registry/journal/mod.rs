@@ -4,7 +4,8 @@ use synd_registry::{
     RegistryDbResult,
     event::{
         Event, EventCursor, EventCursorPos, EventEncoding, EventInterests, EventJournal,
-        EventJournalAppend, EventReadBatch, EventType, JournaledEvent, ProcessorId,
+        EventJournalAppend, EventJournalCompact, EventReadBatch, EventType, JournalCompaction,
+        JournaledEvent, ProcessorId,
     },
 };
 
@@ -146,6 +147,78 @@ async fn advance_cursor(
     Ok(())
 }
 
+/// Deletes journal positions at or below the slowest cursor of `processors`.
+/// A processor without a cursor row has consumed nothing, so it blocks
+/// compaction entirely.
+async fn compact_journal(
+    tx: &mut Transaction<'_, Sqlite>,
+    processors: &[ProcessorId],
+    limit: usize,
+) -> SqliteResult<JournalCompaction> {
+    if processors.is_empty() {
+        return Ok(JournalCompaction::default());
+    }
+    let processors_json = serde_json::to_string(
+        &processors
+            .iter()
+            .map(|processor| processor.as_str())
+            .collect::<Vec<_>>(),
+    )?;
+
+    let watermark = sqlx::query_as::<_, CompactionWatermarkRow>(
+        r"
+        SELECT COUNT(*) AS cursor_count, MIN(position) AS position
+        FROM event_cursor
+        WHERE processor IN (SELECT value FROM json_each(?))
+        ",
+    )
+    .bind(&processors_json)
+    .fetch_one(&mut **tx)
+    .await?;
+    let Some(through) = watermark
+        .position
+        .filter(|_| usize::try_from(watermark.cursor_count).ok() == Some(processors.len()))
+    else {
+        return Ok(JournalCompaction::default());
+    };
+
+    let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+    let removed = sqlx::query(
+        r"
+        DELETE FROM event_journal
+        WHERE position IN (
+            SELECT position
+            FROM event_journal
+            WHERE position <= ?
+            ORDER BY position
+            LIMIT ?
+        )
+        ",
+    )
+    .bind(through)
+    .bind(limit)
+    .execute(&mut **tx)
+    .await?
+    .rows_affected();
+
+    let has_more = sqlx::query_scalar::<_, bool>(
+        r"
+        SELECT EXISTS (SELECT 1 FROM event_journal WHERE position <= ?)
+        ",
+    )
+    .bind(through)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    Ok(JournalCompaction { removed, has_more })
+}
+
+#[derive(sqlx::FromRow)]
+struct CompactionWatermarkRow {
+    cursor_count: i64,
+    position: Option<i64>,
+}
+
 #[derive(sqlx::FromRow)]
 struct ScannedPositionRow {
     scanned_position: i64,
@@ -215,5 +288,15 @@ impl EventJournal for SqliteRegistryTx<'_> {
     }
 }
 
+impl EventJournalCompact for SqliteRegistryTx<'_> {
+    async fn compact_journal(
+        &mut self,
+        processors: &[ProcessorId],
+        limit: usize,
+    ) -> RegistryDbResult<JournalCompaction> {
+        compact_journal(&mut self.tx, processors, limit).await.db()
+    }
+}
+
 #[cfg(test)]
 mod tests;
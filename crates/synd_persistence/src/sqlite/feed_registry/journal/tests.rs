@@ -49,3 +49,115 @@ async fn append_and_read_subscription_events_for_processor() -> anyhow::Result<(
     );
     Ok(())
 }
+
+async fn append_subscription_events(
+    db: &SqliteFeedRegistryDb,
+    names: &[&str],
+) -> anyhow::Result<()> {
+    let mut tx = db.begin().await?;
+    let mut recorded = RecordedEvents::with_capacity(names.len());
+    let clock = TestClock(test_occurred_at());
+    EventRecorder::new(&mut tx, &mut recorded, &clock)
+        .record_all(names.iter().map(|name| subscribed_event(name)))
+        .await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+async fn advance_all_cursors(
+    db: &SqliteFeedRegistryDb,
+    positions: [&str; 5],
+) -> anyhow::Result<()> {
+    let mut tx = db.begin().await?;
+    for (processor, position) in ProcessorId::ALL.into_iter().zip(positions) {
+        tx.advance_cursor(&EventCursor::at(
+            processor,
+            EventCursorPos::position(position),
+        ))
+        .await?;
+    }
+    tx.commit().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn compaction_keeps_journal_until_every_processor_committed() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    append_subscription_events(&db, &["a", "b", "c"]).await?;
+    {
+        let mut tx = db.begin().await?;
+        tx.advance_cursor(&EventCursor::at(
+            ProcessorId::CrawlTargetProjection,
+            EventCursorPos::position("3"),
+        ))
+        .await?;
+        let compaction = tx.compact_journal(&ProcessorId::ALL, 100).await?;
+        tx.commit().await?;
+        assert_eq!(compaction, JournalCompaction::default());
+    }
+
+    advance_all_cursors(&db, ["3", "2", "3", "3", "3"]).await?;
+    let mut tx = db.begin().await?;
+    let compaction = tx.compact_journal(&ProcessorId::ALL, 100).await?;
+    let batch = tx
+        .read_after(
+            &EventCursor::initial(ProcessorId::FeedProjection),
+            subscription_lifecycle_interests(),
+        )
+        .await?;
+    tx.commit().await?;
+
+    assert_eq!(
+        compaction,
+        JournalCompaction {
+            removed: 2,
+            has_more: false,
+        }
+    );
+    assert_eq!(batch.events().len(), 1);
+    assert_eq!(batch.events()[0].event(), &subscribed_event("c"));
+
+    // Positions keep increasing after the rows below them are gone.
+    append_subscription_events(&db, &["d"]).await?;
+    let mut tx = db.begin().await?;
+    let batch = tx
+        .read_after(
+            &EventCursor::at(ProcessorId::FeedProjection, EventCursorPos::position("3")),
+            subscription_lifecycle_interests(),
+        )
+        .await?;
+    tx.commit().await?;
+    assert_eq!(
+        batch.scanned_cursor(),
+        &EventCursor::at(ProcessorId::FeedProjection, EventCursorPos::position("4"))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn compaction_stops_at_limit() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    append_subscription_events(&db, &["a", "b", "c"]).await?;
+    advance_all_cursors(&db, ["3", "3", "3", "3", "3"]).await?;
+
+    let mut tx = db.begin().await?;
+    let first = tx.compact_journal(&ProcessorId::ALL, 2).await?;
+    let second = tx.compact_journal(&ProcessorId::ALL, 2).await?;
+    tx.commit().await?;
+
+    assert_eq!(
+        first,
+        JournalCompaction {
+            removed: 2,
+            has_more: true,
+        }
+    );
+    assert_eq!(
+        second,
+        JournalCompaction {
+            removed: 1,
+            has_more: false,
+        }
+    );
+    Ok(())
+}
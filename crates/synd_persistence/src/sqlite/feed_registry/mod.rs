@@ -9,6 +9,7 @@ use synd_registry::{
 use self::error::{IntoDbResult, SqliteResult};
 use super::SqliteDatabase;
 
+mod article;
 mod blob;
 mod codec;
 mod crawl;
@@ -18,6 +19,9 @@ mod feed;
 
 mod journal;
 mod pagination;
+mod read;
+mod saved;
+mod search;
 mod subscription;
 #[cfg(test)]
 mod test_support;
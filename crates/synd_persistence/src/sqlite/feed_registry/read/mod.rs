@@ -0,0 +1,155 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Sqlite, Transaction};
+use synd_feed::entry::EntryId;
+use synd_registry::{
+    RegistryDbResult, db::ReadMarkDb, read::EntryReadState, subscription::SubscriberId,
+};
+
+use super::error::{DecodeResultExt, IntoDbResult, SqliteResult};
+
+fn encode_entry_ids(entry_ids: &[EntryId]) -> SqliteResult<String> {
+    let entry_ids = entry_ids.iter().map(EntryId::as_str).collect::<Vec<_>>();
+    Ok(serde_json::to_string(&entry_ids)?)
+}
+
+async fn load_states(
+    tx: &mut Transaction<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+    entry_ids: &[EntryId],
+) -> SqliteResult<Vec<EntryReadState>> {
+    if entry_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Only live entries can be marked: tombstones and entries whose
+    // subscription ended are not on the subscriber's timeline
+    let rows = sqlx::query_as::<_, EntryReadRow>(
+        r#"
+            WITH requested(entry_id) AS (
+                SELECT DISTINCT CAST(value AS TEXT)
+                FROM json_each(?)
+            )
+            SELECT
+                te.entry_id,
+                er.entry_id IS NOT NULL AS read
+            FROM requested AS r
+            INNER JOIN timeline_entry AS te
+                ON te.entry_id = r.entry_id
+            INNER JOIN entry AS e
+                ON e.entry_id = te.entry_id
+            INNER JOIN feed_subscription AS s
+                ON s.subscriber_id = te.subscriber_id
+               AND s.feed_pk = e.feed_pk
+            LEFT JOIN entry_read AS er
+                ON er.subscriber_id = te.subscriber_id
+               AND er.entry_id = te.entry_id
+            WHERE te.subscriber_id = ?
+              AND te.deleted = 0
+            ORDER BY te.entry_id
+            "#,
+    )
+    .bind(encode_entry_ids(entry_ids)?)
+    .bind(subscriber_id.as_str())
+    .fetch_all(&mut **tx)
+    .await?;
+
+    rows.into_iter()
+        .map(|row| {
+            let entry_id = EntryId::parse(row.entry_id).decode()?;
+            Ok(EntryReadState::new(entry_id, row.read))
+        })
+        .collect()
+}
+
+async fn mark_read(
+    tx: &mut Transaction<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+    entry_ids: &[EntryId],
+    read_at: DateTime<Utc>,
+) -> SqliteResult<()> {
+    if entry_ids.is_empty() {
+        return Ok(());
+    }
+
+    sqlx::query(
+        r#"
+            INSERT OR IGNORE INTO entry_read (subscriber_id, entry_id, read_at)
+            SELECT ?, CAST(value AS TEXT), ?
+            FROM json_each(?)
+            "#,
+    )
+    .bind(subscriber_id.as_str())
+    .bind(read_at)
+    .bind(encode_entry_ids(entry_ids)?)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+async fn unmark_read(
+    tx: &mut Transaction<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+    entry_ids: &[EntryId],
+) -> SqliteResult<()> {
+    if entry_ids.is_empty() {
+        return Ok(());
+    }
+
+    sqlx::query(
+        r#"
+            DELETE FROM entry_read
+            WHERE subscriber_id = ?
+              AND entry_id IN (
+                  SELECT CAST(value AS TEXT)
+                  FROM json_each(?)
+              )
+            "#,
+    )
+    .bind(subscriber_id.as_str())
+    .bind(encode_entry_ids(entry_ids)?)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+#[derive(sqlx::FromRow)]
+struct EntryReadRow {
+    entry_id: String,
+    read: bool,
+}
+
+impl ReadMarkDb for super::SqliteRegistryTx<'_> {
+    async fn load_entry_read_states(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_ids: &[EntryId],
+    ) -> RegistryDbResult<Vec<EntryReadState>> {
+        load_states(&mut self.tx, subscriber_id, entry_ids)
+            .await
+            .db()
+    }
+
+    async fn mark_entries_read(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_ids: &[EntryId],
+        read_at: DateTime<Utc>,
+    ) -> RegistryDbResult<()> {
+        mark_read(&mut self.tx, subscriber_id, entry_ids, read_at)
+            .await
+            .db()
+    }
+
+    async fn unmark_entries_read(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_ids: &[EntryId],
+    ) -> RegistryDbResult<()> {
+        unmark_read(&mut self.tx, subscriber_id, entry_ids)
+            .await
+            .db()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,135 @@
+use crate::sqlite::feed_registry::test_support::*;
+
+/// Subscribes to a feed with one entry and projects it onto the timeline.
+async fn timeline_with_entry(db: &SqliteFeedRegistryDb, path: &str) -> anyhow::Result<EntryId> {
+    let subscription = subscription(path);
+    let crawl = record_fetched_crawl(
+        db,
+        &subscription.feed_url,
+        rss_body_with_entry("read feed", "entry", "entry-1"),
+        0,
+    )
+    .await?;
+    project_feed(db, crawl).await?;
+    store_subscription_in_db(db, subscription.clone()).await?;
+    project_timeline(
+        db,
+        TimelineProjInput::FeedSubscribed(feed_subscribed_event(&subscription)),
+    )
+    .await?;
+
+    let page = list_timeline_entries(db, subscriber_id()).await?;
+    Ok(page.nodes[0].entry.id().clone())
+}
+
+async fn load_read_states(
+    db: &SqliteFeedRegistryDb,
+    entry_ids: &[EntryId],
+) -> anyhow::Result<Vec<EntryReadState>> {
+    let mut tx = db.begin().await?;
+    let states = tx
+        .load_entry_read_states(&subscriber_id(), entry_ids)
+        .await?;
+    tx.commit().await?;
+    Ok(states)
+}
+
+#[tokio::test]
+async fn read_marks_round_trip_through_timeline_entries() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let entry_id = timeline_with_entry(&db, "read-round-trip").await?;
+
+    assert_eq!(
+        load_read_states(&db, std::slice::from_ref(&entry_id)).await?,
+        vec![EntryReadState::new(entry_id.clone(), false)]
+    );
+
+    let mut tx = db.begin().await?;
+    tx.mark_entries_read(
+        &subscriber_id(),
+        std::slice::from_ref(&entry_id),
+        test_occurred_at(),
+    )
+    .await?;
+    tx.commit().await?;
+
+    assert_eq!(
+        load_read_states(&db, std::slice::from_ref(&entry_id)).await?,
+        vec![EntryReadState::new(entry_id.clone(), true)]
+    );
+    let page = list_timeline_entries(&db, subscriber_id()).await?;
+    assert!(page.nodes[0].read);
+
+    let mut tx = db.begin().await?;
+    tx.unmark_entries_read(&subscriber_id(), std::slice::from_ref(&entry_id))
+        .await?;
+    tx.commit().await?;
+
+    let page = list_timeline_entries(&db, subscriber_id()).await?;
+    assert!(!page.nodes[0].read);
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_states_skip_entries_missing_from_timeline() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let entry_id = timeline_with_entry(&db, "read-missing").await?;
+    let unknown =
+        EntryId::parse(format!("synd:entry:v1:{}", "0".repeat(64))).expect("valid entry id");
+
+    let states = load_read_states(&db, &[unknown, entry_id.clone()]).await?;
+
+    assert_eq!(states, vec![EntryReadState::new(entry_id, false)]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_mark_projection_bumps_timeline_change_seq() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let entry_id = timeline_with_entry(&db, "read-sync").await?;
+    let since = list_timeline_entries(&db, subscriber_id()).await?.seq;
+
+    let mut tx = db.begin().await?;
+    tx.mark_entries_read(
+        &subscriber_id(),
+        std::slice::from_ref(&entry_id),
+        test_occurred_at(),
+    )
+    .await?;
+    tx.commit().await?;
+    let recorded = project_timeline(
+        &db,
+        TimelineProjInput::EntriesMarkedRead(EntriesMarkedReadEvent::new(
+            subscriber_id(),
+            vec![entry_id.clone()],
+        )),
+    )
+    .await?;
+
+    assert_eq!(recorded.types(), &[TimelineChangedEvent::TYPE]);
+    let mut tx = db.begin().await?;
+    let page = tx
+        .list_timeline_changes(TimelineChangesQuery {
+            subscriber_id: subscriber_id(),
+            since,
+            limit: 10,
+        })
+        .await?;
+    tx.commit().await?;
+    let [TimelineChange::Upsert(entry)] = page.changes.as_slice() else {
+        panic!("expected one upsert, got {:?}", page.changes);
+    };
+    assert_eq!(entry.entry.id(), &entry_id);
+    assert!(entry.read);
+
+    let recorded = project_timeline(
+        &db,
+        TimelineProjInput::EntriesMarkedUnread(EntriesMarkedUnreadEvent::new(
+            subscriber_id(),
+            vec![entry_id],
+        )),
+    )
+    .await?;
+    assert_eq!(recorded.types(), &[TimelineChangedEvent::TYPE]);
+    Ok(())
+}
@@ -0,0 +1,206 @@
+use chrono::{DateTime, Utc};
+use sqlx::{QueryBuilder, Sqlite, Transaction};
+use synd_feed::entry::EntryId;
+use synd_registry::{
+    RegistryDbResult,
+    db::SavedEntryDb,
+    query::{SavedEntriesPage, SavedEntriesQuery, SavedEntry, TimelineEntry},
+    subscription::SubscriberId,
+};
+
+use super::{
+    error::{IntoDbResult, SqliteResult},
+    pagination::PageLimit,
+    timeline::TimelineEntryRow,
+};
+
+// Saved entries may have left the timeline, so the subscription is joined
+// optionally and the entry's own order time stands in for the timeline one
+const SAVED_ENTRY_SELECT: &str = r#"
+SELECT
+    es.saved_at,
+    e.order_time,
+    es.entry_id,
+    e.entry_json,
+    f.url AS feed_url,
+    fs.meta_json,
+    s.requirement,
+    s.category,
+    er.entry_id IS NOT NULL AS read,
+    1 AS saved
+FROM entry_saved AS es
+INNER JOIN entry AS e
+    ON e.entry_id = es.entry_id
+INNER JOIN feed AS f
+    ON f.pk = e.feed_pk
+INNER JOIN feed_snapshot AS fs
+    ON fs.feed_pk = f.pk
+LEFT JOIN feed_subscription AS s
+    ON s.subscriber_id = es.subscriber_id
+   AND s.feed_pk = f.pk
+LEFT JOIN entry_read AS er
+    ON er.subscriber_id = es.subscriber_id
+   AND er.entry_id = es.entry_id
+"#;
+
+async fn load_saved(
+    tx: &mut Transaction<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+    entry_id: &EntryId,
+) -> SqliteResult<Option<bool>> {
+    // Only live timeline entries can be saved, while a saved entry can be
+    // unsaved after it left the timeline
+    let (saved, live) = sqlx::query_as::<_, (bool, bool)>(
+        r#"
+            SELECT
+                EXISTS (
+                    SELECT 1
+                    FROM entry_saved
+                    WHERE subscriber_id = ?1
+                      AND entry_id = ?2
+                ),
+                EXISTS (
+                    SELECT 1
+                    FROM timeline_entry AS te
+                    INNER JOIN entry AS e
+                        ON e.entry_id = te.entry_id
+                    INNER JOIN feed_subscription AS s
+                        ON s.subscriber_id = te.subscriber_id
+                       AND s.feed_pk = e.feed_pk
+                    WHERE te.subscriber_id = ?1
+                      AND te.entry_id = ?2
+                      AND te.deleted = 0
+                )
+            "#,
+    )
+    .bind(subscriber_id.as_str())
+    .bind(entry_id.as_str())
+    .fetch_one(&mut **tx)
+    .await?;
+
+    Ok((saved || live).then_some(saved))
+}
+
+async fn save(
+    tx: &mut Transaction<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+    entry_id: &EntryId,
+    saved_at: DateTime<Utc>,
+) -> SqliteResult<()> {
+    sqlx::query(
+        r#"
+            INSERT OR IGNORE INTO entry_saved (subscriber_id, entry_id, saved_at)
+            VALUES (?, ?, ?)
+            "#,
+    )
+    .bind(subscriber_id.as_str())
+    .bind(entry_id.as_str())
+    .bind(saved_at)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+async fn unsave(
+    tx: &mut Transaction<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+    entry_id: &EntryId,
+) -> SqliteResult<()> {
+    sqlx::query(
+        r#"
+            DELETE FROM entry_saved
+            WHERE subscriber_id = ?
+              AND entry_id = ?
+            "#,
+    )
+    .bind(subscriber_id.as_str())
+    .bind(entry_id.as_str())
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+async fn list_saved(
+    tx: &mut Transaction<'_, Sqlite>,
+    query: SavedEntriesQuery,
+) -> SqliteResult<SavedEntriesPage> {
+    let limit = PageLimit::new(query.first);
+    let mut sql = QueryBuilder::<Sqlite>::new(SAVED_ENTRY_SELECT);
+    sql.push(" WHERE es.subscriber_id = ");
+    sql.push_bind(query.subscriber_id.as_str());
+    if let Some(after) = &query.after {
+        sql.push(" AND (es.saved_at, es.entry_id) < (");
+        sql.push_bind(after.saved_at());
+        sql.push(", ");
+        sql.push_bind(after.entry_id().as_str().to_owned());
+        sql.push(")");
+    }
+    sql.push(" ORDER BY es.saved_at DESC, es.entry_id DESC LIMIT ");
+    sql.push_bind(limit.sql_limit());
+
+    let mut nodes = sql
+        .build_query_as::<SavedEntryRow>()
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .map(|row| {
+            Ok(SavedEntry {
+                saved_at: row.saved_at,
+                node: TimelineEntry::try_from(row.entry)?,
+            })
+        })
+        .collect::<SqliteResult<Vec<_>>>()?;
+    let has_next_page = limit.truncate_overfetch(&mut nodes);
+    let end_cursor = nodes.last().map(SavedEntry::cursor);
+    Ok(SavedEntriesPage {
+        nodes,
+        has_next_page,
+        end_cursor,
+    })
+}
+
+#[derive(sqlx::FromRow)]
+struct SavedEntryRow {
+    saved_at: DateTime<Utc>,
+    #[sqlx(flatten)]
+    entry: TimelineEntryRow,
+}
+
+impl SavedEntryDb for super::SqliteRegistryTx<'_> {
+    async fn load_entry_saved(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+    ) -> RegistryDbResult<Option<bool>> {
+        load_saved(&mut self.tx, subscriber_id, entry_id).await.db()
+    }
+
+    async fn save_entry(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+        saved_at: DateTime<Utc>,
+    ) -> RegistryDbResult<()> {
+        save(&mut self.tx, subscriber_id, entry_id, saved_at)
+            .await
+            .db()
+    }
+
+    async fn unsave_entry(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+    ) -> RegistryDbResult<()> {
+        unsave(&mut self.tx, subscriber_id, entry_id).await.db()
+    }
+
+    async fn list_saved_entries(
+        &mut self,
+        query: SavedEntriesQuery,
+    ) -> RegistryDbResult<SavedEntriesPage> {
+        list_saved(&mut self.tx, query).await.db()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,178 @@
+use crate::sqlite::feed_registry::test_support::*;
+
+/// Subscribes to a feed with one entry and projects it onto the timeline.
+async fn timeline_with_entry(
+    db: &SqliteFeedRegistryDb,
+    subscription: &Subscription,
+) -> anyhow::Result<EntryId> {
+    let crawl = record_fetched_crawl(
+        db,
+        &subscription.feed_url,
+        rss_body_with_entry("saved feed", "entry", "entry-1"),
+        0,
+    )
+    .await?;
+    project_feed(db, crawl).await?;
+    store_subscription_in_db(db, subscription.clone()).await?;
+    project_timeline(
+        db,
+        TimelineProjInput::FeedSubscribed(feed_subscribed_event(subscription)),
+    )
+    .await?;
+
+    let page = list_timeline_entries(db, subscriber_id()).await?;
+    Ok(page.nodes[0].entry.id().clone())
+}
+
+async fn load_saved(db: &SqliteFeedRegistryDb, entry_id: &EntryId) -> anyhow::Result<Option<bool>> {
+    let mut tx = db.begin().await?;
+    let saved = tx.load_entry_saved(&subscriber_id(), entry_id).await?;
+    tx.commit().await?;
+    Ok(saved)
+}
+
+async fn save(db: &SqliteFeedRegistryDb, entry_id: &EntryId) -> anyhow::Result<()> {
+    let mut tx = db.begin().await?;
+    tx.save_entry(&subscriber_id(), entry_id, test_occurred_at())
+        .await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+async fn list_saved(db: &SqliteFeedRegistryDb) -> anyhow::Result<Vec<EntryId>> {
+    let mut tx = db.begin().await?;
+    let page = tx
+        .list_saved_entries(SavedEntriesQuery {
+            subscriber_id: subscriber_id(),
+            after: None,
+            first: 10,
+        })
+        .await?;
+    tx.commit().await?;
+    Ok(page
+        .nodes
+        .iter()
+        .map(|saved| saved.node.entry.id().clone())
+        .collect())
+}
+
+#[tokio::test]
+async fn saved_entries_round_trip_through_timeline_entries() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let entry_id = timeline_with_entry(&db, &subscription("saved-round-trip")).await?;
+
+    assert_eq!(load_saved(&db, &entry_id).await?, Some(false));
+
+    save(&db, &entry_id).await?;
+
+    assert_eq!(load_saved(&db, &entry_id).await?, Some(true));
+    assert_eq!(list_saved(&db).await?, vec![entry_id.clone()]);
+    let page = list_timeline_entries(&db, subscriber_id()).await?;
+    assert!(page.nodes[0].saved);
+
+    let mut tx = db.begin().await?;
+    tx.unsave_entry(&subscriber_id(), &entry_id).await?;
+    tx.commit().await?;
+
+    assert!(list_saved(&db).await?.is_empty());
+    let page = list_timeline_entries(&db, subscriber_id()).await?;
+    assert!(!page.nodes[0].saved);
+    Ok(())
+}
+
+#[tokio::test]
+async fn saved_state_is_unknown_for_entries_missing_from_timeline() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    timeline_with_entry(&db, &subscription("saved-missing")).await?;
+    let unknown =
+        EntryId::parse(format!("synd:entry:v1:{}", "0".repeat(64))).expect("valid entry id");
+
+    assert_eq!(load_saved(&db, &unknown).await?, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn saved_entry_outlives_feed_churn_and_unsubscribe() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let subscription = subscription("saved-churn");
+    let entry_id = timeline_with_entry(&db, &subscription).await?;
+    save(&db, &entry_id).await?;
+
+    // The next body no longer declares the saved entry
+    let crawl = record_fetched_crawl(
+        &db,
+        &subscription.feed_url,
+        rss_body_with_entry("saved feed", "newer entry", "entry-2"),
+        1,
+    )
+    .await?;
+    project_feed(&db, crawl).await?;
+    let mut tx = db.begin().await?;
+    tx.delete_subscription(&subscriber_id(), &subscription.feed_url)
+        .await?;
+    tx.commit().await?;
+    project_timeline(
+        &db,
+        TimelineProjInput::FeedUnsubscribed(FeedUnsubscribedEvent::new(subscription_key(
+            &subscription,
+        ))),
+    )
+    .await?;
+
+    assert!(
+        list_timeline_entries(&db, subscriber_id())
+            .await?
+            .nodes
+            .is_empty()
+    );
+    assert_eq!(load_saved(&db, &entry_id).await?, Some(true));
+
+    let mut tx = db.begin().await?;
+    let page = tx
+        .list_saved_entries(SavedEntriesQuery {
+            subscriber_id: subscriber_id(),
+            after: None,
+            first: 10,
+        })
+        .await?;
+    tx.commit().await?;
+    let [saved] = page.nodes.as_slice() else {
+        panic!("expected one saved entry, got {:?}", page.nodes);
+    };
+    assert_eq!(saved.node.entry.id(), &entry_id);
+    assert_eq!(saved.saved_at, test_occurred_at());
+    assert!(saved.node.saved);
+    assert_eq!(saved.node.feed_meta.requirement, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_projection_bumps_timeline_change_seq() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let entry_id = timeline_with_entry(&db, &subscription("saved-sync")).await?;
+    let since = list_timeline_entries(&db, subscriber_id()).await?.seq;
+
+    save(&db, &entry_id).await?;
+    let recorded = project_timeline(
+        &db,
+        TimelineProjInput::EntrySaved(EntrySavedEvent::new(subscriber_id(), entry_id.clone())),
+    )
+    .await?;
+
+    assert_eq!(recorded.types(), &[TimelineChangedEvent::TYPE]);
+    let mut tx = db.begin().await?;
+    let page = tx
+        .list_timeline_changes(TimelineChangesQuery {
+            subscriber_id: subscriber_id(),
+            since,
+            limit: 10,
+        })
+        .await?;
+    tx.commit().await?;
+    let [TimelineChange::Upsert(entry)] = page.changes.as_slice() else {
+        panic!("expected one upsert, got {:?}", page.changes);
+    };
+    assert_eq!(entry.entry.id(), &entry_id);
+    assert!(entry.saved);
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use sqlx::{Sqlite, Transaction};
+use synd_feed::{
+    entry::{Content, Entry},
+    types::Text,
+};
+
+use super::error::SqliteResult;
+
+/// Rewrites the search index row of one entry from its current document.
+pub(super) async fn index_entry(
+    tx: &mut Transaction<'_, Sqlite>,
+    entry: &Entry,
+) -> SqliteResult<()> {
+    let doc_pk = sqlx::query_scalar::<_, i64>(
+        r#"
+            INSERT INTO entry_search_doc (entry_id)
+            VALUES (?)
+            ON CONFLICT(entry_id) DO UPDATE SET
+                entry_id = excluded.entry_id
+            RETURNING pk
+            "#,
+    )
+    .bind(entry.id().as_str())
+    .fetch_one(&mut **tx)
+    .await?;
+
+    sqlx::query(
+        r#"
+            DELETE FROM entry_search
+            WHERE rowid = ?
+            "#,
+    )
+    .bind(doc_pk)
+    .execute(&mut **tx)
+    .await?;
+
+    sqlx::query(
+        r#"
+            INSERT INTO entry_search (rowid, title, summary, content)
+            VALUES (?, ?, ?, ?)
+            "#,
+    )
+    .bind(doc_pk)
+    .bind(entry.title().map(text_of).unwrap_or_default())
+    .bind(entry.summary().map(text_of).unwrap_or_default())
+    .bind(entry.content().map(content_text).unwrap_or_default())
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// Drops the search index rows of the entries in `entry_ids_json`, a JSON
+/// array of entry ids.
+pub(super) async fn unindex_entries(
+    tx: &mut Transaction<'_, Sqlite>,
+    entry_ids_json: &str,
+) -> SqliteResult<()> {
+    sqlx::query(
+        r#"
+            DELETE FROM entry_search
+            WHERE rowid IN (
+                SELECT d.pk
+                FROM entry_search_doc AS d
+                WHERE d.entry_id IN (SELECT CAST(value AS TEXT) FROM json_each(?))
+            )
+            "#,
+    )
+    .bind(entry_ids_json)
+    .execute(&mut **tx)
+    .await?;
+
+    sqlx::query(
+        r#"
+            DELETE FROM entry_search_doc
+            WHERE entry_id IN (SELECT CAST(value AS TEXT) FROM json_each(?))
+            "#,
+    )
+    .bind(entry_ids_json)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// Builds an FTS5 match expression requiring every word of `text`, each as a
+/// prefix so partially typed words still match. Words are quoted, so FTS5
+/// operators typed by users are searched for literally. `None` when `text`
+/// has no words.
+pub(super) fn match_expression(text: &str) -> Option<String> {
+    let terms = text
+        .split_whitespace()
+        .map(|word| format!("\"{}\"*", word.replace('"', "\"\"")))
+        .collect::<Vec<_>>();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+fn text_of(text: &Text) -> String {
+    searchable(text.content(), text.content_type())
+}
+
+fn content_text(content: &Content) -> String {
+    content
+        .body()
+        .map(|body| searchable(body, content.content_type()))
+        .unwrap_or_default()
+}
+
+fn searchable(value: &str, content_type: &str) -> String {
+    if content_type.contains("html") {
+        strip_markup(value)
+    } else {
+        value.to_owned()
+    }
+}
+
+/// Drops tags and character references, which would otherwise be indexed as
+/// words. Each becomes a space so the words around them stay apart.
+fn strip_markup(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find(['<', '&']) {
+        text.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(len) = markup_len(rest) {
+            text.push(' ');
+            rest = &rest[len..];
+        } else {
+            text.push_str(&rest[..1]);
+            rest = &rest[1..];
+        }
+    }
+    text.push_str(rest);
+    text
+}
+
+/// Length of the tag or character reference `rest` starts with.
+fn markup_len(rest: &str) -> Option<usize> {
+    if let Some(after) = rest.strip_prefix('<') {
+        let opens_tag = after
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '!');
+        if !opens_tag {
+            return None;
+        }
+        rest.find('>').map(|end| end + 1)
+    } else {
+        let end = rest.find(';').filter(|end| *end <= 10)?;
+        rest[1..end]
+            .chars()
+            .all(|c| c == '#' || c.is_ascii_alphanumeric())
+            .then_some(end + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_expression_requires_every_word_as_prefix() {
+        assert_eq!(
+            match_expression("  rust  async ").as_deref(),
+            Some(r#""rust"* "async"*"#)
+        );
+        assert_eq!(
+            match_expression(r#"say "hi" OR"#).as_deref(),
+            Some(r#""say"* """hi"""* "OR"*"#)
+        );
+        assert_eq!(match_expression("   "), None);
+    }
+
+    #[test]
+    fn strip_markup_keeps_words_apart() {
+        assert_eq!(
+            strip_markup("<p>Rust&nbsp;async</p><p>runtime &amp; tools, 1 < 2</p>"),
+            " Rust async  runtime   tools, 1 < 2 "
+        );
+    }
+}
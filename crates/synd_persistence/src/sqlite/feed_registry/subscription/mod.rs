@@ -7,7 +7,9 @@ use synd_registry::{
     FeedSubscriptionAttrs, RegistryDbResult, SubscriberId, Subscription, SubscriptionKey,
     crawl::target_list::{FeedSubscriptions, SubscriptionPolicy},
     db::SubscriptionDb,
-    query::{Subscriptions, SubscriptionsQuery},
+    query::{
+        SubscriberSummary, SubscribersPage, SubscribersQuery, Subscriptions, SubscriptionsQuery,
+    },
 };
 
 use super::{
@@ -23,6 +25,7 @@ f.url AS feed_url,
 s.requirement AS requirement,
 s.category AS category,
 s.crawl_policy_json AS crawl_policy_json,
+s.fetch_full_content AS fetch_full_content,
 s.subscribed_at AS subscribed_at
 "#;
 
@@ -46,13 +49,15 @@ async fn upsert(
                 requirement,
                 category,
                 crawl_policy_json,
+                fetch_full_content,
                 subscribed_at
             )
-            VALUES (?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
             ON CONFLICT(subscriber_id, feed_pk) DO UPDATE SET
                 requirement = excluded.requirement,
                 category = excluded.category,
-                crawl_policy_json = excluded.crawl_policy_json
+                crawl_policy_json = excluded.crawl_policy_json,
+                fetch_full_content = excluded.fetch_full_content
             "#,
     )
     .bind(subscription.subscriber_id.as_str())
@@ -60,6 +65,7 @@ async fn upsert(
     .bind(requirement)
     .bind(category)
     .bind(policy_json)
+    .bind(attrs.fetch_full_content)
     .bind(now)
     .execute(&mut **tx)
     .await?;
@@ -199,6 +205,109 @@ async fn load_for_feed(
     Ok(FeedSubscriptions::new(feed_url.clone(), subscriptions))
 }
 
+async fn list_for_feed(
+    tx: &mut Transaction<'_, Sqlite>,
+    feed_url: &FeedUrl,
+) -> SqliteResult<Vec<Subscription>> {
+    let sql = format!(
+        r#"
+            SELECT {SUBSCRIPTION_SELECT_COLUMNS}
+            FROM feed_subscription AS s
+            INNER JOIN feed AS f
+                ON f.pk = s.feed_pk
+            WHERE f.url = ?
+            ORDER BY s.subscriber_id
+            "#
+    );
+    let rows = sqlx::query_as::<_, SubscriptionRow>(&sql)
+        .bind(feed_url.as_str())
+        .fetch_all(&mut **tx)
+        .await?;
+
+    rows.into_iter()
+        .map(SubscriptionRow::into_subscription)
+        .collect()
+}
+
+async fn list_subscribers(
+    tx: &mut Transaction<'_, Sqlite>,
+    query: SubscribersQuery,
+) -> SqliteResult<SubscribersPage> {
+    let page_limit = PageLimit::new(query.first);
+    let rows = sqlx::query_as::<_, SubscriberSummaryRow>(
+        r#"
+            SELECT
+                subscriber_id,
+                COUNT(*) AS subscriptions,
+                MIN(subscribed_at) AS first_subscribed_at
+            FROM feed_subscription
+            WHERE ? IS NULL OR subscriber_id > ?
+            GROUP BY subscriber_id
+            ORDER BY subscriber_id
+            LIMIT ?
+            "#,
+    )
+    .bind(query.after.as_ref().map(SubscriberId::as_str))
+    .bind(query.after.as_ref().map(SubscriberId::as_str))
+    .bind(page_limit.sql_limit())
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let mut subscribers = rows
+        .into_iter()
+        .map(SubscriberSummaryRow::into_summary)
+        .collect::<SqliteResult<Vec<_>>>()?;
+    let has_next_page = page_limit.truncate_overfetch(&mut subscribers);
+    let end_cursor = subscribers
+        .last()
+        .map(|summary| summary.subscriber_id.clone());
+
+    Ok(SubscribersPage {
+        subscribers,
+        has_next_page,
+        end_cursor,
+    })
+}
+
+async fn load_subscriber(
+    tx: &mut Transaction<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+) -> SqliteResult<Option<SubscriberSummary>> {
+    let row = sqlx::query_as::<_, SubscriberSummaryRow>(
+        r#"
+            SELECT
+                subscriber_id,
+                COUNT(*) AS subscriptions,
+                MIN(subscribed_at) AS first_subscribed_at
+            FROM feed_subscription
+            WHERE subscriber_id = ?
+            GROUP BY subscriber_id
+            "#,
+    )
+    .bind(subscriber_id.as_str())
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    row.map(SubscriberSummaryRow::into_summary).transpose()
+}
+
+#[derive(sqlx::FromRow)]
+struct SubscriberSummaryRow {
+    subscriber_id: String,
+    subscriptions: i64,
+    first_subscribed_at: DateTime<Utc>,
+}
+
+impl SubscriberSummaryRow {
+    fn into_summary(self) -> SqliteResult<SubscriberSummary> {
+        Ok(SubscriberSummary {
+            subscriber_id: SubscriberId::new(self.subscriber_id),
+            subscriptions: usize::try_from(self.subscriptions).decode()?,
+            first_subscribed_at: self.first_subscribed_at,
+        })
+    }
+}
+
 #[derive(sqlx::FromRow)]
 struct SubscriptionRow {
     subscriber_id: String,
@@ -206,6 +315,7 @@ struct SubscriptionRow {
     requirement: Option<String>,
     category: Option<String>,
     crawl_policy_json: String,
+    fetch_full_content: bool,
     subscribed_at: DateTime<Utc>,
 }
 
@@ -222,6 +332,7 @@ impl SubscriptionRow {
                 .decode()?,
             category: self.category.map(Category::new).transpose().decode()?,
             crawl_policy: codec::decode_crawl_policy_json(&self.crawl_policy_json)?,
+            fetch_full_content: self.fetch_full_content,
             subscribed_at: self.subscribed_at,
         })
     }
@@ -287,6 +398,27 @@ impl SubscriptionDb for SqliteRegistryTx<'_> {
     ) -> RegistryDbResult<FeedSubscriptions> {
         load_for_feed(&mut self.tx, feed_url).await.db()
     }
+
+    async fn load_feed_subscribers(
+        &mut self,
+        feed_url: &FeedUrl,
+    ) -> RegistryDbResult<Vec<Subscription>> {
+        list_for_feed(&mut self.tx, feed_url).await.db()
+    }
+
+    async fn list_subscribers(
+        &mut self,
+        query: SubscribersQuery,
+    ) -> RegistryDbResult<SubscribersPage> {
+        list_subscribers(&mut self.tx, query).await.db()
+    }
+
+    async fn load_subscriber(
+        &mut self,
+        subscriber_id: &SubscriberId,
+    ) -> RegistryDbResult<Option<SubscriberSummary>> {
+        load_subscriber(&mut self.tx, subscriber_id).await.db()
+    }
 }
 
 #[cfg(test)]
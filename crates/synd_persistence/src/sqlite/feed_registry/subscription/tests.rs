@@ -39,8 +39,10 @@ async fn subscription_reads_share_the_feed_ledger() -> anyhow::Result<()> {
         })
         .await?;
     let feed_subscriptions = tx.load_feed_subscriptions(&subscription.feed_url).await?;
+    let feed_subscribers = tx.load_feed_subscribers(&subscription.feed_url).await?;
 
     assert_eq!(page.subscriptions, vec![subscription.clone()]);
+    assert_eq!(feed_subscribers, vec![subscription.clone()]);
     assert_eq!(feed_subscriptions.feed_url, subscription.feed_url);
     assert_eq!(feed_subscriptions.subscriptions.len(), 1);
     assert_eq!(
@@ -88,3 +90,67 @@ async fn editing_attributes_keeps_subscribed_at() -> anyhow::Result<()> {
     assert_eq!(page.subscriptions[0].subscribed_at, subscribed_at);
     Ok(())
 }
+
+#[tokio::test]
+async fn summarizes_subscribers() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let reader = SubscriberId::new("reader");
+    let mut later = subscription_with(
+        reader.clone(),
+        "summary-later",
+        CrawlPolicy::interval(interval(3600)),
+    );
+    later.subscribed_at += chrono::Duration::days(1);
+
+    let mut tx = db.begin().await?;
+    store_subscription(&mut tx, subscription("summary-local")).await?;
+    store_subscription(
+        &mut tx,
+        subscription_with(
+            reader.clone(),
+            "summary-earlier",
+            CrawlPolicy::interval(interval(3600)),
+        ),
+    )
+    .await?;
+    store_subscription(&mut tx, later).await?;
+    tx.commit().await?;
+
+    let mut tx = db.begin().await?;
+    let first = tx
+        .list_subscribers(SubscribersQuery {
+            after: None,
+            first: 1,
+        })
+        .await?;
+    let second = tx
+        .list_subscribers(SubscribersQuery {
+            after: first.end_cursor.clone(),
+            first: 1,
+        })
+        .await?;
+    let reader_summary = tx.load_subscriber(&reader).await?;
+    let unknown = tx.load_subscriber(&SubscriberId::new("unknown")).await?;
+
+    assert_eq!(
+        first.subscribers,
+        vec![SubscriberSummary {
+            subscriber_id: subscriber_id(),
+            subscriptions: 1,
+            first_subscribed_at: subscription("summary-local").subscribed_at,
+        }]
+    );
+    assert!(first.has_next_page);
+    assert_eq!(second.subscribers.len(), 1);
+    assert!(!second.has_next_page);
+    assert_eq!(
+        reader_summary,
+        Some(SubscriberSummary {
+            subscriber_id: reader,
+            subscriptions: 2,
+            first_subscribed_at: subscription("summary-earlier").subscribed_at,
+        })
+    );
+    assert_eq!(unknown, None);
+    Ok(())
+}
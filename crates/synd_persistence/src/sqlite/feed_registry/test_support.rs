@@ -3,30 +3,42 @@ pub(crate) use std::time::Duration;
 pub(crate) use chrono::{DateTime, TimeZone, Utc};
 pub(crate) use sqlx::Row;
 pub(crate) use synd_feed::feed::service::FeedHttpStatus;
-pub(crate) use synd_feed::types::FeedUrl;
+pub(crate) use synd_feed::{entry::EntryId, types::FeedUrl};
 pub(crate) use synd_registry::{
     FeedSubscriptionAttrs, RegistryDbResult, SubscriberId, Subscription, SubscriptionKey,
+    article::{ArticleFetch, ArticleFetchOutcome},
     crawl::{
-        blob::PutBlobCommand,
+        blob::{BlobCollection, PutBlobCommand},
+        due::{PUBLISH_ACTIVITY_SAMPLE_SIZE, PublishActivity},
         job::CrawlJobId,
         policy::{CrawlPolicy, PollingInterval, PollingPolicy},
         state::CrawlStateErrorKind,
         target_list::{CrawlTargetProj, CrawlTargetProjInput, CrawlTargetState},
     },
     db::{
-        BlobDb, CommitTx, CrawlStateDb, CrawlTargetDb, FeedDb, FeedRegistryDb, SubscriptionDb,
-        TimelineDb,
+        ArticleDb, BlobDb, CommitTx, CrawlHistoryDb, CrawlStateDb, CrawlTargetDb, FeedDb,
+        FeedRegistryDb, ReadMarkDb, SavedEntryDb, SubscriptionDb, TimelineDb,
     },
     event::{
         CrawlJobFinishedEvent, CrawlTargetActivatedEvent, CrawlTargetDeactivatedEvent,
-        CrawlTargetPolicyChangedEvent, EntryDiscoveredEvent, Event, EventCursor, EventCursorPos,
-        EventInterests, EventJournal, EventRecorder, FeedSubscribedEvent, FeedUnsubscribedEvent,
-        InputBatch, ProcessorId, Projector, RecordedEvents, RegistryEvent, SubEvent,
-        SubscriptionChangedEvent, TimelineChangedEvent,
+        CrawlTargetPolicyChangedEvent, EntriesMarkedReadEvent, EntriesMarkedUnreadEvent,
+        EntryDiscoveredEvent, EntrySavedEvent, Event, EventCursor, EventCursorPos, EventInterests,
+        EventJournal, EventJournalCompact, EventRecorder, FeedSubscribedEvent,
+        FeedUnsubscribedEvent, InputBatch, JournalCompaction, ProcessorId, Projector,
+        RecordedEvents, RegistryEvent, SubEvent, SubscriptionChangedEvent, TimelineChangedEvent,
     },
     feed::{FeedProj, FeedProjInput},
-    query::{SubscriptionsQuery, TimelineEntriesPage, TimelineEntriesQuery},
-    timeline::{TimelineProj, TimelineProjInput},
+    query::{
+        FeedEntriesQuery, SavedEntriesQuery, SubscriberSummary, SubscribersQuery,
+        SubscriptionsQuery, TimelineChange, TimelineChangesQuery, TimelineEntriesFilter,
+        TimelineEntriesPage, TimelineEntriesQuery, TimelineEntry, TimelineSearchPage,
+        TimelineSearchQuery,
+    },
+    read::EntryReadState,
+    timeline::{
+        TimelineProj, TimelineProjInput,
+        retention::{RetentionPurge, TimelinePrune, TimelineRetention},
+    },
 };
 pub(crate) use synd_support::time::Clock;
 
@@ -93,6 +105,7 @@ pub(crate) fn subscription_with(
         requirement: None,
         category: None,
         crawl_policy,
+        fetch_full_content: false,
         subscribed_at: Utc.with_ymd_and_hms(2026, 5, 24, 12, 0, 0).unwrap(),
     }
 }
@@ -109,6 +122,7 @@ pub(crate) fn subscription_attrs(subscription: &Subscription) -> FeedSubscriptio
         requirement: subscription.requirement,
         category: subscription.category.clone(),
         crawl_policy: subscription.crawl_policy,
+        fetch_full_content: subscription.fetch_full_content,
     }
 }
 
@@ -276,6 +290,7 @@ pub(crate) async fn list_timeline_entries(
     let page = tx
         .list_timeline_entries(TimelineEntriesQuery {
             subscriber_id,
+            filter: TimelineEntriesFilter::default(),
             after: None,
             first: 10,
         })
@@ -284,6 +299,27 @@ pub(crate) async fn list_timeline_entries(
     Ok(page)
 }
 
+pub(crate) async fn search_timeline_entries(
+    db: &SqliteFeedRegistryDb,
+    query: TimelineSearchQuery,
+) -> anyhow::Result<TimelineSearchPage> {
+    let mut tx = db.begin().await?;
+    let page = tx.search_timeline_entries(query).await?;
+    tx.commit().await?;
+    Ok(page)
+}
+
+pub(crate) async fn load_timeline_entry(
+    db: &SqliteFeedRegistryDb,
+    subscriber_id: &SubscriberId,
+    entry_id: &EntryId,
+) -> anyhow::Result<Option<TimelineEntry>> {
+    let mut tx = db.begin().await?;
+    let entry = tx.load_timeline_entry(subscriber_id, entry_id).await?;
+    tx.commit().await?;
+    Ok(entry)
+}
+
 pub(crate) fn rss_body_with_entry(
     feed_title: &str,
     entry_title: &str,
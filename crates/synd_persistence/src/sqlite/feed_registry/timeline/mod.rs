@@ -10,17 +10,22 @@ use synd_registry::{
     RegistryDbResult,
     db::TimelineDb,
     query::{
-        TimelineChange, TimelineChangesPage, TimelineChangesQuery, TimelineEntriesPage,
-        TimelineEntriesQuery, TimelineEntry, TimelineEntryCursor,
+        TimelineChange, TimelineChangesPage, TimelineChangesQuery, TimelineEntriesFilter,
+        TimelineEntriesPage, TimelineEntriesQuery, TimelineEntry, TimelineEntryCursor,
+        TimelineSearchPage, TimelineSearchQuery,
     },
     subscription::{SubscriberId, SubscriptionKey},
-    timeline::TimelineCatchup,
+    timeline::{
+        TimelineCatchup,
+        retention::{RetentionPurge, TimelinePrune, TimelineRetention},
+    },
 };
 
 use super::{
     codec::{decode_stored_entry, decode_stored_feed_meta},
     error::{DecodeResultExt, IntoDbResult, SqliteError, SqliteResult},
     pagination::PageLimit,
+    search,
 };
 
 const TIMELINE_ENTRY_SELECT: &str = r#"
@@ -31,7 +36,9 @@ SELECT
     f.url AS feed_url,
     fs.meta_json,
     s.requirement,
-    s.category
+    s.category,
+    er.entry_id IS NOT NULL AS read,
+    es.entry_id IS NOT NULL AS saved
 FROM timeline_entry AS te
 INNER JOIN entry AS e
     ON e.entry_id = te.entry_id
@@ -42,6 +49,12 @@ INNER JOIN feed_snapshot AS fs
 INNER JOIN feed_subscription AS s
     ON s.subscriber_id = te.subscriber_id
    AND s.feed_pk = f.pk
+LEFT JOIN entry_read AS er
+    ON er.subscriber_id = te.subscriber_id
+   AND er.entry_id = te.entry_id
+LEFT JOIN entry_saved AS es
+    ON es.subscriber_id = te.subscriber_id
+   AND es.entry_id = te.entry_id
 "#;
 
 async fn ensure_timeline(
@@ -119,7 +132,8 @@ impl FeedCatchupCandidates {
 
     async fn insert(&self, tx: &mut Transaction<'_, Sqlite>, base_seq: i64) -> SqliteResult<u64> {
         // Insert missing entries and revive tombstoned ones(resubscribe).
-        // Live rows are left untouched so they emit no sync change.
+        // Live rows are left untouched so they emit no sync change, and
+        // entries retention already pruned stay gone.
         let result = sqlx::query(
             r#"
                 INSERT INTO timeline_entry (
@@ -139,7 +153,11 @@ impl FeedCatchupCandidates {
                    AND e.entry_id = fe.entry_id
                 INNER JOIN feed AS f
                     ON f.pk = fe.feed_pk
+                LEFT JOIN timeline_retired AS tr
+                    ON tr.subscriber_id = ?
+                   AND tr.feed_pk = fe.feed_pk
                 WHERE f.url = ?
+                  AND (tr.retired_through IS NULL OR e.order_time > tr.retired_through)
                 ON CONFLICT (subscriber_id, entry_id) DO UPDATE SET
                     seq = excluded.seq,
                     deleted = 0
@@ -148,6 +166,7 @@ impl FeedCatchupCandidates {
         )
         .bind(self.subscriber_id.as_str())
         .bind(base_seq)
+        .bind(self.subscriber_id.as_str())
         .bind(self.feed_url.as_str())
         .execute(&mut **tx)
         .await?;
@@ -170,6 +189,7 @@ async fn apply_entry_to_timelines(
     let mut affected = Vec::new();
     for target in load_entry_timeline_targets(tx, feed_url, entry_id).await? {
         let touched = match &target.existing {
+            _ if target.is_pruned() => false,
             None => {
                 let seq = next_seq(tx, &target.subscriber_id).await?;
                 insert_timeline_entry(tx, &target, seq).await?;
@@ -316,16 +336,159 @@ async fn load_timeline_entries(
     sql.push(" WHERE te.subscriber_id = ");
     sql.push_bind(query.subscriber_id.as_str());
     sql.push(" AND te.deleted = 0");
-    if let Some(after) = query.after.as_ref() {
+    push_entries_filter(&mut sql, &query.subscriber_id, &query.filter);
+    push_page_window(&mut sql, query.after.as_ref(), limit);
+
+    let rows = sql
+        .build_query_as::<TimelineEntryRow>()
+        .fetch_all(&mut **tx)
+        .await?;
+    rows.into_iter().map(TimelineEntry::try_from).collect()
+}
+
+/// Appends the conditions of `filter` as the set of feeds whose subscription
+/// matches them, so the set is resolved once through the subscription indexes
+/// rather than per scanned entry.
+fn push_entries_filter(
+    sql: &mut QueryBuilder<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+    filter: &TimelineEntriesFilter,
+) {
+    if filter.is_empty() {
+        return;
+    }
+
+    sql.push(
+        r#" AND e.feed_pk IN (
+            SELECT fs_filter.feed_pk
+            FROM feed_subscription AS fs_filter
+            INNER JOIN feed AS f_filter
+                ON f_filter.pk = fs_filter.feed_pk
+            WHERE fs_filter.subscriber_id = "#,
+    );
+    sql.push_bind(subscriber_id.as_str().to_owned());
+    if let Some(requirements) = &filter.requirements {
+        push_nullable_in(
+            sql,
+            "fs_filter.requirement",
+            requirements
+                .iter()
+                .map(|requirement| requirement.map(|r| r.to_string())),
+        );
+    }
+    if let Some(categories) = &filter.categories {
+        push_nullable_in(
+            sql,
+            "fs_filter.category",
+            categories
+                .iter()
+                .map(|category| category.as_ref().map(ToString::to_string)),
+        );
+    }
+    if let Some(feed_urls) = &filter.feed_urls {
+        push_nullable_in(
+            sql,
+            "f_filter.url",
+            feed_urls.iter().map(|url| Some(url.as_str().to_owned())),
+        );
+    }
+    sql.push(")");
+}
+
+/// Appends `AND column IN (values)`, where a `None` value matches NULL.
+/// No values match nothing.
+fn push_nullable_in(
+    sql: &mut QueryBuilder<'_, Sqlite>,
+    column: &str,
+    values: impl IntoIterator<Item = Option<String>>,
+) {
+    let mut matches_null = false;
+    let values = values
+        .into_iter()
+        .filter_map(|value| {
+            matches_null |= value.is_none();
+            value
+        })
+        .collect::<Vec<_>>();
+
+    sql.push(" AND (");
+    if values.is_empty() {
+        sql.push("0");
+    } else {
+        sql.push(column);
+        sql.push(" IN (");
+        let mut separated = sql.separated(", ");
+        for value in values {
+            separated.push_bind(value);
+        }
+        separated.push_unseparated(")");
+    }
+    if matches_null {
+        sql.push(" OR ");
+        sql.push(column);
+        sql.push(" IS NULL");
+    }
+    sql.push(")");
+}
+
+/// Appends the keyset condition after `after` and the canonical order shared
+/// by every timeline entry listing.
+fn push_page_window(
+    sql: &mut QueryBuilder<'_, Sqlite>,
+    after: Option<&TimelineEntryCursor>,
+    limit: PageLimit,
+) {
+    if let Some(after) = after {
         sql.push(" AND (te.order_time, te.entry_id) < (");
         sql.push_bind(after.order_time());
         sql.push(", ");
-        sql.push_bind(after.entry_id().as_str());
+        sql.push_bind(after.entry_id().as_str().to_owned());
         sql.push(")");
     }
 
     sql.push(" ORDER BY te.order_time DESC, te.entry_id DESC LIMIT ");
     sql.push_bind(limit.sql_limit());
+}
+
+async fn search_entries(
+    tx: &mut Transaction<'_, Sqlite>,
+    query: TimelineSearchQuery,
+) -> SqliteResult<TimelineSearchPage> {
+    let limit = PageLimit::new(query.first);
+    let mut nodes = match search::match_expression(&query.text) {
+        Some(expression) => load_matching_entries(tx, &query, &expression, limit).await?,
+        None => Vec::new(),
+    };
+    let has_next_page = limit.truncate_overfetch(&mut nodes);
+    let end_cursor = nodes.last().map(|node| node.cursor.clone());
+    Ok(TimelineSearchPage {
+        nodes,
+        has_next_page,
+        end_cursor,
+    })
+}
+
+async fn load_matching_entries(
+    tx: &mut Transaction<'_, Sqlite>,
+    query: &TimelineSearchQuery,
+    expression: &str,
+    limit: PageLimit,
+) -> SqliteResult<Vec<TimelineEntry>> {
+    let mut sql = QueryBuilder::<Sqlite>::new(TIMELINE_ENTRY_SELECT);
+    sql.push(" WHERE te.subscriber_id = ");
+    sql.push_bind(query.subscriber_id.as_str());
+    sql.push(" AND te.deleted = 0");
+    sql.push(
+        r#" AND te.entry_id IN (
+            SELECT d.entry_id
+            FROM entry_search
+            INNER JOIN entry_search_doc AS d
+                ON d.pk = entry_search.rowid
+            WHERE entry_search MATCH "#,
+    );
+    sql.push_bind(expression);
+    sql.push(")");
+    push_page_window(&mut sql, query.after.as_ref(), limit);
 
     let rows = sql
         .build_query_as::<TimelineEntryRow>()
@@ -334,6 +497,25 @@ async fn load_timeline_entries(
     rows.into_iter().map(TimelineEntry::try_from).collect()
 }
 
+async fn load_entry(
+    tx: &mut Transaction<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+    entry_id: &EntryId,
+) -> SqliteResult<Option<TimelineEntry>> {
+    let mut sql = QueryBuilder::<Sqlite>::new(TIMELINE_ENTRY_SELECT);
+    sql.push(" WHERE te.subscriber_id = ");
+    sql.push_bind(subscriber_id.as_str());
+    sql.push(" AND te.entry_id = ");
+    sql.push_bind(entry_id.as_str());
+    sql.push(" AND te.deleted = 0");
+
+    sql.build_query_as::<TimelineEntryRow>()
+        .fetch_optional(&mut **tx)
+        .await?
+        .map(TimelineEntry::try_from)
+        .transpose()
+}
+
 async fn list_changes(
     tx: &mut Transaction<'_, Sqlite>,
     query: TimelineChangesQuery,
@@ -349,6 +531,7 @@ struct StoredTimelineChangesPage {
     limit: PageLimit,
     since: i64,
     last_seq: i64,
+    expired: bool,
 }
 
 impl StoredTimelineChangesPage {
@@ -357,24 +540,40 @@ impl StoredTimelineChangesPage {
         query: TimelineChangesQuery,
     ) -> SqliteResult<Self> {
         let limit = PageLimit::new(query.limit);
-        let Some(last_seq) = load_last_seq(tx, &query.subscriber_id).await? else {
+        let Some(seqs) = load_timeline_seqs(tx, &query.subscriber_id).await? else {
             return Ok(Self {
                 rows: Vec::new(),
                 limit,
                 since: query.since,
                 last_seq: 0,
+                expired: false,
             });
         };
+        // Removals up to purged_seq are gone, so changes listed from before
+        // it would silently miss them.
+        if query.since < seqs.purged_seq {
+            return Ok(Self {
+                rows: Vec::new(),
+                limit,
+                since: query.since,
+                last_seq: seqs.last_seq,
+                expired: true,
+            });
+        }
         let rows = load_timeline_change_rows(tx, &query, limit).await?;
         Ok(Self {
             rows,
             limit,
             since: query.since,
-            last_seq,
+            last_seq: seqs.last_seq,
+            expired: false,
         })
     }
 
     fn into_page(mut self) -> SqliteResult<TimelineChangesPage> {
+        if self.expired {
+            return Ok(TimelineChangesPage::expired(self.last_seq));
+        }
         let has_more = self.limit.truncate_overfetch(&mut self.rows);
         let seq = if has_more {
             self.rows.last().map_or(self.since, |row| row.seq)
@@ -390,6 +589,7 @@ impl StoredTimelineChangesPage {
             changes,
             seq,
             has_more,
+            expired: false,
         })
     }
 }
@@ -413,7 +613,9 @@ async fn load_timeline_change_rows(
                 f.url AS feed_url,
                 fs.meta_json,
                 s.requirement,
-                s.category
+                s.category,
+                er.entry_id IS NOT NULL AS read,
+                es.entry_id IS NOT NULL AS saved
             FROM timeline_entry AS te
             INNER JOIN entry AS e
                 ON e.entry_id = te.entry_id
@@ -424,6 +626,12 @@ async fn load_timeline_change_rows(
             LEFT JOIN feed_subscription AS s
                 ON s.subscriber_id = te.subscriber_id
                AND s.feed_pk = f.pk
+            LEFT JOIN entry_read AS er
+                ON er.subscriber_id = te.subscriber_id
+               AND er.entry_id = te.entry_id
+            LEFT JOIN entry_saved AS es
+                ON es.subscriber_id = te.subscriber_id
+               AND es.entry_id = te.entry_id
             WHERE te.subscriber_id = ?
               AND te.seq > ?
             ORDER BY te.seq ASC
@@ -445,6 +653,30 @@ async fn timeline_exists(
     Ok(load_last_seq(tx, subscriber_id).await?.is_some())
 }
 
+#[derive(sqlx::FromRow)]
+struct TimelineSeqsRow {
+    last_seq: i64,
+    purged_seq: i64,
+}
+
+async fn load_timeline_seqs(
+    tx: &mut Transaction<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+) -> SqliteResult<Option<TimelineSeqsRow>> {
+    let row = sqlx::query_as::<_, TimelineSeqsRow>(
+        r#"
+            SELECT last_seq, purged_seq
+            FROM timeline
+            WHERE subscriber_id = ?
+            "#,
+    )
+    .bind(subscriber_id.as_str())
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    Ok(row)
+}
+
 async fn load_last_seq(
     tx: &mut Transaction<'_, Sqlite>,
     subscriber_id: &SubscriberId,
@@ -528,7 +760,8 @@ async fn load_entry_timeline_targets(
                 e.entry_id,
                 e.order_time AS entry_order_time,
                 te.entry_id IS NOT NULL AS entry_exists,
-                COALESCE(te.deleted, 0) != 0 AS entry_deleted
+                COALESCE(te.deleted, 0) != 0 AS entry_deleted,
+                COALESCE(e.order_time <= tr.retired_through, 0) AS entry_retired
             FROM feed_subscription AS s
             INNER JOIN feed AS f
                 ON f.pk = s.feed_pk
@@ -540,6 +773,9 @@ async fn load_entry_timeline_targets(
             LEFT JOIN timeline_entry AS te
                 ON te.subscriber_id = s.subscriber_id
                AND te.entry_id = e.entry_id
+            LEFT JOIN timeline_retired AS tr
+                ON tr.subscriber_id = s.subscriber_id
+               AND tr.feed_pk = f.pk
             WHERE f.url = ?
               AND e.entry_id = ?
             ORDER BY s.subscriber_id
@@ -601,11 +837,395 @@ async fn bump_timeline_entry(
     Ok(())
 }
 
+/// Bumps the seq of live timeline entries whose subscriber-scoped state
+/// changed, one seq per row like every other `timeline_entry` mutation.
+async fn touch_timeline_entries(
+    tx: &mut Transaction<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+    entry_ids: &[EntryId],
+) -> SqliteResult<Vec<FeedUrl>> {
+    if entry_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let entry_ids = entry_ids.iter().map(EntryId::as_str).collect::<Vec<_>>();
+    let entry_ids_json = serde_json::to_string(&entry_ids)?;
+    let rows = sqlx::query_as::<_, TouchedTimelineEntryRow>(
+        r#"
+            WITH requested(entry_id) AS (
+                SELECT DISTINCT CAST(value AS TEXT)
+                FROM json_each(?)
+            )
+            SELECT
+                te.entry_id,
+                f.url AS feed_url
+            FROM requested AS r
+            INNER JOIN timeline_entry AS te
+                ON te.entry_id = r.entry_id
+            INNER JOIN entry AS e
+                ON e.entry_id = te.entry_id
+            INNER JOIN feed AS f
+                ON f.pk = e.feed_pk
+            INNER JOIN feed_subscription AS s
+                ON s.subscriber_id = te.subscriber_id
+               AND s.feed_pk = f.pk
+            WHERE te.subscriber_id = ?
+              AND te.deleted = 0
+            ORDER BY te.entry_id
+            "#,
+    )
+    .bind(entry_ids_json)
+    .bind(subscriber_id.as_str())
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let mut feed_urls: Vec<FeedUrl> = Vec::new();
+    for row in rows {
+        let seq = next_seq(tx, subscriber_id).await?;
+        sqlx::query(
+            r#"
+                UPDATE timeline_entry
+                SET seq = ?
+                WHERE subscriber_id = ?
+                  AND entry_id = ?
+                "#,
+        )
+        .bind(seq)
+        .bind(subscriber_id.as_str())
+        .bind(&row.entry_id)
+        .execute(&mut **tx)
+        .await?;
+
+        let feed_url = FeedUrl::parse(&row.feed_url).decode()?;
+        if !feed_urls.contains(&feed_url) {
+            feed_urls.push(feed_url);
+        }
+    }
+    Ok(feed_urls)
+}
+
+/// Turns live entries the retention rules no longer keep into tombstones.
+/// Entries rank by canonical order within their subscriber and feed; saved
+/// entries, and unread ones when asked to, are kept but still count toward
+/// the per-feed limit.
+///
+/// The age rule is an indexed range scan, and only subscriber and feed pairs
+/// over the limit are ranked, so a pass does not sort the whole timeline
+/// table. The newest pruned order time of each pair is kept as its
+/// retention mark.
+async fn remove_retired(
+    tx: &mut Transaction<'_, Sqlite>,
+    retention: &TimelineRetention,
+    limit: usize,
+) -> SqliteResult<TimelinePrune> {
+    let page = PageLimit::new(limit);
+    let max_entries = retention
+        .max_entries_per_feed
+        .map(|max| i64::try_from(max).unwrap_or(i64::MAX));
+    let mut rows = sqlx::query_as::<_, RetiredTimelineEntryRow>(
+        r#"
+            WITH over_limit AS (
+                SELECT
+                    te.subscriber_id,
+                    e.feed_pk
+                FROM timeline_entry AS te
+                INNER JOIN entry AS e
+                    ON e.entry_id = te.entry_id
+                WHERE ? IS NOT NULL
+                  AND te.deleted = 0
+                GROUP BY te.subscriber_id, e.feed_pk
+                HAVING COUNT(*) > ?
+            ),
+            ranked AS (
+                SELECT
+                    te.subscriber_id,
+                    te.entry_id,
+                    te.order_time,
+                    e.feed_pk,
+                    ROW_NUMBER() OVER (
+                        PARTITION BY te.subscriber_id, e.feed_pk
+                        ORDER BY te.order_time DESC, te.entry_id DESC
+                    ) AS rank
+                FROM over_limit AS o
+                INNER JOIN timeline_entry AS te
+                    ON te.subscriber_id = o.subscriber_id
+                INNER JOIN entry AS e
+                    ON e.entry_id = te.entry_id
+                   AND e.feed_pk = o.feed_pk
+                WHERE te.deleted = 0
+            ),
+            candidates AS (
+                SELECT
+                    te.subscriber_id,
+                    te.entry_id,
+                    te.order_time,
+                    e.feed_pk
+                FROM timeline_entry AS te
+                INNER JOIN entry AS e
+                    ON e.entry_id = te.entry_id
+                WHERE ? IS NOT NULL
+                  AND te.deleted = 0
+                  AND te.order_time < ?
+                UNION
+                SELECT
+                    r.subscriber_id,
+                    r.entry_id,
+                    r.order_time,
+                    r.feed_pk
+                FROM ranked AS r
+                WHERE r.rank > ?
+            )
+            SELECT
+                c.subscriber_id,
+                c.entry_id,
+                c.order_time,
+                c.feed_pk,
+                f.url AS feed_url
+            FROM candidates AS c
+            INNER JOIN feed AS f
+                ON f.pk = c.feed_pk
+            WHERE NOT EXISTS (
+                  SELECT 1
+                  FROM entry_saved AS es
+                  WHERE es.subscriber_id = c.subscriber_id
+                    AND es.entry_id = c.entry_id
+              )
+              AND (? = 0 OR EXISTS (
+                  SELECT 1
+                  FROM entry_read AS er
+                  WHERE er.subscriber_id = c.subscriber_id
+                    AND er.entry_id = c.entry_id
+              ))
+            ORDER BY c.subscriber_id, c.entry_id
+            LIMIT ?
+            "#,
+    )
+    .bind(max_entries)
+    .bind(max_entries)
+    .bind(retention.ordered_before)
+    .bind(retention.ordered_before)
+    .bind(max_entries)
+    .bind(retention.keep_unread)
+    .bind(page.sql_limit())
+    .fetch_all(&mut **tx)
+    .await?;
+    let has_more = page.truncate_overfetch(&mut rows);
+
+    let mut prune = TimelinePrune {
+        removed: 0,
+        affected: Vec::new(),
+        has_more,
+    };
+    for subscriber_rows in rows.chunk_by(|a, b| a.subscriber_id == b.subscriber_id) {
+        let subscriber_id = SubscriberId::new(subscriber_rows[0].subscriber_id.clone());
+        prune.removed += tombstone_entries(tx, &subscriber_id, subscriber_rows).await?;
+        record_retired_through(tx, &subscriber_id, subscriber_rows).await?;
+        for row in subscriber_rows {
+            let affected = (
+                subscriber_id.clone(),
+                FeedUrl::parse(&row.feed_url).decode()?,
+            );
+            if !prune.affected.contains(&affected) {
+                prune.affected.push(affected);
+            }
+        }
+    }
+    Ok(prune)
+}
+
+/// Raises the retention mark of each feed to the newest entry pruned from it.
+async fn record_retired_through(
+    tx: &mut Transaction<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+    rows: &[RetiredTimelineEntryRow],
+) -> SqliteResult<()> {
+    let mut marks: Vec<(i64, DateTime<Utc>)> = Vec::new();
+    for row in rows {
+        match marks
+            .iter_mut()
+            .find(|(feed_pk, _)| *feed_pk == row.feed_pk)
+        {
+            Some((_, retired_through)) => *retired_through = (*retired_through).max(row.order_time),
+            None => marks.push((row.feed_pk, row.order_time)),
+        }
+    }
+    for (feed_pk, retired_through) in marks {
+        sqlx::query(
+            r#"
+                INSERT INTO timeline_retired (subscriber_id, feed_pk, retired_through)
+                VALUES (?, ?, ?)
+                ON CONFLICT (subscriber_id, feed_pk) DO UPDATE SET
+                    retired_through = MAX(retired_through, excluded.retired_through)
+                "#,
+        )
+        .bind(subscriber_id.as_str())
+        .bind(feed_pk)
+        .bind(retired_through)
+        .execute(&mut **tx)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Tombstones the given live entries of one timeline. Every row gets its own
+/// seq: change pagination pages by seq alone, so rows sharing a seq would be
+/// lost at page boundaries.
+async fn tombstone_entries(
+    tx: &mut Transaction<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+    rows: &[RetiredTimelineEntryRow],
+) -> SqliteResult<u64> {
+    let entry_ids = rows
+        .iter()
+        .map(|row| row.entry_id.as_str())
+        .collect::<Vec<_>>();
+    let entry_ids_json = serde_json::to_string(&entry_ids)?;
+    let count = i64::try_from(entry_ids.len()).unwrap_or(i64::MAX);
+    let base_seq = alloc_seq_range(tx, subscriber_id, count).await?;
+    let result = sqlx::query(
+        r#"
+            UPDATE timeline_entry
+            SET
+                deleted = 1,
+                seq = ? + ranked.rn
+            FROM (
+                SELECT
+                    CAST(value AS TEXT) AS entry_id,
+                    ROW_NUMBER() OVER (ORDER BY CAST(value AS TEXT)) AS rn
+                FROM json_each(?)
+            ) AS ranked
+            WHERE timeline_entry.subscriber_id = ?
+              AND timeline_entry.entry_id = ranked.entry_id
+            "#,
+    )
+    .bind(base_seq)
+    .bind(entry_ids_json)
+    .bind(subscriber_id.as_str())
+    .execute(&mut **tx)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+#[derive(sqlx::FromRow)]
+struct RetiredTimelineEntryRow {
+    subscriber_id: String,
+    entry_id: String,
+    order_time: DateTime<Utc>,
+    feed_pk: i64,
+    feed_url: String,
+}
+
+const PURGEABLE_TOMBSTONE_SELECT: &str = r"
+SELECT te.subscriber_id, te.entry_id, te.seq
+FROM timeline_entry AS te
+INNER JOIN timeline AS t
+    ON t.subscriber_id = te.subscriber_id
+WHERE te.deleted != 0
+  AND te.seq <= t.last_seq - ?
+";
+
+/// Deletes tombstones that fell out of the sync window together with the
+/// read markers still pointing at them.
+///
+/// The highest purged seq is kept on the timeline so change listings can
+/// tell clients syncing from before it to reload.
+async fn purge_tombstones(
+    tx: &mut Transaction<'_, Sqlite>,
+    seq_window: u64,
+    limit: usize,
+) -> SqliteResult<RetentionPurge> {
+    let seq_window = i64::try_from(seq_window).unwrap_or(i64::MAX);
+    let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+    let purged = format!("{PURGEABLE_TOMBSTONE_SELECT} ORDER BY te.subscriber_id, te.seq LIMIT ?");
+    let purged_keys = format!("SELECT subscriber_id, entry_id FROM ({purged})");
+
+    sqlx::query(&format!(
+        r"
+        UPDATE timeline
+        SET purged_seq = MAX(purged_seq, p.seq)
+        FROM (
+            SELECT subscriber_id, MAX(seq) AS seq
+            FROM ({purged})
+            GROUP BY subscriber_id
+        ) AS p
+        WHERE timeline.subscriber_id = p.subscriber_id
+        "
+    ))
+    .bind(seq_window)
+    .bind(limit)
+    .execute(&mut **tx)
+    .await?;
+    sqlx::query(&format!(
+        "DELETE FROM entry_read WHERE (subscriber_id, entry_id) IN ({purged_keys})"
+    ))
+    .bind(seq_window)
+    .bind(limit)
+    .execute(&mut **tx)
+    .await?;
+    let removed = sqlx::query(&format!(
+        "DELETE FROM timeline_entry WHERE (subscriber_id, entry_id) IN ({purged_keys})"
+    ))
+    .bind(seq_window)
+    .bind(limit)
+    .execute(&mut **tx)
+    .await?
+    .rows_affected();
+
+    let has_more =
+        sqlx::query_scalar::<_, bool>(&format!("SELECT EXISTS ({PURGEABLE_TOMBSTONE_SELECT})"))
+            .bind(seq_window)
+            .fetch_one(&mut **tx)
+            .await?;
+
+    Ok(RetentionPurge { removed, has_more })
+}
+
+/// Deletes one timeline in foreign key order: read markers, entries,
+/// retention marks, then the timeline itself. Entries no other timeline holds are left to the
+/// orphan purge, which also drops their search index rows.
+async fn delete_timeline(
+    tx: &mut Transaction<'_, Sqlite>,
+    subscriber_id: &SubscriberId,
+) -> SqliteResult<()> {
+    for table in [
+        "entry_read",
+        "timeline_entry",
+        "timeline_retired",
+        "timeline",
+    ] {
+        sqlx::query(&format!("DELETE FROM {table} WHERE subscriber_id = ?"))
+            .bind(subscriber_id.as_str())
+            .execute(&mut **tx)
+            .await?;
+    }
+    Ok(())
+}
+
+#[derive(sqlx::FromRow)]
+struct TouchedTimelineEntryRow {
+    entry_id: String,
+    feed_url: String,
+}
+
 struct TimelineEntryTarget {
     subscriber_id: SubscriberId,
     entry_id: String,
     entry_order_time: DateTime<Utc>,
     existing: Option<ExistingTimelineEntry>,
+    /// Ordered at or before the retention mark of its feed.
+    retired: bool,
+}
+
+impl TimelineEntryTarget {
+    /// Retention pruned the entry: it is past the mark and not live, either
+    /// tombstoned or already purged.
+    fn is_pruned(&self) -> bool {
+        self.retired
+            && self
+                .existing
+                .as_ref()
+                .is_none_or(|existing| existing.deleted)
+    }
 }
 
 struct ExistingTimelineEntry {
@@ -619,6 +1239,7 @@ struct TimelineEntryTargetRow {
     entry_order_time: DateTime<Utc>,
     entry_exists: bool,
     entry_deleted: bool,
+    entry_retired: bool,
 }
 
 impl From<TimelineEntryTargetRow> for TimelineEntryTarget {
@@ -630,6 +1251,7 @@ impl From<TimelineEntryTargetRow> for TimelineEntryTarget {
             existing: row.entry_exists.then_some(ExistingTimelineEntry {
                 deleted: row.entry_deleted,
             }),
+            retired: row.entry_retired,
         }
     }
 }
@@ -664,7 +1286,7 @@ impl TryFrom<TimelineChangeRow> for TimelineChange {
 }
 
 #[derive(sqlx::FromRow)]
-struct TimelineEntryRow {
+pub(super) struct TimelineEntryRow {
     #[sqlx(flatten)]
     entry: TimelineEntryColumns,
     meta_json: String,
@@ -678,6 +1300,8 @@ struct TimelineEntryColumns {
     feed_url: String,
     requirement: Option<String>,
     category: Option<String>,
+    read: bool,
+    saved: bool,
 }
 
 impl TryFrom<TimelineEntryRow> for TimelineEntry {
@@ -705,6 +1329,9 @@ impl TryFrom<TimelineEntryRow> for TimelineEntry {
             entry,
             feed_meta,
             cursor,
+            read: row.entry.read,
+            saved: row.entry.saved,
+            article: None,
         })
     }
 }
@@ -724,6 +1351,28 @@ impl TimelineDb for super::SqliteRegistryTx<'_> {
         list_changes(&mut self.tx, query).await.db()
     }
 
+    async fn load_timeline_seq(&mut self, subscriber_id: &SubscriberId) -> RegistryDbResult<i64> {
+        load_last_seq(&mut self.tx, subscriber_id)
+            .await
+            .map(Option::unwrap_or_default)
+            .db()
+    }
+
+    async fn search_timeline_entries(
+        &mut self,
+        query: TimelineSearchQuery,
+    ) -> RegistryDbResult<TimelineSearchPage> {
+        search_entries(&mut self.tx, query).await.db()
+    }
+
+    async fn load_timeline_entry(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+    ) -> RegistryDbResult<Option<TimelineEntry>> {
+        load_entry(&mut self.tx, subscriber_id, entry_id).await.db()
+    }
+
     async fn catchup_subscribed_feed(
         &mut self,
         subscriber_id: &SubscriberId,
@@ -754,6 +1403,36 @@ impl TimelineDb for super::SqliteRegistryTx<'_> {
             .await
             .db()
     }
+
+    async fn touch_timeline_entries(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_ids: &[EntryId],
+    ) -> RegistryDbResult<Vec<FeedUrl>> {
+        touch_timeline_entries(&mut self.tx, subscriber_id, entry_ids)
+            .await
+            .db()
+    }
+
+    async fn remove_retired_timeline_entries(
+        &mut self,
+        retention: &TimelineRetention,
+        limit: usize,
+    ) -> RegistryDbResult<TimelinePrune> {
+        remove_retired(&mut self.tx, retention, limit).await.db()
+    }
+
+    async fn purge_timeline_tombstones(
+        &mut self,
+        seq_window: u64,
+        limit: usize,
+    ) -> RegistryDbResult<RetentionPurge> {
+        purge_tombstones(&mut self.tx, seq_window, limit).await.db()
+    }
+
+    async fn delete_timeline(&mut self, subscriber_id: &SubscriberId) -> RegistryDbResult<()> {
+        delete_timeline(&mut self.tx, subscriber_id).await.db()
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use synd_feed::types::Text;
+use synd_feed::types::{Category, Requirement, Text};
 
 use crate::sqlite::feed_registry::test_support::*;
 
@@ -44,3 +44,513 @@ async fn subscription_catchup_reads_complete_entries_from_current_membership() -
     );
     Ok(())
 }
+
+#[tokio::test]
+async fn load_entry_is_scoped_to_the_subscriber_timeline() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let subscription = subscription("timeline-load-entry");
+    let crawl = record_fetched_crawl(
+        &db,
+        &subscription.feed_url,
+        rss_body_with_entry("timeline feed", "loaded entry", "entry-1"),
+        0,
+    )
+    .await?;
+    project_feed(&db, crawl).await?;
+    store_subscription_in_db(&db, subscription.clone()).await?;
+    project_timeline(
+        &db,
+        TimelineProjInput::FeedSubscribed(feed_subscribed_event(&subscription)),
+    )
+    .await?;
+    let page = list_timeline_entries(&db, subscription.subscriber_id.clone()).await?;
+    let entry_id = page.nodes[0].entry.id().clone();
+
+    let loaded = load_timeline_entry(&db, &subscription.subscriber_id, &entry_id)
+        .await?
+        .expect("entry must be in the timeline");
+    assert_eq!(
+        loaded.entry.title().map(Text::content),
+        Some("loaded entry")
+    );
+    assert_eq!(loaded.cursor, page.nodes[0].cursor);
+
+    let other = SubscriberId::new("other-subscriber");
+    assert!(load_timeline_entry(&db, &other, &entry_id).await?.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_pages_through_matching_entries_in_timeline_order() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let subscription = subscription("timeline-search");
+    let crawl = record_fetched_crawl(
+        &db,
+        &subscription.feed_url,
+        br#"<?xml version="1.0" encoding="utf-8"?>
+<rss version="2.0">
+  <channel>
+    <title>search feed</title>
+    <link>https://example.com/</link>
+    <description>example feed</description>
+    <item>
+      <title>Async Rust in practice</title>
+      <guid>entry-1</guid>
+      <pubDate>Wed, 03 Jun 2026 00:00:00 GMT</pubDate>
+    </item>
+    <item>
+      <title>Gardening notes</title>
+      <description>&lt;p&gt;Planting &amp;amp; asynchronous watering&lt;/p&gt;</description>
+      <guid>entry-2</guid>
+      <pubDate>Tue, 02 Jun 2026 00:00:00 GMT</pubDate>
+    </item>
+    <item>
+      <title>Cooking</title>
+      <description>Nothing to see here</description>
+      <guid>entry-3</guid>
+      <pubDate>Mon, 01 Jun 2026 00:00:00 GMT</pubDate>
+    </item>
+  </channel>
+</rss>"#
+            .to_vec(),
+        0,
+    )
+    .await?;
+    project_feed(&db, crawl).await?;
+    store_subscription_in_db(&db, subscription.clone()).await?;
+    project_timeline(
+        &db,
+        TimelineProjInput::FeedSubscribed(feed_subscribed_event(&subscription)),
+    )
+    .await?;
+    let search = |text: &str, after, first| TimelineSearchQuery {
+        subscriber_id: subscription.subscriber_id.clone(),
+        text: text.to_owned(),
+        after,
+        first,
+    };
+
+    let first_page = search_timeline_entries(&db, search("ASYNC", None, 1)).await?;
+    assert!(first_page.has_next_page);
+    assert_eq!(titles(&first_page), ["Async Rust in practice"]);
+
+    let second_page =
+        search_timeline_entries(&db, search("ASYNC", first_page.end_cursor, 1)).await?;
+    assert!(!second_page.has_next_page);
+    assert_eq!(titles(&second_page), ["Gardening notes"]);
+
+    let markup = search_timeline_entries(&db, search("amp", None, 10)).await?;
+    assert!(markup.nodes.is_empty(), "markup must not be indexed");
+    let blank = search_timeline_entries(&db, search("  ", None, 10)).await?;
+    assert!(blank.nodes.is_empty());
+    Ok(())
+}
+
+fn titles(page: &TimelineSearchPage) -> Vec<&str> {
+    page.nodes
+        .iter()
+        .filter_map(|node| node.entry.title().map(Text::content))
+        .collect()
+}
+
+fn rss_body_with_dated_entries(guids: &[&str]) -> Vec<u8> {
+    let items = guids
+        .iter()
+        .enumerate()
+        .map(|(day, guid)| {
+            format!(
+                "<item><title>{guid}</title><guid>{guid}</guid>\
+                 <pubDate>{:02} Jun 2026 00:00:00 GMT</pubDate></item>",
+                10 - day
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<rss version="2.0">
+  <channel>
+    <title>retention feed</title>
+    <link>https://example.com/</link>
+    <description>example feed</description>
+    {items}
+  </channel>
+</rss>"#
+    )
+    .into_bytes()
+}
+
+/// Subscribes to a feed declaring `guids`, newest first, and returns the
+/// timeline entry ids in the same order.
+async fn timeline_with_entries(
+    db: &SqliteFeedRegistryDb,
+    subscription: &Subscription,
+    guids: &[&str],
+) -> anyhow::Result<Vec<EntryId>> {
+    let crawl = record_fetched_crawl(
+        db,
+        &subscription.feed_url,
+        rss_body_with_dated_entries(guids),
+        0,
+    )
+    .await?;
+    project_feed(db, crawl).await?;
+    store_subscription_in_db(db, subscription.clone()).await?;
+    project_timeline(
+        db,
+        TimelineProjInput::FeedSubscribed(feed_subscribed_event(subscription)),
+    )
+    .await?;
+    let page = list_timeline_entries(db, subscription.subscriber_id.clone()).await?;
+    Ok(page
+        .nodes
+        .iter()
+        .map(|node| node.entry.id().clone())
+        .collect())
+}
+
+#[tokio::test]
+async fn retention_tombstones_read_entries_beyond_the_feed_limit() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let subscription = subscription("retention-limit");
+    let entry_ids = timeline_with_entries(&db, &subscription, &["one", "two", "three"]).await?;
+    let mut tx = db.begin().await?;
+    tx.mark_entries_read(&subscriber_id(), &entry_ids[1..], test_occurred_at())
+        .await?;
+    tx.save_entry(&subscriber_id(), &entry_ids[2], test_occurred_at())
+        .await?;
+    let since = list_timeline_entries_seq(&mut tx).await?;
+    tx.commit().await?;
+
+    let mut tx = db.begin().await?;
+    let prune = tx
+        .remove_retired_timeline_entries(
+            &TimelineRetention {
+                ordered_before: None,
+                max_entries_per_feed: Some(1),
+                keep_unread: true,
+            },
+            10,
+        )
+        .await?;
+    tx.commit().await?;
+
+    assert_eq!(
+        prune,
+        TimelinePrune {
+            removed: 1,
+            affected: vec![(subscriber_id(), subscription.feed_url.clone())],
+            has_more: false,
+        }
+    );
+    let page = list_timeline_entries(&db, subscriber_id()).await?;
+    let remaining = page
+        .nodes
+        .iter()
+        .map(|node| node.entry.id().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(remaining, vec![entry_ids[0].clone(), entry_ids[2].clone()]);
+
+    let mut tx = db.begin().await?;
+    let changes = tx
+        .list_timeline_changes(TimelineChangesQuery {
+            subscriber_id: subscriber_id(),
+            since,
+            limit: 10,
+        })
+        .await?;
+    let seq = tx.load_timeline_seq(&subscriber_id()).await?;
+    tx.commit().await?;
+    assert_eq!(seq, changes.seq);
+    assert!(matches!(
+        changes.changes.as_slice(),
+        [TimelineChange::Remove { entry_id }] if entry_id == &entry_ids[1]
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn retention_by_age_skips_unread_entries_only_when_asked() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let subscription = subscription("retention-age");
+    timeline_with_entries(&db, &subscription, &["one", "two", "three"]).await?;
+    let retention = |keep_unread| TimelineRetention {
+        ordered_before: Some(Utc.with_ymd_and_hms(2026, 6, 10, 0, 0, 0).unwrap()),
+        max_entries_per_feed: None,
+        keep_unread,
+    };
+
+    let mut tx = db.begin().await?;
+    let kept = tx
+        .remove_retired_timeline_entries(&retention(true), 10)
+        .await?;
+    let first = tx
+        .remove_retired_timeline_entries(&retention(false), 1)
+        .await?;
+    let second = tx
+        .remove_retired_timeline_entries(&retention(false), 1)
+        .await?;
+    tx.commit().await?;
+
+    assert_eq!(kept, TimelinePrune::default());
+    assert_eq!((first.removed, first.has_more), (1, true));
+    assert_eq!((second.removed, second.has_more), (1, false));
+    let page = list_timeline_entries(&db, subscriber_id()).await?;
+    assert_eq!(page.nodes.len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn pruned_entries_stay_gone_after_entry_changes_and_catchup() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let subscription = subscription("retention-revive");
+    let entry_ids = timeline_with_entries(&db, &subscription, &["one", "two", "three"]).await?;
+
+    let mut tx = db.begin().await?;
+    let prune = tx
+        .remove_retired_timeline_entries(
+            &TimelineRetention {
+                ordered_before: None,
+                max_entries_per_feed: Some(1),
+                keep_unread: false,
+            },
+            10,
+        )
+        .await?;
+    let changed_tombstone = tx
+        .apply_entry_to_timelines(&subscription.feed_url, &entry_ids[1], true)
+        .await?;
+    tx.purge_timeline_tombstones(0, 10).await?;
+    let changed_purged = tx
+        .apply_entry_to_timelines(&subscription.feed_url, &entry_ids[2], true)
+        .await?;
+    let catchup = tx
+        .catchup_subscribed_feed(&subscriber_id(), &subscription.feed_url)
+        .await?;
+    tx.commit().await?;
+
+    assert_eq!(prune.removed, 2);
+    assert!(changed_tombstone.is_empty());
+    assert!(changed_purged.is_empty());
+    assert_eq!(catchup.inserted_items(), 0);
+    let page = list_timeline_entries(&db, subscriber_id()).await?;
+    let remaining = page
+        .nodes
+        .iter()
+        .map(|node| node.entry.id().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(remaining, vec![entry_ids[0].clone()]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn purge_drops_tombstones_outside_the_sync_window_then_orphaned_entries() -> anyhow::Result<()>
+{
+    let db = migrated_db().await?;
+    let subscription = subscription("retention-purge");
+    let entry_ids = timeline_with_entries(&db, &subscription, &["one"]).await?;
+    let mut tx = db.begin().await?;
+    tx.mark_entries_read(&subscriber_id(), &entry_ids, test_occurred_at())
+        .await?;
+    tx.commit().await?;
+    project_timeline(
+        &db,
+        TimelineProjInput::FeedUnsubscribed(FeedUnsubscribedEvent::new(subscription_key(
+            &subscription,
+        ))),
+    )
+    .await?;
+    let crawl = record_fetched_crawl(
+        &db,
+        &subscription.feed_url,
+        rss_body_with_dated_entries(&["two"]),
+        1,
+    )
+    .await?;
+    project_feed(&db, crawl).await?;
+
+    let mut tx = db.begin().await?;
+    let referenced = tx.delete_orphaned_entries(10).await?;
+    let within_window = tx.purge_timeline_tombstones(100, 10).await?;
+    let purged = tx.purge_timeline_tombstones(0, 10).await?;
+    let orphaned = tx.delete_orphaned_entries(10).await?;
+    let remaining = tx.load_entries(&entry_ids).await?;
+    tx.commit().await?;
+
+    assert_eq!(referenced, RetentionPurge::default());
+    assert_eq!(within_window, RetentionPurge::default());
+    assert_eq!(
+        purged,
+        RetentionPurge {
+            removed: 1,
+            has_more: false,
+        }
+    );
+    assert_eq!(
+        orphaned,
+        RetentionPurge {
+            removed: 1,
+            has_more: false,
+        }
+    );
+    assert_eq!(remaining, synd_registry::entry::Entries::default());
+    Ok(())
+}
+
+#[tokio::test]
+async fn changes_since_purged_tombstones_are_expired() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let subscription = subscription("retention-expired-since");
+    timeline_with_entries(&db, &subscription, &["one"]).await?;
+    let mut tx = db.begin().await?;
+    let before_removal = tx.load_timeline_seq(&subscriber_id()).await?;
+    tx.commit().await?;
+    project_timeline(
+        &db,
+        TimelineProjInput::FeedUnsubscribed(FeedUnsubscribedEvent::new(subscription_key(
+            &subscription,
+        ))),
+    )
+    .await?;
+
+    let mut tx = db.begin().await?;
+    let after_removal = tx.load_timeline_seq(&subscriber_id()).await?;
+    tx.purge_timeline_tombstones(0, 10).await?;
+    let expired = tx
+        .list_timeline_changes(TimelineChangesQuery {
+            subscriber_id: subscriber_id(),
+            since: before_removal,
+            limit: 10,
+        })
+        .await?;
+    let current = tx
+        .list_timeline_changes(TimelineChangesQuery {
+            subscriber_id: subscriber_id(),
+            since: after_removal,
+            limit: 10,
+        })
+        .await?;
+    tx.commit().await?;
+
+    assert!(expired.expired);
+    assert!(expired.changes.is_empty());
+    assert_eq!(expired.seq, after_removal);
+    assert!(!current.expired);
+    assert_eq!(current.seq, after_removal);
+    Ok(())
+}
+
+#[tokio::test]
+async fn list_filters_entries_by_subscription_attributes() -> anyhow::Result<()> {
+    let db = migrated_db().await?;
+    let rust = Category::new("rust")?;
+    let news = Category::new("news")?;
+    let must = Subscription {
+        requirement: Some(Requirement::Must),
+        category: Some(rust.clone()),
+        ..subscription("filter-must")
+    };
+    let may = Subscription {
+        requirement: Some(Requirement::May),
+        category: Some(news.clone()),
+        ..subscription("filter-may")
+    };
+    let unset = subscription("filter-unset");
+    timeline_with_entries(&db, &must, &["must-1", "must-2"]).await?;
+    timeline_with_entries(&db, &may, &["may-1"]).await?;
+    timeline_with_entries(&db, &unset, &["unset-1"]).await?;
+    let list = |filter: TimelineEntriesFilter, after, first| {
+        let db = &db;
+        async move {
+            let mut tx = db.begin().await?;
+            let page = tx
+                .list_timeline_entries(TimelineEntriesQuery {
+                    subscriber_id: subscriber_id(),
+                    filter,
+                    after,
+                    first,
+                })
+                .await?;
+            tx.commit().await?;
+            anyhow::Ok(page)
+        }
+    };
+    let filtered = |filter: TimelineEntriesFilter| async move {
+        let page = list(filter, None, 10).await?;
+        let mut titles = page
+            .nodes
+            .iter()
+            .filter_map(|node| node.entry.title().map(|title| title.content().to_owned()))
+            .collect::<Vec<_>>();
+        titles.sort();
+        anyhow::Ok(titles)
+    };
+
+    assert_eq!(
+        filtered(TimelineEntriesFilter {
+            requirements: Some(vec![Some(Requirement::Must)]),
+            ..Default::default()
+        })
+        .await?,
+        ["must-1", "must-2"]
+    );
+    assert_eq!(
+        filtered(TimelineEntriesFilter {
+            requirements: Some(vec![Some(Requirement::Must), None]),
+            ..Default::default()
+        })
+        .await?,
+        ["must-1", "must-2", "unset-1"]
+    );
+    assert_eq!(
+        filtered(TimelineEntriesFilter {
+            categories: Some(vec![Some(news), None]),
+            ..Default::default()
+        })
+        .await?,
+        ["may-1", "unset-1"]
+    );
+    assert_eq!(
+        filtered(TimelineEntriesFilter {
+            categories: Some(vec![Some(rust)]),
+            feed_urls: Some(vec![may.feed_url.clone()]),
+            ..Default::default()
+        })
+        .await?,
+        Vec::<String>::new()
+    );
+    assert!(
+        filtered(TimelineEntriesFilter {
+            requirements: Some(Vec::new()),
+            ..Default::default()
+        })
+        .await?
+        .is_empty()
+    );
+
+    let must_only = TimelineEntriesFilter {
+        feed_urls: Some(vec![must.feed_url.clone()]),
+        ..Default::default()
+    };
+    let first_page = list(must_only.clone(), None, 1).await?;
+    assert!(first_page.has_next_page);
+    let second_page = list(must_only, first_page.end_cursor, 1).await?;
+    assert!(!second_page.has_next_page);
+    assert_eq!(
+        second_page.nodes[0].entry.title().map(Text::content),
+        Some("must-2")
+    );
+    Ok(())
+}
+
+async fn list_timeline_entries_seq(tx: &mut SqliteRegistryTx<'_>) -> anyhow::Result<i64> {
+    let page = tx
+        .list_timeline_entries(TimelineEntriesQuery {
+            subscriber_id: subscriber_id(),
+            filter: TimelineEntriesFilter::default(),
+            after: None,
+            first: 1,
+        })
+        .await?;
+    Ok(page.seq)
+}
@@ -10,11 +10,12 @@ use std::time::Duration;
 use synd_feed::types::FeedUrl;
 use synd_persistence::sqlite::{SqliteDatabase, SqliteFeedRegistryDb};
 use synd_registry::{
-    FeedRegistry, FeedRegistryConfig, FeedRegistryWorkerConfig, SubscribeFeedCommand, SubscriberId,
-    api::ApiEvent,
-    crawl::state::CrawlState,
+    FeedRegistry, FeedRegistryConfig, FeedRegistryWorkerConfig, SaveEntryCommand,
+    SubscribeFeedCommand, SubscriberId,
+    api::{ApiEvent, ApiEventSubscriber, ApiSubscriptionChange},
+    crawl::{history::CrawlOutcome, state::CrawlState},
     db::{CrawlStateDb, FeedRegistryDb},
-    query::TimelineEntriesQuery,
+    query::{CrawlHistoryQuery, TimelineEntriesFilter, TimelineEntriesQuery},
 };
 use tokio_util::sync::CancellationToken;
 
@@ -45,9 +46,16 @@ fn subscribe_command(subscriber_id: &SubscriberId, feed_url: &FeedUrl) -> Subscr
         requirement: None,
         category: None,
         crawl_policy: None,
+        fetch_full_content: false,
     }
 }
 
+async fn next_api_event(api_events: &mut ApiEventSubscriber) -> anyhow::Result<ApiEvent> {
+    tokio::time::timeout(Duration::from_secs(30), api_events.recv())
+        .await?
+        .map_err(|err| anyhow::anyhow!("api event recv failed: {err:?}"))
+}
+
 /// Polls the crawl state until `condition` holds or the timeout passes.
 async fn wait_for_crawl_state(
     db: &SqliteFeedRegistryDb,
@@ -88,11 +96,17 @@ async fn subscribe_flows_through_crawl_to_timeline_notification() -> anyhow::Res
         .subscribe(subscribe_command(&subscriber_id, &feed_url))
         .await?;
 
+    let event = next_api_event(&mut api_events).await?;
+    let ApiEvent::SubscriptionChanged(subscribed) = event else {
+        anyhow::bail!("unexpected api event: {event:?}");
+    };
+    assert_eq!(subscribed.change, ApiSubscriptionChange::Subscribed);
+
     // The whole chain must produce a subscriber-visible timeline notification.
-    let event = tokio::time::timeout(Duration::from_secs(30), api_events.recv())
-        .await?
-        .map_err(|err| anyhow::anyhow!("api event recv failed: {err:?}"))?;
-    let ApiEvent::TimelineChanged(changed) = event;
+    let event = next_api_event(&mut api_events).await?;
+    let ApiEvent::TimelineChanged(changed) = event else {
+        anyhow::bail!("unexpected api event: {event:?}");
+    };
     assert_eq!(changed.subscriber_id, subscriber_id);
     assert_eq!(changed.affected_feeds, vec![feed_url.clone()]);
 
@@ -100,17 +114,53 @@ async fn subscribe_flows_through_crawl_to_timeline_notification() -> anyhow::Res
     let page = registry
         .list_timeline_entries(TimelineEntriesQuery {
             subscriber_id: subscriber_id.clone(),
+            filter: TimelineEntriesFilter::default(),
             after: None,
             first: 10,
         })
         .await?;
     assert!(!page.nodes.is_empty(), "timeline should contain entries");
 
+    // Later timeline changes are published entry by entry, still announced
+    // by a plain timeline change for listeners that only understand it.
+    let saved_id = page.nodes[0].entry.id().clone();
+    registry
+        .save_entry(SaveEntryCommand {
+            subscriber_id: subscriber_id.clone(),
+            entry_id: saved_id.clone(),
+        })
+        .await?;
+    let mut announced = false;
+    loop {
+        match next_api_event(&mut api_events).await? {
+            ApiEvent::TimelineChanged(changed) if changed.resolved => announced = true,
+            ApiEvent::EntryAdded(added) if added.entry.entry.id() == &saved_id => {
+                assert!(added.entry.saved);
+                assert!(added.seqs.since < added.seqs.seq);
+                assert!(announced, "entry-level events follow the timeline change");
+                break;
+            }
+            ApiEvent::EntryAdded(_) => {}
+            event => anyhow::bail!("unexpected api event: {event:?}"),
+        }
+    }
+
     // The finished crawl leaves its observation behind: a healthy state the
     // scheduler derives the next periodic crawl from.
     let state = wait_for_crawl_state(&db, &feed_url, |state| state.last.is_normal()).await?;
     assert_eq!(state.health.failure_streak.value(), 0);
 
+    // The same completion appends to the feed's crawl history.
+    let history = registry
+        .list_crawl_history(CrawlHistoryQuery {
+            feed_url: feed_url.clone(),
+            limit: 10,
+        })
+        .await?;
+    let first = history.last().expect("crawl history should be recorded");
+    assert_eq!(first.outcome, CrawlOutcome::Fetched);
+    assert!(first.body_changed, "first fetched body counts as changed");
+
     ct.cancel();
     drop(workers);
     Ok(())
@@ -125,6 +175,7 @@ async fn failed_crawl_records_failure_state_for_retry() -> anyhow::Result<()> {
     let (registry, workers) = FeedRegistry::start(db.clone(), registry_config(), ct.clone());
 
     let subscriber_id = SubscriberId::new("e2e-retry-reader");
+    let mut api_events = registry.subscribe_events(subscriber_id.clone());
     let feed_url = FeedUrl::parse(&format!("http://{mock_addr}/feed/error/internal"))?;
 
     registry
@@ -139,6 +190,32 @@ async fn failed_crawl_records_failure_state_for_retry() -> anyhow::Result<()> {
         "failure streak should grow"
     );
 
+    let history = registry
+        .list_crawl_history(CrawlHistoryQuery {
+            feed_url: feed_url.clone(),
+            limit: 1,
+        })
+        .await?;
+    assert_eq!(history[0].outcome, CrawlOutcome::UnexpectedStatus);
+    assert_eq!(
+        history[0]
+            .error_kind
+            .map(|kind| kind.to_string())
+            .as_deref(),
+        Some("http_server_error")
+    );
+
+    // Subscribers learn about the failure from their api events.
+    let failed = loop {
+        match next_api_event(&mut api_events).await? {
+            ApiEvent::CrawlFailed(failed) => break failed,
+            ApiEvent::SubscriptionChanged(_) => {}
+            event => anyhow::bail!("unexpected api event: {event:?}"),
+        }
+    };
+    assert_eq!(failed.feed_url, feed_url);
+    assert_eq!(failed.crawl.outcome, CrawlOutcome::UnexpectedStatus);
+
     ct.cancel();
     drop(workers);
     Ok(())
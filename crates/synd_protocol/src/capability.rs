@@ -10,6 +10,12 @@ pub fn local_api_capabilities() -> CapabilitySet {
     CapabilitySet::new([TIMELINE_READ, SUBSCRIPTION_WRITE, FEED_REFRESH])
 }
 
+/// Capabilities served by every daemon, including builds that cannot report
+/// their own set.
+pub fn baseline_api_capabilities() -> CapabilitySet {
+    CapabilitySet::new([TIMELINE_READ, SUBSCRIPTION_WRITE, FEED_REFRESH])
+}
+
 /// Capability names negotiated across the client/server protocol.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CapabilitySet {
@@ -8,16 +8,23 @@ pub const STATUS_PATH: &str = "/daemon/status";
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DaemonStatusResponse {
     sessions: DaemonSessionStatus,
+    /// Absent from daemons that predate crawl status reporting.
+    #[serde(default)]
+    crawl: DaemonCrawlStatus,
 }
 
 impl DaemonStatusResponse {
-    pub fn new(sessions: DaemonSessionStatus) -> Self {
-        Self { sessions }
+    pub fn new(sessions: DaemonSessionStatus, crawl: DaemonCrawlStatus) -> Self {
+        Self { sessions, crawl }
     }
 
     pub fn sessions(&self) -> &DaemonSessionStatus {
         &self.sessions
     }
+
+    pub fn crawl(&self) -> &DaemonCrawlStatus {
+        &self.crawl
+    }
 }
 
 /// Snapshot of daemon session lifecycle state.
@@ -98,3 +105,77 @@ impl DaemonIdleShutdownStatus {
         self.pending
     }
 }
+
+/// Snapshot of daemon crawl dispatch state.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DaemonCrawlStatus {
+    host_backoffs: Vec<DaemonHostBackoff>,
+}
+
+impl DaemonCrawlStatus {
+    pub fn new(host_backoffs: Vec<DaemonHostBackoff>) -> Self {
+        Self { host_backoffs }
+    }
+
+    pub fn host_backoffs(&self) -> &[DaemonHostBackoff] {
+        &self.host_backoffs
+    }
+}
+
+/// A host whose feeds are held back after it answered `429` or `503`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DaemonHostBackoff {
+    host: String,
+    feed_url: String,
+    remaining: Duration,
+}
+
+impl DaemonHostBackoff {
+    pub fn new(host: String, feed_url: String, remaining: Duration) -> Self {
+        Self {
+            host,
+            feed_url,
+            remaining,
+        }
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Feed whose crawl was answered with the rate limit.
+    pub fn feed_url(&self) -> &str {
+        &self.feed_url
+    }
+
+    /// Time left until feeds on the host are dispatched again.
+    pub fn remaining(&self) -> Duration {
+        self.remaining
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_response_without_crawl_status_decodes_as_empty() {
+        let sessions = DaemonSessionStatus::new(
+            1,
+            Duration::from_secs(30),
+            Duration::from_secs(10),
+            DaemonIdleShutdownStatus::disabled(),
+        );
+        let mut value = serde_json::to_value(DaemonStatusResponse::new(
+            sessions.clone(),
+            DaemonCrawlStatus::default(),
+        ))
+        .unwrap();
+        value.as_object_mut().unwrap().remove("crawl");
+
+        let response: DaemonStatusResponse = serde_json::from_value(value).unwrap();
+
+        assert_eq!(response.sessions(), &sessions);
+        assert!(response.crawl().host_backoffs().is_empty());
+    }
+}
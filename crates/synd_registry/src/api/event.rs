@@ -1,29 +1,44 @@
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
-use synd_feed::types::FeedUrl;
+use synd_feed::{entry::EntryId, types::FeedUrl};
 
-use crate::subscription::SubscriberId;
+use crate::{
+    crawl::history::CrawlHistoryEntry, subscription::SubscriberId, timeline::query::TimelineEntry,
+};
 
 /// Public event contract exposed through the API stream.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub enum ApiEvent {
     TimelineChanged(ApiTimelineChanged),
+    EntryAdded(ApiEntryAdded),
+    EntryRemoved(ApiEntryRemoved),
+    SubscriptionChanged(ApiSubscriptionChanged),
+    CrawlFailed(ApiCrawlFailed),
 }
 
 impl ApiEvent {
     pub fn subscriber_id(&self) -> &SubscriberId {
         match self {
             Self::TimelineChanged(event) => &event.subscriber_id,
+            Self::EntryAdded(event) => &event.subscriber_id,
+            Self::EntryRemoved(event) => &event.subscriber_id,
+            Self::SubscriptionChanged(event) => &event.subscriber_id,
+            Self::CrawlFailed(event) => &event.subscriber_id,
         }
     }
 }
 
 /// API stream payload emitted when a timeline's visible contents change.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Emitted for every batch of timeline changes, so listeners that only
+/// understand it catch up through the timeline changes query.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ApiTimelineChanged {
     pub subscriber_id: SubscriberId,
     pub changed_at: DateTime<Utc>,
     pub affected_feeds: Vec<FeedUrl>,
+    /// Whether entry-level events of the same changes are published along
+    /// with this event. Listeners applying them can ignore it.
+    pub resolved: bool,
 }
 
 impl ApiTimelineChanged {
@@ -31,11 +46,66 @@ impl ApiTimelineChanged {
         subscriber_id: SubscriberId,
         changed_at: DateTime<Utc>,
         affected_feeds: Vec<FeedUrl>,
+        resolved: bool,
     ) -> Self {
         Self {
             subscriber_id,
             changed_at,
             affected_feeds,
+            resolved,
         }
     }
 }
+
+/// Range of timeline change seqs one batch of entry-level events covers.
+///
+/// A listener synced up to any seq within the range applies every event of
+/// the batch and is then synced up to `seq`. A listener behind `since`
+/// missed changes and has to catch up first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApiTimelineSeqs {
+    pub since: i64,
+    pub seq: i64,
+}
+
+/// An entry was added to a timeline or its subscriber-scoped state changed.
+#[derive(Debug, Clone)]
+pub struct ApiEntryAdded {
+    pub subscriber_id: SubscriberId,
+    /// The entry with its cursor on the timeline.
+    pub entry: Box<TimelineEntry>,
+    pub seqs: ApiTimelineSeqs,
+}
+
+/// An entry left a timeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiEntryRemoved {
+    pub subscriber_id: SubscriberId,
+    pub entry_id: EntryId,
+    pub seqs: ApiTimelineSeqs,
+}
+
+/// How a subscription changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiSubscriptionChange {
+    Subscribed,
+    Changed,
+    Unsubscribed,
+}
+
+/// A subscription of the subscriber was created, updated or ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiSubscriptionChanged {
+    pub subscriber_id: SubscriberId,
+    pub feed_url: FeedUrl,
+    pub change: ApiSubscriptionChange,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// A crawl of a feed the subscriber subscribes to failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiCrawlFailed {
+    pub subscriber_id: SubscriberId,
+    pub feed_url: FeedUrl,
+    pub crawl: CrawlHistoryEntry,
+}
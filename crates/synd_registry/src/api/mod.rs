@@ -2,6 +2,11 @@
 
 mod event;
 mod publisher;
+mod resolver;
 
-pub use event::{ApiEvent, ApiTimelineChanged};
+pub use event::{
+    ApiCrawlFailed, ApiEntryAdded, ApiEntryRemoved, ApiEvent, ApiSubscriptionChange,
+    ApiSubscriptionChanged, ApiTimelineChanged, ApiTimelineSeqs,
+};
 pub use publisher::{ApiEventPublisher, ApiEventRecvError, ApiEventSubscriber};
+pub use resolver::{ApiEventInput, ApiEventResolver};
@@ -1,19 +1,10 @@
 use std::fmt;
 
-use chrono::{DateTime, Utc};
 use tokio::sync::broadcast;
-use tracing::debug;
 
-use crate::{
-    SubscriberId,
-    api::{ApiEvent, ApiTimelineChanged},
-    event::{
-        Event, EventInput, EventType, Processor, ProcessorError, ProcessorId, ProcessorResult,
-        RegistryEvent, Sink, TimelineChangedEvent,
-    },
-};
+use crate::{SubscriberId, api::ApiEvent};
 
-/// Broadcasts committed API events to subscriber-scoped listeners.
+/// Broadcasts resolved API events to subscriber-scoped listeners.
 #[derive(Clone)]
 pub struct ApiEventPublisher {
     sender: broadcast::Sender<ApiEvent>,
@@ -32,6 +23,10 @@ impl ApiEventPublisher {
         }
     }
 
+    pub fn has_receivers(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+
     pub fn publish(&self, event: ApiEvent) -> usize {
         self.sender.send(event).unwrap_or_default()
     }
@@ -49,36 +44,6 @@ impl Default for ApiEventPublisher {
     }
 }
 
-impl Processor for ApiEventPublisher {
-    type Input = ApiEvent;
-
-    fn id(&self) -> ProcessorId {
-        ProcessorId::ApiEventPublisher
-    }
-}
-
-impl Sink for ApiEventPublisher {
-    async fn sink(&mut self, input: Self::Input) {
-        let receivers = self.publish(input);
-        debug!(receivers, "registry api event publisher delivered event");
-    }
-}
-
-impl EventInput for ApiEvent {
-    const INTERESTS: &'static [EventType] = &[TimelineChangedEvent::TYPE];
-
-    fn from_event(event: Event, occurred_at: DateTime<Utc>) -> ProcessorResult<Self> {
-        match event {
-            Event::TimelineChanged(event) => Ok(Self::TimelineChanged(ApiTimelineChanged::new(
-                event.subscriber_id,
-                occurred_at,
-                event.affected_feeds,
-            ))),
-            event => Err(ProcessorError::unexpected_input("api event", &event)),
-        }
-    }
-}
-
 /// Receives API events for one subscriber.
 pub struct ApiEventSubscriber {
     subscriber_id: SubscriberId,
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use tracing::{debug, warn};
+
+use crate::{
+    api::{
+        ApiCrawlFailed, ApiEntryAdded, ApiEntryRemoved, ApiEvent, ApiEventPublisher,
+        ApiSubscriptionChange, ApiSubscriptionChanged, ApiTimelineChanged, ApiTimelineSeqs,
+    },
+    crawl::history::CrawlHistoryQuery,
+    db::{CommitTx, CrawlHistoryDb, FeedRegistryDb, SubscriptionDb, TimelineDb},
+    error::RegistryDbResult,
+    event::{
+        CrawlJobFinishedEvent, Event, EventInput, EventType, FeedSubscribedEvent,
+        FeedUnsubscribedEvent, Processor, ProcessorError, ProcessorId, ProcessorResult,
+        RegistryEvent, Sink, SubEvent, SubscriptionChangedEvent, TimelineChangedEvent,
+        TimelineDeletedEvent,
+    },
+    subscription::SubscriberId,
+    timeline::query::{TimelineChange, TimelineChangesQuery},
+};
+
+/// Timeline changes resolved into entry-level events at most per timeline
+/// change. Larger batches are published as a plain timeline change.
+const MAX_RESOLVED_CHANGES: usize = 100;
+
+/// Recent crawls searched for the one a finished job recorded.
+const CRAWL_HISTORY_LOOKBACK: usize = 5;
+
+/// Resolves committed events into API events and publishes them.
+///
+/// Every timeline change is published as a plain timeline change, followed
+/// by the entries that changed since the previously published batch of the
+/// same subscriber. Until a batch was published for a subscriber, or when a
+/// batch is too large, only the plain timeline change is published.
+pub struct ApiEventResolver<S> {
+    db: S,
+    publisher: ApiEventPublisher,
+    /// Timeline seq the published events of each subscriber reached.
+    timeline_seqs: HashMap<SubscriberId, i64>,
+}
+
+impl<S> ApiEventResolver<S> {
+    pub fn new(db: S, publisher: ApiEventPublisher) -> Self {
+        Self {
+            db,
+            publisher,
+            timeline_seqs: HashMap::new(),
+        }
+    }
+}
+
+impl<S> ApiEventResolver<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: CrawlHistoryDb + SubscriptionDb + TimelineDb,
+{
+    async fn resolve(&mut self, input: ApiEventInput) -> RegistryDbResult<Vec<ApiEvent>> {
+        match input {
+            ApiEventInput::TimelineChanged { event, occurred_at } => {
+                Ok(self.resolve_timeline_changed(event, occurred_at).await)
+            }
+            ApiEventInput::SubscriptionChanged { event, occurred_at } => {
+                Ok(vec![subscription_changed(&event, occurred_at)])
+            }
+            ApiEventInput::CrawlJobFinished(event) => self.resolve_crawl_failed(event).await,
+            ApiEventInput::TimelineDeleted(event) => {
+                // A new timeline of the subscriber restarts its seqs.
+                self.timeline_seqs.remove(&event.subscriber_id);
+                Ok(Vec::new())
+            }
+        }
+    }
+
+    async fn resolve_timeline_changed(
+        &mut self,
+        event: TimelineChangedEvent,
+        occurred_at: DateTime<Utc>,
+    ) -> Vec<ApiEvent> {
+        let subscriber_id = event.subscriber_id;
+        let events = match self.resolve_timeline_changes(&subscriber_id).await {
+            Ok(events) => events,
+            Err(err) => {
+                warn!(
+                    subscriber_id = subscriber_id.as_str(),
+                    error = %err,
+                    "registry api event resolver failed to resolve timeline changes"
+                );
+                self.timeline_seqs.remove(&subscriber_id);
+                None
+            }
+        };
+        let changed = ApiEvent::TimelineChanged(ApiTimelineChanged::new(
+            subscriber_id,
+            occurred_at,
+            event.affected_feeds,
+            events.is_some(),
+        ));
+        std::iter::once(changed)
+            .chain(events.into_iter().flatten())
+            .collect()
+    }
+
+    /// Returns `None` when the changes cannot be published entry by entry.
+    async fn resolve_timeline_changes(
+        &mut self,
+        subscriber_id: &SubscriberId,
+    ) -> RegistryDbResult<Option<Vec<ApiEvent>>> {
+        let mut tx = self.db.begin().await?;
+        let Some(since) = self.timeline_seqs.get(subscriber_id).copied() else {
+            let seq = tx.load_timeline_seq(subscriber_id).await?;
+            tx.commit().await?;
+            self.timeline_seqs.insert(subscriber_id.clone(), seq);
+            return Ok(None);
+        };
+        let page = tx
+            .list_timeline_changes(TimelineChangesQuery {
+                subscriber_id: subscriber_id.clone(),
+                since,
+                limit: MAX_RESOLVED_CHANGES,
+            })
+            .await?;
+        if page.has_more || page.expired {
+            let seq = tx.load_timeline_seq(subscriber_id).await?;
+            tx.commit().await?;
+            self.timeline_seqs.insert(subscriber_id.clone(), seq);
+            return Ok(None);
+        }
+        tx.commit().await?;
+
+        self.timeline_seqs.insert(subscriber_id.clone(), page.seq);
+        let seqs = ApiTimelineSeqs {
+            since,
+            seq: page.seq,
+        };
+        Ok(Some(
+            page.changes
+                .into_iter()
+                .map(|change| match change {
+                    TimelineChange::Upsert(entry) => ApiEvent::EntryAdded(ApiEntryAdded {
+                        subscriber_id: subscriber_id.clone(),
+                        entry,
+                        seqs,
+                    }),
+                    TimelineChange::Remove { entry_id } => {
+                        ApiEvent::EntryRemoved(ApiEntryRemoved {
+                            subscriber_id: subscriber_id.clone(),
+                            entry_id,
+                            seqs,
+                        })
+                    }
+                })
+                .collect(),
+        ))
+    }
+
+    async fn resolve_crawl_failed(
+        &self,
+        event: CrawlJobFinishedEvent,
+    ) -> RegistryDbResult<Vec<ApiEvent>> {
+        let mut tx = self.db.begin().await?;
+        let crawl = tx
+            .list_crawl_history(CrawlHistoryQuery {
+                feed_url: event.feed_url.clone(),
+                limit: CRAWL_HISTORY_LOOKBACK,
+            })
+            .await?
+            .into_iter()
+            .find(|crawl| crawl.job_id == event.job_id);
+        let Some(crawl) = crawl.filter(|crawl| crawl.outcome.is_failure()) else {
+            tx.commit().await?;
+            return Ok(Vec::new());
+        };
+        let subscriptions = tx.load_feed_subscribers(&event.feed_url).await?;
+        tx.commit().await?;
+
+        Ok(subscriptions
+            .into_iter()
+            .map(|subscription| {
+                ApiEvent::CrawlFailed(ApiCrawlFailed {
+                    subscriber_id: subscription.subscriber_id,
+                    feed_url: event.feed_url.clone(),
+                    crawl: crawl.clone(),
+                })
+            })
+            .collect())
+    }
+}
+
+fn subscription_changed(event: &SubEvent, occurred_at: DateTime<Utc>) -> ApiEvent {
+    let change = match event {
+        SubEvent::Subscribed(_) => ApiSubscriptionChange::Subscribed,
+        SubEvent::Changed(_) => ApiSubscriptionChange::Changed,
+        SubEvent::Unsubscribed(_) => ApiSubscriptionChange::Unsubscribed,
+    };
+    let subscription = event.subscription();
+    ApiEvent::SubscriptionChanged(ApiSubscriptionChanged {
+        subscriber_id: subscription.subscriber_id.clone(),
+        feed_url: subscription.feed_url.clone(),
+        change,
+        changed_at: occurred_at,
+    })
+}
+
+impl<S> Processor for ApiEventResolver<S>
+where
+    S: Send + 'static,
+{
+    type Input = ApiEventInput;
+
+    fn id(&self) -> ProcessorId {
+        ProcessorId::ApiEventPublisher
+    }
+}
+
+impl<S> Sink for ApiEventResolver<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: CrawlHistoryDb + SubscriptionDb + TimelineDb,
+{
+    async fn sink(&mut self, input: Self::Input) {
+        if !self.publisher.has_receivers() {
+            // Listeners sync their timeline before watching, so nothing
+            // published earlier is relevant to the next one.
+            self.timeline_seqs.clear();
+            return;
+        }
+        match self.resolve(input).await {
+            Ok(events) => {
+                let count = events.len();
+                for event in events {
+                    self.publisher.publish(event);
+                }
+                debug!(count, "registry api event resolver published events");
+            }
+            Err(err) => {
+                warn!(error = %err, "registry api event resolver failed to resolve event");
+            }
+        }
+    }
+}
+
+/// Event input consumed by [`ApiEventResolver`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiEventInput {
+    TimelineChanged {
+        event: TimelineChangedEvent,
+        occurred_at: DateTime<Utc>,
+    },
+    SubscriptionChanged {
+        event: SubEvent,
+        occurred_at: DateTime<Utc>,
+    },
+    CrawlJobFinished(CrawlJobFinishedEvent),
+    TimelineDeleted(TimelineDeletedEvent),
+}
+
+impl EventInput for ApiEventInput {
+    const INTERESTS: &'static [EventType] = &[
+        TimelineChangedEvent::TYPE,
+        FeedSubscribedEvent::TYPE,
+        SubscriptionChangedEvent::TYPE,
+        FeedUnsubscribedEvent::TYPE,
+        CrawlJobFinishedEvent::TYPE,
+        TimelineDeletedEvent::TYPE,
+    ];
+
+    fn from_event(event: Event, occurred_at: DateTime<Utc>) -> ProcessorResult<Self> {
+        let subscription_changed = |event| Self::SubscriptionChanged { event, occurred_at };
+        match event {
+            Event::TimelineChanged(event) => Ok(Self::TimelineChanged { event, occurred_at }),
+            Event::FeedSubscribed(event) => Ok(subscription_changed(SubEvent::Subscribed(event))),
+            Event::SubscriptionChanged(event) => Ok(subscription_changed(SubEvent::Changed(event))),
+            Event::FeedUnsubscribed(event) => {
+                Ok(subscription_changed(SubEvent::Unsubscribed(event)))
+            }
+            Event::CrawlJobFinished(event) => Ok(Self::CrawlJobFinished(event)),
+            Event::TimelineDeleted(event) => Ok(Self::TimelineDeleted(event)),
+            event => Err(ProcessorError::unexpected_input("api event", &event)),
+        }
+    }
+}
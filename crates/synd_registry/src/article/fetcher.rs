@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use synd_feed::feed::service::FetchArticle;
+use synd_support::o11y::metric;
+use tracing::{debug, info, warn};
+
+use crate::{
+    article::{ArticleFetch, ArticleFetchOutcome},
+    config::ArticleFetchConfig,
+    crawl::{
+        blob::PutBlobCommand,
+        host::{ActiveHostBackoffs, HostBackoffBoard},
+        worker::CrawlHostCapacity,
+    },
+    db::{ArticleDb, BlobDb, CommitTx, FeedRegistryDb},
+    event::{
+        EntryDiscoveredEvent, EventInterests, Reaction, Reconciler, RecordedEvents, RegistryEvent,
+        WakeRequest, WorkerId, WorkerResult,
+    },
+};
+
+/// Level-driven worker fetching the web page of queued entries and storing
+/// the extracted article.
+///
+/// Pages are fetched outside any transaction; each result is recorded on
+/// its own so a slow site does not hold the database. Entries are queued by
+/// the feed projection when they are discovered, which also wakes this
+/// worker.
+///
+/// Pages are fetched under the same per-host caps and request spacing as
+/// feed crawls, and a host backed off by a rate limit is not asked for pages
+/// until its backoff ends.
+pub(crate) struct ArticleFetcher<F> {
+    fetcher: F,
+    config: ArticleFetchConfig,
+    hosts: Arc<CrawlHostCapacity>,
+    host_backoffs: HostBackoffBoard,
+}
+
+impl<F> ArticleFetcher<F> {
+    pub(crate) fn new(
+        fetcher: F,
+        config: ArticleFetchConfig,
+        hosts: Arc<CrawlHostCapacity>,
+        host_backoffs: HostBackoffBoard,
+    ) -> Self {
+        Self {
+            fetcher,
+            config,
+            hosts,
+            host_backoffs,
+        }
+    }
+}
+
+impl<F> ArticleFetcher<F>
+where
+    F: FetchArticle,
+{
+    async fn fetch(
+        &self,
+        fetch: &ArticleFetch,
+        backoffs: &ActiveHostBackoffs,
+        now: DateTime<Utc>,
+    ) -> Fetched {
+        if let Some(until) = backoffs.until(&fetch.url) {
+            debug!(
+                entry_id = %fetch.entry_id,
+                url = fetch.url.as_str(),
+                %until,
+                "article fetch deferred by host backoff"
+            );
+            return Fetched::Failed(fetch.deferred(until));
+        }
+        let _host_permit = self.hosts.reserve(&fetch.url).await;
+        match self.fetcher.fetch_article(fetch.url.clone()).await {
+            Ok(article) => Fetched::Article(article),
+            Err(err) => {
+                let outcome = fetch.failed(err.is_transient(), &self.config, now);
+                warn!(
+                    entry_id = %fetch.entry_id,
+                    url = fetch.url.as_str(),
+                    error = %err,
+                    ?outcome,
+                    "article fetch failed"
+                );
+                Fetched::Failed(outcome)
+            }
+        }
+    }
+}
+
+/// Result of fetching one page, before it is recorded.
+enum Fetched {
+    Article(String),
+    /// Also carries fetches put off without being attempted.
+    Failed(ArticleFetchOutcome),
+}
+
+impl<S, F> Reconciler<S> for ArticleFetcher<F>
+where
+    S: FeedRegistryDb,
+    F: FetchArticle + 'static,
+    for<'tx> S::Tx<'tx>: ArticleDb + BlobDb + Send,
+{
+    fn id(&self) -> WorkerId {
+        WorkerId::ArticleFetcher
+    }
+
+    fn wake_hints(&self) -> EventInterests {
+        EventInterests::new(vec![EntryDiscoveredEvent::TYPE])
+    }
+
+    async fn reconcile(&mut self, db: &S, now: DateTime<Utc>) -> WorkerResult<Reaction> {
+        let limit = self.config.batch_size.max(1);
+        let mut tx = db.begin().await?;
+        let due = tx.load_due_article_fetches(now, limit).await?;
+        tx.commit().await?;
+
+        let backoffs = self
+            .host_backoffs
+            .snapshot()
+            .into_iter()
+            .collect::<ActiveHostBackoffs>();
+        let mut fetched = 0_u64;
+        for fetch in &due {
+            let result = self.fetch(fetch, &backoffs, now).await;
+            let mut tx = db.begin().await?;
+            let outcome = match result {
+                Fetched::Article(article) => {
+                    fetched += 1;
+                    let article = tx
+                        .put_blob(PutBlobCommand::new(article.into_bytes(), now))
+                        .await?;
+                    ArticleFetchOutcome::Fetched {
+                        article,
+                        fetched_at: now,
+                    }
+                }
+                Fetched::Failed(outcome) => outcome,
+            };
+            tx.record_article_fetch(&fetch.entry_id, outcome).await?;
+            tx.commit().await?;
+        }
+
+        if due.is_empty() {
+            debug!("no articles due for fetching");
+        } else {
+            info!(attempted = due.len(), fetched, "articles fetched");
+        }
+        if fetched > 0 {
+            metric!(monotonic_counter.registry.article.fetched = fetched);
+        }
+
+        let wake = if due.len() >= limit {
+            WakeRequest::at(now)
+        } else {
+            let mut tx = db.begin().await?;
+            let next = tx.next_article_fetch_at().await?;
+            tx.commit().await?;
+            next.map_or(WakeRequest::None, WakeRequest::at)
+        };
+        Ok(Reaction::new(RecordedEvents::empty(), wake))
+    }
+}
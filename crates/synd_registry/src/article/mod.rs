@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use synd_feed::{entry::EntryId, types::FeedUrl};
+
+use crate::{config::ArticleFetchConfig, crawl::blob::BlobRef};
+
+pub(crate) mod fetcher;
+
+/// Exponent cap for the retry delay; retries stop long before it matters.
+const MAX_RETRY_EXPONENT: u32 = 16;
+
+/// Pending fetch of the web page an entry links to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArticleFetch {
+    pub entry_id: EntryId,
+    pub url: FeedUrl,
+    /// Failed attempts so far.
+    pub attempts: u32,
+}
+
+impl ArticleFetch {
+    pub fn new(entry_id: EntryId, url: FeedUrl) -> Self {
+        Self {
+            entry_id,
+            url,
+            attempts: 0,
+        }
+    }
+
+    /// Decides what a failed attempt leaves behind. Pages that failed for
+    /// good, such as ones without an article, are not fetched again.
+    pub fn failed(
+        &self,
+        transient: bool,
+        config: &ArticleFetchConfig,
+        now: DateTime<Utc>,
+    ) -> ArticleFetchOutcome {
+        let attempts = self.attempts.saturating_add(1);
+        if !transient || attempts >= config.max_attempts {
+            return ArticleFetchOutcome::Abandoned { attempts };
+        }
+        let delay = retry_delay(config.retry_delay, self.attempts);
+        let next_attempt_at = TimeDelta::from_std(delay)
+            .ok()
+            .and_then(|delay| now.checked_add_signed(delay))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        ArticleFetchOutcome::Retry {
+            attempts,
+            next_attempt_at,
+        }
+    }
+
+    /// Puts the fetch off until `until` without counting an attempt, for a
+    /// host that is backed off.
+    pub fn deferred(&self, until: DateTime<Utc>) -> ArticleFetchOutcome {
+        ArticleFetchOutcome::Retry {
+            attempts: self.attempts,
+            next_attempt_at: until,
+        }
+    }
+}
+
+/// What one attempt to fetch an article leaves behind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArticleFetchOutcome {
+    /// The extracted article is stored.
+    Fetched {
+        article: BlobRef,
+        fetched_at: DateTime<Utc>,
+    },
+    /// The page is fetched again at `next_attempt_at`.
+    Retry {
+        attempts: u32,
+        next_attempt_at: DateTime<Utc>,
+    },
+    /// The entry keeps only its feed content.
+    Abandoned { attempts: u32 },
+}
+
+/// Retry delay doubling with every failed attempt.
+fn retry_delay(base: Duration, failed_attempts: u32) -> Duration {
+    base.saturating_mul(2u32.saturating_pow(failed_attempts.min(MAX_RETRY_EXPONENT)))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 7, 4, 12, 0, 0).unwrap()
+    }
+
+    fn fetch(attempts: u32) -> ArticleFetch {
+        ArticleFetch {
+            attempts,
+            ..ArticleFetch::new(
+                EntryId::parse(format!("synd:entry:v1:{}", "1".repeat(64))).unwrap(),
+                FeedUrl::parse("https://example.com/posts/1").unwrap(),
+            )
+        }
+    }
+
+    fn config() -> ArticleFetchConfig {
+        ArticleFetchConfig {
+            batch_size: 10,
+            max_attempts: 3,
+            retry_delay: Duration::from_mins(10),
+        }
+    }
+
+    #[test]
+    fn transient_failure_retries_with_doubling_delay() {
+        assert_eq!(
+            fetch(0).failed(true, &config(), now()),
+            ArticleFetchOutcome::Retry {
+                attempts: 1,
+                next_attempt_at: now() + TimeDelta::minutes(10),
+            }
+        );
+        assert_eq!(
+            fetch(1).failed(true, &config(), now()),
+            ArticleFetchOutcome::Retry {
+                attempts: 2,
+                next_attempt_at: now() + TimeDelta::minutes(20),
+            }
+        );
+    }
+
+    #[test]
+    fn transient_failure_is_abandoned_after_max_attempts() {
+        assert_eq!(
+            fetch(2).failed(true, &config(), now()),
+            ArticleFetchOutcome::Abandoned { attempts: 3 }
+        );
+    }
+
+    #[test]
+    fn deferred_fetch_keeps_its_attempts() {
+        let until = now() + TimeDelta::minutes(30);
+        assert_eq!(
+            fetch(1).deferred(until),
+            ArticleFetchOutcome::Retry {
+                attempts: 1,
+                next_attempt_at: until,
+            }
+        );
+    }
+
+    #[test]
+    fn permanent_failure_is_abandoned_at_once() {
+        assert_eq!(
+            fetch(0).failed(false, &config(), now()),
+            ArticleFetchOutcome::Abandoned { attempts: 1 }
+        );
+    }
+}
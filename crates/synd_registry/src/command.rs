@@ -1,4 +1,7 @@
-use synd_feed::types::{Category, FeedUrl, Requirement};
+use synd_feed::{
+    entry::EntryId,
+    types::{Category, FeedUrl, Requirement},
+};
 
 use crate::{
     crawl::{policy::CrawlPolicy, request::RequestCrawlOutcome},
@@ -15,6 +18,7 @@ pub struct SubscribeFeedCommand {
     pub requirement: Option<Requirement>,
     pub category: Option<Category<'static>>,
     pub crawl_policy: Option<CrawlPolicy>,
+    pub fetch_full_content: bool,
 }
 
 impl SubscribeFeedCommand {
@@ -27,6 +31,7 @@ impl SubscribeFeedCommand {
             requirement: self.requirement,
             category: self.category,
             crawl_policy: self.crawl_policy.unwrap_or(default_crawl_policy),
+            fetch_full_content: self.fetch_full_content,
         };
         (subscription, attrs)
     }
@@ -85,3 +90,76 @@ pub struct RequestCrawlCommand {
 pub struct RequestCrawlOutput {
     pub outcome: RequestCrawlOutcome,
 }
+
+/// Request to remove everything one subscriber declared: its subscriptions
+/// and saved entries.
+#[derive(Debug, Clone)]
+pub struct DeleteSubscriberCommand {
+    pub subscriber_id: SubscriberId,
+}
+
+/// Result returned after deleting a subscriber.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteSubscriberOutput {
+    pub unsubscribed: usize,
+    pub unsaved: usize,
+}
+
+/// Request to mark timeline entries as read for one subscriber.
+#[derive(Debug, Clone)]
+pub struct MarkEntriesReadCommand {
+    pub subscriber_id: SubscriberId,
+    pub entry_ids: Vec<EntryId>,
+}
+
+/// Result returned after handling a mark-read request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkEntriesReadOutput {
+    /// Entries that were unread before the request. Entries already read or
+    /// not on the subscriber's timeline are left out.
+    pub changed: Vec<EntryId>,
+}
+
+/// Request to mark timeline entries as unread for one subscriber.
+#[derive(Debug, Clone)]
+pub struct MarkEntriesUnreadCommand {
+    pub subscriber_id: SubscriberId,
+    pub entry_ids: Vec<EntryId>,
+}
+
+/// Result returned after handling a mark-unread request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkEntriesUnreadOutput {
+    /// Entries that were read before the request. Entries already unread or
+    /// not on the subscriber's timeline are left out.
+    pub changed: Vec<EntryId>,
+}
+
+/// Request to save one timeline entry for one subscriber.
+#[derive(Debug, Clone)]
+pub struct SaveEntryCommand {
+    pub subscriber_id: SubscriberId,
+    pub entry_id: EntryId,
+}
+
+/// Result returned after handling a save request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveEntryOutput {
+    /// Whether the entry was newly saved. False when it was already saved
+    /// or is not on the subscriber's timeline.
+    pub changed: bool,
+}
+
+/// Request to remove one entry from a subscriber's saved entries.
+#[derive(Debug, Clone)]
+pub struct UnsaveEntryCommand {
+    pub subscriber_id: SubscriberId,
+    pub entry_id: EntryId,
+}
+
+/// Result returned after handling an unsave request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsaveEntryOutput {
+    /// Whether the entry was saved before the request.
+    pub changed: bool,
+}
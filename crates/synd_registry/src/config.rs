@@ -12,7 +12,12 @@ pub struct FeedRegistryWorkerConfig {
     pub feed_projection_poll_interval: Duration,
     pub timeline_projection_poll_interval: Duration,
     pub api_event_publisher_poll_interval: Duration,
+    pub entry_notifier_poll_interval: Duration,
     pub crawl_dispatcher_poll_interval: Duration,
+    pub journal_compactor_poll_interval: Duration,
+    pub blob_collector_poll_interval: Duration,
+    pub article_fetcher_poll_interval: Duration,
+    pub timeline_pruner_poll_interval: Duration,
 }
 
 impl FeedRegistryWorkerConfig {
@@ -22,7 +27,12 @@ impl FeedRegistryWorkerConfig {
             feed_projection_poll_interval: poll_interval,
             timeline_projection_poll_interval: poll_interval,
             api_event_publisher_poll_interval: poll_interval,
+            entry_notifier_poll_interval: poll_interval,
             crawl_dispatcher_poll_interval: poll_interval,
+            journal_compactor_poll_interval: poll_interval,
+            blob_collector_poll_interval: poll_interval,
+            article_fetcher_poll_interval: poll_interval,
+            timeline_pruner_poll_interval: poll_interval,
         }
     }
 }
@@ -49,6 +59,96 @@ impl Default for CrawlDispatchConfig {
     }
 }
 
+/// Runtime configuration for event journal compaction.
+#[derive(Debug, Clone, Copy)]
+pub struct JournalCompactionConfig {
+    /// Maximum journal entries deleted in one transaction. A pass that hits
+    /// the limit wakes again immediately instead of waiting for the poll.
+    pub batch_size: usize,
+}
+
+impl Default for JournalCompactionConfig {
+    fn default() -> Self {
+        Self { batch_size: 1000 }
+    }
+}
+
+/// Runtime configuration for unreferenced feed body collection.
+#[derive(Debug, Clone, Copy)]
+pub struct BlobCollectionConfig {
+    /// Minimum age before an unreferenced blob is deleted.
+    pub safety_margin: Duration,
+    /// Maximum blobs deleted in one transaction.
+    pub batch_size: usize,
+}
+
+impl Default for BlobCollectionConfig {
+    fn default() -> Self {
+        Self {
+            safety_margin: Duration::from_hours(24),
+            batch_size: 100,
+        }
+    }
+}
+
+/// Runtime configuration for fetching full articles of opted-in entries.
+#[derive(Debug, Clone, Copy)]
+pub struct ArticleFetchConfig {
+    /// Maximum pages fetched in one pass.
+    pub batch_size: usize,
+    /// Attempts after which a page that keeps failing is given up on.
+    pub max_attempts: u32,
+    /// Delay before the first retry; it doubles with every further attempt.
+    pub retry_delay: Duration,
+}
+
+impl Default for ArticleFetchConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 10,
+            max_attempts: 3,
+            retry_delay: Duration::from_mins(10),
+        }
+    }
+}
+
+/// Runtime configuration for pruning old entries off timelines.
+#[derive(Debug, Clone, Copy)]
+pub struct TimelineRetentionConfig {
+    /// Entries ordered longer ago than this leave the timeline.
+    pub max_age: Option<Duration>,
+    /// Entries beyond the newest this many of each feed leave the timeline.
+    pub max_entries_per_feed: Option<usize>,
+    /// Keep unread entries regardless of age and count. Saved entries are
+    /// always kept.
+    pub keep_unread: bool,
+    /// Number of most recent change seqs of each timeline whose removals stay
+    /// as tombstones. Clients further behind reload instead of syncing.
+    pub tombstone_seq_window: u64,
+    /// Maximum rows removed by each step in one transaction.
+    pub batch_size: usize,
+}
+
+impl Default for TimelineRetentionConfig {
+    fn default() -> Self {
+        Self {
+            max_age: None,
+            max_entries_per_feed: None,
+            keep_unread: true,
+            tombstone_seq_window: 10_000,
+            batch_size: 500,
+        }
+    }
+}
+
+/// Limits applied to each subscriber's subscriptions.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SubscriptionQuotaConfig {
+    /// Maximum feeds one subscriber may subscribe to. Editing an existing
+    /// subscription is always allowed.
+    pub max_subscriptions: Option<usize>,
+}
+
 /// Runtime configuration for the registry facade and event workers.
 #[derive(Debug, Clone, Copy)]
 pub struct FeedRegistryConfig {
@@ -56,6 +156,11 @@ pub struct FeedRegistryConfig {
     pub event_wake_channel_capacity: usize,
     pub workers: FeedRegistryWorkerConfig,
     pub crawl_dispatch: CrawlDispatchConfig,
+    pub journal_compaction: JournalCompactionConfig,
+    pub blob_collection: BlobCollectionConfig,
+    pub article_fetch: ArticleFetchConfig,
+    pub timeline_retention: TimelineRetentionConfig,
+    pub subscription_quota: SubscriptionQuotaConfig,
     pub crawl_worker_pool: CrawlWorkerPoolConfig,
 }
 
@@ -69,6 +174,11 @@ impl Default for FeedRegistryConfig {
             event_wake_channel_capacity: 1024,
             workers: FeedRegistryWorkerConfig::default(),
             crawl_dispatch: CrawlDispatchConfig::default(),
+            journal_compaction: JournalCompactionConfig::default(),
+            blob_collection: BlobCollectionConfig::default(),
+            article_fetch: ArticleFetchConfig::default(),
+            timeline_retention: TimelineRetentionConfig::default(),
+            subscription_quota: SubscriptionQuotaConfig::default(),
             crawl_worker_pool: CrawlWorkerPoolConfig::default(),
         }
     }
@@ -32,3 +32,12 @@ impl PutBlobCommand {
         Self { bytes, created_at }
     }
 }
+
+/// Result of one unreferenced-blob collection pass.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlobCollection {
+    /// Number of blobs deleted.
+    pub removed: u64,
+    /// Collectable blobs remain because the pass stopped at its limit.
+    pub has_more: bool,
+}
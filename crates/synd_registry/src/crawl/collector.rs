@@ -0,0 +1,113 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use synd_support::o11y::metric;
+use tracing::{debug, info};
+
+use crate::{
+    config::BlobCollectionConfig,
+    crawl::blob::BlobCollection,
+    db::{BlobDb, CommitTx, FeedRegistryDb},
+    error::RegistryDbResult,
+    event::{
+        EventInterests, Reaction, Reconciler, RecordedEvents, WakeRequest, WorkerId, WorkerResult,
+    },
+};
+
+/// Level-driven cleanup deleting feed bodies nothing points at anymore.
+///
+/// A body stays alive while the current feed snapshot uses it or while a
+/// `CrawlJobFinished` event carrying it is still waiting in the journal, so
+/// collection naturally trails journal compaction. Bodies newer than the
+/// safety margin are left alone regardless.
+pub(crate) struct BlobCollector {
+    config: BlobCollectionConfig,
+}
+
+impl BlobCollector {
+    pub(crate) fn new(config: BlobCollectionConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl<S> Reconciler<S> for BlobCollector
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: BlobDb,
+{
+    fn id(&self) -> WorkerId {
+        WorkerId::BlobCollector
+    }
+
+    fn wake_hints(&self) -> EventInterests {
+        EventInterests::empty()
+    }
+
+    async fn reconcile(&mut self, db: &S, now: DateTime<Utc>) -> WorkerResult<Reaction> {
+        let collection = collect_batch(db, &self.config, now).await?;
+
+        if collection.removed > 0 {
+            info!(
+                removed = collection.removed,
+                has_more = collection.has_more,
+                "unreferenced feed bodies collected"
+            );
+        } else {
+            debug!("no unreferenced feed bodies to collect");
+        }
+
+        let wake = if collection.has_more {
+            WakeRequest::at(now)
+        } else {
+            WakeRequest::None
+        };
+        Ok(Reaction::new(RecordedEvents::empty(), wake))
+    }
+}
+
+/// Deletes every collectable blob in batches and returns the total removed.
+///
+/// Runs the same passes as the background collector, for one-shot cleanup
+/// outside the daemon.
+pub async fn collect_unreferenced_blobs<S>(
+    db: &S,
+    config: &BlobCollectionConfig,
+    now: DateTime<Utc>,
+) -> RegistryDbResult<u64>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: BlobDb,
+{
+    let mut removed = 0;
+    loop {
+        let collection = collect_batch(db, config, now).await?;
+        removed += collection.removed;
+        if !collection.has_more {
+            return Ok(removed);
+        }
+    }
+}
+
+async fn collect_batch<S>(
+    db: &S,
+    config: &BlobCollectionConfig,
+    now: DateTime<Utc>,
+) -> RegistryDbResult<BlobCollection>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: BlobDb,
+{
+    let safety_margin = TimeDelta::from_std(config.safety_margin).unwrap_or(TimeDelta::MAX);
+    let created_before = now
+        .checked_sub_signed(safety_margin)
+        .unwrap_or(DateTime::<Utc>::MIN_UTC);
+
+    let mut tx = db.begin().await?;
+    let collection = tx
+        .delete_unreferenced_blobs(created_before, config.batch_size.max(1))
+        .await?;
+    tx.commit().await?;
+
+    if collection.removed > 0 {
+        metric!(monotonic_counter.registry.blob.collected = collection.removed);
+    }
+    Ok(collection)
+}
@@ -3,7 +3,10 @@ use synd_feed::feed::service::{
     FeedConditionalFetch, FeedFetchOutcome, FeedHttpResponse, FeedHttpStatus,
 };
 
-use crate::crawl::state::{CrawlHttpErrorKind, CrawlStateError, LastCrawlResult};
+use crate::crawl::{
+    history::CrawlOutcome,
+    state::{CrawlHttpErrorKind, CrawlStateError, LastCrawlResult},
+};
 
 /// Pure classification of one fetch outcome into the facts a finished crawl
 /// leaves behind: the last-result summary, the conditional-fetch headers to
@@ -14,6 +17,8 @@ pub(crate) struct CrawlCompletion {
     /// Body bytes of a successfully fetched and parsed feed. Failure bodies
     /// are not kept: they have no reader.
     pub(crate) body: Option<Vec<u8>>,
+    /// Length of whatever response body was read, kept or not.
+    pub(crate) body_bytes: Option<u64>,
     pub(crate) summary: CrawlCompletionSummary,
 }
 
@@ -71,6 +76,16 @@ impl CrawlCompletion {
             | FeedFetchOutcome::FetchFailed(_) => previous_conditional.clone(),
         };
 
+        let body_bytes = match &outcome {
+            FeedFetchOutcome::Fetched(fetched) => Some(fetched.body.bytes.len()),
+            FeedFetchOutcome::UnexpectedStatus(body) => Some(body.bytes.len()),
+            FeedFetchOutcome::ParseFailed(failure) => Some(failure.body.bytes.len()),
+            FeedFetchOutcome::NotModified(_)
+            | FeedFetchOutcome::BodyReadFailed(_)
+            | FeedFetchOutcome::FetchFailed(_) => None,
+        }
+        .map(|len| len as u64);
+
         let body = match outcome {
             FeedFetchOutcome::Fetched(fetched) => Some(fetched.body.bytes),
             _ => None,
@@ -80,6 +95,7 @@ impl CrawlCompletion {
             last,
             conditional,
             body,
+            body_bytes,
             summary,
         }
     }
@@ -88,7 +104,7 @@ impl CrawlCompletion {
 /// Operational summary of one crawl completion, used for logging.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct CrawlCompletionSummary {
-    pub(crate) outcome: CrawlCompletionOutcome,
+    pub(crate) outcome: CrawlOutcome,
     pub(crate) http_status: Option<FeedHttpStatus>,
     pub(crate) error_kind: Option<&'static str>,
 }
@@ -97,22 +113,21 @@ impl CrawlCompletionSummary {
     fn from_outcome(outcome: &FeedFetchOutcome) -> Self {
         let http_status = outcome_response(outcome).map(|http| http.status);
         let (name, error_kind) = match outcome {
-            FeedFetchOutcome::Fetched(_) => (CrawlCompletionOutcome::Fetched, None),
-            FeedFetchOutcome::NotModified(_) => (CrawlCompletionOutcome::NotModified, None),
+            FeedFetchOutcome::Fetched(_) => (CrawlOutcome::Fetched, None),
+            FeedFetchOutcome::NotModified(_) => (CrawlOutcome::NotModified, None),
             FeedFetchOutcome::UnexpectedStatus(body) => (
-                CrawlCompletionOutcome::UnexpectedStatus,
+                CrawlOutcome::UnexpectedStatus,
                 Some(CrawlHttpErrorKind::from_status(body.response.status).as_str()),
             ),
             FeedFetchOutcome::BodyReadFailed(failure) => (
-                CrawlCompletionOutcome::BodyReadFailed,
+                CrawlOutcome::BodyReadFailed,
                 Some(failure.failure.kind.as_str()),
             ),
-            FeedFetchOutcome::FetchFailed(failure) => (
-                CrawlCompletionOutcome::FetchFailed,
-                Some(failure.kind.as_str()),
-            ),
+            FeedFetchOutcome::FetchFailed(failure) => {
+                (CrawlOutcome::FetchFailed, Some(failure.kind.as_str()))
+            }
             FeedFetchOutcome::ParseFailed(failure) => (
-                CrawlCompletionOutcome::ParseFailed,
+                CrawlOutcome::ParseFailed,
                 Some(failure.failure.kind.as_str()),
             ),
         };
@@ -124,30 +139,6 @@ impl CrawlCompletionSummary {
     }
 }
 
-/// Coarse crawl outcome name used for operational logging.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum CrawlCompletionOutcome {
-    Fetched,
-    NotModified,
-    UnexpectedStatus,
-    BodyReadFailed,
-    FetchFailed,
-    ParseFailed,
-}
-
-impl CrawlCompletionOutcome {
-    pub(crate) fn as_str(self) -> &'static str {
-        match self {
-            Self::Fetched => "fetched",
-            Self::NotModified => "not_modified",
-            Self::UnexpectedStatus => "unexpected_status",
-            Self::BodyReadFailed => "body_read_failed",
-            Self::FetchFailed => "fetch_failed",
-            Self::ParseFailed => "parse_failed",
-        }
-    }
-}
-
 fn outcome_response(outcome: &FeedFetchOutcome) -> Option<&FeedHttpResponse> {
     match outcome {
         FeedFetchOutcome::Fetched(fetched) => Some(&fetched.body.response),
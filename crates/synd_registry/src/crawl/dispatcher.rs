@@ -6,6 +6,7 @@ use crate::{
     crawl::{
         dispatch::{DispatchEntry, DispatchQueueWriter, InflightCrawls},
         due::{CrawlDue, CrawlDueDecision},
+        host::{ActiveHostBackoffs, HostBackoffBoard},
     },
     db::{CommitTx, CrawlTargetDb, FeedRegistryDb},
     event::{
@@ -23,20 +24,35 @@ struct DispatchPlan {
     /// Due feeds remain beyond the claimed batch (queue saturated), so the
     /// pass must be retried shortly.
     saturated: bool,
-    /// Earliest future instant a waiting feed becomes due.
+    /// Due feeds held back because their host is backed off.
+    deferred: usize,
+    /// Earliest future instant a waiting or deferred feed becomes due.
     next_due_at: Option<DateTime<Utc>>,
 }
 
 impl DispatchPlan {
-    fn decide(decisions: Vec<CrawlDueDecision>, capacity: usize) -> Self {
+    fn decide(
+        decisions: Vec<CrawlDueDecision>,
+        backoffs: &ActiveHostBackoffs,
+        capacity: usize,
+    ) -> Self {
         let mut dues = Vec::new();
+        let mut deferred = 0;
         let mut next_due_at: Option<DateTime<Utc>> = None;
+        let mut wait_until = |until: DateTime<Utc>| {
+            next_due_at = Some(next_due_at.map_or(until, |next| next.min(until)));
+        };
         for decision in decisions {
             match decision {
-                CrawlDueDecision::Due(due) => dues.push(due),
-                CrawlDueDecision::Wait(at) => {
-                    next_due_at = Some(next_due_at.map_or(at, |next| next.min(at)));
-                }
+                // Manual requests wait too: the host asked every client to back off.
+                CrawlDueDecision::Due(due) => match backoffs.until(&due.feed_url) {
+                    Some(until) => {
+                        deferred += 1;
+                        wait_until(until);
+                    }
+                    None => dues.push(due),
+                },
+                CrawlDueDecision::Wait { until, .. } => wait_until(until),
                 CrawlDueDecision::Dormant => {}
             }
         }
@@ -49,6 +65,7 @@ impl DispatchPlan {
         Self {
             claim: dues,
             saturated,
+            deferred,
             next_due_at,
         }
     }
@@ -74,9 +91,13 @@ impl DispatchPlan {
 /// derives each feed's next crawl, and hands due feeds to the queue. Nothing
 /// about the schedule is persisted, so restarts and missed wakes recover by
 /// re-derivation; the in-process inflight set is the only dispatch memory.
+///
+/// A `429`/`503` answer backs off the whole host, not just the feed that got
+/// it: every due feed on that host waits until the rate-limited feed's retry.
 pub(crate) struct CrawlDispatcher {
     queue: DispatchQueueWriter,
     inflight: InflightCrawls,
+    host_backoffs: HostBackoffBoard,
     config: CrawlDispatchConfig,
 }
 
@@ -84,11 +105,13 @@ impl CrawlDispatcher {
     pub(crate) fn new(
         queue: DispatchQueueWriter,
         inflight: InflightCrawls,
+        host_backoffs: HostBackoffBoard,
         config: CrawlDispatchConfig,
     ) -> Self {
         Self {
             queue,
             inflight,
+            host_backoffs,
             config,
         }
     }
@@ -135,22 +158,27 @@ where
         tx.commit().await?;
 
         // decide: inflight feeds are excluded entirely; their completion
-        // wake triggers the pass that re-evaluates them
+        // wake triggers the pass that re-evaluates them. Host backoffs still
+        // see every input, inflight or not.
+        let backoffs = ActiveHostBackoffs::derive(&inputs, now);
         let decisions = inputs
             .iter()
             .filter(|input| !self.inflight.contains(&input.feed_url))
             .map(|input| input.evaluate(now))
             .collect::<Vec<_>>();
-        let plan = DispatchPlan::decide(decisions, capacity);
+        let plan = DispatchPlan::decide(decisions, &backoffs, capacity);
         let wake = plan.wake_after(now, self.config.saturated_retry_delay);
 
         // converge
         debug!(
             dispatched_count = plan.claim.len(),
             saturated = plan.saturated,
+            backed_off_hosts = backoffs.len(),
+            deferred_count = plan.deferred,
             next_due_at = ?plan.next_due_at,
             "crawl dispatcher converged"
         );
+        self.host_backoffs.publish(backoffs.into_sorted());
         self.push_claimed(plan.claim, now);
 
         Ok(Reaction::new(RecordedEvents::empty(), wake))
@@ -165,7 +193,7 @@ mod tests {
     use synd_feed::types::FeedUrl;
 
     use super::*;
-    use crate::crawl::due::DueReason;
+    use crate::crawl::{due::DueReason, host::HostBackoff, policy::PollingInterval};
 
     fn now() -> DateTime<Utc> {
         Utc.with_ymd_and_hms(2026, 7, 4, 12, 0, 0).unwrap()
@@ -176,9 +204,21 @@ mod tests {
             feed_url: FeedUrl::parse(&format!("https://example.com/{name}.xml")).unwrap(),
             due_at,
             reason,
+            interval: None,
         })
     }
 
+    fn wait(until: DateTime<Utc>) -> CrawlDueDecision {
+        CrawlDueDecision::Wait {
+            until,
+            interval: PollingInterval::try_from(Duration::from_hours(1)).unwrap(),
+        }
+    }
+
+    fn no_backoff() -> ActiveHostBackoffs {
+        ActiveHostBackoffs::default()
+    }
+
     #[test]
     fn plan_orders_by_priority_then_due_time() {
         let plan = DispatchPlan::decide(
@@ -196,6 +236,7 @@ mod tests {
                     now() - chrono::Duration::seconds(10),
                 ),
             ],
+            &no_backoff(),
             4,
         );
 
@@ -224,6 +265,7 @@ mod tests {
                 due("b", DueReason::Periodic, now()),
                 due("c", DueReason::Periodic, now()),
             ],
+            &no_backoff(),
             2,
         );
 
@@ -236,11 +278,8 @@ mod tests {
         let early = now() + chrono::Duration::minutes(10);
         let late = now() + chrono::Duration::hours(1);
         let plan = DispatchPlan::decide(
-            vec![
-                CrawlDueDecision::Wait(late),
-                CrawlDueDecision::Wait(early),
-                CrawlDueDecision::Dormant,
-            ],
+            vec![wait(late), wait(early), CrawlDueDecision::Dormant],
+            &no_backoff(),
             4,
         );
 
@@ -254,7 +293,8 @@ mod tests {
 
     #[test]
     fn wake_prefers_short_retry_when_saturated() {
-        let plan = DispatchPlan::decide(vec![due("a", DueReason::Periodic, now())], 0);
+        let plan =
+            DispatchPlan::decide(vec![due("a", DueReason::Periodic, now())], &no_backoff(), 0);
 
         assert!(plan.saturated);
         assert_eq!(
@@ -262,4 +302,43 @@ mod tests {
             WakeRequest::at(now() + chrono::Duration::seconds(1))
         );
     }
+
+    #[test]
+    fn plan_defers_every_due_feed_on_a_backed_off_host() {
+        let until = now() + chrono::Duration::minutes(30);
+        let backoffs = [HostBackoff {
+            host: "example.com".to_owned(),
+            feed_url: FeedUrl::parse("https://example.com/limited.xml").unwrap(),
+            until,
+        }]
+        .into_iter()
+        .collect::<ActiveHostBackoffs>();
+        let other = CrawlDueDecision::Due(CrawlDue {
+            feed_url: FeedUrl::parse("https://example.org/feed.xml").unwrap(),
+            due_at: now(),
+            reason: DueReason::Periodic,
+            interval: None,
+        });
+        let plan = DispatchPlan::decide(
+            vec![
+                due("periodic", DueReason::Periodic, now()),
+                due("manual", DueReason::Manual, now()),
+                other,
+            ],
+            &backoffs,
+            4,
+        );
+
+        let names = plan
+            .claim
+            .iter()
+            .map(|due| due.feed_url.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["https://example.org/feed.xml"]);
+        assert_eq!(plan.deferred, 2);
+        assert_eq!(
+            plan.wake_after(now(), Duration::from_secs(1)),
+            WakeRequest::at(until)
+        );
+    }
 }
@@ -1,9 +1,13 @@
 use std::{fmt, time::Duration};
 
 use chrono::{DateTime, Utc};
-use synd_feed::types::FeedUrl;
+use synd_feed::types::{FeedUrl, UpdateHints};
 
-use crate::crawl::{job::CrawlJobTrigger, policy::PollingPolicy, state::CrawlState};
+use crate::crawl::{
+    job::CrawlJobTrigger,
+    policy::{PollingInterval, PollingPolicy},
+    state::CrawlState,
+};
 
 /// Base delay applied to the first crawl retry after a failure.
 const RETRY_BACKOFF_BASE: Duration = Duration::from_mins(1);
@@ -11,6 +15,9 @@ const RETRY_BACKOFF_BASE: Duration = Duration::from_mins(1);
 /// Exponent cap keeping the retry backoff below `60s * 2^8` (~4.3h).
 const RETRY_BACKOFF_MAX_EXPONENT: u32 = 8;
 
+/// Most recent entries sampled to estimate a feed's publish cadence.
+pub const PUBLISH_ACTIVITY_SAMPLE_SIZE: u32 = 10;
+
 /// Why a feed's next crawl is due.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DueReason {
@@ -63,14 +70,45 @@ pub struct CrawlDueInput {
     /// Pending manual crawl request, cleared by the crawl that serves it.
     pub manual_requested_at: Option<DateTime<Utc>>,
     pub state: Option<CrawlState>,
+    /// Publish cadence of the feed's known entries; `None` before any entry.
+    pub activity: Option<PublishActivity>,
+    /// Polling hints the publisher declared in the latest accepted body.
+    pub hints: UpdateHints,
+}
+
+/// Order times of a feed's most recent entries, summarized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublishActivity {
+    /// Sampled entries, at most [`PUBLISH_ACTIVITY_SAMPLE_SIZE`].
+    pub sampled: u32,
+    pub oldest: DateTime<Utc>,
+    pub newest: DateTime<Utc>,
+}
+
+impl PublishActivity {
+    /// Mean gap between consecutive sampled entries; `None` below two entries.
+    pub fn mean_gap(&self) -> Option<Duration> {
+        if self.sampled < 2 {
+            return None;
+        }
+        (self.newest - self.oldest)
+            .to_std()
+            .ok()
+            .map(|span| span / (self.sampled - 1))
+    }
 }
 
 /// The scheduler's decision for one feed at one instant.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CrawlDueDecision {
     Due(CrawlDue),
-    /// Not due yet; the instant it becomes due.
-    Wait(DateTime<Utc>),
+    /// Not due yet.
+    Wait {
+        /// The instant it becomes due.
+        until: DateTime<Utc>,
+        /// Polling interval in effect for the feed.
+        interval: PollingInterval,
+    },
     /// Nothing schedules this feed (manual-only policy, no pending request).
     Dormant,
 }
@@ -81,23 +119,28 @@ pub struct CrawlDue {
     pub feed_url: FeedUrl,
     pub due_at: DateTime<Utc>,
     pub reason: DueReason,
+    /// Polling interval in effect for the feed; `None` under a manual policy.
+    pub interval: Option<PollingInterval>,
 }
 
 impl CrawlDueInput {
     /// Pure due evaluation over durable facts:
     /// a pending manual request is due immediately; otherwise the next
-    /// periodic instant follows the last crawl, with failed crawls retried
-    /// on a capped exponential backoff that honors `Retry-After`.
+    /// periodic instant follows the last crawl, moved out of the publisher's
+    /// skipped hours and days, with failed crawls retried on a capped
+    /// exponential backoff that honors `Retry-After`.
     pub fn evaluate(&self, now: DateTime<Utc>) -> CrawlDueDecision {
+        let interval = self.polling_interval();
         if let Some(requested_at) = self.manual_requested_at {
             return CrawlDueDecision::Due(CrawlDue {
                 feed_url: self.feed_url.clone(),
                 due_at: requested_at,
                 reason: DueReason::Manual,
+                interval,
             });
         }
 
-        let PollingPolicy::Interval { interval } = self.polling else {
+        let Some(interval) = interval else {
             return CrawlDueDecision::Dormant;
         };
         let Some(state) = &self.state else {
@@ -106,22 +149,15 @@ impl CrawlDueInput {
                 feed_url: self.feed_url.clone(),
                 due_at: now,
                 reason: DueReason::Periodic,
+                interval: Some(interval),
             });
         };
 
         let (next, reason) = if state.last.is_normal() {
-            (
-                add_duration(state.last.finished_at, interval.duration()),
-                DueReason::Periodic,
-            )
+            let next = add_duration(state.last.finished_at, interval.duration());
+            (self.hints.next_allowed(next), DueReason::Periodic)
         } else {
-            // Retry never waits longer than the regular cadence.
-            let delay = retry_backoff(state.health.failure_streak.value()).min(interval.duration());
-            let mut next = add_duration(state.last.finished_at, delay);
-            if let Some(retry_after) = state.last.retry_after {
-                next = next.max(retry_after);
-            }
-            (next, DueReason::Retry)
+            (retry_at(state, Some(interval)), DueReason::Retry)
         };
 
         if next <= now {
@@ -129,11 +165,85 @@ impl CrawlDueInput {
                 feed_url: self.feed_url.clone(),
                 due_at: next,
                 reason,
+                interval: Some(interval),
             })
         } else {
-            CrawlDueDecision::Wait(next)
+            CrawlDueDecision::Wait {
+                until: next,
+                interval,
+            }
         }
     }
+
+    /// Polling interval in effect; `None` under a manual policy.
+    ///
+    /// The publisher's `<ttl>` and `sy:updatePeriod` act as a lower bound:
+    /// no policy polls more often than the feed asks for.
+    pub fn polling_interval(&self) -> Option<PollingInterval> {
+        let interval = match self.polling {
+            PollingPolicy::Manual => return None,
+            PollingPolicy::Interval { interval } => interval,
+            PollingPolicy::Adaptive { min, max } => {
+                adaptive_interval(min, max, self.activity.as_ref(), self.state.as_ref())
+            }
+        };
+        let hinted = self
+            .hints
+            .min_interval()
+            .and_then(|hinted| PollingInterval::try_from(hinted).ok());
+        Some(hinted.map_or(interval, |hinted| interval.max(hinted)))
+    }
+
+    /// Instant the feed's host asked to be left alone until; `None` unless
+    /// the last crawl was rate limited.
+    pub fn rate_limited_until(&self) -> Option<DateTime<Utc>> {
+        let state = self.state.as_ref()?;
+        state
+            .last
+            .is_rate_limited()
+            .then(|| retry_at(state, self.polling_interval()))
+    }
+}
+
+/// Retry instant after a failed crawl: exponential backoff, never earlier than
+/// `Retry-After`. The backoff never waits longer than the regular cadence.
+fn retry_at(state: &CrawlState, interval: Option<PollingInterval>) -> DateTime<Utc> {
+    let mut delay = retry_backoff(state.health.failure_streak.value());
+    if let Some(interval) = interval {
+        delay = delay.min(interval.duration());
+    }
+    let next = add_duration(state.last.finished_at, delay);
+    state
+        .last
+        .retry_after
+        .map_or(next, |retry_after| next.max(retry_after))
+}
+
+/// Adaptive cadence: two polls per observed publish gap, stretched by a
+/// quarter of the gap for every consecutive `304 Not Modified`, kept within
+/// `[min, max]`.
+///
+/// The observed gap is the mean gap between the most recent entries, or the
+/// quiet period since the newest one when that is longer, so a feed that
+/// stops publishing drifts towards `max`. Without entries `max` applies.
+fn adaptive_interval(
+    min: PollingInterval,
+    max: PollingInterval,
+    activity: Option<&PublishActivity>,
+    state: Option<&CrawlState>,
+) -> PollingInterval {
+    let (min, max) = (min.min(max), max.max(min));
+    let Some(activity) = activity else {
+        return max;
+    };
+    let quiet = state.and_then(|state| (state.last.finished_at - activity.newest).to_std().ok());
+    let Some(gap) = activity.mean_gap().max(quiet) else {
+        return max;
+    };
+
+    let not_modified_streak = state.map_or(0, |state| state.health.not_modified_streak.value());
+    let seconds = (gap.as_secs() / 2).saturating_mul(not_modified_streak.saturating_add(2)) / 2;
+    PollingInterval::clamped_secs(seconds, min, max)
 }
 
 /// Exponential retry backoff derived from the consecutive failure count.
@@ -158,7 +268,10 @@ mod tests {
     use super::*;
     use crate::crawl::{
         policy::PollingInterval,
-        state::{CrawlHealth, CrawlStateError, FailureStreak, LastCrawlResult},
+        state::{
+            CrawlHealth, CrawlHttpErrorKind, CrawlStateError, FailureStreak, LastCrawlResult,
+            NotModifiedStreak,
+        },
     };
 
     fn now() -> DateTime<Utc> {
@@ -169,8 +282,19 @@ mod tests {
         FeedUrl::parse("https://example.com/feed.xml").unwrap()
     }
 
+    fn interval(duration: Duration) -> PollingInterval {
+        PollingInterval::try_from(duration).unwrap()
+    }
+
     fn interval_policy(duration: Duration) -> PollingPolicy {
-        PollingPolicy::interval(PollingInterval::try_from(duration).unwrap())
+        PollingPolicy::interval(interval(duration))
+    }
+
+    fn adaptive_policy() -> PollingPolicy {
+        PollingPolicy::adaptive(
+            interval(Duration::from_mins(10)),
+            interval(Duration::from_hours(24)),
+        )
     }
 
     fn input(
@@ -183,6 +307,24 @@ mod tests {
             polling,
             manual_requested_at,
             state,
+            activity: None,
+            hints: UpdateHints::default(),
+        }
+    }
+
+    fn adaptive_input(state: CrawlState, activity: Option<PublishActivity>) -> CrawlDueInput {
+        CrawlDueInput {
+            activity,
+            ..input(adaptive_policy(), None, Some(state))
+        }
+    }
+
+    /// `sampled` entries evenly spaced by `gap`, the newest at `newest`.
+    fn activity(sampled: u32, gap: chrono::Duration, newest: DateTime<Utc>) -> PublishActivity {
+        PublishActivity {
+            sampled,
+            oldest: newest - gap * (sampled.max(1) - 1).cast_signed(),
+            newest,
         }
     }
 
@@ -207,6 +349,7 @@ mod tests {
             ),
             health: CrawlHealth {
                 failure_streak: FailureStreak::new(failure_streak),
+                not_modified_streak: NotModifiedStreak::zero(),
             },
             conditional: FeedConditionalFetch::default(),
         }
@@ -223,6 +366,7 @@ mod tests {
                 feed_url: feed_url(),
                 due_at: requested_at,
                 reason: DueReason::Manual,
+                interval: None,
             })
         );
     }
@@ -244,6 +388,7 @@ mod tests {
                 feed_url: feed_url(),
                 due_at: now(),
                 reason: DueReason::Periodic,
+                interval: Some(interval(Duration::from_hours(1))),
             })
         );
     }
@@ -260,7 +405,10 @@ mod tests {
 
         assert_eq!(
             decision,
-            CrawlDueDecision::Wait(finished_at + chrono::Duration::hours(1))
+            CrawlDueDecision::Wait {
+                until: finished_at + chrono::Duration::hours(1),
+                interval: interval(Duration::from_hours(1)),
+            }
         );
     }
 
@@ -280,6 +428,7 @@ mod tests {
                 feed_url: feed_url(),
                 due_at: finished_at + chrono::Duration::hours(1),
                 reason: DueReason::Periodic,
+                interval: Some(interval(Duration::from_hours(1))),
             })
         );
     }
@@ -300,6 +449,7 @@ mod tests {
                 feed_url: feed_url(),
                 due_at: now() + chrono::Duration::seconds(240),
                 reason: DueReason::Retry,
+                interval: Some(interval(Duration::from_hours(1))),
             })
         );
     }
@@ -319,6 +469,7 @@ mod tests {
                 feed_url: feed_url(),
                 due_at: now() + chrono::Duration::minutes(2),
                 reason: DueReason::Retry,
+                interval: Some(interval(Duration::from_mins(2))),
             })
         );
     }
@@ -333,7 +484,206 @@ mod tests {
         )
         .evaluate(now());
 
-        assert_eq!(decision, CrawlDueDecision::Wait(retry_after));
+        assert_eq!(
+            decision,
+            CrawlDueDecision::Wait {
+                until: retry_after,
+                interval: interval(Duration::from_hours(1)),
+            }
+        );
+    }
+
+    #[test]
+    fn rate_limited_until_follows_retry_of_rate_limited_crawl() {
+        let retry_after = now() + chrono::Duration::minutes(30);
+        let mut state = failed_state(1, Some(retry_after));
+        state.last.error = Some(CrawlStateError::http(CrawlHttpErrorKind::RateLimited));
+        let rate_limited = input(interval_policy(Duration::from_hours(1)), None, Some(state));
+        let parse_failed = input(
+            interval_policy(Duration::from_hours(1)),
+            None,
+            Some(failed_state(1, Some(retry_after))),
+        );
+
+        assert_eq!(rate_limited.rate_limited_until(), Some(retry_after));
+        assert_eq!(parse_failed.rate_limited_until(), None);
+    }
+
+    #[test]
+    fn adaptive_interval_follows_publish_gap() {
+        // hourly posts, the newest just before the last crawl -> poll every 30m
+        let finished_at = now() - chrono::Duration::minutes(10);
+        let decision = adaptive_input(
+            normal_state(finished_at),
+            Some(activity(
+                10,
+                chrono::Duration::hours(1),
+                finished_at - chrono::Duration::minutes(5),
+            )),
+        )
+        .evaluate(now());
+
+        assert_eq!(
+            decision,
+            CrawlDueDecision::Wait {
+                until: finished_at + chrono::Duration::minutes(30),
+                interval: interval(Duration::from_mins(30)),
+            }
+        );
+    }
+
+    #[test]
+    fn adaptive_interval_stretches_with_quiet_period() {
+        // hourly posts that stopped 10h ago -> the quiet period wins
+        let finished_at = now();
+        let decision = adaptive_input(
+            normal_state(finished_at),
+            Some(activity(
+                10,
+                chrono::Duration::hours(1),
+                finished_at - chrono::Duration::hours(10),
+            )),
+        )
+        .evaluate(now());
+
+        assert_eq!(
+            decision,
+            CrawlDueDecision::Wait {
+                until: finished_at + chrono::Duration::hours(5),
+                interval: interval(Duration::from_hours(5)),
+            }
+        );
+    }
+
+    #[test]
+    fn adaptive_interval_stretches_with_not_modified_streak() {
+        let finished_at = now();
+        let mut state = normal_state(finished_at);
+        state.health = CrawlHealth {
+            failure_streak: FailureStreak::zero(),
+            not_modified_streak: NotModifiedStreak::new(2),
+        };
+        // 1h gap -> 30m base, two 304s in a row -> +2 * 15m
+        let decision = adaptive_input(
+            state,
+            Some(activity(10, chrono::Duration::hours(1), finished_at)),
+        )
+        .evaluate(now());
+
+        assert_eq!(
+            decision,
+            CrawlDueDecision::Wait {
+                until: finished_at + chrono::Duration::hours(1),
+                interval: interval(Duration::from_hours(1)),
+            }
+        );
+    }
+
+    #[test]
+    fn adaptive_interval_is_clamped_to_bounds() {
+        let finished_at = now();
+        let busy = adaptive_input(
+            normal_state(finished_at),
+            Some(activity(10, chrono::Duration::minutes(1), finished_at)),
+        );
+        let weekly = adaptive_input(
+            normal_state(finished_at),
+            Some(activity(10, chrono::Duration::weeks(1), finished_at)),
+        );
+
+        assert_eq!(
+            busy.polling_interval(),
+            Some(interval(Duration::from_mins(10)))
+        );
+        assert_eq!(
+            weekly.polling_interval(),
+            Some(interval(Duration::from_hours(24)))
+        );
+    }
+
+    #[test]
+    fn adaptive_interval_without_entries_uses_max() {
+        let input = adaptive_input(normal_state(now()), None);
+
+        assert_eq!(
+            input.polling_interval(),
+            Some(interval(Duration::from_hours(24)))
+        );
+    }
+
+    #[test]
+    fn publisher_ttl_is_a_lower_bound_on_the_interval() {
+        let finished_at = now() - chrono::Duration::minutes(30);
+        let decision = CrawlDueInput {
+            hints: UpdateHints::builder().ttl_minutes(180).build(),
+            ..input(
+                interval_policy(Duration::from_hours(1)),
+                None,
+                Some(normal_state(finished_at)),
+            )
+        }
+        .evaluate(now());
+
+        assert_eq!(
+            decision,
+            CrawlDueDecision::Wait {
+                until: finished_at + chrono::Duration::hours(3),
+                interval: interval(Duration::from_hours(3)),
+            }
+        );
+    }
+
+    #[test]
+    fn publisher_ttl_does_not_shorten_a_longer_interval() {
+        let input = CrawlDueInput {
+            hints: UpdateHints::builder().ttl_minutes(10).build(),
+            ..input(interval_policy(Duration::from_hours(1)), None, None)
+        };
+
+        assert_eq!(
+            input.polling_interval(),
+            Some(interval(Duration::from_hours(1)))
+        );
+    }
+
+    #[test]
+    fn periodic_crawl_moves_out_of_skipped_hours() {
+        // last crawl 11:00, hourly -> 12:00, skipped until 14:00
+        let finished_at = now() - chrono::Duration::hours(1);
+        let decision = CrawlDueInput {
+            hints: UpdateHints::builder().skip_hours(vec![12, 13]).build(),
+            ..input(
+                interval_policy(Duration::from_hours(1)),
+                None,
+                Some(normal_state(finished_at)),
+            )
+        }
+        .evaluate(now());
+
+        assert_eq!(
+            decision,
+            CrawlDueDecision::Wait {
+                until: now() + chrono::Duration::hours(2),
+                interval: interval(Duration::from_hours(1)),
+            }
+        );
+    }
+
+    #[test]
+    fn manual_request_ignores_skipped_hours() {
+        let decision = CrawlDueInput {
+            hints: UpdateHints::builder().skip_hours((0..23).collect()).build(),
+            ..input(interval_policy(Duration::from_hours(1)), Some(now()), None)
+        }
+        .evaluate(now());
+
+        assert!(matches!(
+            decision,
+            CrawlDueDecision::Due(CrawlDue {
+                reason: DueReason::Manual,
+                ..
+            })
+        ));
     }
 
     #[test]
@@ -0,0 +1,105 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use synd_feed::{feed::service::FeedHttpStatus, types::FeedUrl};
+
+use crate::crawl::{
+    blob::BlobRef,
+    job::{CrawlJobId, CrawlJobTrigger},
+    state::CrawlStateErrorKind,
+};
+
+/// Coarse result of one finished crawl.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrawlOutcome {
+    Fetched,
+    NotModified,
+    UnexpectedStatus,
+    BodyReadFailed,
+    FetchFailed,
+    ParseFailed,
+}
+
+impl CrawlOutcome {
+    /// Whether the crawl failed to bring the feed up to date.
+    pub fn is_failure(self) -> bool {
+        !matches!(self, Self::Fetched | Self::NotModified)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Fetched => "fetched",
+            Self::NotModified => "not_modified",
+            Self::UnexpectedStatus => "unexpected_status",
+            Self::BodyReadFailed => "body_read_failed",
+            Self::FetchFailed => "fetch_failed",
+            Self::ParseFailed => "parse_failed",
+        }
+    }
+}
+
+impl fmt::Display for CrawlOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One finished crawl as kept in the per-feed crawl history.
+///
+/// Unlike `CrawlState`, which keeps only the latest result, history rows are
+/// appended per job so failure streaks and 304 rates can be traced back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrawlHistoryEntry {
+    pub job_id: CrawlJobId,
+    pub trigger: CrawlJobTrigger,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub outcome: CrawlOutcome,
+    pub http_status: Option<FeedHttpStatus>,
+    pub error_kind: Option<CrawlStateErrorKind>,
+    /// Length of the response body when one was read.
+    pub body_bytes: Option<u64>,
+    /// The accepted body differs from the one accepted by the previous
+    /// crawl that kept a body. Always false when no body was accepted.
+    pub body_changed: bool,
+}
+
+impl CrawlHistoryEntry {
+    pub fn duration_ms(&self) -> i64 {
+        (self.finished_at - self.started_at).num_milliseconds()
+    }
+}
+
+/// Command appending one finished crawl to a feed's history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppendCrawlHistoryCommand {
+    pub feed_url: FeedUrl,
+    pub entry: CrawlHistoryEntry,
+    /// Accepted body of the crawl, kept to detect changes on the next one.
+    pub body_blob: Option<BlobRef>,
+    /// Number of most recent entries kept for the feed after appending.
+    pub retain: usize,
+}
+
+impl AppendCrawlHistoryCommand {
+    pub fn new(
+        feed_url: FeedUrl,
+        entry: CrawlHistoryEntry,
+        body_blob: Option<BlobRef>,
+        retain: usize,
+    ) -> Self {
+        Self {
+            feed_url,
+            entry,
+            body_blob,
+            retain,
+        }
+    }
+}
+
+/// Query for the most recent crawls of one feed, newest first.
+#[derive(Debug, Clone)]
+pub struct CrawlHistoryQuery {
+    pub feed_url: FeedUrl,
+    pub limit: usize,
+}
@@ -0,0 +1,294 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use chrono::{DateTime, Utc};
+use synd_feed::types::FeedUrl;
+
+use crate::crawl::due::CrawlDueInput;
+
+/// Backoff a host asked for with `429`/`503`, holding back every feed it serves.
+///
+/// Keyed by the full host, the scope a `Retry-After` applies to and the key
+/// of the per-host crawl capacity: a rate limit from `api.github.com` leaves
+/// `github.com` alone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostBackoff {
+    /// Rate-limited host.
+    pub host: String,
+    /// Feed whose crawl was answered with the rate limit.
+    pub feed_url: FeedUrl,
+    /// No feed on the host is dispatched before this instant.
+    pub until: DateTime<Utc>,
+}
+
+/// Host backoffs in effect for one scheduler pass, keyed by host.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ActiveHostBackoffs {
+    by_host: HashMap<String, HostBackoff>,
+}
+
+impl ActiveHostBackoffs {
+    /// Pure derivation over the pass's due inputs: the most recently finished
+    /// crawl on each host speaks for the host, so a rate-limited crawl backs
+    /// the host off until its retry instant, and a later answer from any other
+    /// feed on the same host lifts the backoff.
+    pub(crate) fn derive<'a>(
+        inputs: impl IntoIterator<Item = &'a CrawlDueInput>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let mut latest: HashMap<&str, &CrawlDueInput> = HashMap::new();
+        for input in inputs {
+            let (Some(host), Some(state)) = (input.feed_url.host(), &input.state) else {
+                continue;
+            };
+            latest
+                .entry(host)
+                .and_modify(|current| {
+                    if current
+                        .state
+                        .as_ref()
+                        .is_none_or(|current| current.last.finished_at < state.last.finished_at)
+                    {
+                        *current = input;
+                    }
+                })
+                .or_insert(input);
+        }
+
+        latest
+            .into_iter()
+            .filter_map(|(host, input)| {
+                let until = input.rate_limited_until().filter(|until| *until > now)?;
+                Some(HostBackoff {
+                    host: host.to_owned(),
+                    feed_url: input.feed_url.clone(),
+                    until,
+                })
+            })
+            .collect()
+    }
+
+    /// Instant the feed's host is backed off until, if it is.
+    pub(crate) fn until(&self, feed_url: &FeedUrl) -> Option<DateTime<Utc>> {
+        feed_url
+            .host()
+            .and_then(|host| self.by_host.get(host))
+            .map(|backoff| backoff.until)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.by_host.len()
+    }
+
+    /// Backoffs ordered by host.
+    pub(crate) fn into_sorted(self) -> Vec<HostBackoff> {
+        let mut backoffs = self.by_host.into_values().collect::<Vec<_>>();
+        backoffs.sort_by(|a, b| a.host.cmp(&b.host));
+        backoffs
+    }
+}
+
+impl FromIterator<HostBackoff> for ActiveHostBackoffs {
+    fn from_iter<I: IntoIterator<Item = HostBackoff>>(iter: I) -> Self {
+        Self {
+            by_host: iter
+                .into_iter()
+                .map(|backoff| (backoff.host.clone(), backoff))
+                .collect(),
+        }
+    }
+}
+
+/// Latest host backoffs applied by the dispatcher, shared for status reporting.
+///
+/// Not persisted: the dispatcher republishes it on every pass from the
+/// backoffs it re-derives out of durable crawl state.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HostBackoffBoard {
+    inner: Arc<Mutex<Vec<HostBackoff>>>,
+}
+
+impl HostBackoffBoard {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn publish(&self, backoffs: Vec<HostBackoff>) {
+        *self
+            .inner
+            .lock()
+            .expect("host backoff lock is never poisoned") = backoffs;
+    }
+
+    pub(crate) fn snapshot(&self) -> Vec<HostBackoff> {
+        self.inner
+            .lock()
+            .expect("host backoff lock is never poisoned")
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::TimeZone;
+    use synd_feed::{feed::service::FeedConditionalFetch, types::UpdateHints};
+
+    use super::*;
+    use crate::crawl::{
+        policy::{PollingInterval, PollingPolicy},
+        state::{CrawlHealth, CrawlHttpErrorKind, CrawlState, CrawlStateError, LastCrawlResult},
+    };
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 7, 4, 12, 0, 0).unwrap()
+    }
+
+    fn feed_url(url: &str) -> FeedUrl {
+        FeedUrl::parse(url).unwrap()
+    }
+
+    fn input(url: &str, last: LastCrawlResult) -> CrawlDueInput {
+        let health = CrawlHealth::for_last_result(&last, None);
+        CrawlDueInput {
+            feed_url: feed_url(url),
+            polling: PollingPolicy::interval(
+                PollingInterval::try_from(Duration::from_hours(1)).unwrap(),
+            ),
+            manual_requested_at: None,
+            state: Some(CrawlState {
+                feed_url: feed_url(url),
+                last,
+                health,
+                conditional: FeedConditionalFetch::default(),
+            }),
+            activity: None,
+            hints: UpdateHints::default(),
+        }
+    }
+
+    fn normal(finished_at: DateTime<Utc>) -> LastCrawlResult {
+        LastCrawlResult::normal(finished_at, finished_at, None, None)
+    }
+
+    fn rate_limited(finished_at: DateTime<Utc>, retry_after: DateTime<Utc>) -> LastCrawlResult {
+        LastCrawlResult::abnormal(
+            finished_at,
+            finished_at,
+            None,
+            CrawlStateError::http(CrawlHttpErrorKind::RateLimited),
+            Some(retry_after),
+        )
+    }
+
+    #[test]
+    fn rate_limited_crawl_backs_off_every_feed_on_the_host() {
+        let retry_after = now() + chrono::Duration::minutes(30);
+        let backoffs = ActiveHostBackoffs::derive(
+            &[
+                input(
+                    "https://example.com/a.xml",
+                    normal(now() - chrono::Duration::hours(1)),
+                ),
+                input(
+                    "https://example.com/b.xml",
+                    rate_limited(now() - chrono::Duration::minutes(1), retry_after),
+                ),
+                input("https://other.example.org/feed.xml", normal(now())),
+            ],
+            now(),
+        );
+
+        assert_eq!(
+            backoffs.until(&feed_url("https://example.com/a.xml")),
+            Some(retry_after)
+        );
+        assert_eq!(
+            backoffs.until(&feed_url("https://other.example.org/feed.xml")),
+            None
+        );
+        assert_eq!(
+            backoffs.into_sorted(),
+            [HostBackoff {
+                host: "example.com".to_owned(),
+                feed_url: feed_url("https://example.com/b.xml"),
+                until: retry_after,
+            }]
+        );
+    }
+
+    #[test]
+    fn rate_limited_subdomain_leaves_its_parent_domain_alone() {
+        let retry_after = now() + chrono::Duration::minutes(30);
+        let backoffs = ActiveHostBackoffs::derive(
+            &[
+                input(
+                    "https://github.com/rust-lang/rust/releases.atom",
+                    normal(now() - chrono::Duration::hours(1)),
+                ),
+                input(
+                    "https://api.github.com/repos/rust-lang/rust/events",
+                    rate_limited(now() - chrono::Duration::minutes(1), retry_after),
+                ),
+            ],
+            now(),
+        );
+
+        assert_eq!(
+            backoffs.until(&feed_url("https://github.com/tokio-rs/tokio/releases.atom")),
+            None
+        );
+        assert_eq!(
+            backoffs.until(&feed_url(
+                "https://api.github.com/repos/tokio-rs/tokio/events"
+            )),
+            Some(retry_after)
+        );
+        assert_eq!(
+            backoffs.into_sorted(),
+            [HostBackoff {
+                host: "api.github.com".to_owned(),
+                feed_url: feed_url("https://api.github.com/repos/rust-lang/rust/events"),
+                until: retry_after,
+            }]
+        );
+    }
+
+    #[test]
+    fn later_answer_from_the_host_lifts_the_backoff() {
+        let backoffs = ActiveHostBackoffs::derive(
+            &[
+                input(
+                    "https://example.com/a.xml",
+                    rate_limited(
+                        now() - chrono::Duration::minutes(5),
+                        now() + chrono::Duration::minutes(30),
+                    ),
+                ),
+                input("https://example.com/b.xml", normal(now())),
+            ],
+            now(),
+        );
+
+        assert_eq!(backoffs.len(), 0);
+    }
+
+    #[test]
+    fn expired_backoff_is_not_applied() {
+        let backoffs = ActiveHostBackoffs::derive(
+            &[input(
+                "https://example.com/a.xml",
+                rate_limited(
+                    now() - chrono::Duration::hours(2),
+                    now() - chrono::Duration::minutes(1),
+                ),
+            )],
+            now(),
+        );
+
+        assert_eq!(backoffs.len(), 0);
+    }
+}
@@ -1,8 +1,11 @@
 pub mod blob;
+pub mod collector;
 pub(crate) mod completion;
 pub(crate) mod dispatch;
 pub(crate) mod dispatcher;
 pub mod due;
+pub mod history;
+pub mod host;
 pub mod job;
 pub mod policy;
 pub mod request;
@@ -1,6 +1,5 @@
 use std::{fmt, num::NonZeroU64, time::Duration};
 
-use chrono::{DateTime, Utc};
 use serde::{Deserialize, Deserializer, Serialize};
 
 /// Error returned when a polling interval cannot be represented.
@@ -32,6 +31,20 @@ impl PollingInterval {
     pub fn min(self, other: Self) -> Self {
         Self(self.0.min(other.0))
     }
+
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        Self(self.0.max(other.0))
+    }
+
+    /// Whole-second interval within `[min, max]`.
+    pub(crate) fn clamped_secs(seconds: u64, min: Self, max: Self) -> Self {
+        Self(
+            NonZeroU64::new(seconds)
+                .unwrap_or(min.0)
+                .clamp(min.0, max.0),
+        )
+    }
 }
 
 impl TryFrom<Duration> for PollingInterval {
@@ -69,6 +82,13 @@ pub enum PollingPolicy {
         #[serde(rename = "interval_seconds")]
         interval: PollingInterval,
     },
+    /// Polls as often as the feed publishes, within `[min, max]`.
+    Adaptive {
+        #[serde(rename = "min_seconds")]
+        min: PollingInterval,
+        #[serde(rename = "max_seconds")]
+        max: PollingInterval,
+    },
 }
 
 impl PollingPolicy {
@@ -80,10 +100,11 @@ impl PollingPolicy {
         Self::Interval { interval }
     }
 
-    pub fn next_after(self, polled_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
-        match self {
-            Self::Manual => None,
-            Self::Interval { interval } => Some(add_duration(polled_at, interval)),
+    /// Adaptive policy over the given bounds, swapped if given in reverse.
+    pub fn adaptive(min: PollingInterval, max: PollingInterval) -> Self {
+        Self::Adaptive {
+            min: min.min(max),
+            max: max.max(min),
         }
     }
 }
@@ -93,6 +114,9 @@ impl fmt::Display for PollingPolicy {
         match self {
             Self::Manual => f.write_str("manual"),
             Self::Interval { interval } => write!(f, "interval:{}s", interval.as_secs()),
+            Self::Adaptive { min, max } => {
+                write!(f, "adaptive:{}s-{}s", min.as_secs(), max.as_secs())
+            }
         }
     }
 }
@@ -115,10 +139,12 @@ impl CrawlPolicy {
             polling: PollingPolicy::manual(),
         }
     }
-}
 
-fn add_duration(time: DateTime<Utc>, interval: PollingInterval) -> DateTime<Utc> {
-    chrono::Duration::from_std(interval.duration()).map_or(time, |duration| time + duration)
+    pub fn adaptive(min: PollingInterval, max: PollingInterval) -> Self {
+        Self {
+            polling: PollingPolicy::adaptive(min, max),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -148,31 +174,44 @@ mod tests {
     }
 
     #[test]
-    fn interval_policy_computes_next_poll_time() {
-        let polled_at = Utc::now();
-        let policy = PollingPolicy::interval(interval(Duration::from_mins(1)));
+    fn adaptive_policy_orders_bounds() {
+        let policy = PollingPolicy::adaptive(
+            interval(Duration::from_hours(6)),
+            interval(Duration::from_mins(5)),
+        );
 
         assert_eq!(
-            policy.next_after(polled_at),
-            Some(polled_at + chrono::Duration::seconds(60))
+            policy,
+            PollingPolicy::Adaptive {
+                min: interval(Duration::from_mins(5)),
+                max: interval(Duration::from_hours(6)),
+            }
         );
     }
 
     #[test]
-    fn manual_policy_has_no_next_poll_time() {
-        let policy = PollingPolicy::manual();
+    fn crawl_policy_serializes_polling_policy() {
+        let policy = CrawlPolicy::interval(interval(Duration::from_hours(1)));
+        let json = serde_json::to_string(&policy).unwrap();
 
-        assert_eq!(policy.next_after(Utc::now()), None);
+        assert_eq!(
+            json,
+            r#"{"polling":{"kind":"interval","interval_seconds":3600}}"#
+        );
+        assert_eq!(serde_json::from_str::<CrawlPolicy>(&json).unwrap(), policy);
     }
 
     #[test]
-    fn crawl_policy_serializes_polling_policy() {
-        let policy = CrawlPolicy::interval(interval(Duration::from_hours(1)));
+    fn adaptive_policy_serializes_bounds_as_seconds() {
+        let policy = CrawlPolicy::adaptive(
+            interval(Duration::from_mins(5)),
+            interval(Duration::from_hours(6)),
+        );
         let json = serde_json::to_string(&policy).unwrap();
 
         assert_eq!(
             json,
-            r#"{"polling":{"kind":"interval","interval_seconds":3600}}"#
+            r#"{"polling":{"kind":"adaptive","min_seconds":300,"max_seconds":21600}}"#
         );
         assert_eq!(serde_json::from_str::<CrawlPolicy>(&json).unwrap(), policy);
     }
@@ -132,6 +132,7 @@ where
 #[cfg(test)]
 mod tests {
     use chrono::{DateTime, TimeZone, Utc};
+    use synd_feed::types::UpdateHints;
 
     use super::*;
     use crate::crawl::policy::PollingPolicy;
@@ -150,6 +151,8 @@ mod tests {
             polling: PollingPolicy::manual(),
             manual_requested_at,
             state: None,
+            activity: None,
+            hints: UpdateHints::default(),
         }
     }
 
@@ -8,6 +8,8 @@ use synd_feed::{
     types::FeedUrl,
 };
 
+const NOT_MODIFIED: u16 = 304;
+
 /// Observation: what crawling has learned about one feed — the summary of
 /// the last crawl plus the conditional-fetch context for the next one.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -63,17 +65,41 @@ impl LastCrawlResult {
     pub fn is_normal(&self) -> bool {
         self.error.is_none()
     }
+
+    /// The server answered the conditional fetch with `304 Not Modified`.
+    pub fn is_not_modified(&self) -> bool {
+        self.is_normal()
+            && self
+                .http_status
+                .is_some_and(|status| status.as_u16() == NOT_MODIFIED)
+    }
+
+    /// The server asked to back off with `429 Too Many Requests` or
+    /// `503 Service Unavailable`.
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(
+            self.error,
+            Some(CrawlStateError {
+                kind: CrawlStateErrorKind::Http(
+                    CrawlHttpErrorKind::RateLimited | CrawlHttpErrorKind::Unavailable
+                ),
+            })
+        )
+    }
 }
 
 /// Current crawl health facts derived from recent crawl results.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CrawlHealth {
     pub failure_streak: FailureStreak,
+    pub not_modified_streak: NotModifiedStreak,
 }
 
 impl CrawlHealth {
     pub fn for_last_result(last: &LastCrawlResult, previous: Option<&CrawlState>) -> Self {
-        if last.is_normal() {
+        if last.is_not_modified() {
+            Self::not_modified(previous)
+        } else if last.is_normal() {
             Self::healthy()
         } else {
             Self::failed(previous)
@@ -83,6 +109,7 @@ impl CrawlHealth {
     pub fn healthy() -> Self {
         Self {
             failure_streak: FailureStreak::zero(),
+            not_modified_streak: NotModifiedStreak::zero(),
         }
     }
 
@@ -90,6 +117,15 @@ impl CrawlHealth {
         let previous = previous.map_or(0, |state| state.health.failure_streak.value());
         Self {
             failure_streak: FailureStreak::new(previous.saturating_add(1)),
+            not_modified_streak: NotModifiedStreak::zero(),
+        }
+    }
+
+    pub fn not_modified(previous: Option<&CrawlState>) -> Self {
+        let previous = previous.map_or(0, |state| state.health.not_modified_streak.value());
+        Self {
+            failure_streak: FailureStreak::zero(),
+            not_modified_streak: NotModifiedStreak::new(previous.saturating_add(1)),
         }
     }
 }
@@ -112,6 +148,24 @@ impl FailureStreak {
     }
 }
 
+/// Consecutive crawl-result count answered with `304 Not Modified`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotModifiedStreak(u64);
+
+impl NotModifiedStreak {
+    pub fn zero() -> Self {
+        Self(0)
+    }
+
+    pub fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    pub fn value(self) -> u64 {
+        self.0
+    }
+}
+
 /// Error fact projected into current crawl state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CrawlStateError {
@@ -140,19 +140,29 @@ impl FeedSubscriptions {
 
     /// The most demanding polling policy across all subscriptions: the
     /// shortest interval wins; manual applies only when every subscription is
-    /// manual.
+    /// manual. A fixed interval caps adaptive bounds, so once any
+    /// subscription is adaptive the result is adaptive with the tightest
+    /// `min` and `max` of all polling subscriptions.
     fn effective_policy(&self) -> CrawlPolicy {
-        let interval = self
-            .subscriptions
-            .iter()
-            .filter_map(|subscription| match subscription.crawl_policy.polling {
-                PollingPolicy::Manual => None,
-                PollingPolicy::Interval { interval } => Some(interval),
-            })
-            .reduce(PollingInterval::min);
-
-        match interval {
-            Some(interval) => CrawlPolicy::interval(interval),
+        let mut bounds: Option<(PollingInterval, PollingInterval)> = None;
+        let mut adaptive = false;
+        for subscription in &self.subscriptions {
+            let (min, max) = match subscription.crawl_policy.polling {
+                PollingPolicy::Manual => continue,
+                PollingPolicy::Interval { interval } => (interval, interval),
+                PollingPolicy::Adaptive { min, max } => {
+                    adaptive = true;
+                    (min, max)
+                }
+            };
+            bounds = Some(bounds.map_or((min, max), |(current_min, current_max)| {
+                (current_min.min(min), current_max.min(max))
+            }));
+        }
+
+        match bounds {
+            Some((min, max)) if adaptive => CrawlPolicy::adaptive(min.min(max), max),
+            Some((_, interval)) => CrawlPolicy::interval(interval),
             None => CrawlPolicy::manual(),
         }
     }
@@ -306,6 +316,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn crawl_target_decision_caps_adaptive_bounds_by_fixed_interval() {
+        let subscriptions = subscription_set(vec![
+            subscription(
+                "adaptive",
+                CrawlPolicy::adaptive(
+                    interval(Duration::from_mins(5)),
+                    interval(Duration::from_hours(24)),
+                ),
+            ),
+            subscription(
+                "two-hours",
+                CrawlPolicy::interval(interval(Duration::from_hours(2))),
+            ),
+        ]);
+
+        let target = subscriptions.crawl_target_decision();
+
+        assert_eq!(
+            target.state,
+            CrawlTargetState::Active {
+                effective_policy: CrawlPolicy::adaptive(
+                    interval(Duration::from_mins(5)),
+                    interval(Duration::from_hours(2)),
+                ),
+            }
+        );
+    }
+
     #[test]
     fn crawl_target_decision_is_manual_when_all_subscriptions_are_manual() {
         let subscriptions = subscription_set(vec![subscription("manual", CrawlPolicy::manual())]);
@@ -1,9 +1,19 @@
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use chrono::{DateTime, Utc};
-use synd_feed::feed::service::{FeedFetchOutcome, FeedFetchRequest, FeedHttpStatus, FetchFeed};
+use synd_feed::{
+    feed::service::{FeedFetchOutcome, FeedFetchRequest, FeedHttpStatus, FeedService, FetchFeed},
+    types::FeedUrl,
+};
 use synd_support::time::Clock;
-use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::{
+    sync::{Notify, OwnedSemaphorePermit, Semaphore},
+    time::Instant,
+};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info};
 
@@ -12,10 +22,11 @@ use crate::{
         blob::PutBlobCommand,
         completion::{CrawlCompletion, CrawlCompletionSummary},
         dispatch::{DispatchEntry, DispatchQueueReader},
+        history::{AppendCrawlHistoryCommand, CrawlHistoryEntry},
         job::{CrawlJob, CrawlJobId, CrawlJobQueueLane, CrawlJobTrigger},
         state::{CrawlHealth, CrawlState, UpsertCrawlStateCommand},
     },
-    db::{BlobDb, CommitTx, CrawlStateDb, CrawlTargetDb, FeedRegistryDb},
+    db::{BlobDb, CommitTx, CrawlHistoryDb, CrawlStateDb, CrawlTargetDb, FeedRegistryDb},
     event::{
         CrawlJobFinishedEvent, EventJournal, EventJournalAppend, EventRecorder, EventWakePublisher,
         RecordedEvents, WorkerHandle, WorkerId, WorkerResult,
@@ -29,7 +40,10 @@ pub struct CrawlWorkerPoolConfig {
     pub manual_queue: CrawlWorkerQueueConfig,
     pub default_queue: CrawlWorkerQueueConfig,
     pub retry_queue: CrawlWorkerQueueConfig,
+    pub host: CrawlWorkerHostConfig,
     pub fetch: CrawlWorkerFetchConfig,
+    /// Most recent crawl history entries kept per feed.
+    pub history_retention: usize,
 }
 
 impl Default for CrawlWorkerPoolConfig {
@@ -45,7 +59,9 @@ impl Default for CrawlWorkerPoolConfig {
             retry_queue: CrawlWorkerQueueConfig {
                 max_running_jobs: 1,
             },
+            host: CrawlWorkerHostConfig::default(),
             fetch: CrawlWorkerFetchConfig::default(),
+            history_retention: 100,
         }
     }
 }
@@ -56,11 +72,47 @@ pub struct CrawlWorkerQueueConfig {
     pub max_running_jobs: usize,
 }
 
+/// Per-host crawl limits, keyed by the feed URL's full host.
+///
+/// Hosts are not grouped by registrable domain: telling `api.github.com`
+/// apart from `alice.github.io` needs the Public Suffix List, and a guess
+/// would put every site under a shared suffix like `github.io` or
+/// `blogspot.com` behind one budget.
+#[derive(Debug, Clone, Copy)]
+pub struct CrawlWorkerHostConfig {
+    pub max_running_jobs: usize,
+    /// Minimum delay between the starts of two crawls against the same host.
+    pub min_request_spacing: Option<Duration>,
+}
+
+impl Default for CrawlWorkerHostConfig {
+    fn default() -> Self {
+        Self {
+            max_running_jobs: 2,
+            min_request_spacing: None,
+        }
+    }
+}
+
 /// HTTP fetch configuration used by crawl workers.
 #[derive(Debug, Clone, Copy)]
 pub struct CrawlWorkerFetchConfig {
     pub user_agent: &'static str,
     pub max_body_bytes: usize,
+    /// Refuses to fetch from loopback, private, and link-local addresses.
+    /// Multi-user daemons set this so subscribers cannot reach the network
+    /// the daemon runs in.
+    pub public_only: bool,
+}
+
+impl CrawlWorkerFetchConfig {
+    pub(crate) fn feed_service(&self) -> FeedService {
+        if self.public_only {
+            FeedService::public_only(self.user_agent, self.max_body_bytes)
+        } else {
+            FeedService::new(self.user_agent, self.max_body_bytes)
+        }
+    }
 }
 
 impl Default for CrawlWorkerFetchConfig {
@@ -68,6 +120,7 @@ impl Default for CrawlWorkerFetchConfig {
         Self {
             user_agent: "syndicationd",
             max_body_bytes: 10 * 1024 * 1024,
+            public_only: false,
         }
     }
 }
@@ -75,18 +128,25 @@ impl Default for CrawlWorkerFetchConfig {
 /// Runs crawl dispatch entries handed over through the dispatch queue.
 ///
 /// The pool is queue-driven: it awaits the next dispatched entry, waits for
-/// global and lane capacity, then runs the crawl on its own task. The
+/// global, lane, and host capacity, then runs the crawl on its own task. The
 /// dispatch queue is its only input; it does not consume registry events.
+///
+/// An entry whose host is at its cap or inside its request spacing is parked
+/// in a per-host wait queue until the host frees up, so one busy host does
+/// not hold back the entries of other hosts queued behind it. Once
+/// [`MAX_PARKED_ENTRIES`] are parked, the pool stops taking entries off the
+/// dispatch queue until a host frees up.
 pub(crate) struct CrawlWorkerPool<S, F> {
-    db: S,
-    fetcher: F,
-    wake_publisher: EventWakePublisher,
+    launcher: CrawlWorkerLauncher<S, F>,
     dispatch_queue: DispatchQueueReader,
     ct: CancellationToken,
-    capacity: CrawlWorkerCapacity,
-    clock: Arc<dyn Clock>,
+    capacity: Arc<CrawlWorkerCapacity>,
+    parked: ParkedEntries,
 }
 
+/// Most entries the pool holds back for busy hosts.
+const MAX_PARKED_ENTRIES: usize = 256;
+
 impl<S, F> CrawlWorkerPool<S, F> {
     pub(crate) fn new(
         db: S,
@@ -98,23 +158,42 @@ impl<S, F> CrawlWorkerPool<S, F> {
         clock: Arc<dyn Clock>,
     ) -> Self {
         Self {
-            db,
-            fetcher,
-            wake_publisher,
+            launcher: CrawlWorkerLauncher {
+                db,
+                fetcher,
+                wake_publisher,
+                ct: ct.clone(),
+                history_retention: config.history_retention,
+                clock,
+            },
             dispatch_queue,
             ct,
-            capacity: CrawlWorkerCapacity::new(config),
-            clock,
+            capacity: Arc::new(CrawlWorkerCapacity::new(
+                config,
+                Arc::new(CrawlHostCapacity::new(config.host)),
+            )),
+            parked: ParkedEntries::default(),
         }
     }
+
+    /// Per-host capacity of the pool, for other fetches against the same
+    /// hosts to share.
+    pub(crate) fn hosts(&self) -> Arc<CrawlHostCapacity> {
+        Arc::clone(&self.capacity.hosts)
+    }
 }
 
 impl<S, F> CrawlWorkerPool<S, F>
 where
     S: FeedRegistryDb,
     F: FetchFeed + Clone + Send + Sync + 'static,
-    for<'tx> S::Tx<'tx>:
-        BlobDb + CrawlStateDb + CrawlTargetDb + EventJournalAppend + EventJournal + Send,
+    for<'tx> S::Tx<'tx>: BlobDb
+        + CrawlHistoryDb
+        + CrawlStateDb
+        + CrawlTargetDb
+        + EventJournalAppend
+        + EventJournal
+        + Send,
 {
     pub(crate) fn spawn(self) -> WorkerHandle {
         WorkerHandle::new(WorkerId::CrawlWorkerPool, tokio::spawn(self.run()))
@@ -127,19 +206,26 @@ where
         );
 
         loop {
+            let spacing_ends = self.parked.spacing_ends(&self.capacity.hosts);
             let entry = tokio::select! {
                 () = self.ct.cancelled() => break,
-                entry = self.dispatch_queue.recv() => match entry {
-                    Some(entry) => entry,
-                    None => break,
-                },
-            };
-            let lane = entry.trigger.queue_lane();
-            let slot = tokio::select! {
-                () = self.ct.cancelled() => break,
-                slot = self.capacity.reserve(lane) => slot,
+                entry = self.dispatch_queue.recv(), if self.parked.len() < MAX_PARKED_ENTRIES => {
+                    match entry {
+                        Some(entry) => Some(entry),
+                        None => break,
+                    }
+                }
+                () = self.capacity.hosts.released(), if !self.parked.is_empty() => None,
+                () = sleep_until_some(spacing_ends) => None,
             };
-            self.start_worker(entry, slot);
+            if let Some(entry) = entry
+                && !self.dispatch(entry).await
+            {
+                break;
+            }
+            if !self.start_parked().await {
+                break;
+            }
         }
 
         debug!(
@@ -148,11 +234,152 @@ where
         );
     }
 
-    fn start_worker(&self, entry: DispatchEntry, slot: CrawlWorkerSlot) {
+    /// Starts the entry, or parks it behind its host. Entries of a host with
+    /// parked entries queue up behind them to keep dispatch order. Returns
+    /// `false` once the pool is cancelled.
+    async fn dispatch(&mut self, entry: DispatchEntry) -> bool {
+        let host_permit = if self.parked.has_host(&entry.feed_url) {
+            None
+        } else {
+            self.capacity.hosts.try_reserve(&entry.feed_url)
+        };
+        if let Some(host_permit) = host_permit {
+            return self.start(entry, host_permit).await;
+        }
+        debug!(
+            worker = WorkerId::CrawlWorkerPool.as_str(),
+            feed_url = entry.feed_url.as_str(),
+            queue = entry.trigger.queue_lane().as_str(),
+            "crawl job parked until its host frees up"
+        );
+        self.parked.push(entry);
+        true
+    }
+
+    /// Starts parked entries whose host frees up, in order per host. Returns
+    /// `false` once the pool is cancelled.
+    async fn start_parked(&mut self) -> bool {
+        for host in self.parked.hosts() {
+            while let Some(feed_url) = self.parked.front(&host) {
+                let Some(host_permit) = self.capacity.hosts.try_reserve(feed_url) else {
+                    break;
+                };
+                let entry = self.parked.pop(&host);
+                if !self.start(entry, host_permit).await {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Waits for global and lane capacity, then starts the crawl. Returns
+    /// `false` when the pool is cancelled while waiting.
+    async fn start(&self, entry: DispatchEntry, host_permit: CrawlHostPermit) -> bool {
+        let lane = entry.trigger.queue_lane();
+        let slot = tokio::select! {
+            () = self.ct.cancelled() => return false,
+            slot = self.capacity.reserve(lane, host_permit) => slot,
+        };
+        self.launcher.launch(entry, slot);
+        true
+    }
+}
+
+/// Dispatch entries waiting for their host, first in first out per host.
+#[derive(Default)]
+struct ParkedEntries {
+    by_host: HashMap<String, VecDeque<DispatchEntry>>,
+    len: usize,
+}
+
+impl ParkedEntries {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn has_host(&self, feed_url: &FeedUrl) -> bool {
+        feed_url
+            .host()
+            .is_some_and(|host| self.by_host.contains_key(host))
+    }
+
+    fn hosts(&self) -> Vec<String> {
+        self.by_host.keys().cloned().collect()
+    }
+
+    fn push(&mut self, entry: DispatchEntry) {
+        let host = entry.feed_url.host().unwrap_or_default().to_owned();
+        self.by_host.entry(host).or_default().push_back(entry);
+        self.len += 1;
+    }
+
+    fn front(&self, host: &str) -> Option<&FeedUrl> {
+        self.by_host
+            .get(host)
+            .and_then(VecDeque::front)
+            .map(|entry| &entry.feed_url)
+    }
+
+    fn pop(&mut self, host: &str) -> DispatchEntry {
+        let queue = self.by_host.get_mut(host).expect("parked host has a queue");
+        let entry = queue.pop_front().expect("parked host queue is never empty");
+        if queue.is_empty() {
+            self.by_host.remove(host);
+        }
+        self.len -= 1;
+        entry
+    }
+
+    /// Earliest instant a parked host leaves its request spacing.
+    fn spacing_ends(&self, hosts: &CrawlHostCapacity) -> Option<Instant> {
+        self.by_host
+            .keys()
+            .filter_map(|host| hosts.spacing_end(host))
+            .min()
+    }
+}
+
+async fn sleep_until_some(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Starts a crawl worker for an entry once its capacity slot is reserved.
+#[derive(Clone)]
+struct CrawlWorkerLauncher<S, F> {
+    db: S,
+    fetcher: F,
+    wake_publisher: EventWakePublisher,
+    ct: CancellationToken,
+    history_retention: usize,
+    clock: Arc<dyn Clock>,
+}
+
+impl<S, F> CrawlWorkerLauncher<S, F>
+where
+    S: FeedRegistryDb,
+    F: FetchFeed + Clone + Send + Sync + 'static,
+    for<'tx> S::Tx<'tx>: BlobDb
+        + CrawlHistoryDb
+        + CrawlStateDb
+        + CrawlTargetDb
+        + EventJournalAppend
+        + EventJournal
+        + Send,
+{
+    fn launch(&self, entry: DispatchEntry, slot: CrawlWorkerSlot) {
         let db = self.db.clone();
         let fetcher = self.fetcher.clone();
         let wake_publisher = self.wake_publisher.clone();
         let worker_ct = self.ct.child_token();
+        let history_retention = self.history_retention;
         let clock = Arc::clone(&self.clock);
         let (job, inflight) = entry.into_crawl_job();
         tokio::spawn(async move {
@@ -166,7 +393,14 @@ where
             );
 
             let lane = slot.lane();
-            let worker = CrawlWorker::new(db, fetcher, wake_publisher, worker_ct, clock);
+            let worker = CrawlWorker::new(
+                db,
+                fetcher,
+                wake_publisher,
+                worker_ct,
+                history_retention,
+                clock,
+            );
             if let Err(err) = worker.run(job, lane).await {
                 error!(
                     worker = WorkerId::CrawlWorkerPool.as_str(),
@@ -190,6 +424,7 @@ struct CrawlWorker<S, F> {
     fetcher: F,
     wake_publisher: EventWakePublisher,
     ct: CancellationToken,
+    history_retention: usize,
     clock: Arc<dyn Clock>,
 }
 
@@ -199,6 +434,7 @@ impl<S, F> CrawlWorker<S, F> {
         fetcher: F,
         wake_publisher: EventWakePublisher,
         ct: CancellationToken,
+        history_retention: usize,
         clock: Arc<dyn Clock>,
     ) -> Self {
         Self {
@@ -206,6 +442,7 @@ impl<S, F> CrawlWorker<S, F> {
             fetcher,
             wake_publisher,
             ct,
+            history_retention,
             clock,
         }
     }
@@ -215,8 +452,13 @@ impl<S, F> CrawlWorker<S, F>
 where
     S: FeedRegistryDb,
     F: FetchFeed + Send + Sync,
-    for<'tx> S::Tx<'tx>:
-        BlobDb + CrawlStateDb + CrawlTargetDb + EventJournalAppend + EventJournal + Send,
+    for<'tx> S::Tx<'tx>: BlobDb
+        + CrawlHistoryDb
+        + CrawlStateDb
+        + CrawlTargetDb
+        + EventJournalAppend
+        + EventJournal
+        + Send,
 {
     #[tracing::instrument(
         name = "registry.crawl.worker.run",
@@ -271,8 +513,8 @@ where
     }
 
     /// Records what the finished crawl leaves behind in one transaction:
-    /// the body blob, the crawl-state summary, the served manual request,
-    /// and the `CrawlJobFinished` fact.
+    /// the body blob, the crawl-state summary, the history entry, the served
+    /// manual request, and the `CrawlJobFinished` fact.
     async fn record_completion(
         &self,
         job: CrawlJob,
@@ -293,6 +535,21 @@ where
             Some(bytes) => Some(tx.put_blob(PutBlobCommand::new(bytes, finished_at)).await?),
             None => None,
         };
+        let body_changed = match body_blob {
+            Some(body_blob) => tx.load_last_crawl_body(&job.feed_url).await? != Some(body_blob),
+            None => false,
+        };
+        let history = CrawlHistoryEntry {
+            job_id: job.job_id.clone(),
+            trigger: job.trigger,
+            started_at: job.started_at,
+            finished_at,
+            outcome: completion.summary.outcome,
+            http_status: completion.summary.http_status,
+            error_kind: completion.last.error.map(|error| error.kind),
+            body_bytes: completion.body_bytes,
+            body_changed,
+        };
         tx.upsert_crawl_state(UpsertCrawlStateCommand::new(
             job.feed_url.clone(),
             completion.last,
@@ -300,6 +557,13 @@ where
             completion.conditional,
         ))
         .await?;
+        tx.append_crawl_history(AppendCrawlHistoryCommand::new(
+            job.feed_url.clone(),
+            history,
+            body_blob,
+            self.history_retention,
+        ))
+        .await?;
         tx.clear_manual_request(&job.feed_url, job.started_at)
             .await?;
 
@@ -366,30 +630,34 @@ impl CrawlJob {
     }
 }
 
-/// Runtime permits controlling global and lane-local crawl concurrency.
+/// Runtime permits controlling global, lane-local, and per-host crawl
+/// concurrency.
 struct CrawlWorkerCapacity {
     global: Arc<Semaphore>,
     manual: Arc<Semaphore>,
     default: Arc<Semaphore>,
     retry: Arc<Semaphore>,
+    hosts: Arc<CrawlHostCapacity>,
 }
 
 impl CrawlWorkerCapacity {
-    fn new(config: CrawlWorkerPoolConfig) -> Self {
+    fn new(config: CrawlWorkerPoolConfig, hosts: Arc<CrawlHostCapacity>) -> Self {
         Self {
             global: Arc::new(Semaphore::new(config.max_running_jobs)),
             manual: Arc::new(Semaphore::new(config.manual_queue.max_running_jobs)),
             default: Arc::new(Semaphore::new(config.default_queue.max_running_jobs)),
             retry: Arc::new(Semaphore::new(config.retry_queue.max_running_jobs)),
+            hosts,
         }
     }
 
-    /// Waits until both a global and a lane slot are available.
+    /// Waits until a global and a lane slot are available for a crawl that
+    /// already holds its host slot.
     ///
-    /// The global permit is held while waiting for the lane permit, so a
-    /// saturated lane blocks subsequent dispatches (head-of-line), matching
-    /// the single-consumer dispatch queue semantics.
-    async fn reserve(&self, lane: CrawlJobQueueLane) -> CrawlWorkerSlot {
+    /// The global permit is held while waiting for the lane, so a saturated
+    /// lane blocks subsequent dispatches (head-of-line), matching the
+    /// single-consumer dispatch queue semantics.
+    async fn reserve(&self, lane: CrawlJobQueueLane, host: CrawlHostPermit) -> CrawlWorkerSlot {
         let global_permit = Arc::clone(&self.global)
             .acquire_owned()
             .await
@@ -404,23 +672,37 @@ impl CrawlWorkerCapacity {
             lane,
             _global_permit: global_permit,
             _lane_permit: lane_permit,
+            _host_permit: host,
         }
     }
 
     #[cfg(test)]
-    fn try_reserve(&self, lane: CrawlJobQueueLane) -> Option<CrawlWorkerSlot> {
+    fn try_reserve(&self, lane: CrawlJobQueueLane, feed_url: &FeedUrl) -> Option<CrawlWorkerSlot> {
         let lane_capacity = self.lane_capacity(lane);
-        if self.global.available_permits() == 0 || lane_capacity.available_permits() == 0 {
+        let host_capacity = feed_url
+            .host()
+            .map(|host| self.hosts.capacity(host, Instant::now()));
+        if self.global.available_permits() == 0
+            || lane_capacity.available_permits() == 0
+            || host_capacity
+                .as_ref()
+                .is_some_and(|host| host.available_permits() == 0)
+        {
             return None;
         }
 
         let global_permit = Arc::clone(&self.global).try_acquire_owned().ok()?;
         let lane_permit = lane_capacity.try_acquire_owned().ok()?;
+        let host_permit = match host_capacity {
+            Some(host) => Some(host.try_acquire_owned().ok()?),
+            None => None,
+        };
 
         Some(CrawlWorkerSlot {
             lane,
             _global_permit: global_permit,
             _lane_permit: lane_permit,
+            _host_permit: self.hosts.permit(host_permit),
         })
     }
 
@@ -433,11 +715,165 @@ impl CrawlWorkerCapacity {
     }
 }
 
+/// Per-host permits and request spacing, created on first use.
+///
+/// Shared with the article fetcher, so that fetching the web pages of
+/// entries counts against the same per-host budget as crawling feeds.
+pub(crate) struct CrawlHostCapacity {
+    config: CrawlWorkerHostConfig,
+    hosts: Mutex<HashMap<String, CrawlHostSlots>>,
+    /// Signalled when a host permit is released.
+    released: Arc<Notify>,
+}
+
+struct CrawlHostSlots {
+    permits: Arc<Semaphore>,
+    /// Earliest start of the host's next crawl under the request spacing.
+    next_start: Option<Instant>,
+}
+
+impl CrawlHostCapacity {
+    pub(crate) fn new(config: CrawlWorkerHostConfig) -> Self {
+        Self {
+            config,
+            hosts: Mutex::new(HashMap::new()),
+            released: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Takes a host slot if the URL's host is below its cap and outside its
+    /// request spacing. `None` means the entry has to wait for its host.
+    pub(crate) fn try_reserve(&self, feed_url: &FeedUrl) -> Option<CrawlHostPermit> {
+        let Some(host) = feed_url.host() else {
+            return Some(self.permit(None));
+        };
+        let now = Instant::now();
+        let permit = self.capacity(host, now).try_acquire_owned().ok()?;
+        self.try_book_start(host, now)
+            .then(|| self.permit(Some(permit)))
+    }
+
+    /// Waits until a host slot is available, then until the host's request
+    /// spacing has elapsed.
+    pub(crate) async fn reserve(&self, feed_url: &FeedUrl) -> CrawlHostPermit {
+        let Some(host) = feed_url.host() else {
+            return self.permit(None);
+        };
+        let permit = self
+            .capacity(host, Instant::now())
+            .acquire_owned()
+            .await
+            .expect("crawl worker host semaphore is never closed");
+        tokio::time::sleep_until(self.book_start(host, Instant::now())).await;
+        self.permit(Some(permit))
+    }
+
+    /// Waits until a host permit is released. A release while nobody waits
+    /// is kept for the next call.
+    pub(crate) async fn released(&self) {
+        self.released.notified().await;
+    }
+
+    fn permit(&self, permit: Option<OwnedSemaphorePermit>) -> CrawlHostPermit {
+        CrawlHostPermit {
+            permit,
+            released: Arc::clone(&self.released),
+        }
+    }
+
+    /// End of the host's request spacing, if it is still ahead.
+    fn spacing_end(&self, host: &str) -> Option<Instant> {
+        let hosts = self
+            .hosts
+            .lock()
+            .expect("host capacity lock is never poisoned");
+        hosts
+            .get(host)?
+            .next_start
+            .filter(|next_start| *next_start > Instant::now())
+    }
+
+    /// Returns the host's semaphore. Hosts with no running crawl and no
+    /// pending spacing are forgotten along the way.
+    fn capacity(&self, host: &str, now: Instant) -> Arc<Semaphore> {
+        let mut hosts = self
+            .hosts
+            .lock()
+            .expect("host capacity lock is never poisoned");
+        hosts.retain(|_, slots| {
+            Arc::strong_count(&slots.permits) > 1
+                || slots.next_start.is_some_and(|next_start| next_start > now)
+        });
+        let slots = hosts
+            .entry(host.to_owned())
+            .or_insert_with(|| CrawlHostSlots {
+                permits: Arc::new(Semaphore::new(self.config.max_running_jobs.max(1))),
+                next_start: None,
+            });
+        Arc::clone(&slots.permits)
+    }
+
+    /// Books the host's next crawl start at `now` unless the previous start
+    /// was less than the request spacing ago.
+    fn try_book_start(&self, host: &str, now: Instant) -> bool {
+        let Some(spacing) = self.config.min_request_spacing else {
+            return true;
+        };
+        let mut hosts = self
+            .hosts
+            .lock()
+            .expect("host capacity lock is never poisoned");
+        let Some(slots) = hosts.get_mut(host) else {
+            return true;
+        };
+        if slots.next_start.is_some_and(|next_start| next_start > now) {
+            return false;
+        }
+        slots.next_start = Some(now + spacing);
+        true
+    }
+
+    /// Books the host's next crawl start: `now`, or later when the previous
+    /// start was less than the request spacing ago.
+    fn book_start(&self, host: &str, now: Instant) -> Instant {
+        let Some(spacing) = self.config.min_request_spacing else {
+            return now;
+        };
+        let mut hosts = self
+            .hosts
+            .lock()
+            .expect("host capacity lock is never poisoned");
+        let Some(slots) = hosts.get_mut(host) else {
+            return now;
+        };
+        let start = slots
+            .next_start
+            .map_or(now, |next_start| next_start.max(now));
+        slots.next_start = Some(start + spacing);
+        start
+    }
+}
+
+/// Host permit of a fetch; empty for URLs without a host.
+pub(crate) struct CrawlHostPermit {
+    permit: Option<OwnedSemaphorePermit>,
+    released: Arc<Notify>,
+}
+
+impl Drop for CrawlHostPermit {
+    fn drop(&mut self) {
+        if self.permit.take().is_some() {
+            self.released.notify_one();
+        }
+    }
+}
+
 /// Acquired capacity permits for one running crawl job.
 struct CrawlWorkerSlot {
     lane: CrawlJobQueueLane,
     _global_permit: OwnedSemaphorePermit,
     _lane_permit: OwnedSemaphorePermit,
+    _host_permit: CrawlHostPermit,
 }
 
 impl CrawlWorkerSlot {
@@ -448,36 +884,253 @@ impl CrawlWorkerSlot {
 
 #[cfg(test)]
 mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use synd_feed::types::FeedUrl;
+    use tokio::time::Instant;
+
     use super::{
-        CrawlWorkerCapacity, CrawlWorkerFetchConfig, CrawlWorkerPoolConfig, CrawlWorkerQueueConfig,
+        CrawlHostCapacity, CrawlWorkerCapacity, CrawlWorkerFetchConfig, CrawlWorkerHostConfig,
+        CrawlWorkerPoolConfig, CrawlWorkerQueueConfig, ParkedEntries,
     };
     use crate::crawl::job::CrawlJobQueueLane;
 
     mod capacity {
-        use super::{CrawlJobQueueLane, CrawlWorkerCapacity, config};
+        use super::{
+            CrawlHostCapacity, CrawlJobQueueLane, CrawlWorkerHostConfig, Duration, Instant,
+            capacity, config, feed_url,
+        };
 
         #[test]
         fn enforces_global_and_lane_capacity() {
-            let capacity = CrawlWorkerCapacity::new(config(2, 1, 2, 1));
+            let capacity = capacity(config(2, 1, 2, 1));
 
             let manual = capacity
-                .try_reserve(CrawlJobQueueLane::Manual)
+                .try_reserve(
+                    CrawlJobQueueLane::Manual,
+                    &feed_url("https://a.example/feed"),
+                )
                 .expect("manual slot should be available");
-            assert!(capacity.try_reserve(CrawlJobQueueLane::Manual).is_none());
+            assert!(
+                capacity
+                    .try_reserve(
+                        CrawlJobQueueLane::Manual,
+                        &feed_url("https://b.example/feed")
+                    )
+                    .is_none()
+            );
 
             let default = capacity
-                .try_reserve(CrawlJobQueueLane::Default)
+                .try_reserve(
+                    CrawlJobQueueLane::Default,
+                    &feed_url("https://c.example/feed"),
+                )
                 .expect("default slot should be available");
-            assert!(capacity.try_reserve(CrawlJobQueueLane::Retry).is_none());
+            assert!(
+                capacity
+                    .try_reserve(
+                        CrawlJobQueueLane::Retry,
+                        &feed_url("https://d.example/feed")
+                    )
+                    .is_none()
+            );
 
             drop(manual);
             let retry = capacity
-                .try_reserve(CrawlJobQueueLane::Retry)
+                .try_reserve(
+                    CrawlJobQueueLane::Retry,
+                    &feed_url("https://e.example/feed"),
+                )
                 .expect("released global slot should be reusable");
 
             drop(default);
             drop(retry);
         }
+
+        #[test]
+        fn enforces_capacity_per_host() {
+            let mut config = config(4, 4, 4, 4);
+            config.host.max_running_jobs = 1;
+            let capacity = capacity(config);
+
+            let first = capacity
+                .try_reserve(
+                    CrawlJobQueueLane::Default,
+                    &feed_url("https://github.com/a/releases.atom"),
+                )
+                .expect("host slot should be available");
+            assert!(
+                capacity
+                    .try_reserve(
+                        CrawlJobQueueLane::Default,
+                        &feed_url("https://github.com/b/releases.atom"),
+                    )
+                    .is_none()
+            );
+            let subdomain = capacity
+                .try_reserve(
+                    CrawlJobQueueLane::Default,
+                    &feed_url("https://api.github.com/b/releases.atom"),
+                )
+                .expect("other host under the same domain should be unaffected");
+            let sibling = capacity
+                .try_reserve(
+                    CrawlJobQueueLane::Default,
+                    &feed_url("https://alice.github.io/feed.xml"),
+                )
+                .expect("site under a shared suffix should be unaffected");
+
+            drop(first);
+            let second = capacity
+                .try_reserve(
+                    CrawlJobQueueLane::Default,
+                    &feed_url("https://github.com/b/releases.atom"),
+                )
+                .expect("released host slot should be reusable");
+
+            drop(subdomain);
+            drop(sibling);
+            drop(second);
+        }
+
+        #[tokio::test]
+        async fn capped_host_does_not_hold_back_other_hosts() {
+            let mut config = config(4, 4, 4, 4);
+            config.host.max_running_jobs = 1;
+            let capacity = capacity(config);
+            let running = capacity
+                .try_reserve(
+                    CrawlJobQueueLane::Default,
+                    &feed_url("https://github.com/a/releases.atom"),
+                )
+                .expect("host slot should be available");
+
+            // Queued behind the capped host, another host still gets its slot.
+            let capped = feed_url("https://github.com/b/releases.atom");
+            assert!(capacity.hosts.try_reserve(&capped).is_none());
+            let other = capacity
+                .hosts
+                .try_reserve(&feed_url("https://example.com/feed"))
+                .expect("other host should not wait for the capped one");
+            let other = capacity.reserve(CrawlJobQueueLane::Default, other).await;
+
+            let mut parked = std::pin::pin!(capacity.hosts.reserve(&capped));
+            assert!(
+                tokio::time::timeout(Duration::from_millis(10), &mut parked)
+                    .await
+                    .is_err()
+            );
+            drop(running);
+            let parked = tokio::time::timeout(Duration::from_secs(1), parked)
+                .await
+                .expect("parked entry should get the released host slot");
+
+            drop(other);
+            drop(parked);
+        }
+
+        #[tokio::test]
+        async fn released_host_permit_wakes_the_pool() {
+            let hosts = CrawlHostCapacity::new(CrawlWorkerHostConfig {
+                max_running_jobs: 1,
+                min_request_spacing: None,
+            });
+            let running = hosts
+                .try_reserve(&feed_url("https://example.com/a.xml"))
+                .expect("host slot should be available");
+            assert!(
+                hosts
+                    .try_reserve(&feed_url("https://example.com/b.xml"))
+                    .is_none()
+            );
+
+            let mut released = std::pin::pin!(hosts.released());
+            assert!(
+                tokio::time::timeout(Duration::from_millis(10), &mut released)
+                    .await
+                    .is_err()
+            );
+            drop(running);
+            tokio::time::timeout(Duration::from_secs(1), released)
+                .await
+                .expect("released host permit should wake the pool");
+        }
+
+        #[test]
+        fn spaces_request_starts_per_host() {
+            let hosts = CrawlHostCapacity::new(CrawlWorkerHostConfig {
+                max_running_jobs: 4,
+                min_request_spacing: Some(Duration::from_secs(2)),
+            });
+            let now = Instant::now();
+            let _github = hosts.capacity("github.com", now);
+            let _example = hosts.capacity("example.com", now);
+
+            assert_eq!(hosts.book_start("github.com", now), now);
+            assert_eq!(
+                hosts.book_start("github.com", now),
+                now + Duration::from_secs(2)
+            );
+            assert_eq!(
+                hosts.book_start("github.com", now + Duration::from_secs(1)),
+                now + Duration::from_secs(4)
+            );
+            assert_eq!(hosts.book_start("example.com", now), now);
+            assert!(!hosts.try_book_start("example.com", now));
+            assert!(hosts.try_book_start("example.com", now + Duration::from_secs(2)));
+            assert_eq!(
+                hosts.book_start("github.com", now + Duration::from_secs(10)),
+                now + Duration::from_secs(10)
+            );
+        }
+    }
+
+    mod parked {
+        use chrono::Utc;
+
+        use super::{ParkedEntries, feed_url};
+        use crate::crawl::{
+            dispatch::{DispatchEntry, InflightCrawls},
+            job::CrawlJobTrigger,
+        };
+
+        fn entry(inflight: &InflightCrawls, url: &str) -> DispatchEntry {
+            let feed_url = feed_url(url);
+            let guard = inflight.try_claim(&feed_url).unwrap();
+            DispatchEntry::new(feed_url, CrawlJobTrigger::PeriodicDue, Utc::now(), guard)
+        }
+
+        #[test]
+        fn entries_leave_in_dispatch_order_per_host() {
+            let inflight = InflightCrawls::new();
+            let mut parked = ParkedEntries::default();
+            parked.push(entry(&inflight, "https://example.com/a.xml"));
+            parked.push(entry(&inflight, "https://other.example.org/feed.xml"));
+            parked.push(entry(&inflight, "https://example.com/b.xml"));
+
+            assert_eq!(parked.len(), 3);
+            assert!(parked.has_host(&feed_url("https://example.com/c.xml")));
+            assert!(!parked.has_host(&feed_url("https://api.example.com/feed.xml")));
+            assert_eq!(
+                parked.pop("example.com").feed_url,
+                feed_url("https://example.com/a.xml")
+            );
+            assert_eq!(
+                parked.front("example.com"),
+                Some(&feed_url("https://example.com/b.xml"))
+            );
+            parked.pop("example.com");
+            assert!(!parked.has_host(&feed_url("https://example.com/a.xml")));
+            assert_eq!(parked.len(), 1);
+        }
+    }
+
+    fn feed_url(url: &str) -> FeedUrl {
+        FeedUrl::parse(url).unwrap()
+    }
+
+    fn capacity(config: CrawlWorkerPoolConfig) -> CrawlWorkerCapacity {
+        CrawlWorkerCapacity::new(config, Arc::new(CrawlHostCapacity::new(config.host)))
     }
 
     fn config(
@@ -497,7 +1150,9 @@ mod tests {
             retry_queue: CrawlWorkerQueueConfig {
                 max_running_jobs: retry_max_running_jobs,
             },
+            host: CrawlWorkerHostConfig::default(),
             fetch: CrawlWorkerFetchConfig::default(),
+            history_retention: 100,
         }
     }
 }
@@ -7,27 +7,35 @@ use synd_feed::{
 };
 
 use crate::{
+    article::{ArticleFetch, ArticleFetchOutcome},
     crawl::{
-        blob::{BlobRef, PutBlobCommand},
+        blob::{BlobCollection, BlobRef, PutBlobCommand},
         due::CrawlDueInput,
+        history::{AppendCrawlHistoryCommand, CrawlHistoryEntry, CrawlHistoryQuery},
         state::{CrawlState, UpsertCrawlStateCommand},
         target_list::{CrawlTarget, FeedSubscriptions},
     },
     entry::Entries,
     error::{RegistryDbError, RegistryDbResult},
-    event::{EventJournal, EventJournalAppend},
+    event::{EventJournal, EventJournalAppend, EventJournalCompact},
     feed::FeedUpdate,
     query::{
-        Subscriptions, SubscriptionsQuery, TimelineChangesPage, TimelineChangesQuery,
-        TimelineEntriesPage, TimelineEntriesQuery,
+        FeedEntriesPage, FeedEntriesQuery, SavedEntriesPage, SavedEntriesQuery, SubscriberSummary,
+        SubscribersPage, SubscribersQuery, Subscriptions, SubscriptionsQuery, TimelineChangesPage,
+        TimelineChangesQuery, TimelineEntriesPage, TimelineEntriesQuery, TimelineEntry,
+        TimelineSearchPage, TimelineSearchQuery,
+    },
+    read::EntryReadState,
+    subscription::{FeedSubscriptionAttrs, SubscriberId, Subscription, SubscriptionKey},
+    timeline::{
+        TimelineCatchup,
+        retention::{RetentionPurge, TimelinePrune, TimelineRetention},
     },
-    subscription::{FeedSubscriptionAttrs, SubscriberId, SubscriptionKey},
-    timeline::TimelineCatchup,
 };
 
 /// Opens registry database transactions.
 pub trait FeedRegistryDb: Clone + Send + Sync + 'static {
-    type Tx<'a>: EventJournal + EventJournalAppend + CommitTx + Send
+    type Tx<'a>: EventJournal + EventJournalAppend + EventJournalCompact + CommitTx + Send
     where
         Self: 'a;
 
@@ -66,6 +74,25 @@ pub trait SubscriptionDb {
         &mut self,
         feed_url: &FeedUrl,
     ) -> impl Future<Output = RegistryDbResult<FeedSubscriptions>> + Send;
+
+    /// Loads every subscription to one feed with its attributes, ordered by
+    /// subscriber id.
+    fn load_feed_subscribers(
+        &mut self,
+        feed_url: &FeedUrl,
+    ) -> impl Future<Output = RegistryDbResult<Vec<Subscription>>> + Send;
+
+    /// Lists subscribers holding at least one subscription, ordered by id.
+    fn list_subscribers(
+        &mut self,
+        query: SubscribersQuery,
+    ) -> impl Future<Output = RegistryDbResult<SubscribersPage>> + Send;
+
+    /// Loads one subscriber's summary, `None` when it holds no subscription.
+    fn load_subscriber(
+        &mut self,
+        subscriber_id: &SubscriberId,
+    ) -> impl Future<Output = RegistryDbResult<Option<SubscriberSummary>>> + Send;
 }
 
 /// Transactional operations over crawl target state.
@@ -122,6 +149,28 @@ pub trait CrawlStateDb {
     ) -> impl Future<Output = RegistryDbResult<()>> + Send;
 }
 
+/// Transactional operations over the bounded per-feed crawl history.
+pub trait CrawlHistoryDb {
+    /// Loads the body accepted by the feed's most recent crawl that kept one,
+    /// looking only at the retained history.
+    fn load_last_crawl_body(
+        &mut self,
+        feed_url: &FeedUrl,
+    ) -> impl Future<Output = RegistryDbResult<Option<BlobRef>>> + Send;
+
+    /// Appends one entry and prunes the feed's history down to
+    /// `command.retain` most recent entries.
+    fn append_crawl_history(
+        &mut self,
+        command: AppendCrawlHistoryCommand,
+    ) -> impl Future<Output = RegistryDbResult<()>> + Send;
+
+    fn list_crawl_history(
+        &mut self,
+        query: CrawlHistoryQuery,
+    ) -> impl Future<Output = RegistryDbResult<Vec<CrawlHistoryEntry>>> + Send;
+}
+
 /// Transactional generic blob-store operations.
 pub trait BlobDb {
     fn put_blob(
@@ -133,6 +182,15 @@ pub trait BlobDb {
         &mut self,
         blob: BlobRef,
     ) -> impl Future<Output = RegistryDbResult<Vec<u8>>> + Send;
+
+    /// Deletes up to `limit` blobs created before `created_before` that are
+    /// referenced neither by a feed snapshot, a fetched article, nor by a
+    /// `CrawlJobFinished` event still in the journal.
+    fn delete_unreferenced_blobs(
+        &mut self,
+        created_before: DateTime<Utc>,
+        limit: usize,
+    ) -> impl Future<Output = RegistryDbResult<BlobCollection>> + Send;
 }
 
 /// Transactional operations over parsed current feed state.
@@ -151,6 +209,20 @@ pub trait FeedDb {
         &mut self,
         feed_urls: &[FeedUrl],
     ) -> impl Future<Output = RegistryDbResult<HashMap<FeedUrl, Feed>>> + Send;
+
+    /// Lists every catalog entry of a subscribed feed in canonical entry
+    /// order, whether or not it is still on the subscriber's timeline.
+    fn list_feed_entries(
+        &mut self,
+        query: FeedEntriesQuery,
+    ) -> impl Future<Output = RegistryDbResult<FeedEntriesPage>> + Send;
+
+    /// Deletes up to `limit` catalog entries that no feed declares anymore
+    /// and that are neither on any timeline, tombstones included, nor saved.
+    fn delete_orphaned_entries(
+        &mut self,
+        limit: usize,
+    ) -> impl Future<Output = RegistryDbResult<RetentionPurge>> + Send;
 }
 
 /// Transactional operations for reading and applying timeline membership.
@@ -165,6 +237,27 @@ pub trait TimelineDb {
         query: TimelineChangesQuery,
     ) -> impl Future<Output = RegistryDbResult<TimelineChangesPage>> + Send;
 
+    /// Loads the change seq the subscriber's timeline is at, 0 before its
+    /// first change.
+    fn load_timeline_seq(
+        &mut self,
+        subscriber_id: &SubscriberId,
+    ) -> impl Future<Output = RegistryDbResult<i64>> + Send;
+
+    /// Lists the live timeline entries whose text matches the query, in
+    /// timeline order.
+    fn search_timeline_entries(
+        &mut self,
+        query: TimelineSearchQuery,
+    ) -> impl Future<Output = RegistryDbResult<TimelineSearchPage>> + Send;
+
+    /// Loads one live entry of the subscriber's timeline, if present.
+    fn load_timeline_entry(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+    ) -> impl Future<Output = RegistryDbResult<Option<TimelineEntry>>> + Send;
+
     fn catchup_subscribed_feed(
         &mut self,
         subscriber_id: &SubscriberId,
@@ -185,6 +278,133 @@ pub trait TimelineDb {
         &mut self,
         subscription: &SubscriptionKey,
     ) -> impl Future<Output = RegistryDbResult<Option<SubscriberId>>> + Send;
+
+    /// Bumps the item seq of live timeline entries whose subscriber-scoped
+    /// state changed, so syncing clients re-read them. Returns the feeds of
+    /// the touched entries.
+    fn touch_timeline_entries(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_ids: &[EntryId],
+    ) -> impl Future<Output = RegistryDbResult<Vec<FeedUrl>>> + Send;
+
+    /// Turns up to `limit` live entries the retention rules no longer keep
+    /// into tombstones, one seq per row. Saved entries are always kept.
+    /// Retired entries stay out of later catch-ups and entry changes.
+    fn remove_retired_timeline_entries(
+        &mut self,
+        retention: &TimelineRetention,
+        limit: usize,
+    ) -> impl Future<Output = RegistryDbResult<TimelinePrune>> + Send;
+
+    /// Deletes up to `limit` tombstones whose seq is older than the most
+    /// recent `seq_window` seqs of their timeline.
+    fn purge_timeline_tombstones(
+        &mut self,
+        seq_window: u64,
+        limit: usize,
+    ) -> impl Future<Output = RegistryDbResult<RetentionPurge>> + Send;
+
+    /// Deletes the subscriber's timeline with its entries and read markers.
+    /// Subscribing again starts a new timeline.
+    fn delete_timeline(
+        &mut self,
+        subscriber_id: &SubscriberId,
+    ) -> impl Future<Output = RegistryDbResult<()>> + Send;
+}
+
+/// Transactional operations over the full articles fetched for entries.
+pub trait ArticleDb {
+    /// Queues the entries of the feed for fetching, provided an active
+    /// subscription of the feed opted in. Entries queued before are left as
+    /// they are.
+    fn enqueue_article_fetches(
+        &mut self,
+        feed_url: &FeedUrl,
+        fetches: &[ArticleFetch],
+        queued_at: DateTime<Utc>,
+    ) -> impl Future<Output = RegistryDbResult<()>> + Send;
+
+    /// Loads up to `limit` queued fetches due at `now`, earliest first.
+    fn load_due_article_fetches(
+        &mut self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> impl Future<Output = RegistryDbResult<Vec<ArticleFetch>>> + Send;
+
+    /// Earliest instant a queued fetch becomes due.
+    fn next_article_fetch_at(
+        &mut self,
+    ) -> impl Future<Output = RegistryDbResult<Option<DateTime<Utc>>>> + Send;
+
+    fn record_article_fetch(
+        &mut self,
+        entry_id: &EntryId,
+        outcome: ArticleFetchOutcome,
+    ) -> impl Future<Output = RegistryDbResult<()>> + Send;
+
+    /// Loads the entry's article for a subscriber whose subscription to the
+    /// entry's feed asks for full content.
+    fn load_article(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+    ) -> impl Future<Output = RegistryDbResult<Option<BlobRef>>> + Send;
+}
+
+/// Transactional operations over subscriber read markers.
+pub trait ReadMarkDb {
+    /// Loads the read state of the given entries that are live on the
+    /// subscriber's timeline. Unknown or removed entries are left out.
+    fn load_entry_read_states(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_ids: &[EntryId],
+    ) -> impl Future<Output = RegistryDbResult<Vec<EntryReadState>>> + Send;
+
+    fn mark_entries_read(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_ids: &[EntryId],
+        read_at: DateTime<Utc>,
+    ) -> impl Future<Output = RegistryDbResult<()>> + Send;
+
+    fn unmark_entries_read(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_ids: &[EntryId],
+    ) -> impl Future<Output = RegistryDbResult<()>> + Send;
+}
+
+/// Transactional operations over the entries subscribers saved.
+pub trait SavedEntryDb {
+    /// Loads whether the subscriber saved the entry. `None` when the entry
+    /// is neither saved nor live on the subscriber's timeline.
+    fn load_entry_saved(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+    ) -> impl Future<Output = RegistryDbResult<Option<bool>>> + Send;
+
+    fn save_entry(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+        saved_at: DateTime<Utc>,
+    ) -> impl Future<Output = RegistryDbResult<()>> + Send;
+
+    fn unsave_entry(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+    ) -> impl Future<Output = RegistryDbResult<()>> + Send;
+
+    /// Lists saved entries latest save first, including those no longer
+    /// on the timeline.
+    fn list_saved_entries(
+        &mut self,
+        query: SavedEntriesQuery,
+    ) -> impl Future<Output = RegistryDbResult<SavedEntriesPage>> + Send;
 }
 
 /// Commits a registry database transaction.
@@ -35,13 +35,15 @@ impl Entries {
 pub enum Change {
     Discovered(SyndEntry),
     Changed(SyndEntry),
+    /// Stored again to pick up newly captured fields, without an event.
+    Backfilled(SyndEntry),
 }
 
 impl Change {
     /// Returns the resulting entry state carried by this change.
     pub fn entry(&self) -> &SyndEntry {
         match self {
-            Self::Discovered(entry) | Self::Changed(entry) => entry,
+            Self::Discovered(entry) | Self::Changed(entry) | Self::Backfilled(entry) => entry,
         }
     }
 
@@ -59,16 +61,16 @@ impl Change {
             return Ok(Some(Self::Discovered(entry)));
         };
 
-        match current.compute_diff(&observed)? {
-            SyndEntryDiff::Unchanged => Ok(None),
-            SyndEntryDiff::EntryChanged => {
-                let entry = SyndEntry::builder()
-                    .entry(observed)
-                    .order_key(current.order_key())
-                    .build();
-                Ok(Some(Self::Changed(entry)))
-            }
-        }
+        let change: fn(SyndEntry) -> Self = match current.compute_diff(&observed)? {
+            SyndEntryDiff::Unchanged => return Ok(None),
+            SyndEntryDiff::Backfilled => Self::Backfilled,
+            SyndEntryDiff::EntryChanged => Self::Changed,
+        };
+        let entry = SyndEntry::builder()
+            .entry(observed)
+            .order_key(current.order_key())
+            .build();
+        Ok(Some(change(entry)))
     }
 }
 
@@ -154,6 +156,32 @@ mod tests {
         assert_eq!(entry.order_key().as_datetime(), order_key);
     }
 
+    #[test]
+    fn entry_gaining_newly_captured_fields_is_backfilled() {
+        let order_key = time("2026-07-18T12:00:00Z");
+        let current = SyndEntry::builder()
+            .entry(Entry::builder().id(entry_id('a')).build())
+            .order_key(EntryOrderKey::from_datetime(order_key))
+            .build();
+        let observed = Entry::builder()
+            .id(entry_id('a'))
+            .tags(vec!["rust".to_owned()])
+            .build();
+
+        let change = Change::decide(
+            Some(current),
+            observed.clone(),
+            time("2026-07-21T12:00:00Z"),
+        )
+        .unwrap();
+
+        let Some(Change::Backfilled(entry)) = change else {
+            panic!("expected a backfilled entry");
+        };
+        assert_eq!(entry.entry(), &observed);
+        assert_eq!(entry.order_key().as_datetime(), order_key);
+    }
+
     #[test]
     fn unchanged_entry_produces_no_change() {
         let observed_at = time("2026-07-20T12:00:00Z");
@@ -45,4 +45,6 @@ pub enum FeedRegistryError {
     Rejected(#[from] crate::subscription::SubReject),
     #[error(transparent)]
     CrawlRequestRejected(#[from] crate::crawl::request::CrawlRequestReject),
+    #[error("feed discovery failed: {0}")]
+    Discovery(#[from] synd_feed::feed::service::FeedDiscoveryError),
 }
@@ -78,6 +78,7 @@ mod tests {
                 crawl_policy: CrawlPolicy::interval(
                     PollingInterval::try_from(Duration::from_hours(1)).unwrap(),
                 ),
+                fetch_full_content: false,
             },
         ));
 
@@ -99,7 +100,8 @@ mod tests {
                             "kind": "interval",
                             "interval_seconds": 3600
                         }
-                    }
+                    },
+                    "fetch_full_content": false
                 }
             })
         );
@@ -110,4 +112,33 @@ mod tests {
             event
         );
     }
+
+    #[test]
+    fn event_decoding_defaults_attrs_journaled_before_full_content() {
+        let payload_json = serde_json::json!({
+            "type": "sub.subscription.changed",
+            "subscription": {
+                "subscriber_id": "reader",
+                "feed_url": "https://example.com/feed.xml"
+            },
+            "attrs": {
+                "requirement": null,
+                "category": null,
+                "crawl_policy": {
+                    "polling": {
+                        "kind": "interval",
+                        "interval_seconds": 3600
+                    }
+                }
+            }
+        })
+        .to_string();
+
+        let Event::SubscriptionChanged(event) =
+            Event::decode("sub.subscription.changed", &payload_json).unwrap()
+        else {
+            panic!("expected SubscriptionChanged");
+        };
+        assert!(!event.attrs.fetch_full_content);
+    }
 }
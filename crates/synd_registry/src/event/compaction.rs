@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use synd_support::o11y::metric;
+use tracing::{debug, info};
+
+use crate::{
+    config::JournalCompactionConfig,
+    db::{CommitTx, FeedRegistryDb},
+    event::{
+        EventInterests, EventJournalCompact, ProcessorId, Reaction, Reconciler, RecordedEvents,
+        WakeRequest, WorkerId, WorkerResult,
+    },
+};
+
+/// Level-driven cleanup deleting journal entries every processor has
+/// committed past.
+///
+/// The slowest cursor is re-read on every pass, so a processor that falls
+/// behind simply holds compaction back until it catches up. No journal event
+/// hints at new work; the worker runs on its poll interval and re-wakes
+/// immediately while a backlog larger than one batch remains.
+pub(crate) struct JournalCompactor {
+    config: JournalCompactionConfig,
+}
+
+impl JournalCompactor {
+    pub(crate) fn new(config: JournalCompactionConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl<S> Reconciler<S> for JournalCompactor
+where
+    S: FeedRegistryDb,
+{
+    fn id(&self) -> WorkerId {
+        WorkerId::JournalCompactor
+    }
+
+    fn wake_hints(&self) -> EventInterests {
+        EventInterests::empty()
+    }
+
+    async fn reconcile(&mut self, db: &S, now: DateTime<Utc>) -> WorkerResult<Reaction> {
+        let mut tx = db.begin().await?;
+        let compaction = tx
+            .compact_journal(&ProcessorId::ALL, self.config.batch_size.max(1))
+            .await?;
+        tx.commit().await?;
+
+        if compaction.removed > 0 {
+            info!(
+                removed = compaction.removed,
+                has_more = compaction.has_more,
+                "registry event journal compacted"
+            );
+            metric!(monotonic_counter.registry.event_journal.compacted = compaction.removed);
+        } else {
+            debug!("registry event journal has nothing to compact");
+        }
+
+        let wake = if compaction.has_more {
+            WakeRequest::at(now)
+        } else {
+            WakeRequest::None
+        };
+        Ok(Reaction::new(RecordedEvents::empty(), wake))
+    }
+}
@@ -48,6 +48,21 @@ pub enum Event {
     #[serde(rename = "timeline.changed")]
     #[strum_discriminants(strum(serialize = "timeline.changed"))]
     TimelineChanged(TimelineChangedEvent),
+    #[serde(rename = "timeline.deleted")]
+    #[strum_discriminants(strum(serialize = "timeline.deleted"))]
+    TimelineDeleted(TimelineDeletedEvent),
+    #[serde(rename = "read.entries.marked")]
+    #[strum_discriminants(strum(serialize = "read.entries.marked"))]
+    EntriesMarkedRead(EntriesMarkedReadEvent),
+    #[serde(rename = "read.entries.unmarked")]
+    #[strum_discriminants(strum(serialize = "read.entries.unmarked"))]
+    EntriesMarkedUnread(EntriesMarkedUnreadEvent),
+    #[serde(rename = "saved.entry.saved")]
+    #[strum_discriminants(strum(serialize = "saved.entry.saved"))]
+    EntrySaved(EntrySavedEvent),
+    #[serde(rename = "saved.entry.unsaved")]
+    #[strum_discriminants(strum(serialize = "saved.entry.unsaved"))]
+    EntryUnsaved(EntryUnsavedEvent),
 }
 
 impl Event {
@@ -317,6 +332,84 @@ impl TimelineChangedEvent {
     }
 }
 
+/// One subscriber's timeline was deleted together with its read markers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimelineDeletedEvent {
+    pub subscriber_id: SubscriberId,
+}
+
+impl TimelineDeletedEvent {
+    pub fn new(subscriber_id: SubscriberId) -> Self {
+        Self { subscriber_id }
+    }
+}
+
+/// A subscriber marked timeline entries as read.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntriesMarkedReadEvent {
+    pub subscriber_id: SubscriberId,
+    /// Entries whose read state changed; never empty.
+    pub entry_ids: Vec<EntryId>,
+}
+
+impl EntriesMarkedReadEvent {
+    pub fn new(subscriber_id: SubscriberId, entry_ids: Vec<EntryId>) -> Self {
+        Self {
+            subscriber_id,
+            entry_ids,
+        }
+    }
+}
+
+/// A subscriber marked previously read timeline entries as unread.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntriesMarkedUnreadEvent {
+    pub subscriber_id: SubscriberId,
+    /// Entries whose read state changed; never empty.
+    pub entry_ids: Vec<EntryId>,
+}
+
+impl EntriesMarkedUnreadEvent {
+    pub fn new(subscriber_id: SubscriberId, entry_ids: Vec<EntryId>) -> Self {
+        Self {
+            subscriber_id,
+            entry_ids,
+        }
+    }
+}
+
+/// A subscriber saved an entry of their timeline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntrySavedEvent {
+    pub subscriber_id: SubscriberId,
+    pub entry_id: EntryId,
+}
+
+impl EntrySavedEvent {
+    pub fn new(subscriber_id: SubscriberId, entry_id: EntryId) -> Self {
+        Self {
+            subscriber_id,
+            entry_id,
+        }
+    }
+}
+
+/// A subscriber removed an entry from their saved entries.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntryUnsavedEvent {
+    pub subscriber_id: SubscriberId,
+    pub entry_id: EntryId,
+}
+
+impl EntryUnsavedEvent {
+    pub fn new(subscriber_id: SubscriberId, entry_id: EntryId) -> Self {
+        Self {
+            subscriber_id,
+            entry_id,
+        }
+    }
+}
+
 impl RegistryEvent for FeedSubscribedEvent {
     const TYPE: EventType = EventType::FeedSubscribed;
 }
@@ -360,3 +453,23 @@ impl RegistryEvent for EntryChangedEvent {
 impl RegistryEvent for TimelineChangedEvent {
     const TYPE: EventType = EventType::TimelineChanged;
 }
+
+impl RegistryEvent for TimelineDeletedEvent {
+    const TYPE: EventType = EventType::TimelineDeleted;
+}
+
+impl RegistryEvent for EntriesMarkedReadEvent {
+    const TYPE: EventType = EventType::EntriesMarkedRead;
+}
+
+impl RegistryEvent for EntriesMarkedUnreadEvent {
+    const TYPE: EventType = EventType::EntriesMarkedUnread;
+}
+
+impl RegistryEvent for EntrySavedEvent {
+    const TYPE: EventType = EventType::EntrySaved;
+}
+
+impl RegistryEvent for EntryUnsavedEvent {
+    const TYPE: EventType = EventType::EntryUnsaved;
+}
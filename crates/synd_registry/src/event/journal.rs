@@ -39,6 +39,27 @@ pub trait EventJournal {
     ) -> impl Future<Output = RegistryDbResult<()>> + Send;
 }
 
+/// Transactional removal of journal entries every processor has consumed.
+pub trait EventJournalCompact {
+    /// Deletes up to `limit` entries at or below the slowest committed cursor
+    /// among `processors`. Nothing is deleted while any of them has not
+    /// committed a cursor yet.
+    fn compact_journal(
+        &mut self,
+        processors: &[ProcessorId],
+        limit: usize,
+    ) -> impl Future<Output = RegistryDbResult<JournalCompaction>> + Send;
+}
+
+/// Result of one journal compaction pass.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JournalCompaction {
+    /// Number of journal entries deleted.
+    pub removed: u64,
+    /// Consumed entries remain because the pass stopped at its limit.
+    pub has_more: bool,
+}
+
 /// A batch of journal entries selected for one processor.
 ///
 /// `events` contains only entries the processor should handle. `scanned_cursor`
@@ -1,4 +1,5 @@
 mod codec;
+mod compaction;
 mod domain;
 mod journal;
 mod processor;
@@ -7,14 +8,18 @@ mod recorder;
 mod worker;
 
 pub use codec::{EncodedEvent, EventEncoding, EventEncodingError, EventEncodingResult};
+pub(crate) use compaction::JournalCompactor;
 pub use domain::{
     CrawlJobFinishedEvent, CrawlRequestedEvent, CrawlTargetActivatedEvent,
-    CrawlTargetDeactivatedEvent, CrawlTargetPolicyChangedEvent, EntryChangedEvent,
-    EntryDiscoveredEvent, Event, EventInterests, EventType, FeedSubscribedEvent,
+    CrawlTargetDeactivatedEvent, CrawlTargetPolicyChangedEvent, EntriesMarkedReadEvent,
+    EntriesMarkedUnreadEvent, EntryChangedEvent, EntryDiscoveredEvent, EntrySavedEvent,
+    EntryUnsavedEvent, Event, EventInterests, EventType, FeedSubscribedEvent,
     FeedUnsubscribedEvent, RegistryEvent, SubEvent, SubscriptionChangedEvent, TimelineChangedEvent,
+    TimelineDeletedEvent,
 };
 pub use journal::{
-    EventCursor, EventCursorPos, EventJournal, EventJournalAppend, EventReadBatch, JournaledEvent,
+    EventCursor, EventCursorPos, EventJournal, EventJournalAppend, EventJournalCompact,
+    EventReadBatch, JournalCompaction, JournaledEvent,
 };
 
 pub use processor::{
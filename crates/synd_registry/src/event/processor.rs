@@ -121,16 +121,27 @@ pub enum ProcessorId {
     FeedProjection,
     TimelineProjection,
     ApiEventPublisher,
+    EntryNotifier,
 }
 
 impl ProcessorId {
+    /// Every processor that keeps a journal cursor. Journal compaction never
+    /// deletes past the slowest of them.
+    pub const ALL: [Self; 5] = [
+        Self::CrawlTargetProjection,
+        Self::FeedProjection,
+        Self::TimelineProjection,
+        Self::ApiEventPublisher,
+        Self::EntryNotifier,
+    ];
+
     pub fn as_str(self) -> &'static str {
         match self {
             Self::CrawlTargetProjection => "CrawlTargetProjection",
-
             Self::FeedProjection => "FeedProjection",
             Self::TimelineProjection => "TimelineProjection",
             Self::ApiEventPublisher => "ApiEventPublisher",
+            Self::EntryNotifier => "EntryNotifier",
         }
     }
 }
@@ -175,6 +186,12 @@ pub trait Processor: Send + 'static {
     fn interests(&self) -> EventInterests {
         EventInterests::new(Self::Input::INTERESTS.to_vec())
     }
+
+    /// Whether a processor without a committed cursor starts at the current
+    /// journal head instead of replaying the whole journal.
+    fn starts_at_journal_head(&self) -> bool {
+        false
+    }
 }
 
 /// Inputs selected from one journal read for a processor.
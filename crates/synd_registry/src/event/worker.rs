@@ -11,9 +11,9 @@ use crate::{
     db::{CommitTx, FeedRegistryDb},
     error::RegistryDbError,
     event::{
-        EventCursor, EventInput, EventInterests, EventJournal, EventJournalAppend, EventRecorder,
-        InputBatch, ProcessorError, ProcessorId, Projector, Reaction, RecordedEvents, Sink,
-        WakeRequest,
+        EventCursor, EventCursorPos, EventInput, EventInterests, EventJournal, EventJournalAppend,
+        EventRecorder, InputBatch, ProcessorError, ProcessorId, Projector, Reaction,
+        RecordedEvents, Sink, WakeRequest,
     },
 };
 
@@ -57,6 +57,10 @@ pub enum WorkerId {
     Processor(ProcessorId),
     CrawlDispatcher,
     CrawlWorkerPool,
+    JournalCompactor,
+    BlobCollector,
+    ArticleFetcher,
+    TimelinePruner,
 }
 
 impl WorkerId {
@@ -65,6 +69,10 @@ impl WorkerId {
             Self::Processor(processor) => processor.as_str(),
             Self::CrawlDispatcher => "CrawlDispatcher",
             Self::CrawlWorkerPool => "CrawlWorkerPool",
+            Self::JournalCompactor => "JournalCompactor",
+            Self::BlobCollector => "BlobCollector",
+            Self::ArticleFetcher => "ArticleFetcher",
+            Self::TimelinePruner => "TimelinePruner",
         }
     }
 }
@@ -401,7 +409,13 @@ where
         let processor_id = self.projector.id();
         let interests = self.projector.interests();
         let mut tx = self.db.begin().await?;
-        let batch = ReadInputBatch::<P::Input>::read(&mut tx, processor_id, interests).await?;
+        let batch = ReadInputBatch::<P::Input>::read(
+            &mut tx,
+            processor_id,
+            interests,
+            self.projector.starts_at_journal_head(),
+        )
+        .await?;
 
         let produced = self.projector.project_batch(&mut tx, batch.inputs).await?;
         let mut recorded_events = RecordedEvents::with_capacity(produced.len());
@@ -435,6 +449,11 @@ impl<S, P> PostCommitWorker<S, P> {
     pub fn new(db: S, processor: P) -> Self {
         Self { db, processor }
     }
+
+    #[cfg(test)]
+    pub(crate) fn processor(&self) -> &P {
+        &self.processor
+    }
 }
 
 impl<S, P> EventWorker for PostCommitWorker<S, P>
@@ -454,7 +473,13 @@ where
         let processor_id = self.processor.id();
         let interests = self.processor.interests();
         let mut tx = self.db.begin().await?;
-        let batch = ReadInputBatch::<P::Input>::read(&mut tx, processor_id, interests).await?;
+        let batch = ReadInputBatch::<P::Input>::read(
+            &mut tx,
+            processor_id,
+            interests,
+            self.processor.starts_at_journal_head(),
+        )
+        .await?;
 
         tx.advance_cursor(&batch.scanned_cursor).await?;
         tx.commit().await?;
@@ -489,15 +514,26 @@ where
     /// Loads the processor cursor, reads interested events after it, and
     /// decodes them into typed inputs. Undecodable events are handled by the
     /// shared failure policy.
+    ///
+    /// With `from_head`, a processor without a committed cursor skips the
+    /// events journaled before its first read.
     async fn read<Tx>(
         tx: &mut Tx,
         processor_id: ProcessorId,
         interests: EventInterests,
+        from_head: bool,
     ) -> WorkerResult<Self>
     where
         Tx: EventJournal + Send,
     {
-        let cursor = tx.load_cursor(processor_id).await?;
+        let mut cursor = tx.load_cursor(processor_id).await?;
+        if from_head && *cursor.position() == EventCursorPos::initial() {
+            cursor = tx
+                .read_after(&cursor, EventInterests::empty())
+                .await?
+                .scanned_cursor()
+                .clone();
+        }
         let batch = tx.read_after(&cursor, interests).await?;
         let event_count = batch.events().len();
         let scanned_cursor = batch.scanned_cursor().clone();
@@ -1,4 +1,5 @@
 mod projection;
+pub mod query;
 mod source;
 mod update;
 
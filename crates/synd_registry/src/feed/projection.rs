@@ -5,8 +5,9 @@ use chrono::{DateTime, Utc};
 use tracing::debug;
 
 use crate::{
+    article::ArticleFetch,
     crawl::job::CrawlJobId,
-    db::{BlobDb, FeedDb, FeedRegistryDb},
+    db::{ArticleDb, BlobDb, FeedDb, FeedRegistryDb},
     entry::{Change, Entries},
     event::{
         CrawlJobFinishedEvent, EntryChangedEvent, EntryDiscoveredEvent, Event, EventInput,
@@ -129,10 +130,37 @@ impl AppliedFeedUpdate {
                 self.changed += 1;
                 EntryChangedEvent::new(self.feed_url.clone(), entry_id).into()
             }
+            Change::Backfilled(_) => return,
         };
         self.events.push(event);
     }
 
+    /// Queues the web pages of the discovered entries. The database keeps
+    /// them only for feeds a subscription asked full content for.
+    async fn enqueue_articles<T>(&self, tx: &mut T, update: &FeedUpdate) -> ProcessorResult<()>
+    where
+        T: ArticleDb + Send,
+    {
+        let feed_type = update.meta().r#type();
+        let fetches = update
+            .entry_changes()
+            .iter()
+            .filter_map(|change| match change {
+                Change::Discovered(entry) => Some(entry.entry()),
+                Change::Changed(_) | Change::Backfilled(_) => None,
+            })
+            .filter_map(|entry| {
+                let url = FeedUrl::parse(entry.website_url(feed_type)?).ok()?;
+                Some(ArticleFetch::new(entry.id().clone(), url))
+            })
+            .collect::<Vec<_>>();
+        if !fetches.is_empty() {
+            tx.enqueue_article_fetches(&self.feed_url, &fetches, update.source().seen_at)
+                .await?;
+        }
+        Ok(())
+    }
+
     fn log(&self) {
         debug!(
             feed_url = self.feed_url.as_str(),
@@ -192,7 +220,7 @@ impl Processor for FeedProj {
 impl<S> Projector<S> for FeedProj
 where
     S: FeedRegistryDb,
-    for<'tx> S::Tx<'tx>: BlobDb + FeedDb + Send,
+    for<'tx> S::Tx<'tx>: ArticleDb + BlobDb + FeedDb + Send,
 {
     async fn project(
         &mut self,
@@ -204,6 +232,7 @@ where
         };
         let update = FeedUpdateInput::observe(tx, source).await?.decide()?;
         let applied = AppliedFeedUpdate::apply(tx, &update).await?;
+        applied.enqueue_articles(tx, &update).await?;
         applied.log();
         Ok(applied.into_events())
     }
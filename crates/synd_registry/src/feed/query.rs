@@ -0,0 +1,25 @@
+use synd_feed::types::FeedUrl;
+
+use crate::{
+    query::{TimelineEntry, TimelineEntryCursor},
+    subscription::SubscriberId,
+};
+
+/// Query for the entry history of one feed the subscriber subscribes to,
+/// in the canonical entry order timelines share.
+#[derive(Debug, Clone)]
+pub struct FeedEntriesQuery {
+    pub subscriber_id: SubscriberId,
+    pub feed_url: FeedUrl,
+    pub after: Option<TimelineEntryCursor>,
+    pub first: usize,
+}
+
+/// Page of one feed's entries, including those that left the feed body or
+/// the timeline. Empty unless the subscriber subscribes to the feed.
+#[derive(Debug, Clone)]
+pub struct FeedEntriesPage {
+    pub nodes: Vec<TimelineEntry>,
+    pub has_next_page: bool,
+    pub end_cursor: Option<TimelineEntryCursor>,
+}
@@ -1,4 +1,7 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+};
 
 use chrono::{DateTime, Utc};
 use synd_feed::{
@@ -8,44 +11,57 @@ use synd_feed::{
 use tokio::sync::{Mutex, MutexGuard};
 
 use crate::{
+    article::{ArticleFetch, ArticleFetchOutcome},
     crawl::{
-        blob::{BlobRef, PutBlobCommand},
-        due::CrawlDueInput,
+        blob::{BlobCollection, BlobRef, PutBlobCommand},
+        due::{CrawlDueInput, PUBLISH_ACTIVITY_SAMPLE_SIZE, PublishActivity},
+        history::{AppendCrawlHistoryCommand, CrawlHistoryEntry, CrawlHistoryQuery},
         state::{CrawlState, UpsertCrawlStateCommand},
         target_list::{CrawlTarget, CrawlTargetState, FeedSubscriptions, SubscriptionPolicy},
     },
     db::{
-        BlobDb, CommitTx, CrawlStateDb, CrawlTargetDb, FeedDb, FeedRegistryDb, SubscriptionDb,
-        TimelineDb,
+        ArticleDb, BlobDb, CommitTx, CrawlHistoryDb, CrawlStateDb, CrawlTargetDb, FeedDb,
+        FeedRegistryDb, ReadMarkDb, SavedEntryDb, SubscriptionDb, TimelineDb,
     },
     entry::Entries,
     error::{RegistryDbError, RegistryDbResult},
     event::{
         Event, EventCursor, EventCursorPos, EventInterests, EventJournal, EventJournalAppend,
-        EventType, JournaledEvent, ProcessorId,
+        EventJournalCompact, EventType, JournalCompaction, JournaledEvent, ProcessorId,
     },
     feed::FeedUpdate,
     query::{
-        Subscriptions, SubscriptionsQuery, TimelineChangesPage, TimelineChangesQuery,
-        TimelineEntriesPage, TimelineEntriesQuery,
+        FeedEntriesPage, FeedEntriesQuery, SavedEntriesPage, SavedEntriesQuery, SubscriberSummary,
+        SubscribersPage, SubscribersQuery, Subscriptions, SubscriptionsQuery, TimelineChangesPage,
+        TimelineChangesQuery, TimelineEntriesPage, TimelineEntriesQuery, TimelineEntry,
+        TimelineSearchPage, TimelineSearchQuery,
     },
+    read::EntryReadState,
     subscription::{FeedSubscriptionAttrs, SubscriberId, Subscription, SubscriptionKey},
-    timeline::TimelineCatchup,
+    timeline::{
+        TimelineCatchup,
+        retention::{RetentionPurge, TimelinePrune, TimelineRetention},
+    },
 };
 
 /// Mutable registry state held by the in-memory adapter.
 #[derive(Debug, Clone, Default)]
 struct InMemoryState {
     journal: Vec<InMemoryJournalEntry>,
+    last_journal_position: i64,
     cursors: HashMap<ProcessorId, i64>,
     subscriptions: HashMap<SubscriptionKeyParts, Subscription>,
     crawl_targets: HashMap<String, CrawlTarget>,
     manual_requests: HashMap<String, DateTime<Utc>>,
     crawl_states: HashMap<String, CrawlState>,
+    crawl_history: HashMap<String, Vec<(CrawlHistoryEntry, Option<BlobRef>)>>,
     timeline_catchup_counts: HashMap<String, u64>,
     feeds: InMemoryFeeds,
-    blobs: HashMap<i64, Vec<u8>>,
+    blobs: HashMap<i64, InMemoryBlob>,
     next_blob_pk: i64,
+    read_marks: HashMap<(String, EntryId), DateTime<Utc>>,
+    saved_entries: HashMap<(String, EntryId), DateTime<Utc>>,
+    articles: HashMap<EntryId, InMemoryArticle>,
 }
 
 /// Current feed state owned by the in-memory `FeedDb` adapter.
@@ -54,6 +70,7 @@ struct InMemoryFeeds {
     meta: HashMap<FeedUrl, FeedMeta>,
     entries: HashMap<EntryId, SyndEntry>,
     membership: HashMap<FeedUrl, Vec<EntryId>>,
+    body_blobs: HashMap<FeedUrl, BlobRef>,
 }
 
 impl InMemoryFeeds {
@@ -71,10 +88,32 @@ impl InMemoryFeeds {
         }
         let feed_url = update.source().feed_url.clone();
         self.meta.insert(feed_url.clone(), update.meta().clone());
+        self.body_blobs
+            .insert(feed_url.clone(), update.source().body_blob);
         self.membership
             .insert(feed_url, update.membership().to_vec());
     }
 
+    /// Samples the feed's current members; the persistent adapter also samples
+    /// entries that already left the feed.
+    fn publish_activity(&self, feed_url: &FeedUrl) -> Option<PublishActivity> {
+        let mut order_times = self
+            .membership
+            .get(feed_url)?
+            .iter()
+            .filter_map(|entry_id| self.entries.get(entry_id))
+            .map(|entry| entry.order_key().as_datetime())
+            .collect::<Vec<_>>();
+        order_times.sort_unstable_by(|a, b| b.cmp(a));
+        order_times.truncate(PUBLISH_ACTIVITY_SAMPLE_SIZE as usize);
+
+        Some(PublishActivity {
+            sampled: u32::try_from(order_times.len()).ok()?,
+            oldest: *order_times.last()?,
+            newest: *order_times.first()?,
+        })
+    }
+
     fn load(&self, feed_urls: &[FeedUrl]) -> RegistryDbResult<HashMap<FeedUrl, Feed>> {
         feed_urls
             .iter()
@@ -148,6 +187,21 @@ impl SubscriptionKeyParts {
     }
 }
 
+/// Blob row stored by the in-memory adapter.
+#[derive(Debug, Clone)]
+struct InMemoryBlob {
+    bytes: Vec<u8>,
+    created_at: DateTime<Utc>,
+}
+
+/// Article fetch row stored by the in-memory adapter.
+#[derive(Debug, Clone)]
+struct InMemoryArticle {
+    fetch: ArticleFetch,
+    next_attempt_at: Option<DateTime<Utc>>,
+    article: Option<BlobRef>,
+}
+
 /// Journal row stored by the in-memory adapter.
 #[derive(Debug, Clone)]
 struct InMemoryJournalEntry {
@@ -201,9 +255,11 @@ impl EventJournalAppend for InMemoryRegistryTx<'_> {
     ) -> RegistryDbResult<EventType> {
         let event_type = event.event_type();
         let state = &mut self.state;
-        let position = i64::try_from(state.journal.len())
-            .map_err(|_| RegistryDbError::invariant("event journal position overflow"))?
-            .saturating_add(1);
+        let position = state
+            .last_journal_position
+            .checked_add(1)
+            .ok_or_else(|| RegistryDbError::invariant("event journal position overflow"))?;
+        state.last_journal_position = position;
         state.journal.push(InMemoryJournalEntry {
             position,
             event_type,
@@ -271,6 +327,36 @@ impl EventJournal for InMemoryRegistryTx<'_> {
     }
 }
 
+impl EventJournalCompact for InMemoryRegistryTx<'_> {
+    async fn compact_journal(
+        &mut self,
+        processors: &[ProcessorId],
+        limit: usize,
+    ) -> RegistryDbResult<JournalCompaction> {
+        let state = &mut self.state;
+        let Some(through) = processors
+            .iter()
+            .map(|processor| state.cursors.get(processor).copied())
+            .collect::<Option<Vec<_>>>()
+            .and_then(|positions| positions.into_iter().min())
+        else {
+            return Ok(JournalCompaction::default());
+        };
+
+        let consumed = state
+            .journal
+            .iter()
+            .take_while(|entry| entry.position <= through)
+            .count();
+        let removed = consumed.min(limit);
+        state.journal.drain(..removed);
+        Ok(JournalCompaction {
+            removed: removed as u64,
+            has_more: consumed > removed,
+        })
+    }
+}
+
 impl SubscriptionDb for InMemoryRegistryTx<'_> {
     async fn upsert_subscription(
         &mut self,
@@ -292,6 +378,7 @@ impl SubscriptionDb for InMemoryRegistryTx<'_> {
                 requirement: attrs.requirement,
                 category: attrs.category,
                 crawl_policy: attrs.crawl_policy,
+                fetch_full_content: attrs.fetch_full_content,
                 subscribed_at,
             },
         );
@@ -380,9 +467,82 @@ impl SubscriptionDb for InMemoryRegistryTx<'_> {
         });
         Ok(FeedSubscriptions::new(feed_url.clone(), subscriptions))
     }
+
+    async fn load_feed_subscribers(
+        &mut self,
+        feed_url: &FeedUrl,
+    ) -> RegistryDbResult<Vec<Subscription>> {
+        let mut subscriptions = self
+            .state
+            .subscriptions
+            .values()
+            .filter(|subscription| subscription.feed_url == *feed_url)
+            .cloned()
+            .collect::<Vec<_>>();
+        subscriptions.sort_by(|a, b| a.subscriber_id.as_str().cmp(b.subscriber_id.as_str()));
+        Ok(subscriptions)
+    }
+
+    async fn list_subscribers(
+        &mut self,
+        query: SubscribersQuery,
+    ) -> RegistryDbResult<SubscribersPage> {
+        let mut subscribers = self
+            .state
+            .subscriber_summaries()
+            .into_values()
+            .filter(|summary| {
+                query
+                    .after
+                    .as_ref()
+                    .is_none_or(|after| summary.subscriber_id.as_str() > after.as_str())
+            })
+            .collect::<Vec<_>>();
+        let has_next_page = subscribers.len() > query.first;
+        if has_next_page {
+            subscribers.truncate(query.first);
+        }
+        let end_cursor = subscribers
+            .last()
+            .map(|summary| summary.subscriber_id.clone());
+        Ok(SubscribersPage {
+            subscribers,
+            has_next_page,
+            end_cursor,
+        })
+    }
+
+    async fn load_subscriber(
+        &mut self,
+        subscriber_id: &SubscriberId,
+    ) -> RegistryDbResult<Option<SubscriberSummary>> {
+        Ok(self
+            .state
+            .subscriber_summaries()
+            .remove(subscriber_id.as_str()))
+    }
 }
 
 impl InMemoryState {
+    fn subscriber_summaries(&self) -> BTreeMap<String, SubscriberSummary> {
+        let mut summaries = BTreeMap::<String, SubscriberSummary>::new();
+        for subscription in self.subscriptions.values() {
+            summaries
+                .entry(subscription.subscriber_id.as_str().to_owned())
+                .and_modify(|summary| {
+                    summary.subscriptions += 1;
+                    summary.first_subscribed_at =
+                        summary.first_subscribed_at.min(subscription.subscribed_at);
+                })
+                .or_insert_with(|| SubscriberSummary {
+                    subscriber_id: subscription.subscriber_id.clone(),
+                    subscriptions: 1,
+                    first_subscribed_at: subscription.subscribed_at,
+                });
+        }
+        summaries
+    }
+
     fn crawl_due_input(&self, target: &CrawlTarget) -> Option<CrawlDueInput> {
         let CrawlTargetState::Active { effective_policy } = &target.state else {
             return None;
@@ -392,6 +552,13 @@ impl InMemoryState {
             polling: effective_policy.polling,
             manual_requested_at: self.manual_requests.get(target.feed_url.as_str()).copied(),
             state: self.crawl_states.get(target.feed_url.as_str()).cloned(),
+            activity: self.feeds.publish_activity(&target.feed_url),
+            hints: self
+                .feeds
+                .meta
+                .get(&target.feed_url)
+                .map(|meta| meta.update_hints().clone())
+                .unwrap_or_default(),
         })
     }
 }
@@ -467,7 +634,13 @@ impl BlobDb for InMemoryRegistryTx<'_> {
         let state = &mut self.state;
         state.next_blob_pk = state.next_blob_pk.saturating_add(1);
         let blob = BlobRef::new(state.next_blob_pk);
-        state.blobs.insert(blob.pk(), command.bytes);
+        state.blobs.insert(
+            blob.pk(),
+            InMemoryBlob {
+                bytes: command.bytes,
+                created_at: command.created_at,
+            },
+        );
         Ok(blob)
     }
 
@@ -476,9 +649,50 @@ impl BlobDb for InMemoryRegistryTx<'_> {
         state
             .blobs
             .get(&blob.pk())
-            .cloned()
+            .map(|stored| stored.bytes.clone())
             .ok_or_else(|| RegistryDbError::invariant(format!("blob not found: {}", blob.pk())))
     }
+
+    async fn delete_unreferenced_blobs(
+        &mut self,
+        created_before: DateTime<Utc>,
+        limit: usize,
+    ) -> RegistryDbResult<BlobCollection> {
+        let state = &mut self.state;
+        let referenced = state
+            .feeds
+            .body_blobs
+            .values()
+            .copied()
+            .chain(state.journal.iter().filter_map(|entry| match &entry.event {
+                Event::CrawlJobFinished(event) => event.body_blob,
+                _ => None,
+            }))
+            .chain(
+                state
+                    .articles
+                    .values()
+                    .filter_map(|article| article.article),
+            )
+            .map(BlobRef::pk)
+            .collect::<HashSet<_>>();
+        let mut collectable = state
+            .blobs
+            .iter()
+            .filter(|(pk, stored)| stored.created_at < created_before && !referenced.contains(*pk))
+            .map(|(pk, _)| *pk)
+            .collect::<Vec<_>>();
+        collectable.sort_unstable();
+
+        let removed = collectable.len().min(limit);
+        for pk in &collectable[..removed] {
+            state.blobs.remove(pk);
+        }
+        Ok(BlobCollection {
+            removed: removed as u64,
+            has_more: collectable.len() > removed,
+        })
+    }
 }
 
 impl CrawlStateDb for InMemoryRegistryTx<'_> {
@@ -508,6 +722,53 @@ impl CrawlStateDb for InMemoryRegistryTx<'_> {
     }
 }
 
+impl CrawlHistoryDb for InMemoryRegistryTx<'_> {
+    async fn load_last_crawl_body(
+        &mut self,
+        feed_url: &FeedUrl,
+    ) -> RegistryDbResult<Option<BlobRef>> {
+        let state = &self.state;
+        Ok(state
+            .crawl_history
+            .get(feed_url.as_str())
+            .and_then(|history| history.iter().rev().find_map(|(_, body_blob)| *body_blob)))
+    }
+
+    async fn append_crawl_history(
+        &mut self,
+        command: AppendCrawlHistoryCommand,
+    ) -> RegistryDbResult<()> {
+        let state = &mut self.state;
+        let history = state
+            .crawl_history
+            .entry(command.feed_url.as_str().to_owned())
+            .or_default();
+        history.push((command.entry, command.body_blob));
+        let excess = history.len().saturating_sub(command.retain);
+        history.drain(..excess);
+        Ok(())
+    }
+
+    async fn list_crawl_history(
+        &mut self,
+        query: CrawlHistoryQuery,
+    ) -> RegistryDbResult<Vec<CrawlHistoryEntry>> {
+        let state = &self.state;
+        Ok(state
+            .crawl_history
+            .get(query.feed_url.as_str())
+            .map(|history| {
+                history
+                    .iter()
+                    .rev()
+                    .take(query.limit)
+                    .map(|(entry, _)| entry.clone())
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
+
 impl FeedDb for InMemoryRegistryTx<'_> {
     async fn load_entries(&mut self, entry_ids: &[EntryId]) -> RegistryDbResult<Entries> {
         Ok(self.state.feeds.load_entries(entry_ids))
@@ -524,6 +785,21 @@ impl FeedDb for InMemoryRegistryTx<'_> {
     ) -> RegistryDbResult<HashMap<FeedUrl, Feed>> {
         self.state.feeds.load(feed_urls)
     }
+
+    async fn list_feed_entries(
+        &mut self,
+        _query: FeedEntriesQuery,
+    ) -> RegistryDbResult<FeedEntriesPage> {
+        Ok(FeedEntriesPage {
+            nodes: Vec::new(),
+            has_next_page: false,
+            end_cursor: None,
+        })
+    }
+
+    async fn delete_orphaned_entries(&mut self, _limit: usize) -> RegistryDbResult<RetentionPurge> {
+        Ok(RetentionPurge::default())
+    }
 }
 
 impl TimelineDb for InMemoryRegistryTx<'_> {
@@ -547,9 +823,33 @@ impl TimelineDb for InMemoryRegistryTx<'_> {
             changes: Vec::new(),
             seq: 0,
             has_more: false,
+            expired: false,
         })
     }
 
+    async fn load_timeline_seq(&mut self, _subscriber_id: &SubscriberId) -> RegistryDbResult<i64> {
+        Ok(0)
+    }
+
+    async fn search_timeline_entries(
+        &mut self,
+        _query: TimelineSearchQuery,
+    ) -> RegistryDbResult<TimelineSearchPage> {
+        Ok(TimelineSearchPage {
+            nodes: Vec::new(),
+            has_next_page: false,
+            end_cursor: None,
+        })
+    }
+
+    async fn load_timeline_entry(
+        &mut self,
+        _subscriber_id: &SubscriberId,
+        _entry_id: &EntryId,
+    ) -> RegistryDbResult<Option<TimelineEntry>> {
+        Ok(None)
+    }
+
     async fn catchup_subscribed_feed(
         &mut self,
         subscriber_id: &SubscriberId,
@@ -583,6 +883,255 @@ impl TimelineDb for InMemoryRegistryTx<'_> {
     ) -> RegistryDbResult<Option<SubscriberId>> {
         Ok(None)
     }
+
+    async fn touch_timeline_entries(
+        &mut self,
+        _subscriber_id: &SubscriberId,
+        _entry_ids: &[EntryId],
+    ) -> RegistryDbResult<Vec<FeedUrl>> {
+        Ok(Vec::new())
+    }
+
+    async fn remove_retired_timeline_entries(
+        &mut self,
+        _retention: &TimelineRetention,
+        _limit: usize,
+    ) -> RegistryDbResult<TimelinePrune> {
+        Ok(TimelinePrune::default())
+    }
+
+    async fn purge_timeline_tombstones(
+        &mut self,
+        _seq_window: u64,
+        _limit: usize,
+    ) -> RegistryDbResult<RetentionPurge> {
+        Ok(RetentionPurge::default())
+    }
+
+    async fn delete_timeline(&mut self, subscriber_id: &SubscriberId) -> RegistryDbResult<()> {
+        self.state
+            .read_marks
+            .retain(|(subscriber, _), _| subscriber != subscriber_id.as_str());
+        Ok(())
+    }
+}
+
+impl ArticleDb for InMemoryRegistryTx<'_> {
+    async fn enqueue_article_fetches(
+        &mut self,
+        feed_url: &FeedUrl,
+        fetches: &[ArticleFetch],
+        queued_at: DateTime<Utc>,
+    ) -> RegistryDbResult<()> {
+        let state = &mut self.state;
+        let opted_in = state.subscriptions.values().any(|subscription| {
+            subscription.feed_url == *feed_url && subscription.fetch_full_content
+        });
+        if !opted_in {
+            return Ok(());
+        }
+        for fetch in fetches {
+            state
+                .articles
+                .entry(fetch.entry_id.clone())
+                .or_insert_with(|| InMemoryArticle {
+                    fetch: fetch.clone(),
+                    next_attempt_at: Some(queued_at),
+                    article: None,
+                });
+        }
+        Ok(())
+    }
+
+    async fn load_due_article_fetches(
+        &mut self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> RegistryDbResult<Vec<ArticleFetch>> {
+        let state = &self.state;
+        let mut due = state
+            .articles
+            .values()
+            .filter_map(|article| {
+                let next_attempt_at = article.next_attempt_at?;
+                (next_attempt_at <= now).then_some((next_attempt_at, &article.fetch))
+            })
+            .collect::<Vec<_>>();
+        due.sort_by(|(left_at, left), (right_at, right)| {
+            left_at
+                .cmp(right_at)
+                .then_with(|| left.entry_id.as_str().cmp(right.entry_id.as_str()))
+        });
+        Ok(due
+            .into_iter()
+            .take(limit)
+            .map(|(_, fetch)| fetch.clone())
+            .collect())
+    }
+
+    async fn next_article_fetch_at(&mut self) -> RegistryDbResult<Option<DateTime<Utc>>> {
+        let state = &self.state;
+        Ok(state
+            .articles
+            .values()
+            .filter_map(|article| article.next_attempt_at)
+            .min())
+    }
+
+    async fn record_article_fetch(
+        &mut self,
+        entry_id: &EntryId,
+        outcome: ArticleFetchOutcome,
+    ) -> RegistryDbResult<()> {
+        let state = &mut self.state;
+        let Some(article) = state.articles.get_mut(entry_id) else {
+            return Ok(());
+        };
+        match outcome {
+            ArticleFetchOutcome::Fetched { article: blob, .. } => {
+                article.article = Some(blob);
+                article.next_attempt_at = None;
+            }
+            ArticleFetchOutcome::Retry {
+                attempts,
+                next_attempt_at,
+            } => {
+                article.fetch.attempts = attempts;
+                article.next_attempt_at = Some(next_attempt_at);
+            }
+            ArticleFetchOutcome::Abandoned { attempts } => {
+                article.fetch.attempts = attempts;
+                article.next_attempt_at = None;
+            }
+        }
+        Ok(())
+    }
+
+    async fn load_article(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+    ) -> RegistryDbResult<Option<BlobRef>> {
+        let state = &self.state;
+        let opted_in = state.subscriptions.values().any(|subscription| {
+            subscription.subscriber_id == *subscriber_id
+                && subscription.fetch_full_content
+                && state
+                    .feeds
+                    .membership
+                    .get(&subscription.feed_url)
+                    .is_some_and(|members| members.contains(entry_id))
+        });
+        if !opted_in {
+            return Ok(None);
+        }
+        Ok(state
+            .articles
+            .get(entry_id)
+            .and_then(|article| article.article))
+    }
+}
+
+impl ReadMarkDb for InMemoryRegistryTx<'_> {
+    async fn load_entry_read_states(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_ids: &[EntryId],
+    ) -> RegistryDbResult<Vec<EntryReadState>> {
+        let state = &self.state;
+        let mut states: Vec<EntryReadState> = Vec::new();
+        for entry_id in entry_ids {
+            if !state.feeds.entries.contains_key(entry_id)
+                || states.iter().any(|state| &state.entry_id == entry_id)
+            {
+                continue;
+            }
+            let read = state
+                .read_marks
+                .contains_key(&(subscriber_id.as_str().to_owned(), entry_id.clone()));
+            states.push(EntryReadState::new(entry_id.clone(), read));
+        }
+        Ok(states)
+    }
+
+    async fn mark_entries_read(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_ids: &[EntryId],
+        read_at: DateTime<Utc>,
+    ) -> RegistryDbResult<()> {
+        let state = &mut self.state;
+        for entry_id in entry_ids {
+            state
+                .read_marks
+                .entry((subscriber_id.as_str().to_owned(), entry_id.clone()))
+                .or_insert(read_at);
+        }
+        Ok(())
+    }
+
+    async fn unmark_entries_read(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_ids: &[EntryId],
+    ) -> RegistryDbResult<()> {
+        let state = &mut self.state;
+        for entry_id in entry_ids {
+            state
+                .read_marks
+                .remove(&(subscriber_id.as_str().to_owned(), entry_id.clone()));
+        }
+        Ok(())
+    }
+}
+
+impl SavedEntryDb for InMemoryRegistryTx<'_> {
+    async fn load_entry_saved(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+    ) -> RegistryDbResult<Option<bool>> {
+        let state = &self.state;
+        let saved = state
+            .saved_entries
+            .contains_key(&(subscriber_id.as_str().to_owned(), entry_id.clone()));
+        Ok((saved || state.feeds.entries.contains_key(entry_id)).then_some(saved))
+    }
+
+    async fn save_entry(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+        saved_at: DateTime<Utc>,
+    ) -> RegistryDbResult<()> {
+        self.state
+            .saved_entries
+            .entry((subscriber_id.as_str().to_owned(), entry_id.clone()))
+            .or_insert(saved_at);
+        Ok(())
+    }
+
+    async fn unsave_entry(
+        &mut self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+    ) -> RegistryDbResult<()> {
+        self.state
+            .saved_entries
+            .remove(&(subscriber_id.as_str().to_owned(), entry_id.clone()));
+        Ok(())
+    }
+
+    async fn list_saved_entries(
+        &mut self,
+        _query: SavedEntriesQuery,
+    ) -> RegistryDbResult<SavedEntriesPage> {
+        Ok(SavedEntriesPage {
+            nodes: Vec::new(),
+            has_next_page: false,
+            end_cursor: None,
+        })
+    }
 }
 
 fn cursor_position(position: &EventCursorPos) -> RegistryDbResult<i64> {
@@ -612,13 +1161,18 @@ mod tests {
 
     use super::*;
     use crate::{
-        api::ApiEvent,
+        api::{ApiEvent, ApiSubscriptionChange},
+        command::DeleteSubscriberCommand,
         command::SubscribeFeedCommand,
-        config::{FeedRegistryConfig, FeedRegistryWorkerConfig},
+        config::{FeedRegistryConfig, FeedRegistryWorkerConfig, SubscriptionQuotaConfig},
         crawl::policy::{CrawlPolicy, PollingInterval},
-        event::{FeedSubscribedEvent, RegistryEvent},
+        error::FeedRegistryError,
+        event::{
+            EventInput, EventWorker, FeedSubscribedEvent, PostCommitWorker, Processor,
+            ProcessorResult, RegistryEvent, Sink, Trigger,
+        },
         registry::FeedRegistry,
-        subscription::SubscribeOutcome,
+        subscription::{SubReject, SubscribeOutcome},
     };
 
     fn test_occurred_at() -> DateTime<Utc> {
@@ -654,6 +1208,7 @@ mod tests {
             requirement: None,
             category: None,
             crawl_policy: Some(CrawlPolicy::interval(interval(seconds))),
+            fetch_full_content: false,
         }
     }
 
@@ -661,7 +1216,7 @@ mod tests {
     async fn request_crawl_rejects_unknown_feed() -> anyhow::Result<()> {
         let db = InMemoryFeedRegistryDb::new();
         let config = FeedRegistryConfig::default();
-        let registry = FeedRegistry::builder(db, config)
+        let registry = FeedRegistry::builder(db, &config)
             .with_clock(Arc::new(TestClock(test_occurred_at())))
             .build();
 
@@ -682,7 +1237,7 @@ mod tests {
     async fn subscribe_records_fact_event() -> anyhow::Result<()> {
         let db = InMemoryFeedRegistryDb::new();
         let config = FeedRegistryConfig::default();
-        let registry = FeedRegistry::builder(db.clone(), config)
+        let registry = FeedRegistry::builder(db.clone(), &config)
             .with_clock(Arc::new(TestClock(test_occurred_at())))
             .build();
 
@@ -704,6 +1259,65 @@ mod tests {
         Ok(())
     }
 
+    /// Sink counting the subscriptions it is handed.
+    struct CountingSink {
+        from_head: bool,
+        seen: usize,
+    }
+
+    struct Subscribed;
+
+    impl EventInput for Subscribed {
+        const INTERESTS: &'static [EventType] = &[FeedSubscribedEvent::TYPE];
+
+        fn from_event(_event: Event, _occurred_at: DateTime<Utc>) -> ProcessorResult<Self> {
+            Ok(Self)
+        }
+    }
+
+    impl Processor for CountingSink {
+        type Input = Subscribed;
+
+        fn id(&self) -> ProcessorId {
+            ProcessorId::EntryNotifier
+        }
+
+        fn starts_at_journal_head(&self) -> bool {
+            self.from_head
+        }
+    }
+
+    impl Sink for CountingSink {
+        async fn sink(&mut self, _input: Subscribed) {
+            self.seen += 1;
+        }
+    }
+
+    #[tokio::test]
+    async fn processor_starting_at_journal_head_skips_earlier_events() -> anyhow::Result<()> {
+        for (from_head, seen) in [(false, 1), (true, 0)] {
+            let db = InMemoryFeedRegistryDb::new();
+            let config = FeedRegistryConfig::default();
+            let registry = FeedRegistry::builder(db.clone(), &config)
+                .with_clock(Arc::new(TestClock(test_occurred_at())))
+                .build();
+            registry
+                .subscribe(subscribe_command("journal-head", 3600))
+                .await?;
+
+            let mut worker = PostCommitWorker::new(db.clone(), CountingSink { from_head, seen: 0 });
+            worker.react(Trigger::Startup).await?;
+            assert_eq!(worker.processor().seen, seen, "from_head: {from_head}");
+
+            registry
+                .subscribe(subscribe_command("journal-head-later", 3600))
+                .await?;
+            worker.react(Trigger::Wake).await?;
+            assert_eq!(worker.processor().seen, seen + 1, "from_head: {from_head}");
+        }
+        Ok(())
+    }
+
     #[tokio::test]
     async fn runtime_subscribe_writes_subscription_immediately() -> anyhow::Result<()> {
         let db = InMemoryFeedRegistryDb::new();
@@ -757,6 +1371,7 @@ mod tests {
         registry
             .subscribe(SubscribeFeedCommand {
                 crawl_policy: None,
+                fetch_full_content: false,
                 ..subscribe_command("runtime-default-crawl-policy", 3600)
             })
             .await?;
@@ -809,7 +1424,19 @@ mod tests {
 
         let event = tokio::time::timeout(Duration::from_secs(1), api_events.recv()).await?;
         let event = event.map_err(|err| anyhow::anyhow!("api event recv failed: {err:?}"))?;
-        let ApiEvent::TimelineChanged(event) = event;
+        let ApiEvent::SubscriptionChanged(event) = event else {
+            anyhow::bail!("unexpected api event: {event:?}");
+        };
+        assert_eq!(event.change, ApiSubscriptionChange::Subscribed);
+        assert_eq!(event.feed_url, feed_url);
+
+        // The first timeline change of a subscriber has no published seq to
+        // resolve entries from.
+        let event = tokio::time::timeout(Duration::from_secs(1), api_events.recv()).await?;
+        let event = event.map_err(|err| anyhow::anyhow!("api event recv failed: {err:?}"))?;
+        let ApiEvent::TimelineChanged(event) = event else {
+            anyhow::bail!("unexpected api event: {event:?}");
+        };
         assert_eq!(event.subscriber_id, subscriber_id());
         assert_eq!(event.affected_feeds, vec![feed_url]);
 
@@ -849,4 +1476,94 @@ mod tests {
         drop(event_workers);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn subscribe_rejects_new_feed_over_quota() -> anyhow::Result<()> {
+        let db = InMemoryFeedRegistryDb::new();
+        let config = FeedRegistryConfig {
+            subscription_quota: SubscriptionQuotaConfig {
+                max_subscriptions: Some(1),
+            },
+            ..FeedRegistryConfig::default()
+        };
+        let registry = FeedRegistry::builder(db, &config).build();
+
+        registry
+            .subscribe(subscribe_command("quota-a", 3600))
+            .await?;
+        let changed = registry
+            .subscribe(subscribe_command("quota-a", 600))
+            .await?;
+        assert!(matches!(changed.outcome, SubscribeOutcome::Changed(_)));
+
+        let rejected = registry.subscribe(subscribe_command("quota-b", 3600)).await;
+        assert!(matches!(
+            rejected,
+            Err(FeedRegistryError::Rejected(SubReject::QuotaExceeded {
+                max_subscriptions: 1,
+                ..
+            }))
+        ));
+
+        let other = registry
+            .subscribe(SubscribeFeedCommand {
+                subscriber_id: SubscriberId::new("other"),
+                ..subscribe_command("quota-b", 3600)
+            })
+            .await?;
+        assert!(matches!(other.outcome, SubscribeOutcome::Subscribed(_)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lists_and_deletes_subscribers() -> anyhow::Result<()> {
+        let db = InMemoryFeedRegistryDb::new();
+        let config = FeedRegistryConfig::default();
+        let registry = FeedRegistry::builder(db, &config)
+            .with_clock(Arc::new(TestClock(test_occurred_at())))
+            .build();
+        registry
+            .subscribe(subscribe_command("list-a", 3600))
+            .await?;
+        registry
+            .subscribe(subscribe_command("list-b", 3600))
+            .await?;
+        registry
+            .subscribe(SubscribeFeedCommand {
+                subscriber_id: SubscriberId::new("other"),
+                ..subscribe_command("list-a", 3600)
+            })
+            .await?;
+
+        let page = registry
+            .list_subscribers(SubscribersQuery {
+                after: None,
+                first: 1,
+            })
+            .await?;
+        assert_eq!(
+            page.subscribers,
+            vec![SubscriberSummary {
+                subscriber_id: SubscriberId::new("other"),
+                subscriptions: 1,
+                first_subscribed_at: test_occurred_at(),
+            }]
+        );
+        assert!(page.has_next_page);
+
+        let output = registry
+            .delete_subscriber(DeleteSubscriberCommand {
+                subscriber_id: subscriber_id(),
+            })
+            .await?;
+        assert_eq!(output.unsubscribed, 2);
+        assert_eq!(registry.load_subscriber(&subscriber_id()).await?, None);
+        assert!(
+            registry
+                .load_subscriber(&SubscriberId::new("other"))
+                .await?
+                .is_some()
+        );
+        Ok(())
+    }
 }
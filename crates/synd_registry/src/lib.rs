@@ -2,6 +2,7 @@
 #![allow(async_fn_in_trait)]
 
 pub mod api;
+pub mod article;
 pub mod command;
 pub mod config;
 pub mod crawl;
@@ -13,18 +14,29 @@ pub mod feed;
 mod handler;
 #[cfg(any(test, feature = "test"))]
 pub mod in_memory;
+pub mod notify;
 pub mod query;
+pub mod read;
 pub mod registry;
+pub mod saved;
 pub mod subscription;
 pub mod timeline;
 
 pub use command::{
-    RequestCrawlCommand, RequestCrawlOutput, SubscribeFeedCommand, SubscribeFeedOutput,
-    UnsubscribeFeedCommand, UnsubscribeFeedOutput,
+    DeleteSubscriberCommand, DeleteSubscriberOutput, MarkEntriesReadCommand, MarkEntriesReadOutput,
+    MarkEntriesUnreadCommand, MarkEntriesUnreadOutput, RequestCrawlCommand, RequestCrawlOutput,
+    SaveEntryCommand, SaveEntryOutput, SubscribeFeedCommand, SubscribeFeedOutput,
+    UnsaveEntryCommand, UnsaveEntryOutput, UnsubscribeFeedCommand, UnsubscribeFeedOutput,
+};
+pub use config::{
+    ArticleFetchConfig, BlobCollectionConfig, CrawlDispatchConfig, FeedRegistryConfig,
+    FeedRegistryWorkerConfig, JournalCompactionConfig, SubscriptionQuotaConfig,
+    TimelineRetentionConfig,
 };
-pub use config::{CrawlDispatchConfig, FeedRegistryConfig, FeedRegistryWorkerConfig};
 pub use crawl::request::{CrawlRequestReject, RequestCrawlOutcome};
-pub use crawl::worker::{CrawlWorkerFetchConfig, CrawlWorkerPoolConfig, CrawlWorkerQueueConfig};
+pub use crawl::worker::{
+    CrawlWorkerFetchConfig, CrawlWorkerHostConfig, CrawlWorkerPoolConfig, CrawlWorkerQueueConfig,
+};
 pub use error::{FeedRegistryError, RegistryDbError, RegistryDbResult};
 #[cfg(any(test, feature = "test"))]
 pub use in_memory::{InMemoryFeedRegistryDb, InMemoryRegistryTx};
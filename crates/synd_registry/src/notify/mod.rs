@@ -0,0 +1,10 @@
+//! Notification stream for newly discovered entries.
+
+mod notification;
+mod notifier;
+
+pub use notification::EntryNotification;
+pub use notifier::{
+    EntryNotificationPublisher, EntryNotificationRecvError, EntryNotificationSubscriber,
+    EntryNotifier, EntryNotifierInput,
+};
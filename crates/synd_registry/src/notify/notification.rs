@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+use synd_feed::{
+    entry::Entry,
+    types::{Feed, Text},
+};
+
+use crate::subscription::Subscription;
+
+/// A newly discovered entry of one subscription, resolved for delivery
+/// outside the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryNotification {
+    pub subscription: Subscription,
+    pub feed_title: Option<String>,
+    pub entry: Entry,
+    /// Web page of the entry, resolved with the feed format.
+    pub entry_url: Option<String>,
+    pub discovered_at: DateTime<Utc>,
+}
+
+impl EntryNotification {
+    pub(crate) fn new(
+        subscription: Subscription,
+        feed: Option<&Feed>,
+        entry: Entry,
+        discovered_at: DateTime<Utc>,
+    ) -> Self {
+        let feed_title = feed
+            .and_then(|feed| feed.meta().title())
+            .map(|title| title.content().to_owned());
+        let entry_url = feed
+            .and_then(|feed| entry.website_url(feed.meta().r#type()))
+            .map(ToOwned::to_owned);
+        Self {
+            subscription,
+            feed_title,
+            entry,
+            entry_url,
+            discovered_at,
+        }
+    }
+
+    /// Whether the entry was published after the subscription was made.
+    ///
+    /// The first crawl of a feed discovers its whole backlog, so entries
+    /// published earlier are not news to the subscriber. Undated entries
+    /// count as fresh: the notifier only sees entries discovered after it
+    /// started, so they were just discovered.
+    pub fn is_fresh(&self) -> bool {
+        self.entry
+            .published()
+            .or(self.entry.updated())
+            .is_none_or(|published| published >= self.subscription.subscribed_at)
+    }
+
+    pub fn entry_title(&self) -> Option<&str> {
+        self.entry.title().map(Text::content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use synd_feed::{
+        entry::EntryId,
+        types::{FeedUrl, Time},
+    };
+
+    use crate::{crawl::policy::CrawlPolicy, subscription::SubscriberId};
+
+    use super::*;
+
+    fn notification(published: Option<&str>, updated: Option<&str>) -> EntryNotification {
+        let entry = Entry::builder()
+            .id(EntryId::parse(format!("synd:entry:v1:{}", "a".repeat(64))).unwrap())
+            .maybe_published(published.map(time))
+            .maybe_updated(updated.map(time))
+            .build();
+        let subscription = Subscription {
+            subscriber_id: SubscriberId::new("reader"),
+            feed_url: FeedUrl::parse("https://example.com/feed.xml").unwrap(),
+            requirement: None,
+            category: None,
+            crawl_policy: CrawlPolicy::manual(),
+            fetch_full_content: false,
+            subscribed_at: time("2026-01-10T00:00:00Z"),
+        };
+        EntryNotification::new(subscription, None, entry, time("2026-01-20T00:00:00Z"))
+    }
+
+    fn time(value: &str) -> Time {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn only_entries_published_after_subscribing_are_fresh() {
+        assert!(notification(Some("2026-01-15T00:00:00Z"), None).is_fresh());
+        assert!(notification(None, Some("2026-01-15T00:00:00Z")).is_fresh());
+        assert!(!notification(Some("2026-01-05T00:00:00Z"), None).is_fresh());
+    }
+
+    #[test]
+    fn undated_entries_are_fresh() {
+        assert!(notification(None, None).is_fresh());
+    }
+}
@@ -0,0 +1,205 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::broadcast;
+use tracing::{debug, warn};
+
+use crate::{
+    db::{CommitTx, FeedDb, FeedRegistryDb, SubscriptionDb},
+    error::RegistryDbResult,
+    event::{
+        EntryDiscoveredEvent, Event, EventInput, EventType, Processor, ProcessorError, ProcessorId,
+        ProcessorResult, RegistryEvent, Sink,
+    },
+    notify::EntryNotification,
+};
+
+/// Resolves discovered entries into notifications for every subscription
+/// of their feed, and broadcasts the fresh ones.
+///
+/// The notifier starts at the journal head the first time it runs, so
+/// entries discovered before it existed are never notified.
+///
+/// While nobody listens, discovered entries are consumed and dropped rather
+/// than resolved. Holding the cursor instead would block journal compaction
+/// on daemons without notification sinks, and a listener that attaches
+/// later only wants entries discovered from then on.
+pub struct EntryNotifier<S> {
+    db: S,
+    publisher: EntryNotificationPublisher,
+}
+
+impl<S> EntryNotifier<S> {
+    pub fn new(db: S, publisher: EntryNotificationPublisher) -> Self {
+        Self { db, publisher }
+    }
+}
+
+impl<S> EntryNotifier<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: FeedDb + SubscriptionDb,
+{
+    async fn resolve(&self, input: EntryNotifierInput) -> RegistryDbResult<Vec<EntryNotification>> {
+        let EntryNotifierInput {
+            event: EntryDiscoveredEvent { feed_url, entry_id },
+            discovered_at,
+        } = input;
+
+        let mut tx = self.db.begin().await?;
+        let subscriptions = tx.load_feed_subscribers(&feed_url).await?;
+        if subscriptions.is_empty() {
+            tx.commit().await?;
+            return Ok(Vec::new());
+        }
+        let entry = tx
+            .load_entries(std::slice::from_ref(&entry_id))
+            .await?
+            .remove(&entry_id);
+        let feed = tx
+            .load_feeds(std::slice::from_ref(&feed_url))
+            .await?
+            .remove(&feed_url);
+        tx.commit().await?;
+
+        let Some(entry) = entry else {
+            return Ok(Vec::new());
+        };
+        Ok(subscriptions
+            .into_iter()
+            .map(|subscription| {
+                EntryNotification::new(
+                    subscription,
+                    feed.as_ref(),
+                    entry.entry().clone(),
+                    discovered_at,
+                )
+            })
+            .filter(EntryNotification::is_fresh)
+            .collect())
+    }
+}
+
+impl<S> Processor for EntryNotifier<S>
+where
+    S: Send + 'static,
+{
+    type Input = EntryNotifierInput;
+
+    fn id(&self) -> ProcessorId {
+        ProcessorId::EntryNotifier
+    }
+
+    fn starts_at_journal_head(&self) -> bool {
+        true
+    }
+}
+
+impl<S> Sink for EntryNotifier<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: FeedDb + SubscriptionDb,
+{
+    async fn sink(&mut self, input: Self::Input) {
+        if !self.publisher.has_receivers() {
+            return;
+        }
+        let entry_id = input.event.entry_id.clone();
+        match self.resolve(input).await {
+            Ok(notifications) => {
+                let count = notifications.len();
+                for notification in notifications {
+                    self.publisher.publish(notification);
+                }
+                debug!(%entry_id, count, "registry entry notifier delivered notifications");
+            }
+            Err(err) => {
+                warn!(%entry_id, error = %err, "registry entry notifier failed to resolve entry");
+            }
+        }
+    }
+}
+
+/// Event input consumed by [`EntryNotifier`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryNotifierInput {
+    pub event: EntryDiscoveredEvent,
+    pub discovered_at: DateTime<Utc>,
+}
+
+impl EventInput for EntryNotifierInput {
+    const INTERESTS: &'static [EventType] = &[EntryDiscoveredEvent::TYPE];
+
+    fn from_event(event: Event, occurred_at: DateTime<Utc>) -> ProcessorResult<Self> {
+        match event {
+            Event::EntryDiscovered(event) => Ok(Self {
+                event,
+                discovered_at: occurred_at,
+            }),
+            event => Err(ProcessorError::unexpected_input("entry notifier", &event)),
+        }
+    }
+}
+
+/// Broadcasts entry notifications to in-process listeners.
+#[derive(Clone)]
+pub struct EntryNotificationPublisher {
+    sender: broadcast::Sender<EntryNotification>,
+}
+
+impl EntryNotificationPublisher {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> EntryNotificationSubscriber {
+        EntryNotificationSubscriber {
+            receiver: self.sender.subscribe(),
+        }
+    }
+
+    pub fn has_receivers(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+
+    pub fn publish(&self, notification: EntryNotification) -> usize {
+        self.sender.send(notification).unwrap_or_default()
+    }
+}
+
+impl fmt::Debug for EntryNotificationPublisher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EntryNotificationPublisher")
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for EntryNotificationPublisher {
+    fn default() -> Self {
+        Self::new(256)
+    }
+}
+
+/// Receives notifications for every subscriber.
+pub struct EntryNotificationSubscriber {
+    receiver: broadcast::Receiver<EntryNotification>,
+}
+
+impl EntryNotificationSubscriber {
+    pub async fn recv(&mut self) -> Result<EntryNotification, EntryNotificationRecvError> {
+        self.receiver.recv().await.map_err(|err| match err {
+            broadcast::error::RecvError::Closed => EntryNotificationRecvError::Closed,
+            broadcast::error::RecvError::Lagged(skipped) => {
+                EntryNotificationRecvError::Lagged(skipped)
+            }
+        })
+    }
+}
+
+/// Error returned while receiving entry notifications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryNotificationRecvError {
+    Closed,
+    Lagged(u64),
+}
@@ -1,7 +1,15 @@
 pub use crate::{
-    subscription::query::{Subscriptions, SubscriptionsQuery},
+    crawl::history::{CrawlHistoryEntry, CrawlHistoryQuery},
+    feed::query::{FeedEntriesPage, FeedEntriesQuery},
+    saved::query::{
+        SavedEntriesPage, SavedEntriesQuery, SavedEntry, SavedEntryCursor, SavedEntryCursorError,
+    },
+    subscription::query::{
+        SubscriberSummary, SubscribersPage, SubscribersQuery, Subscriptions, SubscriptionsQuery,
+    },
     timeline::query::{
-        TimelineChange, TimelineChangesPage, TimelineChangesQuery, TimelineEntriesPage,
-        TimelineEntriesQuery, TimelineEntry, TimelineEntryCursor, TimelineEntryCursorError,
+        TimelineChange, TimelineChangesPage, TimelineChangesQuery, TimelineEntriesFilter,
+        TimelineEntriesPage, TimelineEntriesQuery, TimelineEntry, TimelineEntryCursor,
+        TimelineEntryCursorError, TimelineSearchPage, TimelineSearchQuery,
     },
 };
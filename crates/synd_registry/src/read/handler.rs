@@ -0,0 +1,232 @@
+use std::sync::Arc;
+
+use synd_feed::entry::EntryId;
+use synd_support::time::Clock;
+use tracing::info;
+
+use crate::{
+    command::{
+        MarkEntriesReadCommand, MarkEntriesReadOutput, MarkEntriesUnreadCommand,
+        MarkEntriesUnreadOutput,
+    },
+    db::{CommitTx, FeedRegistryDb, ReadMarkDb},
+    error::FeedRegistryError,
+    event::{
+        EntriesMarkedReadEvent, EntriesMarkedUnreadEvent, Event, EventJournalAppend, EventRecorder,
+        RecordedEvents,
+    },
+    handler::{CommandHandler, HandledCommand},
+    read::{EntryReadState, ReadMark},
+    subscription::SubscriberId,
+};
+
+/// Decision made for one mark request: the entries whose read state flips
+/// and the fact to record when any did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ReadMarkDecision {
+    changed: Vec<EntryId>,
+    event: Option<Event>,
+}
+
+impl ReadMarkDecision {
+    /// Pure decision over the current read state of the requested entries.
+    /// Entries already in the requested state are no-ops, so repeating a
+    /// request records nothing.
+    fn decide(subscriber_id: &SubscriberId, mark: ReadMark, states: &[EntryReadState]) -> Self {
+        let changed: Vec<EntryId> = states
+            .iter()
+            .filter(|state| state.read != mark.is_read())
+            .map(|state| state.entry_id.clone())
+            .collect();
+        let event = (!changed.is_empty()).then(|| match mark {
+            ReadMark::Read => {
+                EntriesMarkedReadEvent::new(subscriber_id.clone(), changed.clone()).into()
+            }
+            ReadMark::Unread => {
+                EntriesMarkedUnreadEvent::new(subscriber_id.clone(), changed.clone()).into()
+            }
+        });
+        Self { changed, event }
+    }
+}
+
+/// Handles read-marker commands as subscriber-scoped state plus journaled
+/// facts. The timeline projection turns those facts into timeline changes so
+/// other clients pick up the new state.
+#[derive(Clone)]
+pub(crate) struct ReadMarkHandler<S> {
+    db: S,
+    clock: Arc<dyn Clock>,
+}
+
+impl<S> ReadMarkHandler<S> {
+    pub(crate) fn new(db: S, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
+    }
+}
+
+impl<S> ReadMarkHandler<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: ReadMarkDb + EventJournalAppend,
+{
+    async fn handle_mark(
+        &self,
+        subscriber_id: SubscriberId,
+        entry_ids: Vec<EntryId>,
+        mark: ReadMark,
+    ) -> Result<HandledCommand<Vec<EntryId>>, FeedRegistryError> {
+        let mut tx = self.db.begin().await?;
+        let states = tx
+            .load_entry_read_states(&subscriber_id, &entry_ids)
+            .await?;
+        let decision = ReadMarkDecision::decide(&subscriber_id, mark, &states);
+
+        if !decision.changed.is_empty() {
+            match mark {
+                ReadMark::Read => {
+                    tx.mark_entries_read(&subscriber_id, &decision.changed, self.clock.now())
+                        .await?;
+                }
+                ReadMark::Unread => {
+                    tx.unmark_entries_read(&subscriber_id, &decision.changed)
+                        .await?;
+                }
+            }
+        }
+        let mut recorded_events = RecordedEvents::with_capacity(1);
+        EventRecorder::new(&mut tx, &mut recorded_events, self.clock.as_ref())
+            .record_all(decision.event)
+            .await?;
+        tx.commit().await?;
+
+        info!(
+            subscriber_id = subscriber_id.as_str(),
+            mark = mark.as_str(),
+            requested = entry_ids.len(),
+            changed = decision.changed.len(),
+            "read mark committed"
+        );
+
+        Ok(HandledCommand {
+            output: decision.changed,
+            recorded_events,
+        })
+    }
+}
+
+impl<S> CommandHandler<MarkEntriesReadCommand> for ReadMarkHandler<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: ReadMarkDb + EventJournalAppend,
+{
+    type Output = MarkEntriesReadOutput;
+    type Error = FeedRegistryError;
+
+    async fn handle(
+        &self,
+        command: MarkEntriesReadCommand,
+    ) -> Result<HandledCommand<Self::Output>, Self::Error> {
+        let handled = self
+            .handle_mark(command.subscriber_id, command.entry_ids, ReadMark::Read)
+            .await?;
+        Ok(HandledCommand {
+            output: MarkEntriesReadOutput {
+                changed: handled.output,
+            },
+            recorded_events: handled.recorded_events,
+        })
+    }
+}
+
+impl<S> CommandHandler<MarkEntriesUnreadCommand> for ReadMarkHandler<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: ReadMarkDb + EventJournalAppend,
+{
+    type Output = MarkEntriesUnreadOutput;
+    type Error = FeedRegistryError;
+
+    async fn handle(
+        &self,
+        command: MarkEntriesUnreadCommand,
+    ) -> Result<HandledCommand<Self::Output>, Self::Error> {
+        let handled = self
+            .handle_mark(command.subscriber_id, command.entry_ids, ReadMark::Unread)
+            .await?;
+        Ok(HandledCommand {
+            output: MarkEntriesUnreadOutput {
+                changed: handled.output,
+            },
+            recorded_events: handled.recorded_events,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subscriber_id() -> SubscriberId {
+        SubscriberId::new("reader")
+    }
+
+    fn entry_id(digit: char) -> EntryId {
+        EntryId::parse(format!("synd:entry:v1:{}", digit.to_string().repeat(64))).unwrap()
+    }
+
+    #[test]
+    fn marks_only_unread_entries_read() {
+        let states = vec![
+            EntryReadState::new(entry_id('1'), false),
+            EntryReadState::new(entry_id('2'), true),
+        ];
+
+        let decision = ReadMarkDecision::decide(&subscriber_id(), ReadMark::Read, &states);
+
+        assert_eq!(
+            decision,
+            ReadMarkDecision {
+                changed: vec![entry_id('1')],
+                event: Some(
+                    EntriesMarkedReadEvent::new(subscriber_id(), vec![entry_id('1')]).into()
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn marks_only_read_entries_unread() {
+        let states = vec![
+            EntryReadState::new(entry_id('1'), false),
+            EntryReadState::new(entry_id('2'), true),
+        ];
+
+        let decision = ReadMarkDecision::decide(&subscriber_id(), ReadMark::Unread, &states);
+
+        assert_eq!(
+            decision,
+            ReadMarkDecision {
+                changed: vec![entry_id('2')],
+                event: Some(
+                    EntriesMarkedUnreadEvent::new(subscriber_id(), vec![entry_id('2')]).into()
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn records_nothing_when_state_already_matches() {
+        let states = vec![EntryReadState::new(entry_id('1'), true)];
+
+        let decision = ReadMarkDecision::decide(&subscriber_id(), ReadMark::Read, &states);
+
+        assert_eq!(
+            decision,
+            ReadMarkDecision {
+                changed: Vec::new(),
+                event: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,40 @@
+//! Subscriber-scoped read markers on timeline entries.
+
+use synd_feed::entry::EntryId;
+
+mod handler;
+
+pub(crate) use handler::ReadMarkHandler;
+
+/// Read state of one live timeline entry as seen by its subscriber.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryReadState {
+    pub entry_id: EntryId,
+    pub read: bool,
+}
+
+impl EntryReadState {
+    pub fn new(entry_id: EntryId, read: bool) -> Self {
+        Self { entry_id, read }
+    }
+}
+
+/// Read state requested by a mark command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadMark {
+    Read,
+    Unread,
+}
+
+impl ReadMark {
+    pub fn is_read(self) -> bool {
+        matches!(self, Self::Read)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Unread => "unread",
+        }
+    }
+}
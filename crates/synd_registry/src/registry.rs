@@ -1,41 +1,58 @@
 use std::{collections::HashMap, sync::Arc, time::Duration};
 
-use synd_feed::feed::service::FeedService;
+use synd_feed::entry::EntryId;
+use synd_feed::feed::{discovery::FeedCandidate, service::FeedService};
 use synd_feed::types::{Feed, FeedUrl};
 use synd_support::time::{Clock, SystemClock};
 use tokio_util::sync::CancellationToken;
 
 use crate::{
-    api::{ApiEventPublisher, ApiEventSubscriber},
+    api::{ApiEventPublisher, ApiEventResolver, ApiEventSubscriber},
+    article::fetcher::ArticleFetcher,
     command::{
-        RequestCrawlCommand, RequestCrawlOutput, SubscribeFeedCommand, SubscribeFeedOutput,
+        DeleteSubscriberCommand, DeleteSubscriberOutput, MarkEntriesReadCommand,
+        MarkEntriesReadOutput, MarkEntriesUnreadCommand, MarkEntriesUnreadOutput,
+        RequestCrawlCommand, RequestCrawlOutput, SaveEntryCommand, SaveEntryOutput,
+        SubscribeFeedCommand, SubscribeFeedOutput, UnsaveEntryCommand, UnsaveEntryOutput,
         UnsubscribeFeedCommand, UnsubscribeFeedOutput,
     },
     config::FeedRegistryConfig,
     crawl::{
+        collector::BlobCollector,
         dispatch::{DispatchQueueReader, DispatchQueueWriter, InflightCrawls, dispatch_queue},
         dispatcher::CrawlDispatcher,
+        host::{HostBackoff, HostBackoffBoard},
         request::CrawlRequestHandler,
         target_list::CrawlTargetProj,
-        worker::CrawlWorkerPool,
+        worker::{CrawlHostCapacity, CrawlWorkerPool},
     },
     db::{
-        BlobDb, CommitTx, CrawlStateDb, CrawlTargetDb, FeedDb, FeedRegistryDb, SubscriptionDb,
-        TimelineDb,
+        ArticleDb, BlobDb, CommitTx, CrawlHistoryDb, CrawlStateDb, CrawlTargetDb, FeedDb,
+        FeedRegistryDb, ReadMarkDb, SavedEntryDb, SubscriptionDb, TimelineDb,
     },
     error::FeedRegistryError,
     event::{
-        EventJournal, EventJournalAppend, EventLoop, EventWakePublisher, JournalWorker,
-        PostCommitWorker, Projector, ReconcilerWorker, Sink, WorkerHandle, WorkerSet,
+        EventJournal, EventJournalAppend, EventLoop, EventWakePublisher, JournalCompactor,
+        JournalWorker, PostCommitWorker, Projector, ReconcilerWorker, Sink, WorkerHandle,
+        WorkerSet,
     },
     feed::FeedProj,
     handler::CommandHandler,
+    notify::{EntryNotificationPublisher, EntryNotificationSubscriber, EntryNotifier},
     query::{
-        Subscriptions, SubscriptionsQuery, TimelineChangesPage, TimelineChangesQuery,
-        TimelineEntriesPage, TimelineEntriesQuery,
+        CrawlHistoryEntry, CrawlHistoryQuery, FeedEntriesPage, FeedEntriesQuery, SavedEntriesPage,
+        SavedEntriesQuery, SubscriberSummary, SubscribersPage, SubscribersQuery, Subscriptions,
+        SubscriptionsQuery, TimelineChangesPage, TimelineChangesQuery, TimelineEntriesPage,
+        TimelineEntriesQuery, TimelineEntry, TimelineSearchPage, TimelineSearchQuery,
     },
+    read::ReadMarkHandler,
+    saved::SavedEntryHandler,
     subscription::{SubHandler, SubscriberId},
-    timeline::TimelineProj,
+    timeline::{
+        TimelineProj,
+        handler::{DeleteTimelineCommand, TimelineHandler},
+        retention::TimelinePruner,
+    },
 };
 
 /// Builds a registry facade with shared dispatch channels and clock wiring.
@@ -43,6 +60,7 @@ pub(crate) struct FeedRegistryBuilder<S> {
     db: S,
     config: FeedRegistryConfig,
     event_dispatch: EventDispatch,
+    host_backoffs: HostBackoffBoard,
     clock: Arc<dyn Clock>,
 }
 
@@ -50,11 +68,12 @@ impl<S> FeedRegistryBuilder<S>
 where
     S: Clone,
 {
-    fn new(db: S, config: FeedRegistryConfig) -> Self {
+    fn new(db: S, config: &FeedRegistryConfig) -> Self {
         Self {
             db,
-            config,
+            config: *config,
             event_dispatch: EventDispatch::new(config),
+            host_backoffs: HostBackoffBoard::new(),
             clock: Arc::new(SystemClock),
         }
     }
@@ -69,6 +88,10 @@ where
         &self.event_dispatch
     }
 
+    fn host_backoffs(&self) -> &HostBackoffBoard {
+        &self.host_backoffs
+    }
+
     fn clock(&self) -> &Arc<dyn Clock> {
         &self.clock
     }
@@ -78,15 +101,23 @@ where
             subscriptions: SubHandler::new(
                 self.db.clone(),
                 self.config.default_crawl_policy,
+                self.config.subscription_quota,
                 Arc::clone(&self.clock),
             ),
             crawl_requests: CrawlRequestHandler::new(self.db.clone(), Arc::clone(&self.clock)),
+            read_marks: ReadMarkHandler::new(self.db.clone(), Arc::clone(&self.clock)),
+            saved_entries: SavedEntryHandler::new(self.db.clone(), Arc::clone(&self.clock)),
+            timelines: TimelineHandler::new(self.db.clone(), Arc::clone(&self.clock)),
         };
 
+        let discovery = self.config.crawl_worker_pool.fetch.feed_service();
+
         FeedRegistry {
             db: self.db,
             handlers,
             event_dispatch: self.event_dispatch,
+            host_backoffs: self.host_backoffs,
+            discovery,
         }
     }
 }
@@ -96,6 +127,9 @@ where
 pub(crate) struct RegistryHandlers<S> {
     subscriptions: SubHandler<S>,
     crawl_requests: CrawlRequestHandler<S>,
+    read_marks: ReadMarkHandler<S>,
+    saved_entries: SavedEntryHandler<S>,
+    timelines: TimelineHandler<S>,
 }
 
 /// Facade for registry commands, queries, and API event subscriptions.
@@ -104,6 +138,25 @@ pub struct FeedRegistry<S> {
     db: S,
     handlers: RegistryHandlers<S>,
     event_dispatch: EventDispatch,
+    host_backoffs: HostBackoffBoard,
+    discovery: FeedService,
+}
+
+impl<S> FeedRegistry<S> {
+    /// Feeds the URL leads to, best candidate first. Nothing is recorded;
+    /// subscribers pick a candidate and subscribe to it.
+    pub async fn discover_feeds(
+        &self,
+        url: FeedUrl,
+    ) -> Result<Vec<FeedCandidate>, FeedRegistryError> {
+        Ok(self.discovery.discover_feeds(url).await?)
+    }
+
+    /// Hosts the crawl dispatcher currently holds back after a `429`/`503`,
+    /// as of its latest pass.
+    pub fn crawl_host_backoffs(&self) -> Vec<HostBackoff> {
+        self.host_backoffs.snapshot()
+    }
 }
 
 impl<S> FeedRegistry<S>
@@ -111,7 +164,7 @@ where
     S: FeedRegistryDb,
     for<'tx> S::Tx<'tx>: EventJournalAppend + SubscriptionDb,
 {
-    pub(crate) fn builder(db: S, config: FeedRegistryConfig) -> FeedRegistryBuilder<S> {
+    pub(crate) fn builder(db: S, config: &FeedRegistryConfig) -> FeedRegistryBuilder<S> {
         FeedRegistryBuilder::new(db, config)
     }
 
@@ -119,6 +172,11 @@ where
         self.event_dispatch.api_events.subscribe(subscriber_id)
     }
 
+    /// Receives fresh entries of every subscriber as their feeds discover them.
+    pub fn subscribe_notifications(&self) -> EntryNotificationSubscriber {
+        self.event_dispatch.notifications.subscribe()
+    }
+
     pub async fn subscribe(
         &self,
         command: SubscribeFeedCommand,
@@ -162,7 +220,75 @@ where
 impl<S> FeedRegistry<S>
 where
     S: FeedRegistryDb,
-    for<'tx> S::Tx<'tx>: BlobDb
+    for<'tx> S::Tx<'tx>: ReadMarkDb + EventJournalAppend,
+{
+    pub async fn mark_entries_read(
+        &self,
+        command: MarkEntriesReadCommand,
+    ) -> Result<MarkEntriesReadOutput, FeedRegistryError> {
+        let handled = self.handlers.read_marks.handle(command).await?;
+        self.event_dispatch
+            .wake_publisher
+            .publish(handled.recorded_events);
+        Ok(handled.output)
+    }
+
+    pub async fn mark_entries_unread(
+        &self,
+        command: MarkEntriesUnreadCommand,
+    ) -> Result<MarkEntriesUnreadOutput, FeedRegistryError> {
+        let handled = self.handlers.read_marks.handle(command).await?;
+        self.event_dispatch
+            .wake_publisher
+            .publish(handled.recorded_events);
+        Ok(handled.output)
+    }
+}
+
+impl<S> FeedRegistry<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: SavedEntryDb + EventJournalAppend,
+{
+    pub async fn save_entry(
+        &self,
+        command: SaveEntryCommand,
+    ) -> Result<SaveEntryOutput, FeedRegistryError> {
+        let handled = self.handlers.saved_entries.handle(command).await?;
+        self.event_dispatch
+            .wake_publisher
+            .publish(handled.recorded_events);
+        Ok(handled.output)
+    }
+
+    pub async fn unsave_entry(
+        &self,
+        command: UnsaveEntryCommand,
+    ) -> Result<UnsaveEntryOutput, FeedRegistryError> {
+        let handled = self.handlers.saved_entries.handle(command).await?;
+        self.event_dispatch
+            .wake_publisher
+            .publish(handled.recorded_events);
+        Ok(handled.output)
+    }
+
+    pub async fn list_saved_entries(
+        &self,
+        query: SavedEntriesQuery,
+    ) -> Result<SavedEntriesPage, FeedRegistryError> {
+        let mut tx = self.db.begin().await?;
+        let page = tx.list_saved_entries(query).await?;
+        tx.commit().await?;
+        Ok(page)
+    }
+}
+
+impl<S> FeedRegistry<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: ArticleDb
+        + BlobDb
+        + CrawlHistoryDb
         + CrawlStateDb
         + CrawlTargetDb
         + FeedDb
@@ -171,16 +297,20 @@ where
         + EventJournalAppend,
 {
     pub fn start(db: S, config: FeedRegistryConfig, ct: CancellationToken) -> (Self, WorkerSet) {
-        let builder = FeedRegistry::builder(db.clone(), config);
+        let builder = FeedRegistry::builder(db.clone(), &config);
         let event_dispatch = builder.event_dispatch();
         let workers = WorkerSpawnCtx::new(
             db,
             event_dispatch.wake_publisher.clone(),
-            config,
+            &config,
             ct,
             Arc::clone(builder.clock()),
         )
-        .spawn_all(event_dispatch.api_events.clone());
+        .spawn_all(
+            event_dispatch.api_events.clone(),
+            event_dispatch.notifications.clone(),
+            builder.host_backoffs().clone(),
+        );
         let registry = builder.build();
 
         (registry, workers)
@@ -201,6 +331,122 @@ where
         tx.commit().await?;
         Ok(page)
     }
+
+    pub async fn list_subscribers(
+        &self,
+        query: SubscribersQuery,
+    ) -> Result<SubscribersPage, FeedRegistryError> {
+        let mut tx = self.db.begin().await?;
+        let page = tx.list_subscribers(query).await?;
+        tx.commit().await?;
+        Ok(page)
+    }
+
+    pub async fn load_subscriber(
+        &self,
+        subscriber_id: &SubscriberId,
+    ) -> Result<Option<SubscriberSummary>, FeedRegistryError> {
+        let mut tx = self.db.begin().await?;
+        let summary = tx.load_subscriber(subscriber_id).await?;
+        tx.commit().await?;
+        Ok(summary)
+    }
+
+    pub async fn has_subscription(
+        &self,
+        subscriber_id: &SubscriberId,
+        feed_url: &FeedUrl,
+    ) -> Result<bool, FeedRegistryError> {
+        let mut tx = self.db.begin().await?;
+        let subscribed = tx.has_subscription(subscriber_id, feed_url).await?;
+        tx.commit().await?;
+        Ok(subscribed)
+    }
+}
+
+impl<S> FeedRegistry<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: SubscriptionDb + SavedEntryDb + TimelineDb + EventJournalAppend,
+{
+    /// Unsubscribes every feed and unsaves every entry of the subscriber,
+    /// then deletes its timeline.
+    ///
+    /// Each removal is its own journaled command, so projections see the
+    /// unsubscribes as any other. The timeline is deleted last, leaving no
+    /// tombstones or read markers behind. An interrupted deletion is
+    /// completed by running it again.
+    pub async fn delete_subscriber(
+        &self,
+        command: DeleteSubscriberCommand,
+    ) -> Result<DeleteSubscriberOutput, FeedRegistryError> {
+        const BATCH_SIZE: usize = 100;
+        let subscriber_id = command.subscriber_id;
+        let mut output = DeleteSubscriberOutput {
+            unsubscribed: 0,
+            unsaved: 0,
+        };
+
+        loop {
+            let page = self
+                .list_subscriptions(SubscriptionsQuery {
+                    subscriber_id: subscriber_id.clone(),
+                    after: None,
+                    first: BATCH_SIZE,
+                })
+                .await?;
+            if page.subscriptions.is_empty() {
+                break;
+            }
+            for subscription in page.subscriptions {
+                self.unsubscribe(UnsubscribeFeedCommand {
+                    subscriber_id: subscriber_id.clone(),
+                    feed_url: subscription.feed_url,
+                })
+                .await?;
+                output.unsubscribed += 1;
+            }
+        }
+
+        loop {
+            let page = self
+                .list_saved_entries(SavedEntriesQuery {
+                    subscriber_id: subscriber_id.clone(),
+                    after: None,
+                    first: BATCH_SIZE,
+                })
+                .await?;
+            let mut changed = false;
+            for saved in page.nodes {
+                let unsaved = self
+                    .unsave_entry(UnsaveEntryCommand {
+                        subscriber_id: subscriber_id.clone(),
+                        entry_id: saved.node.entry.id().clone(),
+                    })
+                    .await?;
+                if unsaved.changed {
+                    output.unsaved += 1;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let handled = self
+            .handlers
+            .timelines
+            .handle(DeleteTimelineCommand {
+                subscriber_id: subscriber_id.clone(),
+            })
+            .await?;
+        self.event_dispatch
+            .wake_publisher
+            .publish(handled.recorded_events);
+
+        Ok(output)
+    }
 }
 
 impl<S> FeedRegistry<S>
@@ -217,6 +463,16 @@ where
         tx.commit().await?;
         Ok(feeds)
     }
+
+    pub async fn list_feed_entries(
+        &self,
+        query: FeedEntriesQuery,
+    ) -> Result<FeedEntriesPage, FeedRegistryError> {
+        let mut tx = self.db.begin().await?;
+        let page = tx.list_feed_entries(query).await?;
+        tx.commit().await?;
+        Ok(page)
+    }
 }
 
 impl<S> FeedRegistry<S>
@@ -243,19 +499,73 @@ where
         tx.commit().await?;
         Ok(page)
     }
+
+    pub async fn search_timeline(
+        &self,
+        query: TimelineSearchQuery,
+    ) -> Result<TimelineSearchPage, FeedRegistryError> {
+        let mut tx = self.db.begin().await?;
+        let page = tx.search_timeline_entries(query).await?;
+        tx.commit().await?;
+        Ok(page)
+    }
+}
+
+impl<S> FeedRegistry<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: ArticleDb + BlobDb + TimelineDb,
+{
+    /// Loads one entry of the subscriber's timeline together with its
+    /// fetched article, if the subscriber asked the feed for full content.
+    pub async fn load_timeline_entry(
+        &self,
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+    ) -> Result<Option<TimelineEntry>, FeedRegistryError> {
+        let mut tx = self.db.begin().await?;
+        let Some(mut entry) = tx.load_timeline_entry(subscriber_id, entry_id).await? else {
+            tx.commit().await?;
+            return Ok(None);
+        };
+        if let Some(article) = tx.load_article(subscriber_id, entry_id).await? {
+            let bytes = tx.load_blob(article).await?;
+            entry.article = String::from_utf8(bytes).ok();
+        }
+        tx.commit().await?;
+        Ok(Some(entry))
+    }
+}
+
+impl<S> FeedRegistry<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: CrawlHistoryDb,
+{
+    pub async fn list_crawl_history(
+        &self,
+        query: CrawlHistoryQuery,
+    ) -> Result<Vec<CrawlHistoryEntry>, FeedRegistryError> {
+        let mut tx = self.db.begin().await?;
+        let history = tx.list_crawl_history(query).await?;
+        tx.commit().await?;
+        Ok(history)
+    }
 }
 
 /// Channels shared by registry commands and post-commit event workers.
 #[derive(Clone)]
 struct EventDispatch {
     api_events: ApiEventPublisher,
+    notifications: EntryNotificationPublisher,
     wake_publisher: EventWakePublisher,
 }
 
 impl EventDispatch {
-    fn new(config: FeedRegistryConfig) -> Self {
+    fn new(config: &FeedRegistryConfig) -> Self {
         Self {
             api_events: ApiEventPublisher::default(),
+            notifications: EntryNotificationPublisher::default(),
             wake_publisher: EventWakePublisher::new(config.event_wake_channel_capacity),
         }
     }
@@ -276,22 +586,29 @@ where
     fn new(
         db: S,
         wake_publisher: EventWakePublisher,
-        config: FeedRegistryConfig,
+        config: &FeedRegistryConfig,
         ct: CancellationToken,
         clock: Arc<dyn Clock>,
     ) -> Self {
         Self {
             db,
             wake_publisher,
-            config,
+            config: *config,
             ct,
             clock,
         }
     }
 
-    fn spawn_all(self, api_events: ApiEventPublisher) -> WorkerSet
+    fn spawn_all(
+        self,
+        api_events: ApiEventPublisher,
+        notifications: EntryNotificationPublisher,
+        host_backoffs: HostBackoffBoard,
+    ) -> WorkerSet
     where
-        for<'tx> S::Tx<'tx>: BlobDb
+        for<'tx> S::Tx<'tx>: ArticleDb
+            + BlobDb
+            + CrawlHistoryDb
             + CrawlStateDb
             + CrawlTargetDb
             + FeedDb
@@ -304,14 +621,22 @@ where
         let (dispatch_queue_writer, dispatch_queue_reader) = self.dispatch_queue();
         // Shared between the dispatcher (claims) and crawl jobs (releases).
         let inflight = InflightCrawls::new();
+        let crawl_worker_pool = self.crawl_worker_pool(dispatch_queue_reader);
+        // Article fetches share the per-host capacity of crawl jobs.
+        let hosts = crawl_worker_pool.hosts();
 
         WorkerSet::new(vec![
             self.spawn_crawl_target_projection(),
-            self.spawn_crawl_dispatcher(dispatch_queue_writer, inflight),
-            self.spawn_crawl_worker_pool(dispatch_queue_reader),
+            self.spawn_crawl_dispatcher(dispatch_queue_writer, inflight, host_backoffs.clone()),
+            crawl_worker_pool.spawn(),
             self.spawn_feed_projection(),
             self.spawn_timeline_projection(),
             self.spawn_api_event_publisher(api_events),
+            self.spawn_entry_notifier(notifications),
+            self.spawn_journal_compactor(),
+            self.spawn_blob_collector(),
+            self.spawn_article_fetcher(hosts, host_backoffs),
+            self.spawn_timeline_pruner(),
         ])
     }
 
@@ -327,7 +652,7 @@ where
 
     fn spawn_feed_projection(&self) -> WorkerHandle
     where
-        for<'tx> S::Tx<'tx>: BlobDb + FeedDb + EventJournalAppend,
+        for<'tx> S::Tx<'tx>: ArticleDb + BlobDb + FeedDb + EventJournalAppend,
     {
         self.spawn_journal_worker(
             self.config.workers.feed_projection_poll_interval,
@@ -347,11 +672,21 @@ where
 
     fn spawn_api_event_publisher(&self, api_events: ApiEventPublisher) -> WorkerHandle
     where
-        for<'tx> S::Tx<'tx>: EventJournalAppend,
+        for<'tx> S::Tx<'tx>: CrawlHistoryDb + SubscriptionDb + TimelineDb + EventJournalAppend,
     {
         self.spawn_post_commit_worker(
             self.config.workers.api_event_publisher_poll_interval,
-            api_events,
+            ApiEventResolver::new(self.db.clone(), api_events),
+        )
+    }
+
+    fn spawn_entry_notifier(&self, notifications: EntryNotificationPublisher) -> WorkerHandle
+    where
+        for<'tx> S::Tx<'tx>: FeedDb + SubscriptionDb + EventJournalAppend,
+    {
+        self.spawn_post_commit_worker(
+            self.config.workers.entry_notifier_poll_interval,
+            EntryNotifier::new(self.db.clone(), notifications),
         )
     }
 
@@ -363,12 +698,17 @@ where
         &self,
         dispatch_queue_writer: DispatchQueueWriter,
         inflight: InflightCrawls,
+        host_backoffs: HostBackoffBoard,
     ) -> WorkerHandle
     where
         for<'tx> S::Tx<'tx>: CrawlTargetDb + Send,
     {
-        let dispatcher =
-            CrawlDispatcher::new(dispatch_queue_writer, inflight, self.config.crawl_dispatch);
+        let dispatcher = CrawlDispatcher::new(
+            dispatch_queue_writer,
+            inflight,
+            host_backoffs,
+            self.config.crawl_dispatch,
+        );
         EventLoop::new(
             ReconcilerWorker::new(self.db.clone(), dispatcher, Arc::clone(&self.clock)),
             self.wake_publisher.clone(),
@@ -378,15 +718,90 @@ where
         .spawn()
     }
 
-    fn spawn_crawl_worker_pool(&self, dispatch_queue_reader: DispatchQueueReader) -> WorkerHandle
+    fn spawn_journal_compactor(&self) -> WorkerHandle {
+        EventLoop::new(
+            ReconcilerWorker::new(
+                self.db.clone(),
+                JournalCompactor::new(self.config.journal_compaction),
+                Arc::clone(&self.clock),
+            ),
+            self.wake_publisher.clone(),
+            self.config.workers.journal_compactor_poll_interval,
+            self.ct.clone(),
+        )
+        .spawn()
+    }
+
+    fn spawn_blob_collector(&self) -> WorkerHandle
+    where
+        for<'tx> S::Tx<'tx>: BlobDb,
+    {
+        EventLoop::new(
+            ReconcilerWorker::new(
+                self.db.clone(),
+                BlobCollector::new(self.config.blob_collection),
+                Arc::clone(&self.clock),
+            ),
+            self.wake_publisher.clone(),
+            self.config.workers.blob_collector_poll_interval,
+            self.ct.clone(),
+        )
+        .spawn()
+    }
+
+    fn spawn_article_fetcher(
+        &self,
+        hosts: Arc<CrawlHostCapacity>,
+        host_backoffs: HostBackoffBoard,
+    ) -> WorkerHandle
     where
-        for<'tx> S::Tx<'tx>:
-            BlobDb + CrawlStateDb + CrawlTargetDb + EventJournal + EventJournalAppend + Send,
+        for<'tx> S::Tx<'tx>: ArticleDb + BlobDb + Send,
     {
-        let fetcher = Arc::new(FeedService::new(
-            self.config.crawl_worker_pool.fetch.user_agent,
-            self.config.crawl_worker_pool.fetch.max_body_bytes,
-        ));
+        let fetcher = self.config.crawl_worker_pool.fetch.feed_service();
+        EventLoop::new(
+            ReconcilerWorker::new(
+                self.db.clone(),
+                ArticleFetcher::new(fetcher, self.config.article_fetch, hosts, host_backoffs),
+                Arc::clone(&self.clock),
+            ),
+            self.wake_publisher.clone(),
+            self.config.workers.article_fetcher_poll_interval,
+            self.ct.clone(),
+        )
+        .spawn()
+    }
+
+    fn spawn_timeline_pruner(&self) -> WorkerHandle
+    where
+        for<'tx> S::Tx<'tx>: FeedDb + TimelineDb + EventJournalAppend + Send,
+    {
+        EventLoop::new(
+            ReconcilerWorker::new(
+                self.db.clone(),
+                TimelinePruner::new(self.config.timeline_retention, Arc::clone(&self.clock)),
+                Arc::clone(&self.clock),
+            ),
+            self.wake_publisher.clone(),
+            self.config.workers.timeline_pruner_poll_interval,
+            self.ct.clone(),
+        )
+        .spawn()
+    }
+
+    fn crawl_worker_pool(
+        &self,
+        dispatch_queue_reader: DispatchQueueReader,
+    ) -> CrawlWorkerPool<S, Arc<FeedService>>
+    where
+        for<'tx> S::Tx<'tx>: BlobDb
+            + CrawlHistoryDb
+            + CrawlStateDb
+            + CrawlTargetDb
+            + EventJournal
+            + EventJournalAppend
+            + Send,
+    {
+        let fetcher = Arc::new(self.config.crawl_worker_pool.fetch.feed_service());
         CrawlWorkerPool::new(
             self.db.clone(),
             fetcher,
@@ -396,7 +811,6 @@ where
             self.ct.clone(),
             Arc::clone(&self.clock),
         )
-        .spawn()
     }
 
     fn spawn_journal_worker<P>(&self, poll_interval: Duration, projector: P) -> WorkerHandle
@@ -0,0 +1,211 @@
+use std::sync::Arc;
+
+use synd_feed::entry::EntryId;
+use synd_support::time::Clock;
+use tracing::info;
+
+use crate::{
+    command::{SaveEntryCommand, SaveEntryOutput, UnsaveEntryCommand, UnsaveEntryOutput},
+    db::{CommitTx, FeedRegistryDb, SavedEntryDb},
+    error::FeedRegistryError,
+    event::{
+        EntrySavedEvent, EntryUnsavedEvent, Event, EventJournalAppend, EventRecorder,
+        RecordedEvents,
+    },
+    handler::{CommandHandler, HandledCommand},
+    saved::SaveMark,
+    subscription::SubscriberId,
+};
+
+/// Decision made for one save request and the fact to record when the
+/// saved state flips.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SaveDecision {
+    event: Option<Event>,
+}
+
+impl SaveDecision {
+    /// Pure decision over the current saved state of the entry, `None` when
+    /// it is neither on the timeline nor saved. Only timeline entries can be
+    /// saved, while saved ones can always be unsaved.
+    fn decide(
+        subscriber_id: &SubscriberId,
+        entry_id: &EntryId,
+        mark: SaveMark,
+        saved: Option<bool>,
+    ) -> Self {
+        let changed = saved.is_some_and(|saved| saved != mark.is_saved());
+        let event = changed.then(|| match mark {
+            SaveMark::Save => EntrySavedEvent::new(subscriber_id.clone(), entry_id.clone()).into(),
+            SaveMark::Unsave => {
+                EntryUnsavedEvent::new(subscriber_id.clone(), entry_id.clone()).into()
+            }
+        });
+        Self { event }
+    }
+
+    fn changed(&self) -> bool {
+        self.event.is_some()
+    }
+}
+
+/// Handles save commands as subscriber-scoped state plus journaled facts.
+/// The timeline projection re-publishes the entry so other clients pick up
+/// the new state.
+#[derive(Clone)]
+pub(crate) struct SavedEntryHandler<S> {
+    db: S,
+    clock: Arc<dyn Clock>,
+}
+
+impl<S> SavedEntryHandler<S> {
+    pub(crate) fn new(db: S, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
+    }
+}
+
+impl<S> SavedEntryHandler<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: SavedEntryDb + EventJournalAppend,
+{
+    async fn handle_mark(
+        &self,
+        subscriber_id: SubscriberId,
+        entry_id: EntryId,
+        mark: SaveMark,
+    ) -> Result<HandledCommand<bool>, FeedRegistryError> {
+        let mut tx = self.db.begin().await?;
+        let saved = tx.load_entry_saved(&subscriber_id, &entry_id).await?;
+        let decision = SaveDecision::decide(&subscriber_id, &entry_id, mark, saved);
+        let changed = decision.changed();
+
+        if changed {
+            match mark {
+                SaveMark::Save => {
+                    tx.save_entry(&subscriber_id, &entry_id, self.clock.now())
+                        .await?;
+                }
+                SaveMark::Unsave => {
+                    tx.unsave_entry(&subscriber_id, &entry_id).await?;
+                }
+            }
+        }
+        let mut recorded_events = RecordedEvents::with_capacity(1);
+        EventRecorder::new(&mut tx, &mut recorded_events, self.clock.as_ref())
+            .record_all(decision.event)
+            .await?;
+        tx.commit().await?;
+
+        info!(
+            subscriber_id = subscriber_id.as_str(),
+            entry_id = %entry_id,
+            mark = mark.as_str(),
+            changed,
+            "saved entry committed"
+        );
+
+        Ok(HandledCommand {
+            output: changed,
+            recorded_events,
+        })
+    }
+}
+
+impl<S> CommandHandler<SaveEntryCommand> for SavedEntryHandler<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: SavedEntryDb + EventJournalAppend,
+{
+    type Output = SaveEntryOutput;
+    type Error = FeedRegistryError;
+
+    async fn handle(
+        &self,
+        command: SaveEntryCommand,
+    ) -> Result<HandledCommand<Self::Output>, Self::Error> {
+        let handled = self
+            .handle_mark(command.subscriber_id, command.entry_id, SaveMark::Save)
+            .await?;
+        Ok(HandledCommand {
+            output: SaveEntryOutput {
+                changed: handled.output,
+            },
+            recorded_events: handled.recorded_events,
+        })
+    }
+}
+
+impl<S> CommandHandler<UnsaveEntryCommand> for SavedEntryHandler<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: SavedEntryDb + EventJournalAppend,
+{
+    type Output = UnsaveEntryOutput;
+    type Error = FeedRegistryError;
+
+    async fn handle(
+        &self,
+        command: UnsaveEntryCommand,
+    ) -> Result<HandledCommand<Self::Output>, Self::Error> {
+        let handled = self
+            .handle_mark(command.subscriber_id, command.entry_id, SaveMark::Unsave)
+            .await?;
+        Ok(HandledCommand {
+            output: UnsaveEntryOutput {
+                changed: handled.output,
+            },
+            recorded_events: handled.recorded_events,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subscriber_id() -> SubscriberId {
+        SubscriberId::new("reader")
+    }
+
+    fn entry_id() -> EntryId {
+        EntryId::parse(format!("synd:entry:v1:{}", "1".repeat(64))).unwrap()
+    }
+
+    #[test]
+    fn saves_unsaved_timeline_entry() {
+        let decision =
+            SaveDecision::decide(&subscriber_id(), &entry_id(), SaveMark::Save, Some(false));
+
+        assert_eq!(
+            decision.event,
+            Some(EntrySavedEvent::new(subscriber_id(), entry_id()).into())
+        );
+    }
+
+    #[test]
+    fn unsaves_saved_entry() {
+        let decision =
+            SaveDecision::decide(&subscriber_id(), &entry_id(), SaveMark::Unsave, Some(true));
+
+        assert_eq!(
+            decision.event,
+            Some(EntryUnsavedEvent::new(subscriber_id(), entry_id()).into())
+        );
+    }
+
+    #[test]
+    fn records_nothing_when_state_already_matches() {
+        let decision =
+            SaveDecision::decide(&subscriber_id(), &entry_id(), SaveMark::Save, Some(true));
+
+        assert!(!decision.changed());
+    }
+
+    #[test]
+    fn ignores_entries_off_the_timeline() {
+        let decision = SaveDecision::decide(&subscriber_id(), &entry_id(), SaveMark::Save, None);
+
+        assert!(!decision.changed());
+    }
+}
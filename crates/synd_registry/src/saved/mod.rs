@@ -0,0 +1,30 @@
+//! Subscriber-scoped saved entries.
+//!
+//! A saved entry outlives its place on the timeline: it stays listed after
+//! the feed dropped it from its body or the subscription ended, until the
+//! subscriber unsaves it.
+
+mod handler;
+pub mod query;
+
+pub(crate) use handler::SavedEntryHandler;
+
+/// Saved state requested by a save command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveMark {
+    Save,
+    Unsave,
+}
+
+impl SaveMark {
+    pub fn is_saved(self) -> bool {
+        matches!(self, Self::Save)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Save => "save",
+            Self::Unsave => "unsave",
+        }
+    }
+}
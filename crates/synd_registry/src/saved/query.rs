@@ -0,0 +1,83 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use synd_feed::entry::EntryId;
+use thiserror::Error;
+
+use crate::{query::TimelineEntry, subscription::SubscriberId};
+
+/// Query for the entries one subscriber saved, latest save first.
+#[derive(Debug, Clone)]
+pub struct SavedEntriesQuery {
+    pub subscriber_id: SubscriberId,
+    pub after: Option<SavedEntryCursor>,
+    pub first: usize,
+}
+
+/// Opaque pagination cursor for saved entries.
+/// `(saved_at, entry_id)` is a total order because one subscriber saves an
+/// entry at most once; saving it again after unsaving moves it to the top.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedEntryCursor {
+    saved_at: DateTime<Utc>,
+    entry_id: EntryId,
+}
+
+impl SavedEntryCursor {
+    pub fn new(saved_at: DateTime<Utc>, entry_id: EntryId) -> Self {
+        Self { saved_at, entry_id }
+    }
+
+    pub fn decode(value: &str) -> Result<Self, SavedEntryCursorError> {
+        serde_json::from_str(value).map_err(SavedEntryCursorError::Invalid)
+    }
+
+    pub fn encode(&self) -> String {
+        serde_json::to_string(self).expect("saved entry cursor serialization should not fail")
+    }
+
+    pub fn saved_at(&self) -> DateTime<Utc> {
+        self.saved_at
+    }
+
+    pub fn entry_id(&self) -> &EntryId {
+        &self.entry_id
+    }
+}
+
+impl fmt::Display for SavedEntryCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+/// Error returned when decoding a saved entry cursor.
+#[derive(Debug, Error)]
+pub enum SavedEntryCursorError {
+    #[error("invalid saved entry cursor: {0}")]
+    Invalid(serde_json::Error),
+}
+
+/// One saved entry. Entries whose subscription ended carry no requirement
+/// or category.
+#[derive(Debug, Clone)]
+pub struct SavedEntry {
+    pub saved_at: DateTime<Utc>,
+    pub node: TimelineEntry,
+}
+
+impl SavedEntry {
+    pub fn cursor(&self) -> SavedEntryCursor {
+        SavedEntryCursor::new(self.saved_at, self.node.entry.id().clone())
+    }
+}
+
+/// Page of saved entries returned by a saved entries query.
+#[derive(Debug, Clone)]
+pub struct SavedEntriesPage {
+    pub nodes: Vec<SavedEntry>,
+    pub has_next_page: bool,
+    pub end_cursor: Option<SavedEntryCursor>,
+}
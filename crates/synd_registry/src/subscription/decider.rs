@@ -3,7 +3,7 @@ use thiserror::Error;
 use crate::{
     event::{FeedSubscribedEvent, FeedUnsubscribedEvent, SubEvent, SubscriptionChangedEvent},
     handler::Decider,
-    subscription::{FeedSubscriptionAttrs, SubscriptionKey},
+    subscription::{FeedSubscriptionAttrs, SubscriberId, SubscriptionKey},
 };
 
 /// Current command-time state of one subscriber/feed relation.
@@ -40,6 +40,11 @@ impl SubCommand {
 pub enum SubReject {
     #[error("feed is not subscribed: {0:?}")]
     NotSubscribed(SubscriptionKey),
+    #[error("subscriber {subscriber_id} reached its quota of {max_subscriptions} subscriptions")]
+    QuotaExceeded {
+        subscriber_id: SubscriberId,
+        max_subscriptions: usize,
+    },
 }
 
 /// Decides subscription domain events from a subscription command and state.
@@ -101,11 +106,8 @@ mod tests {
     use synd_feed::types::FeedUrl;
 
     use super::*;
+    use crate::crawl::policy::{CrawlPolicy, PollingInterval};
     use crate::handler::Decider;
-    use crate::{
-        SubscriberId,
-        crawl::policy::{CrawlPolicy, PollingInterval},
-    };
 
     #[test]
     fn decides_subscribe_when_not_subscribed() -> anyhow::Result<()> {
@@ -225,6 +227,7 @@ mod tests {
             crawl_policy: CrawlPolicy::interval(PollingInterval::try_from(Duration::from_hours(
                 1,
             ))?),
+            fetch_full_content: false,
         })
     }
 }
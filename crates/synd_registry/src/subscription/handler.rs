@@ -8,13 +8,15 @@ use crate::{
     command::{
         SubscribeFeedCommand, SubscribeFeedOutput, UnsubscribeFeedCommand, UnsubscribeFeedOutput,
     },
+    config::SubscriptionQuotaConfig,
     crawl::policy::CrawlPolicy,
     db::{CommitTx, FeedRegistryDb, SubscriptionDb},
     error::{FeedRegistryError, RegistryDbError, RegistryDbResult},
     event::{EventRecorder, RecordedEvents, SubEvent},
     handler::{CommandHandler, Decider, HandledCommand, StateApplier},
     subscription::{
-        SubCommand, SubDecider, SubState, SubscribeOutcome, SubscriptionKey, UnsubscribeOutcome,
+        SubCommand, SubDecider, SubReject, SubState, SubscribeOutcome, SubscriberId,
+        SubscriptionKey, UnsubscribeOutcome,
     },
 };
 
@@ -23,16 +25,23 @@ use crate::{
 pub(crate) struct SubHandler<S> {
     db: S,
     default_crawl_policy: CrawlPolicy,
+    quota: SubscriptionQuotaConfig,
     clock: Arc<dyn Clock>,
     decider: SubDecider,
     applier: SubStateApplier,
 }
 
 impl<S> SubHandler<S> {
-    pub(crate) fn new(db: S, default_crawl_policy: CrawlPolicy, clock: Arc<dyn Clock>) -> Self {
+    pub(crate) fn new(
+        db: S,
+        default_crawl_policy: CrawlPolicy,
+        quota: SubscriptionQuotaConfig,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
         Self {
             db,
             default_crawl_policy,
+            quota,
             clock,
             decider: SubDecider,
             applier: SubStateApplier,
@@ -57,6 +66,34 @@ impl SubState {
     }
 }
 
+impl SubscriptionQuotaConfig {
+    /// Rejects a new subscription once the subscriber holds the maximum.
+    async fn check<Tx>(
+        &self,
+        tx: &mut Tx,
+        subscriber_id: &SubscriberId,
+    ) -> Result<(), FeedRegistryError>
+    where
+        Tx: SubscriptionDb + Send,
+    {
+        let Some(max_subscriptions) = self.max_subscriptions else {
+            return Ok(());
+        };
+        let subscriptions = tx
+            .load_subscriber(subscriber_id)
+            .await?
+            .map_or(0, |summary| summary.subscriptions);
+        if subscriptions >= max_subscriptions {
+            return Err(SubReject::QuotaExceeded {
+                subscriber_id: subscriber_id.clone(),
+                max_subscriptions,
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
 /// Applies subscription domain events to the subscription store transaction.
 #[derive(Debug, Clone, Copy, Default)]
 pub(crate) struct SubStateApplier;
@@ -172,6 +209,13 @@ where
     {
         let mut tx = self.db.begin().await?;
         let state = SubState::load(&mut tx, sub_command.subscription()).await?;
+        if let (SubCommand::Subscribe { subscription, .. }, SubState::NotSubscribed) =
+            (&sub_command, &state)
+        {
+            self.quota
+                .check(&mut tx, &subscription.subscriber_id)
+                .await?;
+        }
         let events = self.decider.decide(sub_command, state)?;
         let output = O::from_events(&events)?;
 
@@ -60,6 +60,10 @@ pub struct FeedSubscriptionAttrs {
     pub requirement: Option<Requirement>,
     pub category: Option<Category<'static>>,
     pub crawl_policy: CrawlPolicy,
+    /// Fetch the web page of each newly discovered entry and keep its main
+    /// article, for feeds that only publish summaries.
+    #[serde(default)]
+    pub fetch_full_content: bool,
 }
 
 /// Result of applying a subscribe operation to current subscription state.
@@ -83,6 +87,7 @@ pub struct Subscription {
     pub requirement: Option<Requirement>,
     pub category: Option<Category<'static>>,
     pub crawl_policy: CrawlPolicy,
+    pub fetch_full_content: bool,
     /// When the relation was created. Editing attributes keeps this value.
     pub subscribed_at: DateTime<Utc>,
 }
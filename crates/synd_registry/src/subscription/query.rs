@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+
 use crate::subscription::{SubscriberId, Subscription};
 
 /// Query for one subscriber's current subscriptions.
@@ -29,3 +31,27 @@ impl Subscriptions {
         }
     }
 }
+
+/// Query for subscribers that currently hold at least one subscription.
+#[derive(Debug, Clone)]
+pub struct SubscribersQuery {
+    pub after: Option<SubscriberId>,
+    pub first: usize,
+}
+
+/// One subscriber and the extent of its current subscriptions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubscriberSummary {
+    pub subscriber_id: SubscriberId,
+    pub subscriptions: usize,
+    /// When the oldest current subscription was created.
+    pub first_subscribed_at: DateTime<Utc>,
+}
+
+/// Page of subscribers ordered by id.
+#[derive(Debug, Clone)]
+pub struct SubscribersPage {
+    pub subscribers: Vec<SubscriberSummary>,
+    pub has_next_page: bool,
+    pub end_cursor: Option<SubscriberId>,
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use synd_support::time::Clock;
+use tracing::info;
+
+use crate::{
+    db::{CommitTx, FeedRegistryDb, TimelineDb},
+    error::FeedRegistryError,
+    event::{EventJournalAppend, EventRecorder, RecordedEvents, TimelineDeletedEvent},
+    handler::{CommandHandler, HandledCommand},
+    subscription::SubscriberId,
+};
+
+/// Request to delete one subscriber's timeline.
+#[derive(Debug, Clone)]
+pub(crate) struct DeleteTimelineCommand {
+    pub subscriber_id: SubscriberId,
+}
+
+/// Handles timeline deletion as a direct state change plus a journaled fact,
+/// so workers holding per-timeline state let go of it.
+#[derive(Clone)]
+pub(crate) struct TimelineHandler<S> {
+    db: S,
+    clock: Arc<dyn Clock>,
+}
+
+impl<S> TimelineHandler<S> {
+    pub(crate) fn new(db: S, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
+    }
+}
+
+impl<S> CommandHandler<DeleteTimelineCommand> for TimelineHandler<S>
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: TimelineDb + EventJournalAppend,
+{
+    type Output = ();
+    type Error = FeedRegistryError;
+
+    async fn handle(
+        &self,
+        command: DeleteTimelineCommand,
+    ) -> Result<HandledCommand<Self::Output>, Self::Error> {
+        let subscriber_id = command.subscriber_id;
+        let mut tx = self.db.begin().await?;
+        tx.delete_timeline(&subscriber_id).await?;
+        let mut recorded_events = RecordedEvents::with_capacity(1);
+        EventRecorder::new(&mut tx, &mut recorded_events, self.clock.as_ref())
+            .record(TimelineDeletedEvent::new(subscriber_id.clone()))
+            .await?;
+        tx.commit().await?;
+
+        info!(
+            subscriber_id = subscriber_id.as_str(),
+            "timeline deletion committed"
+        );
+
+        Ok(HandledCommand {
+            output: (),
+            recorded_events,
+        })
+    }
+}
@@ -2,8 +2,10 @@ use synd_feed::types::FeedUrl;
 
 use crate::subscription::SubscriberId;
 
+pub(crate) mod handler;
 mod projection;
 pub mod query;
+pub mod retention;
 
 pub use projection::{TimelineProj, TimelineProjInput};
 
@@ -5,7 +5,8 @@ use tracing::info;
 use crate::{
     db::{FeedRegistryDb, TimelineDb},
     event::{
-        EntryChangedEvent, EntryDiscoveredEvent, Event, EventInput, EventType, FeedSubscribedEvent,
+        EntriesMarkedReadEvent, EntriesMarkedUnreadEvent, EntryChangedEvent, EntryDiscoveredEvent,
+        EntrySavedEvent, EntryUnsavedEvent, Event, EventInput, EventType, FeedSubscribedEvent,
         FeedUnsubscribedEvent, InputBatch, Processor, ProcessorError, ProcessorId, ProcessorResult,
         Projector, RegistryEvent, TimelineChangedEvent,
     },
@@ -19,6 +20,10 @@ pub enum TimelineProjInput {
     FeedUnsubscribed(FeedUnsubscribedEvent),
     EntryDiscovered(EntryDiscoveredEvent),
     EntryChanged(EntryChangedEvent),
+    EntriesMarkedRead(EntriesMarkedReadEvent),
+    EntriesMarkedUnread(EntriesMarkedUnreadEvent),
+    EntrySaved(EntrySavedEvent),
+    EntryUnsaved(EntryUnsavedEvent),
 }
 
 impl TimelineProjInput {
@@ -54,9 +59,38 @@ impl TimelineProjInput {
             Self::EntryChanged(event) => {
                 Self::apply_entry(tx, event.feed_url, &event.entry_id, true).await
             }
+            Self::EntriesMarkedRead(event) => {
+                Self::touch_entries(tx, event.subscriber_id, &event.entry_ids).await
+            }
+            Self::EntriesMarkedUnread(event) => {
+                Self::touch_entries(tx, event.subscriber_id, &event.entry_ids).await
+            }
+            Self::EntrySaved(event) => {
+                Self::touch_entries(tx, event.subscriber_id, &[event.entry_id]).await
+            }
+            Self::EntryUnsaved(event) => {
+                Self::touch_entries(tx, event.subscriber_id, &[event.entry_id]).await
+            }
         }
     }
 
+    /// Re-publishes entries whose subscriber-scoped state changed, such as
+    /// read markers or saves, without changing membership.
+    async fn touch_entries<Tx>(
+        tx: &mut Tx,
+        subscriber_id: SubscriberId,
+        entry_ids: &[EntryId],
+    ) -> ProcessorResult<Vec<(SubscriberId, FeedUrl)>>
+    where
+        Tx: TimelineDb + Send,
+    {
+        let feed_urls = tx.touch_timeline_entries(&subscriber_id, entry_ids).await?;
+        Ok(feed_urls
+            .into_iter()
+            .map(|feed_url| (subscriber_id.clone(), feed_url))
+            .collect())
+    }
+
     async fn apply_entry<Tx>(
         tx: &mut Tx,
         feed_url: FeedUrl,
@@ -82,6 +116,10 @@ impl EventInput for TimelineProjInput {
         FeedUnsubscribedEvent::TYPE,
         EntryDiscoveredEvent::TYPE,
         EntryChangedEvent::TYPE,
+        EntriesMarkedReadEvent::TYPE,
+        EntriesMarkedUnreadEvent::TYPE,
+        EntrySavedEvent::TYPE,
+        EntryUnsavedEvent::TYPE,
     ];
 
     fn from_event(event: Event, _occurred_at: DateTime<Utc>) -> ProcessorResult<Self> {
@@ -90,6 +128,10 @@ impl EventInput for TimelineProjInput {
             Event::FeedUnsubscribed(event) => Ok(Self::FeedUnsubscribed(event)),
             Event::EntryDiscovered(event) => Ok(Self::EntryDiscovered(event)),
             Event::EntryChanged(event) => Ok(Self::EntryChanged(event)),
+            Event::EntriesMarkedRead(event) => Ok(Self::EntriesMarkedRead(event)),
+            Event::EntriesMarkedUnread(event) => Ok(Self::EntriesMarkedUnread(event)),
+            Event::EntrySaved(event) => Ok(Self::EntrySaved(event)),
+            Event::EntryUnsaved(event) => Ok(Self::EntryUnsaved(event)),
             event => Err(ProcessorError::unexpected_input(
                 "timeline projection event",
                 &event,
@@ -4,7 +4,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use synd_feed::{
     entry::{Entry, EntryId},
-    types::{Annotated, FeedMeta},
+    types::{Annotated, Category, FeedMeta, FeedUrl, Requirement},
 };
 use thiserror::Error;
 
@@ -14,10 +14,32 @@ use crate::subscription::SubscriberId;
 #[derive(Debug, Clone)]
 pub struct TimelineEntriesQuery {
     pub subscriber_id: SubscriberId,
+    pub filter: TimelineEntriesFilter,
     pub after: Option<TimelineEntryCursor>,
     pub first: usize,
 }
 
+/// Narrows a timeline listing by the subscription each entry came from.
+/// An unset condition matches every entry; set conditions must all hold.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimelineEntriesFilter {
+    /// Requirements of the matching subscriptions. `None` matches
+    /// subscriptions without a requirement.
+    pub requirements: Option<Vec<Option<Requirement>>>,
+    /// Categories of the matching subscriptions. `None` matches
+    /// subscriptions without a category.
+    pub categories: Option<Vec<Option<Category<'static>>>>,
+    /// Feeds the matching entries belong to.
+    pub feed_urls: Option<Vec<FeedUrl>>,
+}
+
+impl TimelineEntriesFilter {
+    /// Whether the filter matches every entry.
+    pub fn is_empty(&self) -> bool {
+        self.requirements.is_none() && self.categories.is_none() && self.feed_urls.is_none()
+    }
+}
+
 /// Opaque pagination cursor for timeline entry ordering.
 /// Carries the immutable order key of the last entry on a page:
 /// `(order_time, entry_id)` is a total order because `entry_id` is unique
@@ -73,6 +95,13 @@ pub struct TimelineEntry {
     pub entry: Entry,
     pub feed_meta: Annotated<FeedMeta>,
     pub cursor: TimelineEntryCursor,
+    /// Whether the subscriber marked the entry as read.
+    pub read: bool,
+    /// Whether the subscriber saved the entry.
+    pub saved: bool,
+    /// Article fetched from the entry's web page, for subscriptions asking
+    /// for full content. Only loaded for a single entry.
+    pub article: Option<String>,
 }
 
 /// Page of timeline entries returned by a timeline query.
@@ -86,6 +115,26 @@ pub struct TimelineEntriesPage {
     pub seq: i64,
 }
 
+/// Full-text query over the entries on one subscriber's timeline.
+#[derive(Debug, Clone)]
+pub struct TimelineSearchQuery {
+    pub subscriber_id: SubscriberId,
+    /// Words every matching entry contains in its title, summary or content.
+    /// The last word may be partially typed.
+    pub text: String,
+    pub after: Option<TimelineEntryCursor>,
+    pub first: usize,
+}
+
+/// Page of matching timeline entries in timeline order, so the same cursor
+/// pages through both.
+#[derive(Debug, Clone)]
+pub struct TimelineSearchPage {
+    pub nodes: Vec<TimelineEntry>,
+    pub has_next_page: bool,
+    pub end_cursor: Option<TimelineEntryCursor>,
+}
+
 /// Query for timeline changes observed after a known seq.
 #[derive(Debug, Clone)]
 pub struct TimelineChangesQuery {
@@ -111,6 +160,22 @@ pub struct TimelineChangesPage {
     /// Equals the server position when `has_more` is false.
     pub seq: i64,
     pub has_more: bool,
+    /// `since` predates purged tombstones, so the removals after it can no
+    /// longer be listed. The client reloads its window instead.
+    pub expired: bool,
+}
+
+impl TimelineChangesPage {
+    /// Page answering a `since` older than the purged tombstones.
+    /// `seq` is the server position.
+    pub fn expired(seq: i64) -> Self {
+        Self {
+            changes: Vec::new(),
+            seq,
+            has_more: false,
+            expired: true,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,239 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use synd_feed::types::FeedUrl;
+use synd_support::{o11y::metric, time::Clock};
+use tracing::{debug, info};
+
+use crate::{
+    config::TimelineRetentionConfig,
+    db::{CommitTx, FeedDb, FeedRegistryDb, TimelineDb},
+    event::{
+        EventInterests, EventJournalAppend, EventRecorder, Reaction, Reconciler, RecordedEvents,
+        TimelineChangedEvent, WakeRequest, WorkerId, WorkerResult,
+    },
+    subscription::SubscriberId,
+};
+
+/// Retention rules resolved against the clock for one pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimelineRetention {
+    /// Entries ordered before this instant leave the timeline.
+    pub ordered_before: Option<DateTime<Utc>>,
+    /// Entries beyond the newest this many of their feed leave the timeline.
+    pub max_entries_per_feed: Option<usize>,
+    /// Unread entries stay regardless of age and count.
+    pub keep_unread: bool,
+}
+
+impl TimelineRetention {
+    pub fn resolve(config: &TimelineRetentionConfig, now: DateTime<Utc>) -> Self {
+        let ordered_before = config.max_age.map(|max_age| {
+            let max_age = TimeDelta::from_std(max_age).unwrap_or(TimeDelta::MAX);
+            now.checked_sub_signed(max_age)
+                .unwrap_or(DateTime::<Utc>::MIN_UTC)
+        });
+        Self {
+            ordered_before,
+            max_entries_per_feed: config.max_entries_per_feed,
+            keep_unread: config.keep_unread,
+        }
+    }
+
+    /// Whether the rules keep every entry.
+    pub fn keeps_all(&self) -> bool {
+        self.ordered_before.is_none() && self.max_entries_per_feed.is_none()
+    }
+}
+
+/// Result of one pass removing entries the retention rules no longer keep.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TimelinePrune {
+    /// Number of timeline entries turned into tombstones.
+    pub removed: u64,
+    /// Timelines that lost entries, paired with the feed of each entry.
+    pub affected: Vec<(SubscriberId, FeedUrl)>,
+    /// Entries to remove remain because the pass stopped at its limit.
+    pub has_more: bool,
+}
+
+/// Result of one pass deleting rows nothing needs anymore.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RetentionPurge {
+    /// Number of rows deleted.
+    pub removed: u64,
+    /// Deletable rows remain because the pass stopped at its limit.
+    pub has_more: bool,
+}
+
+/// Level-driven cleanup keeping timelines and the entry catalog bounded.
+///
+/// Each pass first removes entries the retention rules no longer keep, as
+/// tombstones so syncing clients observe the removal, then purges tombstones
+/// that fell out of the sync window, and finally deletes catalog entries
+/// that no feed, timeline or save refers to anymore. Saved entries are never
+/// removed.
+pub(crate) struct TimelinePruner {
+    config: TimelineRetentionConfig,
+    clock: Arc<dyn Clock>,
+}
+
+impl TimelinePruner {
+    pub(crate) fn new(config: TimelineRetentionConfig, clock: Arc<dyn Clock>) -> Self {
+        Self { config, clock }
+    }
+
+    async fn remove_retired<S>(
+        &self,
+        db: &S,
+        now: DateTime<Utc>,
+        recorded: &mut RecordedEvents,
+    ) -> WorkerResult<TimelinePrune>
+    where
+        S: FeedRegistryDb,
+        for<'tx> S::Tx<'tx>: TimelineDb + EventJournalAppend + Send,
+    {
+        let retention = TimelineRetention::resolve(&self.config, now);
+        if retention.keeps_all() {
+            return Ok(TimelinePrune::default());
+        }
+
+        let mut tx = db.begin().await?;
+        let prune = tx
+            .remove_retired_timeline_entries(&retention, self.config.batch_size.max(1))
+            .await?;
+        EventRecorder::new(&mut tx, recorded, self.clock.as_ref())
+            .record_all(timeline_changes(&prune.affected))
+            .await?;
+        tx.commit().await?;
+
+        if prune.removed > 0 {
+            metric!(monotonic_counter.registry.timeline.pruned = prune.removed);
+        }
+        Ok(prune)
+    }
+}
+
+impl<S> Reconciler<S> for TimelinePruner
+where
+    S: FeedRegistryDb,
+    for<'tx> S::Tx<'tx>: FeedDb + TimelineDb + EventJournalAppend + Send,
+{
+    fn id(&self) -> WorkerId {
+        WorkerId::TimelinePruner
+    }
+
+    fn wake_hints(&self) -> EventInterests {
+        EventInterests::empty()
+    }
+
+    async fn reconcile(&mut self, db: &S, now: DateTime<Utc>) -> WorkerResult<Reaction> {
+        let limit = self.config.batch_size.max(1);
+        let mut recorded = RecordedEvents::empty();
+        let prune = self.remove_retired(db, now, &mut recorded).await?;
+
+        let mut tx = db.begin().await?;
+        let tombstones = tx
+            .purge_timeline_tombstones(self.config.tombstone_seq_window, limit)
+            .await?;
+        tx.commit().await?;
+
+        let mut tx = db.begin().await?;
+        let entries = tx.delete_orphaned_entries(limit).await?;
+        tx.commit().await?;
+
+        if prune.removed + tombstones.removed + entries.removed > 0 {
+            info!(
+                removed_entries = prune.removed,
+                purged_tombstones = tombstones.removed,
+                deleted_orphans = entries.removed,
+                "timelines pruned"
+            );
+        } else {
+            debug!("no timeline entries to prune");
+        }
+        if entries.removed > 0 {
+            metric!(monotonic_counter.registry.entry.purged = entries.removed);
+        }
+
+        let wake = if prune.has_more || tombstones.has_more || entries.has_more {
+            WakeRequest::at(now)
+        } else {
+            WakeRequest::None
+        };
+        Ok(Reaction::new(recorded, wake))
+    }
+}
+
+/// Groups the affected feeds by timeline, one event per subscriber.
+fn timeline_changes(affected: &[(SubscriberId, FeedUrl)]) -> Vec<TimelineChangedEvent> {
+    let mut events: Vec<TimelineChangedEvent> = Vec::new();
+    for (subscriber_id, feed_url) in affected {
+        match events
+            .iter_mut()
+            .find(|event| &event.subscriber_id == subscriber_id)
+        {
+            Some(event) => {
+                if !event.affected_feeds.contains(feed_url) {
+                    event.affected_feeds.push(feed_url.clone());
+                }
+            }
+            None => events.push(TimelineChangedEvent::new(
+                subscriber_id.clone(),
+                vec![feed_url.clone()],
+            )),
+        }
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn feed_url(path: &str) -> FeedUrl {
+        FeedUrl::parse(&format!("https://example.com/{path}.xml")).unwrap()
+    }
+
+    #[test]
+    fn resolve_subtracts_max_age_from_now() {
+        let now = Utc.with_ymd_and_hms(2026, 6, 8, 12, 0, 0).unwrap();
+        let config = TimelineRetentionConfig {
+            max_age: Some(Duration::from_hours(24)),
+            ..TimelineRetentionConfig::default()
+        };
+
+        let retention = TimelineRetention::resolve(&config, now);
+
+        assert_eq!(
+            retention.ordered_before,
+            Some(Utc.with_ymd_and_hms(2026, 6, 7, 12, 0, 0).unwrap())
+        );
+        assert!(!retention.keeps_all());
+        assert!(TimelineRetention::resolve(&TimelineRetentionConfig::default(), now).keeps_all());
+    }
+
+    #[test]
+    fn timeline_changes_group_feeds_by_subscriber() {
+        let alice = SubscriberId::new("alice");
+        let bob = SubscriberId::new("bob");
+        let affected = vec![
+            (alice.clone(), feed_url("a")),
+            (bob.clone(), feed_url("a")),
+            (alice.clone(), feed_url("b")),
+            (alice.clone(), feed_url("a")),
+        ];
+
+        assert_eq!(
+            timeline_changes(&affected),
+            vec![
+                TimelineChangedEvent::new(alice, vec![feed_url("a"), feed_url("b")]),
+                TimelineChangedEvent::new(bob, vec![feed_url("a")]),
+            ]
+        );
+    }
+}
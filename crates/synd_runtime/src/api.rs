@@ -8,7 +8,7 @@ use synd_api::{
 use synd_persistence::sqlite::{SqliteDatabase, SqliteFeedRegistryDb};
 use synd_registry::{FeedRegistry, FeedRegistryConfig, event::WorkerSet};
 
-use crate::{Result, RuntimeDatabase};
+use crate::{NotifyConfig, Result, RuntimeDatabase, notify::Notifier};
 
 /// Prepared synd-api dependency graph for one runtime database.
 pub(crate) struct ApiService {
@@ -19,12 +19,16 @@ pub(crate) struct ApiService {
 impl ApiService {
     pub(crate) async fn from_database(
         database: &RuntimeDatabase,
+        registry_config: &FeedRegistryConfig,
+        notify: &NotifyConfig,
         authenticator: Authenticator,
         serve_options: ServeOptions,
         shutdown: &Shutdown,
     ) -> Result<Self> {
         Self::from_database_path(
             database.sqlite_path(),
+            registry_config,
+            notify,
             authenticator,
             serve_options,
             shutdown,
@@ -34,14 +38,18 @@ impl ApiService {
 
     pub(crate) async fn from_database_path(
         database_path: &Path,
+        registry_config: &FeedRegistryConfig,
+        notify: &NotifyConfig,
         authenticator: Authenticator,
         serve_options: ServeOptions,
         shutdown: &Shutdown,
     ) -> Result<Self> {
         let db = open_sqlite_registry_db(database_path).await?;
-        let config = FeedRegistryConfig::default();
         let (registry, event_workers) =
-            FeedRegistry::start(db, config, shutdown.cancellation_token());
+            FeedRegistry::start(db, *registry_config, shutdown.cancellation_token());
+        if notify.is_enabled() {
+            Notifier::new(notify.clone()).spawn(registry.subscribe_notifications(), shutdown);
+        }
 
         let dependency = Dependency::new(authenticator, registry, None, serve_options);
 
@@ -12,7 +12,9 @@ use crate::daemon::{
 };
 use crate::{
     DaemonState, DaemonStatus, Error, PlacementSummary, Result, Runtime, ShutdownResult,
+    SystemdUnits,
     connection::{RuntimeEndpointConnectionStatus, RuntimeEndpointConnector},
+    daemon::DaemonLauncher,
     placement::PlacementSpec,
     startup::{StartupLock, StartupLockAcquirer, StartupLockAcquisition},
 };
@@ -44,7 +46,11 @@ impl<'a> Control<'a> {
                     .status()
                     .await
                 {
-                    Ok(status) => Ok(DaemonStatus::running(summary, status.sessions().clone())),
+                    Ok(status) => Ok(DaemonStatus::running(
+                        summary,
+                        status.sessions().clone(),
+                        status.crawl().clone(),
+                    )),
                     Err(error) if daemon_status_endpoint_missing(&error) => {
                         Ok(DaemonStatus::new(DaemonState::Running, summary))
                     }
@@ -113,6 +119,18 @@ impl<'a> Control<'a> {
         }
     }
 
+    /// Renders systemd user units that run this runtime's daemon persistently.
+    pub fn systemd_units(&self) -> Result<SystemdUnits> {
+        let placement = self.runtime.placement().clone();
+        let config = self.runtime.config().daemon();
+        let config = config
+            .clone()
+            .with_session(config.session().with_persistent(true));
+        let launch = DaemonLauncher::new(&config, placement.clone()).resolve()?;
+
+        Ok(SystemdUnits::render(placement.endpoint().path(), &launch))
+    }
+
     pub async fn force_shutdown(&self) -> Result<ShutdownResult> {
         #[cfg(unix)]
         {
@@ -156,7 +156,7 @@ pub struct DaemonLaunchInfo {
 }
 
 impl DaemonLaunchInfo {
-    fn new(
+    pub(crate) fn new(
         executable: PathBuf,
         arguments: Vec<OsString>,
         environment: Vec<(OsString, OsString)>,
@@ -226,12 +226,6 @@ impl DaemonLaunchEnvironment {
         }
     }
 
-    fn apply_to(&self, command: &mut Command) {
-        for (key, value) in &self.values {
-            command.env(key, value);
-        }
-    }
-
     fn as_slice(&self) -> &[(OsString, OsString)] {
         &self.values
     }
@@ -266,6 +260,7 @@ impl DaemonServeArguments {
 struct DaemonSessionServeArguments {
     lease_duration: Duration,
     idle_shutdown_grace: Duration,
+    persistent: bool,
 }
 
 impl From<DaemonSessionConfig> for DaemonSessionServeArguments {
@@ -273,6 +268,7 @@ impl From<DaemonSessionConfig> for DaemonSessionServeArguments {
         Self {
             lease_duration: config.lease_policy().lease_duration(),
             idle_shutdown_grace: config.idle_shutdown_grace(),
+            persistent: config.is_persistent(),
         }
     }
 }
@@ -282,13 +278,16 @@ impl IntoIterator for DaemonSessionServeArguments {
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
-        vec![
+        let mut arguments = vec![
             OsString::from("--session-lease-duration"),
             OsString::from(String::from(HumanDuration::from(self.lease_duration))),
             OsString::from("--session-idle-shutdown-grace"),
             OsString::from(String::from(HumanDuration::from(self.idle_shutdown_grace))),
-        ]
-        .into_iter()
+        ];
+        if self.persistent {
+            arguments.push(OsString::from("--persistent"));
+        }
+        arguments.into_iter()
     }
 }
 
@@ -303,29 +302,34 @@ impl<'a> DaemonLauncher<'a> {
         Self { config, placement }
     }
 
-    pub(crate) fn launch(self) -> Result<DaemonHandle> {
+    /// Resolves the daemon command for the placement without starting it.
+    pub(crate) fn resolve(&self) -> Result<DaemonLaunchInfo> {
         let command = ResolvedDaemonLaunchCommand::daemon_serve(
             self.config.executable().resolve()?,
             self.placement.instance().canonical_database_path(),
             self.config.session(),
         );
         let environment = DaemonLaunchEnvironment::from_placement(&self.placement);
-        let log = self.config.log().open()?;
-        let launch = DaemonLaunchInfo::new(
-            command.executable.clone(),
+
+        Ok(DaemonLaunchInfo::new(
+            command.executable,
             command.arguments.as_slice().to_vec(),
             environment.as_slice().to_vec(),
             self.config.log().path().to_path_buf(),
-        );
+        ))
+    }
+
+    pub(crate) fn launch(self) -> Result<DaemonHandle> {
+        let launch = self.resolve()?;
+        let log = self.config.log().open()?;
         debug!(
             daemon_launch = ?launch,
             "Launching daemon"
         );
 
-        let mut child_command = Command::new(&command.executable);
-        child_command.args(command.arguments.as_slice());
-        environment.apply_to(&mut child_command);
-        let child = child_command
+        let child = Command::new(launch.executable())
+            .args(launch.arguments())
+            .envs(launch.environment().iter().cloned())
             .stdin(Stdio::null())
             .stdout(Stdio::from(log.stdout))
             .stderr(Stdio::from(log.stderr))
@@ -426,6 +430,24 @@ mod tests {
         }
     }
 
+    mod persistent_daemon_serve_command {
+        use super::*;
+
+        #[test]
+        fn appends_persistent_flag() {
+            let command = ResolvedDaemonLaunchCommand::daemon_serve(
+                DaemonExecutable::path("/usr/bin/synd").resolve().unwrap(),
+                Path::new("/tmp/synd.db"),
+                DaemonSessionConfig::default().with_persistent(true),
+            );
+
+            assert_eq!(
+                command.arguments.as_slice().last(),
+                Some(&OsString::from("--persistent"))
+            );
+        }
+    }
+
     mod launch_log {
         use super::*;
 
@@ -4,6 +4,7 @@ mod control;
 mod launch;
 mod service;
 mod status;
+mod unit;
 
 #[cfg(unix)]
 pub(crate) use claim::{
@@ -15,3 +16,4 @@ pub use launch::{DaemonExecutable, DaemonLaunchConfig, DaemonLaunchInfo, DaemonL
 pub(crate) use launch::{DaemonHandle, DaemonLauncher};
 pub use service::{Daemon, DaemonConfig};
 pub use status::{PlacementSummary, ShutdownResult, State, Status};
+pub use unit::SystemdUnits;
@@ -9,6 +9,8 @@ use std::{
     time::{Duration, Instant},
 };
 
+#[cfg(unix)]
+use listenfd::ListenFd;
 #[cfg(test)]
 use synd_api::session::DaemonSessionLeasePolicy;
 use synd_api::{
@@ -16,7 +18,9 @@ use synd_api::{
     session::DaemonSessionConfig,
     shutdown::Shutdown,
 };
+use synd_registry::{CrawlWorkerHostConfig, FeedRegistryConfig, TimelineRetentionConfig};
 
+use tokio::net::TcpListener;
 #[cfg(unix)]
 use tokio::net::UnixListener;
 use tracing::{debug, info, warn};
@@ -24,7 +28,7 @@ use tracing::{debug, info, warn};
 #[cfg(unix)]
 use crate::daemon::DaemonClaimOwner;
 use crate::{
-    Error, Result, RuntimeDatabase,
+    DaemonListener, Error, NotifyConfig, Result, RuntimeDatabase,
     api::ApiService,
     placement::{PlacementEnvironment, PlacementResolver, PlacementSpec},
 };
@@ -54,6 +58,10 @@ impl Daemon {
     }
 
     async fn serve_placement(self, placement: PlacementSpec) -> Result<()> {
+        if let Some(listener) = self.config.listener.clone() {
+            return self.serve_tcp(placement, listener).await;
+        }
+
         #[cfg(unix)]
         {
             self.serve_unix(placement).await
@@ -71,11 +79,19 @@ impl Daemon {
     async fn serve_unix(self, placement: PlacementSpec) -> Result<()> {
         let started_at = Instant::now();
         let _claim = DaemonClaimOwner::create(&placement)?;
-        let bound_endpoint = DaemonEndpointBinder::new(placement.endpoint()).bind()?;
-        let (listener, endpoint_cleanup) = bound_endpoint.into_parts();
+        let (listener, endpoint_cleanup) =
+            if let Some(listener) = take_inherited_listener(placement.endpoint())? {
+                (listener, None)
+            } else {
+                let bound_endpoint = DaemonEndpointBinder::new(placement.endpoint()).bind()?;
+                let (listener, endpoint_cleanup) = bound_endpoint.into_parts();
+                (listener, Some(endpoint_cleanup))
+            };
         let shutdown_endpoint_cleanup = endpoint_cleanup.clone();
         let shutdown = Shutdown::manual(move || {
-            if let Err(error) = shutdown_endpoint_cleanup.unlink_socket() {
+            if let Some(cleanup) = &shutdown_endpoint_cleanup
+                && let Err(error) = cleanup.unlink_socket()
+            {
                 warn!(
                     error = %error,
                     "Failed to cleanup daemon endpoint during shutdown"
@@ -88,6 +104,8 @@ impl Daemon {
         let daemon_sessions = serve_options.daemon_sessions;
         let api_service = ApiService::from_database(
             self.config.database(),
+            &self.config.registry,
+            &self.config.notify,
             Authenticator::trusted_local(),
             serve_options,
             &shutdown,
@@ -100,8 +118,10 @@ impl Daemon {
             runtime_instance_id = %placement.instance().id(),
             database = %placement.instance().canonical_database_path().display(),
             endpoint = %placement.endpoint().path().display(),
+            socket_activated = endpoint_cleanup.is_none(),
             session_lease_ms = daemon_sessions.lease_policy().lease_duration().as_millis(),
             idle_shutdown_grace_ms = daemon_sessions.idle_shutdown_grace().as_millis(),
+            persistent = daemon_sessions.is_persistent(),
             "Daemon ready"
         );
 
@@ -117,12 +137,96 @@ impl Daemon {
 
         Ok(())
     }
+
+    /// Serves remote clients until a shutdown signal arrives.
+    ///
+    /// Remote daemons are always on: they hold the daemon claim so no local
+    /// daemon opens the same database, but never shut down when idle.
+    async fn serve_tcp(self, placement: PlacementSpec, listener: DaemonListener) -> Result<()> {
+        let started_at = Instant::now();
+        #[cfg(unix)]
+        let _claim = DaemonClaimOwner::create(&placement)?;
+        let tls_config = serve::rustls_config_from_pem_files(
+            listener.tls_certificate(),
+            listener.tls_private_key(),
+        )
+        .await?;
+        let tcp_listener = TcpListener::bind(listener.addr()).await?;
+        let local_addr = tcp_listener.local_addr()?;
+        let shutdown = Shutdown::watch_signal(shutdown_signal(), || {
+            debug!("Running daemon shutdown callback");
+        });
+        let shutdown_status = shutdown.clone();
+        let authenticator = if listener.is_multi_user() {
+            Authenticator::new()?.with_operator_token(listener.token().as_str())?
+        } else {
+            Authenticator::local(listener.token().as_str())?
+        };
+        // Identity provider users must not make the daemon fetch from the
+        // network it runs in.
+        let mut registry = self.config.registry;
+        registry.crawl_worker_pool.fetch.public_only = listener.is_multi_user();
+        let api_service = ApiService::from_database(
+            self.config.database(),
+            &registry,
+            &self.config.notify,
+            authenticator,
+            self.config.serve_options(),
+            &shutdown,
+        )
+        .await?;
+        let (mut dependency, _event_workers) = api_service.into_parts();
+        dependency.tls_config = Some(tls_config);
+        info!(
+            pid = std::process::id(),
+            runtime_instance_id = %placement.instance().id(),
+            database = %placement.instance().canonical_database_path().display(),
+            listen = %local_addr,
+            multi_user = listener.is_multi_user(),
+            max_subscriptions = ?self.config.registry.subscription_quota.max_subscriptions,
+            "Remote daemon ready"
+        );
+
+        // Keep event workers alive for the entire serve future.
+        serve::serve(tcp_listener, dependency, shutdown).await?;
+        info!(
+            reason = shutdown_status
+                .reason()
+                .map_or("unknown", synd_api::shutdown::ShutdownReason::as_str),
+            uptime_ms = started_at.elapsed().as_millis(),
+            "Remote daemon stopped"
+        );
+
+        Ok(())
+    }
+}
+
+/// Resolves on Ctrl-C, or on SIGTERM, which systemd, containers and
+/// supervisors send to stop an always-on daemon.
+async fn shutdown_signal() -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        let mut terminate = signal(SignalKind::terminate())?;
+        tokio::select! {
+            result = tokio::signal::ctrl_c() => result,
+            _ = terminate.recv() => Ok(()),
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        tokio::signal::ctrl_c().await
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct DaemonConfig {
     database: RuntimeDatabase,
     session: DaemonSessionConfig,
+    registry: FeedRegistryConfig,
+    notify: NotifyConfig,
+    listener: Option<DaemonListener>,
     placement_environment: PlacementEnvironment,
     #[cfg(test)]
     session_lease_policy: Option<DaemonSessionLeasePolicy>,
@@ -133,6 +237,9 @@ impl DaemonConfig {
         Self {
             database,
             session: DaemonSessionConfig::default(),
+            registry: FeedRegistryConfig::default(),
+            notify: NotifyConfig::default(),
+            listener: None,
             placement_environment: PlacementEnvironment::capture(),
             #[cfg(test)]
             session_lease_policy: None,
@@ -159,12 +266,58 @@ impl DaemonConfig {
         self
     }
 
+    #[must_use]
+    pub fn with_crawl_host(mut self, host: CrawlWorkerHostConfig) -> Self {
+        self.registry.crawl_worker_pool.host = host;
+        self
+    }
+
     #[must_use]
     pub fn with_runtime_root(mut self, root: impl Into<PathBuf>) -> Self {
         self.placement_environment = PlacementEnvironment::from_root(root);
         self
     }
 
+    /// Keeps the daemon running after its last session is gone.
+    #[must_use]
+    pub fn with_persistent(mut self, persistent: bool) -> Self {
+        self.session = self.session.with_persistent(persistent);
+        self
+    }
+
+    /// Limits how many feeds each subscriber may subscribe to.
+    #[must_use]
+    pub fn with_max_subscriptions(mut self, max_subscriptions: usize) -> Self {
+        self.registry.subscription_quota.max_subscriptions = Some(max_subscriptions);
+        self
+    }
+
+    /// Prunes old entries off timelines and purges removals clients no
+    /// longer sync.
+    #[must_use]
+    pub fn with_timeline_retention(mut self, retention: TimelineRetentionConfig) -> Self {
+        self.registry.timeline_retention = retention;
+        self
+    }
+
+    /// Delivers fresh entries matching the notification rules to their sinks.
+    #[must_use]
+    pub fn with_notify(mut self, notify: NotifyConfig) -> Self {
+        self.notify = notify;
+        self
+    }
+
+    /// Serves remote clients on `listener` instead of the runtime socket.
+    #[must_use]
+    pub fn with_listener(mut self, listener: DaemonListener) -> Self {
+        self.listener = Some(listener);
+        self
+    }
+
+    pub fn listener(&self) -> Option<&DaemonListener> {
+        self.listener.as_ref()
+    }
+
     fn serve_options(&self) -> serve::ServeOptions {
         #[cfg(test)]
         let session = match self.session_lease_policy {
@@ -193,6 +346,27 @@ impl DaemonConfig {
     }
 }
 
+/// Takes the endpoint socket passed by systemd socket activation (`LISTEN_FDS`).
+///
+/// systemd owns the socket file, so the daemon leaves it in place on shutdown.
+#[cfg(unix)]
+fn take_inherited_listener(endpoint: &UdsEndpoint) -> Result<Option<UnixListener>> {
+    let Some(listener) = ListenFd::from_env().take_unix_listener(0)? else {
+        return Ok(None);
+    };
+    let local_addr = listener.local_addr()?;
+    if local_addr.as_pathname() != Some(endpoint.path()) {
+        warn!(
+            inherited_endpoint = ?local_addr.as_pathname(),
+            endpoint = %endpoint.path().display(),
+            "Inherited socket is not the runtime endpoint; runtimes will not connect to this daemon"
+        );
+    }
+    listener.set_nonblocking(true)?;
+
+    Ok(Some(UnixListener::from_std(listener)?))
+}
+
 /// Binds the Unix domain socket endpoint for a daemon.
 #[cfg(unix)]
 struct DaemonEndpointBinder<'a> {
@@ -367,6 +541,7 @@ mod tests {
     #[cfg(unix)]
     #[derive(Debug, Default)]
     struct StartedDaemonConfig {
+        persistent: bool,
         session_lease_policy: Option<DaemonSessionLeasePolicy>,
         renewal_observer: Option<SessionRenewalObserver>,
         session_requirements: Option<SessionRequirements>,
@@ -374,6 +549,11 @@ mod tests {
 
     #[cfg(unix)]
     impl StartedDaemonConfig {
+        fn with_persistent(mut self) -> Self {
+            self.persistent = true;
+            self
+        }
+
         fn with_session_lease_policy(mut self, lease_policy: DaemonSessionLeasePolicy) -> Self {
             self.session_lease_policy = Some(lease_policy);
             self
@@ -470,8 +650,9 @@ mod tests {
                 }
             };
             let runtime = Runtime::try_new(runtime_config)?;
-            let mut daemon_config =
-                DaemonConfig::new(database).with_placement_environment(placement_environment);
+            let mut daemon_config = DaemonConfig::new(database)
+                .with_placement_environment(placement_environment)
+                .with_persistent(config.persistent);
             if let Some(lease_policy) = config.session_lease_policy {
                 daemon_config = daemon_config.with_session_lease_policy(lease_policy);
             }
@@ -562,6 +743,35 @@ mod tests {
         }
     }
 
+    #[cfg(unix)]
+    mod persistent {
+        use super::*;
+
+        #[tokio::test]
+        async fn disables_idle_shutdown() -> crate::Result<()> {
+            let tmp = tempfile::tempdir()?;
+            let mut daemon = StartedDaemon::spawn_with_config(
+                tmp.path(),
+                StartedDaemonConfig::default().with_persistent(),
+            )?;
+
+            daemon.wait_until_running().await;
+            daemon
+                .probe
+                .runtime
+                .acquire_session()
+                .await?
+                .close()
+                .await?;
+            let status = daemon.probe.runtime.daemon().inspect().await?;
+
+            assert_eq!(status.state(), DaemonState::Running);
+            assert!(!status.sessions().unwrap().idle_shutdown().is_enabled());
+            daemon.shutdown().await;
+            Ok(())
+        }
+    }
+
     #[cfg(unix)]
     mod session {
         use super::*;
@@ -664,6 +874,226 @@ mod tests {
         }
     }
 
+    #[cfg(unix)]
+    mod remote {
+        use url::Url;
+
+        use crate::{DaemonListener, RemoteEndpoint, RemoteToken};
+
+        use super::*;
+
+        struct RemoteDaemon {
+            endpoint: Url,
+            token: RemoteToken,
+            daemon_task: tokio::task::JoinHandle<crate::Result<()>>,
+        }
+
+        impl RemoteDaemon {
+            fn spawn(root: &Path) -> crate::Result<Self> {
+                Self::spawn_with(root, |config| config)
+            }
+
+            fn spawn_with(
+                root: &Path,
+                configure: impl FnOnce(DaemonConfig) -> DaemonConfig,
+            ) -> crate::Result<Self> {
+                let _ = rustls::crypto::ring::default_provider().install_default();
+                let addr = std::net::TcpListener::bind(("127.0.0.1", 0))?.local_addr()?;
+                let token_file = root.join("token");
+                std::fs::write(&token_file, "secret\n")?;
+                let token = RemoteToken::from_file(&token_file)?;
+                let daemon_config = configure(
+                    DaemonConfig::new(RuntimeDatabase::sqlite(root.join("synd.db")))
+                        .with_placement_environment(PlacementEnvironment::new(PlacementRoot::from(
+                            root.join("runtime"),
+                        )))
+                        .with_listener(DaemonListener::new(
+                            addr,
+                            synd_test::certificate(),
+                            synd_test::private_key(),
+                            token.clone(),
+                        )),
+                );
+                let daemon_task = tokio::spawn(Daemon::new(daemon_config).serve());
+
+                Ok(Self {
+                    endpoint: Url::parse(&format!("https://{addr}")).unwrap(),
+                    token,
+                    daemon_task,
+                })
+            }
+
+            fn runtime(&self, root: &Path, token: RemoteToken) -> crate::Result<Runtime> {
+                Runtime::try_new(
+                    RuntimeConfig::new(RuntimeDatabase::sqlite(root.join("client.db")))
+                        .with_api_timeout(Duration::from_secs(2), "synd-runtime-test")
+                        .with_placement_environment(PlacementEnvironment::new(PlacementRoot::from(
+                            root.join("client-runtime"),
+                        )))
+                        .with_remote(
+                            RemoteEndpoint::new(self.endpoint.clone(), token)
+                                .danger_accept_invalid_certs(true),
+                        ),
+                )
+            }
+
+            async fn wait_until_serving(&mut self, runtime: &Runtime) -> crate::Session {
+                let deadline = Instant::now() + DAEMON_READY_TIMEOUT;
+
+                loop {
+                    assert!(
+                        !self.daemon_task.is_finished(),
+                        "remote daemon finished before serving"
+                    );
+                    match runtime.acquire_session().await {
+                        Ok(session) => return session,
+                        Err(error) => assert!(
+                            Instant::now() < deadline,
+                            "timed out waiting for remote daemon: {error:?}"
+                        ),
+                    }
+
+                    tokio::time::sleep(DAEMON_POLL_INTERVAL).await;
+                }
+            }
+        }
+
+        #[tokio::test]
+        async fn serves_token_authenticated_sessions() -> crate::Result<()> {
+            let tmp = tempfile::tempdir()?;
+            let mut daemon = RemoteDaemon::spawn(tmp.path())?;
+            let runtime = daemon.runtime(tmp.path(), daemon.token.clone())?;
+
+            let session = daemon.wait_until_serving(&runtime).await;
+            assert_eq!(
+                session.available_capabilities(),
+                &synd_protocol::capability::local_api_capabilities()
+            );
+            assert_eq!(
+                session.client().fetch_capabilities().await?,
+                synd_protocol::capability::local_api_capabilities()
+            );
+            session.client().fetch_subscription(None, Some(10)).await?;
+            session.close().await?;
+            assert!(!daemon.daemon_task.is_finished());
+
+            daemon.daemon_task.abort();
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn rejects_unknown_token() -> crate::Result<()> {
+            let tmp = tempfile::tempdir()?;
+            let mut daemon = RemoteDaemon::spawn(tmp.path())?;
+            let runtime = daemon.runtime(tmp.path(), daemon.token.clone())?;
+            daemon.wait_until_serving(&runtime).await.close().await?;
+
+            let wrong_token = tmp.path().join("wrong-token");
+            std::fs::write(&wrong_token, "guess")?;
+            let runtime = daemon.runtime(tmp.path(), RemoteToken::from_file(&wrong_token)?)?;
+            let error = runtime.acquire_session().await.err().unwrap();
+
+            assert!(
+                matches!(error, crate::Error::RemoteSessionUnavailable { .. }),
+                "unexpected error: {error:?}"
+            );
+            daemon.daemon_task.abort();
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn operator_manages_subscribers_of_multi_user_daemon() -> crate::Result<()> {
+            let tmp = tempfile::tempdir()?;
+            let mut daemon = RemoteDaemon::spawn_with(tmp.path(), |config| {
+                let listener = config.listener().cloned().unwrap().with_multi_user(true);
+                config.with_listener(listener).with_max_subscriptions(1)
+            })?;
+            let runtime = daemon.runtime(tmp.path(), daemon.token.clone())?;
+            let session = daemon.wait_until_serving(&runtime).await;
+            let client = session.client();
+
+            client
+                .subscribe_feed(subscribe_input("https://example.com/a.xml"))
+                .await?;
+            assert!(
+                client
+                    .subscribe_feed(subscribe_input("https://example.com/b.xml"))
+                    .await
+                    .is_err()
+            );
+
+            let subscribers = client.fetch_subscribers(None, 10).await?;
+            assert_eq!(subscribers.nodes.len(), 1);
+            assert_eq!(subscribers.nodes[0].id, "local");
+            assert_eq!(subscribers.nodes[0].subscription_count, 1);
+            let details = client
+                .fetch_subscriber("local".to_owned(), None, 10)
+                .await?
+                .unwrap();
+            assert_eq!(
+                details.subscriptions.nodes[0].url.as_str(),
+                "https://example.com/a.xml"
+            );
+
+            let db = synd_persistence::sqlite::SqliteDatabase::open(tmp.path().join("synd.db"))
+                .await
+                .unwrap();
+            // The timeline projection creates the timeline in the background.
+            for _ in 0..100 {
+                if subscriber_rows(&db, "timeline").await > 0 {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+            assert_eq!(subscriber_rows(&db, "timeline").await, 1);
+
+            let err = client
+                .discover_feeds("http://127.0.0.1:1/".try_into().unwrap())
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("not a public address"), "{err}");
+
+            let deleted = client.delete_subscriber("local".to_owned()).await?;
+            assert_eq!(deleted.unsubscribed, 1);
+            assert!(
+                client
+                    .fetch_subscriber("local".to_owned(), None, 10)
+                    .await?
+                    .is_none()
+            );
+            for table in ["timeline", "timeline_entry", "entry_read"] {
+                assert_eq!(subscriber_rows(&db, table).await, 0, "{table}");
+            }
+
+            session.close().await?;
+            daemon.daemon_task.abort();
+            Ok(())
+        }
+
+        async fn subscriber_rows(
+            db: &synd_persistence::sqlite::SqliteDatabase,
+            table: &str,
+        ) -> i64 {
+            let mut tx = db.begin().await.unwrap();
+            sqlx::query_scalar(&format!(
+                "SELECT COUNT(*) FROM {table} WHERE subscriber_id = 'local'"
+            ))
+            .fetch_one(&mut *tx)
+            .await
+            .unwrap()
+        }
+
+        fn subscribe_input(url: &str) -> synd_client::payload::SubscribeFeedInput {
+            synd_client::payload::SubscribeFeedInput {
+                url: url.try_into().unwrap(),
+                requirement: None,
+                category: None,
+                crawl_policy: None,
+                fetch_full_content: false,
+            }
+        }
+    }
+
     #[cfg(unix)]
     mod endpoint_binding {
         use super::*;
@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 
-use synd_protocol::daemon::DaemonSessionStatus;
+use synd_protocol::daemon::{DaemonCrawlStatus, DaemonSessionStatus};
 
 use crate::placement::PlacementSpec;
 
@@ -15,6 +15,7 @@ pub struct Status {
     state: State,
     placement: PlacementSummary,
     sessions: Option<DaemonSessionStatus>,
+    crawl: Option<DaemonCrawlStatus>,
 }
 
 impl Status {
@@ -23,14 +24,20 @@ impl Status {
             state,
             placement,
             sessions: None,
+            crawl: None,
         }
     }
 
-    pub(crate) fn running(placement: PlacementSummary, sessions: DaemonSessionStatus) -> Self {
+    pub(crate) fn running(
+        placement: PlacementSummary,
+        sessions: DaemonSessionStatus,
+        crawl: DaemonCrawlStatus,
+    ) -> Self {
         Self {
             state: State::Running,
             placement,
             sessions: Some(sessions),
+            crawl: Some(crawl),
         }
     }
 
@@ -45,6 +52,10 @@ impl Status {
     pub fn sessions(&self) -> Option<&DaemonSessionStatus> {
         self.sessions.as_ref()
     }
+
+    pub fn crawl(&self) -> Option<&DaemonCrawlStatus> {
+        self.crawl.as_ref()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
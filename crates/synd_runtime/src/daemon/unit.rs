@@ -0,0 +1,162 @@
+use std::{ffi::OsStr, fmt::Write as _, path::Path};
+
+use crate::daemon::DaemonLaunchInfo;
+
+const SOCKET_UNIT_NAME: &str = "synd-daemon.socket";
+const SERVICE_UNIT_NAME: &str = "synd-daemon.service";
+
+/// systemd user units that keep a socket-activated daemon running.
+///
+/// The socket unit listens on the runtime endpoint, so runtimes resolving the
+/// same placement connect to the managed daemon instead of launching their own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemdUnits {
+    socket: String,
+    service: String,
+}
+
+impl SystemdUnits {
+    pub(crate) fn render(endpoint: &Path, launch: &DaemonLaunchInfo) -> Self {
+        Self {
+            socket: render_socket(endpoint),
+            service: render_service(launch),
+        }
+    }
+
+    pub fn socket_name(&self) -> &'static str {
+        SOCKET_UNIT_NAME
+    }
+
+    pub fn socket(&self) -> &str {
+        &self.socket
+    }
+
+    pub fn service_name(&self) -> &'static str {
+        SERVICE_UNIT_NAME
+    }
+
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+}
+
+fn render_socket(endpoint: &Path) -> String {
+    format!(
+        "[Unit]
+Description=syndicationd runtime daemon socket
+
+[Socket]
+ListenStream={endpoint}
+SocketMode=0600
+DirectoryMode=0700
+
+[Install]
+WantedBy=sockets.target
+",
+        endpoint = escape_specifiers(&endpoint.to_string_lossy()),
+    )
+}
+
+fn render_service(launch: &DaemonLaunchInfo) -> String {
+    let mut environment = String::new();
+    for (key, value) in launch.environment() {
+        let assignment = format!("{}={}", key.to_string_lossy(), value.to_string_lossy());
+        let _ = writeln!(environment, "Environment={}", quote_word(&assignment));
+    }
+    let exec_start = std::iter::once(launch.executable().as_os_str())
+        .chain(launch.arguments().iter().map(AsRef::as_ref))
+        .map(|word: &OsStr| quote_word(&word.to_string_lossy()))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(
+        "[Unit]
+Description=syndicationd runtime daemon
+Requires={SOCKET_UNIT_NAME}
+After={SOCKET_UNIT_NAME}
+
+[Service]
+{environment}ExecStart={exec_start}
+Restart=on-failure
+
+[Install]
+WantedBy=default.target
+"
+    )
+}
+
+/// Escapes `%` specifiers, which systemd expands in every unit setting.
+fn escape_specifiers(value: &str) -> String {
+    value.replace('%', "%%")
+}
+
+/// Quotes one command line word the way systemd splits `ExecStart=` and
+/// `Environment=`.
+fn quote_word(word: &str) -> String {
+    let word = escape_specifiers(word).replace('$', "$$");
+    if !word.is_empty()
+        && !word
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '"' | '\'' | '\\' | ';'))
+    {
+        return word;
+    }
+
+    format!("\"{}\"", word.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::OsString, path::PathBuf};
+
+    use crate::daemon::DaemonLaunchInfo;
+
+    use super::{SystemdUnits, quote_word};
+
+    #[test]
+    fn service_runs_persistent_daemon_serve() {
+        let launch = DaemonLaunchInfo::new(
+            PathBuf::from("/usr/bin/synd"),
+            [
+                "daemon",
+                "serve",
+                "--sqlite-db",
+                "/data/my feeds/synd.db",
+                "--persistent",
+            ]
+            .into_iter()
+            .map(OsString::from)
+            .collect(),
+            vec![(
+                OsString::from("SYND_RUNTIME_ROOT"),
+                OsString::from("/run/user/1000/syndicationd"),
+            )],
+            PathBuf::from("/tmp/synd.log"),
+        );
+
+        let units = SystemdUnits::render(
+            &PathBuf::from("/run/user/1000/syndicationd/abc.sock"),
+            &launch,
+        );
+
+        assert!(
+            units
+                .socket()
+                .contains("ListenStream=/run/user/1000/syndicationd/abc.sock\n")
+        );
+        assert!(units.service().contains(
+            "Environment=SYND_RUNTIME_ROOT=/run/user/1000/syndicationd\n\
+             ExecStart=/usr/bin/synd daemon serve --sqlite-db \"/data/my feeds/synd.db\" --persistent\n"
+        ));
+        assert!(units.service().contains("Requires=synd-daemon.socket\n"));
+    }
+
+    #[test]
+    fn quotes_systemd_special_characters() {
+        assert_eq!(quote_word("plain"), "plain");
+        assert_eq!(quote_word("100%"), "100%%");
+        assert_eq!(quote_word("$HOME"), "$$HOME");
+        assert_eq!(quote_word(r#"a "b""#), r#""a \"b\"""#);
+        assert_eq!(quote_word(""), r#""""#);
+    }
+}
@@ -3,6 +3,7 @@ use std::{path::PathBuf, process::ExitStatus};
 use synd_protocol::CapabilitySet;
 
 use thiserror::Error;
+use url::Url;
 
 use crate::DaemonLaunchInfo;
 
@@ -46,6 +47,31 @@ pub enum Error {
         missing_capabilities: CapabilitySet,
     },
 
+    #[error(
+        "remote daemon at {endpoint} is missing required session capabilities: {missing_capabilities}"
+    )]
+    MissingRemoteSessionCapabilities {
+        endpoint: Url,
+        missing_capabilities: CapabilitySet,
+    },
+
+    #[error("failed to open remote session at {endpoint}")]
+    RemoteSessionUnavailable {
+        endpoint: Url,
+        #[source]
+        source: Box<synd_client::SyndApiError>,
+    },
+
+    #[error("invalid notification title pattern `{pattern}`")]
+    InvalidNotifyTitle {
+        pattern: String,
+        #[source]
+        source: regex::Error,
+    },
+
+    #[error("remote daemon token file {} is empty", path.display())]
+    EmptyRemoteToken { path: PathBuf },
+
     #[error("refusing to remove non-socket runtime endpoint {}", path.display())]
     NonSocketEndpoint { path: PathBuf },
 
@@ -9,7 +9,9 @@ mod database;
 mod error;
 #[allow(dead_code)]
 mod instance;
+mod notify;
 mod placement;
+mod remote;
 mod runtime;
 mod session;
 #[allow(dead_code)]
@@ -20,14 +22,18 @@ mod uds;
 pub use daemon::{
     Control as DaemonControl, Daemon, DaemonConfig, DaemonExecutable, DaemonLaunchConfig,
     DaemonLaunchInfo, DaemonLaunchLog, PlacementSummary, ShutdownResult, State as DaemonState,
-    Status as DaemonStatus,
+    Status as DaemonStatus, SystemdUnits,
 };
 pub use database::RuntimeDatabase;
 pub use error::{Error, Result};
+pub use notify::{NotifyConfig, NotifyRule, NotifySink};
+pub use remote::{DaemonListener, RemoteEndpoint, RemoteToken};
 pub use runtime::{ApiClientConfig, Config as RuntimeConfig, Runtime};
 pub use session::{
     Config as SessionConfig, Handle as SessionHandle, Requirements as SessionRequirements, Session,
 };
 pub use synd_api::session::{DaemonSessionConfig, DaemonSessionLeasePolicy};
 pub use synd_protocol::CapabilitySet;
-pub use synd_protocol::daemon::{DaemonIdleShutdownStatus, DaemonSessionStatus};
+pub use synd_protocol::daemon::{
+    DaemonCrawlStatus, DaemonHostBackoff, DaemonIdleShutdownStatus, DaemonSessionStatus,
+};
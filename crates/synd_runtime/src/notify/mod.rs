@@ -0,0 +1,310 @@
+//! Delivery of fresh entries matching configured rules to notification sinks.
+
+mod sink;
+
+use regex::Regex;
+use synd_api::{local_subscriber_id, shutdown::Shutdown};
+use synd_feed::types::{Category, Requirement};
+use synd_registry::{
+    SubscriberId,
+    notify::{EntryNotification, EntryNotificationRecvError, EntryNotificationSubscriber},
+};
+use tracing::{debug, warn};
+
+pub use sink::NotifySink;
+use sink::{Notification, SinkClient};
+
+use crate::{Error, Result};
+
+/// Conditions an entry must meet to be notified. Unset conditions match
+/// every entry, except the subscriber, which defaults to the local one so
+/// other users' entries never reach the operator's sinks by accident.
+#[derive(Debug, Clone, Default)]
+pub struct NotifyRule {
+    subscriber: Option<SubscriberId>,
+    requirement: Option<Requirement>,
+    category: Option<Category<'static>>,
+    title: Option<Regex>,
+}
+
+impl NotifyRule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches subscriptions of `subscriber` instead of the local subscriber.
+    #[must_use]
+    pub fn with_subscriber(mut self, subscriber: SubscriberId) -> Self {
+        self.subscriber = Some(subscriber);
+        self
+    }
+
+    /// Matches subscriptions at least as required as `requirement`.
+    #[must_use]
+    pub fn with_requirement(mut self, requirement: Requirement) -> Self {
+        self.requirement = Some(requirement);
+        self
+    }
+
+    #[must_use]
+    pub fn with_category(mut self, category: Category<'static>) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Matches entries whose title matches the regular expression `pattern`.
+    pub fn with_title(mut self, pattern: &str) -> Result<Self> {
+        let title = Regex::new(pattern).map_err(|source| Error::InvalidNotifyTitle {
+            pattern: pattern.to_owned(),
+            source,
+        })?;
+        self.title = Some(title);
+        Ok(self)
+    }
+
+    fn matches(&self, notification: &EntryNotification) -> bool {
+        let subscription = &notification.subscription;
+        let subscriber = match &self.subscriber {
+            Some(subscriber) => &subscription.subscriber_id == subscriber,
+            None => subscription.subscriber_id == local_subscriber_id(),
+        };
+        let requirement = self.requirement.is_none_or(|min| {
+            subscription
+                .requirement
+                .is_some_and(|requirement| requirement.is_satisfied(min))
+        });
+        let category = self
+            .category
+            .as_ref()
+            .is_none_or(|category| subscription.category.as_ref() == Some(category));
+        let title = self.title.as_ref().is_none_or(|pattern| {
+            notification
+                .entry_title()
+                .is_some_and(|title| pattern.is_match(title))
+        });
+
+        subscriber && requirement && category && title
+    }
+}
+
+/// Rules selecting notified entries and the sinks delivering them.
+#[derive(Debug, Clone, Default)]
+pub struct NotifyConfig {
+    rules: Vec<NotifyRule>,
+    sinks: Vec<NotifySink>,
+}
+
+impl NotifyConfig {
+    pub fn new(rules: Vec<NotifyRule>, sinks: Vec<NotifySink>) -> Self {
+        Self { rules, sinks }
+    }
+
+    pub fn rules(&self) -> &[NotifyRule] {
+        &self.rules
+    }
+
+    pub fn sinks(&self) -> &[NotifySink] {
+        &self.sinks
+    }
+
+    /// Whether any entry can be notified at all.
+    pub fn is_enabled(&self) -> bool {
+        !self.rules.is_empty() && !self.sinks.is_empty()
+    }
+
+    fn matches(&self, notification: &EntryNotification) -> bool {
+        self.rules.iter().any(|rule| rule.matches(notification))
+    }
+}
+
+/// Delivers registry entry notifications until the daemon shuts down.
+pub(crate) struct Notifier {
+    config: NotifyConfig,
+    client: SinkClient,
+}
+
+impl Notifier {
+    pub(crate) fn new(config: NotifyConfig) -> Self {
+        Self {
+            config,
+            client: SinkClient::new(),
+        }
+    }
+
+    pub(crate) fn spawn(self, notifications: EntryNotificationSubscriber, shutdown: &Shutdown) {
+        let ct = shutdown.cancellation_token();
+        tokio::spawn(async move {
+            tokio::select! {
+                () = ct.cancelled() => {},
+                () = self.run(notifications) => {},
+            }
+        });
+    }
+
+    async fn run(self, mut notifications: EntryNotificationSubscriber) {
+        loop {
+            match notifications.recv().await {
+                Ok(notification) => self.notify(&notification).await,
+                Err(EntryNotificationRecvError::Lagged(skipped)) => {
+                    warn!(
+                        skipped,
+                        "Notifier fell behind and dropped entry notifications"
+                    );
+                }
+                Err(EntryNotificationRecvError::Closed) => return,
+            }
+        }
+    }
+
+    async fn notify(&self, notification: &EntryNotification) {
+        if !self.config.matches(notification) {
+            return;
+        }
+        let payload = Notification::from(notification);
+        for sink in &self.config.sinks {
+            match self.client.deliver(sink, &payload).await {
+                Ok(()) => debug!(
+                    sink = sink.kind(),
+                    entry_id = %payload.entry_id,
+                    "Delivered entry notification"
+                ),
+                Err(err) => warn!(
+                    sink = sink.kind(),
+                    entry_id = %payload.entry_id,
+                    error = %err,
+                    "Failed to deliver entry notification"
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use synd_feed::{entry::Entry, entry::EntryId, types::FeedUrl};
+    use synd_registry::{Subscription, crawl::policy::CrawlPolicy};
+
+    use super::*;
+
+    fn notification(
+        requirement: Option<Requirement>,
+        category: Option<&str>,
+        title: &str,
+    ) -> EntryNotification {
+        subscriber_notification(local_subscriber_id(), requirement, category, title)
+    }
+
+    fn subscriber_notification(
+        subscriber_id: SubscriberId,
+        requirement: Option<Requirement>,
+        category: Option<&str>,
+        title: &str,
+    ) -> EntryNotification {
+        let published = "2026-01-15T00:00:00Z".parse().unwrap();
+        EntryNotification {
+            subscription: Subscription {
+                subscriber_id,
+                feed_url: FeedUrl::parse("https://example.com/feed.xml").unwrap(),
+                requirement,
+                category: category.map(|category| Category::new(category.to_owned()).unwrap()),
+                crawl_policy: CrawlPolicy::manual(),
+                fetch_full_content: false,
+                subscribed_at: "2026-01-10T00:00:00Z".parse().unwrap(),
+            },
+            feed_title: None,
+            entry: Entry::builder()
+                .id(EntryId::parse(format!("synd:entry:v1:{}", "a".repeat(64))).unwrap())
+                .title(
+                    serde_json::from_value(serde_json::json!({
+                        "content": title,
+                        "content_type": "text/plain",
+                    }))
+                    .unwrap(),
+                )
+                .published(published)
+                .build(),
+            entry_url: None,
+            discovered_at: published,
+        }
+    }
+
+    #[test]
+    fn rule_requires_every_condition() {
+        let rule = NotifyRule::new()
+            .with_requirement(Requirement::Should)
+            .with_category(Category::new("rust").unwrap())
+            .with_title("(?i)release")
+            .unwrap();
+
+        assert!(rule.matches(&notification(
+            Some(Requirement::Must),
+            Some("rust"),
+            "Rust 1.90 Released"
+        )));
+        assert!(!rule.matches(&notification(
+            Some(Requirement::May),
+            Some("rust"),
+            "Rust 1.90 Released"
+        )));
+        assert!(!rule.matches(&notification(None, Some("rust"), "Rust 1.90 Released")));
+        assert!(!rule.matches(&notification(
+            Some(Requirement::Must),
+            Some("go"),
+            "Rust 1.90 Released"
+        )));
+        assert!(!rule.matches(&notification(
+            Some(Requirement::Must),
+            Some("rust"),
+            "This Week in Rust"
+        )));
+    }
+
+    #[test]
+    fn config_matches_any_rule() {
+        let config = NotifyConfig::new(
+            vec![
+                NotifyRule::new().with_requirement(Requirement::Must),
+                NotifyRule::new().with_category(Category::new("security").unwrap()),
+            ],
+            vec![NotifySink::Desktop],
+        );
+
+        assert!(config.is_enabled());
+        assert!(config.matches(&notification(Some(Requirement::Must), None, "a")));
+        assert!(config.matches(&notification(None, Some("security"), "b")));
+        assert!(!config.matches(&notification(Some(Requirement::Should), None, "c")));
+        assert!(!NotifyConfig::new(Vec::new(), vec![NotifySink::Desktop]).is_enabled());
+    }
+
+    #[test]
+    fn rule_matches_only_the_configured_subscriber_of_a_shared_feed() {
+        let alice = SubscriberId::new("alice");
+        let local = |rule: &NotifyRule| {
+            rule.matches(&subscriber_notification(
+                local_subscriber_id(),
+                None,
+                None,
+                "a",
+            ))
+        };
+        let other = |rule: &NotifyRule| {
+            rule.matches(&subscriber_notification(alice.clone(), None, None, "a"))
+        };
+
+        let rule = NotifyRule::new();
+        assert!(local(&rule));
+        assert!(!other(&rule));
+
+        let rule = NotifyRule::new().with_subscriber(alice.clone());
+        assert!(!local(&rule));
+        assert!(other(&rule));
+    }
+
+    #[test]
+    fn invalid_title_pattern_is_rejected() {
+        assert!(matches!(
+            NotifyRule::new().with_title("("),
+            Err(Error::InvalidNotifyTitle { .. })
+        ));
+    }
+}
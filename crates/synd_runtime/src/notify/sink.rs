@@ -0,0 +1,273 @@
+use std::{path::PathBuf, process::Stdio, time::Duration};
+
+use serde::Serialize;
+use synd_registry::notify::EntryNotification;
+use thiserror::Error;
+use tokio::{io::AsyncWriteExt, process::Command};
+use url::Url;
+
+/// Upper bound for one delivery, so a hung sink cannot stall the others.
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Destination an entry notification is delivered to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotifySink {
+    /// Desktop notification through the platform notification service: the
+    /// freedesktop notification service over D-Bus on Linux and BSD.
+    Desktop,
+    /// HTTP POST of the notification as a JSON body.
+    Webhook(Url),
+    /// Command run with the notification as JSON on its stdin.
+    Command { program: PathBuf, args: Vec<String> },
+}
+
+impl NotifySink {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Desktop => "desktop",
+            Self::Webhook(_) => "webhook",
+            Self::Command { .. } => "command",
+        }
+    }
+}
+
+/// JSON body delivered to webhook and command sinks.
+#[derive(Debug, Serialize)]
+pub(super) struct Notification {
+    pub(super) subscriber_id: String,
+    pub(super) feed_url: String,
+    pub(super) feed_title: Option<String>,
+    pub(super) entry_id: String,
+    pub(super) entry_title: Option<String>,
+    pub(super) entry_url: Option<String>,
+    pub(super) published: Option<String>,
+    pub(super) requirement: Option<String>,
+    pub(super) category: Option<String>,
+    pub(super) discovered_at: String,
+}
+
+impl From<&EntryNotification> for Notification {
+    fn from(notification: &EntryNotification) -> Self {
+        let subscription = &notification.subscription;
+        let entry = &notification.entry;
+        Self {
+            subscriber_id: subscription.subscriber_id.as_str().to_owned(),
+            feed_url: subscription.feed_url.to_string(),
+            feed_title: notification.feed_title.clone(),
+            entry_id: entry.id().to_string(),
+            entry_title: notification.entry_title().map(ToOwned::to_owned),
+            entry_url: notification.entry_url.clone(),
+            published: entry
+                .published()
+                .or(entry.updated())
+                .map(|published| published.to_rfc3339()),
+            requirement: subscription
+                .requirement
+                .map(|requirement| requirement.to_string()),
+            category: subscription
+                .category
+                .as_ref()
+                .map(|category| category.as_str().to_owned()),
+            discovered_at: notification.discovered_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub(super) enum DeliverError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+    #[error(transparent)]
+    Desktop(#[from] notify_rust::error::Error),
+    #[error("{program} exited with {status}")]
+    CommandFailed {
+        program: String,
+        status: std::process::ExitStatus,
+    },
+    #[error("delivery timed out after {}s", DELIVERY_TIMEOUT.as_secs())]
+    Timeout,
+}
+
+/// Delivers notifications to every kind of sink.
+pub(super) struct SinkClient {
+    http: reqwest::Client,
+}
+
+impl SinkClient {
+    pub(super) fn new() -> Self {
+        Self {
+            http: reqwest::Client::new(),
+        }
+    }
+
+    pub(super) async fn deliver(
+        &self,
+        sink: &NotifySink,
+        notification: &Notification,
+    ) -> Result<(), DeliverError> {
+        let delivery = async {
+            match sink {
+                NotifySink::Desktop => Self::show_desktop(notification).await,
+                NotifySink::Webhook(url) => self.post_webhook(url, notification).await,
+                NotifySink::Command { program, args } => {
+                    Self::run_command(program, args, notification).await
+                }
+            }
+        };
+        tokio::time::timeout(DELIVERY_TIMEOUT, delivery)
+            .await
+            .map_err(|_| DeliverError::Timeout)?
+    }
+
+    async fn show_desktop(notification: &Notification) -> Result<(), DeliverError> {
+        let summary = notification.entry_title.as_deref().unwrap_or("New entry");
+        let body = [
+            notification.feed_title.as_deref(),
+            notification.entry_url.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("\n");
+
+        let mut desktop = notify_rust::Notification::new();
+        desktop.appname("synd").summary(summary).body(&body);
+
+        #[cfg(all(unix, not(target_os = "macos")))]
+        desktop.show_async().await?;
+        // Other platforms only have a blocking API.
+        #[cfg(not(all(unix, not(target_os = "macos"))))]
+        tokio::task::spawn_blocking(move || desktop.show().map(drop))
+            .await
+            .map_err(std::io::Error::other)??;
+        Ok(())
+    }
+
+    async fn post_webhook(
+        &self,
+        url: &Url,
+        notification: &Notification,
+    ) -> Result<(), DeliverError> {
+        self.http
+            .post(url.clone())
+            .json(notification)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    async fn run_command(
+        program: &PathBuf,
+        args: &[String],
+        notification: &Notification,
+    ) -> Result<(), DeliverError> {
+        let body = serde_json::to_vec(notification)?;
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            // Commands are free to ignore the payload and exit early.
+            match stdin.write_all(&body).await {
+                Err(err) if err.kind() != std::io::ErrorKind::BrokenPipe => return Err(err.into()),
+                _ => {}
+            }
+        }
+        let status = child.wait().await?;
+        Self::check_status(&program.display().to_string(), status)
+    }
+
+    fn check_status(program: &str, status: std::process::ExitStatus) -> Result<(), DeliverError> {
+        if status.success() {
+            Ok(())
+        } else {
+            Err(DeliverError::CommandFailed {
+                program: program.to_owned(),
+                status,
+            })
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use synd_feed::{
+        entry::{Entry, EntryId},
+        types::{FeedUrl, Requirement},
+    };
+    use synd_registry::{SubscriberId, Subscription, crawl::policy::CrawlPolicy};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn command_sink_receives_notification_on_stdin() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("notification.json");
+        let published = "2026-01-15T00:00:00Z".parse().unwrap();
+        let notification = EntryNotification {
+            subscription: Subscription {
+                subscriber_id: SubscriberId::new("reader"),
+                feed_url: FeedUrl::parse("https://example.com/feed.xml").unwrap(),
+                requirement: Some(Requirement::Must),
+                category: None,
+                crawl_policy: CrawlPolicy::manual(),
+                fetch_full_content: false,
+                subscribed_at: "2026-01-10T00:00:00Z".parse().unwrap(),
+            },
+            feed_title: Some("Example".to_owned()),
+            entry: Entry::builder()
+                .id(EntryId::parse(format!("synd:entry:v1:{}", "a".repeat(64))).unwrap())
+                .published(published)
+                .build(),
+            entry_url: Some("https://example.com/a".to_owned()),
+            discovered_at: published,
+        };
+        let sink = NotifySink::Command {
+            program: "sh".into(),
+            args: vec!["-c".to_owned(), format!("cat > {}", output.display())],
+        };
+
+        SinkClient::new()
+            .deliver(&sink, &Notification::from(&notification))
+            .await
+            .unwrap();
+
+        let delivered: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&output).unwrap()).unwrap();
+        assert_eq!(delivered["subscriber_id"], "reader");
+        assert_eq!(delivered["feed_title"], "Example");
+        assert_eq!(delivered["entry_url"], "https://example.com/a");
+        assert_eq!(delivered["requirement"], "MUST");
+        assert_eq!(delivered["published"], "2026-01-15T00:00:00+00:00");
+    }
+
+    #[tokio::test]
+    async fn failing_command_sink_reports_exit_status() {
+        let sink = NotifySink::Command {
+            program: "false".into(),
+            args: Vec::new(),
+        };
+        let notification = Notification {
+            subscriber_id: "reader".to_owned(),
+            feed_url: "https://example.com/feed.xml".to_owned(),
+            feed_title: None,
+            entry_id: "entry".to_owned(),
+            entry_title: None,
+            entry_url: None,
+            published: None,
+            requirement: None,
+            category: None,
+            discovered_at: "2026-01-15T00:00:00+00:00".to_owned(),
+        };
+
+        let result = SinkClient::new().deliver(&sink, &notification).await;
+
+        assert!(matches!(result, Err(DeliverError::CommandFailed { .. })));
+    }
+}
@@ -0,0 +1,239 @@
+use std::{
+    fmt,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+};
+
+use synd_protocol::capability;
+use tracing::{debug, info, warn};
+use url::Url;
+
+use crate::{Error, Result, RuntimeConfig, Session, SessionHandle};
+
+/// Bearer token shared between a remote daemon and its clients.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RemoteToken {
+    token: String,
+}
+
+impl RemoteToken {
+    /// Reads the token from `path`, ignoring surrounding whitespace.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let token = std::fs::read_to_string(path)?.trim().to_owned();
+        if token.is_empty() {
+            return Err(Error::EmptyRemoteToken {
+                path: path.to_path_buf(),
+            });
+        }
+
+        Ok(Self { token })
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        &self.token
+    }
+}
+
+impl fmt::Debug for RemoteToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RemoteToken(<redacted>)")
+    }
+}
+
+/// TCP listener that serves a daemon to remote clients over TLS.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaemonListener {
+    addr: SocketAddr,
+    tls_certificate: PathBuf,
+    tls_private_key: PathBuf,
+    token: RemoteToken,
+    multi_user: bool,
+}
+
+impl DaemonListener {
+    pub fn new(
+        addr: SocketAddr,
+        tls_certificate: impl Into<PathBuf>,
+        tls_private_key: impl Into<PathBuf>,
+        token: RemoteToken,
+    ) -> Self {
+        Self {
+            addr,
+            tls_certificate: tls_certificate.into(),
+            tls_private_key: tls_private_key.into(),
+            token,
+            multi_user: false,
+        }
+    }
+
+    /// Also serves GitHub and Google users, each as its own subscriber. The
+    /// token then identifies the operator, who may manage those subscribers.
+    #[must_use]
+    pub fn with_multi_user(mut self, multi_user: bool) -> Self {
+        self.multi_user = multi_user;
+        self
+    }
+
+    pub fn is_multi_user(&self) -> bool {
+        self.multi_user
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    pub fn tls_certificate(&self) -> &Path {
+        &self.tls_certificate
+    }
+
+    pub fn tls_private_key(&self) -> &Path {
+        &self.tls_private_key
+    }
+
+    pub(crate) fn token(&self) -> &RemoteToken {
+        &self.token
+    }
+}
+
+/// Remote daemon a runtime connects to instead of acquiring a local session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteEndpoint {
+    endpoint: Url,
+    token: RemoteToken,
+    root_certificate: Option<PathBuf>,
+    accept_invalid_certs: bool,
+}
+
+impl RemoteEndpoint {
+    pub fn new(endpoint: Url, token: RemoteToken) -> Self {
+        Self {
+            endpoint,
+            token,
+            root_certificate: None,
+            accept_invalid_certs: false,
+        }
+    }
+
+    /// Trusts the PEM certificate at `path` in addition to the system roots.
+    #[must_use]
+    pub fn with_root_certificate(mut self, path: impl Into<PathBuf>) -> Self {
+        self.root_certificate = Some(path.into());
+        self
+    }
+
+    #[must_use]
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
+
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    pub fn root_certificate(&self) -> Option<&Path> {
+        self.root_certificate.as_deref()
+    }
+
+    /// Opens a session against the remote daemon.
+    ///
+    /// Remote daemons are always on, so the session is not leased and closing it
+    /// leaves the daemon running.
+    pub(crate) async fn open_session(&self, config: &RuntimeConfig) -> Result<Session> {
+        let client = self.client(config)?;
+        // The probe is authenticated so a rejected token fails here rather than
+        // on the first request of the session.
+        debug!(remote_endpoint = %self.endpoint, "Probing remote daemon");
+        client
+            .fetch_subscription(None, Some(1))
+            .await
+            .map_err(|source| Error::RemoteSessionUnavailable {
+                endpoint: self.endpoint.clone(),
+                source: Box::new(source),
+            })?;
+
+        // Daemons that predate capability discovery reject the query; they
+        // serve the baseline set.
+        let available_capabilities = match client.fetch_capabilities().await {
+            Ok(capabilities) => capabilities,
+            Err(error) => {
+                warn!(
+                    remote_endpoint = %self.endpoint,
+                    %error,
+                    "Failed to fetch remote capabilities, assuming the baseline set"
+                );
+                capability::baseline_api_capabilities()
+            }
+        };
+        let missing_capabilities = config
+            .requirements()
+            .required_capabilities()
+            .missing_from(&available_capabilities);
+        if !missing_capabilities.is_empty() {
+            return Err(Error::MissingRemoteSessionCapabilities {
+                endpoint: self.endpoint.clone(),
+                missing_capabilities,
+            });
+        }
+
+        info!(
+            remote_endpoint = %self.endpoint,
+            available_capabilities = %available_capabilities,
+            "Remote session acquired"
+        );
+        Ok(Session::new(
+            client,
+            available_capabilities,
+            SessionHandle::inert(),
+        ))
+    }
+
+    fn client(&self, config: &RuntimeConfig) -> Result<synd_client::Client> {
+        let mut options = synd_client::ClientOptions::new(
+            config.client().request_timeout(),
+            config.client().user_agent(),
+        )
+        .danger_accept_invalid_certs(self.accept_invalid_certs);
+        if let Some(path) = &self.root_certificate {
+            options = options.with_root_certificate_pem(&std::fs::read(path)?)?;
+        }
+
+        let mut client = synd_client::Client::new(self.endpoint.clone(), options)?;
+        client.set_local_token(self.token.as_str())?;
+
+        Ok(client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RemoteToken;
+
+    mod token_file {
+        use super::*;
+
+        #[test]
+        fn trims_whitespace() -> crate::Result<()> {
+            let tmp = tempfile::tempdir()?;
+            let path = tmp.path().join("token");
+            std::fs::write(&path, "  secret\n")?;
+
+            assert_eq!(RemoteToken::from_file(&path)?.as_str(), "secret");
+            Ok(())
+        }
+
+        #[test]
+        fn rejects_empty() -> crate::Result<()> {
+            let tmp = tempfile::tempdir()?;
+            let path = tmp.path().join("token");
+            std::fs::write(&path, "\n")?;
+
+            assert!(matches!(
+                RemoteToken::from_file(&path),
+                Err(crate::Error::EmptyRemoteToken { .. })
+            ));
+            Ok(())
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use std::{path::PathBuf, time::Duration};
 
 use crate::{
-    DaemonControl, DaemonLaunchConfig, DaemonLaunchLog, PlacementSummary, Result, RuntimeDatabase,
-    Session, SessionConfig, SessionRequirements,
+    DaemonControl, DaemonLaunchConfig, DaemonLaunchLog, PlacementSummary, RemoteEndpoint, Result,
+    RuntimeDatabase, Session, SessionConfig, SessionRequirements,
     acquisition::SessionAcquisition,
     placement::{PlacementEnvironment, PlacementResolver, PlacementSpec},
 };
@@ -33,8 +33,13 @@ impl Runtime {
         PlacementSummary::from_placement(&self.placement)
     }
 
+    /// Connects to the configured remote daemon, or acquires a session from the
+    /// local daemon, launching it when needed.
     pub async fn acquire_session(&self) -> Result<Session> {
-        SessionAcquisition::new(self).acquire().await
+        match self.config.remote() {
+            Some(remote) => remote.open_session(&self.config).await,
+            None => SessionAcquisition::new(self).acquire().await,
+        }
     }
 
     pub fn daemon(&self) -> DaemonControl<'_> {
@@ -49,6 +54,7 @@ pub struct Config {
     session: SessionConfig,
     daemon: DaemonLaunchConfig,
     requirements: SessionRequirements,
+    remote: Option<RemoteEndpoint>,
     placement_environment: PlacementEnvironment,
 }
 
@@ -60,6 +66,7 @@ impl Config {
             session: SessionConfig::default(),
             daemon: DaemonLaunchConfig::default(),
             requirements: SessionRequirements::default(),
+            remote: None,
             placement_environment: PlacementEnvironment::capture(),
         }
     }
@@ -131,6 +138,12 @@ impl Config {
         self
     }
 
+    #[must_use]
+    pub fn with_remote(mut self, remote: RemoteEndpoint) -> Self {
+        self.remote = Some(remote);
+        self
+    }
+
     pub fn database(&self) -> &RuntimeDatabase {
         &self.database
     }
@@ -151,6 +164,10 @@ impl Config {
         &self.requirements
     }
 
+    pub fn remote(&self) -> Option<&RemoteEndpoint> {
+        self.remote.as_ref()
+    }
+
     pub(crate) fn placement_environment(&self) -> PlacementEnvironment {
         self.placement_environment.clone()
     }
@@ -6,7 +6,7 @@ use crate::{
     config::Categories,
     interact::Interact,
     terminal::Terminal,
-    ui::theme::Theme,
+    ui::theme::{Theme, Themes},
 };
 
 pub struct ApplicationBuilder<
@@ -26,6 +26,7 @@ pub struct ApplicationBuilder<
     pub(super) theme: Theme,
     pub(super) interactor: Interactor,
 
+    pub(super) themes: Option<Themes>,
     pub(super) authenticator: Option<Authenticator>,
     pub(super) gh_client: Option<GhClient>,
     pub(super) clock: Option<Box<dyn Clock>>,
@@ -42,6 +43,7 @@ impl Default for ApplicationBuilder {
             config: (),
             theme: (),
             interactor: (),
+            themes: None,
             authenticator: None,
             gh_client: None,
             clock: None,
@@ -64,6 +66,7 @@ impl<T1, T2, T3, T4, T5, T6> ApplicationBuilder<(), T1, T2, T3, T4, T5, T6> {
             config: self.config,
             theme: self.theme,
             interactor: self.interactor,
+            themes: self.themes,
             authenticator: self.authenticator,
             gh_client: self.gh_client,
             clock: self.clock,
@@ -86,6 +89,7 @@ impl<T1, T2, T3, T4, T5, T6> ApplicationBuilder<T1, (), T2, T3, T4, T5, T6> {
             config: self.config,
             theme: self.theme,
             interactor: self.interactor,
+            themes: self.themes,
             authenticator: self.authenticator,
             gh_client: self.gh_client,
             clock: self.clock,
@@ -108,6 +112,7 @@ impl<T1, T2, T3, T4, T5, T6> ApplicationBuilder<T1, T2, (), T3, T4, T5, T6> {
             config: self.config,
             theme: self.theme,
             interactor: self.interactor,
+            themes: self.themes,
             authenticator: self.authenticator,
             gh_client: self.gh_client,
             clock: self.clock,
@@ -127,6 +132,7 @@ impl<T1, T2, T3, T4, T5, T6> ApplicationBuilder<T1, T2, T3, (), T4, T5, T6> {
             config: self.config,
             theme: self.theme,
             interactor: self.interactor,
+            themes: self.themes,
             authenticator: self.authenticator,
             gh_client: self.gh_client,
             clock: self.clock,
@@ -146,6 +152,7 @@ impl<T1, T2, T3, T4, T5, T6> ApplicationBuilder<T1, T2, T3, T4, (), T5, T6> {
             config,
             theme: self.theme,
             interactor: self.interactor,
+            themes: self.themes,
             authenticator: self.authenticator,
             gh_client: self.gh_client,
             clock: self.clock,
@@ -165,6 +172,7 @@ impl<T1, T2, T3, T4, T5, T6> ApplicationBuilder<T1, T2, T3, T4, T5, (), T6> {
             config: self.config,
             theme,
             interactor: self.interactor,
+            themes: self.themes,
             authenticator: self.authenticator,
             gh_client: self.gh_client,
             clock: self.clock,
@@ -187,6 +195,7 @@ impl<T1, T2, T3, T4, T5, T6> ApplicationBuilder<T1, T2, T3, T4, T5, T6, ()> {
             config: self.config,
             theme: self.theme,
             interactor,
+            themes: self.themes,
             authenticator: self.authenticator,
             gh_client: self.gh_client,
             clock: self.clock,
@@ -196,6 +205,15 @@ impl<T1, T2, T3, T4, T5, T6> ApplicationBuilder<T1, T2, T3, T4, T5, T6, ()> {
 }
 
 impl<T1, T2, T3, T4, T5, T6, T7> ApplicationBuilder<T1, T2, T3, T4, T5, T6, T7> {
+    /// Palettes and overrides the theme rotates through.
+    #[must_use]
+    pub fn themes(self, themes: Themes) -> Self {
+        Self {
+            themes: Some(themes),
+            ..self
+        }
+    }
+
     #[must_use]
     pub fn authenticator(self, authenticator: Authenticator) -> Self {
         Self {
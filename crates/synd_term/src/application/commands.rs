@@ -17,9 +17,10 @@ impl Application {
             Command::Feeds(command) if self.components.shell.permits_main_ui() => self
                 .components
                 .apply_feeds_command(command, self.config.feeds_per_pagination),
-            Command::Filter(command) if self.components.shell.permits_main_ui() => {
-                self.components.apply_filter_command(command).into()
-            }
+            Command::Filter(command) if self.components.shell.permits_main_ui() => self
+                .components
+                .apply_filter_command(command, self.config.entries_limit)
+                .into(),
             Command::Gh(command) if self.components.shell.permits_main_ui() => {
                 self.components.apply_gh_command(command)
             }
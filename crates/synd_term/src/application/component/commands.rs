@@ -4,7 +4,10 @@ use crate::{
     command::{FeedsCommand, FilterCommand, FilterTarget, GhCommand, ShellCommand},
     keymap,
     operation::{Operation, Operations},
-    ui::widgets::{filter::Filterer, tabs::Tab},
+    ui::widgets::{
+        filter::{FeedFilterer, Filterer},
+        tabs::Tab,
+    },
 };
 
 use super::{Components, FeedsComponent};
@@ -13,6 +16,7 @@ use super::{Components, FeedsComponent};
 enum FeedsCommandState {
     Subscription,
     Timeline,
+    Reader,
     UnsubscribePopup,
     Unavailable,
 }
@@ -97,7 +101,7 @@ impl Components {
                 Operations::Nop
             }
             (FeedsCommandState::Subscription, FeedsCommand::PromptFeedSubscription) => {
-                Operation::OpenFeedSubscriptionEditor.into()
+                Operation::OpenFeedSubscriptionEditor { prompt: None }.into()
             }
             (FeedsCommandState::Subscription, FeedsCommand::PromptFeedEdition) => {
                 self.feeds.edit_selected_feed().into()
@@ -125,23 +129,31 @@ impl Components {
             (FeedsCommandState::Subscription, FeedsCommand::ReloadSubscription) => {
                 FeedsComponent::reload_subscription(feeds_first).into()
             }
+            (FeedsCommandState::Subscription, FeedsCommand::RefreshFeed) => {
+                self.feeds.refresh_selected_feed().into()
+            }
             (FeedsCommandState::Subscription, FeedsCommand::OpenFeed) => {
                 self.feeds.open_selected_feed().into()
             }
+            (FeedsCommandState::Subscription, FeedsCommand::BrowseFeedEntries) => {
+                let operation = self.feeds.browse_selected_feed_entries();
+                if operation.is_some() {
+                    self.shell.select_tab(Tab::Entries);
+                }
+                operation.into()
+            }
             (FeedsCommandState::Timeline, FeedsCommand::RefreshTimeline) => {
                 self.feeds.refresh_timeline().into()
             }
             (FeedsCommandState::Timeline, FeedsCommand::MoveEntry(direction)) => {
-                self.feeds.move_entry(direction);
-                Operations::Nop
+                self.feeds.move_entry(direction).into()
             }
             (FeedsCommandState::Timeline, FeedsCommand::MoveEntryFirst) => {
                 self.feeds.move_entry_first();
                 Operations::Nop
             }
             (FeedsCommandState::Timeline, FeedsCommand::MoveEntryLast) => {
-                self.feeds.move_entry_last();
-                Operations::Nop
+                self.feeds.move_entry_last().into()
             }
             (FeedsCommandState::Timeline, FeedsCommand::OpenEntry) => {
                 self.feeds.open_selected_entry().into()
@@ -149,6 +161,45 @@ impl Components {
             (FeedsCommandState::Timeline, FeedsCommand::BrowseEntry) => {
                 self.feeds.browse_selected_entry()
             }
+            (FeedsCommandState::Timeline, FeedsCommand::ToggleEntryRead) => {
+                self.feeds.toggle_selected_entry_read().into()
+            }
+            (FeedsCommandState::Timeline, FeedsCommand::ToggleEntrySaved) => {
+                self.feeds.toggle_selected_entry_saved().into()
+            }
+            (FeedsCommandState::Timeline, FeedsCommand::ToggleSavedEntries) => {
+                self.feeds.toggle_saved_entries().into()
+            }
+            (FeedsCommandState::Timeline, FeedsCommand::OpenEntryReader) => {
+                self.feeds.open_reader().into()
+            }
+            (FeedsCommandState::Reader, FeedsCommand::CloseEntryReader) => {
+                self.feeds.close_reader();
+                Operations::Nop
+            }
+            (FeedsCommandState::Reader, FeedsCommand::ScrollEntryReader(direction)) => {
+                self.feeds.scroll_reader(direction);
+                Operations::Nop
+            }
+            (FeedsCommandState::Reader, FeedsCommand::ScrollEntryReaderPage(direction)) => {
+                self.feeds.scroll_reader_page(direction);
+                Operations::Nop
+            }
+            (FeedsCommandState::Reader, FeedsCommand::ScrollEntryReaderFirst) => {
+                self.feeds.scroll_reader_first();
+                Operations::Nop
+            }
+            (FeedsCommandState::Reader, FeedsCommand::ScrollEntryReaderLast) => {
+                self.feeds.scroll_reader_last();
+                Operations::Nop
+            }
+            (FeedsCommandState::Reader, FeedsCommand::MoveEntryReaderLink(direction)) => {
+                self.feeds.move_reader_link(direction);
+                Operations::Nop
+            }
+            (FeedsCommandState::Reader, FeedsCommand::OpenEntryReaderLink) => {
+                self.feeds.open_reader_link().into()
+            }
             _ => Operations::Nop,
         }
     }
@@ -203,6 +254,7 @@ impl Components {
     pub(in crate::application) fn apply_filter_command(
         &mut self,
         command: FilterCommand,
+        entries_limit: usize,
     ) -> Option<Operation> {
         if self.gh.is_filter_popup_open() {
             return None;
@@ -212,7 +264,12 @@ impl Components {
             FilterCommand::MoveFilterRequirement(direction)
                 if self.shell.current_filter_target() == FilterTarget::Feeds =>
             {
-                self.move_filter_requirement(direction)
+                self.move_filter_requirement(direction, entries_limit)
+            }
+            FilterCommand::ToggleFilterUnreadOnly
+                if self.shell.current_filter_target() == FilterTarget::Feeds =>
+            {
+                self.toggle_filter_unread_only(entries_limit)
             }
             FilterCommand::ActivateCategoryFiltering => {
                 self.activate_category_filtering();
@@ -223,33 +280,43 @@ impl Components {
                 None
             }
             FilterCommand::PromptInsertChar(ch) if self.shell.filter.is_search_active() => {
-                self.insert_prompt_char(ch)
+                self.insert_prompt_char(ch, entries_limit)
             }
             FilterCommand::PromptDeleteBackward if self.shell.filter.is_search_active() => {
-                self.delete_prompt_backward()
+                self.delete_prompt_backward(entries_limit)
+            }
+            FilterCommand::PromptSubmit
+                if self.shell.filter.is_search_active()
+                    && self.shell.tabs.current() == Tab::Entries =>
+            {
+                self.submit_server_search()
             }
-            FilterCommand::DeactivateFiltering if self.shell.filter.is_filtering_active() => {
+            FilterCommand::DeactivateFiltering
+                if self.shell.filter.is_filtering_active() || self.feeds.is_showing_listing() =>
+            {
                 self.deactivate_filtering();
                 None
             }
             FilterCommand::ToggleFilterCategory { category, target }
                 if self.shell.filter.category_filter_target() == Some(target) =>
             {
-                self.toggle_filter_category(&category, target)
+                self.toggle_filter_category(&category, target, entries_limit)
             }
             FilterCommand::ActivateAllFilterCategories { target }
                 if self.shell.filter.category_filter_target() == Some(target) =>
             {
-                self.activate_all_filter_categories(target)
+                self.activate_all_filter_categories(target, entries_limit)
             }
             FilterCommand::DeactivateAllFilterCategories { target }
                 if self.shell.filter.category_filter_target() == Some(target) =>
             {
-                self.deactivate_all_filter_categories(target)
+                self.deactivate_all_filter_categories(target, entries_limit)
             }
             FilterCommand::MoveFilterRequirement(_)
+            | FilterCommand::ToggleFilterUnreadOnly
             | FilterCommand::PromptInsertChar(_)
             | FilterCommand::PromptDeleteBackward
+            | FilterCommand::PromptSubmit
             | FilterCommand::DeactivateFiltering
             | FilterCommand::ToggleFilterCategory { .. }
             | FilterCommand::ActivateAllFilterCategories { .. }
@@ -265,6 +332,7 @@ impl Components {
         } else {
             match self.shell.tabs.current() {
                 Tab::Feeds => FeedsCommandState::Subscription,
+                Tab::Entries if self.feeds.is_reader_open() => FeedsCommandState::Reader,
                 Tab::Entries => FeedsCommandState::Timeline,
                 Tab::Gh => FeedsCommandState::Unavailable,
             }
@@ -284,9 +352,18 @@ impl Components {
     pub(in crate::application) fn move_filter_requirement(
         &mut self,
         direction: crate::application::Direction,
+        entries_limit: usize,
     ) -> Option<Operation> {
         let filterer = self.shell.move_filter_requirement(direction);
-        self.apply_filterer(filterer)
+        self.apply_filterer(filterer, entries_limit)
+    }
+
+    pub(in crate::application) fn toggle_filter_unread_only(
+        &mut self,
+        entries_limit: usize,
+    ) -> Option<Operation> {
+        let filterer = self.shell.toggle_filter_unread_only();
+        self.apply_filterer(filterer, entries_limit)
     }
 
     pub(in crate::application) fn activate_category_filtering(&mut self) {
@@ -308,54 +385,83 @@ impl Components {
         self.shell.filter.activate_search_filtering();
     }
 
-    pub(in crate::application) fn insert_prompt_char(&mut self, ch: char) -> Option<Operation> {
+    pub(in crate::application) fn insert_prompt_char(
+        &mut self,
+        ch: char,
+        entries_limit: usize,
+    ) -> Option<Operation> {
         self.shell.filter.insert_prompt_char(ch);
         let filterer = self.shell.active_filterer();
-        self.apply_filterer(filterer)
+        self.apply_filterer(filterer, entries_limit)
     }
 
-    pub(in crate::application) fn delete_prompt_backward(&mut self) -> Option<Operation> {
+    pub(in crate::application) fn delete_prompt_backward(
+        &mut self,
+        entries_limit: usize,
+    ) -> Option<Operation> {
         self.shell.filter.delete_prompt_backward();
         let filterer = self.shell.active_filterer();
-        self.apply_filterer(filterer)
+        self.apply_filterer(filterer, entries_limit)
+    }
+
+    /// Runs the search prompt on the server. The prompt is cleared so the
+    /// local matcher does not hide matches found only in entry content.
+    pub(in crate::application) fn submit_server_search(&mut self) -> Option<Operation> {
+        let query = self.shell.filter.take_search_query()?;
+        let filterer = self.shell.filter.feed_filterer();
+        self.apply_feed_filterer(filterer);
+        Some(self.feeds.begin_search(query))
     }
 
     pub(in crate::application) fn deactivate_filtering(&mut self) {
         self.shell.filter.deactivate_filtering();
+        if self.feeds.is_showing_listing() {
+            self.feeds.end_listing();
+        }
     }
 
     pub(in crate::application) fn toggle_filter_category(
         &mut self,
         category: &Category<'static>,
         target: FilterTarget,
+        entries_limit: usize,
     ) -> Option<Operation> {
         let filterer = self.shell.filter.toggle_category_state(category, target);
-        self.apply_filterer(filterer)
+        self.apply_filterer(filterer, entries_limit)
     }
 
     pub(in crate::application) fn activate_all_filter_categories(
         &mut self,
         target: FilterTarget,
+        entries_limit: usize,
     ) -> Option<Operation> {
         let filterer = self.shell.filter.activate_all_categories_state(target);
-        self.apply_filterer(filterer)
+        self.apply_filterer(filterer, entries_limit)
     }
 
     pub(in crate::application) fn deactivate_all_filter_categories(
         &mut self,
         target: FilterTarget,
+        entries_limit: usize,
     ) -> Option<Operation> {
         let filterer = self.shell.filter.deactivate_all_categories_state(target);
-        self.apply_filterer(filterer)
+        self.apply_filterer(filterer, entries_limit)
     }
 
+    fn apply_feed_filterer(&mut self, filterer: FeedFilterer) {
+        self.feeds.entries.update_filterer(filterer.clone());
+        self.feeds.subscription.update_filterer(filterer);
+    }
+
+    /// Applies the filter locally, and reloads the timeline when the part the
+    /// server evaluates changed.
     #[must_use]
-    fn apply_filterer(&mut self, filterer: Filterer) -> Option<Operation> {
+    fn apply_filterer(&mut self, filterer: Filterer, entries_limit: usize) -> Option<Operation> {
         match filterer {
             Filterer::Feed(filterer) => {
-                self.feeds.entries.update_filterer(filterer.clone());
-                self.feeds.subscription.update_filterer(filterer);
-                None
+                self.apply_feed_filterer(filterer);
+                let filter = self.shell.filter.timeline_filter();
+                self.feeds.set_timeline_filter(filter, entries_limit)
             }
             Filterer::GhNotification(filterer) => {
                 self.gh.notifications.update_filterer(filterer);
@@ -1,10 +1,13 @@
-use synd_client::payload;
+use synd_client::payload::{self, FeedCandidateSource};
+use synd_feed::types::FeedUrl;
 use tracing::debug;
 
 use crate::{
     application::{Populate, input_parser::InputParser},
     event::{FeedRequestEvent, GhEvent},
     operation::{Operation, Operations},
+    types::{EntryExt, Feed},
+    ui::widgets::entries::EntryListing,
 };
 
 use super::{Components, FeedsComponent};
@@ -21,7 +24,7 @@ impl Components {
                     .set_error_message(format!("{} already subscribed", input.url));
                 None
             }
-            Ok(input) => Some(Operation::SubscribeFeed { input }),
+            Ok(input) => Some(Operation::DiscoverFeeds { input }),
             Err(error) => {
                 self.shell.prompt.set_error_message(error.to_string());
                 None
@@ -29,6 +32,80 @@ impl Components {
         }
     }
 
+    /// Subscribes to the only feed the URL leads to, or lets the user pick
+    /// one in the subscription editor when there are several. The pick goes
+    /// through the same checks as a newly entered subscription.
+    fn apply_feeds_discovered(
+        &mut self,
+        mut input: payload::SubscribeFeedInput,
+        candidates: Option<Vec<payload::FeedCandidate>>,
+    ) -> Option<Operation> {
+        let Some(candidates) = candidates else {
+            return Some(Operation::SubscribeFeed { input });
+        };
+        match candidates.as_slice() {
+            [] => {
+                self.shell
+                    .prompt
+                    .set_error_message(format!("No feed found at {}", input.url));
+                None
+            }
+            [best, ..] if best.source == FeedCandidateSource::Requested => {
+                Some(Operation::SubscribeFeed { input })
+            }
+            [only] => {
+                input.url = only.url.clone();
+                if self.feeds.is_already_subscribed(&input.url) {
+                    self.shell
+                        .prompt
+                        .set_error_message(format!("{} already subscribed", input.url));
+                    return None;
+                }
+                Some(Operation::SubscribeFeed { input })
+            }
+            _ => Some(Operation::OpenFeedSubscriptionEditor {
+                prompt: Some(InputParser::pick_feed_prompt(&input, &candidates)),
+            }),
+        }
+    }
+
+    fn apply_crawl_requested(
+        &mut self,
+        url: &FeedUrl,
+        code: &payload::ResponseCode,
+        disposition: &payload::RequestCrawlDisposition,
+    ) {
+        let prompt = &mut self.shell.prompt;
+        match (code, disposition) {
+            (payload::ResponseCode::Ok, payload::RequestCrawlDisposition::Requested) => {
+                prompt.set_info_message(format!("Refresh queued for {url}"));
+            }
+            (payload::ResponseCode::Ok, payload::RequestCrawlDisposition::AlreadyPending) => {
+                prompt.set_info_message(format!("Refresh already queued for {url}"));
+            }
+            (payload::ResponseCode::Ok, payload::RequestCrawlDisposition::NotActiveTarget) => {
+                prompt.set_error_message(format!(
+                    "Refresh not queued: {url} is not crawled by this daemon"
+                ));
+            }
+            (payload::ResponseCode::Ok, payload::RequestCrawlDisposition::Other(disposition)) => {
+                prompt.set_info_message(format!("Refresh of {url}: {disposition}"));
+            }
+            (code, _) => {
+                let reason = match code {
+                    payload::ResponseCode::Unauthorized => "unauthorized",
+                    payload::ResponseCode::InvalidFeedUrl => "invalid feed URL",
+                    payload::ResponseCode::FeedUnavailable => "feed unavailable",
+                    payload::ResponseCode::Ok | payload::ResponseCode::InternalError => {
+                        "internal error"
+                    }
+                    payload::ResponseCode::Other(code) => code,
+                };
+                prompt.set_error_message(format!("Refresh of {url} failed: {reason}"));
+            }
+        }
+    }
+
     pub(in crate::application) fn apply_feed_edition_editor_closed(
         &mut self,
         input: &str,
@@ -45,6 +122,8 @@ impl Components {
     pub(in crate::application) fn apply_feed_push(
         &mut self,
         event: payload::FeedEvent,
+        feeds_first: i64,
+        entries_limit: usize,
     ) -> Option<Operation> {
         match event {
             payload::FeedEvent::TimelineChanged(event) => {
@@ -55,6 +134,44 @@ impl Components {
                 );
                 self.feeds.refresh_timeline()
             }
+            payload::FeedEvent::EntryAdded(event) => {
+                let operation = self.feeds.apply_pushed_timeline_change(
+                    payload::TimelineChange::Upsert {
+                        timeline_entry: event.timeline_entry,
+                    },
+                    event.since..=event.seq,
+                    entries_limit,
+                );
+                self.refresh_feed_categories();
+                operation
+            }
+            payload::FeedEvent::EntryRemoved(event) => self.feeds.apply_pushed_timeline_change(
+                payload::TimelineChange::Remove {
+                    entry_id: event.entry_id,
+                },
+                event.since..=event.seq,
+                entries_limit,
+            ),
+            payload::FeedEvent::SubscriptionChanged(event) => match event.change {
+                payload::SubscriptionChange::Unsubscribed => {
+                    self.feeds.feed_unsubscribed(&event.feed_url);
+                    self.refresh_feed_categories();
+                    None
+                }
+                change => {
+                    debug!(feed_url = %event.feed_url, ?change, "subscription changed");
+                    Some(FeedsComponent::reload_subscription(feeds_first))
+                }
+            },
+            payload::FeedEvent::CrawlFailed(event) => {
+                debug!(
+                    feed_url = %event.feed_url,
+                    outcome = event.crawl.outcome.as_str(),
+                    error_kind = event.crawl.error_kind,
+                    "feed crawl failed"
+                );
+                None
+            }
         }
     }
 
@@ -65,6 +182,9 @@ impl Components {
         entries_limit: usize,
     ) -> Option<Operation> {
         match event {
+            FeedRequestEvent::FeedsDiscovered { input, candidates } => {
+                self.apply_feeds_discovered(input, candidates)
+            }
             FeedRequestEvent::FeedSubscribed { url } => {
                 debug!(%url, "feed subscribed");
                 Some(FeedsComponent::reload_subscription(feeds_first))
@@ -74,6 +194,23 @@ impl Components {
                 self.refresh_feed_categories();
                 None
             }
+            FeedRequestEvent::CrawlRequested {
+                url,
+                code,
+                disposition,
+            } => {
+                debug!(%url, ?code, ?disposition, "feed crawl requested");
+                self.apply_crawl_requested(&url, &code, &disposition);
+                None
+            }
+            FeedRequestEvent::EntryReadChanged { entry_id, read } => {
+                self.feeds.entries.set_entry_read(&entry_id, read);
+                None
+            }
+            FeedRequestEvent::EntrySavedChanged { entry_id, saved } => {
+                self.feeds.entries.set_entry_saved(&entry_id, saved);
+                None
+            }
             FeedRequestEvent::SubscriptionFetched {
                 populate,
                 subscription,
@@ -90,6 +227,52 @@ impl Components {
                 self.refresh_feed_categories();
                 None
             }
+            FeedRequestEvent::TimelineChangesExpired => {
+                self.feeds.expire_timeline_catch_up();
+                None
+            }
+            FeedRequestEvent::TimelineSearched {
+                query,
+                populate,
+                entries,
+                next_cursor,
+            } => {
+                self.feeds.apply_listing_page(
+                    &EntryListing::Search { query },
+                    populate,
+                    entries,
+                    next_cursor,
+                );
+                None
+            }
+            FeedRequestEvent::SavedEntriesFetched {
+                populate,
+                entries,
+                next_cursor,
+            } => {
+                self.feeds
+                    .apply_listing_page(&EntryListing::Saved, populate, entries, next_cursor);
+                None
+            }
+            FeedRequestEvent::FeedEntriesFetched {
+                url,
+                populate,
+                entries,
+                next_cursor,
+            } => {
+                self.feeds.apply_listing_page(
+                    &EntryListing::Feed { url },
+                    populate,
+                    entries,
+                    next_cursor,
+                );
+                None
+            }
+            FeedRequestEvent::EntryContentFetched { entry_id, entry } => {
+                self.feeds
+                    .apply_entry_content(&entry_id, entry.map(|entry| *entry));
+                None
+            }
         }
     }
 
@@ -109,15 +292,23 @@ impl Components {
         self.feeds
             .subscription
             .update_subscription(populate, subscription);
+        self.refresh_feed_categories();
         next_page
     }
 
     fn refresh_feed_categories(&mut self) {
-        self.shell.filter.update_categories(
-            &self.shell.categories,
-            Populate::Replace,
-            self.feeds.entries.entries(),
-        );
+        // Subscribed feeds keep the categories a server-side filter left out
+        // of the timeline selectable.
+        let categories = self
+            .feeds
+            .entries
+            .entries()
+            .map(EntryExt::category)
+            .chain(self.feeds.subscription.feeds().map(Feed::category))
+            .cloned();
+        self.shell
+            .filter
+            .update_categories(&self.shell.categories, Populate::Replace, categories);
     }
 
     pub(in crate::application) fn apply_gh_event(&mut self, event: GhEvent) -> Operations {
@@ -1,5 +1,7 @@
+use std::ops::RangeInclusive;
+
 use synd_client::payload;
-use synd_feed::types::FeedUrl;
+use synd_feed::{entry::EntryId, types::FeedUrl};
 use tracing::warn;
 use url::Url;
 
@@ -7,7 +9,8 @@ use crate::{
     application::{Direction, Populate, input_parser::InputParser},
     operation::{Operation, Operations},
     ui::widgets::{
-        entries::EntriesWidget,
+        entries::{EntriesWidget, EntryListing},
+        reader::ReaderWidget,
         subscription::{SubscriptionWidget, UnsubscribeSelection},
     },
 };
@@ -21,11 +24,45 @@ pub(crate) enum TimelineState {
     Ready { seq: i64 },
 }
 
+/// Paging state of the server-side entry listing being shown.
+#[derive(Debug)]
+struct ListingPaging {
+    listing: EntryListing,
+    next_cursor: Option<String>,
+    fetching: bool,
+}
+
+impl ListingPaging {
+    fn fetch(&self, populate: Populate, after: Option<String>) -> Operation {
+        match &self.listing {
+            EntryListing::Search { query } => Operation::SearchTimeline {
+                query: query.clone(),
+                populate,
+                after,
+            },
+            EntryListing::Saved => Operation::FetchSavedEntries { populate, after },
+            EntryListing::Feed { url } => Operation::FetchFeedEntries {
+                url: url.clone(),
+                populate,
+                after,
+            },
+        }
+    }
+}
+
 /// Feed subscription and timeline application state.
 pub(crate) struct FeedsComponent {
     pub(crate) subscription: SubscriptionWidget,
     pub(crate) entries: EntriesWidget,
+    /// Reader opened on an entry, shown instead of the entries.
+    pub(crate) reader: Option<ReaderWidget>,
     timeline: TimelineState,
+    /// Server-side conditions of the timeline window.
+    timeline_filter: payload::TimelineEntriesFilter,
+    /// The window is fetched again once the window or catch-up in flight
+    /// completes: the filter changed, or the server expired the catch-up seq.
+    timeline_window_stale: bool,
+    listing: Option<ListingPaging>,
 }
 
 impl FeedsComponent {
@@ -33,7 +70,11 @@ impl FeedsComponent {
         Self {
             subscription: SubscriptionWidget::new(),
             entries: EntriesWidget::new(),
+            reader: None,
             timeline: TimelineState::Uninitialized,
+            timeline_filter: payload::TimelineEntriesFilter::default(),
+            timeline_window_stale: false,
+            listing: None,
         }
     }
 
@@ -98,6 +139,14 @@ impl FeedsComponent {
             })
     }
 
+    pub(in crate::application) fn refresh_selected_feed(&self) -> Option<Operation> {
+        self.subscription
+            .selected_feed()
+            .map(|feed| Operation::RequestCrawl {
+                url: feed.url.clone(),
+            })
+    }
+
     pub(in crate::application) fn open_selected_feed(&self) -> Option<Operation> {
         let feed_website_url = self.subscription.selected_feed()?.website_url.as_ref()?;
         Self::parse_browser_url(feed_website_url, "feed")
@@ -119,6 +168,114 @@ impl FeedsComponent {
         .into()
     }
 
+    pub(in crate::application) fn toggle_selected_entry_read(&self) -> Option<Operation> {
+        let entry = self.entries.selected_timeline_entry()?;
+        Some(Operation::MarkEntryRead {
+            entry_id: entry.entry.id.clone(),
+            read: !entry.read,
+        })
+    }
+
+    pub(in crate::application) fn toggle_selected_entry_saved(&self) -> Option<Operation> {
+        let entry = self.entries.selected_timeline_entry()?;
+        Some(Operation::SaveEntry {
+            entry_id: entry.entry.id.clone(),
+            saved: !entry.saved,
+        })
+    }
+
+    /// Opens the selected entry in the reader and fetches its content body,
+    /// which the timeline listing leaves out.
+    pub(in crate::application) fn open_reader(&mut self) -> Option<Operation> {
+        let entry = self.entries.selected_timeline_entry()?.entry.clone();
+        let entry_id = entry.id.clone();
+        self.reader = Some(ReaderWidget::new(entry));
+        Some(Operation::FetchEntryContent { entry_id })
+    }
+
+    pub(in crate::application) fn close_reader(&mut self) {
+        self.reader = None;
+    }
+
+    pub(in crate::application) fn is_reader_open(&self) -> bool {
+        self.reader.is_some()
+    }
+
+    /// Content for an entry the reader has since moved away from is dropped.
+    pub(in crate::application) fn apply_entry_content(
+        &mut self,
+        entry_id: &EntryId,
+        entry: Option<payload::Entry>,
+    ) {
+        if let Some(reader) = self.reader_of(entry_id) {
+            reader.apply_content(entry);
+        }
+    }
+
+    pub(in crate::application) fn complete_entry_content(&mut self, entry_id: &EntryId) {
+        if let Some(reader) = self.reader_of(entry_id) {
+            reader.complete_fetch();
+        }
+    }
+
+    pub(in crate::application) fn scroll_reader(&mut self, direction: Direction) {
+        if let Some(reader) = self.reader.as_mut() {
+            reader.scroll(direction);
+        }
+    }
+
+    pub(in crate::application) fn scroll_reader_page(&mut self, direction: Direction) {
+        if let Some(reader) = self.reader.as_mut() {
+            reader.scroll_page(direction);
+        }
+    }
+
+    pub(in crate::application) fn scroll_reader_first(&mut self) {
+        if let Some(reader) = self.reader.as_mut() {
+            reader.scroll_first();
+        }
+    }
+
+    pub(in crate::application) fn scroll_reader_last(&mut self) {
+        if let Some(reader) = self.reader.as_mut() {
+            reader.scroll_last();
+        }
+    }
+
+    pub(in crate::application) fn move_reader_link(&mut self, direction: Direction) {
+        if let Some(reader) = self.reader.as_mut() {
+            reader.move_link(direction);
+        }
+    }
+
+    /// Opens the selected link, or the entry itself when no link is selected.
+    /// Relative links resolve against the entry URL.
+    pub(in crate::application) fn open_reader_link(&self) -> Option<Operation> {
+        let reader = self.reader.as_ref()?;
+        let entry_url = reader
+            .entry_website_url()
+            .and_then(|url| Self::parse_url(url, "entry"));
+        let Some(link) = reader.selected_link_url() else {
+            return entry_url.map(|url| Operation::OpenBrowser { url });
+        };
+        match entry_url {
+            Some(base) => match base.join(&link) {
+                Ok(url) => Some(Operation::OpenBrowser { url }),
+                Err(error) => {
+                    warn!(%error, url = link, "cannot open invalid link URL");
+                    None
+                }
+            },
+            None => Self::parse_browser_url(&link, "link"),
+        }
+    }
+
+    fn reader_of(&mut self, entry_id: &EntryId) -> Option<&mut ReaderWidget> {
+        self.reader
+            .as_mut()
+            .filter(|reader| reader.entry_id() == entry_id)
+    }
+
     fn selected_entry_url(&self) -> Option<Url> {
         let entry_website_url = self.entries.selected_entry_website_url()?;
         Self::parse_url(entry_website_url, "entry")
@@ -169,8 +326,39 @@ impl FeedsComponent {
             matches!(self.timeline, TimelineState::Uninitialized),
             "timeline bootstrap started more than once"
         );
+        self.begin_timeline_window(limit)
+    }
+
+    fn begin_timeline_window(&mut self, limit: usize) -> Operation {
         self.timeline = TimelineState::FetchingWindow { base_seq: None };
-        Operation::FetchTimelineWindow { limit }
+        self.timeline_window_stale = false;
+        Operation::FetchTimelineWindow {
+            limit,
+            filter: self.timeline_filter.clone(),
+        }
+    }
+
+    /// Replaces the timeline window with one matching `filter`.
+    ///
+    /// A window or catch-up in flight finishes first, then the window is
+    /// fetched again.
+    pub(in crate::application) fn set_timeline_filter(
+        &mut self,
+        filter: payload::TimelineEntriesFilter,
+        limit: usize,
+    ) -> Option<Operation> {
+        if self.timeline_filter == filter {
+            return None;
+        }
+        self.timeline_filter = filter;
+        match self.timeline {
+            TimelineState::Uninitialized => None,
+            TimelineState::FetchingWindow { .. } | TimelineState::CatchingUp { .. } => {
+                self.timeline_window_stale = true;
+                None
+            }
+            TimelineState::Ready { .. } => Some(self.begin_timeline_window(limit)),
+        }
     }
 
     pub(in crate::application) fn refresh_timeline(&mut self) -> Option<Operation> {
@@ -191,6 +379,26 @@ impl FeedsComponent {
         }
     }
 
+    /// Applies a change pushed by the server when it continues from the seq
+    /// the timeline is synced up to, and catches up otherwise.
+    pub(in crate::application) fn apply_pushed_timeline_change(
+        &mut self,
+        change: payload::TimelineChange,
+        seqs: RangeInclusive<i64>,
+        limit: usize,
+    ) -> Option<Operation> {
+        match &mut self.timeline {
+            TimelineState::Ready { seq } if seqs.contains(seq) => {
+                self.entries.apply_changes(vec![change], limit);
+                *seq = *seqs.end();
+                None
+            }
+            // Already synced past the change.
+            TimelineState::Ready { seq } if *seq > *seqs.end() => None,
+            _ => self.refresh_timeline(),
+        }
+    }
+
     pub(in crate::application) fn apply_timeline_window_chunk(
         &mut self,
         entries: Vec<payload::TimelineEntry>,
@@ -219,12 +427,16 @@ impl FeedsComponent {
     pub(in crate::application) fn complete_timeline_window(
         &mut self,
         succeeded: bool,
+        limit: usize,
     ) -> Option<Operation> {
         let TimelineState::FetchingWindow { base_seq } =
             std::mem::replace(&mut self.timeline, TimelineState::Uninitialized)
         else {
             panic!("timeline window completed outside window bootstrap");
         };
+        if self.timeline_window_stale {
+            return Some(self.begin_timeline_window(limit));
+        }
         match base_seq {
             Some(seq) => {
                 self.timeline = TimelineState::CatchingUp { seq, dirty: false };
@@ -253,16 +465,29 @@ impl FeedsComponent {
         *current_seq = seq;
     }
 
+    /// The server dropped removals after the catch-up seq, so the window is
+    /// reloaded instead of catching up.
+    pub(in crate::application) fn expire_timeline_catch_up(&mut self) {
+        assert!(
+            matches!(self.timeline, TimelineState::CatchingUp { .. }),
+            "timeline changes expired outside catch-up"
+        );
+        self.timeline_window_stale = true;
+    }
+
     pub(in crate::application) fn complete_timeline_catch_up(
         &mut self,
         succeeded: bool,
+        limit: usize,
     ) -> Option<Operation> {
         let TimelineState::CatchingUp { seq, dirty } =
             std::mem::replace(&mut self.timeline, TimelineState::Uninitialized)
         else {
             panic!("timeline catch-up completed outside catch-up");
         };
-        if succeeded && dirty {
+        if self.timeline_window_stale {
+            Some(self.begin_timeline_window(limit))
+        } else if succeeded && dirty {
             self.timeline = TimelineState::CatchingUp { seq, dirty: false };
             Some(Operation::CatchUpTimeline { since: seq })
         } else {
@@ -271,16 +496,108 @@ impl FeedsComponent {
         }
     }
 
-    pub(in crate::application) fn move_entry(&mut self, direction: Direction) {
+    pub(in crate::application) fn move_entry(&mut self, direction: Direction) -> Option<Operation> {
         self.entries.move_selection(direction);
+        self.fetch_next_listing_page_if_needed()
     }
 
     pub(in crate::application) fn move_entry_first(&mut self) {
         self.entries.move_first();
     }
 
-    pub(in crate::application) fn move_entry_last(&mut self) {
+    pub(in crate::application) fn move_entry_last(&mut self) -> Option<Operation> {
         self.entries.move_last();
+        self.fetch_next_listing_page_if_needed()
+    }
+
+    /// Replaces the shown entries with the server-side matches of `query`.
+    pub(in crate::application) fn begin_search(&mut self, query: String) -> Operation {
+        self.begin_listing(EntryListing::Search { query })
+    }
+
+    /// Shows the saved entries instead of the timeline, or returns to the
+    /// timeline when they are already shown.
+    pub(in crate::application) fn toggle_saved_entries(&mut self) -> Option<Operation> {
+        if self.is_showing(&EntryListing::Saved) {
+            self.end_listing();
+            None
+        } else {
+            Some(self.begin_listing(EntryListing::Saved))
+        }
+    }
+
+    /// Shows the entry history of the selected feed instead of the timeline.
+    pub(in crate::application) fn browse_selected_feed_entries(&mut self) -> Option<Operation> {
+        let url = self.subscription.selected_feed()?.url.clone();
+        self.close_reader();
+        Some(self.begin_listing(EntryListing::Feed { url }))
+    }
+
+    fn begin_listing(&mut self, listing: EntryListing) -> Operation {
+        self.entries.start_listing(listing.clone());
+        let paging = ListingPaging {
+            listing,
+            next_cursor: None,
+            fetching: true,
+        };
+        let operation = paging.fetch(Populate::Replace, None);
+        self.listing = Some(paging);
+        operation
+    }
+
+    pub(in crate::application) fn is_showing_listing(&self) -> bool {
+        self.listing.is_some()
+    }
+
+    fn is_showing(&self, listing: &EntryListing) -> bool {
+        self.listing
+            .as_ref()
+            .is_some_and(|paging| &paging.listing == listing)
+    }
+
+    pub(in crate::application) fn end_listing(&mut self) {
+        self.listing = None;
+        self.entries.end_listing();
+    }
+
+    /// Pages of a listing that has since been ended or replaced are dropped.
+    pub(in crate::application) fn apply_listing_page(
+        &mut self,
+        listing: &EntryListing,
+        populate: Populate,
+        entries: Vec<payload::TimelineEntry>,
+        next_cursor: Option<String>,
+    ) {
+        let Some(paging) = self
+            .listing
+            .as_mut()
+            .filter(|paging| &paging.listing == listing)
+        else {
+            return;
+        };
+        paging.next_cursor = next_cursor;
+        self.entries.update_listing(populate, entries);
+    }
+
+    pub(in crate::application) fn complete_listing_page(&mut self, listing: &EntryListing) {
+        if let Some(paging) = self
+            .listing
+            .as_mut()
+            .filter(|paging| &paging.listing == listing)
+        {
+            paging.fetching = false;
+        }
+    }
+
+    /// Requests the next page of the listing once its last loaded entry is selected.
+    fn fetch_next_listing_page_if_needed(&mut self) -> Option<Operation> {
+        if !self.entries.is_last_listing_entry_selected() {
+            return None;
+        }
+        let paging = self.listing.as_mut().filter(|paging| !paging.fetching)?;
+        let after = paging.next_cursor.clone()?;
+        paging.fetching = true;
+        Some(paging.fetch(Populate::Append, Some(after)))
     }
 
     #[cfg(feature = "integration")]
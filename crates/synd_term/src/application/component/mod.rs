@@ -1,4 +1,8 @@
-use crate::{application::Features, config::Categories, ui::theme::Theme};
+use crate::{
+    application::Features,
+    config::Categories,
+    ui::theme::{Theme, Themes},
+};
 
 mod commands;
 mod events;
@@ -21,11 +25,12 @@ impl Components {
     pub(super) fn new(
         features: &Features,
         theme: Theme,
+        themes: Themes,
         categories: Categories,
         authentication: AuthenticationState,
     ) -> Self {
         Self {
-            shell: ShellComponent::new(features, theme, categories, authentication),
+            shell: ShellComponent::new(features, theme, themes, categories, authentication),
             feeds: FeedsComponent::new(),
             gh: GhComponent::new(),
         }
@@ -11,7 +11,7 @@ use crate::{
     event::{AuthEvent, OperationError},
     operation::Operation,
     ui::{
-        theme::{Palette, Theme},
+        theme::{Theme, Themes},
         widgets::{
             filter::{FilterWidget, Filterer},
             status::StatusLineWidget,
@@ -159,6 +159,7 @@ impl AuthenticationState {
 /// Global terminal interaction and status state shared across domain components.
 pub(crate) struct ShellComponent {
     pub(in crate::application) theme: Theme,
+    themes: Themes,
     pub(in crate::application) categories: Categories,
     state: State,
     authentication: AuthenticationState,
@@ -174,11 +175,13 @@ impl ShellComponent {
     pub(super) fn new(
         features: &Features,
         theme: Theme,
+        themes: Themes,
         categories: Categories,
         authentication: AuthenticationState,
     ) -> Self {
         Self {
             theme,
+            themes,
             categories,
             state: State::new(),
             authentication,
@@ -280,6 +283,10 @@ impl ShellComponent {
         self.tabs.move_selection(direction)
     }
 
+    pub(in crate::application) fn select_tab(&mut self, tab: Tab) {
+        self.tabs.select(tab);
+    }
+
     pub(in crate::application) fn move_filter_requirement(
         &mut self,
         direction: Direction,
@@ -287,6 +294,10 @@ impl ShellComponent {
         self.filter.move_requirement(direction)
     }
 
+    pub(in crate::application) fn toggle_filter_unread_only(&mut self) -> Filterer {
+        self.filter.toggle_unread_only()
+    }
+
     pub(in crate::application) fn active_filterer(&self) -> Filterer {
         self.filter.filterer(self.current_filter_target())
     }
@@ -299,13 +310,6 @@ impl ShellComponent {
     }
 
     pub(in crate::application) fn rotate_theme(&mut self) {
-        let palette = match self.theme.name {
-            "ferra" => Palette::solarized_dark(),
-            "solarized_dark" => Palette::helix(),
-            "helix" => Palette::dracula(),
-            "dracula" => Palette::eldritch(),
-            _ => Palette::ferra(),
-        };
-        self.theme = Theme::with_palette(palette);
+        self.theme = self.themes.next(&self.theme.name);
     }
 }
@@ -1,7 +1,7 @@
 use futures_util::FutureExt as _;
 use synd_client::{SyndApiError, payload};
-use synd_feed::types::FeedUrl;
-use tracing::debug;
+use synd_feed::{entry::EntryId, types::FeedUrl};
+use tracing::{debug, warn};
 
 use crate::{
     application::{FeedApiRef, Populate, RequestError},
@@ -15,6 +15,9 @@ use super::{
 
 const TIMELINE_WINDOW_PAGE_SIZE: usize = 250;
 const TIMELINE_CHANGES_PAGE_SIZE: i64 = 200;
+const TIMELINE_SEARCH_PAGE_SIZE: i64 = 50;
+const SAVED_ENTRIES_PAGE_SIZE: i64 = 50;
+const FEED_ENTRIES_PAGE_SIZE: i64 = 50;
 
 /// Executes feed API requests and owns the long-lived feed event source.
 pub(super) struct FeedDriver {
@@ -38,6 +41,30 @@ impl FeedDriver {
         self.watcher.restart_if_started(self.api.clone());
     }
 
+    /// Discovery failing to reach the URL is reported as `None` rather than
+    /// as a request error, so the subscription can still go ahead.
+    pub(super) fn discover_feeds(
+        &self,
+        input: payload::SubscribeFeedInput,
+    ) -> impl FnOnce(RequestContext) -> RequestFuture + use<> {
+        let api = self.api.clone();
+
+        move |context| {
+            async move {
+                let candidates = match api.discover_feeds(input.url.clone()).await {
+                    Ok(candidates) => Some(candidates),
+                    Err(err) => {
+                        warn!(url = %input.url, "failed to discover feeds: {err}");
+                        None
+                    }
+                };
+                context.emit_feeds(FeedRequestEvent::FeedsDiscovered { input, candidates });
+                Ok(())
+            }
+            .boxed()
+        }
+    }
+
     pub(super) fn subscribe_feed(
         &self,
         input: payload::SubscribeFeedInput,
@@ -75,6 +102,74 @@ impl FeedDriver {
         }
     }
 
+    pub(super) fn request_crawl(
+        &self,
+        url: FeedUrl,
+    ) -> impl FnOnce(RequestContext) -> RequestFuture + use<> {
+        let api = self.api.clone();
+
+        move |context| {
+            async move {
+                let payload = api
+                    .request_crawl(url)
+                    .await
+                    .map_err(RequestError::SyndApi)?;
+                context.emit_feeds(FeedRequestEvent::CrawlRequested {
+                    url: payload.url,
+                    code: payload.status.code,
+                    disposition: payload.disposition,
+                });
+                Ok(())
+            }
+            .boxed()
+        }
+    }
+
+    pub(super) fn mark_entry_read(
+        &self,
+        entry_id: EntryId,
+        read: bool,
+    ) -> impl FnOnce(RequestContext) -> RequestFuture + use<> {
+        let api = self.api.clone();
+
+        move |context| {
+            async move {
+                let entry_ids = vec![entry_id.clone()];
+                if read {
+                    api.mark_entries_read(entry_ids).await
+                } else {
+                    api.mark_entries_unread(entry_ids).await
+                }
+                .map_err(RequestError::SyndApi)?;
+                context.emit_feeds(FeedRequestEvent::EntryReadChanged { entry_id, read });
+                Ok(())
+            }
+            .boxed()
+        }
+    }
+
+    pub(super) fn save_entry(
+        &self,
+        entry_id: EntryId,
+        saved: bool,
+    ) -> impl FnOnce(RequestContext) -> RequestFuture + use<> {
+        let api = self.api.clone();
+
+        move |context| {
+            async move {
+                if saved {
+                    api.save_entry(entry_id.clone()).await
+                } else {
+                    api.unsave_entry(entry_id.clone()).await
+                }
+                .map_err(RequestError::SyndApi)?;
+                context.emit_feeds(FeedRequestEvent::EntrySavedChanged { entry_id, saved });
+                Ok(())
+            }
+            .boxed()
+        }
+    }
+
     pub(super) fn fetch_subscription(
         &self,
         populate: Populate,
@@ -99,15 +194,131 @@ impl FeedDriver {
         }
     }
 
+    /// Fetches one timeline entry including the content body the timeline
+    /// listing leaves out.
+    pub(super) fn fetch_entry_content(
+        &self,
+        entry_id: EntryId,
+    ) -> impl FnOnce(RequestContext) -> RequestFuture + use<> {
+        let api = self.api.clone();
+
+        move |context| {
+            async move {
+                let timeline_entry = api
+                    .fetch_timeline_entry(entry_id.clone())
+                    .await
+                    .map_err(RequestError::SyndApi)?;
+                context.emit_feeds(FeedRequestEvent::EntryContentFetched {
+                    entry_id,
+                    entry: timeline_entry.map(|timeline_entry| Box::new(timeline_entry.entry)),
+                });
+                Ok(())
+            }
+            .boxed()
+        }
+    }
+
+    /// Fetches one page of server-side search matches after `after`.
+    pub(super) fn search_timeline(
+        &self,
+        query: String,
+        populate: Populate,
+        after: Option<String>,
+    ) -> impl FnOnce(RequestContext) -> RequestFuture + use<> {
+        let api = self.api.clone();
+
+        move |context| {
+            async move {
+                let page = api
+                    .search_timeline(query.clone(), after, TIMELINE_SEARCH_PAGE_SIZE)
+                    .await
+                    .map_err(RequestError::SyndApi)?;
+                let next_cursor = match page.page_info {
+                    payload::PageInfo::Complete { .. } => None,
+                    payload::PageInfo::More { next_cursor } => Some(next_cursor),
+                };
+                context.emit_feeds(FeedRequestEvent::TimelineSearched {
+                    query,
+                    populate,
+                    entries: page.nodes,
+                    next_cursor,
+                });
+                Ok(())
+            }
+            .boxed()
+        }
+    }
+
+    /// Fetches one page of saved entries after `after`.
+    pub(super) fn fetch_saved_entries(
+        &self,
+        populate: Populate,
+        after: Option<String>,
+    ) -> impl FnOnce(RequestContext) -> RequestFuture + use<> {
+        let api = self.api.clone();
+
+        move |context| {
+            async move {
+                let page = api
+                    .fetch_saved_entries(after, SAVED_ENTRIES_PAGE_SIZE)
+                    .await
+                    .map_err(RequestError::SyndApi)?;
+                let next_cursor = match page.page_info {
+                    payload::PageInfo::Complete { .. } => None,
+                    payload::PageInfo::More { next_cursor } => Some(next_cursor),
+                };
+                context.emit_feeds(FeedRequestEvent::SavedEntriesFetched {
+                    populate,
+                    entries: page.nodes,
+                    next_cursor,
+                });
+                Ok(())
+            }
+            .boxed()
+        }
+    }
+
+    /// Fetches one page of the feed's entry history after `after`.
+    pub(super) fn fetch_feed_entries(
+        &self,
+        url: FeedUrl,
+        populate: Populate,
+        after: Option<String>,
+    ) -> impl FnOnce(RequestContext) -> RequestFuture + use<> {
+        let api = self.api.clone();
+
+        move |context| {
+            async move {
+                let page = api
+                    .fetch_feed_entries(url.clone(), after, FEED_ENTRIES_PAGE_SIZE)
+                    .await
+                    .map_err(RequestError::SyndApi)?;
+                let next_cursor = match page.page_info {
+                    payload::PageInfo::Complete { .. } => None,
+                    payload::PageInfo::More { next_cursor } => Some(next_cursor),
+                };
+                context.emit_feeds(FeedRequestEvent::FeedEntriesFetched {
+                    url,
+                    populate,
+                    entries: page.nodes,
+                    next_cursor,
+                });
+                Ok(())
+            }
+            .boxed()
+        }
+    }
+
     /// Fetches one bounded timeline window while keeping cursor pagination private.
     pub(super) fn fetch_timeline_window(
         &self,
         limit: usize,
+        filter: payload::TimelineEntriesFilter,
     ) -> impl FnOnce(RequestContext) -> RequestFuture + use<> {
         let api = self.api.clone();
 
         move |context| {
-            TimelineWindowRequest::new(api, context, limit)
+            TimelineWindowRequest::new(api, context, limit, filter)
                 .run()
                 .boxed()
         }
@@ -132,14 +343,21 @@ impl FeedDriver {
 struct TimelineWindowRequest {
     api: FeedApiRef,
     context: RequestContext,
+    filter: payload::TimelineEntriesFilter,
     state: TimelineWindowState,
 }
 
 impl TimelineWindowRequest {
-    fn new(api: FeedApiRef, context: RequestContext, limit: usize) -> Self {
+    fn new(
+        api: FeedApiRef,
+        context: RequestContext,
+        limit: usize,
+        filter: payload::TimelineEntriesFilter,
+    ) -> Self {
         Self {
             api,
             context,
+            filter,
             state: TimelineWindowState::new(limit),
         }
     }
@@ -163,7 +381,7 @@ impl TimelineWindowRequest {
             "fetch timeline window chunk"
         );
         self.api
-            .fetch_timeline_entries(self.state.cursor(), first)
+            .fetch_timeline_entries(self.filter.clone(), self.state.cursor(), first)
             .await
             .map_err(RequestError::SyndApi)
     }
@@ -312,6 +530,12 @@ impl TimelineCatchUpRequest {
     async fn run(mut self) -> Result<(), RequestError> {
         loop {
             let page = self.observe_next_page().await?;
+            if page.expired {
+                // Changes gathered so far are dropped with the window.
+                self.context
+                    .emit_feeds(FeedRequestEvent::TimelineChangesExpired);
+                return Ok(());
+            }
             let advance = TimelineCatchUpAdvance::from(page);
             let flow = self.state.apply(advance);
             if flow.is_complete() {
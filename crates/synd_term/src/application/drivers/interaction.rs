@@ -51,8 +51,8 @@ impl<'a> TerminalInteraction<'a> {
         }
     }
 
-    pub(super) fn open_feed_subscription_editor(self) -> Event {
-        self.open_editor(FeedSubscriptionEditor)
+    pub(super) fn open_feed_subscription_editor(self, prompt: Option<&str>) -> Event {
+        self.open_editor(FeedSubscriptionEditor { prompt })
     }
 
     pub(super) fn open_feed_edition_editor(self, prompt: &str) -> Event {
@@ -82,11 +82,13 @@ trait EditorPurpose {
     fn failed(self, error: OpenEditorError) -> OperationError;
 }
 
-struct FeedSubscriptionEditor;
+struct FeedSubscriptionEditor<'a> {
+    prompt: Option<&'a str>,
+}
 
-impl EditorPurpose for FeedSubscriptionEditor {
+impl EditorPurpose for FeedSubscriptionEditor<'_> {
     fn prompt(&self) -> &str {
-        InputParser::SUSBSCRIBE_FEED_PROMPT
+        self.prompt.unwrap_or(InputParser::SUSBSCRIBE_FEED_PROMPT)
     }
 
     fn closed(self, input: String) -> Event {
@@ -156,9 +156,9 @@ impl Drivers {
                     make_request,
                 );
             }
-            Operation::OpenFeedSubscriptionEditor => {
+            Operation::OpenFeedSubscriptionEditor { prompt } => {
                 let event = TerminalInteraction::new(&self.interaction, &mut self.terminal)
-                    .open_feed_subscription_editor();
+                    .open_feed_subscription_editor(prompt.as_deref());
                 self.queue_event(event);
             }
             Operation::OpenFeedEditionEditor { prompt } => {
@@ -166,6 +166,13 @@ impl Drivers {
                     .open_feed_edition_editor(prompt.as_str());
                 self.queue_event(event);
             }
+            Operation::DiscoverFeeds { input } => {
+                let kind = RequestKind::DiscoverFeeds {
+                    url: input.url.clone(),
+                };
+                let make_request = self.feed.discover_feeds(input);
+                self.register_request(kind, make_request);
+            }
             Operation::SubscribeFeed { input } => {
                 let kind = RequestKind::SubscribeFeed {
                     url: input.url.clone(),
@@ -178,6 +185,27 @@ impl Drivers {
                 let make_request = self.feed.unsubscribe_feed(url);
                 self.register_request(kind, make_request);
             }
+            Operation::RequestCrawl { url } => {
+                let kind = RequestKind::RequestCrawl { url: url.clone() };
+                let make_request = self.feed.request_crawl(url);
+                self.register_request(kind, make_request);
+            }
+            Operation::MarkEntryRead { entry_id, read } => {
+                let kind = RequestKind::MarkEntryRead {
+                    entry_id: entry_id.clone(),
+                    read,
+                };
+                let make_request = self.feed.mark_entry_read(entry_id, read);
+                self.register_request(kind, make_request);
+            }
+            Operation::SaveEntry { entry_id, saved } => {
+                let kind = RequestKind::SaveEntry {
+                    entry_id: entry_id.clone(),
+                    saved,
+                };
+                let make_request = self.feed.save_entry(entry_id, saved);
+                self.register_request(kind, make_request);
+            }
             Operation::FetchSubscription {
                 populate,
                 after,
@@ -186,14 +214,24 @@ impl Drivers {
                 let make_request = self.feed.fetch_subscription(populate, after, first);
                 self.register_request(RequestKind::FetchSubscription, make_request);
             }
-            Operation::FetchTimelineWindow { limit } => {
-                let make_request = self.feed.fetch_timeline_window(limit);
+            Operation::FetchTimelineWindow { limit, filter } => {
+                let make_request = self.feed.fetch_timeline_window(limit, filter);
                 self.register_request(RequestKind::FetchTimelineWindow { limit }, make_request);
             }
             Operation::CatchUpTimeline { since } => {
                 let make_request = self.feed.catch_up_timeline(since);
                 self.register_request(RequestKind::CatchUpTimeline { since }, make_request);
             }
+            operation @ (Operation::SearchTimeline { .. }
+            | Operation::FetchSavedEntries { .. }
+            | Operation::FetchFeedEntries { .. }) => self.dispatch_listing_operation(operation),
+            Operation::FetchEntryContent { entry_id } => {
+                let kind = RequestKind::FetchEntryContent {
+                    entry_id: entry_id.clone(),
+                };
+                let make_request = self.feed.fetch_entry_content(entry_id);
+                self.register_request(kind, make_request);
+            }
             Operation::WatchFeedEvents => self.feed.watch_events(),
             Operation::FetchGhNotifications { populate, params } => {
                 let kind = RequestKind::FetchGhNotifications { page: params.page };
@@ -236,6 +274,37 @@ impl Drivers {
         }
     }
 
+    /// Requests one page of a server-side entry listing.
+    fn dispatch_listing_operation(&mut self, operation: Operation) {
+        match operation {
+            Operation::SearchTimeline {
+                query,
+                populate,
+                after,
+            } => {
+                let kind = RequestKind::SearchTimeline {
+                    query: query.clone(),
+                };
+                let make_request = self.feed.search_timeline(query, populate, after);
+                self.register_request(kind, make_request);
+            }
+            Operation::FetchSavedEntries { populate, after } => {
+                let make_request = self.feed.fetch_saved_entries(populate, after);
+                self.register_request(RequestKind::FetchSavedEntries, make_request);
+            }
+            Operation::FetchFeedEntries {
+                url,
+                populate,
+                after,
+            } => {
+                let kind = RequestKind::FetchFeedEntries { url: url.clone() };
+                let make_request = self.feed.fetch_feed_entries(url, populate, after);
+                self.register_request(kind, make_request);
+            }
+            _ => unreachable!("not an entry listing operation"),
+        }
+    }
+
     fn register_request<F>(&mut self, kind: RequestKind, make_request: F)
     where
         F: FnOnce(RequestContext) -> RequestFuture,
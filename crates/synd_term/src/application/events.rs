@@ -5,6 +5,7 @@ use tracing::{error, info_span, instrument, warn};
 use crate::{
     event::{Event, FeedRequestEvent, FeedsEvent, OperationError},
     operation::{Operation, Operations},
+    ui::widgets::entries::EntryListing,
 };
 
 use super::{Application, RequestError, RequestId, RequestKind, component::ApiAccessTransition};
@@ -49,9 +50,14 @@ impl Application {
             Event::Feeds(FeedsEvent::Request { request_id, event }) => {
                 self.apply_feed_request_event(request_id, event).into()
             }
-            Event::Feeds(FeedsEvent::Push { event }) => {
-                self.components.apply_feed_push(event).into()
-            }
+            Event::Feeds(FeedsEvent::Push { event }) => self
+                .components
+                .apply_feed_push(
+                    event,
+                    self.config.feeds_per_pagination,
+                    self.config.entries_limit,
+                )
+                .into(),
             Event::Gh { request_id, event } => {
                 self.components
                     .shell
@@ -128,18 +134,50 @@ impl Application {
         };
 
         match kind {
-            RequestKind::FetchTimelineWindow { .. } => {
-                self.components.feeds.complete_timeline_window(succeeded)
+            RequestKind::FetchTimelineWindow { limit } => self
+                .components
+                .feeds
+                .complete_timeline_window(succeeded, limit),
+            RequestKind::CatchUpTimeline { .. } => self
+                .components
+                .feeds
+                .complete_timeline_catch_up(succeeded, self.config.entries_limit),
+            RequestKind::SearchTimeline { query } => {
+                self.components
+                    .feeds
+                    .complete_listing_page(&EntryListing::Search { query });
+                None
             }
-            RequestKind::CatchUpTimeline { .. } => {
-                self.components.feeds.complete_timeline_catch_up(succeeded)
+            RequestKind::FetchSavedEntries => {
+                self.components
+                    .feeds
+                    .complete_listing_page(&EntryListing::Saved);
+                None
+            }
+            RequestKind::FetchFeedEntries { url } => {
+                self.components
+                    .feeds
+                    .complete_listing_page(&EntryListing::Feed { url });
+                None
+            }
+            RequestKind::FetchEntryContent { entry_id } => {
+                self.components.feeds.complete_entry_content(&entry_id);
+                None
             }
             _ => None,
         }
     }
 
     fn apply_request_failure(&mut self, kind: &RequestKind, error: &RequestError) {
-        let message = Self::request_error_message(error);
+        let message = match kind {
+            RequestKind::RequestCrawl { url } => {
+                format!(
+                    "Refresh of {url} failed: {}",
+                    Self::request_error_message(error)
+                )
+            }
+            _ => Self::request_error_message(error),
+        };
         self.components.shell.apply_request_failure(kind, error);
         error!(?kind, error = %message, "request failed");
         self.show_error_message(message);
@@ -76,6 +76,13 @@ impl InFlightRequests {
             .get_mut(&request_id)
             .expect("driver emitted a request event for an unknown request");
         match (&request.kind, event) {
+            (
+                RequestKind::DiscoverFeeds { url: expected },
+                FeedRequestEvent::FeedsDiscovered { input, .. },
+            ) => assert_eq!(
+                expected, &input.url,
+                "discovered feeds did not match their request"
+            ),
             (
                 RequestKind::SubscribeFeed { url: expected },
                 FeedRequestEvent::FeedSubscribed { url },
@@ -84,10 +91,53 @@ impl InFlightRequests {
                 RequestKind::UnsubscribeFeed { url: expected },
                 FeedRequestEvent::FeedUnsubscribed { url },
             ) => assert_eq!(expected, url, "unsubscribed feed did not match its request"),
-            (RequestKind::FetchSubscription, FeedRequestEvent::SubscriptionFetched { .. })
+            (
+                RequestKind::MarkEntryRead {
+                    entry_id: expected,
+                    read: expected_read,
+                },
+                FeedRequestEvent::EntryReadChanged { entry_id, read },
+            ) => assert_eq!(
+                (expected, expected_read),
+                (entry_id, read),
+                "read mark change did not match its request"
+            ),
+            (
+                RequestKind::SaveEntry {
+                    entry_id: expected,
+                    saved: expected_saved,
+                },
+                FeedRequestEvent::EntrySavedChanged { entry_id, saved },
+            ) => assert_eq!(
+                (expected, expected_saved),
+                (entry_id, saved),
+                "save change did not match its request"
+            ),
+            (
+                RequestKind::SearchTimeline { query: expected },
+                FeedRequestEvent::TimelineSearched { query, .. },
+            ) => assert_eq!(
+                expected, query,
+                "search results did not match their request"
+            ),
+            (
+                RequestKind::FetchFeedEntries { url: expected },
+                FeedRequestEvent::FeedEntriesFetched { url, .. },
+            ) => assert_eq!(expected, url, "feed entries did not match their request"),
+            (
+                RequestKind::FetchEntryContent { entry_id: expected },
+                FeedRequestEvent::EntryContentFetched { entry_id, .. },
+            ) => assert_eq!(
+                expected, entry_id,
+                "entry content did not match its request"
+            ),
+            (RequestKind::RequestCrawl { .. }, FeedRequestEvent::CrawlRequested { .. })
+            | (RequestKind::FetchSubscription, FeedRequestEvent::SubscriptionFetched { .. })
+            | (RequestKind::FetchSavedEntries, FeedRequestEvent::SavedEntriesFetched { .. })
             | (
                 RequestKind::CatchUpTimeline { .. },
-                FeedRequestEvent::TimelineChangesFetched { .. },
+                FeedRequestEvent::TimelineChangesFetched { .. }
+                | FeedRequestEvent::TimelineChangesExpired,
             ) => {}
             (
                 RequestKind::FetchTimelineWindow { .. },
@@ -1,10 +1,13 @@
 use nom_language::error::{VerboseError, VerboseErrorKind};
-use synd_client::payload::SubscribeFeedInput;
+use std::fmt::Write as _;
+
+use synd_client::payload::{FeedCandidate, SubscribeFeedInput};
 use thiserror::Error;
 
 use crate::{
     config::Categories,
     types::{self, CrawlPolicyExt},
+    ui,
 };
 
 type NomError<'s> = VerboseError<&'s str>;
@@ -14,6 +17,10 @@ const CTX_CATEGORY: &str = "category";
 const CTX_CATEGORY_POST: &str = "category_post";
 const CTX_URL: &str = "url";
 const CTX_CRAWL_POLICY: &str = "crawl_policy";
+const CTX_FULL_CONTENT: &str = "full_content";
+
+/// Trailing prompt token opting the subscription into full article fetching.
+const FULL_CONTENT_TOKEN: &str = "full";
 
 #[derive(Error, Debug, PartialEq, Eq)]
 pub(super) enum ParseFeedError {
@@ -29,14 +36,15 @@ impl<'a> InputParser<'a> {
     pub(super) const SUSBSCRIBE_FEED_PROMPT: &'static str =
         "# Please enter the requirement, category, and URL for subscription in the following format
 #
-# <requirement> <category> <url> [manual|interval:<duration>]
+# <requirement> <category> <url> [manual|interval:<duration>|adaptive:<min>-<max>] [full]
 #
 #   * The requirement must be one of 
 #     * \"MUST\" 
 #     * \"SHOULD\" 
 #     * \"MAY\"
 #   * For the category, please choose one category of the feed(for example, \"rust\")
-#   * Crawl policy is optional. Use \"manual\" or \"interval:2h\".
+#   * Crawl policy is optional. Use \"manual\", \"interval:2h\" or \"adaptive:15m-12h\".
+#   * Append \"full\" to fetch the full article of new entries from their web page.
 #
 # with '#' will be ignored, and an empty URL aborts the subscription.
 #
@@ -74,7 +82,7 @@ impl<'a> InputParser<'a> {
                     }
                     Some((input, VerboseErrorKind::Context(CTX_CRAWL_POLICY))) => {
                         format!(
-                            "Invalid crawl policy: use 'manual' or 'interval:<duration>'. {input}"
+                            "Invalid crawl policy: use 'manual', 'interval:<duration>' or 'adaptive:<min>-<max>'. {input}"
                         )
                     }
                     Some((input, _)) => format!("Failed to parse input: {input}"),
@@ -92,13 +100,58 @@ impl<'a> InputParser<'a> {
             .unwrap_or_default();
 
         format!(
-            "{}\n{requirement} {category} {feed_url}{crawl_policy}",
+            "{}\n{requirement} {category} {feed_url}{crawl_policy}{full_content}",
             Self::SUSBSCRIBE_FEED_PROMPT,
             requirement = feed.requirement(),
             category = feed.category(),
             feed_url = feed.url,
+            full_content = full_content_suffix(feed.fetch_full_content),
         )
     }
+
+    /// Prompt to pick one of the feeds discovered from the URL the user
+    /// entered. The best candidate is filled in and the others are listed
+    /// as comments to paste over it.
+    pub(super) fn pick_feed_prompt(
+        input: &SubscribeFeedInput,
+        candidates: &[FeedCandidate],
+    ) -> String {
+        let mut prompt = format!(
+            "{}#\n# Feeds discovered from {}. Replace the URL below to pick another one:\n#\n",
+            Self::SUSBSCRIBE_FEED_PROMPT,
+            input.url,
+        );
+        for candidate in candidates {
+            let _ = write!(prompt, "#   {} ({}", candidate.url, candidate.kind.as_str());
+            if let Some(title) = &candidate.title {
+                let _ = write!(prompt, ", \"{title}\"");
+            }
+            prompt.push_str(")\n");
+        }
+        let crawl_policy = input
+            .crawl_policy
+            .as_ref()
+            .and_then(CrawlPolicyExt::prompt_value)
+            .map(|policy| format!(" {policy}"))
+            .unwrap_or_default();
+        let _ = write!(
+            prompt,
+            "{requirement} {category} {feed_url}{crawl_policy}{full_content}",
+            requirement = input.requirement.unwrap_or(ui::DEFAULT_REQUIREMENT),
+            category = input.category.as_ref().unwrap_or(ui::default_category()),
+            feed_url = candidates.first().map_or(&input.url, |best| &best.url),
+            full_content = full_content_suffix(input.fetch_full_content),
+        );
+        prompt
+    }
+}
+
+fn full_content_suffix(fetch_full_content: bool) -> String {
+    if fetch_full_content {
+        format!(" {FULL_CONTENT_TOKEN}")
+    } else {
+        String::new()
+    }
 }
 
 mod feed {
@@ -107,21 +160,23 @@ mod feed {
         branch::alt,
         bytes::complete::{tag_no_case, take_while, take_while_m_n},
         character::complete::{multispace0, multispace1},
-        combinator::{all_consuming, map, opt, value},
+        combinator::{all_consuming, eof, map, opt, peek, value},
         error::context,
         sequence::delimited,
     };
     use nom_language::error::{VerboseError, VerboseErrorKind};
-    use synd_client::payload::{
-        CrawlPolicyInput, PollingIntervalSeconds, PollingPolicyInput, SubscribeFeedInput,
-    };
+    use synd_client::payload::{CrawlPolicyInput, SubscribeFeedInput};
     use synd_feed::types::{Category, FeedUrl, Requirement};
     use tracing::{Level, event};
     use url::Url;
 
     use super::NomError;
-    use crate::application::input_parser::{
-        CTX_CATEGORY, CTX_CATEGORY_POST, CTX_CRAWL_POLICY, CTX_REQUIREMENT, CTX_URL, comment,
+    use crate::{
+        application::input_parser::{
+            CTX_CATEGORY, CTX_CATEGORY_POST, CTX_CRAWL_POLICY, CTX_FULL_CONTENT, CTX_REQUIREMENT,
+            CTX_URL, FULL_CONTENT_TOKEN, comment,
+        },
+        types,
     };
 
     pub(super) fn parse(s: &'_ str) -> Result<SubscribeFeedInput, NomError<'_>> {
@@ -132,7 +187,7 @@ mod feed {
     }
 
     fn feed_input(s: &'_ str) -> IResult<&'_ str, SubscribeFeedInput, NomError<'_>> {
-        let (remain, (_, requirement, _, category, _, feed_url, crawl_policy, _)) = (
+        let (remain, (_, requirement, _, category, _, feed_url, crawl_policy, full_content, _)) = (
             multispace0,
             requirement,
             multispace1,
@@ -140,6 +195,7 @@ mod feed {
             context(CTX_CATEGORY_POST, multispace1),
             url,
             opt((multispace1, crawl_policy).map(|(_, policy)| policy)),
+            opt((multispace1, full_content).map(|(_, full)| full)),
             multispace0,
         )
             .parse(s)?;
@@ -150,6 +206,7 @@ mod feed {
                 requirement: Some(requirement),
                 category: Some(category),
                 crawl_policy,
+                fetch_full_content: full_content.is_some(),
             },
         ))
     }
@@ -206,37 +263,26 @@ mod feed {
         )
         .parse(s)?;
 
-        if token.eq_ignore_ascii_case("manual") {
-            return Ok((
-                remain,
-                CrawlPolicyInput {
-                    polling: PollingPolicyInput::Manual,
-                },
-            ));
-        }
-
-        let Some((kind, duration)) = token.split_once(':') else {
-            return Err(invalid_crawl_policy(s));
-        };
-        if !kind.eq_ignore_ascii_case("interval") {
-            return Err(invalid_crawl_policy(s));
+        // Without a crawl policy the full content token follows the URL
+        if token.eq_ignore_ascii_case(FULL_CONTENT_TOKEN) {
+            return Err(nom::Err::Error(VerboseError {
+                errors: vec![(s, VerboseErrorKind::Context(CTX_CRAWL_POLICY))],
+            }));
         }
+        let polling = types::parse_polling_policy(token).map_err(|_| invalid_crawl_policy(s))?;
 
-        let duration = synd_support::time::humantime::parse_duration(duration)
-            .map_err(|_| invalid_crawl_policy(s))?;
-        if duration.subsec_nanos() != 0 {
-            return Err(invalid_crawl_policy(s));
-        }
-        let seconds = i64::try_from(duration.as_secs()).unwrap_or(i64::MAX);
-        let seconds =
-            PollingIntervalSeconds::try_from(seconds).map_err(|_| invalid_crawl_policy(s))?;
+        Ok((remain, CrawlPolicyInput { polling }))
+    }
 
-        Ok((
-            remain,
-            CrawlPolicyInput {
-                polling: PollingPolicyInput::Interval { seconds },
-            },
-        ))
+    fn full_content(s: &'_ str) -> IResult<&'_ str, (), NomError<'_>> {
+        value(
+            (),
+            context(
+                CTX_FULL_CONTENT,
+                tag_no_case(FULL_CONTENT_TOKEN).and(peek(alt((multispace1, eof)))),
+            ),
+        )
+        .parse(s)
     }
 
     fn invalid_crawl_policy(input: &'_ str) -> nom::Err<NomError<'_>> {
@@ -247,6 +293,8 @@ mod feed {
 
     #[cfg(test)]
     mod tests {
+        use synd_client::payload::{PollingIntervalSeconds, PollingPolicyInput};
+
         use super::*;
 
         #[test]
@@ -279,6 +327,7 @@ mod feed {
                         requirement: Some(Requirement::Must),
                         category: Some(Category::new("rust").unwrap()),
                         crawl_policy: None,
+                        fetch_full_content: false,
                     }
                 ))
             );
@@ -297,6 +346,7 @@ mod feed {
                         crawl_policy: Some(CrawlPolicyInput {
                             polling: PollingPolicyInput::Manual,
                         }),
+                        fetch_full_content: false,
                     }
                 ))
             );
@@ -317,11 +367,59 @@ mod feed {
                                 seconds: PollingIntervalSeconds::try_from(1800).unwrap(),
                             },
                         }),
+                        fetch_full_content: false,
                     }
                 ))
             );
         }
 
+        #[test]
+        fn parse_feed_input_with_adaptive_crawl_policy() {
+            assert_eq!(
+                feed_input("MUST rust https://example.ymgyt.io/atom.xml adaptive:15m-12h"),
+                Ok((
+                    "",
+                    SubscribeFeedInput {
+                        url: "https://example.ymgyt.io/atom.xml".try_into().unwrap(),
+                        requirement: Some(Requirement::Must),
+                        category: Some(Category::new("rust").unwrap()),
+                        crawl_policy: Some(CrawlPolicyInput {
+                            polling: PollingPolicyInput::Adaptive {
+                                min: PollingIntervalSeconds::try_from(900).unwrap(),
+                                max: PollingIntervalSeconds::try_from(43_200).unwrap(),
+                            },
+                        }),
+                        fetch_full_content: false,
+                    }
+                ))
+            );
+        }
+
+        #[test]
+        fn parse_feed_input_with_full_content() {
+            let expected = |crawl_policy| SubscribeFeedInput {
+                url: "https://example.ymgyt.io/atom.xml".try_into().unwrap(),
+                requirement: Some(Requirement::Must),
+                category: Some(Category::new("rust").unwrap()),
+                crawl_policy,
+                fetch_full_content: true,
+            };
+            assert_eq!(
+                feed_input("MUST rust https://example.ymgyt.io/atom.xml full"),
+                Ok(("", expected(None)))
+            );
+            assert_eq!(
+                feed_input("MUST rust https://example.ymgyt.io/atom.xml manual FULL"),
+                Ok((
+                    "",
+                    expected(Some(CrawlPolicyInput {
+                        polling: PollingPolicyInput::Manual,
+                    }))
+                ))
+            );
+            assert!(parse("MUST rust https://example.ymgyt.io/atom.xml fullest").is_err());
+        }
+
         #[test]
         fn parse_feed_input_error() {
             let tests = vec![
@@ -341,6 +439,10 @@ mod feed {
                     "should rust https://example.ymgyt.io/atom.xml interval:1500ms",
                     CTX_CRAWL_POLICY,
                 ),
+                (
+                    "should rust https://example.ymgyt.io/atom.xml adaptive:12h-15m",
+                    CTX_CRAWL_POLICY,
+                ),
             ];
 
             for test in tests {
@@ -407,3 +509,55 @@ mod comment {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use synd_client::payload::{
+        CrawlPolicyInput, FeedCandidateKind, FeedCandidateSource, PollingPolicyInput,
+    };
+    use synd_feed::types::{Category, FeedUrl, Requirement};
+
+    use super::*;
+
+    #[test]
+    fn pick_feed_prompt_fills_in_the_best_candidate() {
+        let input = SubscribeFeedInput {
+            url: FeedUrl::parse("https://blog.example.com").unwrap(),
+            requirement: Some(Requirement::Must),
+            category: Some(Category::new("rust").unwrap()),
+            crawl_policy: Some(CrawlPolicyInput {
+                polling: PollingPolicyInput::Manual,
+            }),
+            fetch_full_content: true,
+        };
+        let candidate = |url: &str, kind, title: Option<&str>| FeedCandidate {
+            url: FeedUrl::parse(url).unwrap(),
+            kind,
+            title: title.map(ToOwned::to_owned),
+            source: FeedCandidateSource::LinkAlternate,
+        };
+        let candidates = [
+            candidate(
+                "https://blog.example.com/atom.xml",
+                FeedCandidateKind::Atom,
+                Some("Posts"),
+            ),
+            candidate(
+                "https://blog.example.com/rss.xml",
+                FeedCandidateKind::Rss,
+                None,
+            ),
+        ];
+
+        let prompt = InputParser::pick_feed_prompt(&input, &candidates);
+
+        assert!(prompt.contains("#   https://blog.example.com/rss.xml (rss)\n"));
+        assert_eq!(
+            InputParser::new(&prompt).parse_feed_subscription(&Categories::default_toml()),
+            Ok(SubscribeFeedInput {
+                url: candidates[0].url.clone(),
+                ..input
+            })
+        );
+    }
+}
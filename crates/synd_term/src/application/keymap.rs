@@ -47,6 +47,10 @@ impl Application {
         }
 
         match self.components.shell.tabs.current() {
+            Tab::Entries if self.components.feeds.is_reader_open() => {
+                layers.push(Layer::Reader);
+                return layers;
+            }
             Tab::Entries => layers.push(Layer::Entries),
             Tab::Feeds => layers.push(Layer::Feeds),
             Tab::Gh => layers.push(Layer::GhNotifications),
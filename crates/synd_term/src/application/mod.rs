@@ -92,6 +92,7 @@ impl Application {
             cache,
             config,
             theme,
+            themes,
             authenticator,
             interactor,
             clock,
@@ -103,7 +104,13 @@ impl Application {
             } else {
                 AuthenticationState::NotRequired
             };
-            Components::new(&config.features, theme, categories, authentication)
+            Components::new(
+                &config.features,
+                theme,
+                themes.unwrap_or_default(),
+                categories,
+                authentication,
+            )
         };
         let drivers = {
             let parts = DriverParts {
@@ -238,7 +245,7 @@ impl Application {
             }) => Operations::Nop,
             CrosstermEvent::Key(key) => {
                 debug!("Handle key event: {key:?}");
-                self.components.shell.prompt.clear_error_message();
+                self.components.shell.prompt.clear_message();
                 self.reset_idle_timer();
                 self.resolve_keymap(*key)
                     .map_or(Operations::Nop, |command| self.apply_command(command))
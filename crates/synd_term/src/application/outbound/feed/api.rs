@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use futures_util::future::BoxFuture;
 use synd_client::{ApiCredential, SyndApiError, payload};
-use synd_feed::types::FeedUrl;
+use synd_feed::{entry::EntryId, types::FeedUrl};
 
 pub type FeedApiRef = Arc<dyn FeedApi>;
 
@@ -21,6 +21,11 @@ pub trait FeedApi: Send + Sync + 'static {
         first: Option<i64>,
     ) -> BoxFuture<'static, Result<payload::SubscriptionPayload, SyndApiError>>;
 
+    fn discover_feeds(
+        &self,
+        url: FeedUrl,
+    ) -> BoxFuture<'static, Result<Vec<payload::FeedCandidate>, SyndApiError>>;
+
     fn subscribe_feed(
         &self,
         input: payload::SubscribeFeedInput,
@@ -30,16 +35,67 @@ pub trait FeedApi: Send + Sync + 'static {
 
     fn fetch_timeline_entries(
         &self,
+        filter: payload::TimelineEntriesFilter,
         after: Option<String>,
         first: i64,
     ) -> BoxFuture<'static, Result<payload::TimelineEntryConnection, SyndApiError>>;
 
+    fn fetch_timeline_entry(
+        &self,
+        id: EntryId,
+    ) -> BoxFuture<'static, Result<Option<payload::TimelineEntry>, SyndApiError>>;
+
+    fn search_timeline(
+        &self,
+        query: String,
+        after: Option<String>,
+        first: i64,
+    ) -> BoxFuture<'static, Result<payload::TimelineSearchConnection, SyndApiError>>;
+
+    fn fetch_saved_entries(
+        &self,
+        after: Option<String>,
+        first: i64,
+    ) -> BoxFuture<'static, Result<payload::SavedEntryConnection, SyndApiError>>;
+
+    fn fetch_feed_entries(
+        &self,
+        url: FeedUrl,
+        after: Option<String>,
+        first: i64,
+    ) -> BoxFuture<'static, Result<payload::FeedEntryConnection, SyndApiError>>;
+
     fn fetch_timeline_changes(
         &self,
         since: i64,
         first: i64,
     ) -> BoxFuture<'static, Result<payload::TimelineChangesPayload, SyndApiError>>;
 
+    fn request_crawl(
+        &self,
+        url: FeedUrl,
+    ) -> BoxFuture<'static, Result<payload::RequestCrawlPayload, SyndApiError>>;
+
+    fn mark_entries_read(
+        &self,
+        entry_ids: Vec<EntryId>,
+    ) -> BoxFuture<'static, Result<payload::MarkEntriesPayload, SyndApiError>>;
+
+    fn mark_entries_unread(
+        &self,
+        entry_ids: Vec<EntryId>,
+    ) -> BoxFuture<'static, Result<payload::MarkEntriesPayload, SyndApiError>>;
+
+    fn save_entry(
+        &self,
+        entry_id: EntryId,
+    ) -> BoxFuture<'static, Result<payload::SaveEntryPayload, SyndApiError>>;
+
+    fn unsave_entry(
+        &self,
+        entry_id: EntryId,
+    ) -> BoxFuture<'static, Result<payload::SaveEntryPayload, SyndApiError>>;
+
     fn watch_feed_events(
         &self,
     ) -> BoxFuture<'static, Result<Box<dyn FeedEventWatch>, SyndApiError>>;
@@ -4,7 +4,7 @@ use futures_util::{FutureExt as _, future::BoxFuture};
 use synd_client::{
     ApiCredential, Client, FeedEventWatch as ClientFeedEventWatch, SyndApiError, payload,
 };
-use synd_feed::types::FeedUrl;
+use synd_feed::{entry::EntryId, types::FeedUrl};
 
 use super::{FeedApi, FeedEventWatch};
 
@@ -51,6 +51,14 @@ impl FeedApi for ClientFeedApi {
         async move { client.fetch_subscription(after, first).await }.boxed()
     }
 
+    fn discover_feeds(
+        &self,
+        url: FeedUrl,
+    ) -> BoxFuture<'static, Result<Vec<payload::FeedCandidate>, SyndApiError>> {
+        let client = self.client();
+        async move { client.discover_feeds(url).await }.boxed()
+    }
+
     fn subscribe_feed(
         &self,
         input: payload::SubscribeFeedInput,
@@ -64,13 +72,75 @@ impl FeedApi for ClientFeedApi {
         async move { client.unsubscribe_feed(url).await.map(|_| ()) }.boxed()
     }
 
+    fn request_crawl(
+        &self,
+        url: FeedUrl,
+    ) -> BoxFuture<'static, Result<payload::RequestCrawlPayload, SyndApiError>> {
+        let client = self.client();
+        async move { client.request_crawl(url).await }.boxed()
+    }
+
+    fn mark_entries_read(
+        &self,
+        entry_ids: Vec<EntryId>,
+    ) -> BoxFuture<'static, Result<payload::MarkEntriesPayload, SyndApiError>> {
+        let client = self.client();
+        async move { client.mark_entries_read(entry_ids).await }.boxed()
+    }
+
+    fn mark_entries_unread(
+        &self,
+        entry_ids: Vec<EntryId>,
+    ) -> BoxFuture<'static, Result<payload::MarkEntriesPayload, SyndApiError>> {
+        let client = self.client();
+        async move { client.mark_entries_unread(entry_ids).await }.boxed()
+    }
+
     fn fetch_timeline_entries(
         &self,
+        filter: payload::TimelineEntriesFilter,
         after: Option<String>,
         first: i64,
     ) -> BoxFuture<'static, Result<payload::TimelineEntryConnection, SyndApiError>> {
         let client = self.client();
-        async move { client.fetch_timeline_entries(after, first).await }.boxed()
+        async move { client.fetch_timeline_entries(filter, after, first).await }.boxed()
+    }
+
+    fn fetch_timeline_entry(
+        &self,
+        id: EntryId,
+    ) -> BoxFuture<'static, Result<Option<payload::TimelineEntry>, SyndApiError>> {
+        let client = self.client();
+        async move { client.fetch_timeline_entry(id).await }.boxed()
+    }
+
+    fn search_timeline(
+        &self,
+        query: String,
+        after: Option<String>,
+        first: i64,
+    ) -> BoxFuture<'static, Result<payload::TimelineSearchConnection, SyndApiError>> {
+        let client = self.client();
+        async move { client.search_timeline(query, after, first).await }.boxed()
+    }
+
+    fn fetch_saved_entries(
+        &self,
+        after: Option<String>,
+        first: i64,
+    ) -> BoxFuture<'static, Result<payload::SavedEntryConnection, SyndApiError>> {
+        let client = self.client();
+        async move { client.fetch_saved_entries(after, first).await }.boxed()
+    }
+
+    fn fetch_feed_entries(
+        &self,
+        url: FeedUrl,
+        after: Option<String>,
+        first: i64,
+    ) -> BoxFuture<'static, Result<payload::FeedEntryConnection, SyndApiError>> {
+        let client = self.client();
+        async move { client.fetch_feed_entries(url, after, first).await }.boxed()
     }
 
     fn fetch_timeline_changes(
@@ -82,6 +152,22 @@ impl FeedApi for ClientFeedApi {
         async move { client.fetch_timeline_changes(since, first).await }.boxed()
     }
 
+    fn save_entry(
+        &self,
+        entry_id: EntryId,
+    ) -> BoxFuture<'static, Result<payload::SaveEntryPayload, SyndApiError>> {
+        let client = self.client();
+        async move { client.save_entry(entry_id).await }.boxed()
+    }
+
+    fn unsave_entry(
+        &self,
+        entry_id: EntryId,
+    ) -> BoxFuture<'static, Result<payload::SaveEntryPayload, SyndApiError>> {
+        let client = self.client();
+        async move { client.unsave_entry(entry_id).await }.boxed()
+    }
+
     fn watch_feed_events(
         &self,
     ) -> BoxFuture<'static, Result<Box<dyn FeedEventWatch>, SyndApiError>> {
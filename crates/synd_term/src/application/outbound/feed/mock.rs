@@ -2,16 +2,24 @@ use std::{collections::VecDeque, future, sync::Mutex};
 
 use futures_util::{FutureExt as _, future::BoxFuture};
 use synd_client::{ApiCredential, SyndApiError, payload};
-use synd_feed::types::FeedUrl;
+use synd_feed::{entry::EntryId, types::FeedUrl};
 
 use super::{FeedApi, FeedEventWatch};
 
 pub enum MockFeedApiResponse {
     Subscription(Result<payload::SubscriptionPayload, SyndApiError>),
+    DiscoverFeeds(Result<Vec<payload::FeedCandidate>, SyndApiError>),
     SubscribeFeed(Result<payload::SubscribeFeedPayload, SyndApiError>),
     UnsubscribeFeed(Result<(), SyndApiError>),
+    RequestCrawl(Result<payload::RequestCrawlPayload, SyndApiError>),
+    MarkEntries(Result<payload::MarkEntriesPayload, SyndApiError>),
+    SaveEntry(Result<payload::SaveEntryPayload, SyndApiError>),
     TimelineEntries(Result<payload::TimelineEntryConnection, SyndApiError>),
     TimelineChanges(Result<payload::TimelineChangesPayload, SyndApiError>),
+    TimelineEntry(Result<Option<Box<payload::TimelineEntry>>, SyndApiError>),
+    TimelineSearch(Result<payload::TimelineSearchConnection, SyndApiError>),
+    SavedEntries(Result<payload::SavedEntryConnection, SyndApiError>),
+    FeedEntries(Result<payload::FeedEntryConnection, SyndApiError>),
     FeedEvents(Result<Vec<payload::FeedEvent>, SyndApiError>),
 }
 
@@ -66,6 +74,23 @@ impl MockFeedApi {
         }
     }
 
+    fn pop_mark_entries(&self) -> Result<payload::MarkEntriesPayload, SyndApiError> {
+        match self.pop_response(|response| matches!(response, MockFeedApiResponse::MarkEntries(_)))
+        {
+            Ok(MockFeedApiResponse::MarkEntries(result)) => result,
+            Ok(_) => Err(Self::mismatch()),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn pop_save_entry(&self) -> Result<payload::SaveEntryPayload, SyndApiError> {
+        match self.pop_response(|response| matches!(response, MockFeedApiResponse::SaveEntry(_))) {
+            Ok(MockFeedApiResponse::SaveEntry(result)) => result,
+            Ok(_) => Err(Self::mismatch()),
+            Err(err) => Err(err),
+        }
+    }
+
     fn mismatch() -> SyndApiError {
         SyndApiError::UnexpectedResponse {
             context: "mock feed API response does not match request",
@@ -93,6 +118,20 @@ impl FeedApi for MockFeedApi {
         future::ready(result).boxed()
     }
 
+    fn discover_feeds(
+        &self,
+        _url: FeedUrl,
+    ) -> BoxFuture<'static, Result<Vec<payload::FeedCandidate>, SyndApiError>> {
+        let result = match self
+            .pop_response(|response| matches!(response, MockFeedApiResponse::DiscoverFeeds(_)))
+        {
+            Ok(MockFeedApiResponse::DiscoverFeeds(result)) => result,
+            Ok(_) => Err(Self::mismatch()),
+            Err(err) => Err(err),
+        };
+        future::ready(result).boxed()
+    }
+
     fn subscribe_feed(
         &self,
         _input: payload::SubscribeFeedInput,
@@ -118,8 +157,37 @@ impl FeedApi for MockFeedApi {
         future::ready(result).boxed()
     }
 
+    fn request_crawl(
+        &self,
+        _url: FeedUrl,
+    ) -> BoxFuture<'static, Result<payload::RequestCrawlPayload, SyndApiError>> {
+        let result = match self
+            .pop_response(|response| matches!(response, MockFeedApiResponse::RequestCrawl(_)))
+        {
+            Ok(MockFeedApiResponse::RequestCrawl(result)) => result,
+            Ok(_) => Err(Self::mismatch()),
+            Err(err) => Err(err),
+        };
+        future::ready(result).boxed()
+    }
+
+    fn mark_entries_read(
+        &self,
+        _entry_ids: Vec<EntryId>,
+    ) -> BoxFuture<'static, Result<payload::MarkEntriesPayload, SyndApiError>> {
+        future::ready(self.pop_mark_entries()).boxed()
+    }
+
+    fn mark_entries_unread(
+        &self,
+        _entry_ids: Vec<EntryId>,
+    ) -> BoxFuture<'static, Result<payload::MarkEntriesPayload, SyndApiError>> {
+        future::ready(self.pop_mark_entries()).boxed()
+    }
+
     fn fetch_timeline_entries(
         &self,
+        _filter: payload::TimelineEntriesFilter,
         _after: Option<String>,
         _first: i64,
     ) -> BoxFuture<'static, Result<payload::TimelineEntryConnection, SyndApiError>> {
@@ -133,6 +201,69 @@ impl FeedApi for MockFeedApi {
         future::ready(result).boxed()
     }
 
+    fn fetch_timeline_entry(
+        &self,
+        _id: EntryId,
+    ) -> BoxFuture<'static, Result<Option<payload::TimelineEntry>, SyndApiError>> {
+        let result = match self
+            .pop_response(|response| matches!(response, MockFeedApiResponse::TimelineEntry(_)))
+        {
+            Ok(MockFeedApiResponse::TimelineEntry(result)) => {
+                result.map(|entry| entry.map(|entry| *entry))
+            }
+            Ok(_) => Err(Self::mismatch()),
+            Err(err) => Err(err),
+        };
+        future::ready(result).boxed()
+    }
+
+    fn search_timeline(
+        &self,
+        _query: String,
+        _after: Option<String>,
+        _first: i64,
+    ) -> BoxFuture<'static, Result<payload::TimelineSearchConnection, SyndApiError>> {
+        let result = match self
+            .pop_response(|response| matches!(response, MockFeedApiResponse::TimelineSearch(_)))
+        {
+            Ok(MockFeedApiResponse::TimelineSearch(result)) => result,
+            Ok(_) => Err(Self::mismatch()),
+            Err(err) => Err(err),
+        };
+        future::ready(result).boxed()
+    }
+
+    fn fetch_saved_entries(
+        &self,
+        _after: Option<String>,
+        _first: i64,
+    ) -> BoxFuture<'static, Result<payload::SavedEntryConnection, SyndApiError>> {
+        let result = match self
+            .pop_response(|response| matches!(response, MockFeedApiResponse::SavedEntries(_)))
+        {
+            Ok(MockFeedApiResponse::SavedEntries(result)) => result,
+            Ok(_) => Err(Self::mismatch()),
+            Err(err) => Err(err),
+        };
+        future::ready(result).boxed()
+    }
+
+    fn fetch_feed_entries(
+        &self,
+        _url: FeedUrl,
+        _after: Option<String>,
+        _first: i64,
+    ) -> BoxFuture<'static, Result<payload::FeedEntryConnection, SyndApiError>> {
+        let result = match self
+            .pop_response(|response| matches!(response, MockFeedApiResponse::FeedEntries(_)))
+        {
+            Ok(MockFeedApiResponse::FeedEntries(result)) => result,
+            Ok(_) => Err(Self::mismatch()),
+            Err(err) => Err(err),
+        };
+        future::ready(result).boxed()
+    }
+
     fn fetch_timeline_changes(
         &self,
         _since: i64,
@@ -148,6 +279,20 @@ impl FeedApi for MockFeedApi {
         future::ready(result).boxed()
     }
 
+    fn save_entry(
+        &self,
+        _entry_id: EntryId,
+    ) -> BoxFuture<'static, Result<payload::SaveEntryPayload, SyndApiError>> {
+        future::ready(self.pop_save_entry()).boxed()
+    }
+
+    fn unsave_entry(
+        &self,
+        _entry_id: EntryId,
+    ) -> BoxFuture<'static, Result<payload::SaveEntryPayload, SyndApiError>> {
+        future::ready(self.pop_save_entry()).boxed()
+    }
+
     fn watch_feed_events(
         &self,
     ) -> BoxFuture<'static, Result<Box<dyn FeedEventWatch>, SyndApiError>> {
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use synd_client::SyndApiError;
-use synd_feed::types::FeedUrl;
+use synd_feed::{entry::EntryId, types::FeedUrl};
 
 use crate::{
     auth::AuthenticationProvider,
@@ -24,11 +24,19 @@ impl RequestId {
 pub(crate) enum RequestKind {
     StartDeviceFlow { provider: AuthenticationProvider },
     PollDeviceFlowAccessToken { provider: AuthenticationProvider },
+    DiscoverFeeds { url: FeedUrl },
     SubscribeFeed { url: FeedUrl },
     UnsubscribeFeed { url: FeedUrl },
+    RequestCrawl { url: FeedUrl },
+    MarkEntryRead { entry_id: EntryId, read: bool },
+    SaveEntry { entry_id: EntryId, saved: bool },
     FetchSubscription,
     FetchTimelineWindow { limit: usize },
     CatchUpTimeline { since: i64 },
+    SearchTimeline { query: String },
+    FetchSavedEntries,
+    FetchFeedEntries { url: FeedUrl },
+    FetchEntryContent { entry_id: EntryId },
     FetchGhNotifications { page: u8 },
     FetchGhIssue { id: IssueId },
     FetchGhPullRequest { id: PullRequestId },
@@ -41,13 +49,31 @@ impl RequestKind {
         match self {
             Self::StartDeviceFlow { .. } => Cow::Borrowed("Request device authorization"),
             Self::PollDeviceFlowAccessToken { .. } => Cow::Borrowed("Poll device access token"),
+            Self::DiscoverFeeds { url } => Cow::Owned(format!("Discover feeds from {url}")),
             Self::SubscribeFeed { url } => Cow::Owned(format!("Subscribe feed {url}")),
             Self::UnsubscribeFeed { url } => Cow::Owned(format!("Unsubscribe feed {url}")),
+            Self::RequestCrawl { url } => Cow::Owned(format!("Request crawl {url}")),
+            Self::MarkEntryRead { entry_id, read } => {
+                let mark = if *read { "read" } else { "unread" };
+                Cow::Owned(format!("Mark entry {entry_id} {mark}"))
+            }
+            Self::SaveEntry { entry_id, saved } => {
+                let verb = if *saved { "Save" } else { "Unsave" };
+                Cow::Owned(format!("{verb} entry {entry_id}"))
+            }
             Self::FetchSubscription => Cow::Borrowed("Fetch subscriptions"),
             Self::FetchTimelineWindow { .. } => Cow::Borrowed("Fetch timeline"),
             Self::CatchUpTimeline { since } => {
                 Cow::Owned(format!("Catch up timeline from {since}"))
             }
+            Self::SearchTimeline { query } => {
+                Cow::Owned(format!("Search timeline for \"{query}\""))
+            }
+            Self::FetchSavedEntries => Cow::Borrowed("Fetch saved entries"),
+            Self::FetchFeedEntries { url } => Cow::Owned(format!("Fetch entries of {url}")),
+            Self::FetchEntryContent { entry_id } => {
+                Cow::Owned(format!("Fetch content of entry {entry_id}"))
+            }
             Self::FetchGhNotifications { page } => {
                 Cow::Owned(format!("Fetch GitHub notifications page {page}"))
             }
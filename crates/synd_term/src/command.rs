@@ -38,7 +38,9 @@ pub(crate) enum FeedsCommand {
     SelectFeedUnsubscriptionPopup,
     CancelFeedUnsubscriptionPopup,
     ReloadSubscription,
+    RefreshFeed,
     OpenFeed,
+    BrowseFeedEntries,
 
     RefreshTimeline,
     MoveEntry(Direction),
@@ -46,15 +48,29 @@ pub(crate) enum FeedsCommand {
     MoveEntryLast,
     OpenEntry,
     BrowseEntry,
+    ToggleEntryRead,
+    ToggleEntrySaved,
+    ToggleSavedEntries,
+
+    OpenEntryReader,
+    CloseEntryReader,
+    ScrollEntryReader(Direction),
+    ScrollEntryReaderPage(Direction),
+    ScrollEntryReaderFirst,
+    ScrollEntryReaderLast,
+    MoveEntryReaderLink(Direction),
+    OpenEntryReaderLink,
 }
 
 #[derive(Debug, Clone)]
 pub(crate) enum FilterCommand {
     MoveFilterRequirement(Direction),
+    ToggleFilterUnreadOnly,
     ActivateCategoryFiltering,
     ActivateSearchFiltering,
     PromptInsertChar(char),
     PromptDeleteBackward,
+    PromptSubmit,
     DeactivateFiltering,
     ToggleFilterCategory {
         target: FilterTarget,
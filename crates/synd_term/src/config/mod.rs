@@ -1,5 +1,7 @@
 mod categories;
+mod theme;
 pub use categories::{Categories, CategoryConfig, Icon, IconColor};
+pub use theme::{InvalidThemeColor, PaletteConfig, StyleOverride, ThemeColor, ThemeOverrides};
 
 pub mod client {
     use std::time::Duration;
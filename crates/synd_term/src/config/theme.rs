@@ -0,0 +1,214 @@
+use std::str::FromStr;
+
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Deserializer, de};
+
+/// A color written in the config file, either as a hex code(`"#f8f8f2"`) or
+/// as an ANSI color name or index(`"light-red"`, `"208"`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThemeColor(Color);
+
+impl ThemeColor {
+    pub fn color(self) -> Color {
+        self.0
+    }
+}
+
+impl FromStr for ThemeColor {
+    type Err = InvalidThemeColor;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Color::from_str(s.trim())
+            .map(Self)
+            .map_err(|_| InvalidThemeColor(s.to_owned()))
+    }
+}
+
+impl<'de> Deserialize<'de> for ThemeColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid color `{0}`: expected a hex code like \"#rrggbb\" or an ANSI color")]
+pub struct InvalidThemeColor(String);
+
+/// `[theme.palettes.<name>]`: colors of a user defined palette.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaletteConfig {
+    pub bg: ThemeColor,
+    pub fg: ThemeColor,
+    pub fg_inactive: ThemeColor,
+    pub fg_focus: ThemeColor,
+    pub error: ThemeColor,
+}
+
+/// Style of one themed element. Unset attributes keep the palette's style.
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StyleOverride {
+    fg: Option<ThemeColor>,
+    bg: Option<ThemeColor>,
+    bold: Option<bool>,
+    italic: Option<bool>,
+    underlined: Option<bool>,
+}
+
+impl StyleOverride {
+    pub(crate) fn apply(self, style: &mut Style) {
+        if let Some(fg) = self.fg {
+            *style = style.fg(fg.color());
+        }
+        if let Some(bg) = self.bg {
+            *style = style.bg(bg.color());
+        }
+        for (enabled, modifier) in [
+            (self.bold, Modifier::BOLD),
+            (self.italic, Modifier::ITALIC),
+            (self.underlined, Modifier::UNDERLINED),
+        ] {
+            *style = match enabled {
+                Some(true) => style.add_modifier(modifier),
+                Some(false) => style.remove_modifier(modifier),
+                None => *style,
+            };
+        }
+    }
+}
+
+/// `[theme.overrides]`: per-element styles applied on top of every palette.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThemeOverrides {
+    pub base: Option<StyleOverride>,
+    pub application_title: Option<StyleOverride>,
+    pub tabs: Option<StyleOverride>,
+    pub tabs_selected: Option<StyleOverride>,
+    pub default_icon_fg: Option<ThemeColor>,
+    #[serde(default)]
+    pub login: LoginOverrides,
+    #[serde(default)]
+    pub prompt: PromptOverrides,
+    #[serde(default)]
+    pub subscription: SubscriptionOverrides,
+    #[serde(default)]
+    pub entries: EntriesOverrides,
+    #[serde(default)]
+    pub reader: ReaderOverrides,
+    #[serde(default)]
+    pub error: ErrorOverrides,
+    #[serde(default)]
+    pub requirement: RequirementLabelOverrides,
+    #[serde(default)]
+    pub selection_popup: SelectionPopupOverrides,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LoginOverrides {
+    pub title: Option<StyleOverride>,
+    pub selected_auth_provider_item: Option<StyleOverride>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PromptOverrides {
+    pub key: Option<StyleOverride>,
+    pub key_desc: Option<StyleOverride>,
+    pub background: Option<StyleOverride>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionOverrides {
+    pub background: Option<StyleOverride>,
+    pub header: Option<StyleOverride>,
+    pub selected_feed: Option<StyleOverride>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EntriesOverrides {
+    pub header: Option<StyleOverride>,
+    pub selected_entry: Option<StyleOverride>,
+    pub summary: Option<StyleOverride>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReaderOverrides {
+    pub heading: Option<StyleOverride>,
+    pub code: Option<StyleOverride>,
+    pub link: Option<StyleOverride>,
+    pub selected_link: Option<StyleOverride>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ErrorOverrides {
+    pub message: Option<StyleOverride>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RequirementLabelOverrides {
+    pub must: Option<ThemeColor>,
+    pub should: Option<ThemeColor>,
+    pub may: Option<ThemeColor>,
+    pub fg: Option<ThemeColor>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SelectionPopupOverrides {
+    pub highlight: Option<StyleOverride>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn theme_color_accepts_hex_and_ansi() {
+        assert_eq!(
+            "#f8f8f2".parse::<ThemeColor>().unwrap().color(),
+            Color::Rgb(0xf8, 0xf8, 0xf2)
+        );
+        assert_eq!(
+            "light-red".parse::<ThemeColor>().unwrap().color(),
+            Color::LightRed
+        );
+        assert_eq!(
+            "208".parse::<ThemeColor>().unwrap().color(),
+            Color::Indexed(208)
+        );
+        assert!("#zzzzzz".parse::<ThemeColor>().is_err());
+    }
+
+    #[test]
+    fn style_override_keeps_unset_attributes() {
+        let mut style = Style::new()
+            .fg(Color::Red)
+            .bg(Color::Black)
+            .add_modifier(Modifier::BOLD);
+        let style_override: StyleOverride =
+            toml::from_str("fg = \"blue\"\nbold = false\nunderlined = true").unwrap();
+
+        style_override.apply(&mut style);
+
+        assert_eq!(
+            style,
+            Style::new()
+                .fg(Color::Blue)
+                .bg(Color::Black)
+                .remove_modifier(Modifier::BOLD)
+                .add_modifier(Modifier::UNDERLINED)
+        );
+    }
+}
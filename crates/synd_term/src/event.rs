@@ -2,7 +2,7 @@ use std::fmt::{self, Display};
 
 use synd_auth::device_flow::DeviceAuthorizationResponse;
 use synd_client::{SyndApiError, payload};
-use synd_feed::types::FeedUrl;
+use synd_feed::{entry::EntryId, types::FeedUrl};
 use url::Url;
 
 use crate::{
@@ -40,12 +40,31 @@ pub(crate) enum FeedsEvent {
 /// Successful feed fact produced by a registered request.
 #[derive(Debug)]
 pub(crate) enum FeedRequestEvent {
+    /// Feeds the URL entered to subscribe leads to, best first. `None` when
+    /// the URL could not be reached to discover them.
+    FeedsDiscovered {
+        input: payload::SubscribeFeedInput,
+        candidates: Option<Vec<payload::FeedCandidate>>,
+    },
     FeedSubscribed {
         url: FeedUrl,
     },
     FeedUnsubscribed {
         url: FeedUrl,
     },
+    CrawlRequested {
+        url: FeedUrl,
+        code: payload::ResponseCode,
+        disposition: payload::RequestCrawlDisposition,
+    },
+    EntryReadChanged {
+        entry_id: EntryId,
+        read: bool,
+    },
+    EntrySavedChanged {
+        entry_id: EntryId,
+        saved: bool,
+    },
     SubscriptionFetched {
         populate: Populate,
         subscription: payload::SubscriptionPayload,
@@ -58,6 +77,37 @@ pub(crate) enum FeedRequestEvent {
         changes: Vec<payload::TimelineChange>,
         seq: i64,
     },
+    /// The server no longer keeps the changes since the catch-up seq.
+    TimelineChangesExpired,
+    /// One page of timeline entries matching `query`. `next_cursor` is set
+    /// when more matches follow.
+    TimelineSearched {
+        query: String,
+        populate: Populate,
+        entries: Vec<payload::TimelineEntry>,
+        next_cursor: Option<String>,
+    },
+    /// One page of saved entries, latest save first. `next_cursor` is set
+    /// when more saved entries follow.
+    SavedEntriesFetched {
+        populate: Populate,
+        entries: Vec<payload::TimelineEntry>,
+        next_cursor: Option<String>,
+    },
+    /// One page of a feed's entry history, newest first. `next_cursor` is
+    /// set when older entries follow.
+    FeedEntriesFetched {
+        url: FeedUrl,
+        populate: Populate,
+        entries: Vec<payload::TimelineEntry>,
+        next_cursor: Option<String>,
+    },
+    /// Entry fetched with its content body. `None` when the entry is no
+    /// longer on the timeline.
+    EntryContentFetched {
+        entry_id: EntryId,
+        entry: Option<Box<payload::Entry>>,
+    },
 }
 
 /// Successful GitHub fact produced by a registered request.
@@ -194,11 +244,20 @@ impl AuthEvent {
 impl FeedRequestEvent {
     fn name(&self) -> &'static str {
         match self {
+            Self::FeedsDiscovered { .. } => "FeedsDiscovered",
             Self::FeedSubscribed { .. } => "FeedSubscribed",
             Self::FeedUnsubscribed { .. } => "FeedUnsubscribed",
+            Self::CrawlRequested { .. } => "CrawlRequested",
+            Self::EntryReadChanged { .. } => "EntryReadChanged",
+            Self::EntrySavedChanged { .. } => "EntrySavedChanged",
             Self::SubscriptionFetched { .. } => "SubscriptionFetched",
             Self::TimelineWindowChunkFetched { .. } => "TimelineWindowChunkFetched",
             Self::TimelineChangesFetched { .. } => "TimelineChangesFetched",
+            Self::TimelineChangesExpired => "TimelineChangesExpired",
+            Self::TimelineSearched { .. } => "TimelineSearched",
+            Self::SavedEntriesFetched { .. } => "SavedEntriesFetched",
+            Self::FeedEntriesFetched { .. } => "FeedEntriesFetched",
+            Self::EntryContentFetched { .. } => "EntryContentFetched",
         }
     }
 }
@@ -33,6 +33,7 @@ impl From<CommandId> for KeymapAction {
 pub(crate) enum PromptAction {
     InsertChar(char),
     DeleteBackward,
+    Submit,
 }
 
 impl From<PromptAction> for FilterCommand {
@@ -40,6 +41,7 @@ impl From<PromptAction> for FilterCommand {
         match action {
             PromptAction::InsertChar(ch) => Self::PromptInsertChar(ch),
             PromptAction::DeleteBackward => Self::PromptDeleteBackward,
+            PromptAction::Submit => Self::PromptSubmit,
         }
     }
 }
@@ -31,6 +31,21 @@ pub enum CommandId {
     RefreshTimeline,
     OpenEntry,
     BrowseEntry,
+    ToggleEntryRead,
+    ToggleEntrySaved,
+    ToggleSavedEntries,
+    ToggleFilterUnreadOnly,
+    OpenEntryReader,
+    CloseEntryReader,
+    ScrollEntryReaderUp,
+    ScrollEntryReaderDown,
+    ScrollEntryReaderPageUp,
+    ScrollEntryReaderPageDown,
+    ScrollEntryReaderFirst,
+    ScrollEntryReaderLast,
+    MoveEntryReaderLinkPrev,
+    MoveEntryReaderLinkNext,
+    OpenEntryReaderLink,
     MoveSubscribedFeedPrev,
     MoveSubscribedFeedNext,
     MoveSubscribedFeedFirst,
@@ -39,7 +54,9 @@ pub enum CommandId {
     PromptFeedEdition,
     PromptFeedUnsubscription,
     ReloadSubscription,
+    RefreshFeed,
     OpenFeed,
+    BrowseFeedEntries,
     MoveFeedUnsubscriptionPopupSelectionPrev,
     MoveFeedUnsubscriptionPopupSelectionNext,
     SelectFeedUnsubscriptionPopup,
@@ -91,6 +108,21 @@ impl CommandId {
             Self::RefreshTimeline => "timeline.refresh",
             Self::OpenEntry => "entries.open",
             Self::BrowseEntry => "entries.browse",
+            Self::ToggleEntryRead => "entries.toggle-read",
+            Self::ToggleEntrySaved => "entries.save",
+            Self::ToggleSavedEntries => "entries.saved",
+            Self::ToggleFilterUnreadOnly => "filter.unread-only.toggle",
+            Self::OpenEntryReader => "entries.read",
+            Self::CloseEntryReader => "reader.close",
+            Self::ScrollEntryReaderUp => "reader.up",
+            Self::ScrollEntryReaderDown => "reader.down",
+            Self::ScrollEntryReaderPageUp => "reader.page-up",
+            Self::ScrollEntryReaderPageDown => "reader.page-down",
+            Self::ScrollEntryReaderFirst => "reader.first",
+            Self::ScrollEntryReaderLast => "reader.last",
+            Self::MoveEntryReaderLinkPrev => "reader.link.prev",
+            Self::MoveEntryReaderLinkNext => "reader.link.next",
+            Self::OpenEntryReaderLink => "reader.link.open",
             Self::MoveSubscribedFeedPrev => "feeds.prev",
             Self::MoveSubscribedFeedNext => "feeds.next",
             Self::MoveSubscribedFeedFirst => "feeds.first",
@@ -99,7 +131,9 @@ impl CommandId {
             Self::PromptFeedEdition => "feeds.edit",
             Self::PromptFeedUnsubscription => "feeds.unsubscribe",
             Self::ReloadSubscription => "feeds.reload",
+            Self::RefreshFeed => "feeds.refresh",
             Self::OpenFeed => "feeds.open",
+            Self::BrowseFeedEntries => "feeds.entries",
             Self::MoveFeedUnsubscriptionPopupSelectionPrev => "feeds.unsubscribe-popup.prev",
             Self::MoveFeedUnsubscriptionPopupSelectionNext => "feeds.unsubscribe-popup.next",
             Self::SelectFeedUnsubscriptionPopup => "feeds.unsubscribe-popup.select",
@@ -153,6 +187,7 @@ impl CommandId {
 }
 
 impl From<CommandId> for Command {
+    #[expect(clippy::too_many_lines)]
     fn from(command: CommandId) -> Self {
         match command {
             CommandId::Nop => Command::Nop,
@@ -179,6 +214,37 @@ impl From<CommandId> for Command {
             CommandId::RefreshTimeline => Command::Feeds(FeedsCommand::RefreshTimeline),
             CommandId::OpenEntry => Command::Feeds(FeedsCommand::OpenEntry),
             CommandId::BrowseEntry => Command::Feeds(FeedsCommand::BrowseEntry),
+            CommandId::ToggleEntryRead => Command::Feeds(FeedsCommand::ToggleEntryRead),
+            CommandId::ToggleEntrySaved => Command::Feeds(FeedsCommand::ToggleEntrySaved),
+            CommandId::ToggleSavedEntries => Command::Feeds(FeedsCommand::ToggleSavedEntries),
+            CommandId::ToggleFilterUnreadOnly => {
+                Command::Filter(FilterCommand::ToggleFilterUnreadOnly)
+            }
+            CommandId::OpenEntryReader => Command::Feeds(FeedsCommand::OpenEntryReader),
+            CommandId::CloseEntryReader => Command::Feeds(FeedsCommand::CloseEntryReader),
+            CommandId::ScrollEntryReaderUp => {
+                Command::Feeds(FeedsCommand::ScrollEntryReader(Direction::Up))
+            }
+            CommandId::ScrollEntryReaderDown => {
+                Command::Feeds(FeedsCommand::ScrollEntryReader(Direction::Down))
+            }
+            CommandId::ScrollEntryReaderPageUp => {
+                Command::Feeds(FeedsCommand::ScrollEntryReaderPage(Direction::Up))
+            }
+            CommandId::ScrollEntryReaderPageDown => {
+                Command::Feeds(FeedsCommand::ScrollEntryReaderPage(Direction::Down))
+            }
+            CommandId::ScrollEntryReaderFirst => {
+                Command::Feeds(FeedsCommand::ScrollEntryReaderFirst)
+            }
+            CommandId::ScrollEntryReaderLast => Command::Feeds(FeedsCommand::ScrollEntryReaderLast),
+            CommandId::MoveEntryReaderLinkPrev => {
+                Command::Feeds(FeedsCommand::MoveEntryReaderLink(Direction::Up))
+            }
+            CommandId::MoveEntryReaderLinkNext => {
+                Command::Feeds(FeedsCommand::MoveEntryReaderLink(Direction::Down))
+            }
+            CommandId::OpenEntryReaderLink => Command::Feeds(FeedsCommand::OpenEntryReaderLink),
             CommandId::MoveSubscribedFeedPrev => {
                 Command::Feeds(FeedsCommand::MoveSubscribedFeed(Direction::Up))
             }
@@ -199,7 +265,9 @@ impl From<CommandId> for Command {
                 Command::Feeds(FeedsCommand::PromptFeedUnsubscription)
             }
             CommandId::ReloadSubscription => Command::Feeds(FeedsCommand::ReloadSubscription),
+            CommandId::RefreshFeed => Command::Feeds(FeedsCommand::RefreshFeed),
             CommandId::OpenFeed => Command::Feeds(FeedsCommand::OpenFeed),
+            CommandId::BrowseFeedEntries => Command::Feeds(FeedsCommand::BrowseFeedEntries),
             CommandId::MoveFeedUnsubscriptionPopupSelectionPrev => Command::Feeds(
                 FeedsCommand::MoveFeedUnsubscriptionPopupSelection(Direction::Left),
             ),
@@ -481,6 +549,81 @@ const COMMAND_SPECS: &[CommandSpec] = specs![
         typable: None,
         layers: [Entries],
     },
+    ToggleEntryRead {
+        aliases: [],
+        typable: None,
+        layers: [Entries],
+    },
+    ToggleEntrySaved {
+        aliases: [],
+        typable: Some(":save-entry"),
+        layers: [Entries],
+    },
+    ToggleSavedEntries {
+        aliases: [],
+        typable: Some(":saved"),
+        layers: [Entries],
+    },
+    ToggleFilterUnreadOnly {
+        aliases: [],
+        typable: None,
+        layers: [Entries],
+    },
+    OpenEntryReader {
+        aliases: [],
+        typable: Some(":read-entry"),
+        layers: [Entries],
+    },
+    CloseEntryReader {
+        aliases: [],
+        typable: None,
+        layers: [Reader],
+    },
+    ScrollEntryReaderUp {
+        aliases: [],
+        typable: None,
+        layers: [Reader],
+    },
+    ScrollEntryReaderDown {
+        aliases: [],
+        typable: None,
+        layers: [Reader],
+    },
+    ScrollEntryReaderPageUp {
+        aliases: [],
+        typable: None,
+        layers: [Reader],
+    },
+    ScrollEntryReaderPageDown {
+        aliases: [],
+        typable: None,
+        layers: [Reader],
+    },
+    ScrollEntryReaderFirst {
+        aliases: [],
+        typable: None,
+        layers: [Reader],
+    },
+    ScrollEntryReaderLast {
+        aliases: [],
+        typable: None,
+        layers: [Reader],
+    },
+    MoveEntryReaderLinkPrev {
+        aliases: [],
+        typable: None,
+        layers: [Reader],
+    },
+    MoveEntryReaderLinkNext {
+        aliases: [],
+        typable: None,
+        layers: [Reader],
+    },
+    OpenEntryReaderLink {
+        aliases: [],
+        typable: None,
+        layers: [Reader],
+    },
     MoveSubscribedFeedPrev {
         aliases: ["move_up_subscribed_feed"],
         typable: None,
@@ -521,11 +664,21 @@ const COMMAND_SPECS: &[CommandSpec] = specs![
         typable: Some(":reload-subscription"),
         layers: [Feeds],
     },
+    RefreshFeed {
+        aliases: [],
+        typable: Some(":refresh-feed"),
+        layers: [Feeds],
+    },
     OpenFeed {
         aliases: ["open_feed"],
         typable: None,
         layers: [Feeds],
     },
+    BrowseFeedEntries {
+        aliases: [],
+        typable: Some(":feed-entries"),
+        layers: [Feeds],
+    },
     MoveFeedUnsubscriptionPopupSelectionPrev {
         aliases: ["move_feed_unsubscription_popup_selection_left"],
         typable: None,
@@ -104,6 +104,30 @@ pub(super) fn default_keymap_config() -> KeymapConfig {
         CommandId::BrowseEntry,
         "Browse entry with text browser"
     );
+    bind!(
+        Layer::Entries,
+        ["m"],
+        CommandId::ToggleEntryRead,
+        "Toggle entry read"
+    );
+    bind!(
+        Layer::Entries,
+        ["s"],
+        CommandId::ToggleEntrySaved,
+        "Toggle entry saved"
+    );
+    bind!(
+        Layer::Entries,
+        ["S-s"],
+        CommandId::ToggleSavedEntries,
+        "Toggle saved entries"
+    );
+    bind!(
+        Layer::Entries,
+        ["u"],
+        CommandId::ToggleFilterUnreadOnly,
+        "Toggle unread-only filter"
+    );
     bind!(
         Layer::Entries,
         ["g", "g"],
@@ -116,6 +140,97 @@ pub(super) fn default_keymap_config() -> KeymapConfig {
         CommandId::MoveEntryLast,
         "Go to last entry"
     );
+    bind!(
+        Layer::Entries,
+        ["v"],
+        CommandId::OpenEntryReader,
+        "Read entry in the reader"
+    );
+
+    bind!(
+        Layer::Reader,
+        ["q"],
+        CommandId::CloseEntryReader,
+        "Close reader"
+    );
+    bind!(
+        Layer::Reader,
+        ["esc"],
+        CommandId::CloseEntryReader,
+        "Close reader"
+    );
+    bind!(
+        Layer::Reader,
+        ["k"],
+        CommandId::ScrollEntryReaderUp,
+        "Scroll up"
+    );
+    bind!(
+        Layer::Reader,
+        ["up"],
+        CommandId::ScrollEntryReaderUp,
+        "Scroll up"
+    );
+    bind!(
+        Layer::Reader,
+        ["j"],
+        CommandId::ScrollEntryReaderDown,
+        "Scroll down"
+    );
+    bind!(
+        Layer::Reader,
+        ["down"],
+        CommandId::ScrollEntryReaderDown,
+        "Scroll down"
+    );
+    bind!(
+        Layer::Reader,
+        ["C-u"],
+        CommandId::ScrollEntryReaderPageUp,
+        "Scroll up a page"
+    );
+    bind!(
+        Layer::Reader,
+        ["C-d"],
+        CommandId::ScrollEntryReaderPageDown,
+        "Scroll down a page"
+    );
+    bind!(
+        Layer::Reader,
+        ["space"],
+        CommandId::ScrollEntryReaderPageDown,
+        "Scroll down a page"
+    );
+    bind!(
+        Layer::Reader,
+        ["g", "g"],
+        CommandId::ScrollEntryReaderFirst,
+        "Go to top"
+    );
+    bind!(
+        Layer::Reader,
+        ["g", "e"],
+        CommandId::ScrollEntryReaderLast,
+        "Go to bottom"
+    );
+    bind!(
+        Layer::Reader,
+        ["["],
+        CommandId::MoveEntryReaderLinkPrev,
+        "Previous link"
+    );
+    bind!(
+        Layer::Reader,
+        ["]"],
+        CommandId::MoveEntryReaderLinkNext,
+        "Next link"
+    );
+    bind!(
+        Layer::Reader,
+        ["enter"],
+        CommandId::OpenEntryReaderLink,
+        "Open selected link with web browser"
+    );
 
     bind!(
         Layer::Feeds,
@@ -165,12 +280,24 @@ pub(super) fn default_keymap_config() -> KeymapConfig {
         CommandId::ReloadSubscription,
         "Reload subscriptions"
     );
+    bind!(
+        Layer::Feeds,
+        ["f"],
+        CommandId::RefreshFeed,
+        "Request crawl of selected feed"
+    );
     bind!(
         Layer::Feeds,
         ["enter"],
         CommandId::OpenFeed,
         "Open selected feed"
     );
+    bind!(
+        Layer::Feeds,
+        ["v"],
+        CommandId::BrowseFeedEntries,
+        "View entries of selected feed"
+    );
     bind!(
         Layer::Feeds,
         ["g", "g"],
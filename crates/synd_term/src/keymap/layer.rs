@@ -10,6 +10,7 @@ pub enum Layer {
     Login,
     Tabs,
     Entries,
+    Reader,
     Feeds,
     Filter,
     CategoryFilter,
@@ -27,6 +28,7 @@ impl Layer {
             Self::Login => "login",
             Self::Tabs => "tabs",
             Self::Entries => "entries",
+            Self::Reader => "reader",
             Self::Feeds => "feeds",
             Self::Filter => "filter",
             Self::CategoryFilter => "category-filter",
@@ -54,6 +56,7 @@ impl FromStr for Layer {
             "login" => Ok(Self::Login),
             "tabs" => Ok(Self::Tabs),
             "entries" => Ok(Self::Entries),
+            "reader" => Ok(Self::Reader),
             "feeds" => Ok(Self::Feeds),
             "filter" => Ok(Self::Filter),
             "category-filter" => Ok(Self::CategoryFilter),
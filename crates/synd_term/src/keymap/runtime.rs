@@ -44,6 +44,13 @@ impl LayerKeymap {
                 Some("Delete previous character"),
             )
             .expect("valid search prompt keymap");
+        builder
+            .bind(
+                ["enter"],
+                KeymapAction::Prompt(PromptAction::Submit),
+                Some("Search all entries on the server"),
+            )
+            .expect("valid search prompt keymap");
         builder.build().expect("valid search prompt keymap")
     }
 }
@@ -148,6 +148,46 @@ fn app_layer_can_override_global_layer() {
     );
 }
 
+#[test]
+fn reader_layer_overrides_global_quit_and_link_keys() {
+    let mut keymap = Keymap::default_keymaps();
+    let layers = LayerStack::from([Layer::App, Layer::Global, Layer::Tabs, Layer::Reader]);
+
+    assert_matches!(
+        result_to_command(&keymap.resolve(&layers, key("q"))),
+        Some(Command::Feeds(FeedsCommand::CloseEntryReader))
+    );
+    assert_matches!(
+        result_to_command(&keymap.resolve(&layers, key("]"))),
+        Some(Command::Feeds(FeedsCommand::MoveEntryReaderLink(
+            Direction::Down
+        )))
+    );
+    assert_matches!(
+        result_to_command(&keymap.resolve(&layers, key("enter"))),
+        Some(Command::Feeds(FeedsCommand::OpenEntryReaderLink))
+    );
+}
+
+#[test]
+fn entries_layer_binds_save_and_saved_view_to_s() {
+    let mut keymap = Keymap::default_keymaps();
+    let layers = LayerStack::from([Layer::App, Layer::Global, Layer::Tabs, Layer::Entries]);
+
+    assert_matches!(
+        result_to_command(&keymap.resolve(&layers, key("s"))),
+        Some(Command::Feeds(FeedsCommand::ToggleEntrySaved))
+    );
+    assert_matches!(
+        result_to_command(&keymap.resolve(&layers, key("S-s"))),
+        Some(Command::Feeds(FeedsCommand::ToggleSavedEntries))
+    );
+    assert_eq!(
+        CommandRegistry.command_id("entries.saved").unwrap(),
+        CommandId::ToggleSavedEntries
+    );
+}
+
 #[test]
 fn search_prompt_layer_turns_text_input_into_actions() {
     let mut keymap = Keymap::default_keymaps();
@@ -161,6 +201,13 @@ fn search_prompt_layer_turns_text_input_into_actions() {
         Some(&KeymapAction::Prompt(PromptAction::InsertChar('a')))
     );
 
+    let result = keymap.resolve(&layers, key("enter"));
+
+    assert_eq!(
+        matched_action(&result),
+        Some(&KeymapAction::Prompt(PromptAction::Submit))
+    );
+
     let result = keymap.resolve(&layers, key("esc"));
 
     assert_eq!(
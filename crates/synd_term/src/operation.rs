@@ -1,6 +1,6 @@
 use synd_auth::device_flow::DeviceAuthorizationResponse;
 use synd_client::payload;
-use synd_feed::types::FeedUrl;
+use synd_feed::{entry::EntryId, types::FeedUrl};
 use url::Url;
 
 use crate::{
@@ -92,17 +92,34 @@ pub(crate) enum Operation {
         device_authorization: Box<DeviceAuthorizationResponse>,
     },
 
-    OpenFeedSubscriptionEditor,
+    /// Opens the subscription editor, prefilled with `prompt` when given.
+    OpenFeedSubscriptionEditor {
+        prompt: Option<String>,
+    },
     OpenFeedEditionEditor {
         prompt: String,
     },
 
+    DiscoverFeeds {
+        input: payload::SubscribeFeedInput,
+    },
     SubscribeFeed {
         input: payload::SubscribeFeedInput,
     },
     UnsubscribeFeed {
         url: FeedUrl,
     },
+    RequestCrawl {
+        url: FeedUrl,
+    },
+    MarkEntryRead {
+        entry_id: EntryId,
+        read: bool,
+    },
+    SaveEntry {
+        entry_id: EntryId,
+        saved: bool,
+    },
     FetchSubscription {
         populate: Populate,
         after: Option<String>,
@@ -110,10 +127,28 @@ pub(crate) enum Operation {
     },
     FetchTimelineWindow {
         limit: usize,
+        filter: payload::TimelineEntriesFilter,
     },
     CatchUpTimeline {
         since: i64,
     },
+    SearchTimeline {
+        query: String,
+        populate: Populate,
+        after: Option<String>,
+    },
+    FetchSavedEntries {
+        populate: Populate,
+        after: Option<String>,
+    },
+    FetchFeedEntries {
+        url: FeedUrl,
+        populate: Populate,
+        after: Option<String>,
+    },
+    FetchEntryContent {
+        entry_id: EntryId,
+    },
     WatchFeedEvents,
 
     FetchGhNotifications {
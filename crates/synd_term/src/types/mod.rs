@@ -44,6 +44,20 @@ impl CrawlPolicyExt for payload::CrawlPolicy {
     }
 }
 
+impl CrawlPolicyExt for payload::CrawlPolicyInput {
+    fn prompt_value(&self) -> Option<String> {
+        Some(match self.polling {
+            payload::PollingPolicyInput::Manual => "manual".to_owned(),
+            payload::PollingPolicyInput::Interval { seconds } => {
+                format!("interval:{}s", seconds.get())
+            }
+            payload::PollingPolicyInput::Adaptive { min, max } => {
+                format!("adaptive:{}s-{}s", min.get(), max.get())
+            }
+        })
+    }
+}
+
 trait PollingPolicyExt {
     fn prompt_value(&self) -> Option<String>;
 }
@@ -55,11 +69,55 @@ impl PollingPolicyExt for payload::PollingPolicy {
             payload::PollingPolicy::Interval { seconds } => {
                 Some(format!("interval:{}s", seconds.get()))
             }
+            payload::PollingPolicy::Adaptive { min, max } => {
+                Some(format!("adaptive:{}s-{}s", min.get(), max.get()))
+            }
             payload::PollingPolicy::Other { .. } => None,
         }
     }
 }
 
+/// Parses the `manual`, `interval:<duration>` and `adaptive:<min>-<max>`
+/// polling policy notation shared by the subscription prompt and the CLI.
+pub fn parse_polling_policy(
+    value: &str,
+) -> Result<payload::PollingPolicyInput, InvalidPollingPolicy> {
+    if value.eq_ignore_ascii_case("manual") {
+        return Ok(payload::PollingPolicyInput::Manual);
+    }
+    let (kind, durations) = value.split_once(':').ok_or(InvalidPollingPolicy)?;
+    if kind.eq_ignore_ascii_case("interval") {
+        return Ok(payload::PollingPolicyInput::Interval {
+            seconds: polling_seconds(durations)?,
+        });
+    }
+    if kind.eq_ignore_ascii_case("adaptive") {
+        let (min, max) = durations.split_once('-').ok_or(InvalidPollingPolicy)?;
+        let (min, max) = (polling_seconds(min)?, polling_seconds(max)?);
+        if min.get() > max.get() {
+            return Err(InvalidPollingPolicy);
+        }
+        return Ok(payload::PollingPolicyInput::Adaptive { min, max });
+    }
+    Err(InvalidPollingPolicy)
+}
+
+fn polling_seconds(
+    duration: &str,
+) -> Result<payload::PollingIntervalSeconds, InvalidPollingPolicy> {
+    let duration = synd_support::time::humantime::parse_duration(duration)
+        .map_err(|_| InvalidPollingPolicy)?;
+    if duration.subsec_nanos() != 0 {
+        return Err(InvalidPollingPolicy);
+    }
+    let seconds = i64::try_from(duration.as_secs()).unwrap_or(i64::MAX);
+    payload::PollingIntervalSeconds::try_from(seconds).map_err(|_| InvalidPollingPolicy)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("invalid polling policy: use 'manual', 'interval:<duration>' or 'adaptive:<min>-<max>'")]
+pub struct InvalidPollingPolicy;
+
 #[derive(Debug, Clone)]
 #[cfg_attr(test, derive(fake::Dummy))]
 pub struct Feed {
@@ -74,6 +132,7 @@ pub struct Feed {
     pub entries: Vec<EntryMeta>,
     pub authors: Vec<String>,
     pub crawl_policy: payload::CrawlPolicy,
+    pub fetch_full_content: bool,
     requirement: Option<Requirement>,
     category: Option<Category<'static>>,
 }
@@ -116,6 +175,7 @@ impl From<payload::SubscribedFeed> for Feed {
             requirement,
             category,
             crawl_policy,
+            fetch_full_content,
             feed: details,
         } = f;
         Self {
@@ -147,6 +207,7 @@ impl From<payload::SubscribedFeed> for Feed {
                 .map(|details| details.authors.nodes.clone())
                 .unwrap_or_default(),
             crawl_policy,
+            fetch_full_content,
             requirement,
             category,
         }
@@ -161,6 +222,8 @@ pub struct ExportedFeed {
     pub category: Option<Category<'static>>,
     #[serde(default)]
     pub crawl_policy: Option<ExportedCrawlPolicy>,
+    #[serde(default)]
+    pub fetch_full_content: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -174,6 +237,10 @@ pub struct ExportedCrawlPolicy {
 pub struct ExportedPollingPolicy {
     pub kind: ExportedPollingPolicyKind,
     pub interval_seconds: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_interval_seconds: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_interval_seconds: Option<i64>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -181,14 +248,23 @@ pub struct ExportedPollingPolicy {
 pub enum ExportedPollingPolicyKind {
     Manual,
     Interval,
+    Adaptive,
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum ExportedPollingPolicyError {
-    #[error("intervalSeconds must be omitted for a MANUAL polling policy")]
+    #[error("intervalSeconds is only valid for an INTERVAL polling policy")]
     UnexpectedInterval,
     #[error("intervalSeconds is required for an INTERVAL polling policy")]
     MissingInterval,
+    #[error(
+        "minIntervalSeconds and maxIntervalSeconds are only valid for an ADAPTIVE polling policy"
+    )]
+    UnexpectedBounds,
+    #[error(
+        "minIntervalSeconds and maxIntervalSeconds are required for an ADAPTIVE polling policy"
+    )]
+    MissingBounds,
     #[error(transparent)]
     InvalidInterval(#[from] payload::InvalidPollingInterval),
 }
@@ -200,15 +276,30 @@ impl ExportedCrawlPolicy {
                 polling: ExportedPollingPolicy {
                     kind: ExportedPollingPolicyKind::Manual,
                     interval_seconds: None,
+                    min_interval_seconds: None,
+                    max_interval_seconds: None,
                 },
             }),
             payload::PollingPolicy::Interval { seconds } => Some(Self {
                 polling: ExportedPollingPolicy {
                     kind: ExportedPollingPolicyKind::Interval,
                     interval_seconds: Some(seconds.get()),
+                    min_interval_seconds: None,
+                    max_interval_seconds: None,
                 },
             }),
-            payload::PollingPolicy::Other { .. } => None,
+            payload::PollingPolicy::Adaptive { min, max } => Some(Self {
+                polling: ExportedPollingPolicy {
+                    kind: ExportedPollingPolicyKind::Adaptive,
+                    interval_seconds: None,
+                    min_interval_seconds: Some(min.get()),
+                    max_interval_seconds: Some(max.get()),
+                },
+            }),
+            payload::PollingPolicy::Other { .. } => {
+                warn!(polling = ?value.polling, "Unknown polling policy is not exported");
+                None
+            }
         }
     }
 }
@@ -217,21 +308,36 @@ impl TryFrom<ExportedCrawlPolicy> for payload::CrawlPolicyInput {
     type Error = ExportedPollingPolicyError;
 
     fn try_from(value: ExportedCrawlPolicy) -> Result<Self, Self::Error> {
-        let polling = match value.polling.kind {
-            ExportedPollingPolicyKind::Manual if value.polling.interval_seconds.is_none() => {
-                payload::PollingPolicyInput::Manual
-            }
-            ExportedPollingPolicyKind::Manual => {
-                return Err(ExportedPollingPolicyError::UnexpectedInterval);
-            }
+        let ExportedPollingPolicy {
+            kind,
+            interval_seconds,
+            min_interval_seconds,
+            max_interval_seconds,
+        } = value.polling;
+        let bounds = (min_interval_seconds, max_interval_seconds);
+        if kind != ExportedPollingPolicyKind::Adaptive && bounds != (None, None) {
+            return Err(ExportedPollingPolicyError::UnexpectedBounds);
+        }
+        if kind != ExportedPollingPolicyKind::Interval && interval_seconds.is_some() {
+            return Err(ExportedPollingPolicyError::UnexpectedInterval);
+        }
+        let polling = match kind {
+            ExportedPollingPolicyKind::Manual => payload::PollingPolicyInput::Manual,
             ExportedPollingPolicyKind::Interval => {
-                let seconds = value
-                    .polling
-                    .interval_seconds
+                let seconds = interval_seconds
                     .ok_or(ExportedPollingPolicyError::MissingInterval)?
                     .try_into()?;
                 payload::PollingPolicyInput::Interval { seconds }
             }
+            ExportedPollingPolicyKind::Adaptive => {
+                let (Some(min), Some(max)) = bounds else {
+                    return Err(ExportedPollingPolicyError::MissingBounds);
+                };
+                payload::PollingPolicyInput::Adaptive {
+                    min: min.try_into()?,
+                    max: max.try_into()?,
+                }
+            }
         };
         Ok(Self { polling })
     }
@@ -246,6 +352,7 @@ impl From<payload::SubscribedFeed> for ExportedFeed {
             requirement: v.requirement,
             category: v.category,
             crawl_policy,
+            fetch_full_content: v.fetch_full_content,
         }
     }
 }
@@ -259,6 +366,7 @@ impl TryFrom<ExportedFeed> for synd_client::payload::SubscribeFeedInput {
             requirement: feed.requirement,
             category: feed.category,
             crawl_policy: feed.crawl_policy.map(TryInto::try_into).transpose()?,
+            fetch_full_content: feed.fetch_full_content,
         })
     }
 }
@@ -15,18 +15,22 @@ macro_rules! icon {
     (filter)            => { "󰈶" };
     (gh)                => { "󰊤" };
     (google)            => { "󰊭" };
+    (image)             => { "󰋩" };
     (issueopen)         => { "" };
     (issuereopened)     => { "" };
     (issuenotplanned)   => { "" };
     (issueclosed)       => { "" };
     (label)             => { "󱍵" };
+    (media)             => { "󰝚" };
     (requirement)       => { "" };
     (open)              => { "󰏌" };
     (pullrequest)       => { "" };
     (pullrequestmerged) => { "" };
     (pullrequestclosed) => { "" };
     (pullrequestdraft)  => { "" };
+    (reader)            => { "󰗚" };
     (repository)        => { "" };
+    (saved)             => { "󰃀" };
     (search)            => { "" };
     (summary)           => { "󱙓" };
     (tag)               => { "󰓹" };
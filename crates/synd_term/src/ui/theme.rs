@@ -1,8 +1,10 @@
 use ratatui::style::{Color, Modifier, Style};
 
+use crate::config::{PaletteConfig, StyleOverride, ThemeColor, ThemeOverrides};
+
 #[derive(Clone)]
 pub struct Theme {
-    pub name: &'static str,
+    pub name: String,
     pub base: Style,
     pub application_title: Style,
     pub login: LoginTheme,
@@ -11,6 +13,7 @@ pub struct Theme {
     pub prompt: PromptTheme,
     pub subscription: SubscriptionTheme,
     pub entries: EntriesTheme,
+    pub reader: ReaderTheme,
     pub error: ErrorTheme,
     pub default_icon_fg: Color,
     pub requirement: RequirementLabelTheme,
@@ -49,6 +52,14 @@ pub struct EntriesTheme {
     pub summary: Style,
 }
 
+#[derive(Clone)]
+pub struct ReaderTheme {
+    pub heading: Style,
+    pub code: Style,
+    pub link: Style,
+    pub selected_link: Style,
+}
+
 #[derive(Clone)]
 pub struct RequirementLabelTheme {
     pub must: Color,
@@ -64,7 +75,7 @@ pub struct SelectionPopup {
 
 #[derive(Clone, Debug)]
 pub struct Palette {
-    name: &'static str,
+    name: String,
     bg: Color,
     fg: Color,
     fg_inactive: Color,
@@ -73,13 +84,40 @@ pub struct Palette {
 }
 
 impl Palette {
-    pub fn name(&self) -> &'static str {
-        self.name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Names of the built-in palettes in rotation order.
+    pub const BUILTIN_NAMES: [&'static str; 5] =
+        ["ferra", "solarized_dark", "helix", "dracula", "eldritch"];
+
+    /// Look up a built-in palette. `solarized-dark` is accepted as well.
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "ferra" => Some(Self::ferra()),
+            "solarized_dark" | "solarized-dark" => Some(Self::solarized_dark()),
+            "helix" => Some(Self::helix()),
+            "dracula" => Some(Self::dracula()),
+            "eldritch" => Some(Self::eldritch()),
+            _ => None,
+        }
+    }
+
+    pub fn from_config(name: impl Into<String>, config: &PaletteConfig) -> Self {
+        Self {
+            name: name.into(),
+            bg: config.bg.color(),
+            fg: config.fg.color(),
+            fg_inactive: config.fg_inactive.color(),
+            fg_focus: config.fg_focus.color(),
+            error: config.error.color(),
+        }
     }
 
     pub fn dracula() -> Self {
         Self {
-            name: "dracula",
+            name: "dracula".into(),
             bg: Color::Rgb(0x28, 0x2a, 0x36),
             fg: Color::Rgb(0xf8, 0xf8, 0xf2),
             fg_inactive: Color::Rgb(0x62, 0x72, 0xa4),
@@ -90,7 +128,7 @@ impl Palette {
 
     pub fn eldritch() -> Self {
         Self {
-            name: "eldritch",
+            name: "eldritch".into(),
             bg: Color::Rgb(0x21, 0x23, 0x37),
             fg: Color::Rgb(0xeb, 0xfa, 0xfa),
             fg_inactive: Color::Rgb(0x70, 0x81, 0xd0),
@@ -101,7 +139,7 @@ impl Palette {
 
     pub fn helix() -> Self {
         Self {
-            name: "helix",
+            name: "helix".into(),
             bg: Color::Rgb(0x3b, 0x22, 0x4c),
             fg: Color::Rgb(0xa4, 0xa0, 0xe8),
             fg_inactive: Color::Rgb(0x69, 0x7c, 0x81),
@@ -112,7 +150,7 @@ impl Palette {
 
     pub fn ferra() -> Self {
         Self {
-            name: "ferra",
+            name: "ferra".into(),
             bg: Color::Rgb(0x2b, 0x29, 0x2d),
             fg: Color::Rgb(0xfe, 0xcd, 0xb2),
             fg_inactive: Color::Rgb(0x6F, 0x5D, 0x63),
@@ -123,7 +161,7 @@ impl Palette {
 
     pub fn solarized_dark() -> Self {
         Self {
-            name: "solarized_dark",
+            name: "solarized_dark".into(),
             bg: Color::Rgb(0x00, 0x2b, 0x36),
             fg: Color::Rgb(0x93, 0xa1, 0xa1),
             fg_inactive: Color::Rgb(0x58, 0x6e, 0x75),
@@ -170,6 +208,12 @@ impl Theme {
                 selected_entry: Style::new().fg(fg_focus).add_modifier(Modifier::BOLD),
                 summary: Style::new().fg(fg),
             },
+            reader: ReaderTheme {
+                heading: Style::new().fg(fg_focus).add_modifier(Modifier::BOLD),
+                code: Style::new().fg(fg_inactive),
+                link: Style::new().fg(fg_focus).add_modifier(Modifier::UNDERLINED),
+                selected_link: Style::new().fg(bg).bg(fg_focus),
+            },
             error: ErrorTheme {
                 message: Style::new().fg(error).bg(bg),
             },
@@ -187,6 +231,138 @@ impl Theme {
     }
 }
 
+impl Theme {
+    /// Apply the user's per-element styles on top of the palette's.
+    #[must_use]
+    pub fn with_overrides(mut self, overrides: &ThemeOverrides) -> Self {
+        fn apply(style: &mut Style, style_override: Option<StyleOverride>) {
+            if let Some(style_override) = style_override {
+                style_override.apply(style);
+            }
+        }
+        fn replace(color: &mut Color, theme_color: Option<ThemeColor>) {
+            if let Some(theme_color) = theme_color {
+                *color = theme_color.color();
+            }
+        }
+
+        apply(&mut self.base, overrides.base);
+        apply(&mut self.application_title, overrides.application_title);
+        apply(&mut self.tabs, overrides.tabs);
+        apply(&mut self.tabs_selected, overrides.tabs_selected);
+        replace(&mut self.default_icon_fg, overrides.default_icon_fg);
+
+        let login = &overrides.login;
+        apply(&mut self.login.title, login.title);
+        apply(
+            &mut self.login.selected_auth_provider_item,
+            login.selected_auth_provider_item,
+        );
+
+        let prompt = &overrides.prompt;
+        apply(&mut self.prompt.key, prompt.key);
+        apply(&mut self.prompt.key_desc, prompt.key_desc);
+        apply(&mut self.prompt.background, prompt.background);
+
+        let subscription = &overrides.subscription;
+        apply(&mut self.subscription.background, subscription.background);
+        apply(&mut self.subscription.header, subscription.header);
+        apply(
+            &mut self.subscription.selected_feed,
+            subscription.selected_feed,
+        );
+
+        let entries = &overrides.entries;
+        apply(&mut self.entries.header, entries.header);
+        apply(&mut self.entries.selected_entry, entries.selected_entry);
+        apply(&mut self.entries.summary, entries.summary);
+
+        let reader = &overrides.reader;
+        apply(&mut self.reader.heading, reader.heading);
+        apply(&mut self.reader.code, reader.code);
+        apply(&mut self.reader.link, reader.link);
+        apply(&mut self.reader.selected_link, reader.selected_link);
+
+        apply(&mut self.error.message, overrides.error.message);
+
+        let requirement = &overrides.requirement;
+        replace(&mut self.requirement.must, requirement.must);
+        replace(&mut self.requirement.should, requirement.should);
+        replace(&mut self.requirement.may, requirement.may);
+        replace(&mut self.requirement.fg, requirement.fg);
+
+        apply(
+            &mut self.selection_popup.highlight,
+            overrides.selection_popup.highlight,
+        );
+
+        self
+    }
+}
+
+/// Palettes available to the application along with the user's overrides.
+#[derive(Clone, Debug)]
+pub struct Themes {
+    palettes: Vec<Palette>,
+    overrides: ThemeOverrides,
+}
+
+impl Themes {
+    /// Built-in palettes come first in their rotation order, followed by the
+    /// custom palettes sorted by name. A custom palette named after a built-in
+    /// one replaces it.
+    pub fn new(custom: impl IntoIterator<Item = Palette>, overrides: ThemeOverrides) -> Self {
+        let mut palettes: Vec<Palette> = Palette::BUILTIN_NAMES
+            .into_iter()
+            .filter_map(Palette::builtin)
+            .collect();
+        let mut custom = custom.into_iter().collect::<Vec<_>>();
+        custom.sort_by(|a, b| a.name.cmp(&b.name));
+
+        for palette in custom {
+            let name = Palette::builtin(&palette.name).map_or(palette.name.clone(), |p| p.name);
+            match palettes.iter_mut().find(|p| p.name == name) {
+                Some(builtin) => *builtin = Palette { name, ..palette },
+                None => palettes.push(palette),
+            }
+        }
+
+        Self {
+            palettes,
+            overrides,
+        }
+    }
+
+    pub fn palette(&self, name: &str) -> Option<&Palette> {
+        let name = Palette::builtin(name).map_or_else(|| name.to_owned(), |p| p.name);
+        self.palettes.iter().find(|p| p.name == name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.palettes.iter().map(Palette::name)
+    }
+
+    pub fn theme(&self, palette: Palette) -> Theme {
+        Theme::with_palette(palette).with_overrides(&self.overrides)
+    }
+
+    /// Theme of the palette following `current` in rotation order.
+    pub fn next(&self, current: &str) -> Theme {
+        let next = self
+            .palettes
+            .iter()
+            .position(|p| p.name == current)
+            .map_or(0, |i| (i + 1) % self.palettes.len());
+        self.theme(self.palettes[next].clone())
+    }
+}
+
+impl Default for Themes {
+    fn default() -> Self {
+        Self::new([], ThemeOverrides::default())
+    }
+}
+
 impl Default for Theme {
     fn default() -> Self {
         Theme::with_palette(Palette::ferra())
@@ -202,3 +378,91 @@ impl Theme {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom_palette(name: &str) -> Palette {
+        let config: PaletteConfig = toml::from_str(
+            r##"
+bg = "#000000"
+fg = "#ffffff"
+fg_inactive = "gray"
+fg_focus = "yellow"
+error = "red"
+"##,
+        )
+        .unwrap();
+        Palette::from_config(name, &config)
+    }
+
+    #[test]
+    fn rotation_includes_custom_palettes() {
+        let themes = Themes::new(
+            [custom_palette("zenburn"), custom_palette("acme")],
+            ThemeOverrides::default(),
+        );
+
+        let mut name = "ferra".to_owned();
+        let mut rotation = Vec::new();
+        for _ in 0..7 {
+            name = themes.next(&name).name;
+            rotation.push(name.clone());
+        }
+
+        assert_eq!(
+            rotation,
+            [
+                "solarized_dark",
+                "helix",
+                "dracula",
+                "eldritch",
+                "acme",
+                "zenburn",
+                "ferra"
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_palette_replaces_builtin_with_same_name() {
+        let themes = Themes::new(
+            [custom_palette("solarized-dark")],
+            ThemeOverrides::default(),
+        );
+
+        assert_eq!(themes.names().count(), Palette::BUILTIN_NAMES.len());
+        let palette = themes.palette("solarized_dark").unwrap();
+        assert_eq!(palette.name(), "solarized_dark");
+        assert_eq!(palette.bg, Color::Rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn overrides_apply_on_top_of_palette() {
+        let overrides: ThemeOverrides = toml::from_str(
+            r##"
+[entries.selected_entry]
+fg = "#50fa7b"
+
+[requirement]
+must = "red"
+"##,
+        )
+        .unwrap();
+
+        let theme = Themes::new([], overrides).theme(Palette::ferra());
+
+        assert_eq!(
+            theme.entries.selected_entry,
+            Style::new()
+                .fg(Color::Rgb(0x50, 0xfa, 0x7b))
+                .add_modifier(Modifier::BOLD)
+        );
+        assert_eq!(theme.requirement.must, Color::Red);
+        assert_eq!(
+            theme.requirement.should,
+            Theme::default().requirement.should
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, ops::ControlFlow};
 
 use synd_client::payload;
 
@@ -17,17 +17,79 @@ use ratatui::{
     text::{Line, Span, Text},
     widgets::{Block, BorderType, Borders, Cell, Padding, Paragraph, Row, Widget, Wrap},
 };
-use synd_feed::types::FeedUrl;
+use synd_feed::{entry::EntryId, types::FeedUrl};
 
 #[allow(clippy::struct_field_names)]
 pub(crate) struct EntriesWidget {
     entries: FilterableVec<payload::TimelineEntry, FeedFilterer>,
+    /// Server-side listing, shown instead of the timeline while set.
+    listing: Option<ListingEntries>,
+}
+
+/// Server-side entry listing that can replace the timeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum EntryListing {
+    /// Timeline entries matching a search query.
+    Search { query: String },
+    /// Entries the subscriber saved, latest save first.
+    Saved,
+    /// Entry history of one subscribed feed, newest first.
+    Feed { url: FeedUrl },
+}
+
+struct ListingEntries {
+    listing: EntryListing,
+    entries: FilterableVec<payload::TimelineEntry, FeedFilterer>,
 }
 
 impl EntriesWidget {
     pub(crate) fn new() -> Self {
         Self {
             entries: FilterableVec::new(),
+            listing: None,
+        }
+    }
+
+    pub(crate) fn start_listing(&mut self, listing: EntryListing) {
+        self.listing = Some(ListingEntries {
+            listing,
+            entries: FilterableVec::from_filter(self.entries.filter().clone()),
+        });
+    }
+
+    pub(crate) fn update_listing(
+        &mut self,
+        populate: Populate,
+        entries: Vec<payload::TimelineEntry>,
+    ) {
+        if let Some(listing) = self.listing.as_mut() {
+            listing.entries.update(populate, entries);
+        }
+    }
+
+    pub(crate) fn end_listing(&mut self) {
+        self.listing = None;
+    }
+
+    /// Whether the last shown listing entry is selected.
+    pub(crate) fn is_last_listing_entry_selected(&self) -> bool {
+        self.listing.as_ref().is_some_and(|listing| {
+            !listing.entries.is_empty()
+                && listing.entries.selected_index() + 1 == listing.entries.len()
+        })
+    }
+
+    /// Entries currently shown: the listing while one is set, else the timeline.
+    fn shown(&self) -> &FilterableVec<payload::TimelineEntry, FeedFilterer> {
+        self.listing
+            .as_ref()
+            .map_or(&self.entries, |listing| &listing.entries)
+    }
+
+    fn shown_mut(&mut self) -> &mut FilterableVec<payload::TimelineEntry, FeedFilterer> {
+        match self.listing.as_mut() {
+            Some(listing) => &mut listing.entries,
+            None => &mut self.entries,
         }
     }
 
@@ -67,23 +129,33 @@ impl EntriesWidget {
     }
 
     pub(crate) fn update_filterer(&mut self, filterer: FeedFilterer) {
+        if let Some(listing) = self.listing.as_mut() {
+            listing.entries.update_filter(filterer.clone());
+        }
         self.entries.update_filter(filterer);
     }
 
     pub(crate) fn remove_unsubscribed_entries(&mut self, url: &FeedUrl) {
+        if let Some(listing) = self
+            .listing
+            .as_mut()
+            .filter(|listing| matches!(listing.listing, EntryListing::Search { .. }))
+        {
+            listing.entries.retain(|entry| &entry.entry.feed.url != url);
+        }
         self.entries.retain(|entry| &entry.entry.feed.url != url);
     }
 
     pub(crate) fn move_selection(&mut self, direction: Direction) {
-        self.entries.move_selection(direction);
+        self.shown_mut().move_selection(direction);
     }
 
     pub(crate) fn move_first(&mut self) {
-        self.entries.move_first();
+        self.shown_mut().move_first();
     }
 
     pub(crate) fn move_last(&mut self) {
-        self.entries.move_last();
+        self.shown_mut().move_last();
     }
 
     pub(crate) fn entries(&self) -> impl Iterator<Item = &payload::Entry> {
@@ -98,8 +170,38 @@ impl EntriesWidget {
             .and_then(|entry| entry.website_url.as_deref())
     }
 
+    pub(crate) fn selected_timeline_entry(&self) -> Option<&payload::TimelineEntry> {
+        self.shown().selected()
+    }
+
+    /// Reflects a read-state change before the timeline sync delivers it.
+    pub(crate) fn set_entry_read(&mut self, entry_id: &EntryId, read: bool) {
+        self.update_entry(entry_id, |timeline_entry| timeline_entry.read = read);
+    }
+
+    /// Reflects a save-state change before the timeline sync delivers it.
+    /// Unsaved entries stay in the saved listing until it is reloaded.
+    pub(crate) fn set_entry_saved(&mut self, entry_id: &EntryId, saved: bool) {
+        self.update_entry(entry_id, |timeline_entry| timeline_entry.saved = saved);
+    }
+
+    fn update_entry(&mut self, entry_id: &EntryId, update: impl Fn(&mut payload::TimelineEntry)) {
+        let mark = |timeline_entry: &mut payload::TimelineEntry| {
+            if &timeline_entry.entry.id == entry_id {
+                update(timeline_entry);
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        };
+        if let Some(listing) = self.listing.as_mut() {
+            listing.entries.with_mut(mark);
+        }
+        self.entries.with_mut(mark);
+    }
+
     fn selected_entry(&self) -> Option<&payload::Entry> {
-        self.entries.selected().map(|entry| &entry.entry)
+        self.shown().selected().map(|entry| &entry.entry)
     }
 }
 
@@ -122,7 +224,7 @@ impl EntriesWidget {
             .widths(widths)
             .rows(rows)
             .theme(&cx.theme.entries)
-            .selected_idx(self.entries.selected_index())
+            .selected_idx(self.shown().selected_index())
             .highlight_modifier(cx.table_highlight_modifier())
             .build()
             .render(entries_area, buf);
@@ -134,13 +236,13 @@ impl EntriesWidget {
             height: area
                 .height
                 .saturating_sub(header_rows)
-                .min(self.entries.len() as u16),
+                .min(self.shown().len() as u16),
             ..area
         };
 
         Scrollbar {
-            content_length: self.entries.len(),
-            position: self.entries.selected_index(),
+            content_length: self.shown().len(),
+            position: self.shown().selected_index(),
         }
         .render(scrollbar_area, buf, cx);
     }
@@ -153,19 +255,36 @@ impl EntriesWidget {
         impl IntoIterator<Item = Constraint>,
         impl IntoIterator<Item = Row<'a>>,
     ) {
+        let shown = self.shown();
         let (n, m) = {
-            if self.entries.is_empty() {
+            if shown.is_empty() {
                 (Cow::Borrowed("-"), Cow::Borrowed("-"))
             } else {
                 (
-                    Cow::Owned((self.entries.selected_index() + 1).to_string()),
-                    Cow::Owned(self.entries.len().to_string()),
+                    Cow::Owned((shown.selected_index() + 1).to_string()),
+                    Cow::Owned(shown.len().to_string()),
                 )
             }
         };
+        let entry_header = match self.listing.as_ref().map(|listing| &listing.listing) {
+            Some(EntryListing::Search { query }) => {
+                format!("Entry {n}/{m}  {} \"{query}\"", icon!(search))
+            }
+            Some(EntryListing::Saved) => format!("Entry {n}/{m}  {} Saved", icon!(saved)),
+            Some(EntryListing::Feed { url }) => {
+                let title = self
+                    .shown()
+                    .as_unfiltered_slice()
+                    .first()
+                    .and_then(|entry| entry.entry.feed.title.as_deref())
+                    .unwrap_or(url.as_str());
+                format!("Entry {n}/{m}  {} {title}", icon!(feeds))
+            }
+            None => format!("Entry {n}/{m}"),
+        };
         let header = Row::new([
             Cell::from("Published"),
-            Cell::from(format!("Entry {n}/{m}")),
+            Cell::from(entry_header),
             Cell::from("Feed"),
             Cell::from("Req"),
         ]);
@@ -194,19 +313,25 @@ impl EntriesWidget {
             let feed_title = entry.feed.title.as_deref().unwrap_or(ui::UNKNOWN_SYMBOL);
             let requirement = entry.requirement().label(&cx.theme.requirement);
 
-            Row::new([
+            let row = Row::new([
                 Cell::from(Span::from(published)),
                 Cell::from(Line::from(vec![
                     Span::from(icon.symbol()).fg(icon.color().unwrap_or(cx.theme.default_icon_fg)),
                     Span::from(" "),
+                    Span::from(if timeline_entry.saved {
+                        concat!(icon!(saved), " ")
+                    } else {
+                        ""
+                    }),
                     Span::from(title),
                 ])),
                 Cell::from(Span::from(feed_title)),
                 Cell::from(Line::from(vec![requirement, Span::from(" ")])),
-            ])
+            ]);
+            if timeline_entry.read { row.dim() } else { row }
         };
 
-        (header, constraints, self.entries.iter().map(row))
+        (header, constraints, shown.iter().map(row))
     }
 
     fn render_detail(&self, area: Rect, buf: &mut Buffer, cx: &Context<'_>) {
@@ -226,6 +351,11 @@ impl EntriesWidget {
             Constraint::Length(1),
             Constraint::Length(1),
             Constraint::Length(1),
+            Constraint::Length(u16::from(!entry.tags.is_empty())),
+            Constraint::Length(
+                u16::try_from(entry.enclosures.len().min(ENCLOSURE_ROWS)).unwrap_or(u16::MAX),
+            ),
+            Constraint::Length(u16::from(!entry.thumbnails.is_empty())),
             Constraint::Length(1),
             Constraint::Length(1),
             Constraint::Min(0),
@@ -234,6 +364,9 @@ impl EntriesWidget {
             title_area,
             url_area,
             published_area,
+            tags_area,
+            enclosures_area,
+            thumbnail_area,
             _,
             summary_heading_area,
             summary_area,
@@ -266,6 +399,51 @@ impl EntriesWidget {
         ])
         .render(published_area, buf);
 
+        if !entry.tags.is_empty() {
+            Line::from(vec![
+                Span::from(concat!(icon!(tag), " Tags")).bold(),
+                Span::from("      "),
+                Span::from(entry.tags.join(", ")),
+            ])
+            .render(tags_area, buf);
+        }
+
+        // The rows may be squeezed by a short terminal, so the count of the
+        // enclosures left out goes on the last row actually shown.
+        let enclosure_rows = Layout::vertical(vec![
+            Constraint::Length(1);
+            usize::from(enclosures_area.height)
+                .min(entry.enclosures.len())
+        ])
+        .split(enclosures_area);
+        let hidden_enclosures = entry.enclosures.len() - enclosure_rows.len();
+        for (index, (enclosure, area)) in entry
+            .enclosures
+            .iter()
+            .zip(enclosure_rows.iter())
+            .enumerate()
+        {
+            let mut line = Line::from(vec![
+                Span::from(concat!(icon!(media), " Media")).bold(),
+                Span::from("     "),
+                Span::from(enclosure.url.as_str()),
+                Span::from(enclosure_details(enclosure)).dim(),
+            ]);
+            if hidden_enclosures > 0 && index + 1 == enclosure_rows.len() {
+                line.push_span(Span::from(format!("  +{hidden_enclosures} more")).dim());
+            }
+            line.render(*area, buf);
+        }
+
+        if let Some(thumbnail) = entry.thumbnails.first() {
+            Line::from(vec![
+                Span::from(concat!(icon!(image), " Image")).bold(),
+                Span::from("     "),
+                Span::from(thumbnail.url.as_str()),
+            ])
+            .render(thumbnail_area, buf);
+        }
+
         let Some(summary) = entry.summary_text(inner.width.into()) else {
             return;
         };
@@ -285,3 +463,33 @@ impl EntriesWidget {
         Widget::render(paragraph, summary_area, buf);
     }
 }
+
+/// Rows the entry detail spends on media at most; the enclosures left out
+/// are counted as "+N more".
+const ENCLOSURE_ROWS: usize = 3;
+
+/// Formats the declared media type, play time and size, e.g. ` (audio/mpeg, 1:02:03, 12.3 MB)`.
+fn enclosure_details(enclosure: &payload::Enclosure) -> String {
+    let mut details = Vec::new();
+    if let Some(mime_type) = enclosure.mime_type.as_deref() {
+        details.push(mime_type.to_owned());
+    }
+    if let Some(seconds) = enclosure.duration_seconds.filter(|seconds| *seconds > 0) {
+        let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+        details.push(if hours > 0 {
+            format!("{hours}:{minutes:02}:{seconds:02}")
+        } else {
+            format!("{minutes}:{seconds:02}")
+        });
+    }
+    if let Some(length) = enclosure.length.filter(|length| *length > 0) {
+        #[allow(clippy::cast_precision_loss)]
+        let megabytes = length as f64 / 1_000_000.0;
+        details.push(format!("{megabytes:.1} MB"));
+    }
+    if details.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", details.join(", "))
+    }
+}
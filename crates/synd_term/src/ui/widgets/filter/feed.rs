@@ -12,6 +12,7 @@ use crate::{
 #[derive(Debug)]
 pub(crate) struct FeedHandler {
     pub(super) requirement: Requirement,
+    pub(super) unread_only: bool,
     pub(super) categories_state: CategoriesState,
 }
 
@@ -20,6 +21,7 @@ impl FeedHandler {
     pub(super) fn new() -> Self {
         Self {
             requirement: Self::INITIAL_REQUIREMENT,
+            unread_only: false,
             categories_state: CategoriesState::new(),
         }
     }
@@ -70,5 +72,36 @@ impl Filterable<types::Feed> for RequirementFilterer {
     }
 }
 
-pub(crate) type FeedFilterer =
-    ComposedFilterer<ComposedFilterer<RequirementFilterer, CategoryFilterer>, MatcherFilterer>;
+/// Hides timeline entries the subscriber already read. Feeds carry no read
+/// state, so they always pass.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ReadFilterer {
+    unread_only: bool,
+}
+
+impl ReadFilterer {
+    pub(super) fn new(unread_only: bool) -> Self {
+        Self { unread_only }
+    }
+}
+
+impl Filterable<payload::TimelineEntry> for ReadFilterer {
+    fn filter(&self, entry: &payload::TimelineEntry) -> FilterResult {
+        if self.unread_only && entry.read {
+            FilterResult::Discard
+        } else {
+            FilterResult::Use
+        }
+    }
+}
+
+impl Filterable<types::Feed> for ReadFilterer {
+    fn filter(&self, _feed: &types::Feed) -> FilterResult {
+        FilterResult::Use
+    }
+}
+
+pub(crate) type FeedFilterer = ComposedFilterer<
+    ComposedFilterer<ComposedFilterer<RequirementFilterer, ReadFilterer>, CategoryFilterer>,
+    MatcherFilterer,
+>;
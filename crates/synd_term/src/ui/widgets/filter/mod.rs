@@ -19,16 +19,16 @@ use crate::{
     keymap,
     matcher::Matcher,
     types::{
-        EntryExt, RequirementExt,
+        RequirementExt,
         gh::{PullRequestState, Reason, RepoVisibility},
     },
     ui::{
-        Context, icon,
+        self, Context, icon,
         widgets::prompt::{Prompt, RenderCursor},
         widgets::{
             filter::{
                 category::{CategoriesState, FilterCategoryState},
-                feed::RequirementFilterer,
+                feed::{ReadFilterer, RequirementFilterer},
                 gh::GhNotificationHandler,
             },
             gh_notifications::GhNotificationFilterOptions,
@@ -192,6 +192,18 @@ impl FilterWidget {
         self.prompt.borrow_mut().delete_backward();
     }
 
+    /// Takes the search prompt text to run it elsewhere, leaving search
+    /// filtering with an empty prompt. `None` when the prompt is blank.
+    pub(crate) fn take_search_query(&mut self) -> Option<String> {
+        let query = self.prompt.borrow().line().trim().to_owned();
+        if query.is_empty() {
+            return None;
+        }
+        self.prompt.borrow_mut().clear();
+        self.state = State::Normal;
+        Some(query)
+    }
+
     #[must_use]
     pub fn move_requirement(&mut self, direction: Direction) -> Filterer {
         self.feed.requirement = match direction {
@@ -215,6 +227,13 @@ impl FilterWidget {
         Filterer::Feed(self.feed_filterer())
     }
 
+    #[must_use]
+    pub(crate) fn toggle_unread_only(&mut self) -> Filterer {
+        self.feed.unread_only = !self.feed.unread_only;
+
+        Filterer::Feed(self.feed_filterer())
+    }
+
     #[must_use]
     pub fn toggle_category_state(
         &mut self,
@@ -259,8 +278,9 @@ impl FilterWidget {
     }
 
     #[must_use]
-    fn feed_filterer(&self) -> FeedFilterer {
+    pub(crate) fn feed_filterer(&self) -> FeedFilterer {
         RequirementFilterer::new(self.feed.requirement)
+            .and_then(ReadFilterer::new(self.feed.unread_only))
             .and_then(Self::category_filterer(&self.feed.categories_state))
             .and_then(self.matcher_filterer())
     }
@@ -289,17 +309,57 @@ impl FilterWidget {
         MatcherFilterer::new(matcher)
     }
 
-    pub fn update_categories<'a>(
+    pub fn update_categories(
         &mut self,
         config: &Categories,
         populate: Populate,
-        entries: impl IntoIterator<Item = &'a payload::Entry>,
+        categories: impl IntoIterator<Item = Category<'static>>,
     ) {
-        self.feed.categories_state.update(
-            config,
-            populate,
-            entries.into_iter().map(EntryExt::category).cloned(),
-        );
+        self.feed
+            .categories_state
+            .update(config, populate, categories);
+    }
+
+    /// Requirement and category conditions for the server to evaluate, so
+    /// matches outside the loaded timeline window are listed too.
+    pub(crate) fn timeline_filter(&self) -> payload::TimelineEntriesFilter {
+        let requirement = self.feed.requirement;
+        let requirements = (requirement != Requirement::May).then(|| {
+            [Requirement::Must, Requirement::Should]
+                .into_iter()
+                .filter(|r| r.is_satisfied(requirement))
+                .map(Some)
+                .chain(
+                    ui::DEFAULT_REQUIREMENT
+                        .is_satisfied(requirement)
+                        .then_some(None),
+                )
+                .collect()
+        });
+
+        let categories_state = &self.feed.categories_state;
+        let categories = categories_state
+            .state
+            .values()
+            .any(|state| !state.state.is_active())
+            .then(|| {
+                categories_state
+                    .categories
+                    .iter()
+                    .filter(|c| categories_state.state[*c].state.is_active())
+                    .flat_map(|c| {
+                        // Subscriptions without a category are shown as the default one
+                        let unset = (c == ui::default_category()).then_some(None);
+                        [Some(c.clone())].into_iter().chain(unset)
+                    })
+                    .collect()
+            });
+
+        payload::TimelineEntriesFilter {
+            requirements,
+            categories,
+            feed_urls: None,
+        }
     }
 
     pub fn update_gh_notification_categories(
@@ -351,7 +411,17 @@ impl FilterWidget {
                 if r.content == "MAY" {
                     r = r.dim();
                 }
-                spans.extend([Span::from("    "), r, Span::from("  ")]);
+                let mut unread = Span::from("Unread");
+                if !self.feed.unread_only {
+                    unread = unread.dim();
+                }
+                spans.extend([
+                    Span::from("    "),
+                    r,
+                    Span::from("  "),
+                    unread,
+                    Span::from("  "),
+                ]);
             }
             FilterTarget::GhNotifications => {
                 let options = cx.gh_options;
@@ -527,4 +597,35 @@ mod tests {
 
         assert_eq!(filter.filter(&feed), FilterResult::Use);
     }
+
+    #[test]
+    fn timeline_filter_lists_matching_subscription_attributes() {
+        let rust = Category::new("rust").unwrap();
+        let mut filter = FilterWidget::new();
+        filter.update_categories(
+            &Categories::default_toml(),
+            Populate::Replace,
+            [rust.clone(), ui::default_category().clone()],
+        );
+        assert_eq!(
+            filter.timeline_filter(),
+            payload::TimelineEntriesFilter::default()
+        );
+
+        let _ = filter.move_requirement(Direction::Left);
+        let _ = filter.toggle_category_state(&rust, FilterTarget::Feeds);
+
+        assert_eq!(
+            filter.timeline_filter(),
+            payload::TimelineEntriesFilter {
+                requirements: Some(vec![
+                    Some(Requirement::Must),
+                    Some(Requirement::Should),
+                    None
+                ]),
+                categories: Some(vec![Some(ui::default_category().clone()), None]),
+                feed_urls: None,
+            }
+        );
+    }
 }
@@ -38,6 +38,11 @@ impl Prompt {
         }
     }
 
+    pub(crate) fn clear(&mut self) {
+        self.line.clear();
+        self.cursor = 0;
+    }
+
     pub(crate) fn delete_backward(&mut self) {
         let pos = self.move_cursor(Move::BackwardChar(1));
         self.line.replace_range(pos..self.cursor, "");
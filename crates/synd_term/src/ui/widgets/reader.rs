@@ -0,0 +1,404 @@
+use std::cell::RefCell;
+
+use html2text::render::{RichAnnotation, TaggedLine};
+use ratatui::{
+    prelude::{Buffer, Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph, Widget},
+};
+use synd_client::payload;
+use synd_feed::entry::EntryId;
+use tracing::warn;
+
+use crate::{
+    application::Direction,
+    types::TimeExt,
+    ui::{self, Context, icon, theme::ReaderTheme, widgets::scrollbar::Scrollbar},
+};
+
+/// Scrollable view of one entry's content, rendered from its HTML.
+pub(crate) struct ReaderWidget {
+    entry: payload::Entry,
+    /// Whether the content body is still being fetched.
+    fetching: bool,
+    scroll: usize,
+    selected_link: Option<usize>,
+    /// Body laid out for the last rendered width.
+    document: RefCell<Option<Document>>,
+}
+
+/// Entry body converted to styled lines for one width and theme.
+struct Document {
+    width: u16,
+    theme: String,
+    /// Body rows visible at once
+    height: u16,
+    lines: Vec<Line<'static>>,
+    links: Vec<DocumentLink>,
+}
+
+/// Hyperlink in the body, in document order.
+struct DocumentLink {
+    url: String,
+    /// `(line, span)` positions of the link text
+    spans: Vec<(usize, usize)>,
+}
+
+impl ReaderWidget {
+    /// Opens the reader on the entry as listed on the timeline. The content
+    /// body is expected to follow via `apply_content`.
+    pub(crate) fn new(entry: payload::Entry) -> Self {
+        Self {
+            entry,
+            fetching: true,
+            scroll: 0,
+            selected_link: None,
+            document: RefCell::new(None),
+        }
+    }
+
+    pub(crate) fn entry_id(&self) -> &EntryId {
+        &self.entry.id
+    }
+
+    pub(crate) fn entry_website_url(&self) -> Option<&str> {
+        self.entry.website_url.as_deref()
+    }
+
+    /// Replaces the entry with the one fetched with its content body.
+    pub(crate) fn apply_content(&mut self, entry: Option<payload::Entry>) {
+        self.fetching = false;
+        if let Some(entry) = entry {
+            self.entry = entry;
+            self.selected_link = None;
+            *self.document.get_mut() = None;
+        }
+    }
+
+    pub(crate) fn complete_fetch(&mut self) {
+        self.fetching = false;
+    }
+
+    pub(crate) fn scroll(&mut self, direction: Direction) {
+        match direction {
+            Direction::Up | Direction::Left => self.scroll = self.scroll.saturating_sub(1),
+            Direction::Down | Direction::Right => self.scroll_to(self.scroll.saturating_add(1)),
+        }
+    }
+
+    pub(crate) fn scroll_page(&mut self, direction: Direction) {
+        let page = self
+            .document
+            .get_mut()
+            .as_ref()
+            .map_or(1, |document| usize::from(document.height.max(2) - 1));
+        match direction {
+            Direction::Up | Direction::Left => self.scroll = self.scroll.saturating_sub(page),
+            Direction::Down | Direction::Right => self.scroll_to(self.scroll.saturating_add(page)),
+        }
+    }
+
+    pub(crate) fn scroll_first(&mut self) {
+        self.scroll = 0;
+    }
+
+    pub(crate) fn scroll_last(&mut self) {
+        self.scroll_to(usize::MAX);
+    }
+
+    /// Selects the next or previous link, scrolling it into view.
+    pub(crate) fn move_link(&mut self, direction: Direction) {
+        let Some(document) = self.document.get_mut().as_ref() else {
+            return;
+        };
+        if document.links.is_empty() {
+            return;
+        }
+        let selected = match self.selected_link {
+            Some(selected) => direction.apply(selected, document.links.len()),
+            // Start from the links in view rather than the top of the body
+            None => match direction {
+                Direction::Up | Direction::Left => document
+                    .links
+                    .iter()
+                    .rposition(|link| {
+                        link.line() < self.scroll.saturating_add(usize::from(document.height))
+                    })
+                    .unwrap_or(document.links.len() - 1),
+                Direction::Down | Direction::Right => document
+                    .links
+                    .iter()
+                    .position(|link| link.line() >= self.scroll)
+                    .unwrap_or(0),
+            },
+        };
+        let line = document.links[selected].line();
+        let height = usize::from(document.height);
+        self.selected_link = Some(selected);
+        if line < self.scroll || line >= self.scroll.saturating_add(height) {
+            self.scroll_to(line);
+        }
+    }
+
+    pub(crate) fn selected_link_url(&self) -> Option<String> {
+        let selected = self.selected_link?;
+        self.document
+            .borrow()
+            .as_ref()
+            .and_then(|document| document.links.get(selected))
+            .map(|link| link.url.clone())
+    }
+
+    fn scroll_to(&mut self, scroll: usize) {
+        self.scroll = match self.document.get_mut() {
+            Some(document) => scroll.min(document.max_scroll()),
+            None => scroll,
+        };
+    }
+
+    /// Content body, falling back to the summary until the body is known.
+    fn body(&self) -> Option<&str> {
+        self.entry
+            .content
+            .as_deref()
+            .or(self.entry.summary.as_deref())
+    }
+}
+
+impl ReaderWidget {
+    pub fn render(&self, area: Rect, buf: &mut Buffer, cx: &Context<'_>) {
+        let block = Block::new().padding(Padding::new(2, 1, 1, 0));
+        let inner = block.inner(area);
+        Widget::render(block, area, buf);
+
+        let [title_area, meta_area, body_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .areas(inner);
+
+        Line::from(vec![
+            Span::from(concat!(icon!(reader), " ")).bold(),
+            Span::from(self.entry.title.as_deref().unwrap_or(ui::UNKNOWN_SYMBOL)).bold(),
+        ])
+        .render(title_area, buf);
+
+        let published = self
+            .entry
+            .published
+            .as_ref()
+            .or(self.entry.updated.as_ref())
+            .map_or_else(|| ui::UNKNOWN_SYMBOL.to_string(), TimeExt::local_ymd_hm);
+        let mut meta = vec![
+            Span::from(
+                self.entry
+                    .feed
+                    .title
+                    .as_deref()
+                    .unwrap_or(ui::UNKNOWN_SYMBOL),
+            ),
+            Span::from("  "),
+            Span::from(published),
+            Span::from("  "),
+            Span::from(self.entry.website_url.as_deref().unwrap_or_default()),
+        ];
+        if self.fetching {
+            meta.push(Span::from("  (loading full content)"));
+        }
+        Line::from(meta).dim().render(meta_area, buf);
+
+        self.render_body(body_area, buf, cx);
+    }
+
+    fn render_body(&self, area: Rect, buf: &mut Buffer, cx: &Context<'_>) {
+        let block = Block::new()
+            .borders(Borders::TOP)
+            .border_type(BorderType::Plain);
+        let inner = block.inner(area);
+        Widget::render(block, area, buf);
+
+        let [text_area, scrollbar_area] =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Length(1)]).areas(inner);
+
+        let mut document = self.document.borrow_mut();
+        if document
+            .as_ref()
+            .is_some_and(|document| !document.fits(text_area.width, &cx.theme.name))
+        {
+            *document = None;
+        }
+        let document = document.get_or_insert_with(|| {
+            Document::build(
+                self.body(),
+                text_area.width,
+                &cx.theme.name,
+                &cx.theme.reader,
+            )
+        });
+        document.height = text_area.height;
+
+        let offset = self.scroll.min(document.max_scroll());
+        let end = (offset + usize::from(text_area.height)).min(document.lines.len());
+        let mut lines = document.lines[offset..end].to_vec();
+        if let Some(link) = self
+            .selected_link
+            .and_then(|selected| document.links.get(selected))
+        {
+            for &(line, span) in &link.spans {
+                if let Some(span) = line
+                    .checked_sub(offset)
+                    .and_then(|line| lines.get_mut(line))
+                    .and_then(|line| line.spans.get_mut(span))
+                {
+                    span.style = cx.theme.reader.selected_link;
+                }
+            }
+        }
+
+        Paragraph::new(lines)
+            .style(cx.theme.entries.summary)
+            .render(text_area, buf);
+
+        if document.max_scroll() > 0 {
+            Scrollbar {
+                content_length: document.max_scroll() + 1,
+                position: offset,
+            }
+            .render(scrollbar_area, buf, cx);
+        }
+    }
+}
+
+impl Document {
+    fn build(body: Option<&str>, width: u16, theme_name: &str, theme: &ReaderTheme) -> Self {
+        let tagged_lines = body.map_or_else(Vec::new, |body| {
+            html2text::config::rich()
+                .link_footnotes(true)
+                .lines_from_read(body.as_bytes(), usize::from(width.max(1)))
+                .unwrap_or_else(|err| {
+                    warn!("convert entry content html to text: {err}");
+                    Vec::new()
+                })
+        });
+
+        let mut lines = Vec::with_capacity(tagged_lines.len());
+        let mut hyperlinks: Vec<DocumentLink> = Vec::new();
+        let mut in_link = false;
+        for (row, tagged_line) in tagged_lines.iter().enumerate() {
+            let heading = is_heading(tagged_line);
+            let mut spans = Vec::new();
+            for tagged in tagged_line.tagged_strings() {
+                let url = tagged.tag.iter().find_map(|annotation| match annotation {
+                    RichAnnotation::Link(url) => Some(url),
+                    _ => None,
+                });
+                // A link wrapped over several lines continues until a string
+                // without the annotation, which is at least its footnote marker.
+                match url {
+                    Some(url) => {
+                        if !in_link {
+                            hyperlinks.push(DocumentLink {
+                                url: url.clone(),
+                                spans: Vec::new(),
+                            });
+                        }
+                        if let Some(link) = hyperlinks.last_mut() {
+                            link.spans.push((row, spans.len()));
+                        }
+                        in_link = true;
+                    }
+                    None => in_link = false,
+                }
+                spans.push(Span::styled(
+                    tagged.s.clone(),
+                    annotation_style(&tagged.tag, heading, theme),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        Self {
+            width,
+            theme: theme_name.to_owned(),
+            height: 0,
+            lines,
+            links: hyperlinks,
+        }
+    }
+
+    fn fits(&self, width: u16, theme_name: &str) -> bool {
+        self.width == width && self.theme == theme_name
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.lines.len().saturating_sub(usize::from(self.height))
+    }
+}
+
+impl DocumentLink {
+    fn line(&self) -> usize {
+        self.spans.first().map_or(0, |(line, _)| *line)
+    }
+}
+
+/// html2text marks headings with a `#` prefix per level.
+fn is_heading(line: &TaggedLine<Vec<RichAnnotation>>) -> bool {
+    let text = line
+        .tagged_strings()
+        .map(|s| s.s.as_str())
+        .collect::<String>();
+    let level = text.chars().take_while(|c| *c == '#').count();
+    (1..=6).contains(&level) && text[level..].starts_with(' ')
+}
+
+fn annotation_style(annotations: &[RichAnnotation], heading: bool, theme: &ReaderTheme) -> Style {
+    let base = if heading { theme.heading } else { Style::new() };
+    annotations
+        .iter()
+        .fold(base, |style, annotation| match annotation {
+            RichAnnotation::Strong => style.bold(),
+            RichAnnotation::Emphasis => style.italic(),
+            RichAnnotation::Strikeout => style.crossed_out(),
+            RichAnnotation::Code | RichAnnotation::Preformat(_) => style.patch(theme.code),
+            RichAnnotation::Link(_) => style.patch(theme.link),
+            RichAnnotation::Image(_) => style.dim(),
+            // Inline colours are left to the theme
+            _ => style,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn scrolling_down_after_jumping_to_the_end_saturates() {
+        let entry = serde_json::from_value(json!({
+            "id": format!("synd:entry:v1:{}", "a".repeat(64)),
+            "title": "Entry",
+            "published": null,
+            "updated": null,
+            "websiteUrl": null,
+            "summary": null,
+            "feed": {
+                "title": "Feed",
+                "url": "https://example.com/feed.xml",
+                "requirement": "SHOULD",
+                "category": "rust"
+            }
+        }))
+        .unwrap();
+        let mut reader = ReaderWidget::new(entry);
+
+        // Before the first render there is no document to clamp against.
+        reader.scroll_last();
+        reader.scroll(Direction::Down);
+        reader.scroll_page(Direction::Down);
+
+        assert_eq!(reader.scroll, usize::MAX);
+    }
+}
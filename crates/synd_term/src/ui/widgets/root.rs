@@ -48,7 +48,10 @@ impl<'a> AppWidget<'a> {
 
         match cx.tab {
             Tab::Feeds => feeds.subscription.render(content_area, buf, cx),
-            Tab::Entries => feeds.entries.render(content_area, buf, cx),
+            Tab::Entries => match &feeds.reader {
+                Some(reader) => reader.render(content_area, buf, cx),
+                None => feeds.entries.render(content_area, buf, cx),
+            },
             Tab::Gh => gh.notifications.render(content_area, buf, cx),
         }
 
@@ -17,28 +17,38 @@ use crate::{
 
 use super::tabs::Tab;
 
+enum StatusMessage {
+    Info(String),
+    Error(String),
+}
+
 pub struct StatusLineWidget {
-    error_message: Option<String>,
+    message: Option<StatusMessage>,
 }
 
 impl StatusLineWidget {
     pub fn new() -> Self {
-        Self {
-            error_message: None,
-        }
+        Self { message: None }
+    }
+
+    pub fn set_info_message(&mut self, msg: String) {
+        self.message = Some(StatusMessage::Info(msg));
     }
 
     pub fn set_error_message(&mut self, msg: String) {
-        self.error_message = Some(msg);
+        self.message = Some(StatusMessage::Error(msg));
     }
 
-    pub fn clear_error_message(&mut self) {
-        self.error_message = None;
+    pub fn clear_message(&mut self) {
+        self.message = None;
     }
 
     pub fn render(&self, area: Rect, buf: &mut Buffer, cx: &Context<'_>, tab: Option<Tab>) {
-        if let Some(error_message) = self.error_message.as_ref() {
-            Self::render_error(area, buf, cx, error_message);
+        if let Some(message) = self.message.as_ref() {
+            match message {
+                StatusMessage::Info(message) => Self::render_info(area, buf, cx, message),
+                StatusMessage::Error(message) => Self::render_error(area, buf, cx, message),
+            }
         } else if let Some(status) = cx.in_flight.status() {
             Self::render_in_flight(area, buf, cx, &status);
         } else {
@@ -105,6 +115,7 @@ impl StatusLineWidget {
                 .chain(&[
                     ("h/l", icon!(requirement)),
                     ("Ent", icon!(open)),
+                    ("v", icon!(entries)),
                     ("a", "󰑫"),
                     ("e", ""),
                     ("d", "󰼡"),
@@ -116,6 +127,7 @@ impl StatusLineWidget {
                     ("h/l", icon!(requirement)),
                     ("Ent", icon!(open)),
                     ("Sp", icon!(browse)),
+                    ("v", icon!(reader)),
                 ])
                 .chain(suffix_keys),
             Some(Tab::Gh) => pre_keys
@@ -142,6 +154,14 @@ impl StatusLineWidget {
             .render(area, buf);
     }
 
+    fn render_info(area: Rect, buf: &mut Buffer, cx: &Context<'_>, message: &str) {
+        Paragraph::new(Line::from(message))
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: true })
+            .style(cx.theme.prompt.background)
+            .render(area, buf);
+    }
+
     fn render_error(area: Rect, buf: &mut Buffer, cx: &Context<'_>, error_message: &str) {
         Paragraph::new(Line::from(error_message))
             .alignment(Alignment::Left)
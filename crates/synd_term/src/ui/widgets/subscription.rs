@@ -61,6 +61,10 @@ impl SubscriptionWidget {
         }
     }
 
+    pub(crate) fn feeds(&self) -> impl Iterator<Item = &types::Feed> {
+        self.feeds.as_unfiltered_slice().iter()
+    }
+
     pub(crate) fn has_subscription(&self) -> bool {
         !self.feeds.is_empty()
     }
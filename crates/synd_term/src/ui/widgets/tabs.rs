@@ -51,6 +51,12 @@ impl TabsWidget {
         self.current()
     }
 
+    pub fn select(&mut self, tab: Tab) {
+        if let Some(selected) = self.tabs.iter().position(|t| *t == tab) {
+            self.selected = selected;
+        }
+    }
+
     fn width(&self) -> u16 {
         #[allow(clippy::cast_possible_truncation)]
         self.tabs.iter().fold(0, |width, tab| {
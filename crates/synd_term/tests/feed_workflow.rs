@@ -61,25 +61,563 @@ mod bootstrapped_app {
     }
 }
 
+mod timeline_sync {
+    use super::*;
+
+    #[tokio::test]
+    async fn expired_catch_up_reloads_the_window() {
+        let mut reloaded = timeline_entries();
+        reloaded.nodes.truncate(1);
+        reloaded.seq = 5;
+        let api = MockFeedApi::new([
+            MockFeedApiResponse::FeedEvents(Ok(Vec::new())),
+            MockFeedApiResponse::Subscription(Ok(subscription())),
+            MockFeedApiResponse::TimelineEntries(Ok(timeline_entries())),
+            MockFeedApiResponse::TimelineChanges(Ok(payload::TimelineChangesPayload {
+                changes: Vec::new(),
+                seq: 5,
+                has_more: false,
+                expired: true,
+            })),
+            MockFeedApiResponse::TimelineEntries(Ok(reloaded)),
+            MockFeedApiResponse::TimelineChanges(Ok(payload::TimelineChangesPayload {
+                changes: Vec::new(),
+                seq: 5,
+                has_more: false,
+                expired: false,
+            })),
+        ]);
+        let (_cache_dir, mut app) = app(api, MockInteractor::new());
+        app.bootstrap_for_test();
+
+        let (_tx, mut input) = event_stream();
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("Entry 1/1"));
+        assert!(screen.contains_text("Rust feed architecture"));
+        assert!(!screen.contains_text("Async GraphQL testing"));
+    }
+}
+
+mod entry_read_state {
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn toggling_entry_read_completes_its_request() {
+        let (_cache_dir, mut app) = start_app_with(
+            [MockFeedApiResponse::MarkEntries(Ok(serde_json::from_value(
+                json!({
+                    "status": { "code": "OK" },
+                    "entryIds": [
+                        "synd:entry:v1:0000000000000000000000000000000000000000000000000000000000000001"
+                    ]
+                }),
+            )
+            .expect("mark entries fixture")))],
+            MockInteractor::new(),
+        );
+        let (tx, mut input) = event_stream();
+        app.wait_until_jobs_completed(&mut input).await;
+
+        tx.send(Event::Key(KeyEvent::new(
+            KeyCode::Char('m'),
+            KeyModifiers::NONE,
+        )));
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(!screen.contains_text("mock feed API"));
+    }
+}
+
+mod saved_entries {
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+    use super::*;
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[tokio::test]
+    async fn saved_view_lists_saved_entries_until_closed() {
+        let (_cache_dir, mut app) = start_app_with(
+            [
+                MockFeedApiResponse::SaveEntry(Ok(serde_json::from_value(json!({
+                    "status": { "code": "OK" },
+                    "changed": true
+                }))
+                .expect("save entry fixture"))),
+                MockFeedApiResponse::SavedEntries(Ok(serde_json::from_value(json!({
+                    "nodes": [
+                        {
+                            "orderTime": "2024-11-03T00:00:00Z",
+                            "saved": true,
+                            "entry": {
+                                "id": "synd:entry:v1:0000000000000000000000000000000000000000000000000000000000000004",
+                                "title": "Notes from an unsubscribed feed",
+                                "published": null,
+                                "updated": null,
+                                "websiteUrl": "https://example.org/notes",
+                                "summary": "Kept after the feed was unsubscribed.",
+                                "feed": {
+                                    "title": "Old Notes",
+                                    "url": "https://example.org/feed.xml",
+                                    "requirement": null,
+                                    "category": null
+                                }
+                            }
+                        }
+                    ],
+                    "pageInfo": {
+                        "hasNextPage": false,
+                        "endCursor": null
+                    }
+                }))
+                .expect("saved entries fixture"))),
+            ],
+            MockInteractor::new(),
+        );
+        let (tx, mut input) = event_stream();
+        app.wait_until_jobs_completed(&mut input).await;
+
+        tx.send(key(KeyCode::Char('s')));
+        app.wait_until_jobs_completed(&mut input).await;
+        assert!(!Screen::new(app.buffer()).contains_text("mock feed API"));
+
+        tx.send(Event::Key(KeyEvent::new(
+            KeyCode::Char('S'),
+            KeyModifiers::SHIFT,
+        )));
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("Saved"));
+        assert!(screen.contains_text("Notes from an unsubscribed feed"));
+        assert!(!screen.contains_text("Async GraphQL testing"));
+        assert!(!screen.contains_text("mock feed API"));
+
+        tx.send(key(KeyCode::Esc));
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("Entry 1/2"));
+        assert!(screen.contains_text("Async GraphQL testing"));
+        assert!(!screen.contains_text("Notes from an unsubscribed feed"));
+    }
+}
+
+mod entry_detail {
+    use super::*;
+
+    #[tokio::test]
+    async fn shows_tags_and_media_of_selected_entry() {
+        let (_cache_dir, mut app) = start_app();
+        let (_tx, mut input) = event_stream();
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("rust, architecture"));
+        assert!(screen.contains_text(
+            "https://example.com/rust-feed-architecture.mp3 (audio/mpeg, 30:30, 1.0 MB)"
+        ));
+        assert!(!screen.contains_text("rust-feed-architecture-part-2.mp3"));
+        assert!(screen.contains_text("+4 more"));
+        assert!(screen.contains_text("https://example.com/rust-feed-architecture.jpg"));
+    }
+}
+
+mod server_search {
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+    use super::*;
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[tokio::test]
+    async fn submitted_search_shows_server_matches_until_closed() {
+        let (_cache_dir, mut app) = start_app_with(
+            [MockFeedApiResponse::TimelineSearch(Ok(
+                serde_json::from_value(json!({
+                    "nodes": [
+                        {
+                            "orderTime": "2025-01-10T00:00:00Z",
+                            "read": true,
+                            "entry": {
+                                "id": "synd:entry:v1:0000000000000000000000000000000000000000000000000000000000000003",
+                                "title": "Archived borrow checker notes",
+                                "published": null,
+                                "updated": null,
+                                "websiteUrl": "https://example.com/borrow-checker",
+                                "summary": "Older notes about the borrow checker.",
+                                "feed": {
+                                    "title": "Engineering Notes",
+                                    "url": "https://example.com/feed.xml",
+                                    "requirement": "SHOULD",
+                                    "category": "rust"
+                                }
+                            }
+                        }
+                    ],
+                    "pageInfo": {
+                        "hasNextPage": false,
+                        "endCursor": null
+                    }
+                }))
+                .expect("timeline search fixture"),
+            ))],
+            MockInteractor::new(),
+        );
+        let (tx, mut input) = event_stream();
+        app.wait_until_jobs_completed(&mut input).await;
+
+        tx.send(key(KeyCode::Char('/')));
+        for ch in "borrow".chars() {
+            tx.send(key(KeyCode::Char(ch)));
+        }
+        tx.send(key(KeyCode::Enter));
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("Archived borrow checker notes"));
+        assert!(screen.contains_text("Entry 1/1"));
+        assert!(screen.contains_text("\"borrow\""));
+        assert!(!screen.contains_text("Rust feed architecture"));
+        assert!(!screen.contains_text("mock feed API"));
+
+        tx.send(key(KeyCode::Esc));
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("Entry 1/2"));
+        assert!(screen.contains_text("Rust feed architecture"));
+        assert!(!screen.contains_text("Archived borrow checker notes"));
+    }
+}
+
+mod entry_reader {
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+    use super::*;
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[tokio::test]
+    async fn reader_shows_fetched_content_until_closed() {
+        let (_cache_dir, mut app) = start_app_with(
+            [MockFeedApiResponse::TimelineEntry(Ok(Some(Box::new(
+                serde_json::from_value(json!({
+                    "orderTime": "2026-06-02T00:00:00Z",
+                    "read": false,
+                    "entry": {
+                        "id": "synd:entry:v1:0000000000000000000000000000000000000000000000000000000000000001",
+                        "title": "Rust feed architecture",
+                        "published": null,
+                        "updated": null,
+                        "websiteUrl": "https://example.com/rust-feed-architecture",
+                        "summary": "A note about feed architecture.",
+                        "content": "<h2>Crawl pipeline</h2><p>Entries flow through a <a href=\"/crawl\">dispatcher</a>.</p><pre><code>fn crawl() {}</code></pre><ul><li>Fetch</li><li>Project</li></ul>",
+                        "feed": {
+                            "title": "Engineering Notes",
+                            "url": "https://example.com/feed.xml",
+                            "requirement": "SHOULD",
+                            "category": "rust"
+                        }
+                    }
+                }))
+                .expect("timeline entry fixture"),
+            ))))],
+            MockInteractor::new(),
+        );
+        let (tx, mut input) = event_stream();
+        app.wait_until_jobs_completed(&mut input).await;
+
+        tx.send(key(KeyCode::Char('v')));
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("Rust feed architecture"));
+        assert!(screen.contains_text("## Crawl pipeline"));
+        assert!(screen.contains_text("Entries flow through a dispatcher[1]."));
+        assert!(screen.contains_text("fn crawl() {}"));
+        assert!(screen.contains_text("* Project"));
+        assert!(screen.contains_text("[1]: /crawl"));
+        assert!(!screen.contains_text("loading full content"));
+        assert!(!screen.contains_text("Entry 1/2"));
+
+        tx.send(key(KeyCode::Char(']')));
+        tx.send(key(KeyCode::Enter));
+        tx.send(key(KeyCode::Char('q')));
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("Entry 1/2"));
+        assert!(!screen.contains_text("## Crawl pipeline"));
+
+        tx.send(key(KeyCode::Char('j')));
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("Entry 2/2"));
+    }
+}
+
+mod feed_subscription {
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    use synd_feed::types::FeedUrl;
+
+    use super::*;
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn candidate(url: &str, kind: payload::FeedCandidateKind) -> payload::FeedCandidate {
+        payload::FeedCandidate {
+            url: FeedUrl::parse(url).unwrap(),
+            kind,
+            title: None,
+            source: payload::FeedCandidateSource::LinkAlternate,
+        }
+    }
+
+    fn requested(url: &str, kind: payload::FeedCandidateKind) -> payload::FeedCandidate {
+        payload::FeedCandidate {
+            source: payload::FeedCandidateSource::Requested,
+            ..candidate(url, kind)
+        }
+    }
+
+    #[tokio::test]
+    async fn website_url_offers_discovered_feeds_in_editor() {
+        let picked = "https://blog.example.org/rss.xml";
+        let (_cache_dir, mut app) = start_app_with(
+            [
+                MockFeedApiResponse::DiscoverFeeds(Ok(vec![
+                    candidate(
+                        "https://blog.example.org/atom.xml",
+                        payload::FeedCandidateKind::Atom,
+                    ),
+                    candidate(picked, payload::FeedCandidateKind::Rss),
+                ])),
+                MockFeedApiResponse::DiscoverFeeds(Ok(vec![requested(
+                    picked,
+                    payload::FeedCandidateKind::Rss,
+                )])),
+                MockFeedApiResponse::SubscribeFeed(Ok(serde_json::from_value(json!({
+                    "status": { "code": "OK" },
+                    "url": picked,
+                    "disposition": "SUBSCRIBED"
+                }))
+                .expect("subscribe feed fixture"))),
+                MockFeedApiResponse::Subscription(Ok(subscribed_feeds(&[
+                    ("https://example.com/feed.xml", "Engineering Notes"),
+                    (picked, "Example Blog"),
+                ]))),
+            ],
+            MockInteractor::new().with_buffer(vec![
+                "SHOULD rust https://blog.example.org".to_owned(),
+                format!("SHOULD rust {picked}"),
+            ]),
+        );
+        let (tx, mut input) = event_stream();
+        app.wait_until_jobs_completed(&mut input).await;
+
+        tx.send_multi([key(KeyCode::Tab), key(KeyCode::Char('a'))]);
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("Example Blog"));
+        assert!(!screen.contains_text("mock feed API"));
+    }
+
+    #[tokio::test]
+    async fn picking_an_already_subscribed_feed_is_rejected() {
+        let subscribed = "https://example.com/feed.xml";
+        let (_cache_dir, mut app) = start_app_with(
+            [MockFeedApiResponse::DiscoverFeeds(Ok(vec![
+                candidate(
+                    "https://example.com/atom.xml",
+                    payload::FeedCandidateKind::Atom,
+                ),
+                candidate(subscribed, payload::FeedCandidateKind::Rss),
+            ]))],
+            MockInteractor::new().with_buffer(vec![
+                "SHOULD rust https://example.com".to_owned(),
+                format!("SHOULD rust {subscribed}"),
+            ]),
+        );
+        let (tx, mut input) = event_stream();
+        app.wait_until_jobs_completed(&mut input).await;
+
+        tx.send_multi([key(KeyCode::Tab), key(KeyCode::Char('a'))]);
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("already subscribed"));
+        assert!(!screen.contains_text("mock feed API"));
+    }
+}
+
+mod feed_refresh {
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    use synd_client::SyndApiError;
+
+    use super::*;
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[tokio::test]
+    async fn queued_refresh_is_reported() {
+        let (_cache_dir, mut app) = start_app_with(
+            [MockFeedApiResponse::RequestCrawl(Ok(
+                serde_json::from_value(json!({
+                    "status": { "code": "OK" },
+                    "url": "https://example.com/feed.xml",
+                    "disposition": "REQUESTED"
+                }))
+                .expect("request crawl fixture"),
+            ))],
+            MockInteractor::new(),
+        );
+        let (tx, mut input) = event_stream();
+        app.wait_until_jobs_completed(&mut input).await;
+
+        tx.send_multi([key(KeyCode::Tab), key(KeyCode::Char('f'))]);
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("Refresh queued for https://example.com/feed.xml"));
+    }
+
+    #[tokio::test]
+    async fn failed_refresh_is_reported() {
+        let (_cache_dir, mut app) = start_app_with(
+            [MockFeedApiResponse::RequestCrawl(Err(
+                SyndApiError::Graphql {
+                    errors: vec![graphql_client::Error {
+                        message: "crawler unavailable".to_owned(),
+                        locations: None,
+                        path: None,
+                        extensions: None,
+                    }],
+                },
+            ))],
+            MockInteractor::new(),
+        );
+        let (tx, mut input) = event_stream();
+        app.wait_until_jobs_completed(&mut input).await;
+
+        tx.send_multi([key(KeyCode::Tab), key(KeyCode::Char('f'))]);
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("Refresh of https://example.com/feed.xml failed:"));
+        assert!(screen.contains_text("crawler unavailable"));
+    }
+}
+
+mod feed_entries {
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+    use super::*;
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[tokio::test]
+    async fn selected_feed_shows_its_entry_history_until_closed() {
+        let (_cache_dir, mut app) = start_app_with(
+            [MockFeedApiResponse::FeedEntries(Ok(serde_json::from_value(json!({
+                "nodes": [
+                    {
+                        "orderTime": "2023-01-10T00:00:00Z",
+                        "entry": {
+                            "id": "synd:entry:v1:0000000000000000000000000000000000000000000000000000000000000005",
+                            "title": "Retired crawler design",
+                            "published": null,
+                            "updated": null,
+                            "websiteUrl": "https://example.com/retired",
+                            "summary": "No longer on the timeline.",
+                            "feed": {
+                                "title": "Engineering Notes",
+                                "url": "https://example.com/feed.xml",
+                                "requirement": "SHOULD",
+                                "category": "rust"
+                            }
+                        }
+                    }
+                ],
+                "pageInfo": {
+                    "hasNextPage": false,
+                    "endCursor": null
+                }
+            }))
+            .expect("feed entries fixture")))],
+            MockInteractor::new(),
+        );
+        let (tx, mut input) = event_stream();
+        app.wait_until_jobs_completed(&mut input).await;
+
+        tx.send_multi([key(KeyCode::Tab), key(KeyCode::Char('v'))]);
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("Entry 1/1"));
+        assert!(screen.contains_text("Retired crawler design"));
+        assert!(!screen.contains_text("Async GraphQL testing"));
+        assert!(!screen.contains_text("mock feed API"));
+
+        tx.send(key(KeyCode::Esc));
+        app.wait_until_jobs_completed(&mut input).await;
+
+        let screen = Screen::new(app.buffer());
+        assert!(screen.contains_text("Entry 1/2"));
+        assert!(!screen.contains_text("Retired crawler design"));
+    }
+}
+
 fn start_app() -> (TempDir, Application) {
-    let api = MockFeedApi::new([
-        MockFeedApiResponse::FeedEvents(Ok(Vec::new())),
-        MockFeedApiResponse::Subscription(Ok(subscription())),
-        MockFeedApiResponse::TimelineEntries(Ok(timeline_entries())),
-        MockFeedApiResponse::TimelineChanges(Ok(payload::TimelineChangesPayload {
-            changes: Vec::new(),
-            seq: 2,
-            has_more: false,
-        })),
-    ]);
-    let (cache_dir, mut app) = app(api);
+    start_app_with([], MockInteractor::new())
+}
+
+fn start_app_with(
+    responses: impl IntoIterator<Item = MockFeedApiResponse>,
+    interactor: MockInteractor,
+) -> (TempDir, Application) {
+    let api = MockFeedApi::new(
+        [
+            MockFeedApiResponse::FeedEvents(Ok(Vec::new())),
+            MockFeedApiResponse::Subscription(Ok(subscription())),
+            MockFeedApiResponse::TimelineEntries(Ok(timeline_entries())),
+            MockFeedApiResponse::TimelineChanges(Ok(payload::TimelineChangesPayload {
+                changes: Vec::new(),
+                seq: 2,
+                has_more: false,
+                expired: false,
+            })),
+        ]
+        .into_iter()
+        .chain(responses),
+    );
+    let (cache_dir, mut app) = app(api, interactor);
 
     app.bootstrap_for_test();
 
     (cache_dir, app)
 }
 
-fn app(feed_api: MockFeedApi) -> (TempDir, Application) {
+fn app(feed_api: MockFeedApi, interactor: MockInteractor) -> (TempDir, Application) {
     let cache_dir = tempfile::tempdir().expect("temp cache dir");
     let terminal = new_test_terminal(120, 30);
     let app = Application::builder()
@@ -89,17 +627,36 @@ fn app(feed_api: MockFeedApi) -> (TempDir, Application) {
         .config(Config::default().with_idle_timer_interval(Duration::from_millis(10)))
         .cache(Cache::new(cache_dir.path().to_path_buf()))
         .theme(Theme::default())
-        .interactor(Box::new(MockInteractor::new()))
+        .interactor(Box::new(interactor))
         .build();
 
     (cache_dir, app)
 }
 
 fn subscription() -> payload::SubscriptionPayload {
+    subscribed_feeds(&[("https://example.com/feed.xml", "Engineering Notes")])
+}
+
+fn subscribed_feeds(feeds: &[(&str, &str)]) -> payload::SubscriptionPayload {
+    let nodes = feeds
+        .iter()
+        .map(|(url, title)| subscribed_feed(url, title))
+        .collect::<Vec<_>>();
     serde_json::from_value(json!({
         "feeds": {
-            "nodes": [{
-                "url": "https://example.com/feed.xml",
+            "nodes": nodes,
+            "pageInfo": {
+                "hasNextPage": false,
+                "endCursor": null
+            }
+        }
+    }))
+    .expect("subscription fixture")
+}
+
+fn subscribed_feed(url: &str, title: &str) -> serde_json::Value {
+    json!({
+                "url": url,
                 "requirement": "SHOULD",
                 "category": "rust",
                 "crawlPolicy": {
@@ -110,7 +667,7 @@ fn subscription() -> payload::SubscriptionPayload {
                 },
                 "feed": {
                     "type": "RSS2",
-                    "title": "Engineering Notes",
+                    "title": title,
                     "updated": null,
                     "websiteUrl": "https://example.com",
                     "description": null,
@@ -129,14 +686,7 @@ fn subscription() -> payload::SubscriptionPayload {
                         "nodes": []
                     }
                 }
-            }],
-            "pageInfo": {
-                "hasNextPage": false,
-                "endCursor": null
-            }
-        }
-    }))
-    .expect("subscription fixture")
+    })
 }
 
 fn timeline_entries() -> payload::TimelineEntryConnection {
@@ -151,6 +701,26 @@ fn timeline_entries() -> payload::TimelineEntryConnection {
                     "updated": null,
                     "websiteUrl": "https://example.com/rust-feed-architecture",
                     "summary": "A note about feed architecture.",
+                    "tags": ["rust", "architecture"],
+                    "enclosures": [
+                        {
+                            "url": "https://example.com/rust-feed-architecture.mp3",
+                            "mimeType": "audio/mpeg",
+                            "length": 1_048_576,
+                            "durationSeconds": 1830
+                        },
+                        { "url": "https://example.com/rust-feed-architecture-part-2.mp3" },
+                        { "url": "https://example.com/rust-feed-architecture-part-3.mp3" },
+                        { "url": "https://example.com/rust-feed-architecture-part-4.mp3" },
+                        { "url": "https://example.com/rust-feed-architecture-part-5.mp3" }
+                    ],
+                    "thumbnails": [
+                        {
+                            "url": "https://example.com/rust-feed-architecture.jpg",
+                            "width": 640,
+                            "height": 360
+                        }
+                    ],
                     "feed": {
                         "title": "Engineering Notes",
                         "url": "https://example.com/feed.xml",